@@ -10,6 +10,8 @@ use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{metrics::MeterProvider as SdkMeterProvider, runtime, Resource};
 use tokio::signal::ctrl_c;
 
+mod stats;
+
 fn init_meter_provider(grpc_endpoint: &str) -> SdkMeterProvider {
     opentelemetry_otlp::new_pipeline()
         .metrics(runtime::Tokio)
@@ -92,6 +94,40 @@ async fn main() -> anyhow::Result<()> {
         .with_unit(Unit::new("instructions"))
         .init();
 
+    let bpf_program_run_count = meter
+        .u64_observable_counter("bpf_program_run_count")
+        .with_description("BPF program runs, counted while BPF stats are enabled")
+        .with_unit(Unit::new("runs"))
+        .init();
+
+    let bpf_program_run_time = meter
+        .u64_observable_counter("bpf_program_run_time")
+        .with_description("BPF program run time, counted while BPF stats are enabled")
+        .with_unit(Unit::new("nanoseconds"))
+        .init();
+
+    let bpf_program_average_run_time = meter
+        .u64_observable_gauge("bpf_program_average_run_time")
+        .with_description("BPF program average run time")
+        .with_unit(Unit::new("nanoseconds"))
+        .init();
+
+    let bpf_dispatcher_slot_packets = meter
+        .u64_observable_counter("bpf_dispatcher_slot_packets")
+        .with_description(
+            "Packets a bpfman dispatcher ran the program in a slot on, since it was last rebuilt",
+        )
+        .with_unit(Unit::new("packets"))
+        .init();
+
+    let bpf_dispatcher_slot_verdicts = meter
+        .u64_observable_counter("bpf_dispatcher_slot_verdicts")
+        .with_description(
+            "Verdicts the program in a slot of a bpfman dispatcher returned, since it was last rebuilt",
+        )
+        .with_unit(Unit::new("packets"))
+        .init();
+
     let bpf_map_key_size = meter
         .u64_observable_counter("bpf_map_key_size")
         .with_description("BPF map key size")
@@ -121,6 +157,11 @@ async fn main() -> anyhow::Result<()> {
                 bpf_program_size_translated_bytes.as_any(),
                 bpf_program_mem_bytes.as_any(),
                 bpf_program_verified_instructions.as_any(),
+                bpf_program_run_count.as_any(),
+                bpf_program_run_time.as_any(),
+                bpf_program_average_run_time.as_any(),
+                bpf_dispatcher_slot_packets.as_any(),
+                bpf_dispatcher_slot_verdicts.as_any(),
                 bpf_map_key_size.as_any(),
                 bpf_map_value_size.as_any(),
                 bpf_map_max_entries.as_any(),
@@ -190,6 +231,53 @@ async fn main() -> anyhow::Result<()> {
                         verified_instructions.into(),
                         &prog_key_labels,
                     );
+
+                    if let Ok(run_stats) = stats::run_stats(id) {
+                        observer.observe_u64(
+                            &bpf_program_run_count,
+                            run_stats.run_count,
+                            &prog_key_labels,
+                        );
+
+                        observer.observe_u64(
+                            &bpf_program_run_time,
+                            run_stats.run_time_ns,
+                            &prog_key_labels,
+                        );
+
+                        observer.observe_u64(
+                            &bpf_program_average_run_time,
+                            run_stats
+                                .run_time_ns
+                                .checked_div(run_stats.run_count)
+                                .unwrap_or_default(),
+                            &prog_key_labels,
+                        );
+                    }
+                }
+
+                for slot in stats::dispatcher_slots() {
+                    let slot_labels = [
+                        KeyValue::new("dispatcher", slot.dispatcher),
+                        KeyValue::new("key", slot.key.clone()),
+                        KeyValue::new("slot", slot.slot.to_string()),
+                    ];
+
+                    observer.observe_u64(
+                        &bpf_dispatcher_slot_packets,
+                        slot.stats.packets,
+                        &slot_labels,
+                    );
+
+                    for (verdict, count) in slot.stats.verdicts(slot.kind) {
+                        let verdict_labels = [
+                            KeyValue::new("dispatcher", slot.dispatcher),
+                            KeyValue::new("key", slot.key.clone()),
+                            KeyValue::new("slot", slot.slot.to_string()),
+                            KeyValue::new("verdict", verdict),
+                        ];
+                        observer.observe_u64(&bpf_dispatcher_slot_verdicts, count, &verdict_labels);
+                    }
                 }
 
                 for link in loaded_links().flatten() {
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// aya doesn't report the run counters of programs yet, so they are read from
+// bpf_prog_info with the bpf syscall. They only increase while BPF stats are
+// enabled, by bpfman or the kernel.bpf_stats_enabled sysctl.
+//
+// The counters of the slots of the bpfman dispatchers are read from the stats
+// maps bpfman pins for them.
+
+use std::{fs, io, mem};
+
+use bpfman_api::{
+    dispatcher_stats::{DispatcherKind, SlotStats, DISPATCHER_SLOTS},
+    util::directories::{RTDIR_FS_TC_EGRESS, RTDIR_FS_TC_INGRESS, RTDIR_FS_XDP},
+};
+use nix::libc;
+
+const BPF_PROG_GET_FD_BY_ID: libc::c_long = 13;
+const BPF_OBJ_GET_INFO_BY_FD: libc::c_long = 15;
+
+#[repr(C)]
+#[derive(Default)]
+struct GetFdByIdAttr {
+    id: u32,
+    next_id: u32,
+    open_flags: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct InfoAttr {
+    bpf_fd: u32,
+    info_len: u32,
+    info: u64,
+}
+
+// struct bpf_prog_info, up to the counters.
+#[repr(C)]
+#[derive(Default)]
+struct ProgInfo {
+    _fields: [u64; 24],
+    run_time_ns: u64,
+    run_cnt: u64,
+}
+
+pub(crate) struct RunStats {
+    pub(crate) run_time_ns: u64,
+    pub(crate) run_count: u64,
+}
+
+fn sys_bpf<T>(cmd: libc::c_long, attr: &mut T) -> io::Result<libc::c_long> {
+    // SAFETY: attr is one of the bpf_attr layouts above, the buffers it points
+    // to outlive the call.
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_bpf,
+            cmd,
+            attr as *mut T,
+            mem::size_of::<T>() as libc::c_uint,
+        )
+    };
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+pub(crate) fn run_stats(id: u32) -> io::Result<RunStats> {
+    let mut attr = GetFdByIdAttr {
+        id,
+        ..Default::default()
+    };
+    let fd = sys_bpf(BPF_PROG_GET_FD_BY_ID, &mut attr)? as i32;
+    let mut info = ProgInfo::default();
+    let mut attr = InfoAttr {
+        bpf_fd: fd as u32,
+        info_len: mem::size_of::<ProgInfo>() as u32,
+        info: &mut info as *mut ProgInfo as u64,
+    };
+    let result = sys_bpf(BPF_OBJ_GET_INFO_BY_FD, &mut attr);
+    // SAFETY: the fd was returned by the kernel above and isn't used after.
+    unsafe { libc::close(fd) };
+    result.map(|_| RunStats {
+        run_time_ns: info.run_time_ns,
+        run_count: info.run_cnt,
+    })
+}
+
+pub(crate) struct DispatcherSlot {
+    pub(crate) dispatcher: &'static str,
+    pub(crate) key: String,
+    pub(crate) slot: u32,
+    pub(crate) kind: DispatcherKind,
+    pub(crate) stats: SlotStats,
+}
+
+// Returns the counters of the dispatcher slots that ran a program at least
+// once. Dispatchers are told apart by their type and key, the interface index
+// prefixed with the network namespace id for interfaces in other namespaces.
+pub(crate) fn dispatcher_slots() -> Vec<DispatcherSlot> {
+    let dirs = [
+        (RTDIR_FS_XDP, "xdp", DispatcherKind::Xdp),
+        (RTDIR_FS_TC_INGRESS, "tc-ingress", DispatcherKind::Tc),
+        (RTDIR_FS_TC_EGRESS, "tc-egress", DispatcherKind::Tc),
+    ];
+    let mut slots = vec![];
+    for (dir, dispatcher, kind) in dirs {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(key) = name
+                .strip_prefix("dispatcher_")
+                .and_then(|n| n.strip_suffix("_stats"))
+            else {
+                continue;
+            };
+            for slot in 0..DISPATCHER_SLOTS {
+                match SlotStats::read(entry.path(), slot) {
+                    Ok(stats) if stats.packets > 0 => slots.push(DispatcherSlot {
+                        dispatcher,
+                        key: key.to_string(),
+                        slot,
+                        kind,
+                        stats,
+                    }),
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+    slots
+}
@@ -41,6 +41,33 @@ struct xdp_dispatcher_conf {
  */
 static volatile const struct xdp_dispatcher_conf conf = {};
 
+#define DISPATCHER_VERDICTS 32
+
+/* Packets the program in a slot was run on, and how often it returned each
+ * verdict, by the bit of the verdict in chain_call_actions. Read by bpfman
+ * from the pinned map, summed over all CPUs.
+ */
+struct slot_stats {
+  __u64 packets;
+  __u64 verdicts[DISPATCHER_VERDICTS];
+};
+
+struct {
+  __uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+  __uint(max_entries, MAX_DISPATCHER_ACTIONS);
+  __type(key, __u32);
+  __type(value, struct slot_stats);
+} dispatcher_stats SEC(".maps");
+
+static __always_inline void count_verdict(__u32 slot, int ret) {
+  struct slot_stats *stats = bpf_map_lookup_elem(&dispatcher_stats, &slot);
+
+  if (!stats)
+    return;
+  stats->packets++;
+  stats->verdicts[ret & (DISPATCHER_VERDICTS - 1)]++;
+}
+
 __attribute__((noinline)) int prog0(struct xdp_md *ctx) {
   volatile int ret = XDP_DISPATCHER_RETVAL;
 
@@ -137,60 +164,70 @@ int xdp_dispatcher(struct xdp_md *ctx) {
   if (num_progs_enabled < 1)
     goto out;
   ret = prog0(ctx);
+  count_verdict(0, ret);
   if (!((1U << ret) & conf.chain_call_actions[0]))
     return ret;
 
   if (num_progs_enabled < 2)
     goto out;
   ret = prog1(ctx);
+  count_verdict(1, ret);
   if (!((1U << ret) & conf.chain_call_actions[1]))
     return ret;
 
   if (num_progs_enabled < 3)
     goto out;
   ret = prog2(ctx);
+  count_verdict(2, ret);
   if (!((1U << ret) & conf.chain_call_actions[2]))
     return ret;
 
   if (num_progs_enabled < 4)
     goto out;
   ret = prog3(ctx);
+  count_verdict(3, ret);
   if (!((1U << ret) & conf.chain_call_actions[3]))
     return ret;
 
   if (num_progs_enabled < 5)
     goto out;
   ret = prog4(ctx);
+  count_verdict(4, ret);
   if (!((1U << ret) & conf.chain_call_actions[4]))
     return ret;
 
   if (num_progs_enabled < 6)
     goto out;
   ret = prog5(ctx);
+  count_verdict(5, ret);
   if (!((1U << ret) & conf.chain_call_actions[5]))
     return ret;
 
   if (num_progs_enabled < 7)
     goto out;
   ret = prog6(ctx);
+  count_verdict(6, ret);
   if (!((1U << ret) & conf.chain_call_actions[6]))
     return ret;
 
   if (num_progs_enabled < 8)
     goto out;
   ret = prog7(ctx);
+  count_verdict(7, ret);
   if (!((1U << ret) & conf.chain_call_actions[7]))
     return ret;
 
   if (num_progs_enabled < 9)
     goto out;
   ret = prog8(ctx);
+  count_verdict(8, ret);
   if (!((1U << ret) & conf.chain_call_actions[8]))
     return ret;
 
   if (num_progs_enabled < 10)
     goto out;
   ret = prog9(ctx);
+  count_verdict(9, ret);
   if (!((1U << ret) & conf.chain_call_actions[9]))
     return ret;
 
@@ -1,3 +1,5 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BytecodeImage {
@@ -13,14 +15,19 @@ pub struct BytecodeImage {
 /// BytecodeLocation is either:
 /// - Parameters to pull an eBPF program stored in an OCI container image.
 /// - Local file path for an image.
+/// - An HTTPS URL of a bytecode file along with its digest.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BytecodeLocation {
-    #[prost(oneof = "bytecode_location::Location", tags = "2, 3")]
+    #[prost(oneof = "bytecode_location::Location", tags = "2, 3, 4, 5")]
     pub location: ::core::option::Option<bytecode_location::Location>,
 }
 /// Nested message and enum types in `BytecodeLocation`.
 pub mod bytecode_location {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Location {
@@ -28,8 +35,30 @@ pub mod bytecode_location {
         Image(super::BytecodeImage),
         #[prost(string, tag = "3")]
         File(::prost::alloc::string::String),
+        /// Bytecode object uploaded with the request, for clients that can't
+        /// place files on the node. Objects larger than the message size limit
+        /// are uploaded with LoadUpload.
+        #[prost(bytes, tag = "4")]
+        Data(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "5")]
+        Url(super::BytecodeUrl),
     }
 }
+/// BytecodeUrl is a bytecode file fetched by bpfman over HTTPS. The sha256
+/// digest of the file, hex encoded, is required, as nothing else vouches for
+/// what the URL serves.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BytecodeUrl {
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub sha256: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KernelProgramInfo {
@@ -61,7 +90,14 @@ pub struct KernelProgramInfo {
     pub bytes_memlock: u32,
     #[prost(uint32, tag = "13")]
     pub verified_insns: u32,
+    /// Runs and total run time counted while BPF stats are enabled
+    #[prost(uint64, tag = "14")]
+    pub run_time_ns: u64,
+    #[prost(uint64, tag = "15")]
+    pub run_count: u64,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProgramInfo {
@@ -88,7 +124,28 @@ pub struct ProgramInfo {
         ::prost::alloc::string::String,
         ::prost::alloc::string::String,
     >,
+    #[prost(bool, tag = "9")]
+    pub detached: bool,
+    /// Attachments of an XDP or TC program to interfaces other than the one
+    /// it was loaded on.
+    #[prost(message, repeated, tag = "10")]
+    pub attachments: ::prost::alloc::vec::Vec<AttachInfo>,
+    #[prost(map = "string, uint32", tag = "11")]
+    pub map_max_entries: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        u32,
+    >,
+    /// UUID given by the client on load
+    #[prost(string, optional, tag = "12")]
+    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// User of the process that loaded the program over the Unix socket, as
+    /// reported by the kernel rather than claimed by the client. Unset for
+    /// programs loaded over other transports.
+    #[prost(string, optional, tag = "13")]
+    pub owner: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct XdpAttachInfo {
@@ -100,7 +157,37 @@ pub struct XdpAttachInfo {
     pub position: i32,
     #[prost(int32, repeated, tag = "4")]
     pub proceed_on: ::prost::alloc::vec::Vec<i32>,
+    #[prost(bool, tag = "5")]
+    pub track_iface_glob: bool,
+    #[prost(string, optional, tag = "6")]
+    pub netns_path: ::core::option::Option<::prost::alloc::string::String>,
+    /// Only set on Get and List, see DispatcherSlotStats.
+    #[prost(message, optional, tag = "7")]
+    pub slot_stats: ::core::option::Option<DispatcherSlotStats>,
+    /// Mode the dispatcher of the interface was attached in, "skb", "drv" or
+    /// "hw", which is "skb" if the configured mode wasn't supported and the
+    /// interface is configured to fall back. Only set on Get and List of
+    /// attached programs.
+    #[prost(string, optional, tag = "8")]
+    pub mode: ::core::option::Option<::prost::alloc::string::String>,
+    /// If the interface doesn't exist yet, load the program anyway and attach
+    /// it once the interface is created. The program is reported as detached
+    /// until then.
+    #[prost(bool, tag = "9")]
+    pub wait_for_iface: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DispatcherSlotStats {
+    #[prost(uint64, tag = "1")]
+    pub packets: u64,
+    #[prost(map = "string, uint64", tag = "2")]
+    pub verdicts: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TcAttachInfo {
@@ -114,13 +201,33 @@ pub struct TcAttachInfo {
     pub direction: ::prost::alloc::string::String,
     #[prost(int32, repeated, tag = "5")]
     pub proceed_on: ::prost::alloc::vec::Vec<i32>,
+    /// Attach with a tcx link instead of the tc dispatcher. Defaults to the
+    /// daemon configuration when unset.
+    #[prost(bool, optional, tag = "6")]
+    pub tcx: ::core::option::Option<bool>,
+    #[prost(bool, tag = "7")]
+    pub track_iface_glob: bool,
+    /// If the interface doesn't exist yet, load the program anyway and attach
+    /// it once the interface is created. The program is reported as detached
+    /// until then. Not supported with direction "both".
+    #[prost(bool, tag = "8")]
+    pub wait_for_iface: bool,
+    #[prost(string, optional, tag = "9")]
+    pub netns_path: ::core::option::Option<::prost::alloc::string::String>,
+    /// Only set on Get and List, see DispatcherSlotStats.
+    #[prost(message, optional, tag = "10")]
+    pub slot_stats: ::core::option::Option<DispatcherSlotStats>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TracepointAttachInfo {
     #[prost(string, tag = "1")]
     pub tracepoint: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KprobeAttachInfo {
@@ -133,6 +240,8 @@ pub struct KprobeAttachInfo {
     #[prost(int32, optional, tag = "4")]
     pub container_pid: ::core::option::Option<i32>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UprobeAttachInfo {
@@ -148,15 +257,235 @@ pub struct UprobeAttachInfo {
     pub pid: ::core::option::Option<i32>,
     #[prost(int32, optional, tag = "6")]
     pub container_pid: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "7")]
+    pub container_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LsmAttachInfo {
+    #[prost(string, tag = "1")]
+    pub hook: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CgroupSkbAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub direction: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CgroupSockAddrAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub attach_point: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SockOpsAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CgroupDeviceAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CgroupSysctlAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CgroupSockoptAttachInfo {
+    #[prost(string, tag = "1")]
+    pub cgroup_path: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub attach_type: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub attach_flags: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SkMsgAttachInfo {
+    #[prost(string, tag = "1")]
+    pub map_name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub sock_map_pin_path: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SkSkbAttachInfo {
+    #[prost(string, tag = "1")]
+    pub map_name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub sock_map_pin_path: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub attach_type: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlowDissectorAttachInfo {
+    #[prost(string, tag = "1")]
+    pub netns_path: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SkLookupAttachInfo {
+    #[prost(string, tag = "1")]
+    pub netns_path: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetkitAttachInfo {
+    #[prost(string, tag = "1")]
+    pub iface: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub position: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub priority: i32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PerfEventAttachInfo {
+    #[prost(string, tag = "1")]
+    pub event: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "2")]
+    pub sample_period: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub sample_frequency: ::core::option::Option<u64>,
+    #[prost(uint32, repeated, tag = "4")]
+    pub cpus: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawTracepointAttachInfo {
+    #[prost(string, tag = "1")]
+    pub tracepoint: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub btf: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SocketFilterAttachInfo {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdtAttachInfo {
+    #[prost(string, tag = "1")]
+    pub target: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub provider: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "4")]
+    pub pid: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KprobeMultiAttachInfo {
+    #[prost(string, repeated, tag = "1")]
+    pub fn_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "2")]
+    pub retprobe: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UprobeMultiAttachInfo {
+    #[prost(string, tag = "1")]
+    pub target: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub fn_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "3")]
+    pub retprobe: bool,
+    #[prost(int32, optional, tag = "4")]
+    pub pid: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IterAttachInfo {
+    #[prost(string, tag = "1")]
+    pub iter_type: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtensionAttachInfo {
+    #[prost(string, optional, tag = "1")]
+    pub target_path: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "2")]
+    pub target_id: ::core::option::Option<u32>,
+    #[prost(string, tag = "3")]
+    pub target_fn: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AttachInfo {
-    #[prost(oneof = "attach_info::Info", tags = "2, 3, 4, 5, 6")]
+    #[prost(
+        oneof = "attach_info::Info",
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 15, 16, 17, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28"
+    )]
     pub info: ::core::option::Option<attach_info::Info>,
 }
 /// Nested message and enum types in `AttachInfo`.
 pub mod attach_info {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Info {
@@ -170,8 +499,107 @@ pub mod attach_info {
         KprobeAttachInfo(super::KprobeAttachInfo),
         #[prost(message, tag = "6")]
         UprobeAttachInfo(super::UprobeAttachInfo),
+        #[prost(message, tag = "7")]
+        LsmAttachInfo(super::LsmAttachInfo),
+        #[prost(message, tag = "8")]
+        CgroupSkbAttachInfo(super::CgroupSkbAttachInfo),
+        #[prost(message, tag = "9")]
+        CgroupSockAddrAttachInfo(super::CgroupSockAddrAttachInfo),
+        #[prost(message, tag = "10")]
+        SockOpsAttachInfo(super::SockOpsAttachInfo),
+        #[prost(message, tag = "11")]
+        SkMsgAttachInfo(super::SkMsgAttachInfo),
+        #[prost(message, tag = "12")]
+        SkSkbAttachInfo(super::SkSkbAttachInfo),
+        #[prost(message, tag = "13")]
+        FlowDissectorAttachInfo(super::FlowDissectorAttachInfo),
+        #[prost(message, tag = "15")]
+        PerfEventAttachInfo(super::PerfEventAttachInfo),
+        #[prost(message, tag = "16")]
+        RawTracepointAttachInfo(super::RawTracepointAttachInfo),
+        #[prost(message, tag = "17")]
+        SocketFilterAttachInfo(super::SocketFilterAttachInfo),
+        #[prost(message, tag = "19")]
+        UsdtAttachInfo(super::UsdtAttachInfo),
+        #[prost(message, tag = "20")]
+        KprobeMultiAttachInfo(super::KprobeMultiAttachInfo),
+        #[prost(message, tag = "21")]
+        UprobeMultiAttachInfo(super::UprobeMultiAttachInfo),
+        #[prost(message, tag = "22")]
+        IterAttachInfo(super::IterAttachInfo),
+        #[prost(message, tag = "23")]
+        ExtensionAttachInfo(super::ExtensionAttachInfo),
+        #[prost(message, tag = "24")]
+        CgroupDeviceAttachInfo(super::CgroupDeviceAttachInfo),
+        #[prost(message, tag = "25")]
+        CgroupSysctlAttachInfo(super::CgroupSysctlAttachInfo),
+        #[prost(message, tag = "26")]
+        CgroupSockoptAttachInfo(super::CgroupSockoptAttachInfo),
+        #[prost(message, tag = "27")]
+        SkLookupAttachInfo(super::SkLookupAttachInfo),
+        #[prost(message, tag = "28")]
+        NetkitAttachInfo(super::NetkitAttachInfo),
     }
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapPinPolicy {
+    #[prost(string, repeated, tag = "1")]
+    pub include: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "2")]
+    pub exclude: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(map = "string, string", tag = "3")]
+    pub pin_names: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapInit {
+    #[prost(string, tag = "1")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub entries: ::prost::alloc::vec::Vec<MapEntry>,
+    #[prost(bool, tag = "3")]
+    pub freeze: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InnerMaps {
+    #[prost(string, tag = "1")]
+    pub outer: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub map_type: u32,
+    #[prost(uint32, tag = "3")]
+    pub key_size: u32,
+    #[prost(uint32, tag = "4")]
+    pub value_size: u32,
+    #[prost(uint32, tag = "5")]
+    pub max_entries: u32,
+    #[prost(bytes = "vec", repeated, tag = "6")]
+    pub keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapPermissions {
+    #[prost(string, optional, tag = "1")]
+    pub user: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub group: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub mode: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoadRequest {
@@ -197,7 +625,42 @@ pub struct LoadRequest {
     pub uuid: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(uint32, optional, tag = "8")]
     pub map_owner_id: ::core::option::Option<u32>,
+    #[prost(bool, tag = "9")]
+    pub detached: bool,
+    #[prost(string, repeated, tag = "10")]
+    pub additional_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "11")]
+    pub map_from: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, uint32", tag = "12")]
+    pub map_max_entries: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        u32,
+    >,
+    #[prost(message, optional, tag = "13")]
+    pub map_pinning: ::core::option::Option<MapPinPolicy>,
+    #[prost(message, repeated, tag = "14")]
+    pub map_init: ::prost::alloc::vec::Vec<MapInit>,
+    #[prost(message, repeated, tag = "15")]
+    pub inner_maps: ::prost::alloc::vec::Vec<InnerMaps>,
+    #[prost(message, optional, tag = "16")]
+    pub map_permissions: ::core::option::Option<MapPermissions>,
+    #[prost(uint32, optional, tag = "17")]
+    pub verifier_log_level: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "18")]
+    pub verifier_log_size: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadUploadRequest {
+    #[prost(message, optional, tag = "1")]
+    pub load: ::core::option::Option<LoadRequest>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub chunk: ::prost::alloc::vec::Vec<u8>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoadResponse {
@@ -205,16 +668,199 @@ pub struct LoadResponse {
     pub info: ::core::option::Option<ProgramInfo>,
     #[prost(message, optional, tag = "2")]
     pub kernel_info: ::core::option::Option<KernelProgramInfo>,
+    #[prost(message, repeated, tag = "3")]
+    pub additional_programs: ::prost::alloc::vec::Vec<LoadResponse>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadBatchRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<LoadRequest>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoadBatchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub programs: ::prost::alloc::vec::Vec<LoadResponse>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnloadRequest {
     #[prost(uint32, tag = "1")]
     pub id: u32,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnloadResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub attach: ::core::option::Option<AttachInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, optional, tag = "2")]
+    pub iface: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub direction: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPriorityRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(int32, tag = "2")]
+    pub priority: i32,
+    #[prost(string, optional, tag = "3")]
+    pub iface: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub direction: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPriorityResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublishMapsRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublishMapsResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnpublishMapsRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnpublishMapsResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportMapsRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, repeated, tag = "2")]
+    pub maps: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub mount: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportMapsResponse {
+    #[prost(string, repeated, tag = "1")]
+    pub maps: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnexportMapsRequest {
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnexportMapsResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReplaceRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub bytecode: ::core::option::Option<BytecodeLocation>,
+    #[prost(string, optional, tag = "3")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(map = "string, bytes", tag = "4")]
+    pub global_data: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::vec::Vec<u8>,
+    >,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReplaceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<ProgramInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollbackRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollbackResponse {
+    #[prost(message, optional, tag = "1")]
+    pub info: ::core::option::Option<ProgramInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub kernel_info: ::core::option::Option<KernelProgramInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListRequest {
@@ -227,15 +873,32 @@ pub struct ListRequest {
         ::prost::alloc::string::String,
         ::prost::alloc::string::String,
     >,
+    #[prost(string, optional, tag = "4")]
+    pub iface: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "5")]
+    pub map_owner_id: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "6")]
+    pub page_size: u32,
+    #[prost(string, tag = "7")]
+    pub page_token: ::prost::alloc::string::String,
+    #[prost(bool, optional, tag = "8")]
+    pub foreign_programs_only: ::core::option::Option<bool>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListResponse {
     #[prost(message, repeated, tag = "3")]
     pub results: ::prost::alloc::vec::Vec<list_response::ListResult>,
+    /// Empty if there are no more programs to list
+    #[prost(string, tag = "4")]
+    pub next_page_token: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `ListResponse`.
 pub mod list_response {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ListResult {
@@ -243,23 +906,101 @@ pub mod list_response {
         pub info: ::core::option::Option<super::ProgramInfo>,
         #[prost(message, optional, tag = "2")]
         pub kernel_info: ::core::option::Option<super::KernelProgramInfo>,
+        /// Whether the program was loaded by bpfman, info is only set if so
+        #[prost(bool, tag = "3")]
+        pub bpfman_managed: bool,
     }
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PullBytecodeRequest {
     #[prost(message, optional, tag = "1")]
     pub image: ::core::option::Option<BytecodeImage>,
 }
+/// PullBytecodeResponse describes the pulled image as it's in the image
+/// store.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PullBytecodeResponse {}
+pub struct PullBytecodeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub image: ::core::option::Option<ImageInfo>,
+}
+/// PruneImagesRequest represents a request to remove the bytecode images no
+/// loaded program uses from the image store. The dispatcher images are kept.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetRequest {
-    #[prost(uint32, tag = "1")]
-    pub id: u32,
-}
+pub struct PruneImagesRequest {}
+/// PruneImagesResponse lists the URLs of the removed images.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PruneImagesResponse {
+    #[prost(string, repeated, tag = "1")]
+    pub images: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// ListImagesRequest represents a request to list the bytecode images in the
+/// image store. If url is set only the image pulled from url is listed.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListImagesRequest {
+    #[prost(string, optional, tag = "1")]
+    pub url: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// ImageInfo describes an image in the image store. digest is the digest of
+/// its manifest, empty for images pulled by older versions of bpfman, and
+/// labels are the labels of its config, which hold the metadata of the
+/// bytecode. last_used is the time it was last pulled or loaded, in seconds
+/// since the epoch, and program_ids are the loaded programs using it.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImageInfo {
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub digest: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub size: u64,
+    #[prost(map = "string, string", tag = "4")]
+    pub labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(uint64, tag = "5")]
+    pub last_used: u64,
+    #[prost(uint32, repeated, tag = "6")]
+    pub program_ids: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListImagesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub images: ::prost::alloc::vec::Vec<ImageInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, optional, tag = "2")]
+    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetResponse {
@@ -268,6 +1009,378 @@ pub struct GetResponse {
     #[prost(message, optional, tag = "2")]
     pub kernel_info: ::core::option::Option<KernelProgramInfo>,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VersionRequest {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VersionResponse {
+    #[prost(string, tag = "1")]
+    pub version: ::prost::alloc::string::String,
+    /// Architecture and profile of the build, like "x86_64 release"
+    #[prost(string, tag = "2")]
+    pub build: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub kernel_release: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "4")]
+    pub program_types: ::prost::alloc::vec::Vec<u32>,
+    #[prost(string, repeated, tag = "5")]
+    pub kernel_features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "6")]
+    pub xdp_dispatcher_image: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub tc_dispatcher_image: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMapsRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapInfo {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub id: u32,
+    #[prost(uint32, tag = "3")]
+    pub map_type: u32,
+    #[prost(uint32, tag = "4")]
+    pub key_size: u32,
+    #[prost(uint32, tag = "5")]
+    pub value_size: u32,
+    #[prost(uint32, tag = "6")]
+    pub max_entries: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMapsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub maps: ::prost::alloc::vec::Vec<MapInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKernelLinksRequest {
+    #[prost(uint32, optional, tag = "1")]
+    pub program_id: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KernelLinkInfo {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(uint32, tag = "2")]
+    pub link_type: u32,
+    #[prost(uint32, tag = "3")]
+    pub program_id: u32,
+    /// Whether the program of the link was loaded by bpfman
+    #[prost(bool, tag = "4")]
+    pub bpfman_managed: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKernelLinksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub links: ::prost::alloc::vec::Vec<KernelLinkInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKernelMapsRequest {
+    #[prost(uint32, optional, tag = "1")]
+    pub program_id: ::core::option::Option<u32>,
+    #[prost(bool, tag = "2")]
+    pub foreign_access_only: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KernelMapInfo {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub map_type: u32,
+    #[prost(uint32, tag = "4")]
+    pub key_size: u32,
+    #[prost(uint32, tag = "5")]
+    pub value_size: u32,
+    #[prost(uint32, tag = "6")]
+    pub max_entries: u32,
+    /// Programs using the map, and those of them loaded by bpfman
+    #[prost(uint32, repeated, tag = "7")]
+    pub program_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "8")]
+    pub bpfman_program_ids: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListKernelMapsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub maps: ::prost::alloc::vec::Vec<KernelMapInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProbeFeaturesRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub helper_program_types: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgramHelpers {
+    #[prost(uint32, tag = "1")]
+    pub program_type: u32,
+    #[prost(uint32, repeated, tag = "2")]
+    pub helper_ids: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProbeFeaturesResponse {
+    #[prost(string, tag = "1")]
+    pub kernel_release: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "2")]
+    pub program_types: ::prost::alloc::vec::Vec<u32>,
+    #[prost(string, repeated, tag = "3")]
+    pub map_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "4")]
+    pub features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "5")]
+    pub helpers: ::prost::alloc::vec::Vec<ProgramHelpers>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapEntry {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, optional, tag = "3")]
+    pub key_json: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub value_json: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DumpMapRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub json: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DumpMapResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<MapEntry>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupMapEntryRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag = "4")]
+    pub json: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupMapEntryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<MapEntry>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMapEntryRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub flags: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMapEntryResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteMapEntryRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteMapEntryResponse {}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchRingBufferRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "3")]
+    pub queue_size: ::core::option::Option<u32>,
+    #[prost(int32, optional, tag = "4")]
+    pub drop_policy: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchRingBufferResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub dropped: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPerfBufferRequest {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub map: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "3")]
+    pub page_count: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "4")]
+    pub queue_size: ::core::option::Option<u32>,
+    #[prost(int32, optional, tag = "5")]
+    pub drop_policy: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPerfBufferResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub cpu: u32,
+    #[prost(uint64, tag = "3")]
+    pub lost: u64,
+    #[prost(uint64, tag = "4")]
+    pub dropped: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEventsRequest {
+    #[prost(uint64, optional, tag = "1")]
+    pub since: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "2")]
+    pub until: ::core::option::Option<u64>,
+    #[prost(string, optional, tag = "3")]
+    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "4")]
+    pub limit: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+    #[prost(string, tag = "2")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "3")]
+    pub program_id: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "4")]
+    pub uuid: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "6")]
+    pub details: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "7")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEventsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+}
 /// Generated client implementations.
 pub mod bpfman_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -361,6 +1474,53 @@ pub mod bpfman_client {
             req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Load"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn load_upload(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::LoadUploadRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/LoadUpload",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "LoadUpload"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn load_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoadBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoadBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/LoadBatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "LoadBatch"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn unload(
             &mut self,
             request: impl tonic::IntoRequest<super::UnloadRequest>,
@@ -424,6 +1584,56 @@ pub mod bpfman_client {
                 .insert(GrpcMethod::new("bpfman.v1.Bpfman", "PullBytecode"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn prune_images(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PruneImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PruneImagesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/PruneImages",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "PruneImages"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_images(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListImagesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ListImages",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ListImages"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get(
             &mut self,
             request: impl tonic::IntoRequest<super::GetRequest>,
@@ -443,135 +1653,1783 @@ pub mod bpfman_client {
             req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Get"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod bpfman_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with BpfmanServer.
-    #[async_trait]
-    pub trait Bpfman: Send + Sync + 'static {
-        async fn load(
-            &self,
-            request: tonic::Request<super::LoadRequest>,
-        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status>;
-        async fn unload(
-            &self,
-            request: tonic::Request<super::UnloadRequest>,
-        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status>;
-        async fn list(
-            &self,
-            request: tonic::Request<super::ListRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status>;
-        async fn pull_bytecode(
-            &self,
-            request: tonic::Request<super::PullBytecodeRequest>,
+        pub async fn version(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VersionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PullBytecodeResponse>,
+            tonic::Response<super::VersionResponse>,
             tonic::Status,
-        >;
-        async fn get(
-            &self,
-            request: tonic::Request<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
-    }
-    #[derive(Debug)]
-    pub struct BpfmanServer<T: Bpfman> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: Bpfman> BpfmanServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Version");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Version"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        pub async fn read(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReadResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Read");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Read"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        pub async fn attach(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Attach");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Attach"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        pub async fn detach(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetachRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Detach");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Detach"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+        pub async fn set_priority(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPriorityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetPriorityResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/SetPriority",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "SetPriority"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        pub async fn publish_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PublishMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PublishMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/PublishMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "PublishMaps"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        pub async fn unpublish_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnpublishMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnpublishMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/UnpublishMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "UnpublishMaps"));
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for BpfmanServer<T>
-    where
-        T: Bpfman,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        pub async fn replace(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::ReplaceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReplaceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/Replace");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Replace"));
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/bpfman.v1.Bpfman/Load" => {
+        pub async fn rollback(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RollbackRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollbackResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/Rollback",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "Rollback"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ListMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "ListMaps"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn dump_map(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DumpMapRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DumpMapResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/bpfman.v1.Bpfman/DumpMap");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("bpfman.v1.Bpfman", "DumpMap"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn lookup_map_entry(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LookupMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupMapEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/LookupMapEntry",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "LookupMapEntry"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_map_entry(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateMapEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/UpdateMapEntry",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "UpdateMapEntry"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_map_entry(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteMapEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/DeleteMapEntry",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "DeleteMapEntry"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn watch_ring_buffer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchRingBufferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchRingBufferResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/WatchRingBuffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "WatchRingBuffer"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn watch_perf_buffer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchPerfBufferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchPerfBufferResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/WatchPerfBuffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "WatchPerfBuffer"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn export_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ExportMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ExportMaps"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn unexport_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnexportMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnexportMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/UnexportMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "UnexportMaps"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEventsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/GetEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "GetEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_kernel_links(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListKernelLinksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKernelLinksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ListKernelLinks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ListKernelLinks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_kernel_maps(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListKernelMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKernelMapsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ListKernelMaps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ListKernelMaps"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn probe_features(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProbeFeaturesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProbeFeaturesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/bpfman.v1.Bpfman/ProbeFeatures",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("bpfman.v1.Bpfman", "ProbeFeatures"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod bpfman_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BpfmanServer.
+    #[async_trait]
+    pub trait Bpfman: Send + Sync + 'static {
+        async fn load(
+            &self,
+            request: tonic::Request<super::LoadRequest>,
+        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status>;
+        async fn load_upload(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::LoadUploadRequest>>,
+        ) -> std::result::Result<tonic::Response<super::LoadResponse>, tonic::Status>;
+        async fn load_batch(
+            &self,
+            request: tonic::Request<super::LoadBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoadBatchResponse>,
+            tonic::Status,
+        >;
+        async fn unload(
+            &self,
+            request: tonic::Request<super::UnloadRequest>,
+        ) -> std::result::Result<tonic::Response<super::UnloadResponse>, tonic::Status>;
+        async fn list(
+            &self,
+            request: tonic::Request<super::ListRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResponse>, tonic::Status>;
+        async fn pull_bytecode(
+            &self,
+            request: tonic::Request<super::PullBytecodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PullBytecodeResponse>,
+            tonic::Status,
+        >;
+        async fn prune_images(
+            &self,
+            request: tonic::Request<super::PruneImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PruneImagesResponse>,
+            tonic::Status,
+        >;
+        async fn list_images(
+            &self,
+            request: tonic::Request<super::ListImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListImagesResponse>,
+            tonic::Status,
+        >;
+        async fn get(
+            &self,
+            request: tonic::Request<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
+        async fn version(
+            &self,
+            request: tonic::Request<super::VersionRequest>,
+        ) -> std::result::Result<tonic::Response<super::VersionResponse>, tonic::Status>;
+        /// Server streaming response type for the Read method.
+        type ReadStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReadResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn read(
+            &self,
+            request: tonic::Request<super::ReadRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ReadStream>, tonic::Status>;
+        async fn attach(
+            &self,
+            request: tonic::Request<super::AttachRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachResponse>, tonic::Status>;
+        async fn detach(
+            &self,
+            request: tonic::Request<super::DetachRequest>,
+        ) -> std::result::Result<tonic::Response<super::DetachResponse>, tonic::Status>;
+        async fn set_priority(
+            &self,
+            request: tonic::Request<super::SetPriorityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetPriorityResponse>,
+            tonic::Status,
+        >;
+        async fn publish_maps(
+            &self,
+            request: tonic::Request<super::PublishMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PublishMapsResponse>,
+            tonic::Status,
+        >;
+        async fn unpublish_maps(
+            &self,
+            request: tonic::Request<super::UnpublishMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnpublishMapsResponse>,
+            tonic::Status,
+        >;
+        async fn replace(
+            &self,
+            request: tonic::Request<super::ReplaceRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReplaceResponse>, tonic::Status>;
+        async fn rollback(
+            &self,
+            request: tonic::Request<super::RollbackRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollbackResponse>,
+            tonic::Status,
+        >;
+        async fn list_maps(
+            &self,
+            request: tonic::Request<super::ListMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMapsResponse>,
+            tonic::Status,
+        >;
+        async fn dump_map(
+            &self,
+            request: tonic::Request<super::DumpMapRequest>,
+        ) -> std::result::Result<tonic::Response<super::DumpMapResponse>, tonic::Status>;
+        async fn lookup_map_entry(
+            &self,
+            request: tonic::Request<super::LookupMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupMapEntryResponse>,
+            tonic::Status,
+        >;
+        async fn update_map_entry(
+            &self,
+            request: tonic::Request<super::UpdateMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateMapEntryResponse>,
+            tonic::Status,
+        >;
+        async fn delete_map_entry(
+            &self,
+            request: tonic::Request<super::DeleteMapEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteMapEntryResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchRingBuffer method.
+        type WatchRingBufferStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchRingBufferResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn watch_ring_buffer(
+            &self,
+            request: tonic::Request<super::WatchRingBufferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchRingBufferStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchPerfBuffer method.
+        type WatchPerfBufferStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchPerfBufferResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn watch_perf_buffer(
+            &self,
+            request: tonic::Request<super::WatchPerfBufferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchPerfBufferStream>,
+            tonic::Status,
+        >;
+        async fn export_maps(
+            &self,
+            request: tonic::Request<super::ExportMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportMapsResponse>,
+            tonic::Status,
+        >;
+        async fn unexport_maps(
+            &self,
+            request: tonic::Request<super::UnexportMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnexportMapsResponse>,
+            tonic::Status,
+        >;
+        async fn get_events(
+            &self,
+            request: tonic::Request<super::GetEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEventsResponse>,
+            tonic::Status,
+        >;
+        async fn list_kernel_links(
+            &self,
+            request: tonic::Request<super::ListKernelLinksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKernelLinksResponse>,
+            tonic::Status,
+        >;
+        async fn list_kernel_maps(
+            &self,
+            request: tonic::Request<super::ListKernelMapsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListKernelMapsResponse>,
+            tonic::Status,
+        >;
+        async fn probe_features(
+            &self,
+            request: tonic::Request<super::ProbeFeaturesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProbeFeaturesResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct BpfmanServer<T: Bpfman> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: Bpfman> BpfmanServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for BpfmanServer<T>
+    where
+        T: Bpfman,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/bpfman.v1.Bpfman/Load" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::LoadRequest>
+                    for LoadSvc<T> {
+                        type Response = super::LoadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LoadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::load(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LoadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/LoadUpload" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoadUploadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::ClientStreamingService<super::LoadUploadRequest>
+                    for LoadUploadSvc<T> {
+                        type Response = super::LoadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::LoadUploadRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::load_upload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LoadUploadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/LoadBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoadBatchSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::LoadBatchRequest>
+                    for LoadBatchSvc<T> {
+                        type Response = super::LoadBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LoadBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::load_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LoadBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Unload" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnloadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::UnloadRequest>
+                    for UnloadSvc<T> {
+                        type Response = super::UnloadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnloadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::unload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/List" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ListRequest>
+                    for ListSvc<T> {
+                        type Response = super::ListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/PullBytecode" => {
+                    #[allow(non_camel_case_types)]
+                    struct PullBytecodeSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::PullBytecodeRequest>
+                    for PullBytecodeSvc<T> {
+                        type Response = super::PullBytecodeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PullBytecodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::pull_bytecode(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PullBytecodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/PruneImages" => {
+                    #[allow(non_camel_case_types)]
+                    struct PruneImagesSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::PruneImagesRequest>
+                    for PruneImagesSvc<T> {
+                        type Response = super::PruneImagesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PruneImagesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::prune_images(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PruneImagesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ListImages" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListImagesSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ListImagesRequest>
+                    for ListImagesSvc<T> {
+                        type Response = super::ListImagesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListImagesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::list_images(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListImagesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Get" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::GetRequest>
+                    for GetSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Version" => {
+                    #[allow(non_camel_case_types)]
+                    struct VersionSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::VersionRequest>
+                    for VersionSvc<T> {
+                        type Response = super::VersionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VersionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::version(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VersionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Read" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::ServerStreamingService<super::ReadRequest>
+                    for ReadSvc<T> {
+                        type Response = super::ReadResponse;
+                        type ResponseStream = T::ReadStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::read(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ReadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Attach" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::AttachRequest>
+                    for AttachSvc<T> {
+                        type Response = super::AttachResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::attach(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AttachSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Detach" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetachSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::DetachRequest>
+                    for DetachSvc<T> {
+                        type Response = super::DetachResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DetachRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::detach(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetachSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/SetPriority" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetPrioritySvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::SetPriorityRequest>
+                    for SetPrioritySvc<T> {
+                        type Response = super::SetPriorityResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetPriorityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::set_priority(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetPrioritySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/PublishMaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct PublishMapsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::PublishMapsRequest>
+                    for PublishMapsSvc<T> {
+                        type Response = super::PublishMapsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PublishMapsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::publish_maps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PublishMapsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/UnpublishMaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnpublishMapsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::UnpublishMapsRequest>
+                    for UnpublishMapsSvc<T> {
+                        type Response = super::UnpublishMapsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnpublishMapsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::unpublish_maps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnpublishMapsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Replace" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReplaceSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ReplaceRequest>
+                    for ReplaceSvc<T> {
+                        type Response = super::ReplaceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReplaceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::replace(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ReplaceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/Rollback" => {
+                    #[allow(non_camel_case_types)]
+                    struct RollbackSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::RollbackRequest>
+                    for RollbackSvc<T> {
+                        type Response = super::RollbackResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RollbackRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::rollback(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RollbackSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ListMaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMapsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ListMapsRequest>
+                    for ListMapsSvc<T> {
+                        type Response = super::ListMapsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMapsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::list_maps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMapsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/DumpMap" => {
+                    #[allow(non_camel_case_types)]
+                    struct DumpMapSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::DumpMapRequest>
+                    for DumpMapSvc<T> {
+                        type Response = super::DumpMapResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DumpMapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::dump_map(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DumpMapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/LookupMapEntry" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupMapEntrySvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::LookupMapEntryRequest>
+                    for LookupMapEntrySvc<T> {
+                        type Response = super::LookupMapEntryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupMapEntryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::lookup_map_entry(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LookupMapEntrySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/UpdateMapEntry" => {
                     #[allow(non_camel_case_types)]
-                    struct LoadSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::LoadRequest>
-                    for LoadSvc<T> {
-                        type Response = super::LoadResponse;
+                    struct UpdateMapEntrySvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::UpdateMapEntryRequest>
+                    for UpdateMapEntrySvc<T> {
+                        type Response = super::UpdateMapEntryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::LoadRequest>,
+                            request: tonic::Request<super::UpdateMapEntryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Bpfman>::load(&inner, request).await
+                                <T as Bpfman>::update_map_entry(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -583,7 +3441,7 @@ pub mod bpfman_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = LoadSvc(inner);
+                        let method = UpdateMapEntrySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -599,23 +3457,25 @@ pub mod bpfman_server {
                     };
                     Box::pin(fut)
                 }
-                "/bpfman.v1.Bpfman/Unload" => {
+                "/bpfman.v1.Bpfman/DeleteMapEntry" => {
                     #[allow(non_camel_case_types)]
-                    struct UnloadSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::UnloadRequest>
-                    for UnloadSvc<T> {
-                        type Response = super::UnloadResponse;
+                    struct DeleteMapEntrySvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::DeleteMapEntryRequest>
+                    for DeleteMapEntrySvc<T> {
+                        type Response = super::DeleteMapEntryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UnloadRequest>,
+                            request: tonic::Request<super::DeleteMapEntryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Bpfman>::unload(&inner, request).await
+                                <T as Bpfman>::delete_map_entry(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -627,7 +3487,7 @@ pub mod bpfman_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UnloadSvc(inner);
+                        let method = DeleteMapEntrySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -643,23 +3503,119 @@ pub mod bpfman_server {
                     };
                     Box::pin(fut)
                 }
-                "/bpfman.v1.Bpfman/List" => {
+                "/bpfman.v1.Bpfman/WatchRingBuffer" => {
                     #[allow(non_camel_case_types)]
-                    struct ListSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::ListRequest>
-                    for ListSvc<T> {
-                        type Response = super::ListResponse;
+                    struct WatchRingBufferSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchRingBufferRequest,
+                    > for WatchRingBufferSvc<T> {
+                        type Response = super::WatchRingBufferResponse;
+                        type ResponseStream = T::WatchRingBufferStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchRingBufferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::watch_ring_buffer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WatchRingBufferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/WatchPerfBuffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchPerfBufferSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchPerfBufferRequest,
+                    > for WatchPerfBufferSvc<T> {
+                        type Response = super::WatchPerfBufferResponse;
+                        type ResponseStream = T::WatchPerfBufferStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchPerfBufferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::watch_perf_buffer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WatchPerfBufferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ExportMaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportMapsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::ExportMapsRequest>
+                    for ExportMapsSvc<T> {
+                        type Response = super::ExportMapsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListRequest>,
+                            request: tonic::Request<super::ExportMapsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Bpfman>::list(&inner, request).await
+                                <T as Bpfman>::export_maps(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -671,7 +3627,7 @@ pub mod bpfman_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListSvc(inner);
+                        let method = ExportMapsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -687,25 +3643,25 @@ pub mod bpfman_server {
                     };
                     Box::pin(fut)
                 }
-                "/bpfman.v1.Bpfman/PullBytecode" => {
+                "/bpfman.v1.Bpfman/UnexportMaps" => {
                     #[allow(non_camel_case_types)]
-                    struct PullBytecodeSvc<T: Bpfman>(pub Arc<T>);
+                    struct UnexportMapsSvc<T: Bpfman>(pub Arc<T>);
                     impl<
                         T: Bpfman,
-                    > tonic::server::UnaryService<super::PullBytecodeRequest>
-                    for PullBytecodeSvc<T> {
-                        type Response = super::PullBytecodeResponse;
+                    > tonic::server::UnaryService<super::UnexportMapsRequest>
+                    for UnexportMapsSvc<T> {
+                        type Response = super::UnexportMapsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PullBytecodeRequest>,
+                            request: tonic::Request<super::UnexportMapsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Bpfman>::pull_bytecode(&inner, request).await
+                                <T as Bpfman>::unexport_maps(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -717,7 +3673,7 @@ pub mod bpfman_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = PullBytecodeSvc(inner);
+                        let method = UnexportMapsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -733,23 +3689,23 @@ pub mod bpfman_server {
                     };
                     Box::pin(fut)
                 }
-                "/bpfman.v1.Bpfman/Get" => {
+                "/bpfman.v1.Bpfman/GetEvents" => {
                     #[allow(non_camel_case_types)]
-                    struct GetSvc<T: Bpfman>(pub Arc<T>);
-                    impl<T: Bpfman> tonic::server::UnaryService<super::GetRequest>
-                    for GetSvc<T> {
-                        type Response = super::GetResponse;
+                    struct GetEventsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<T: Bpfman> tonic::server::UnaryService<super::GetEventsRequest>
+                    for GetEventsSvc<T> {
+                        type Response = super::GetEventsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetRequest>,
+                            request: tonic::Request<super::GetEventsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Bpfman>::get(&inner, request).await
+                                <T as Bpfman>::get_events(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -761,7 +3717,145 @@ pub mod bpfman_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetSvc(inner);
+                        let method = GetEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ListKernelLinks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListKernelLinksSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::ListKernelLinksRequest>
+                    for ListKernelLinksSvc<T> {
+                        type Response = super::ListKernelLinksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListKernelLinksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::list_kernel_links(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListKernelLinksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ListKernelMaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListKernelMapsSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::ListKernelMapsRequest>
+                    for ListKernelMapsSvc<T> {
+                        type Response = super::ListKernelMapsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListKernelMapsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::list_kernel_maps(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListKernelMapsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bpfman.v1.Bpfman/ProbeFeatures" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProbeFeaturesSvc<T: Bpfman>(pub Arc<T>);
+                    impl<
+                        T: Bpfman,
+                    > tonic::server::UnaryService<super::ProbeFeaturesRequest>
+                    for ProbeFeaturesSvc<T> {
+                        type Response = super::ProbeFeaturesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProbeFeaturesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Bpfman>::probe_features(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ProbeFeaturesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
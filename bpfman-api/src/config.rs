@@ -7,13 +7,59 @@ use aya::programs::XdpFlags;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::ParseError;
+use crate::{util::directories::RTPATH_BPFMAN_SOCKET, DropPolicy, ImagePullPolicy, ParseError};
 
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct Config {
     pub interfaces: Option<HashMap<String, InterfaceConfig>>,
     #[serde(default)]
     pub signing: Option<SigningConfig>,
+    #[serde(default)]
+    pub tc: Option<TcConfig>,
+    #[serde(default)]
+    pub dispatcher_images: DispatcherImages,
+    #[serde(default)]
+    pub ring_buffer: Option<RingBufferConfig>,
+    #[serde(default)]
+    pub map_pinning: Option<MapPinningConfig>,
+    #[serde(default)]
+    pub registries: Option<RegistriesConfig>,
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    #[serde(default)]
+    pub image_cache: Option<ImageCacheConfig>,
+    #[serde(default)]
+    pub image_pull: ImagePullConfig,
+    #[serde(default)]
+    pub file_signing: Option<FileSigningConfig>,
+    #[serde(default)]
+    pub bytecode_allowlist: Option<BytecodeAllowlistConfig>,
+    #[serde(default)]
+    pub admission: Option<AdmissionConfig>,
+    #[serde(default)]
+    pub policy: Option<PolicyConfig>,
+    #[serde(default)]
+    pub rest_gateway: Option<RestGatewayConfig>,
+    #[serde(default)]
+    pub grpc: GrpcConfig,
+    #[serde(default)]
+    pub authorization: Option<AuthorizationConfig>,
+    #[serde(default)]
+    pub quotas: Option<QuotaConfig>,
+    #[serde(default)]
+    pub audit: Option<AuditConfig>,
+    #[serde(default)]
+    pub program_stats: ProgramStatsConfig,
+    #[serde(default)]
+    pub verifier: VerifierConfig,
+    #[serde(default)]
+    pub tracing: Option<TracingConfig>,
+    #[serde(default)]
+    pub events: EventsConfig,
+    #[serde(default)]
+    pub btf: Option<BtfConfig>,
+    #[serde(default)]
+    pub log: Option<LogConfig>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -30,6 +76,450 @@ impl Default for SigningConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Default, Copy, Clone)]
+pub struct TcConfig {
+    // Attach TC programs with tcx links instead of the tc dispatcher, unless
+    // overridden when the program is loaded. Requires Linux 6.6 or later.
+    #[serde(default)]
+    pub use_tcx: bool,
+}
+
+// How ring buffer records are queued for clients streaming them, unless
+// overridden in the request.
+#[derive(Debug, Deserialize, Copy, Clone)]
+pub struct RingBufferConfig {
+    #[serde(default = "default_ring_buffer_queue_size")]
+    pub queue_size: u32,
+    #[serde(default = "default_ring_buffer_drop_policy")]
+    pub drop_policy: DropPolicy,
+}
+
+impl Default for RingBufferConfig {
+    fn default() -> Self {
+        Self {
+            queue_size: default_ring_buffer_queue_size(),
+            drop_policy: default_ring_buffer_drop_policy(),
+        }
+    }
+}
+
+fn default_ring_buffer_queue_size() -> u32 {
+    1024
+}
+
+fn default_ring_buffer_drop_policy() -> DropPolicy {
+    DropPolicy::DropNewest
+}
+
+// Which maps programs pin when they are loaded, added to what the load
+// requests ask for. Patterns may contain "*" and "?" wildcards, names are the
+// names maps are pinned under rather than their own.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct MapPinningConfig {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+// Which registries bytecode images, including the dispatcher images, may be
+// pulled from. Registries are given as in image URLs, e.g. "quay.io" or
+// "registry.example.com:5000", and may contain "*" and "?" wildcards. If
+// allowed is empty all registries but the denied ones are allowed. Images from
+// the digest_only registries must be given by digest rather than by tag.
+// mirrors redirects pulls of images whose name starts with a prefix, e.g.
+// "quay.io" or "quay.io/bpfman", to the image with the prefix replaced.
+// Credentials for images pulled without a username and password are looked
+// up in auth_files, or in the podman and docker auth files if it's empty. If
+// pin_digests is set, the digest an image given by tag was first pulled with
+// is recorded, and pulling the tag again fails if it has moved to another one.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct RegistriesConfig {
+    #[serde(default)]
+    pub allowed: Vec<String>,
+    #[serde(default)]
+    pub denied: Vec<String>,
+    #[serde(default)]
+    pub digest_only: Vec<String>,
+    #[serde(default)]
+    pub mirrors: HashMap<String, String>,
+    #[serde(default)]
+    pub auth_files: Vec<String>,
+    #[serde(default)]
+    pub pin_digests: bool,
+}
+
+// Proxies to pull images and fetch signing data through. Unset fields fall
+// back to the HTTP_PROXY, HTTPS_PROXY and NO_PROXY environment variables.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct ProxyConfig {
+    pub http_proxy: Option<String>,
+    pub https_proxy: Option<String>,
+    pub no_proxy: Option<Vec<String>>,
+}
+
+// Limits of the bytecode image cache. Images no program uses are removed once
+// they haven't been used for max_age seconds, and the least recently used of
+// them are removed while the cache is larger than max_size bytes.
+#[derive(Debug, Deserialize, Default, Clone, Copy)]
+pub struct ImageCacheConfig {
+    pub max_size: Option<u64>,
+    pub max_age: Option<u64>,
+}
+
+// Timeouts and retries of image pulls. Each attempt to pull an image may take
+// up to timeout seconds, and failed attempts are retried up to retries times,
+// waiting retry_backoff milliseconds before the first retry and twice as long
+// before each further one. All attempts together may take up to deadline
+// seconds, if set. Offline, images are never pulled and only the images in the
+// image store can be loaded.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct ImagePullConfig {
+    pub timeout: u64,
+    pub retries: u32,
+    pub retry_backoff: u64,
+    pub deadline: Option<u64>,
+    pub offline: bool,
+}
+
+impl Default for ImagePullConfig {
+    fn default() -> Self {
+        Self {
+            timeout: 60,
+            retries: 3,
+            retry_backoff: 1000,
+            deadline: None,
+            offline: false,
+        }
+    }
+}
+
+// Verification of the bytecode files loaded from the local filesystem against
+// the public keys at the given paths. Unsigned files are rejected unless
+// allow_unsigned is set.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FileSigningConfig {
+    pub public_keys: Vec<String>,
+    pub allow_unsigned: bool,
+}
+
+// Loads of bytecode whose sha256 digest isn't listed in the allowlist file at
+// path are rejected.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BytecodeAllowlistConfig {
+    pub path: String,
+}
+
+// The hook loads are passed to before they are carried out, either a program
+// to run or an HTTPS URL to POST to, which takes up to timeout seconds. Loads
+// are denied if the hook fails, unless fail_open is set.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AdmissionConfig {
+    pub exec: Option<String>,
+    pub url: Option<String>,
+    #[serde(default = "default_admission_timeout")]
+    pub timeout: u64,
+    #[serde(default)]
+    pub fail_open: bool,
+}
+
+fn default_admission_timeout() -> u64 {
+    5
+}
+
+// Loads are decided by the rules of the policy file at path, before they are
+// passed to the admission hook.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PolicyConfig {
+    pub path: String,
+}
+
+// The gRPC API is served on the socket and, in addition, on the endpoints,
+// which all feed the same bpfman.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct GrpcConfig {
+    #[serde(default)]
+    pub socket: SocketConfig,
+    #[serde(default)]
+    pub endpoints: Vec<GrpcEndpoint>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum GrpcEndpoint {
+    Unix(SocketConfig),
+    Tcp(TcpEndpointConfig),
+}
+
+// TCP endpoints listen on address, a host and port, and always use TLS.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TcpEndpointConfig {
+    pub address: String,
+    pub tls: TlsConfig,
+}
+
+// The gRPC API is served on the Unix socket at path, which is given the mode
+// and, if set, the group, so that only members of the group can connect.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SocketConfig {
+    #[serde(default = "default_socket_path")]
+    pub path: String,
+    #[serde(default = "default_socket_mode")]
+    pub mode: u32,
+    pub group: Option<String>,
+}
+
+impl Default for SocketConfig {
+    fn default() -> Self {
+        Self {
+            path: default_socket_path(),
+            mode: default_socket_mode(),
+            group: None,
+        }
+    }
+}
+
+fn default_socket_path() -> String {
+    RTPATH_BPFMAN_SOCKET.to_string()
+}
+
+fn default_socket_mode() -> u32 {
+    0o660
+}
+
+// The REST gateway listens on address, a host and port, serving HTTPS with
+// the given TLS configuration.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RestGatewayConfig {
+    pub address: String,
+    pub tls: TlsConfig,
+    #[serde(default)]
+    pub allow_unauthenticated_clients: bool,
+}
+
+// Listeners serve the certificate chain and private key of the PEM files at
+// cert and key. If client_ca is set, clients must present a certificate issued
+// by one of the CAs of that PEM file. With spiffe, all of them come from the
+// SPIFFE Workload API instead.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TlsConfig {
+    pub cert: Option<String>,
+    pub key: Option<String>,
+    pub client_ca: Option<String>,
+    pub spiffe: Option<SpiffeConfig>,
+}
+
+// Listeners serve the X.509 SVID of bpfman, fetched from the Workload API at
+// socket, and clients must present an SVID of the trust domains of its trust
+// bundles.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SpiffeConfig {
+    pub socket: String,
+}
+
+// Clients get the role of the first binding matching them, or the default
+// role if none does, and are denied if they have no role.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuthorizationConfig {
+    pub default_role: Option<String>,
+    #[serde(default)]
+    pub roles: HashMap<String, RoleConfig>,
+    #[serde(default)]
+    pub bindings: Vec<RoleBinding>,
+}
+
+// A role allows the operations, the RPCs named in snake case, and loads and
+// attachments of the program types, to the interfaces and of images from the
+// registries. All are glob patterns and limits that aren't set allow anything.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RoleConfig {
+    pub operations: Vec<String>,
+    pub program_types: Option<Vec<String>>,
+    pub interfaces: Option<Vec<String>>,
+    pub registries: Option<Vec<String>>,
+}
+
+// Bindings match clients on Unix sockets by the user of their process, and
+// clients on TLS endpoints by the common name and DNS and URI subject
+// alternative names of their certificate, given as glob patterns.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RoleBinding {
+    pub role: String,
+    #[serde(default)]
+    pub users: Vec<String>,
+    #[serde(default)]
+    pub uids: Vec<u32>,
+    #[serde(default)]
+    pub identities: Vec<String>,
+}
+
+// Limits on the programs of each owner and the memory of their maps, with
+// limits of their own for some owners. Programs without an owner aren't
+// limited.
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuotaConfig {
+    pub max_programs: Option<u32>,
+    pub max_map_bytes: Option<u64>,
+    #[serde(default)]
+    pub owners: HashMap<String, QuotaLimits>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq)]
+pub struct QuotaLimits {
+    pub max_programs: Option<u32>,
+    pub max_map_bytes: Option<u64>,
+}
+
+impl QuotaConfig {
+    // Returns the limits of owner, falling back to the defaults for those it
+    // has none of its own for.
+    pub fn limits(&self, owner: &str) -> QuotaLimits {
+        let limits = self.owners.get(owner).copied().unwrap_or_default();
+        QuotaLimits {
+            max_programs: limits.max_programs.or(self.max_programs),
+            max_map_bytes: limits.max_map_bytes.or(self.max_map_bytes),
+        }
+    }
+}
+
+// Requests changing programs or images are recorded as JSON lines appended to
+// the file at path, and, if journald is set, as journal entries with a field
+// per attribute.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuditConfig {
+    pub path: Option<String>,
+    #[serde(default)]
+    pub journald: bool,
+}
+
+// The kernel counts the runs of programs and the time they take while
+// enabled is set, at the cost of some overhead on every run.
+#[derive(Debug, Deserialize, Default, Clone, Copy)]
+pub struct ProgramStatsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+// Loads the verifier rejects fail with the end of its log, where it gives its
+// reason, of up to max_error_log_size bytes. The log is returned in the error
+// details of the response, which gRPC sends as a header, so clients limiting
+// the size of headers may need a smaller one.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct VerifierConfig {
+    pub max_error_log_size: usize,
+}
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self {
+            max_error_log_size: 8192,
+        }
+    }
+}
+
+// Spans of requests are exported to the OTLP collector at otlp_endpoint, for
+// the share sample_ratio of the traces bpfman starts. Traces continued from a
+// client are sampled as the client decided.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TracingConfig {
+    pub otlp_endpoint: String,
+    #[serde(default = "default_tracing_sample_ratio")]
+    pub sample_ratio: f64,
+}
+
+fn default_tracing_sample_ratio() -> f64 {
+    1.0
+}
+
+// Up to max_events of the latest loads, unloads and dispatcher rebuilds are
+// kept in the database.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct EventsConfig {
+    pub max_events: usize,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self { max_events: 10000 }
+    }
+}
+
+// On kernels without /sys/kernel/btf/vmlinux, CO-RE programs are relocated
+// against the BTF of the running kernel found in path, which is looked up by
+// the kernel release, like <path>/5.4.0-150-generic.btf, in path or the
+// directories under it as in the BTFHub archive.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BtfConfig {
+    pub path: String,
+}
+
+// bpfman logs at level, one of "off", "error", "warn", "info", "debug" or
+// "trace", rather than at the level of RUST_LOG.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LogConfig {
+    pub level: String,
+}
+
+pub const XDP_DISPATCHER_IMAGE: &str = "quay.io/bpfman/xdp-dispatcher:v2";
+pub const TC_DISPATCHER_IMAGE: &str = "quay.io/bpfman/tc-dispatcher:v1";
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DispatcherImages {
+    #[serde(default = "default_xdp_dispatcher_image")]
+    pub xdp: DispatcherImage,
+    #[serde(default = "default_tc_dispatcher_image")]
+    pub tc: DispatcherImage,
+}
+
+impl Default for DispatcherImages {
+    fn default() -> Self {
+        Self {
+            xdp: default_xdp_dispatcher_image(),
+            tc: default_tc_dispatcher_image(),
+        }
+    }
+}
+
+// The image the XDP or TC dispatcher bytecode is pulled from, which can be
+// pointed at a mirror for air-gapped environments.
+#[derive(Debug, Deserialize, Clone)]
+pub struct DispatcherImage {
+    pub url: String,
+    #[serde(default = "default_dispatcher_pull_policy")]
+    pub pull_policy: ImagePullPolicy,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl DispatcherImage {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            pull_policy: default_dispatcher_pull_policy(),
+            username: None,
+            password: None,
+        }
+    }
+}
+
+fn default_xdp_dispatcher_image() -> DispatcherImage {
+    DispatcherImage::new(XDP_DISPATCHER_IMAGE)
+}
+
+fn default_tc_dispatcher_image() -> DispatcherImage {
+    DispatcherImage::new(TC_DISPATCHER_IMAGE)
+}
+
+fn default_dispatcher_pull_policy() -> ImagePullPolicy {
+    ImagePullPolicy::IfNotPresent
+}
+
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Error parsing config file: {0}")]
@@ -44,9 +534,13 @@ impl FromStr for Config {
     }
 }
 
+// XDP programs are attached in skb mode if the driver of the interface doesn't
+// support xdp_mode and xdp_mode_fallback is set, rather than failing.
 #[derive(Debug, Deserialize, Copy, Clone)]
 pub struct InterfaceConfig {
     pub xdp_mode: XdpMode,
+    #[serde(default)]
+    pub xdp_mode_fallback: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
@@ -143,4 +637,515 @@ mod test {
             None => panic!("expected interfaces to be present"),
         }
     }
+
+    #[test]
+    fn test_config_xdp_mode_fallback() {
+        let input = r#"
+        [interfaces]
+          [interfaces.eth0]
+          xdp_mode = "drv"
+          xdp_mode_fallback = true
+          [interfaces.eth1]
+          xdp_mode = "drv"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let interfaces = config.interfaces.unwrap();
+        assert!(interfaces["eth0"].xdp_mode_fallback);
+        assert!(!interfaces["eth1"].xdp_mode_fallback);
+    }
+
+    #[test]
+    fn test_config_tc() {
+        let input = r#"
+        [tc]
+          use_tcx = true
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert!(config.tc.expect("expected tc to be present").use_tcx);
+
+        let config: Config = toml::from_str("[tc]").expect("error parsing toml input");
+        assert!(!config.tc.expect("expected tc to be present").use_tcx);
+    }
+
+    #[test]
+    fn test_config_ring_buffer() {
+        let input = r#"
+        [ring_buffer]
+          queue_size = 64
+          drop_policy = "drop-oldest"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let ring_buffer = config
+            .ring_buffer
+            .expect("expected ring_buffer to be present");
+        assert_eq!(ring_buffer.queue_size, 64);
+        assert_eq!(ring_buffer.drop_policy, DropPolicy::DropOldest);
+
+        let config: Config = toml::from_str("[ring_buffer]").expect("error parsing toml input");
+        let ring_buffer = config
+            .ring_buffer
+            .expect("expected ring_buffer to be present");
+        assert_eq!(ring_buffer.queue_size, 1024);
+        assert_eq!(ring_buffer.drop_policy, DropPolicy::DropNewest);
+    }
+
+    #[test]
+    fn test_config_map_pinning() {
+        let input = r#"
+        [map_pinning]
+          exclude = ["scratch_*"]
+          [map_pinning.names]
+          flows = "flows_v1"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let map_pinning = config
+            .map_pinning
+            .expect("expected map_pinning to be present");
+        assert!(map_pinning.include.is_empty());
+        assert_eq!(map_pinning.exclude, vec!["scratch_*".to_string()]);
+        assert_eq!(map_pinning.names.get("flows").unwrap(), "flows_v1");
+    }
+
+    #[test]
+    fn test_config_registries() {
+        let input = r#"
+        [registries]
+          allowed = ["registry.example.com:5000", "*.corp.example.com"]
+          digest_only = ["registry.example.com:5000"]
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let registries = config
+            .registries
+            .expect("expected registries to be present");
+        assert_eq!(registries.allowed.len(), 2);
+        assert!(registries.denied.is_empty());
+        assert_eq!(
+            registries.digest_only,
+            vec!["registry.example.com:5000".to_string()]
+        );
+        assert!(registries.mirrors.is_empty());
+        assert!(registries.auth_files.is_empty());
+        assert!(!registries.pin_digests);
+
+        let input = r#"
+        [registries]
+          pin_digests = true
+          [registries.mirrors]
+          "quay.io" = "mirror.corp.example.com:5000/quay"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let registries = config
+            .registries
+            .expect("expected registries to be present");
+        assert!(registries.pin_digests);
+        assert_eq!(
+            registries.mirrors.get("quay.io").map(String::as_str),
+            Some("mirror.corp.example.com:5000/quay")
+        );
+    }
+
+    #[test]
+    fn test_config_proxy() {
+        let input = r#"
+        [proxy]
+          https_proxy = "http://proxy.example.com:3128"
+          no_proxy = ["localhost", ".corp.example.com"]
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let proxy = config.proxy.expect("expected proxy to be present");
+        assert!(proxy.http_proxy.is_none());
+        assert_eq!(
+            proxy.https_proxy.as_deref(),
+            Some("http://proxy.example.com:3128")
+        );
+        assert_eq!(
+            proxy.no_proxy,
+            Some(vec![
+                "localhost".to_string(),
+                ".corp.example.com".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_config_image_cache() {
+        let input = r#"
+        [image_cache]
+          max_size = 104857600
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let image_cache = config
+            .image_cache
+            .expect("expected image_cache to be present");
+        assert_eq!(image_cache.max_size, Some(104857600));
+        assert!(image_cache.max_age.is_none());
+    }
+
+    #[test]
+    fn test_config_image_pull() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert_eq!(config.image_pull.timeout, 60);
+        assert_eq!(config.image_pull.retries, 3);
+        assert!(!config.image_pull.offline);
+
+        let input = r#"
+        [image_pull]
+          retries = 5
+          deadline = 300
+          offline = true
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.image_pull.timeout, 60);
+        assert_eq!(config.image_pull.retries, 5);
+        assert_eq!(config.image_pull.retry_backoff, 1000);
+        assert_eq!(config.image_pull.deadline, Some(300));
+        assert!(config.image_pull.offline);
+    }
+
+    #[test]
+    fn test_config_file_signing() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.file_signing.is_none());
+
+        let input = r#"
+        [file_signing]
+          public_keys = ["/etc/bpfman/keys/release.pub"]
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let file_signing = config.file_signing.unwrap();
+        assert_eq!(
+            file_signing.public_keys,
+            vec!["/etc/bpfman/keys/release.pub"]
+        );
+        assert!(!file_signing.allow_unsigned);
+    }
+
+    #[test]
+    fn test_config_bytecode_allowlist() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.bytecode_allowlist.is_none());
+
+        let input = r#"
+        [bytecode_allowlist]
+          path = "/etc/bpfman/bytecode-allowlist"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(
+            config.bytecode_allowlist.unwrap().path,
+            "/etc/bpfman/bytecode-allowlist"
+        );
+    }
+
+    #[test]
+    fn test_config_admission() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.admission.is_none());
+
+        let input = r#"
+        [admission]
+          url = "https://policy.example.com/admit"
+          fail_open = true
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let admission = config.admission.unwrap();
+        assert!(admission.exec.is_none());
+        assert_eq!(
+            admission.url.as_deref(),
+            Some("https://policy.example.com/admit")
+        );
+        assert_eq!(admission.timeout, 5);
+        assert!(admission.fail_open);
+    }
+
+    #[test]
+    fn test_config_policy() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.policy.is_none());
+
+        let input = r#"
+        [policy]
+          path = "/etc/bpfman/policy.toml"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.policy.unwrap().path, "/etc/bpfman/policy.toml");
+    }
+
+    #[test]
+    fn test_config_grpc_socket() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert_eq!(config.grpc.socket.path, RTPATH_BPFMAN_SOCKET);
+        assert_eq!(config.grpc.socket.mode, 0o660);
+        assert!(config.grpc.socket.group.is_none());
+
+        let input = r#"
+        [grpc.socket]
+          path = "/run/tenants/acme/bpfman.sock"
+          mode = 0o600
+          group = "acme"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.grpc.socket.path, "/run/tenants/acme/bpfman.sock");
+        assert_eq!(config.grpc.socket.mode, 0o600);
+        assert_eq!(config.grpc.socket.group.as_deref(), Some("acme"));
+    }
+
+    #[test]
+    fn test_config_grpc_endpoints() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.grpc.endpoints.is_empty());
+
+        let input = r#"
+        [[grpc.endpoints]]
+          type = "unix"
+          path = "/run/tenants/acme/bpfman.sock"
+          group = "acme"
+
+        [[grpc.endpoints]]
+          type = "tcp"
+          address = "10.0.0.1:50051"
+          tls = { cert = "/etc/bpfman/tls/server.crt", key = "/etc/bpfman/tls/server.key" }
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.grpc.endpoints.len(), 2);
+        match &config.grpc.endpoints[0] {
+            GrpcEndpoint::Unix(socket) => {
+                assert_eq!(socket.path, "/run/tenants/acme/bpfman.sock");
+                assert_eq!(socket.mode, 0o660);
+            }
+            e => panic!("unexpected endpoint {e:?}"),
+        }
+        match &config.grpc.endpoints[1] {
+            GrpcEndpoint::Tcp(tcp) => {
+                assert_eq!(tcp.address, "10.0.0.1:50051");
+                assert!(tcp.tls.client_ca.is_none());
+            }
+            e => panic!("unexpected endpoint {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_config_audit() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.audit.is_none());
+
+        let input = r#"
+        [audit]
+          path = "/var/log/bpfman/audit.log"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let audit = config.audit.unwrap();
+        assert_eq!(audit.path.as_deref(), Some("/var/log/bpfman/audit.log"));
+        assert!(!audit.journald);
+    }
+
+    #[test]
+    fn test_config_tracing() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.tracing.is_none());
+
+        let input = r#"
+        [tracing]
+          otlp_endpoint = "http://localhost:4317"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let tracing = config.tracing.unwrap();
+        assert_eq!(tracing.otlp_endpoint, "http://localhost:4317");
+        assert_eq!(tracing.sample_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_config_events() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert_eq!(config.events.max_events, 10000);
+
+        let input = r#"
+        [events]
+          max_events = 500
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.events.max_events, 500);
+    }
+
+    #[test]
+    fn test_config_btf() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.btf.is_none());
+
+        let input = r#"
+        [btf]
+          path = "/var/lib/bpfman/btf"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.btf.unwrap().path, "/var/lib/bpfman/btf");
+    }
+
+    #[test]
+    fn test_config_verifier() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert_eq!(config.verifier.max_error_log_size, 8192);
+
+        let input = r#"
+        [verifier]
+          max_error_log_size = 2048
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.verifier.max_error_log_size, 2048);
+    }
+
+    #[test]
+    fn test_config_program_stats() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(!config.program_stats.enabled);
+
+        let input = r#"
+        [program_stats]
+          enabled = true
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert!(config.program_stats.enabled);
+    }
+
+    #[test]
+    fn test_config_quotas() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.quotas.is_none());
+
+        let input = r#"
+        [quotas]
+          max_programs = 10
+          max_map_bytes = 1048576
+
+        [quotas.owners.alice]
+          max_programs = 50
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let quotas = config.quotas.unwrap();
+        assert_eq!(
+            quotas.limits("alice"),
+            QuotaLimits {
+                max_programs: Some(50),
+                max_map_bytes: Some(1048576),
+            }
+        );
+        assert_eq!(quotas.limits("bob").max_programs, Some(10));
+    }
+
+    #[test]
+    fn test_config_authorization() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.authorization.is_none());
+
+        let input = r#"
+        [authorization]
+          default_role = "viewer"
+
+        [authorization.roles.viewer]
+          operations = ["list", "get", "version"]
+
+        [authorization.roles.network]
+          operations = ["*"]
+          program_types = ["xdp", "tc"]
+          interfaces = ["eth*"]
+          registries = ["quay.io"]
+
+        [[authorization.bindings]]
+          role = "network"
+          users = ["alice"]
+          identities = ["netops.example.com"]
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let authorization = config.authorization.unwrap();
+        assert_eq!(authorization.default_role.as_deref(), Some("viewer"));
+        let network = &authorization.roles["network"];
+        assert_eq!(network.program_types.as_ref().unwrap().len(), 2);
+        assert!(authorization.roles["viewer"].interfaces.is_none());
+        assert_eq!(authorization.bindings[0].users, vec!["alice"]);
+        assert!(authorization.bindings[0].uids.is_empty());
+    }
+
+    #[test]
+    fn test_config_tls_spiffe() {
+        let input = r#"
+        [rest_gateway]
+          address = "0.0.0.0:8443"
+          tls = { spiffe = { socket = "/run/spire/sockets/agent.sock" } }
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let tls = config.rest_gateway.unwrap().tls;
+        assert!(tls.cert.is_none());
+        assert_eq!(tls.spiffe.unwrap().socket, "/run/spire/sockets/agent.sock");
+    }
+
+    #[test]
+    fn test_config_rest_gateway() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.rest_gateway.is_none());
+
+        let input = r#"
+        [rest_gateway]
+          address = "0.0.0.0:8443"
+
+        [rest_gateway.tls]
+          cert = "/etc/bpfman/tls/server.crt"
+          key = "/etc/bpfman/tls/server.key"
+          client_ca = "/etc/bpfman/tls/ca.crt"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let gateway = config.rest_gateway.unwrap();
+        assert_eq!(gateway.address, "0.0.0.0:8443");
+        assert_eq!(
+            gateway.tls.key.as_deref(),
+            Some("/etc/bpfman/tls/server.key")
+        );
+        assert_eq!(
+            gateway.tls.client_ca.as_deref(),
+            Some("/etc/bpfman/tls/ca.crt")
+        );
+        assert!(!gateway.allow_unauthenticated_clients);
+
+        let input = r#"
+        [rest_gateway]
+          address = "0.0.0.0:8443"
+        "#;
+        assert!(toml::from_str::<Config>(input).is_err());
+    }
+
+    #[test]
+    fn test_config_dispatcher_images() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert_eq!(config.dispatcher_images.xdp.url, XDP_DISPATCHER_IMAGE);
+        assert_eq!(config.dispatcher_images.tc.url, TC_DISPATCHER_IMAGE);
+
+        let input = r#"
+        [dispatcher_images.xdp]
+          url = "registry.example.com/bpfman/xdp-dispatcher:v2"
+          pull_policy = "Never"
+          username = "someone"
+          password = "secret"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        let xdp = config.dispatcher_images.xdp;
+        assert_eq!(xdp.url, "registry.example.com/bpfman/xdp-dispatcher:v2");
+        assert!(matches!(xdp.pull_policy, ImagePullPolicy::Never));
+        assert_eq!(xdp.username.as_deref(), Some("someone"));
+        assert_eq!(xdp.password.as_deref(), Some("secret"));
+        assert_eq!(config.dispatcher_images.tc.url, TC_DISPATCHER_IMAGE);
+        assert!(matches!(
+            config.dispatcher_images.tc.pull_policy,
+            ImagePullPolicy::IfNotPresent
+        ));
+    }
+
+    #[test]
+    fn test_config_log() {
+        let config: Config = toml::from_str("").expect("error parsing toml input");
+        assert!(config.log.is_none());
+
+        let input = r#"
+        [log]
+          level = "debug"
+        "#;
+        let config: Config = toml::from_str(input).expect("error parsing toml input");
+        assert_eq!(config.log.unwrap().level, "debug");
+    }
 }
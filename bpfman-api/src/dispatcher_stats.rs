@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// The XDP and TC dispatchers count the packets they run the program of each
+// slot on, and the verdicts the program returns, in their dispatcher_stats
+// per-CPU array. bpfman pins the map of the dispatcher of an interface under a
+// name that stays the same across revisions of the dispatcher, so that both
+// bpfman and the metrics exporter can read it. The counters start over when
+// the dispatcher is rebuilt, and dispatchers built before the map was added
+// don't count at all.
+
+use std::{collections::HashMap, path::Path};
+
+use aya::maps::{Map, MapData, MapError, PerCpuArray};
+
+use crate::{TcProceedOnEntry, XdpProceedOnEntry};
+
+pub const DISPATCHER_STATS_MAP: &str = "dispatcher_stats";
+
+// Entries of the map, one per slot.
+pub const DISPATCHER_SLOTS: u32 = 10;
+
+// Verdicts are counted by their bit in the chain call mask of a slot.
+pub const DISPATCHER_VERDICTS: usize = 32;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DispatcherKind {
+    Xdp,
+    Tc,
+}
+
+// struct slot_stats of the dispatchers.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct SlotStats {
+    pub packets: u64,
+    pub verdicts: [u64; DISPATCHER_VERDICTS],
+}
+
+unsafe impl aya::Pod for SlotStats {}
+
+// Path of the stats map of the dispatcher with key, pinned in the bpffs
+// directory of its kind and direction.
+pub fn stats_path(dir: &str, key: &str) -> String {
+    format!("{dir}/dispatcher_{key}_stats")
+}
+
+impl SlotStats {
+    // Reads the counters of slot from the map pinned at path, summed over all
+    // CPUs.
+    pub fn read<P: AsRef<Path>>(path: P, slot: u32) -> Result<Self, MapError> {
+        let array: PerCpuArray<_, SlotStats> =
+            Map::PerCpuArray(MapData::from_pin(path)?).try_into()?;
+        let mut total = SlotStats::default();
+        for cpu in array.get(&slot, 0)?.iter() {
+            total.packets += cpu.packets;
+            for (count, cpu_count) in total.verdicts.iter_mut().zip(cpu.verdicts) {
+                *count += cpu_count;
+            }
+        }
+        Ok(total)
+    }
+
+    // Counts of the verdicts that were returned at least once, by their
+    // proceed-on name, or by their value if they have none.
+    pub fn verdicts(&self, kind: DispatcherKind) -> HashMap<String, u64> {
+        self.verdicts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(bit, count)| (verdict_name(kind, bit), *count))
+            .collect()
+    }
+}
+
+// TC verdicts start at TC_ACT_UNSPEC (-1), so verdict n is counted under bit
+// n + 1, as in TcProceedOn::mask().
+fn verdict_name(kind: DispatcherKind, bit: usize) -> String {
+    match kind {
+        DispatcherKind::Xdp => XdpProceedOnEntry::try_from(bit as i32)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| bit.to_string()),
+        DispatcherKind::Tc => TcProceedOnEntry::try_from(bit as i32 - 1)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| (bit as i32 - 1).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdicts() {
+        let mut stats = SlotStats {
+            packets: 7,
+            ..Default::default()
+        };
+        stats.verdicts[0] = 1;
+        stats.verdicts[2] = 4;
+        stats.verdicts[9] = 2;
+
+        let xdp = stats.verdicts(DispatcherKind::Xdp);
+        assert_eq!(xdp.len(), 3);
+        assert_eq!(xdp["aborted"], 1);
+        assert_eq!(xdp["pass"], 4);
+        assert_eq!(xdp["9"], 2);
+
+        let tc = stats.verdicts(DispatcherKind::Tc);
+        assert_eq!(tc.len(), 3);
+        assert_eq!(tc["unspec"], 1);
+        assert_eq!(tc["reclassify"], 4);
+        assert_eq!(tc["trap"], 2);
+    }
+}
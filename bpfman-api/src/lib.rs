@@ -2,6 +2,7 @@
 // Copyright Authors of bpfman
 
 pub mod config;
+pub mod dispatcher_stats;
 pub mod util;
 #[path = "bpfman.v1.rs"]
 #[rustfmt::skip]
@@ -15,6 +16,9 @@ use thiserror::Error;
 use url::ParseError as urlParseError;
 use v1::bytecode_location::Location;
 
+// Encoded descriptors of the bpfman API, served by the gRPC reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!("bpfman_descriptor.bin");
+
 #[derive(Error, Debug)]
 pub enum ParseError {
     #[error("{program} is not a valid program type")]
@@ -33,6 +37,12 @@ pub enum ParseError {
     InvalidProbeType { probe: String },
     #[error("Invalid XdpMode: {mode}")]
     InvalidXdpMode { mode: String },
+    #[error("{flags} is not a valid cgroup attach flag")]
+    InvalidCgroupAttachFlags { flags: String },
+    #[error("{attach_point} is not a valid attach point")]
+    InvalidAttachPoint { attach_point: String },
+    #[error("{policy} is not a valid drop policy")]
+    InvalidDropPolicy { policy: String },
 }
 
 #[derive(ValueEnum, Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -69,6 +79,7 @@ pub enum ProgramType {
     Lsm,
     SkLookup,
     Syscall,
+    Netfilter,
 }
 
 impl TryFrom<String> for ProgramType {
@@ -108,6 +119,7 @@ impl TryFrom<String> for ProgramType {
             "lsm" => ProgramType::Lsm,
             "sk_lookup" => ProgramType::SkLookup,
             "syscall" => ProgramType::Syscall,
+            "netfilter" => ProgramType::Netfilter,
             other => {
                 return Err(ParseError::InvalidProgramType {
                     program: other.to_string(),
@@ -154,6 +166,7 @@ impl TryFrom<u32> for ProgramType {
             29 => ProgramType::Lsm,
             30 => ProgramType::SkLookup,
             31 => ProgramType::Syscall,
+            32 => ProgramType::Netfilter,
             other => {
                 return Err(ParseError::InvalidProgramType {
                     program: other.to_string(),
@@ -198,6 +211,7 @@ impl From<ProgramType> for u32 {
             ProgramType::Lsm => 29,
             ProgramType::SkLookup => 30,
             ProgramType::Syscall => 31,
+            ProgramType::Netfilter => 32,
         }
     }
 }
@@ -237,6 +251,7 @@ impl std::fmt::Display for ProgramType {
             ProgramType::Lsm => "lsm",
             ProgramType::SkLookup => "sk_lookup",
             ProgramType::Syscall => "syscall",
+            ProgramType::Netfilter => "netfilter",
         };
         write!(f, "{v}")
     }
@@ -577,7 +592,7 @@ impl std::fmt::Display for TcProceedOn {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub enum ImagePullPolicy {
     Always,
     IfNotPresent,
@@ -637,6 +652,145 @@ impl From<ImagePullPolicy> for i32 {
     }
 }
 
+/// What is done with the records of a ring buffer when a client streaming
+/// them falls behind and its queue is full.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DropPolicy {
+    /// New records are dropped until the client catches up.
+    DropNewest,
+    /// The oldest queued records are dropped to make room for new ones.
+    DropOldest,
+    /// The ring buffer isn't read until the client catches up, so records are
+    /// dropped by the eBPF program once the ring buffer is full.
+    Block,
+}
+
+impl std::fmt::Display for DropPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let v = match self {
+            DropPolicy::DropNewest => "drop-newest",
+            DropPolicy::DropOldest => "drop-oldest",
+            DropPolicy::Block => "block",
+        };
+        write!(f, "{v}")
+    }
+}
+
+impl TryFrom<i32> for DropPolicy {
+    type Error = ParseError;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => DropPolicy::DropNewest,
+            1 => DropPolicy::DropOldest,
+            2 => DropPolicy::Block,
+            policy => {
+                return Err(ParseError::InvalidDropPolicy {
+                    policy: policy.to_string(),
+                })
+            }
+        })
+    }
+}
+
+impl TryFrom<&str> for DropPolicy {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "drop-newest" => DropPolicy::DropNewest,
+            "drop-oldest" => DropPolicy::DropOldest,
+            "block" => DropPolicy::Block,
+            policy => {
+                return Err(ParseError::InvalidDropPolicy {
+                    policy: policy.to_string(),
+                })
+            }
+        })
+    }
+}
+
+impl From<DropPolicy> for i32 {
+    fn from(value: DropPolicy) -> Self {
+        match value {
+            DropPolicy::DropNewest => 0,
+            DropPolicy::DropOldest => 1,
+            DropPolicy::Block => 2,
+        }
+    }
+}
+
+/// Flags controlling how a program is attached to a cgroup, mirroring the
+/// kernel's BPF_F_ALLOW_OVERRIDE and BPF_F_ALLOW_MULTI attach flags.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq)]
+pub enum CgroupAttachFlags {
+    None,
+    Override,
+    Multi,
+}
+
+impl std::fmt::Display for CgroupAttachFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let v = match self {
+            CgroupAttachFlags::None => "none",
+            CgroupAttachFlags::Override => "override",
+            CgroupAttachFlags::Multi => "multi",
+        };
+        write!(f, "{v}")
+    }
+}
+
+impl TryFrom<i32> for CgroupAttachFlags {
+    type Error = ParseError;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => CgroupAttachFlags::None,
+            1 => CgroupAttachFlags::Override,
+            2 => CgroupAttachFlags::Multi,
+            flags => {
+                return Err(ParseError::InvalidCgroupAttachFlags {
+                    flags: flags.to_string(),
+                })
+            }
+        })
+    }
+}
+
+impl TryFrom<&str> for CgroupAttachFlags {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "none" => CgroupAttachFlags::None,
+            "override" => CgroupAttachFlags::Override,
+            "multi" => CgroupAttachFlags::Multi,
+            flags => {
+                return Err(ParseError::InvalidCgroupAttachFlags {
+                    flags: flags.to_string(),
+                })
+            }
+        })
+    }
+}
+
+impl From<CgroupAttachFlags> for i32 {
+    fn from(value: CgroupAttachFlags) -> Self {
+        match value {
+            CgroupAttachFlags::None => 0,
+            CgroupAttachFlags::Override => 1,
+            CgroupAttachFlags::Multi => 2,
+        }
+    }
+}
+
+impl From<CgroupAttachFlags> for aya::programs::CgroupAttachMode {
+    fn from(value: CgroupAttachFlags) -> Self {
+        match value {
+            CgroupAttachFlags::None => aya::programs::CgroupAttachMode::Single,
+            CgroupAttachFlags::Override => aya::programs::CgroupAttachMode::AllowOverride,
+            CgroupAttachFlags::Multi => aya::programs::CgroupAttachMode::AllowMultiple,
+        }
+    }
+}
+
 impl ToString for Location {
     fn to_string(&self) -> String {
         match &self {
@@ -647,6 +801,8 @@ impl ToString for Location {
                 TryInto::<ImagePullPolicy>::try_into(i.image_pull_policy).unwrap()
             ),
             Location::File(p) => format!("file: {{ path: {p} }}"),
+            Location::Data(d) => format!("data: {{ size: {} }}", d.len()),
+            Location::Url(u) => format!("url: {{ url: {}, sha256: {} }}", u.url, u.sha256),
         }
     }
 }
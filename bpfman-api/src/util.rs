@@ -34,11 +34,19 @@ pub mod directories {
     pub const RTDIR_PROGRAMS: &str = "/run/bpfman/programs";
     pub const RTDIR_SOCK: &str = "/run/bpfman/sock";
     pub const RTPATH_BPFMAN_SOCKET: &str = "/run/bpfman/sock/bpfman.sock";
+    // Socket used to hand socket filter program fds to clients via SCM_RIGHTS.
+    pub const RTPATH_BPFMAN_FD_SOCKET: &str = "/run/bpfman/sock/bpfman-fd.sock";
+    // Socket used to hand fds of pinned maps to clients via SCM_RIGHTS.
+    pub const RTPATH_BPFMAN_MAP_FD_SOCKET: &str = "/run/bpfman/sock/bpfman-map-fd.sock";
     // The CSI socket must be in it's own sub directory so we can easily create a dedicated
     // K8s volume mount for it.
     pub const RTDIR_BPFMAN_CSI: &str = "/run/bpfman/csi";
     pub const RTPATH_BPFMAN_CSI_SOCKET: &str = "/run/bpfman/csi/csi.sock";
     pub const RTDIR_BPFMAN_CSI_FS: &str = "/run/bpfman/csi/fs";
+    // bpffs mounted for each export of maps bind mounted on a consumer's directory.
+    pub const RTDIR_MAP_EXPORTS: &str = "/run/bpfman/exports";
+    // Bytecode objects uploaded by clients, named after their sha256 digest.
+    pub const RTDIR_UPLOADS: &str = "/run/bpfman/uploads";
 
     // StateDirectory: /var/lib/bpfman/
     pub const STDIR_MODE: u32 = 0o6770;
@@ -118,3 +118,107 @@ func (b *BpfmanClientFake) Get(ctx context.Context, in *gobpfman.GetRequest, opt
 func (b *BpfmanClientFake) PullBytecode(ctx context.Context, in *gobpfman.PullBytecodeRequest, opts ...grpc.CallOption) (*gobpfman.PullBytecodeResponse, error) {
 	return &gobpfman.PullBytecodeResponse{}, nil
 }
+
+func (b *BpfmanClientFake) LoadUpload(ctx context.Context, opts ...grpc.CallOption) (gobpfman.Bpfman_LoadUploadClient, error) {
+	return nil, fmt.Errorf("LoadUpload is not supported by the fake client")
+}
+
+func (b *BpfmanClientFake) LoadBatch(ctx context.Context, in *gobpfman.LoadBatchRequest, opts ...grpc.CallOption) (*gobpfman.LoadBatchResponse, error) {
+	return &gobpfman.LoadBatchResponse{}, nil
+}
+
+func (b *BpfmanClientFake) PruneImages(ctx context.Context, in *gobpfman.PruneImagesRequest, opts ...grpc.CallOption) (*gobpfman.PruneImagesResponse, error) {
+	return &gobpfman.PruneImagesResponse{}, nil
+}
+
+func (b *BpfmanClientFake) ListImages(ctx context.Context, in *gobpfman.ListImagesRequest, opts ...grpc.CallOption) (*gobpfman.ListImagesResponse, error) {
+	return &gobpfman.ListImagesResponse{}, nil
+}
+
+func (b *BpfmanClientFake) Version(ctx context.Context, in *gobpfman.VersionRequest, opts ...grpc.CallOption) (*gobpfman.VersionResponse, error) {
+	return &gobpfman.VersionResponse{}, nil
+}
+
+func (b *BpfmanClientFake) Read(ctx context.Context, in *gobpfman.ReadRequest, opts ...grpc.CallOption) (gobpfman.Bpfman_ReadClient, error) {
+	return nil, fmt.Errorf("Read is not supported by the fake client")
+}
+
+func (b *BpfmanClientFake) Attach(ctx context.Context, in *gobpfman.AttachRequest, opts ...grpc.CallOption) (*gobpfman.AttachResponse, error) {
+	return &gobpfman.AttachResponse{}, nil
+}
+
+func (b *BpfmanClientFake) Detach(ctx context.Context, in *gobpfman.DetachRequest, opts ...grpc.CallOption) (*gobpfman.DetachResponse, error) {
+	return &gobpfman.DetachResponse{}, nil
+}
+
+func (b *BpfmanClientFake) SetPriority(ctx context.Context, in *gobpfman.SetPriorityRequest, opts ...grpc.CallOption) (*gobpfman.SetPriorityResponse, error) {
+	return &gobpfman.SetPriorityResponse{}, nil
+}
+
+func (b *BpfmanClientFake) PublishMaps(ctx context.Context, in *gobpfman.PublishMapsRequest, opts ...grpc.CallOption) (*gobpfman.PublishMapsResponse, error) {
+	return &gobpfman.PublishMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) UnpublishMaps(ctx context.Context, in *gobpfman.UnpublishMapsRequest, opts ...grpc.CallOption) (*gobpfman.UnpublishMapsResponse, error) {
+	return &gobpfman.UnpublishMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) Replace(ctx context.Context, in *gobpfman.ReplaceRequest, opts ...grpc.CallOption) (*gobpfman.ReplaceResponse, error) {
+	return &gobpfman.ReplaceResponse{}, nil
+}
+
+func (b *BpfmanClientFake) Rollback(ctx context.Context, in *gobpfman.RollbackRequest, opts ...grpc.CallOption) (*gobpfman.RollbackResponse, error) {
+	return &gobpfman.RollbackResponse{}, nil
+}
+
+func (b *BpfmanClientFake) ListMaps(ctx context.Context, in *gobpfman.ListMapsRequest, opts ...grpc.CallOption) (*gobpfman.ListMapsResponse, error) {
+	return &gobpfman.ListMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) DumpMap(ctx context.Context, in *gobpfman.DumpMapRequest, opts ...grpc.CallOption) (*gobpfman.DumpMapResponse, error) {
+	return &gobpfman.DumpMapResponse{}, nil
+}
+
+func (b *BpfmanClientFake) LookupMapEntry(ctx context.Context, in *gobpfman.LookupMapEntryRequest, opts ...grpc.CallOption) (*gobpfman.LookupMapEntryResponse, error) {
+	return &gobpfman.LookupMapEntryResponse{}, nil
+}
+
+func (b *BpfmanClientFake) UpdateMapEntry(ctx context.Context, in *gobpfman.UpdateMapEntryRequest, opts ...grpc.CallOption) (*gobpfman.UpdateMapEntryResponse, error) {
+	return &gobpfman.UpdateMapEntryResponse{}, nil
+}
+
+func (b *BpfmanClientFake) DeleteMapEntry(ctx context.Context, in *gobpfman.DeleteMapEntryRequest, opts ...grpc.CallOption) (*gobpfman.DeleteMapEntryResponse, error) {
+	return &gobpfman.DeleteMapEntryResponse{}, nil
+}
+
+func (b *BpfmanClientFake) WatchRingBuffer(ctx context.Context, in *gobpfman.WatchRingBufferRequest, opts ...grpc.CallOption) (gobpfman.Bpfman_WatchRingBufferClient, error) {
+	return nil, fmt.Errorf("WatchRingBuffer is not supported by the fake client")
+}
+
+func (b *BpfmanClientFake) WatchPerfBuffer(ctx context.Context, in *gobpfman.WatchPerfBufferRequest, opts ...grpc.CallOption) (gobpfman.Bpfman_WatchPerfBufferClient, error) {
+	return nil, fmt.Errorf("WatchPerfBuffer is not supported by the fake client")
+}
+
+func (b *BpfmanClientFake) ExportMaps(ctx context.Context, in *gobpfman.ExportMapsRequest, opts ...grpc.CallOption) (*gobpfman.ExportMapsResponse, error) {
+	return &gobpfman.ExportMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) UnexportMaps(ctx context.Context, in *gobpfman.UnexportMapsRequest, opts ...grpc.CallOption) (*gobpfman.UnexportMapsResponse, error) {
+	return &gobpfman.UnexportMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) GetEvents(ctx context.Context, in *gobpfman.GetEventsRequest, opts ...grpc.CallOption) (*gobpfman.GetEventsResponse, error) {
+	return &gobpfman.GetEventsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) ListKernelLinks(ctx context.Context, in *gobpfman.ListKernelLinksRequest, opts ...grpc.CallOption) (*gobpfman.ListKernelLinksResponse, error) {
+	return &gobpfman.ListKernelLinksResponse{}, nil
+}
+
+func (b *BpfmanClientFake) ListKernelMaps(ctx context.Context, in *gobpfman.ListKernelMapsRequest, opts ...grpc.CallOption) (*gobpfman.ListKernelMapsResponse, error) {
+	return &gobpfman.ListKernelMapsResponse{}, nil
+}
+
+func (b *BpfmanClientFake) ProbeFeatures(ctx context.Context, in *gobpfman.ProbeFeaturesRequest, opts ...grpc.CallOption) (*gobpfman.ProbeFeaturesResponse, error) {
+	return &gobpfman.ProbeFeaturesResponse{}, nil
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Loads can be passed to an admission hook before they are carried out, so
+// that the policy deciding them is kept outside of bpfman. The hook is either
+// a program, which gets the request as JSON on its standard input and writes
+// its response to its standard output, or an HTTPS endpoint the request is
+// POSTed to. Requests describe the program to load:
+//
+// {
+//   "program_type": "xdp",
+//   "name": "pass",
+//   "location": { "image": "quay.io/bpfman-bytecode/xdp_pass:latest" },
+//   "metadata": { "owner": "acme" },
+//   "owner": "alice",
+//   "global_data": { "GLOBAL_u8": "01" },
+//   "map_owner_id": null,
+//   "iface": "eth0",
+//   "priority": 50,
+//   "direction": null,
+//   "cgroup_path": null
+// }
+//
+// Responses allow or deny the load, and may add metadata and global data to
+// it, replacing the values of the same keys:
+//
+// { "allowed": false, "reason": "only signed images may be loaded" }
+// { "allowed": true, "metadata": { "team": "network" } }
+
+use std::{collections::HashMap, process::Stdio, time::Duration};
+
+use anyhow::{anyhow, bail, Context};
+use bpfman_api::config::AdmissionConfig;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use tokio::{io::AsyncWriteExt, process::Command, time::timeout};
+
+use crate::{
+    command::{Location, Program},
+    errors::BpfmanError,
+};
+
+// A load as passed to the admission hook and evaluated by the load policy.
+#[derive(Debug, Serialize, Default)]
+pub(crate) struct LoadRequest {
+    pub(crate) program_type: String,
+    pub(crate) name: String,
+    pub(crate) location: HashMap<&'static str, String>,
+    pub(crate) metadata: HashMap<String, String>,
+    pub(crate) owner: Option<String>,
+    pub(crate) global_data: HashMap<String, String>,
+    pub(crate) map_owner_id: Option<u32>,
+    pub(crate) iface: Option<String>,
+    pub(crate) priority: Option<i32>,
+    pub(crate) direction: Option<String>,
+    pub(crate) cgroup_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdmissionResponse {
+    allowed: bool,
+    #[serde(default)]
+    reason: String,
+    metadata: Option<HashMap<String, String>>,
+    global_data: Option<HashMap<String, String>>,
+}
+
+// Passes the load of program to the admission hook, applying the changes the
+// hook makes to it. Loads are denied if the hook fails, unless the
+// configuration says otherwise.
+pub(crate) async fn admit(
+    config: &AdmissionConfig,
+    program: &mut Program,
+) -> Result<(), BpfmanError> {
+    let request = load_request(program)?;
+    let response = match call_hook(config, &request).await {
+        Ok(response) => response,
+        Err(e) if config.fail_open => {
+            warn!(
+                "Admitting {} without the admission hook: {e:#}",
+                request.name
+            );
+            return Ok(());
+        }
+        Err(e) => {
+            return Err(BpfmanError::AdmissionDenied(format!(
+                "admission hook failed: {e:#}"
+            )))
+        }
+    };
+
+    if !response.allowed {
+        return Err(BpfmanError::AdmissionDenied(response.reason));
+    }
+    debug!("Admission hook admitted {}", request.name);
+
+    let data = program.get_data_mut();
+    if let Some(metadata) = response.metadata {
+        data.set_metadata(metadata)?;
+    }
+    if let Some(global_data) = response.global_data {
+        let global_data = global_data
+            .into_iter()
+            .map(|(name, value)| {
+                hex::decode(&value).map(|v| (name.clone(), v)).map_err(|e| {
+                    BpfmanError::AdmissionDenied(format!(
+                        "admission hook returned invalid global data {name}: {e}"
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        data.set_global_data(global_data)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn load_request(program: &Program) -> Result<LoadRequest, BpfmanError> {
+    let data = program.get_data();
+    let location = match program.location()? {
+        Location::Image(image) => HashMap::from([("image", image.image_url)]),
+        Location::File(path) => HashMap::from([("file", path)]),
+        Location::Url(url) => HashMap::from([("url", url.url)]),
+    };
+    Ok(LoadRequest {
+        program_type: program.kind().to_string(),
+        name: data.get_name()?,
+        location,
+        metadata: data.get_metadata()?,
+        owner: data.get_owner()?,
+        global_data: data
+            .get_global_data()?
+            .into_iter()
+            .map(|(name, value)| (name, hex::encode(value)))
+            .collect(),
+        map_owner_id: data.get_map_owner_id()?,
+        iface: program.if_name().ok(),
+        priority: program.priority().ok(),
+        direction: program.direction()?.map(|d| d.to_string()),
+        cgroup_path: program.cgroup_path()?,
+    })
+}
+
+async fn call_hook(
+    config: &AdmissionConfig,
+    request: &LoadRequest,
+) -> Result<AdmissionResponse, anyhow::Error> {
+    let limit = Duration::from_secs(config.timeout);
+    match (&config.exec, &config.url) {
+        (Some(exec), _) => timeout(limit, exec_hook(exec, request))
+            .await
+            .map_err(|_| anyhow!("{exec} timed out"))?,
+        (None, Some(url)) => post_hook(url, limit, request).await,
+        (None, None) => bail!("no admission hook is configured"),
+    }
+}
+
+async fn exec_hook(exec: &str, request: &LoadRequest) -> Result<AdmissionResponse, anyhow::Error> {
+    let mut child = Command::new(exec)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("unable to run {exec}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&serde_json::to_vec(request)?).await?;
+    }
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        bail!(
+            "{exec} failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    serde_json::from_slice(&output.stdout).with_context(|| format!("invalid response of {exec}"))
+}
+
+async fn post_hook(
+    url: &str,
+    limit: Duration,
+    request: &LoadRequest,
+) -> Result<AdmissionResponse, anyhow::Error> {
+    let client = reqwest::Client::builder().timeout(limit).build()?;
+    let response = client
+        .post(url)
+        .json(request)
+        .send()
+        .await?
+        .error_for_status()?;
+    response
+        .json()
+        .await
+        .with_context(|| format!("invalid response of {url}"))
+}
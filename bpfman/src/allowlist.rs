@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Hosts can restrict the bytecode bpfman loads to an allowlist of the sha256
+// digests of approved bytecode, one per line, optionally prefixed with
+// "sha256:" and followed by a comment, like the file name in the output of
+// sha256sum. Lines starting with "#" are ignored. The allowlist is read on
+// every load so that it can be updated without restarting bpfman.
+
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::errors::BpfmanError;
+
+// Rejects bytecode whose sha256 digest isn't in the allowlist at path.
+pub(crate) fn check_allowed(path: &Path, bytecode: &[u8]) -> Result<(), BpfmanError> {
+    let digest = base16ct::lower::encode_string(&Sha256::digest(bytecode));
+    let allowlist = std::fs::read_to_string(path).map_err(|e| {
+        BpfmanError::Error(format!(
+            "unable to read bytecode allowlist {}: {e}",
+            path.display()
+        ))
+    })?;
+    if allowed_digests(&allowlist).any(|d| d == digest) {
+        Ok(())
+    } else {
+        Err(BpfmanError::BytecodeNotAllowed(digest))
+    }
+}
+
+fn allowed_digests(allowlist: &str) -> impl Iterator<Item = String> + '_ {
+    allowlist
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|digest| digest.trim_start_matches("sha256:").to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_digests() {
+        let allowlist = "# approved bytecode\n\nABCD  xdp_pass.bpf.o\nsha256:ef01\n";
+        assert_eq!(
+            allowed_digests(allowlist).collect::<Vec<_>>(),
+            vec!["abcd".to_string(), "ef01".to_string()]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Requests changing programs or images are recorded in an audit log, apart
+// from the debug log, whether they succeed or not, denied ones included. The
+// log is a file of JSON lines, one per request, which is only appended to:
+//
+// {
+//   "timestamp": "2024-05-02T09:12:44.182+00:00",
+//   "operation": "unload",
+//   "identity": "user alice",
+//   "request": { "id": 6932 },
+//   "outcome": "failure",
+//   "error": "Program 6932 does not exist or was not created by bpfman"
+// }
+//
+// and, or instead, journal entries with a BPFMAN_AUDIT_ field per attribute.
+// Bytecode uploaded with a request is recorded by its size only, and the
+// registry credentials of bytecode images aren't recorded.
+
+use std::{
+    fs::{create_dir_all, File, OpenOptions},
+    io::Write,
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+    sync::Mutex,
+};
+
+use anyhow::Context;
+use bpfman_api::{
+    config::AuditConfig,
+    v1::{
+        bytecode_location::Location, AttachRequest, BytecodeLocation, DeleteMapEntryRequest,
+        DetachRequest, ExportMapsRequest, LoadBatchRequest, LoadRequest, PruneImagesRequest,
+        PublishMapsRequest, PullBytecodeRequest, ReplaceRequest, RollbackRequest,
+        SetPriorityRequest, UnexportMapsRequest, UnloadRequest, UnpublishMapsRequest,
+        UpdateMapEntryRequest,
+    },
+};
+use chrono::{SecondsFormat, Utc};
+use libsystemd::logging::{journal_send, Priority};
+use log::error;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tonic::{Request, Response, Status};
+
+use crate::authz::client_identity;
+
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug)]
+pub(crate) struct Auditor {
+    file: Option<Mutex<File>>,
+    journald: bool,
+}
+
+// A request being carried out, recorded once its outcome is known.
+#[derive(Serialize)]
+pub(crate) struct AuditRecord {
+    timestamp: String,
+    operation: &'static str,
+    identity: String,
+    request: Value,
+    outcome: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response: Option<Value>,
+}
+
+impl Auditor {
+    pub(crate) fn new(config: &AuditConfig) -> anyhow::Result<Self> {
+        let file = config
+            .path
+            .as_ref()
+            .map(|path| {
+                if let Some(dir) = Path::new(path).parent() {
+                    create_dir_all(dir)?;
+                }
+                OpenOptions::new()
+                    .append(true)
+                    .create(true)
+                    .mode(0o600)
+                    .open(path)
+                    .with_context(|| format!("unable to open audit log {path}"))
+            })
+            .transpose()?;
+        Ok(Self {
+            file: file.map(Mutex::new),
+            journald: config.journald,
+        })
+    }
+
+    pub(crate) fn start<T: Audited>(
+        &self,
+        request: &mut Request<T>,
+        operation: &'static str,
+    ) -> AuditRecord {
+        let mut params = request.get_mut().audit_value();
+        redact(&mut params);
+        AuditRecord {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, false),
+            operation,
+            identity: client_identity(request),
+            request: params,
+            outcome: "success",
+            error: None,
+            response: None,
+        }
+    }
+
+    pub(crate) fn finish<R: Serialize>(
+        &self,
+        mut record: AuditRecord,
+        result: &Result<Response<R>, Status>,
+    ) {
+        match result {
+            Ok(response) => record.response = serde_json::to_value(response.get_ref()).ok(),
+            Err(status) => {
+                record.outcome = "failure";
+                record.error = Some(status.message().to_string());
+            }
+        }
+
+        if let Some(file) = &self.file {
+            let line = serde_json::to_string(&record).unwrap_or_default();
+            let mut file = file.lock().unwrap();
+            if let Err(e) = writeln!(file, "{line}") {
+                error!("Unable to write audit log: {e}");
+            }
+        }
+        if self.journald {
+            let message = format!(
+                "{} by {}: {}",
+                record.operation, record.identity, record.outcome
+            );
+            let fields = [
+                ("BPFMAN_AUDIT_OPERATION", record.operation.to_string()),
+                ("BPFMAN_AUDIT_IDENTITY", record.identity.clone()),
+                ("BPFMAN_AUDIT_REQUEST", record.request.to_string()),
+                ("BPFMAN_AUDIT_OUTCOME", record.outcome.to_string()),
+                (
+                    "BPFMAN_AUDIT_ERROR",
+                    record.error.clone().unwrap_or_default(),
+                ),
+            ];
+            if let Err(e) = journal_send(Priority::Notice, &message, fields.into_iter()) {
+                error!("Unable to write audit journal entry: {e}");
+            }
+        }
+    }
+}
+
+// Requests as they are recorded. Bytecode uploaded with a request is taken
+// out while the request is serialized and recorded by its size, as it can be
+// as large as the upload limit.
+pub(crate) trait Audited: Serialize {
+    fn audit_value(&mut self) -> Value {
+        serde_json::to_value(&*self).unwrap_or_default()
+    }
+}
+
+impl Audited for LoadRequest {
+    fn audit_value(&mut self) -> Value {
+        let bytecode = self.bytecode.take();
+        let mut value = serde_json::to_value(&*self).unwrap_or_default();
+        value["bytecode"] = bytecode_value(bytecode.as_ref());
+        self.bytecode = bytecode;
+        value
+    }
+}
+
+impl Audited for LoadBatchRequest {
+    fn audit_value(&mut self) -> Value {
+        let mut requests = std::mem::take(&mut self.requests);
+        let mut value = serde_json::to_value(&*self).unwrap_or_default();
+        value["requests"] = requests.iter_mut().map(|r| r.audit_value()).collect();
+        self.requests = requests;
+        value
+    }
+}
+
+impl Audited for ReplaceRequest {
+    fn audit_value(&mut self) -> Value {
+        let bytecode = self.bytecode.take();
+        let mut value = serde_json::to_value(&*self).unwrap_or_default();
+        value["bytecode"] = bytecode_value(bytecode.as_ref());
+        self.bytecode = bytecode;
+        value
+    }
+}
+
+impl Audited for AttachRequest {}
+impl Audited for DeleteMapEntryRequest {}
+impl Audited for DetachRequest {}
+impl Audited for ExportMapsRequest {}
+impl Audited for PruneImagesRequest {}
+impl Audited for PublishMapsRequest {}
+impl Audited for PullBytecodeRequest {}
+impl Audited for RollbackRequest {}
+impl Audited for SetPriorityRequest {}
+impl Audited for UnexportMapsRequest {}
+impl Audited for UnloadRequest {}
+impl Audited for UnpublishMapsRequest {}
+impl Audited for UpdateMapEntryRequest {}
+
+fn bytecode_value(bytecode: Option<&BytecodeLocation>) -> Value {
+    match bytecode.and_then(|b| b.location.as_ref()) {
+        Some(Location::Data(data)) => {
+            json!({ "location": { "data": format!("{} bytes", data.len()) } })
+        }
+        _ => serde_json::to_value(bytecode).unwrap_or_default(),
+    }
+}
+
+// Replaces byte arrays named data by their size, and the credentials images
+// are pulled with by a placeholder.
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                match field {
+                    Value::Array(bytes) if name == "data" => {
+                        *field = Value::String(format!("{} bytes", bytes.len()))
+                    }
+                    Value::String(_) if name == "username" || name == "password" => {
+                        *field = Value::String(REDACTED.to_string())
+                    }
+                    _ => redact(field),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bpfman_api::v1::BytecodeImage;
+
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let mut request = json!({
+            "name": "pass",
+            "bytecode": { "location": { "data": [127, 69, 76, 70] } },
+            "global_data": { "GLOBAL_u8": [1] },
+        });
+        redact(&mut request);
+        assert_eq!(request["bytecode"]["location"]["data"], "4 bytes");
+        assert_eq!(request["global_data"]["GLOBAL_u8"], json!([1]));
+    }
+
+    #[test]
+    fn test_audit_value_uploaded_bytecode() {
+        let mut request = LoadBatchRequest {
+            requests: vec![LoadRequest {
+                name: "pass".to_string(),
+                bytecode: Some(BytecodeLocation {
+                    location: Some(Location::Data(vec![0; 4096])),
+                }),
+                ..Default::default()
+            }],
+        };
+        let params = request.audit_value();
+        assert_eq!(params["requests"][0]["name"], "pass");
+        assert_eq!(
+            params["requests"][0]["bytecode"]["location"]["data"],
+            "4096 bytes"
+        );
+        // The request is left as it was.
+        assert_eq!(
+            request.requests[0].bytecode.as_ref().unwrap().location,
+            Some(Location::Data(vec![0; 4096]))
+        );
+    }
+
+    #[test]
+    fn test_redact_image_credentials() {
+        let request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::Image(BytecodeImage {
+                    url: "quay.io/bpfman-bytecode/xdp_pass:latest".to_string(),
+                    image_pull_policy: 0,
+                    username: Some("someone".to_string()),
+                    password: Some("secret".to_string()),
+                })),
+            }),
+            ..Default::default()
+        };
+        let mut params = serde_json::to_value(&request).unwrap();
+        redact(&mut params);
+        let image = &params["bytecode"]["location"]["image"];
+        assert_eq!(image["url"], "quay.io/bpfman-bytecode/xdp_pass:latest");
+        assert_eq!(image["username"], REDACTED);
+        assert_eq!(image["password"], REDACTED);
+        let line = serde_json::to_string(&params).unwrap();
+        assert!(!line.contains("someone"));
+        assert!(!line.contains("secret"));
+
+        let mut params = json!({ "url": "quay.io/bpfman/xdp-dispatcher:v2", "username": null });
+        redact(&mut params);
+        assert_eq!(params["username"], Value::Null);
+    }
+}
@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Clients can be limited to the operations, program types, interfaces and
+// registries of a role. Requests on existing programs, like unloads or map
+// updates, are limited to the programs the role could have loaded and
+// attached. Clients on Unix sockets are identified by the user of their
+// process, as reported by the kernel, and clients on TLS endpoints and the
+// REST gateway by the common name and the DNS and URI subject alternative
+// names of their certificate. Requests without either only get the default
+// role.
+
+use std::{fmt, sync::Arc};
+
+use anyhow::bail;
+use bpfman_api::config::{AuthorizationConfig, RoleBinding, RoleConfig};
+use oci_distribution::Reference;
+use tokio_rustls::rustls;
+use tonic::{transport::server::UdsConnectInfo, Request, Status};
+use x509_cert::{
+    der::{asn1::ObjectIdentifier, Decode},
+    ext::pkix::{name::GeneralName, SubjectAltName},
+    Certificate,
+};
+
+use crate::{
+    command::{Location, Program},
+    rpc::username,
+    utils::glob_match,
+};
+
+const COMMON_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.3");
+
+#[derive(Debug)]
+pub(crate) struct Authorizer {
+    config: AuthorizationConfig,
+}
+
+impl Authorizer {
+    pub(crate) fn new(config: AuthorizationConfig) -> anyhow::Result<Self> {
+        let roles = config
+            .default_role
+            .iter()
+            .chain(config.bindings.iter().map(|b| &b.role));
+        for role in roles {
+            if !config.roles.contains_key(role) {
+                bail!("authorization role {role} isn't defined");
+            }
+        }
+        Ok(Self { config })
+    }
+
+    // Returns what the client of request may do if its role allows operation.
+    pub(crate) fn authorize<T>(
+        &self,
+        request: &Request<T>,
+        operation: &str,
+    ) -> Result<Grant, Status> {
+        self.authorize_client(&Client::of(request), operation)
+    }
+
+    // Like authorize(), for clients of the fd handover sockets, which are
+    // identified by the uid of their process.
+    pub(crate) fn authorize_uid(&self, uid: u32, operation: &str) -> Result<Grant, Status> {
+        let client = Client {
+            user: Some(username(uid)),
+            uid: Some(uid),
+            identities: vec![],
+        };
+        self.authorize_client(&client, operation)
+    }
+
+    fn authorize_client(&self, client: &Client, operation: &str) -> Result<Grant, Status> {
+        let name = self
+            .config
+            .bindings
+            .iter()
+            .find(|b| client.matches(b))
+            .map(|b| &b.role)
+            .or(self.config.default_role.as_ref())
+            .ok_or_else(|| Status::permission_denied(format!("{client} has no role")))?;
+        let role = &self.config.roles[name];
+        if !matches_any(&role.operations, operation) {
+            return Err(Status::permission_denied(format!(
+                "role {name} of {client} doesn't allow {operation}"
+            )));
+        }
+        Ok(Grant(Some((name.clone(), role.clone()))))
+    }
+}
+
+// Describes the client of request, as the audit log records it.
+pub(crate) fn client_identity<T>(request: &Request<T>) -> String {
+    Client::of(request).to_string()
+}
+
+// The limits of the role of a client, if authorization is configured.
+pub(crate) struct Grant(Option<(String, RoleConfig)>);
+
+impl Grant {
+    pub(crate) fn unlimited() -> Self {
+        Grant(None)
+    }
+
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub(crate) fn check_program(&self, program: &Program) -> Result<(), Status> {
+        let Some((name, role)) = &self.0 else {
+            return Ok(());
+        };
+        let program_type = program.kind().to_string();
+        if !allows(&role.program_types, &program_type) {
+            return Err(Status::permission_denied(format!(
+                "role {name} doesn't allow {program_type} programs"
+            )));
+        }
+        if let Ok(iface) = program.if_name() {
+            self.check_iface(&iface)?;
+        }
+        let location = program
+            .location()
+            .map_err(|e| Status::aborted(format!("failed to get program location: {e}")))?;
+        self.check_location(&location)
+    }
+
+    pub(crate) fn check_iface(&self, iface: &str) -> Result<(), Status> {
+        match &self.0 {
+            Some((name, role)) if !allows(&role.interfaces, iface) => Err(
+                Status::permission_denied(format!("role {name} doesn't allow interface {iface}")),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    // Roles limited to registries may only load images from them.
+    pub(crate) fn check_location(&self, location: &Location) -> Result<(), Status> {
+        let Some((name, role)) = &self.0 else {
+            return Ok(());
+        };
+        if role.registries.is_none() {
+            return Ok(());
+        }
+        let registry = match location {
+            Location::Image(image) => image
+                .image_url
+                .parse::<Reference>()
+                .map(|r| r.registry().to_string())
+                .ok(),
+            _ => None,
+        };
+        match registry {
+            Some(registry) if allows(&role.registries, &registry) => Ok(()),
+            Some(registry) => Err(Status::permission_denied(format!(
+                "role {name} doesn't allow images from {registry}"
+            ))),
+            None => Err(Status::permission_denied(format!(
+                "role {name} only allows images from its registries"
+            ))),
+        }
+    }
+}
+
+// The certificate chain a client of the REST gateway presented, which its
+// requests carry as an extension since they don't come from a tonic server.
+#[derive(Clone)]
+pub(crate) struct PeerCerts(pub(crate) Arc<Vec<rustls::Certificate>>);
+
+#[derive(Default)]
+struct Client {
+    user: Option<String>,
+    uid: Option<u32>,
+    identities: Vec<String>,
+}
+
+impl Client {
+    fn of<T>(request: &Request<T>) -> Self {
+        let uid = request
+            .extensions()
+            .get::<UdsConnectInfo>()
+            .and_then(|info| info.peer_cred)
+            .map(|cred| cred.uid());
+        let identities = request
+            .peer_certs()
+            .and_then(|certs| certs.first().map(|cert| cert_identities(cert.get_ref())))
+            .or_else(|| {
+                let certs = request.extensions().get::<PeerCerts>()?;
+                certs.0.first().map(|cert| cert_identities(&cert.0))
+            })
+            .unwrap_or_default();
+        Client {
+            user: uid.map(username),
+            uid,
+            identities,
+        }
+    }
+
+    fn matches(&self, binding: &RoleBinding) -> bool {
+        self.user
+            .as_ref()
+            .is_some_and(|user| matches_any(&binding.users, user))
+            || self.uid.is_some_and(|uid| binding.uids.contains(&uid))
+            || self
+                .identities
+                .iter()
+                .any(|identity| matches_any(&binding.identities, identity))
+    }
+}
+
+impl fmt::Display for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.user, self.identities.first()) {
+            (Some(user), _) => write!(f, "user {user}"),
+            (None, Some(identity)) => write!(f, "client {identity}"),
+            (None, None) => write!(f, "anonymous client"),
+        }
+    }
+}
+
+// Returns the common names and the DNS and URI subject alternative names of
+// the DER encoded certificate.
+fn cert_identities(der: &[u8]) -> Vec<String> {
+    let Ok(cert) = Certificate::from_der(der) else {
+        return vec![];
+    };
+    let tbs = &cert.tbs_certificate;
+    let mut identities: Vec<String> = tbs
+        .subject
+        .0
+        .iter()
+        .flat_map(|rdn| rdn.0.iter())
+        .filter(|attr| attr.oid == COMMON_NAME)
+        .filter_map(|attr| std::str::from_utf8(attr.value.value()).ok())
+        .map(|cn| cn.to_string())
+        .collect();
+    if let Ok(Some((_, san))) = tbs.get::<SubjectAltName>() {
+        identities.extend(san.0.iter().filter_map(|name| match name {
+            GeneralName::DnsName(n) | GeneralName::UniformResourceIdentifier(n) => {
+                Some(n.to_string())
+            }
+            _ => None,
+        }));
+    }
+    identities
+}
+
+fn matches_any(patterns: &[String], value: &str) -> bool {
+    patterns
+        .iter()
+        .any(|p| glob_match(p.as_bytes(), value.as_bytes()))
+}
+
+fn allows(limit: &Option<Vec<String>>, value: &str) -> bool {
+    limit
+        .as_ref()
+        .map_or(true, |patterns| matches_any(patterns, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_client_matches() {
+        let binding = RoleBinding {
+            role: "network".to_string(),
+            users: vec!["alice".to_string()],
+            uids: vec![1001],
+            identities: vec!["*.netops.example.com".to_string()],
+        };
+        let client = |user: Option<&str>, uid: Option<u32>, identity: Option<&str>| Client {
+            user: user.map(|u| u.to_string()),
+            uid,
+            identities: identity.map(|i| vec![i.to_string()]).unwrap_or_default(),
+        };
+
+        assert!(client(Some("alice"), Some(1000), None).matches(&binding));
+        assert!(client(Some("1001"), Some(1001), None).matches(&binding));
+        assert!(client(None, None, Some("a.netops.example.com")).matches(&binding));
+        assert!(!client(Some("bob"), Some(1002), None).matches(&binding));
+        assert!(!client(None, None, Some("netops.example.org")).matches(&binding));
+        assert!(!Client::default().matches(&binding));
+    }
+
+    #[test]
+    fn test_grant_check_iface() {
+        let role = RoleConfig {
+            operations: vec!["*".to_string()],
+            program_types: None,
+            interfaces: Some(vec!["eth*".to_string()]),
+            registries: None,
+        };
+        let grant = Grant(Some(("network".to_string(), role)));
+        assert!(grant.check_iface("eth0").is_ok());
+        assert!(grant.check_iface("lo").is_err());
+        assert!(Grant::unlimited().check_iface("lo").is_ok());
+    }
+
+    #[test]
+    fn test_authorize_uid() {
+        let role = |operations: &[&str]| RoleConfig {
+            operations: operations.iter().map(|o| o.to_string()).collect(),
+            program_types: None,
+            interfaces: None,
+            registries: None,
+        };
+        let authorizer = Authorizer::new(AuthorizationConfig {
+            default_role: Some("viewer".to_string()),
+            roles: HashMap::from([
+                ("viewer".to_string(), role(&["get"])),
+                ("network".to_string(), role(&["map_fd"])),
+            ]),
+            bindings: vec![RoleBinding {
+                role: "network".to_string(),
+                users: vec![],
+                uids: vec![1001],
+                identities: vec![],
+            }],
+        })
+        .unwrap();
+
+        let grant = authorizer.authorize_uid(1001, "map_fd").unwrap();
+        assert!(!grant.is_unlimited());
+        assert!(authorizer.authorize_uid(1002, "map_fd").is_err());
+        assert!(authorizer.authorize_uid(1002, "get").is_ok());
+    }
+}
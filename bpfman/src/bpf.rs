@@ -2,25 +2,58 @@
 // Copyright Authors of bpfman
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap, HashSet},
     convert::TryInto,
+    os::{
+        fd::{AsFd, BorrowedFd},
+        unix::fs::MetadataExt,
+    },
     path::{Path, PathBuf},
+    slice,
 };
 
 use aya::{
+    maps::{Map, MapData},
     programs::{
-        kprobe::KProbeLink, links::FdLink, loaded_programs, trace_point::TracePointLink,
-        uprobe::UProbeLink, KProbe, TracePoint, UProbe,
+        cgroup_device::CgroupDeviceLink,
+        cgroup_skb::CgroupSkbLink,
+        cgroup_sock_addr::CgroupSockAddrLink,
+        cgroup_sockopt::CgroupSockoptLink,
+        cgroup_sysctl::CgroupSysctlLink,
+        extension::ExtensionLink,
+        flow_dissector::FlowDissectorLink,
+        iter::IterLink,
+        kprobe::KProbeLink,
+        links::FdLink,
+        loaded_programs,
+        lsm::LsmLink,
+        perf_event::{
+            perf_hw_id, perf_sw_ids, PerfEventLink, PerfEventScope, PerfTypeId, SamplePolicy,
+        },
+        raw_trace_point::RawTracePointLink,
+        sk_lookup::SkLookupLink,
+        sk_skb::SkSkbKind,
+        sock_ops::SockOpsLink,
+        tp_btf::BtfTracePointLink,
+        trace_point::TracePointLink,
+        uprobe::UProbeLink,
+        BtfTracePoint, CgroupDevice, CgroupSkb, CgroupSkbAttachType, CgroupSockAddr,
+        CgroupSockAddrAttachType, CgroupSockopt, CgroupSockoptAttachType, CgroupSysctl, Extension,
+        FlowDissector, Iter, KProbe, Lsm, PerfEvent, ProbeKind, ProgramFd, ProgramInfo,
+        RawTracePoint, SchedClassifier, SkLookup, SkMsg, SkSkb, SockOps, SocketFilter, TracePoint,
+        UProbe,
     },
-    BpfLoader,
+    util::online_cpus,
+    BpfLoader, Btf,
 };
 use bpfman_api::{
-    config::Config,
+    config::{Config, ImageCacheConfig},
     util::directories::*,
     ProbeType::{self, *},
-    ProgramType,
+    ProgramType, TcProceedOn,
 };
-use log::{debug, info};
+use log::{debug, info, warn};
+use opentelemetry::{trace::FutureExt, Context};
 use tokio::{
     fs::{create_dir_all, remove_dir_all},
     select,
@@ -32,36 +65,118 @@ use tokio::{
 };
 
 use crate::{
-    command::{BpfMap, Command, Direction, Program, ProgramData, PullBytecodeArgs, UnloadArgs},
+    admission::{admit, load_request},
+    allowlist::check_allowed,
+    command::{
+        attachment_tree_name, attachment_tree_prefix, AttachArgs, BpfMap, Capabilities,
+        CgroupDeviceProgram, CgroupSkbProgram, CgroupSockAddrProgram, CgroupSockoptProgram,
+        CgroupSysctlProgram, Command, DetachArgs, Direction, ExtensionProgram,
+        FlowDissectorProgram, KprobeProgram, LinkEvent, ListArgs, ListImagesArgs, Location,
+        MapPermissions, MapPinPolicy, NetkitProgram, PerfEventProgram, PerfEventType, Program,
+        ProgramData, ProgramPage, PruneImagesArgs, PullBytecodeArgs, RawTracepointProgram,
+        SetPriorityArgs, SkLookupProgram, SkMsgProgram, SkSkbAttachType, SkSkbProgram,
+        SockAddrAttachPoint, SockOpsProgram, SockoptAttachType, TcProgram, TracepointProgram,
+        UnloadArgs, UsdtProgram,
+    },
+    container,
     errors::BpfmanError,
-    multiprog::{Dispatcher, DispatcherId, DispatcherInfo, TcDispatcher, XdpDispatcher},
-    oci_utils::image_manager::Command as ImageManagerCommand,
-    utils::{bytes_to_string, get_ifindex, set_dir_permissions, should_map_be_pinned},
+    events::{Event, EventKind, EventLog, EVENTS_TREE},
+    external_btf::set_external_btf,
+    map_export,
+    maps::{initialize_maps, maps_memory},
+    multiprog::{
+        netkit::{self, check_netkit_supported},
+        tcx::{self, check_tcx_supported},
+        Dispatcher, DispatcherId, DispatcherInfo, TcDispatcher, XdpDispatcher,
+    },
+    oci_utils::image_manager::{Command as ImageManagerCommand, ImageInfo},
+    probe::check_kernel_requirements,
+    sock_map::{self, SockMapProgram},
+    telemetry::{child_span, traced},
+    usdt,
+    utils::{
+        bytes_to_string, bytes_to_u32, check_bpf_lsm_enabled, check_netfilter_supported,
+        get_ifindex, get_ifindex_in, get_netns_id, is_iface_glob, kernel_release,
+        resolve_iface_glob, resolve_kernel_functions, set_dir_owner, set_dir_permissions,
+        sled_get_option, sled_insert,
+    },
+    verifier::{set_verifier_log_level, verifier_error},
     ROOT_DB,
 };
 
 const MAPS_MODE: u32 = 0o0660;
+// Database tree of the names maps are published under, see publish_maps().
+pub(crate) const PUBLISHED_MAPS_TREE: &str = "published_maps";
+// Database tree of the directories maps are exported to, see export_maps().
+pub(crate) const MAP_EXPORTS_TREE: &str = "map_exports";
 
 pub(crate) struct BpfManager {
     config: Config,
     dispatchers: DispatcherMap,
     programs: ProgramMap,
     maps: HashMap<u32, BpfMap>,
-    commands: Receiver<Command>,
+    published_maps: sled::Tree,
+    map_exports: sled::Tree,
+    events: EventLog,
+    commands: Receiver<(Command, Context)>,
     image_manager: Sender<ImageManagerCommand>,
+    policy: Option<PolicyEngine>,
 }
 
 pub(crate) struct ProgramMap {
     programs: HashMap<u32, Program>,
+    // Attachments of XDP and TC programs to further interfaces, keyed by the
+    // name of their database tree. They are part of the dispatchers on those
+    // interfaces like any other program.
+    attachments: HashMap<String, Program>,
 }
 
 impl ProgramMap {
     fn new() -> Self {
         ProgramMap {
             programs: HashMap::new(),
+            attachments: HashMap::new(),
         }
     }
 
+    fn insert_attachment(&mut self, name: String, attachment: Program) -> Option<Program> {
+        self.attachments.insert(name, attachment)
+    }
+
+    fn remove_attachment(&mut self, name: &str) -> Option<Program> {
+        self.attachments.remove(name)
+    }
+
+    // Returns the names of the attachments of program id.
+    fn attachment_names(&self, id: u32) -> Vec<String> {
+        let prefix = attachment_tree_prefix(id);
+        self.attachments
+            .keys()
+            .filter(|name| name.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    // Returns the name of the attachment of program id to iface.
+    fn attachment_name(
+        &self,
+        id: u32,
+        iface: &str,
+        direction: Option<Direction>,
+    ) -> Result<String, BpfmanError> {
+        self.attachment_names(id)
+            .into_iter()
+            .find(|name| {
+                self.attachments.get(name).is_some_and(|a| {
+                    a.if_name().ok().as_deref() == Some(iface)
+                        && a.direction().ok() == Some(direction)
+                })
+            })
+            .ok_or(BpfmanError::Error(format!(
+                "program {id} has no attachment to {iface}"
+            )))
+    }
+
     fn insert(&mut self, id: u32, prog: Program) -> Option<Program> {
         self.programs.insert(id, prog)
     }
@@ -83,35 +198,152 @@ impl ProgramMap {
         program_type: &'a ProgramType,
         if_index: &'a Option<u32>,
         direction: &'a Option<Direction>,
+        nsid: &'a Option<u64>,
     ) -> impl Iterator<Item = &'a mut Program> {
-        self.programs.values_mut().filter(|p| {
-            p.kind() == *program_type
-                && p.if_index().unwrap() == *if_index
-                && p.direction().unwrap() == *direction
+        // Programs attached with tcx links aren't part of any dispatcher, and
+        // detached programs were taken out of theirs.
+        self.programs
+            .values_mut()
+            .chain(self.attachments.values_mut())
+            .filter(|p| {
+                p.kind() == *program_type
+                    && p.if_index().unwrap() == *if_index
+                    && p.direction().unwrap() == *direction
+                    && p.nsid().unwrap() == *nsid
+                    && !p.is_tcx()
+                    && !p.is_detached()
+            })
+    }
+
+    // Returns the id of the tcx program that the given tcx program must be
+    // attached in front of, based on the positions already set, if any.
+    fn next_tcx_program(&self, program: &TcProgram) -> Result<Option<u32>, BpfmanError> {
+        let if_index = program.get_if_index()?;
+        let direction = program.get_direction()?;
+        let nsid = program.get_nsid()?;
+        let next = program.get_current_position()?.map(|pos| pos + 1);
+        Ok(self.programs.iter().find_map(|(id, p)| match p {
+            Program::Tc(p)
+                if p.get_tcx().ok().flatten() == Some(true)
+                    && p.get_data().get_detached().ok() == Some(false)
+                    && p.get_if_index().ok().flatten() == if_index
+                    && p.get_direction().ok() == Some(direction)
+                    && p.get_nsid().ok().flatten() == nsid
+                    && p.get_current_position().ok().flatten() == next =>
+            {
+                Some(*id)
+            }
+            _ => None,
+        }))
+    }
+
+    // Returns the id of the netkit program that the given netkit program must
+    // be attached in front of: the first one at the same position of the
+    // device with a higher priority value. Programs with the same priority run
+    // in the order they were attached.
+    fn next_netkit_program(&self, program: &NetkitProgram) -> Result<Option<u32>, BpfmanError> {
+        let if_index = program.get_if_index()?;
+        let position = program.get_position()?;
+        let priority = program.get_priority()?;
+        Ok(self
+            .programs
+            .iter()
+            .filter_map(|(id, p)| match p {
+                Program::Netkit(p)
+                    if p.get_if_index().ok().flatten() == if_index
+                        && p.get_position().ok() == Some(position) =>
+                {
+                    p.get_priority()
+                        .ok()
+                        .filter(|p| *p > priority)
+                        .map(|p| (p, *id))
+                }
+                _ => None,
+            })
+            .min()
+            .map(|(_, id)| id))
+    }
+
+    // Returns true if an sk_skb program of the given attach type is attached
+    // to the sockmap pinned at map_path.
+    fn sk_skb_attached(&self, map_path: &Path, attach_type: SkSkbAttachType) -> bool {
+        self.programs.values().any(|p| match p {
+            Program::SkSkb(p) => {
+                p.get_attach_type().ok() == Some(attach_type)
+                    && sk_skb_map_path(p).ok().flatten().as_deref() == Some(map_path)
+            }
+            _ => false,
         })
     }
 
-    // Adds a new program and sets the positions of programs that are to be attached via a dispatcher.
+    // Rejects a stream_verdict program on a sockmap shared with other programs
+    // until a stream_parser is attached to it, which remove_program() then
+    // keeps for as long as the verdict. A sockmap of the program's own, with
+    // no map_path yet, can't have a parser, and the kernel runs verdict
+    // programs without one.
+    fn check_sk_skb_order(
+        &self,
+        map_path: Option<&Path>,
+        attach_type: SkSkbAttachType,
+    ) -> Result<(), BpfmanError> {
+        match map_path {
+            Some(path)
+                if attach_type == SkSkbAttachType::StreamVerdict
+                    && !self.sk_skb_attached(path, SkSkbAttachType::StreamParser) =>
+            {
+                Err(BpfmanError::Error(format!(
+                    "a stream_parser program must be attached to {} before a stream_verdict program",
+                    path.display()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Returns the id of the flow dissector program attached to the network
+    // namespace at netns_path, if any.
+    fn flow_dissector_in_netns(&self, netns_path: &str) -> Option<u32> {
+        let netns = netns_id(netns_path).ok()?;
+        self.programs.iter().find_map(|(id, p)| match p {
+            Program::FlowDissector(p) => p
+                .get_netns_path()
+                .ok()
+                .and_then(|path| netns_id(&path).ok())
+                .filter(|n| *n == netns)
+                .map(|_| *id),
+            _ => None,
+        })
+    }
+
+    // Adds new programs for the same hook and sets the positions of programs that are to be attached
+    // via a dispatcher, or via tcx links for TC programs using tcx. Detached programs don't hold a position.
     // Positions are set based on order of priority. Ties are broken based on:
     // - Already attached programs are preferred
     // - Program name. Lowest lexical order wins.
-    fn add_and_set_program_positions(&mut self, program: &mut Program) {
+    fn add_and_set_program_positions(&mut self, new_programs: &mut [Program]) {
+        let program = &new_programs[0];
         let program_type = program.kind();
         let if_index = program.if_index().unwrap();
         let direction = program.direction().unwrap();
+        let nsid = program.nsid().unwrap();
+        let tcx = program.is_tcx();
 
         let mut extensions = self
             .programs
             .values_mut()
+            .chain(self.attachments.values_mut())
             .filter(|p| {
                 p.kind() == program_type
                     && p.if_index().unwrap() == if_index
                     && p.direction().unwrap() == direction
+                    && p.nsid().unwrap() == nsid
+                    && p.is_tcx() == tcx
+                    && !p.is_detached()
             })
             .collect::<Vec<&mut Program>>();
 
-        // add program we're loading
-        extensions.push(program);
+        // add the programs we're loading
+        extensions.extend(new_programs.iter_mut());
 
         extensions.sort_by_key(|b| {
             (
@@ -125,7 +357,8 @@ impl ProgramMap {
         }
     }
 
-    // Sets the positions of programs that are to be attached via a dispatcher.
+    // Sets the positions of programs that are to be attached via a dispatcher,
+    // or via tcx links when tcx is set. Detached programs don't hold a position.
     // Positions are set based on order of priority. Ties are broken based on:
     // - Already attached programs are preferred
     // - Program name. Lowest lexical order wins.
@@ -134,14 +367,20 @@ impl ProgramMap {
         program_type: ProgramType,
         if_index: u32,
         direction: Option<Direction>,
+        nsid: Option<u64>,
+        tcx: bool,
     ) {
         let mut extensions = self
             .programs
             .values_mut()
+            .chain(self.attachments.values_mut())
             .filter(|p| {
                 p.kind() == program_type
                     && p.if_index().unwrap() == Some(if_index)
                     && p.direction().unwrap() == direction
+                    && p.nsid().unwrap() == nsid
+                    && p.is_tcx() == tcx
+                    && !p.is_detached()
             })
             .collect::<Vec<&mut Program>>();
 
@@ -183,6 +422,23 @@ impl DispatcherMap {
         self.dispatchers.insert(id, dis)
     }
 
+    fn contains(&self, id: &DispatcherId) -> bool {
+        self.dispatchers.contains_key(id)
+    }
+
+    /// Returns the ids of the dispatchers on the interface with the given index
+    /// in bpfman's network namespace.
+    fn on_interface(&self, if_index: u32) -> Vec<DispatcherId> {
+        self.dispatchers
+            .keys()
+            .filter(|did| match did {
+                DispatcherId::Xdp(DispatcherInfo(i, _, nsid))
+                | DispatcherId::Tc(DispatcherInfo(i, _, nsid)) => *i == if_index && nsid.is_none(),
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Returns the number of extension programs currently attached to the dispatcher that
     /// would be used to attach the provided [`Program`].
     fn attached_programs(&self, did: &DispatcherId) -> usize {
@@ -197,14 +453,27 @@ impl DispatcherMap {
 impl BpfManager {
     pub(crate) fn new(
         config: Config,
-        commands: Receiver<Command>,
+        commands: Receiver<(Command, Context)>,
         image_manager: Sender<ImageManagerCommand>,
     ) -> Self {
         Self {
+            policy: config.policy.as_ref().map(|p| PolicyEngine::new(&p.path)),
+            events: EventLog::new(
+                ROOT_DB
+                    .open_tree(EVENTS_TREE)
+                    .expect("Unable to open events database tree"),
+                config.events.max_events,
+            ),
             config,
             dispatchers: DispatcherMap::new(),
             programs: ProgramMap::new(),
             maps: HashMap::new(),
+            published_maps: ROOT_DB
+                .open_tree(PUBLISHED_MAPS_TREE)
+                .expect("Unable to open published maps database tree"),
+            map_exports: ROOT_DB
+                .open_tree(MAP_EXPORTS_TREE)
+                .expect("Unable to open map exports database tree"),
             commands,
             image_manager,
         }
@@ -213,8 +482,9 @@ impl BpfManager {
     pub(crate) async fn rebuild_state(&mut self) -> Result<(), anyhow::Error> {
         debug!("BpfManager::rebuild_state()");
 
-        // Rebuild dispatchers after rebuilding programs.
+        // Rebuild dispatchers after rebuilding programs and their attachments.
         let mut dispatchers = Vec::new();
+        let mut attachments = Vec::new();
 
         // re-build programs from database, cache dispatchers to rebuild after.
         for tree_name in ROOT_DB.tree_names() {
@@ -228,6 +498,11 @@ impl BpfManager {
                 continue;
             }
 
+            if name.starts_with("attachment_") {
+                attachments.push(name.clone());
+                continue;
+            }
+
             let id = match name.parse::<u32>() {
                 Ok(id) => id,
                 Err(_) => {
@@ -246,6 +521,9 @@ impl BpfManager {
                         .set_program_bytes(self.image_manager.clone())
                         .await?;
                     self.rebuild_map_entry(id, &mut program).await;
+                    if let Err(e) = reattach_program(&mut program) {
+                        warn!("unable to reattach program {id}: {e}");
+                    }
                     self.programs.insert(id, program);
                 }
                 Err(_) => {
@@ -256,6 +534,32 @@ impl BpfManager {
             }
         }
 
+        for name in attachments {
+            let tree = ROOT_DB
+                .open_tree(&name)
+                .expect("unable to open database tree");
+
+            // Attachments are named after the program they belong to, drop
+            // any that outlived it.
+            let program = name
+                .split('_')
+                .nth(1)
+                .and_then(|id| id.parse::<u32>().ok())
+                .filter(|id| self.programs.get(id).is_some())
+                .map(|id| Program::new_from_db(id, tree));
+            match program {
+                Some(Ok(attachment)) => {
+                    debug!("rebuilding state for attachment {name}");
+                    self.programs.insert_attachment(name, attachment);
+                }
+                _ => {
+                    ROOT_DB
+                        .drop_tree(&name)
+                        .expect("unable to remove broken attachment tree");
+                }
+            }
+        }
+
         for dispatcher in dispatchers {
             let tree = ROOT_DB
                 .open_tree(dispatcher.clone())
@@ -268,19 +572,21 @@ impl BpfManager {
             if dispatcher.contains("xdp_dispatcher") {
                 let dispatcher = XdpDispatcher::new_from_db(tree);
                 let if_index = dispatcher.get_ifindex()?;
+                let nsid = dispatcher.get_nsid()?;
                 debug!("rebuilding state for xdp dispatcher {}", if_index);
 
                 self.dispatchers.insert(
-                    DispatcherId::Xdp(DispatcherInfo(if_index, None)),
+                    DispatcherId::Xdp(DispatcherInfo(if_index, None, nsid)),
                     Dispatcher::Xdp(dispatcher),
                 );
             } else {
                 let dispatcher = TcDispatcher::new_from_db(tree);
                 let if_index = dispatcher.get_ifindex()?;
                 let direction = dispatcher.get_direction()?;
+                let nsid = dispatcher.get_nsid()?;
                 debug!("rebuilding state for tc dispatcher {}", if_index);
 
-                let did = DispatcherId::Tc(DispatcherInfo(if_index, Some(direction)));
+                let did = DispatcherId::Tc(DispatcherInfo(if_index, Some(direction), nsid));
 
                 self.dispatchers
                     .insert(did.clone(), Dispatcher::Tc(dispatcher));
@@ -290,11 +596,17 @@ impl BpfManager {
                     if_index,
                     ProgramType::Tc,
                     Some(direction),
+                    nsid,
                 )
                 .await?;
             }
         }
 
+        // Interfaces of pending programs, or matching tracked globs, may have
+        // been created while bpfman wasn't running.
+        self.attach_pending_programs().await;
+        self.attach_new_glob_matches().await;
+
         Ok(())
     }
 
@@ -302,6 +614,46 @@ impl BpfManager {
         &mut self,
         mut program: Program,
     ) -> Result<Program, BpfmanError> {
+        traced("admit load", self.admit_load(&mut program)).await?;
+        self.check_program_quota(&program, 1)?;
+        check_kernel_requirements(&program)?;
+
+        if let Program::Tc(ref mut p) = program {
+            if p.get_tcx()?.is_none() {
+                let use_tcx = self.config.tc.map(|c| c.use_tcx).unwrap_or(false);
+                p.set_tcx(use_tcx)?;
+            }
+            // The egress side is an attachment, which tcx programs can't have.
+            if p.get_both_directions()? && p.get_tcx()? == Some(true) {
+                return Err(BpfmanError::Error(
+                    "tcx programs can't be attached to both directions".to_string(),
+                ));
+            }
+        }
+
+        if program.is_detached() {
+            if !supports_detach(&program) {
+                return Err(BpfmanError::DetachNotSupported(program.kind().to_string()));
+            }
+            if uses_dispatcher(&program) {
+                return Err(BpfmanError::LoadDetachedNotSupported(
+                    program.kind().to_string(),
+                ));
+            }
+        }
+
+        // Cgroups can be given by container or pod rather than by path, which
+        // is resolved once here.
+        if let Some(target) = program.cgroup_path()? {
+            if !target.starts_with('/') {
+                let cgroup_path = container::cgroup_path(&target)?;
+                info!("Attaching to cgroup {cgroup_path} of {target}");
+                program.set_cgroup_path(cgroup_path)?;
+            }
+        }
+
+        self.resolve_map_from(&mut program)?;
+        self.add_map_pinning_config(&mut program)?;
         let map_owner_id = program.get_data().get_map_owner_id()?;
         // Set map_pin_path if we're using another program's maps
         if let Some(map_owner_id) = map_owner_id {
@@ -309,19 +661,75 @@ impl BpfManager {
             program.get_data_mut().set_map_pin_path(&map_pin_path)?;
         }
 
-        program
-            .get_data_mut()
-            .set_program_bytes(self.image_manager.clone())
-            .await?;
+        traced(
+            "fetch bytecode",
+            program
+                .get_data_mut()
+                .set_program_bytes(self.image_manager.clone()),
+        )
+        .await?;
+        self.check_bytecode_allowed(program.get_data().program_bytes())?;
 
+        let mut glob_matches = vec![];
         let result = match program {
             Program::Xdp(_) | Program::Tc(_) => {
-                program.set_if_index(get_ifindex(&program.if_name().unwrap())?)?;
+                set_netns_id(&mut program)?;
+                glob_matches = expand_iface_glob(&mut program)?;
+                let netns = program.netns()?;
+                match get_ifindex_in(&program.if_name().unwrap(), netns.as_deref()) {
+                    Ok(if_index) => program.set_if_index(if_index)?,
+                    Err(BpfmanError::InvalidInterface) if waits_for_iface(&program) => {
+                        // The egress side is attached as an attachment, which
+                        // needs the interface.
+                        if both_directions(&program) {
+                            return Err(BpfmanError::Error(
+                                "programs attached to both directions can't wait for their interface".to_string(),
+                            ));
+                        }
+                        info!(
+                            "Interface {} doesn't exist yet, attaching the program once it is created",
+                            program.if_name().unwrap()
+                        );
+                        program.get_data_mut().set_detached(true)?;
+                        program.set_pending(true)?;
+                    }
+                    Err(e) => return Err(e),
+                }
 
-                self.add_multi_attach_program(&mut program).await
+                if program.is_tcx() {
+                    traced("load program", self.add_tcx_program(&mut program)).await
+                } else {
+                    traced("load program", self.add_multi_attach_program(&mut program)).await
+                }
+            }
+            Program::Tracepoint(_)
+            | Program::Kprobe(_)
+            | Program::Uprobe(_)
+            | Program::Usdt(_)
+            | Program::KprobeMulti(_)
+            | Program::UprobeMulti(_)
+            | Program::Iter(_)
+            | Program::Extension(_)
+            | Program::Lsm(_)
+            | Program::CgroupSkb(_)
+            | Program::CgroupSockAddr(_)
+            | Program::SockOps(_)
+            | Program::CgroupDevice(_)
+            | Program::CgroupSysctl(_)
+            | Program::CgroupSockopt(_)
+            | Program::SkMsg(_)
+            | Program::SkSkb(_)
+            | Program::FlowDissector(_)
+            | Program::SkLookup(_)
+            | Program::PerfEvent(_)
+            | Program::RawTracepoint(_)
+            | Program::SocketFilter(_) => {
+                traced("load program", self.add_single_attach_program(&mut program)).await
             }
-            Program::Tracepoint(_) | Program::Kprobe(_) | Program::Uprobe(_) => {
-                self.add_single_attach_program(&mut program).await
+            Program::Netkit(_) => {
+                check_netkit_supported()?;
+                program.set_if_index(get_ifindex(&program.if_name().unwrap())?)?;
+                traced("load program", self.add_single_attach_program(&mut program)).await
             }
             Program::Unsupported(_) => panic!("Cannot add unsupported program"),
         };
@@ -348,6 +756,24 @@ impl BpfManager {
                 // Only add program to bpfManager if we've completed all mutations and it's successfully loaded.
                 self.programs.insert(id, program.to_owned());
 
+                if let Err(e) = self.check_map_quota(&mut program) {
+                    let _ = self.remove_program(id).await;
+                    return Err(e);
+                }
+
+                if both_directions(&program) {
+                    if let Err(e) = self.attach_egress(id, program.if_name()?).await {
+                        let _ = self.remove_program(id).await;
+                        return Err(e);
+                    }
+                }
+
+                // Failing to attach to the other interfaces matching the glob
+                // doesn't fail the load.
+                for iface in glob_matches {
+                    self.attach_glob_match(id, iface).await;
+                }
+
                 Ok(program)
             }
             Err(e) => {
@@ -357,9 +783,172 @@ impl BpfManager {
                 if let Some(pin_path) = program.get_data().get_map_pin_path()? {
                     let _ = self.cleanup_map_pin_path(&pin_path, map_owner_id).await;
                 }
-                Err(e)
+                let size = self.verifier_log_size(slice::from_ref(&program));
+                Err(verifier_error(e, size))
+            }
+        }
+    }
+
+    // Loads programs for functions of the same bytecode. The programs after
+    // the first one use its maps, unless it uses the maps of another program
+    // itself. Either all of the programs are loaded or none are.
+    pub(crate) async fn add_programs_sharing_maps(
+        &mut self,
+        programs: Vec<Program>,
+    ) -> Result<Vec<Program>, BpfmanError> {
+        let mut programs = programs.into_iter();
+        let first = self
+            .add_program(
+                programs
+                    .next()
+                    .ok_or(BpfmanError::Error("no programs to load".to_string()))?,
+            )
+            .await?;
+        let first_id = first.get_data().get_id()?;
+        let map_owner_id = first.get_data().get_map_owner_id()?.unwrap_or(first_id);
+
+        let mut loaded = vec![first];
+        for mut program in programs {
+            program.get_data_mut().set_map_owner_id(map_owner_id)?;
+            match self.add_program(program).await {
+                Ok(program) => loaded.push(program),
+                Err(e) => {
+                    // The programs sharing the maps are removed before the
+                    // first one, which owns them.
+                    for program in loaded.iter().rev() {
+                        let id = program.get_data().get_id()?;
+                        if let Err(e) = self.remove_program(id).await {
+                            warn!("Unable to remove program {id}: {e}");
+                        }
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(loaded)
+    }
+
+    // Loads XDP or TC programs for the same interface and direction with a
+    // single dispatcher rebuild, so traffic on the interface switches once.
+    // Either all of the programs are loaded or none are.
+    pub(crate) async fn add_programs(
+        &mut self,
+        mut programs: Vec<Program>,
+    ) -> Result<Vec<Program>, BpfmanError> {
+        if programs.is_empty() {
+            return Err(BpfmanError::Error("no programs to load".to_string()));
+        }
+
+        // The programs of a batch are loaded by the same client, and so have
+        // the same owner.
+        self.check_program_quota(&programs[0], programs.len() as u32)?;
+
+        let use_tcx = self.config.tc.map(|c| c.use_tcx).unwrap_or(false);
+        let mut map_owner_ids = Vec::with_capacity(programs.len());
+        let mut did = None;
+        for program in programs.iter_mut() {
+            self.admit_load(program).await?;
+            if let Program::Tc(ref mut p) = program {
+                if p.get_tcx()?.is_none() {
+                    p.set_tcx(use_tcx)?;
+                }
+            }
+            if !uses_dispatcher(program)
+                || program.is_detached()
+                || both_directions(program)
+                || is_iface_glob(&program.if_name()?)
+            {
+                return Err(BpfmanError::Error(
+                    "only XDP and TC programs attached through a dispatcher to a single interface can be loaded in a batch".to_string(),
+                ));
+            }
+
+            self.resolve_map_from(program)?;
+            self.add_map_pinning_config(program)?;
+            let map_owner_id = program.get_data().get_map_owner_id()?;
+            if let Some(map_owner_id) = map_owner_id {
+                let map_pin_path = self.is_map_owner_id_valid(map_owner_id)?;
+                program.get_data_mut().set_map_pin_path(&map_pin_path)?;
+            }
+            map_owner_ids.push(map_owner_id);
+
+            program
+                .get_data_mut()
+                .set_program_bytes(self.image_manager.clone())
+                .await?;
+            self.check_bytecode_allowed(program.get_data().program_bytes())?;
+            set_netns_id(program)?;
+            let netns = program.netns()?;
+            program.set_if_index(get_ifindex_in(&program.if_name()?, netns.as_deref())?)?;
+            verify_extension(program)?;
+
+            let program_did = program.dispatcher_id()?;
+            if *did.get_or_insert_with(|| program_did.clone()) != program_did {
+                return Err(BpfmanError::Error(
+                    "all programs in a batch must be for the same interface and direction"
+                        .to_string(),
+                ));
+            }
+        }
+
+        let res = self.add_to_dispatcher(&mut programs).await;
+
+        for program in programs.iter_mut() {
+            // Program bytes MUST be cleared after load.
+            program.get_data_mut().clear_program_bytes();
+        }
+
+        if let Err(e) = res {
+            for (program, map_owner_id) in programs.iter().zip(map_owner_ids) {
+                // If kernel ID was never set there's no pins to cleanup here.
+                if program.get_data().get_id().is_ok() {
+                    let _ = program.delete();
+                }
+                if let Some(pin_path) = program.get_data().get_map_pin_path()? {
+                    let _ = self.cleanup_map_pin_path(&pin_path, map_owner_id).await;
+                }
+            }
+            return Err(verifier_error(e, self.verifier_log_size(&programs)));
+        }
+
+        for (program, map_owner_id) in programs.iter_mut().zip(map_owner_ids) {
+            let id = program.get_data().get_id()?;
+            program.set_attached();
+            info!(
+                "Added {} program with name: {} and id: {id}",
+                program.kind(),
+                program.get_data().get_name()?
+            );
+            self.save_map(program, id, map_owner_id).await?;
+            program.get_data_mut().swap_tree(id)?;
+            self.programs.insert(id, program.to_owned());
+        }
+
+        let quota = programs
+            .iter_mut()
+            .try_for_each(|program| self.check_map_quota(program));
+        if let Err(e) = quota {
+            for program in programs.iter().rev() {
+                let id = program.get_data().get_id()?;
+                if let Err(e) = self.remove_program(id).await {
+                    warn!("Unable to remove program {id}: {e}");
+                }
             }
+            return Err(e);
         }
+
+        Ok(programs)
+    }
+
+    // Returns how much of the end of the verifier log is returned when the
+    // verifier rejects one of programs, the most any of them asked for.
+    fn verifier_log_size(&self, programs: &[Program]) -> usize {
+        programs
+            .iter()
+            .filter_map(|p| p.get_data().get_verifier_log_size().ok().flatten())
+            .map(|size| size as usize)
+            .max()
+            .unwrap_or(self.config.verifier.max_error_log_size)
     }
 
     pub(crate) async fn add_multi_attach_program(
@@ -367,27 +956,47 @@ impl BpfManager {
         program: &mut Program,
     ) -> Result<u32, BpfmanError> {
         debug!("BpfManager::add_multi_attach_program()");
-        let name = &program.get_data().get_name()?;
+        verify_extension(program)?;
+
+        // Programs waiting for their interface are only loaded, and added to
+        // its dispatcher once it exists.
+        let res = if program.is_pending() {
+            program.set_position(0)?;
+            Dispatcher::load_pending(
+                &self.config.dispatcher_images,
+                &mut [&mut *program],
+                self.image_manager.clone(),
+            )
+            .await
+        } else {
+            self.add_to_dispatcher(slice::from_mut(program)).await
+        };
+        res.or_else(|e| {
+            // If kernel ID was never set there's no pins to cleanup here so just continue
+            if program.get_data().get_id().is_ok() {
+                program
+                    .delete()
+                    .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+            }
+            Err(e)
+        })?;
 
-        // This load is just to verify the BPF Function Name is valid.
-        // The actual load is performed in the XDP or TC logic.
-        // don't pin maps here.
-        let mut ext_loader = BpfLoader::new()
-            .allow_unsupported_maps()
-            .extension(name)
-            .load(program.get_data().program_bytes())?;
+        let id = program.get_data().get_id()?;
+        program.set_attached();
 
-        match ext_loader.program_mut(name) {
-            Some(_) => Ok(()),
-            None => Err(BpfmanError::BpfFunctionNameNotValid(name.to_owned())),
-        }?;
+        Ok(id)
+    }
 
+    // Rebuilds the dispatcher the given programs are attached through, adding
+    // the programs to it. The programs must all be on the same dispatcher.
+    async fn add_to_dispatcher(&mut self, new_programs: &mut [Program]) -> Result<(), BpfmanError> {
+        let program = &new_programs[0];
         let did = program
             .dispatcher_id()?
             .ok_or(BpfmanError::DispatcherNotRequired)?;
 
         let next_available_id = self.dispatchers.attached_programs(&did);
-        if next_available_id >= 10 {
+        if next_available_id + new_programs.len() > 10 {
             return Err(BpfmanError::TooManyPrograms);
         }
 
@@ -397,18 +1006,22 @@ impl BpfManager {
         let if_index = program.if_index()?;
         let if_name = program.if_name().unwrap().to_string();
         let direction = program.direction()?;
+        let nsid = program.nsid()?;
 
-        self.programs.add_and_set_program_positions(program);
+        self.programs.add_and_set_program_positions(new_programs);
 
         let mut programs: Vec<&mut Program> = self
             .programs
-            .programs_mut(&program_type, &if_index, &direction)
+            .programs_mut(&program_type, &if_index, &direction, &nsid)
             .collect::<Vec<&mut Program>>();
 
-        // add the program that's being loaded
-        programs.push(program);
+        // add the programs that are being loaded
+        programs.extend(new_programs.iter_mut());
 
         let old_dispatcher = self.dispatchers.remove(&did);
+        // The old dispatcher stays attached until the new revision is, so it's
+        // kept if the new revision fails to load.
+        let fallback = old_dispatcher.as_ref().map(Dispatcher::reopen);
         let if_config = if let Some(ref i) = self.config.interfaces {
             i.get(&if_name)
         } else {
@@ -420,28 +1033,72 @@ impl BpfManager {
             1
         };
 
-        let dispatcher = Dispatcher::new(
+        let result = Dispatcher::new(
             if_config,
+            &self.config.dispatcher_images,
             &mut programs,
             next_revision,
             old_dispatcher,
             self.image_manager.clone(),
         )
-        .await
-        .or_else(|e| {
-            // If kernel ID was never set there's no pins to cleanup here so just continue
-            if program.get_data().get_id().is_ok() {
-                program
-                    .delete()
-                    .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+        .await;
+        self.events.record(Event::dispatcher_rebuild(
+            program_type,
+            &if_name,
+            next_revision,
+            &result,
+        ));
+        let dispatcher = match result {
+            Ok(dispatcher) => dispatcher,
+            Err(e) => {
+                if let Some(old) = fallback {
+                    self.dispatchers.insert(did, old);
+                }
+                if let Some(if_index) = if_index {
+                    self.programs.set_program_positions(
+                        program_type,
+                        if_index,
+                        direction,
+                        nsid,
+                        false,
+                    );
+                }
+                return Err(e);
             }
-            Err(e)
-        })?;
+        };
 
         self.dispatchers.insert(did, dispatcher);
-        let id = program.get_data().get_id()?;
-        program.set_attached();
+        Ok(())
+    }
+
+    pub(crate) async fn add_tcx_program(
+        &mut self,
+        program: &mut Program,
+    ) -> Result<u32, BpfmanError> {
+        debug!("BpfManager::add_tcx_program()");
+        check_tcx_supported()?;
+
+        // The kernel runs every program on a tcx hook until one returns a
+        // verdict other than TCX_NEXT, there is no chain call mask to apply.
+        if let Program::Tc(p) = program {
+            let proceed_on = p.get_proceed_on()?;
+            if proceed_on.mask() != TcProceedOn::default().mask() {
+                warn!("proceed-on {proceed_on} is ignored for tcx programs");
+            }
+        }
 
+        // A detached program is only given a position once it is attached.
+        if program.is_detached() {
+            return self.add_single_attach_program(program).await;
+        }
+
+        // Order tcx programs on a hook the same way the dispatcher would, the
+        // link is then attached in front of the program that follows it.
+        self.programs
+            .add_and_set_program_positions(slice::from_mut(program));
+
+        let id = self.add_single_attach_program(program).await?;
+        program.set_attached();
         Ok(id)
     }
 
@@ -458,6 +1115,11 @@ impl BpfManager {
             bpf.set_global(key, value.as_slice(), true);
         }
 
+        let map_max_entries = &p.get_data().get_map_max_entries()?;
+        for (name, max_entries) in map_max_entries {
+            bpf.set_max_entries(name, *max_entries);
+        }
+
         // If map_pin_path is set already it means we need to use a pin
         // path which should already exist on the system.
         if let Some(map_pin_path) = p.get_data().get_map_pin_path()? {
@@ -468,6 +1130,15 @@ impl BpfManager {
             bpf.map_pin_path(map_pin_path);
         }
 
+        // Extension programs have to be loaded against their target program,
+        // which is done when attaching them below.
+        if let Program::Extension(_) = p {
+            bpf.extension(name);
+        }
+
+        set_verifier_log_level(&mut bpf, p.get_data())?;
+        set_external_btf(&mut bpf);
+
         let mut loader = bpf
             .allow_unsupported_maps()
             .load(p.get_data().program_bytes())?;
@@ -477,16 +1148,30 @@ impl BpfManager {
             .ok_or(BpfmanError::BpfFunctionNameNotValid(name.to_owned()))?;
 
         let res = match p {
-            Program::Tracepoint(ref mut program) => {
-                let tracepoint = program.get_tracepoint()?;
-                let parts: Vec<&str> = tracepoint.split('/').collect();
-                if parts.len() != 2 {
-                    return Err(BpfmanError::InvalidAttach(
-                        program.get_tracepoint()?.to_string(),
-                    ));
+            Program::Tc(ref mut program) => {
+                let classifier: &mut SchedClassifier = raw_program.try_into()?;
+
+                classifier.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&classifier.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                if !program.get_data().get_detached()? {
+                    let before = self.programs.next_tcx_program(program)?;
+                    tcx::attach(program, classifier, before)?;
                 }
-                let category = parts[0].to_owned();
-                let name = parts[1].to_owned();
+
+                classifier
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::Tracepoint(ref mut program) => {
+                // Validate the tracepoint before loading anything.
+                tracepoint_parts(program)?;
 
                 let tracepoint: &mut TracePoint = raw_program.try_into()?;
 
@@ -497,16 +1182,9 @@ impl BpfManager {
 
                 let id = program.data.get_id()?;
 
-                let link_id = tracepoint.attach(&category, &name)?;
-
-                let owned_link: TracePointLink = tracepoint.take_link(link_id)?;
-                let fd_link: FdLink = owned_link
-                    .try_into()
-                    .expect("unable to get owned tracepoint attach link");
-
-                fd_link
-                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
-                    .map_err(BpfmanError::UnableToPinLink)?;
+                if !program.get_data().get_detached()? {
+                    attach_tracepoint(tracepoint, program)?;
+                }
 
                 tracepoint
                     .pin(format!("{RTDIR_FS}/prog_{id}"))
@@ -542,16 +1220,9 @@ impl BpfManager {
 
                 let id = program.data.get_id()?;
 
-                let link_id = kprobe.attach(program.get_fn_name()?, program.get_offset()?)?;
-
-                let owned_link: KProbeLink = kprobe.take_link(link_id)?;
-                let fd_link: FdLink = owned_link
-                    .try_into()
-                    .expect("unable to get owned kprobe attach link");
-
-                fd_link
-                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
-                    .map_err(BpfmanError::UnableToPinLink)?;
+                if !program.get_data().get_detached()? {
+                    attach_kprobe(kprobe, program)?;
+                }
 
                 kprobe
                     .pin(format!("{RTDIR_FS}/prog_{id}"))
@@ -565,6 +1236,27 @@ impl BpfManager {
                     false => Uprobe,
                 };
 
+                // Binaries in a container given by id are attached to through
+                // the container's root, which is visible from the host.
+                let (target, pid) = match program.get_container_id()? {
+                    Some(_) if program.get_container_pid()?.is_some() => {
+                        return Err(BpfmanError::Error(
+                            "container_id and container_pid can't both be set".to_string(),
+                        ));
+                    }
+                    Some(container_id) => {
+                        let container_pid = container::container_pid(&container_id)?;
+                        let target =
+                            container::container_path(container_pid, &program.get_target()?)?;
+                        let pid = program
+                            .get_pid()?
+                            .map(|pid| container::host_pid(container_pid, pid))
+                            .transpose()?;
+                        (target, pid)
+                    }
+                    None => (program.get_target()?, program.get_pid()?),
+                };
+
                 let uprobe: &mut UProbe = raw_program.try_into()?;
                 uprobe.load()?;
 
@@ -594,8 +1286,8 @@ impl BpfManager {
                         let link_id = uprobe.attach(
                             fn_name.as_deref(),
                             program.get_offset()?,
-                            program.get_target()?,
-                            None,
+                            target,
+                            pid,
                         )?;
 
                         let owned_link: UProbeLink = uprobe.take_link(link_id)?;
@@ -653,68 +1345,1477 @@ impl BpfManager {
 
                 Ok(id)
             }
-            _ => panic!("not a supported single attach program"),
-        };
+            Program::Lsm(ref mut program) => {
+                // Fail early with a clear error rather than a generic attach
+                // failure when the kernel can't run BPF LSM programs.
+                check_bpf_lsm_enabled()?;
 
-        match res {
-            Ok(id) => {
-                // If this program is the map(s) owner pin all maps (except for .rodata and .bss) by name.
-                if p.get_data().get_map_pin_path()?.is_none() {
-                    let map_pin_path = calc_map_pin_path(id);
-                    p.get_data_mut().set_map_pin_path(&map_pin_path)?;
-                    create_map_pin_path(&map_pin_path).await?;
+                let hook = program.get_hook()?;
+                let btf = Btf::from_sys_fs()?;
 
-                    for (name, map) in loader.maps_mut() {
-                        if !should_map_be_pinned(name) {
-                            continue;
-                        }
-                        debug!(
-                            "Pinning map: {name} to path: {}",
-                            map_pin_path.join(name).display()
-                        );
-                        map.pin(map_pin_path.join(name))
-                            .map_err(BpfmanError::UnableToPinMap)?;
-                    }
-                }
-            }
-            Err(_) => {
-                // If kernel ID was never set there's no pins to cleanup here so just continue
-                if p.get_data().get_id().is_ok() {
-                    p.delete().map_err(BpfmanError::BpfmanProgramDeleteError)?;
-                };
-            }
-        };
+                let lsm: &mut Lsm = raw_program.try_into()?;
+                lsm.load(&hook, &btf)?;
+                program.get_data_mut().set_kernel_info(&lsm.info()?)?;
 
-        res
-    }
+                let id = program.data.get_id()?;
+
+                let link_id = lsm.attach()?;
+
+                let owned_link: LsmLink = lsm.take_link(link_id)?;
+                let fd_link: FdLink = owned_link.into();
+
+                fd_link
+                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+                    .map_err(BpfmanError::UnableToPinLink)?;
+
+                lsm.pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::CgroupSkb(ref mut program) => {
+                let cgroup_skb: &mut CgroupSkb = raw_program.try_into()?;
+                cgroup_skb.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&cgroup_skb.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                cgroup_skb
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_cgroup_skb(cgroup_skb, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::CgroupSockAddr(ref mut program) => {
+                check_sock_addr_attach_point(program)?;
+                let sock_addr: &mut CgroupSockAddr = raw_program.try_into()?;
+                sock_addr.load()?;
+                program.get_data_mut().set_kernel_info(&sock_addr.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                sock_addr
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_cgroup_sock_addr(sock_addr, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::SockOps(ref mut program) => {
+                let sock_ops: &mut SockOps = raw_program.try_into()?;
+                sock_ops.load()?;
+                program.get_data_mut().set_kernel_info(&sock_ops.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                sock_ops
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_sock_ops(sock_ops, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::CgroupDevice(ref mut program) => {
+                let cgroup_device: &mut CgroupDevice = raw_program.try_into()?;
+                cgroup_device.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&cgroup_device.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                cgroup_device
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_cgroup_device(cgroup_device, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::CgroupSysctl(ref mut program) => {
+                let cgroup_sysctl: &mut CgroupSysctl = raw_program.try_into()?;
+                cgroup_sysctl.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&cgroup_sysctl.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                cgroup_sysctl
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_cgroup_sysctl(cgroup_sysctl, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::CgroupSockopt(ref mut program) => {
+                let cgroup_sockopt: &mut CgroupSockopt = raw_program.try_into()?;
+                cgroup_sockopt.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&cgroup_sockopt.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                cgroup_sockopt
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_cgroup_sockopt(cgroup_sockopt, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::SkMsg(ref mut program) => {
+                let sk_msg: &mut SkMsg = raw_program.try_into()?;
+                sk_msg.load()?;
+                program.get_data_mut().set_kernel_info(&sk_msg.info()?)?;
+
+                let id = program.data.get_id()?;
+                let prog_pin_path = format!("{RTDIR_FS}/prog_{id}");
+
+                sk_msg
+                    .pin(&prog_pin_path)
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                // Keep a copy of the program fd so the loader's maps can be
+                // borrowed while attaching.
+                let prog_fd = sk_msg.fd()?.try_clone()?;
+                match sk_msg_map_path(program)? {
+                    Some(path) => {
+                        let map = Map::from_map_data(MapData::from_pin(path)?)?;
+                        sock_map::attach(
+                            sock_map_fd(&map)?,
+                            prog_fd.as_fd(),
+                            SockMapProgram::SkMsg,
+                        )?;
+                    }
+                    None => {
+                        let map_name = program.get_map_name()?;
+                        let map = loader.map(&map_name).ok_or(BpfmanError::Error(format!(
+                            "map {map_name} not found in program {name}"
+                        )))?;
+                        sock_map::attach(
+                            sock_map_fd(map)?,
+                            prog_fd.as_fd(),
+                            SockMapProgram::SkMsg,
+                        )?;
+                    }
+                };
+
+                Ok(id)
+            }
+            Program::SkSkb(ref mut program) => {
+                let attach_type = program.get_attach_type()?;
+                let map_path = sk_skb_map_path(program)?;
+                self.programs
+                    .check_sk_skb_order(map_path.as_deref(), attach_type)?;
+
+                let sk_skb: &mut SkSkb = raw_program.try_into()?;
+                sk_skb.load()?;
+                program.get_data_mut().set_kernel_info(&sk_skb.info()?)?;
+
+                let id = program.data.get_id()?;
+                let prog_pin_path = format!("{RTDIR_FS}/prog_{id}");
+
+                sk_skb
+                    .pin(&prog_pin_path)
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                // Attach with the requested attach type, from a copy of the
+                // program fd so the loader's maps can be borrowed.
+                let prog_fd = sk_skb.fd()?.try_clone()?;
+                let kind = SockMapProgram::SkSkb(attach_type);
+                match map_path {
+                    Some(path) => {
+                        let map = Map::from_map_data(MapData::from_pin(path)?)?;
+                        sock_map::attach(sock_map_fd(&map)?, prog_fd.as_fd(), kind)?;
+                    }
+                    None => {
+                        let map_name = program.get_map_name()?;
+                        let map = loader.map(&map_name).ok_or(BpfmanError::Error(format!(
+                            "map {map_name} not found in program {name}"
+                        )))?;
+                        sock_map::attach(sock_map_fd(map)?, prog_fd.as_fd(), kind)?;
+                    }
+                };
+
+                Ok(id)
+            }
+            Program::FlowDissector(ref mut program) => {
+                let netns_path = program.get_netns_path()?;
+
+                // The kernel allows a single flow dissector per network
+                // namespace, so report which program is in the way.
+                if let Some(id) = self.programs.flow_dissector_in_netns(&netns_path) {
+                    return Err(BpfmanError::FlowDissectorAlreadyAttached {
+                        netns: netns_path,
+                        id,
+                    });
+                }
+
+                let netns = std::fs::File::open(&netns_path).map_err(|e| {
+                    BpfmanError::Error(format!("unable to open netns {netns_path}: {e}"))
+                })?;
+
+                let flow_dissector: &mut FlowDissector = raw_program.try_into()?;
+                flow_dissector.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&flow_dissector.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                let link_id = flow_dissector.attach(netns).map_err(|e| {
+                    BpfmanError::Error(format!(
+                        "unable to attach flow dissector to {netns_path}, another flow dissector may already be attached: {e}"
+                    ))
+                })?;
+
+                let owned_link: FlowDissectorLink = flow_dissector.take_link(link_id)?;
+                let fd_link: FdLink = owned_link
+                    .try_into()
+                    .expect("unable to get owned flow dissector attach link");
+
+                fd_link
+                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+                    .map_err(BpfmanError::UnableToPinLink)?;
+
+                flow_dissector
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::Netkit(ref mut program) => {
+                let classifier: &mut SchedClassifier = raw_program.try_into()?;
+                classifier.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&classifier.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                let before = self.programs.next_netkit_program(program)?;
+                netkit::attach(program, classifier.fd()?.as_fd(), before)?;
+
+                classifier
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::SkLookup(ref mut program) => {
+                let sk_lookup: &mut SkLookup = raw_program.try_into()?;
+                sk_lookup.load()?;
+                program.get_data_mut().set_kernel_info(&sk_lookup.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                sk_lookup
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                if !program.get_data().get_detached()? {
+                    attach_sk_lookup(sk_lookup, program)?;
+                }
+
+                Ok(id)
+            }
+            Program::PerfEvent(ref mut program) => {
+                let (perf_type, config) = perf_event_config(program.get_event()?);
+                let sample_policy = match program.get_sample_period()? {
+                    Some(period) => SamplePolicy::Period(period),
+                    None => SamplePolicy::Frequency(program.get_sample_frequency()?.unwrap_or(0)),
+                };
+                let mut cpus = program.get_cpus()?;
+                if cpus.is_empty() {
+                    cpus = online_cpus().map_err(|e| {
+                        BpfmanError::Error(format!("unable to get online cpus: {e:?}"))
+                    })?;
+                }
+
+                let perf_event: &mut PerfEvent = raw_program.try_into()?;
+                perf_event.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&perf_event.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                for cpu in cpus {
+                    let link_id = perf_event.attach(
+                        perf_type.clone(),
+                        config,
+                        PerfEventScope::AllProcessesOneCpu { cpu },
+                        sample_policy.clone(),
+                        false,
+                    )?;
+
+                    let owned_link: PerfEventLink = perf_event.take_link(link_id)?;
+                    let fd_link: FdLink = owned_link
+                        .try_into()
+                        .expect("unable to get owned perf_event attach link");
+
+                    fd_link
+                        .pin(format!("{RTDIR_FS}/prog_{id}_link_{cpu}"))
+                        .map_err(BpfmanError::UnableToPinLink)?;
+                }
+
+                perf_event
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::Iter(ref mut program) => {
+                let iter_type = program.get_iter_type()?;
+                let btf = Btf::from_sys_fs()?;
+
+                let iter: &mut Iter = raw_program.try_into()?;
+                iter.load(&iter_type, &btf)?;
+                program.get_data_mut().set_kernel_info(&iter.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                // Every read of the pinned link runs the iterator again, see
+                // read_iter().
+                let link_id = iter.attach()?;
+                let owned_link: IterLink = iter.take_link(link_id)?;
+                let fd_link: FdLink = owned_link
+                    .try_into()
+                    .expect("unable to get owned iter attach link");
+
+                fd_link
+                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+                    .map_err(BpfmanError::UnableToPinLink)?;
+
+                iter.pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::Extension(ref mut program) => {
+                let target = extension_target_fd(program)?;
+                let target_fn = program.get_target_fn()?;
+
+                let ext: &mut Extension = raw_program.try_into()?;
+                ext.load(target, &target_fn)?;
+                program.get_data_mut().set_kernel_info(&ext.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                // The link replaces target_fn in the target program until it
+                // is removed.
+                let link_id = ext.attach()?;
+                let owned_link: ExtensionLink = ext.take_link(link_id)?;
+                let fd_link: FdLink = owned_link.into();
+
+                fd_link
+                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+                    .map_err(BpfmanError::UnableToPinLink)?;
+
+                ext.pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            Program::RawTracepoint(ref mut program) => {
+                let tracepoint = program.get_tracepoint()?;
+
+                let (id, fd_link) = if program.get_btf()? {
+                    let btf = Btf::from_sys_fs()?;
+                    let tp_btf: &mut BtfTracePoint = raw_program.try_into()?;
+                    tp_btf.load(&tracepoint, &btf)?;
+                    program.get_data_mut().set_kernel_info(&tp_btf.info()?)?;
+
+                    let id = program.data.get_id()?;
+                    let link_id = tp_btf.attach()?;
+                    let owned_link: BtfTracePointLink = tp_btf.take_link(link_id)?;
+
+                    tp_btf
+                        .pin(format!("{RTDIR_FS}/prog_{id}"))
+                        .map_err(BpfmanError::UnableToPinProgram)?;
+
+                    (id, FdLink::from(owned_link))
+                } else {
+                    let raw_tp: &mut RawTracePoint = raw_program.try_into()?;
+                    raw_tp.load()?;
+                    program.get_data_mut().set_kernel_info(&raw_tp.info()?)?;
+
+                    let id = program.data.get_id()?;
+                    let link_id = raw_tp.attach(&tracepoint)?;
+                    let owned_link: RawTracePointLink = raw_tp.take_link(link_id)?;
+
+                    raw_tp
+                        .pin(format!("{RTDIR_FS}/prog_{id}"))
+                        .map_err(BpfmanError::UnableToPinProgram)?;
+
+                    (id, FdLink::from(owned_link))
+                };
+
+                fd_link
+                    .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+                    .map_err(BpfmanError::UnableToPinLink)?;
+
+                Ok(id)
+            }
+            Program::KprobeMulti(ref mut program) => {
+                let requested_probe_type = match program.get_retprobe()? {
+                    true => Kretprobe,
+                    false => Kprobe,
+                };
+                let fn_names = resolve_kernel_functions(&program.get_fn_names()?)?;
+
+                let kprobe: &mut KProbe = raw_program.try_into()?;
+                kprobe.load()?;
+
+                let loaded_probe_type = ProbeType::from(kprobe.kind());
+                if requested_probe_type != loaded_probe_type {
+                    return Err(BpfmanError::Error(format!(
+                        "expected {requested_probe_type}, loaded program is {loaded_probe_type}"
+                    )));
+                }
+
+                program.get_data_mut().set_kernel_info(&kprobe.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                kprobe
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                // aya can't create BPF_TRACE_KPROBE_MULTI links, so attach the
+                // program once per function and pin every link.
+                debug!(
+                    "attaching kprobe program {id} to {} functions",
+                    fn_names.len()
+                );
+                for (i, fn_name) in fn_names.iter().enumerate() {
+                    let link_id = kprobe.attach(fn_name, 0)?;
+
+                    let owned_link: KProbeLink = kprobe.take_link(link_id)?;
+                    let fd_link: FdLink = owned_link
+                        .try_into()
+                        .expect("unable to get owned kprobe attach link");
+
+                    fd_link
+                        .pin(format!("{RTDIR_FS}/prog_{id}_link_{i}"))
+                        .map_err(BpfmanError::UnableToPinLink)?;
+                }
+
+                Ok(id)
+            }
+            Program::UprobeMulti(ref mut program) => {
+                let requested_probe_type = match program.get_retprobe()? {
+                    true => Uretprobe,
+                    false => Uprobe,
+                };
+
+                let uprobe: &mut UProbe = raw_program.try_into()?;
+                uprobe.load()?;
+
+                let loaded_probe_type = ProbeType::from(uprobe.kind());
+                if requested_probe_type != loaded_probe_type {
+                    return Err(BpfmanError::Error(format!(
+                        "expected {requested_probe_type}, loaded program is {loaded_probe_type}"
+                    )));
+                }
+
+                program.get_data_mut().set_kernel_info(&uprobe.info()?)?;
+
+                let id = program.data.get_id()?;
+                let target = program.get_target()?;
+                let pid = program.get_pid()?;
+
+                uprobe
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                // aya can't create BPF_TRACE_UPROBE_MULTI links, so attach the
+                // program once per symbol and pin every link.
+                for (i, fn_name) in program.get_fn_names()?.iter().enumerate() {
+                    let link_id = uprobe.attach(Some(fn_name.as_str()), 0, &target, pid)?;
+
+                    let owned_link: UProbeLink = uprobe.take_link(link_id)?;
+                    let fd_link: FdLink = owned_link
+                        .try_into()
+                        .expect("unable to get owned uprobe attach link");
+
+                    fd_link
+                        .pin(format!("{RTDIR_FS}/prog_{id}_link_{i}"))
+                        .map_err(BpfmanError::UnableToPinLink)?;
+                }
+
+                Ok(id)
+            }
+            Program::Usdt(ref mut program) => {
+                let target = program.get_target()?;
+                let provider = program.get_provider()?;
+                let name = program.get_name()?;
+                let probes = usdt::find_probes(&target, &provider, &name)?;
+
+                let uprobe: &mut UProbe = raw_program.try_into()?;
+                uprobe.load()?;
+
+                let loaded_probe_type = ProbeType::from(uprobe.kind());
+                if loaded_probe_type != Uprobe {
+                    return Err(BpfmanError::Error(format!(
+                        "expected {Uprobe}, loaded program is {loaded_probe_type}"
+                    )));
+                }
+
+                program.get_data_mut().set_kernel_info(&uprobe.info()?)?;
+
+                let id = program.data.get_id()?;
+                let pid = program.get_pid()?;
+
+                uprobe
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                // A probe can have several sites, attach to all of them.
+                for (i, probe) in probes.iter().enumerate() {
+                    let link_id = uprobe.attach(None, probe.offset, &target, pid)?;
+
+                    let owned_link: UProbeLink = uprobe.take_link(link_id)?;
+                    let fd_link: FdLink = owned_link
+                        .try_into()
+                        .expect("unable to get owned uprobe attach link");
+
+                    fd_link
+                        .pin(format!("{RTDIR_FS}/prog_{id}_link_{i}"))
+                        .map_err(BpfmanError::UnableToPinLink)?;
+                }
+
+                if pid.is_some() {
+                    update_usdt_semaphores(program, 1)?;
+                } else if probes.iter().any(|p| p.semaphore_offset.is_some()) {
+                    warn!(
+                        "usdt {provider}:{name} is guarded by a semaphore but no pid was given, \
+                        the probe only fires while another tracer has the semaphore set"
+                    );
+                }
+
+                Ok(id)
+            }
+            Program::SocketFilter(ref mut program) => {
+                // Socket filters are attached by the client to its own socket,
+                // so only load and pin the program here. The fd is handed out
+                // over the fd socket, see fd_handover.rs.
+                let socket_filter: &mut SocketFilter = raw_program.try_into()?;
+                socket_filter.load()?;
+                program
+                    .get_data_mut()
+                    .set_kernel_info(&socket_filter.info()?)?;
+
+                let id = program.data.get_id()?;
+
+                socket_filter
+                    .pin(format!("{RTDIR_FS}/prog_{id}"))
+                    .map_err(BpfmanError::UnableToPinProgram)?;
+
+                Ok(id)
+            }
+            _ => panic!("not a supported single attach program"),
+        };
+
+        match res {
+            Ok(id) => {
+                // If this program is the map(s) owner pin its maps as its map pin policy says.
+                if p.get_data().get_map_pin_path()?.is_none() {
+                    let map_pin_path = calc_map_pin_path(id);
+                    p.get_data_mut().set_map_pin_path(&map_pin_path)?;
+                    create_map_pin_path(&map_pin_path).await?;
+
+                    let policy = p.get_data().get_map_pin_policy()?;
+                    for (name, map) in loader.maps_mut() {
+                        let pin_name = match policy.pin_name(name) {
+                            Some(pin_name) => pin_name,
+                            None => continue,
+                        };
+                        debug!(
+                            "Pinning map: {name} to path: {}",
+                            map_pin_path.join(pin_name).display()
+                        );
+                        map.pin(map_pin_path.join(pin_name))
+                            .map_err(BpfmanError::UnableToPinMap)?;
+                    }
+                    initialize_maps(&map_pin_path, p.get_data())?;
+                }
+            }
+            Err(_) => {
+                // If kernel ID was never set there's no pins to cleanup here so just continue
+                if p.get_data().get_id().is_ok() {
+                    p.delete().map_err(BpfmanError::BpfmanProgramDeleteError)?;
+                };
+            }
+        };
+
+        res
+    }
+
+    pub(crate) async fn remove_program(&mut self, id: u32) -> Result<(), BpfmanError> {
+        info!("Removing program with id: {id}");
+
+        // A stream parser can't be removed from under a stream verdict program
+        // attached to the same map.
+        if let Some(Program::SkSkb(p)) = self.programs.get(&id) {
+            if p.get_attach_type()? == SkSkbAttachType::StreamParser {
+                if let Some(path) = sk_skb_map_path(p)? {
+                    if self
+                        .programs
+                        .sk_skb_attached(&path, SkSkbAttachType::StreamVerdict)
+                    {
+                        return Err(BpfmanError::Error(format!(
+                            "program {id} is a stream_parser still in use by a stream_verdict program, unload it first"
+                        )));
+                    }
+                }
+            }
+        }
+
+        let prog = match self.programs.remove(&id) {
+            Some(p) => p,
+            None => {
+                return Err(BpfmanError::Error(format!(
+                    "Program {0} does not exist or was not created by bpfman",
+                    id,
+                )));
+            }
+        };
+
+        let map_owner_id = prog.get_data().get_map_owner_id()?;
+
+        match prog {
+            // Removing the link pin below detaches a tcx program, the programs
+            // after it only need their positions updated.
+            Program::Tc(_) if prog.is_tcx() => self.programs.set_program_positions(
+                ProgramType::Tc,
+                prog.if_index()?.unwrap(),
+                prog.direction()?,
+                prog.nsid()?,
+                true,
+            ),
+            Program::Xdp(_) | Program::Tc(_) => {
+                self.remove_attachments(id).await?;
+                // A detached program was already taken out of its dispatcher.
+                if !prog.is_detached() {
+                    self.remove_multi_attach_program(&prog).await?
+                }
+            }
+            Program::Tracepoint(_)
+            | Program::Kprobe(_)
+            | Program::Uprobe(_)
+            | Program::KprobeMulti(_)
+            | Program::UprobeMulti(_)
+            | Program::Iter(_)
+            | Program::Extension(_)
+            | Program::Lsm(_)
+            | Program::CgroupSkb(_)
+            | Program::CgroupSockAddr(_)
+            | Program::SockOps(_)
+            | Program::CgroupDevice(_)
+            | Program::CgroupSysctl(_)
+            | Program::CgroupSockopt(_)
+            | Program::FlowDissector(_)
+            | Program::SkLookup(_)
+            | Program::PerfEvent(_)
+            | Program::RawTracepoint(_)
+            | Program::SocketFilter(_)
+            | Program::Netkit(_)
+            | Program::Unsupported(_) => (),
+            Program::Usdt(ref p) => {
+                if let Err(e) = update_usdt_semaphores(p, -1) {
+                    warn!("unable to release usdt semaphore for program {id}: {e}");
+                }
+            }
+            Program::SkMsg(ref p) => {
+                if let Err(e) = detach_sk_msg(p) {
+                    warn!("unable to detach sk_msg program {id}: {e}");
+                }
+            }
+            Program::SkSkb(ref p) => {
+                if let Err(e) = detach_sk_skb(p) {
+                    warn!("unable to detach sk_skb program {id}: {e}");
+                }
+            }
+        }
+
+        self.delete_map(id, map_owner_id).await?;
+
+        prog.delete()
+            .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn attach_program(&mut self, id: u32) -> Result<(), BpfmanError> {
+        info!("Attaching program with id: {id}");
+
+        // Take the program out of the map while attaching, an XDP or TC program
+        // must not be counted twice when positions are set.
+        let mut program = match self.programs.remove(&id) {
+            Some(p) => p,
+            None => {
+                return Err(BpfmanError::Error(format!(
+                    "Program {0} does not exist or was not created by bpfman",
+                    id,
+                )));
+            }
+        };
+
+        let res = self.attach_detached_program(&mut program).await;
+        self.programs.insert(id, program);
+        res
+    }
+
+    async fn attach_detached_program(&mut self, program: &mut Program) -> Result<(), BpfmanError> {
+        let id = program.get_data().get_id()?;
+        if !program.is_detached() {
+            return Err(BpfmanError::Error(format!(
+                "program {id} is already attached"
+            )));
+        }
+
+        let dispatcher = uses_dispatcher(program);
+        if let Program::Xdp(_) | Program::Tc(_) = program {
+            // The interface may have been recreated while the program was
+            // detached, or not have existed when it was loaded.
+            let netns = program.netns()?;
+            program.set_if_index(get_ifindex_in(&program.if_name()?, netns.as_deref())?)?;
+        }
+
+        program.get_data_mut().set_detached(false)?;
+        let res = if program.is_tcx() {
+            self.attach_pinned_tcx_program(program)
+        } else if dispatcher {
+            // The program is still pinned, so the dispatcher attaches it from
+            // its pin.
+            self.add_to_dispatcher(slice::from_mut(program)).await
+        } else {
+            attach_pinned_program(program)
+        };
+
+        if res.is_ok() {
+            if program.is_pending() {
+                program.set_pending(false)?;
+            }
+        } else {
+            program.get_data_mut().set_detached(true)?;
+            if let Program::Xdp(_) | Program::Tc(_) = program {
+                self.programs.set_program_positions(
+                    program.kind(),
+                    program.if_index()?.unwrap(),
+                    program.direction()?,
+                    program.nsid()?,
+                    program.is_tcx(),
+                );
+            }
+        }
+        res
+    }
+
+    // Attaches a pinned tcx program to its hook, in the position its priority
+    // gives it among the tcx programs already attached there.
+    fn attach_pinned_tcx_program(&mut self, program: &mut Program) -> Result<(), BpfmanError> {
+        let id = program.get_data().get_id()?;
+        self.programs
+            .add_and_set_program_positions(slice::from_mut(program));
+
+        if let Program::Tc(p) = program {
+            let before = self.programs.next_tcx_program(p)?;
+            let mut classifier = SchedClassifier::from_pin(format!("{RTDIR_FS}/prog_{id}"))?;
+            tcx::attach(p, &mut classifier, before)?;
+        }
+
+        program.set_attached();
+        Ok(())
+    }
+
+    pub(crate) async fn detach_program(&mut self, id: u32) -> Result<(), BpfmanError> {
+        info!("Detaching program with id: {id}");
+
+        let program = match self.programs.get_mut(&id) {
+            Some(p) => p,
+            None => {
+                return Err(BpfmanError::Error(format!(
+                    "Program {0} does not exist or was not created by bpfman",
+                    id,
+                )));
+            }
+        };
+
+        if !supports_detach(program) {
+            return Err(BpfmanError::DetachNotSupported(program.kind().to_string()));
+        }
+        if program.is_detached() {
+            return Err(BpfmanError::Error(format!(
+                "program {id} is already detached"
+            )));
+        }
+
+        if uses_dispatcher(program) {
+            // Rebuild the dispatcher without the program, which stays loaded
+            // and pinned. The clone shares the program's database tree.
+            program.get_data_mut().set_detached(true)?;
+            let mut program = program.clone();
+            if let Err(e) = self.remove_multi_attach_program(&program).await {
+                program.get_data_mut().set_detached(false)?;
+                return Err(e);
+            }
+            return Ok(());
+        }
+
+        program.delete_links().map_err(|e| {
+            BpfmanError::Error(format!("unable to remove links of program {id}: {e}"))
+        })?;
+        program.get_data_mut().set_detached(true)?;
+
+        // The tcx programs after it only need their positions updated.
+        if program.is_tcx() {
+            let if_index = program.if_index()?.unwrap();
+            let direction = program.direction()?;
+            let nsid = program.nsid()?;
+            self.programs
+                .set_program_positions(ProgramType::Tc, if_index, direction, nsid, true);
+        }
+
+        Ok(())
+    }
+
+    // Attaches a loaded XDP or TC program to a further interface. The
+    // attachment has its own priority and proceed-on, but shares the program
+    // and its maps.
+    pub(crate) async fn add_attachment(
+        &mut self,
+        id: u32,
+        mut attachment: Program,
+    ) -> Result<(), BpfmanError> {
+        info!("Attaching program with id: {id} to a further interface");
+
+        match self.attach_to_interface(id, &mut attachment).await {
+            Ok(name) => {
+                self.programs.insert_attachment(name, attachment);
+                Ok(())
+            }
+            Err(e) => {
+                let _ = attachment.delete_attachment();
+                Err(e)
+            }
+        }
+    }
+
+    async fn attach_to_interface(
+        &mut self,
+        id: u32,
+        attachment: &mut Program,
+    ) -> Result<String, BpfmanError> {
+        let program = self.programs.get(&id).ok_or(BpfmanError::Error(format!(
+            "Program {0} does not exist or was not created by bpfman",
+            id,
+        )))?;
+
+        if let Program::Tc(p) = attachment {
+            if p.get_tcx()?.unwrap_or(false) {
+                return Err(BpfmanError::Error(
+                    "tcx programs can't be attached to further interfaces".to_string(),
+                ));
+            }
+            p.set_tcx(false)?;
+        }
+        if program.kind() != attachment.kind() || program.dispatcher_id()?.is_none() {
+            return Err(BpfmanError::Error(format!(
+                "program {id} can't be attached to further interfaces, only XDP and TC programs attached through a dispatcher can"
+            )));
+        }
+        let name = program.get_data().get_name()?;
+
+        // Attachments are stored by interface index, which is only unique
+        // within a network namespace, so they share the program's.
+        if attachment.netns()?.is_none() {
+            if let Some(netns) = program.netns()? {
+                attachment.set_netns(&netns)?;
+            }
+        }
+        set_netns_id(attachment)?;
+        if attachment.nsid()? != program.nsid()? {
+            return Err(BpfmanError::Error(format!(
+                "program {id} can only be attached to interfaces in its network namespace"
+            )));
+        }
+
+        let iface = attachment.if_name()?;
+        let if_index = get_ifindex_in(&iface, attachment.netns()?.as_deref())?;
+        let direction = attachment.direction()?;
+        let tree_name = attachment_tree_name(id, if_index, direction);
+        if (program.if_index()? == Some(if_index) && program.direction()? == direction)
+            || self.programs.attachment_names(id).contains(&tree_name)
+        {
+            return Err(BpfmanError::Error(format!(
+                "program {id} is already attached to {iface}"
+            )));
+        }
+
+        attachment.set_if_index(if_index)?;
+        attachment.get_data_mut().set_id(id)?;
+        attachment.get_data_mut().set_name(&name)?;
+        // The program is already loaded, so the dispatcher attaches it from
+        // its pin.
+        attachment.set_attached();
+        attachment
+            .get_data_mut()
+            .swap_attachment_tree(id, if_index, direction)?;
+
+        self.add_to_dispatcher(slice::from_mut(attachment)).await?;
+        Ok(tree_name)
+    }
+
+    // Attaches a TC program loaded for both directions to egress on iface.
+    async fn attach_egress(&mut self, id: u32, iface: String) -> Result<(), BpfmanError> {
+        let mut attachment = self
+            .programs
+            .get(&id)
+            .ok_or(BpfmanError::Error(format!(
+                "Program {0} does not exist or was not created by bpfman",
+                id,
+            )))?
+            .new_attachment(iface)?;
+        if let Program::Tc(ref mut p) = attachment {
+            p.set_direction(Direction::Egress)?;
+        }
+        self.add_attachment(id, attachment).await
+    }
+
+    // Attaches program id to an interface matching its interface glob.
+    async fn attach_glob_match(&mut self, id: u32, iface: String) {
+        let res = match self
+            .programs
+            .get(&id)
+            .map(|p| p.new_attachment(iface.clone()))
+        {
+            Some(Ok(attachment)) => self.add_attachment(id, attachment).await,
+            Some(Err(e)) => Err(e),
+            None => return,
+        };
+        let res = match res {
+            Ok(()) if self.programs.get(&id).is_some_and(both_directions) => {
+                self.attach_egress(id, iface.clone()).await
+            }
+            res => res,
+        };
+        if let Err(e) = res {
+            warn!("Unable to attach program {id} to {iface} matching its interface glob: {e}");
+        }
+    }
+
+    // Attaches the programs that track their interface glob to the matching
+    // interfaces they aren't attached to yet. This runs on startup and
+    // whenever the link monitor reports an interface change.
+    async fn attach_new_glob_matches(&mut self) {
+        let tracked: Vec<(u32, String, Option<PathBuf>)> = self
+            .programs
+            .programs
+            .iter()
+            .filter(|(_, p)| !p.is_detached() && p.tracks_iface_glob().unwrap_or(false))
+            .filter_map(|(id, p)| {
+                let netns = p.netns().ok().flatten();
+                p.iface_glob().ok().flatten().map(|glob| (*id, glob, netns))
+            })
+            .collect();
+
+        for (id, glob, netns) in tracked {
+            let ifaces = match resolve_iface_glob(&glob, netns.as_deref()) {
+                Ok(ifaces) => ifaces,
+                Err(e) => {
+                    warn!("Unable to match interfaces against {glob}: {e}");
+                    continue;
+                }
+            };
+            for iface in ifaces {
+                if !self.is_attached_to(id, &iface) {
+                    debug!("Interface {iface} matches {glob} of program {id}");
+                    self.attach_glob_match(id, iface).await;
+                }
+            }
+        }
+    }
+
+    // Returns true if the XDP or TC program id is attached to iface, either
+    // directly or through one of its attachments.
+    fn is_attached_to(&self, id: u32, iface: &str) -> bool {
+        let Some(program) = self.programs.get(&id) else {
+            return false;
+        };
+        let direction = program.direction().ok();
+        program.if_name().ok().as_deref() == Some(iface)
+            || self.programs.attachment_names(id).iter().any(|name| {
+                self.programs.attachments.get(name).is_some_and(|a| {
+                    a.if_name().ok().as_deref() == Some(iface) && a.direction().ok() == direction
+                })
+            })
+    }
+
+    // Removes the attachment of an XDP or TC program to the given interface.
+    pub(crate) async fn remove_attachment(
+        &mut self,
+        id: u32,
+        iface: &str,
+        direction: Option<Direction>,
+    ) -> Result<(), BpfmanError> {
+        info!("Detaching program with id: {id} from {iface}");
+
+        let name = self.programs.attachment_name(id, iface, direction)?;
+        let attachment = self.programs.remove_attachment(&name).unwrap();
+        self.remove_multi_attach_program(&attachment).await?;
+        attachment
+            .delete_attachment()
+            .map_err(BpfmanError::BpfmanProgramDeleteError)
+    }
+
+    // Changes the priority of an XDP or TC program, or of its attachment to
+    // iface, and moves it to its new position on the interface. The program
+    // isn't reloaded, so its maps keep their state.
+    pub(crate) async fn set_priority(
+        &mut self,
+        id: u32,
+        priority: i32,
+        iface: Option<String>,
+        direction: Option<Direction>,
+    ) -> Result<(), BpfmanError> {
+        info!("Setting priority of program with id: {id} to {priority}");
+
+        let program = match iface {
+            Some(iface) => {
+                let name = self.programs.attachment_name(id, &iface, direction)?;
+                self.programs.attachments.get_mut(&name).unwrap()
+            }
+            None => self
+                .programs
+                .get_mut(&id)
+                .ok_or(BpfmanError::Error(format!(
+                    "Program {0} does not exist or was not created by bpfman",
+                    id,
+                )))?,
+        };
+        if program.priority()? == priority {
+            return Ok(());
+        }
+        program.set_priority(priority)?;
+
+        // Detached and pending programs take their position once attached.
+        if program.is_detached() || program.is_pending() {
+            return Ok(());
+        }
+
+        if program.is_tcx() {
+            // tcx links can't be moved, so the program is attached again in
+            // front of the program that now follows it.
+            let mut program = self.programs.remove(&id).unwrap();
+            let res = match program.delete_links() {
+                Ok(()) => self.attach_pinned_tcx_program(&mut program),
+                Err(e) => Err(BpfmanError::Error(format!(
+                    "unable to remove links of program {id}: {e}"
+                ))),
+            };
+            self.programs.insert(id, program);
+            return res;
+        }
+
+        // The dispatcher is rebuilt with the programs in their new order, they
+        // are attached to it from their pins.
+        let did = program
+            .dispatcher_id()?
+            .ok_or(BpfmanError::DispatcherNotRequired)?;
+        let program_type = program.kind();
+        let if_index = program.if_index()?.unwrap();
+        let direction = program.direction()?;
+        let nsid = program.nsid()?;
+        self.rebuild_multiattach_dispatcher(did, if_index, program_type, direction, nsid)
+            .await
+    }
 
-    pub(crate) async fn remove_program(&mut self, id: u32) -> Result<(), BpfmanError> {
-        info!("Removing program with id: {id}");
-        let prog = match self.programs.remove(&id) {
-            Some(p) => p,
-            None => {
-                return Err(BpfmanError::Error(format!(
-                    "Program {0} does not exist or was not created by bpfman",
-                    id,
-                )));
+    // Replaces an XDP or TC program attached through a dispatcher with the
+    // new bytecode in data. The new program takes the position of the old one
+    // and uses its maps. The old program is only unloaded once the new one is
+    // attached, so it stays in place if the new bytecode fails to load.
+    pub(crate) async fn replace_program(
+        &mut self,
+        id: u32,
+        mut data: ProgramData,
+    ) -> Result<Program, BpfmanError> {
+        info!("Replacing program with id: {id}");
+
+        let old = self.programs.get(&id).ok_or(BpfmanError::Error(format!(
+            "Program {0} does not exist or was not created by bpfman",
+            id,
+        )))?;
+        if !uses_dispatcher(old) || old.is_detached() || old.is_pending() {
+            return Err(BpfmanError::Error(format!(
+                "only XDP and TC programs attached through a dispatcher can be replaced, program {id} isn't"
+            )));
+        }
+        if !self.programs.attachment_names(id).is_empty() {
+            return Err(BpfmanError::Error(format!(
+                "program {id} is attached to further interfaces, detach them first"
+            )));
+        }
+
+        inherit_data(old.get_data(), &mut data)?;
+        let old_map_owner_id = old.get_data().get_map_owner_id()?;
+        let map_owner_id = old_map_owner_id.unwrap_or(id);
+        data.set_map_owner_id(map_owner_id)?;
+        data.set_map_pin_path(&calc_map_pin_path(map_owner_id))?;
+        data.set_program_bytes(self.image_manager.clone()).await?;
+        self.check_bytecode_allowed(data.program_bytes())?;
+
+        let mut program = old.new_replacement(data)?;
+        program.set_if_index(old.if_index()?.unwrap())?;
+        set_netns_id(&mut program)?;
+        verify_extension(&program)?;
+        traced("admit load", self.admit_load(&mut program)).await?;
+
+        // The old program is taken out so the dispatcher is rebuilt with the
+        // new one in its place.
+        let old = self.programs.remove(&id).unwrap();
+        let res = self.add_to_dispatcher(slice::from_mut(&mut program)).await;
+
+        // Program bytes MUST be cleared after load.
+        program.get_data_mut().clear_program_bytes();
+
+        if let Err(e) = res {
+            // If kernel ID was never set there's no pins to cleanup here.
+            if program.get_data().get_id().is_ok() {
+                let _ = program.delete();
+            }
+            let (if_index, direction, nsid) = (old.if_index()?, old.direction()?, old.nsid()?);
+            self.programs.insert(id, old);
+            self.programs.set_program_positions(
+                program.kind(),
+                if_index.unwrap(),
+                direction,
+                nsid,
+                false,
+            );
+            let size = self.verifier_log_size(slice::from_ref(&program));
+            return Err(verifier_error(e, size));
+        }
+
+        let new_id = program.get_data().get_id()?;
+        program.set_attached();
+        program.get_data_mut().set_previous(old.get_data())?;
+        self.save_map(&mut program, new_id, Some(map_owner_id))
+            .await?;
+        program.get_data_mut().swap_tree(new_id)?;
+        self.programs.insert(new_id, program.clone());
+
+        old.delete()
+            .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+        self.delete_map(id, old_map_owner_id).await?;
+
+        info!("Replaced program with id: {id} by program with id: {new_id}");
+        Ok(program)
+    }
+
+    // Replaces a program that was loaded by replace_program with the program
+    // it replaced, loaded again from its bytecode location. Like any
+    // replacement, the program has to be admitted again.
+    pub(crate) async fn rollback_program(&mut self, id: u32) -> Result<Program, BpfmanError> {
+        info!("Rolling back program with id: {id}");
+
+        let previous = self
+            .programs
+            .get(&id)
+            .ok_or(BpfmanError::Error(format!(
+                "Program {0} does not exist or was not created by bpfman",
+                id,
+            )))?
+            .get_data()
+            .get_previous()?
+            .ok_or(BpfmanError::Error(format!(
+                "program {id} didn't replace a program, there is nothing to roll back to"
+            )))?;
+        self.replace_program(id, previous).await
+    }
+
+    // Removes all attachments of program id to further interfaces, this must
+    // be done before the program itself is removed from its dispatcher.
+    async fn remove_attachments(&mut self, id: u32) -> Result<(), BpfmanError> {
+        for name in self.programs.attachment_names(id) {
+            if let Some(attachment) = self.programs.remove_attachment(&name) {
+                self.remove_multi_attach_program(&attachment).await?;
+                attachment
+                    .delete_attachment()
+                    .map_err(BpfmanError::BpfmanProgramDeleteError)?;
             }
+        }
+        Ok(())
+    }
+
+    // Applies the settings of a reloaded configuration that can change while
+    // bpfman runs. Quotas apply to the loads that follow, and the dispatcher
+    // images to the dispatchers built from then on, so programs already
+    // loaded stay as they are.
+    async fn reload_config(&mut self, config: Config) {
+        self.config.quotas = config.quotas;
+        self.config.dispatcher_images = config.dispatcher_images;
+        let reload = ImageManagerCommand::Reload {
+            registries: config.registries.unwrap_or_default(),
+            pull_config: config.image_pull,
         };
+        if let Err(e) = self.image_manager.send(reload).await {
+            warn!("Unable to reload the image manager configuration: {e}");
+        }
+        info!("Reloaded configuration");
+    }
 
-        let map_owner_id = prog.get_data().get_map_owner_id()?;
+    // Follows the XDP and TC programs on an interface the link monitor reports
+    // as changed or removed.
+    async fn handle_link_event(&mut self, event: LinkEvent) {
+        match event {
+            LinkEvent::Changed { if_index, iface } => {
+                self.rename_iface(if_index, &iface);
+                self.reattach_iface(if_index, &iface).await;
+                self.attach_pending_programs().await;
+                self.attach_new_glob_matches().await;
+            }
+            LinkEvent::Removed { if_index, iface } => self.remove_iface(if_index, &iface),
+        }
+    }
 
-        match prog {
-            Program::Xdp(_) | Program::Tc(_) => self.remove_multi_attach_program(&prog).await?,
-            Program::Tracepoint(_)
-            | Program::Kprobe(_)
-            | Program::Uprobe(_)
-            | Program::Unsupported(_) => (),
+    // Attaches the programs that were loaded before their interface was
+    // created, once it exists.
+    async fn attach_pending_programs(&mut self) {
+        let pending: Vec<u32> = self
+            .programs
+            .programs
+            .iter()
+            .filter(|(_, p)| {
+                p.is_pending()
+                    && p.if_name().is_ok_and(|iface| {
+                        get_ifindex_in(&iface, p.netns().ok().flatten().as_deref()).is_ok()
+                    })
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in pending {
+            info!("Interface of pending program {id} was created");
+            if let Err(e) = self.attach_program(id).await {
+                warn!("Unable to attach pending program {id}: {e}");
+            }
         }
+    }
 
-        self.delete_map(id, map_owner_id).await?;
+    // Updates the interface name stored for the programs and dispatchers on
+    // the interface with index if_index. Only programs that are still attached
+    // there are updated, in case the index was reused by a new interface.
+    fn rename_iface(&mut self, if_index: u32, iface: &str) {
+        let dispatchers = &self.dispatchers;
+        for program in self
+            .programs
+            .programs
+            .values_mut()
+            .chain(self.programs.attachments.values_mut())
+        {
+            if !matches!(program, Program::Xdp(_) | Program::Tc(_))
+                || program.is_detached()
+                || program.nsid().ok().flatten().is_some()
+                || program.if_index().ok().flatten() != Some(if_index)
+            {
+                continue;
+            }
+            let old = match program.if_name() {
+                Ok(old) if old != iface => old,
+                _ => continue,
+            };
+            if let Ok(Some(did)) = program.dispatcher_id() {
+                if !dispatchers.contains(&did) {
+                    continue;
+                }
+            }
+            info!("Interface {old} was renamed to {iface}");
+            if let Err(e) = program.set_if_name(iface.to_string()) {
+                warn!("Unable to rename interface of program: {e}");
+            }
+        }
 
-        prog.delete()
-            .map_err(BpfmanError::BpfmanProgramDeleteError)?;
+        for did in self.dispatchers.on_interface(if_index) {
+            if let Some(dispatcher) = self.dispatchers.dispatchers.get_mut(&did) {
+                if dispatcher.if_name() != iface {
+                    if let Err(e) = dispatcher.set_if_name(iface) {
+                        warn!("Unable to rename interface of dispatcher {did:?}: {e}");
+                    }
+                }
+            }
+        }
+    }
+
+    // Removes the dispatchers and tcx links on an interface that was removed.
+    // The programs attached there stay loaded and are attached again if the
+    // interface is recreated, see reattach_iface().
+    fn remove_iface(&mut self, if_index: u32, iface: &str) {
+        for did in self.dispatchers.on_interface(if_index) {
+            info!("Interface {iface} was removed, removing dispatcher {did:?}");
+            if let Some(mut dispatcher) = self.dispatchers.remove(&did) {
+                if let Err(e) = dispatcher.delete(true) {
+                    warn!("Unable to remove dispatcher {did:?}: {e}");
+                }
+            }
+        }
+
+        for (id, program) in self.programs.programs.iter() {
+            if program.is_tcx()
+                && !program.is_detached()
+                && program.nsid().ok().flatten().is_none()
+                && program.if_index().ok().flatten() == Some(if_index)
+            {
+                info!("Interface {iface} was removed, removing the link of program {id}");
+                if let Err(e) = program.delete_links() {
+                    warn!("Unable to remove the links of program {id}: {e}");
+                }
+            }
+        }
+    }
+
+    // Attaches the XDP and TC programs on an interface again after it was
+    // recreated, which usually gives it a new index.
+    async fn reattach_iface(&mut self, if_index: u32, iface: &str) {
+        // Programs attached through a dispatcher lost it with the interface.
+        let mut dids = Vec::new();
+        let dispatchers = &self.dispatchers;
+        for program in self
+            .programs
+            .programs
+            .values_mut()
+            .chain(self.programs.attachments.values_mut())
+        {
+            if !uses_dispatcher(program)
+                || program.is_detached()
+                || program.nsid().ok().flatten().is_some()
+                || program.if_name().ok().as_deref() != Some(iface)
+            {
+                continue;
+            }
+            match program.dispatcher_id() {
+                Ok(Some(did)) if !dispatchers.contains(&did) => (),
+                _ => continue,
+            }
+            if let Err(e) = program.set_if_index(if_index) {
+                warn!("Unable to update the interface index of program: {e}");
+                continue;
+            }
+            let did = program.dispatcher_id().ok().flatten().unwrap();
+            if !dids.iter().any(|(d, _, _)| *d == did) {
+                dids.push((did, program.kind(), program.direction().ok().flatten()));
+            }
+        }
+        for (did, program_type, direction) in dids {
+            info!("Interface {iface} was recreated, attaching dispatcher {did:?}");
+            if let Err(e) = self
+                .create_dispatcher(did.clone(), program_type, if_index, direction)
+                .await
+            {
+                warn!("Unable to attach dispatcher {did:?}: {e}");
+            }
+        }
+
+        // tcx programs lost their links with the interface.
+        let tcx_ids: Vec<u32> = self
+            .programs
+            .programs
+            .iter()
+            .filter(|(_, p)| {
+                p.is_tcx()
+                    && !p.is_detached()
+                    && p.nsid().ok().flatten().is_none()
+                    && p.if_name().ok().as_deref() == Some(iface)
+                    && p.if_index().ok().flatten() != Some(if_index)
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        for id in tcx_ids {
+            info!("Interface {iface} was recreated, attaching program {id}");
+            let Some(mut program) = self.programs.remove(&id) else {
+                continue;
+            };
+            let res = match program.delete_links() {
+                Ok(()) => match program.set_if_index(if_index) {
+                    Ok(()) => self.attach_pinned_tcx_program(&mut program),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(BpfmanError::Error(format!(
+                    "unable to remove links of program {id}: {e}"
+                ))),
+            };
+            self.programs.insert(id, program);
+            if let Err(e) = res {
+                warn!("Unable to attach program {id}: {e}");
+            }
+        }
+    }
+
+    // Attaches a new dispatcher for the programs on an interface in bpfman's
+    // network namespace that has none, see reattach_iface().
+    async fn create_dispatcher(
+        &mut self,
+        did: DispatcherId,
+        program_type: ProgramType,
+        if_index: u32,
+        direction: Option<Direction>,
+    ) -> Result<(), BpfmanError> {
+        self.programs
+            .set_program_positions(program_type, if_index, direction, None, false);
+
+        let if_index = Some(if_index);
+        let mut programs: Vec<&mut Program> = self
+            .programs
+            .programs_mut(&program_type, &if_index, &direction, &None)
+            .collect();
+        let if_name = match programs.first() {
+            Some(p) => p.if_name()?,
+            None => return Ok(()),
+        };
+        let if_config = if let Some(ref i) = self.config.interfaces {
+            i.get(&if_name)
+        } else {
+            None
+        };
 
+        let result = Dispatcher::new(
+            if_config,
+            &self.config.dispatcher_images,
+            &mut programs,
+            1,
+            None,
+            self.image_manager.clone(),
+        )
+        .await;
+        self.events.record(Event::dispatcher_rebuild(
+            program_type,
+            &if_name,
+            1,
+            &result,
+        ));
+        self.dispatchers.insert(did, result?);
         Ok(())
     }
 
@@ -744,17 +2845,20 @@ impl BpfManager {
             program.kind(),
             program.if_index()?.unwrap(),
             program.direction()?,
+            program.nsid()?,
+            false,
         );
 
         let program_type = program.kind();
         let if_index = program.if_index()?;
         let if_name = program.if_name().unwrap();
         let direction = program.direction()?;
+        let nsid = program.nsid()?;
 
         // Intentionally don't add filter program here
         let mut programs: Vec<&mut Program> = self
             .programs
-            .programs_mut(&program_type, &if_index, &direction)
+            .programs_mut(&program_type, &if_index, &direction, &nsid)
             .collect();
 
         let if_config = if let Some(ref i) = self.config.interfaces {
@@ -768,15 +2872,22 @@ impl BpfManager {
             1
         };
         debug!("next_revision = {next_revision}");
-        let dispatcher = Dispatcher::new(
+        let result = Dispatcher::new(
             if_config,
+            &self.config.dispatcher_images,
             &mut programs,
             next_revision,
             old_dispatcher,
             self.image_manager.clone(),
         )
-        .await?;
-        self.dispatchers.insert(did, dispatcher);
+        .await;
+        self.events.record(Event::dispatcher_rebuild(
+            program_type,
+            &if_name,
+            next_revision,
+            &result,
+        ));
+        self.dispatchers.insert(did, result?);
         Ok(())
     }
 
@@ -786,6 +2897,7 @@ impl BpfManager {
         if_index: u32,
         program_type: ProgramType,
         direction: Option<Direction>,
+        nsid: Option<u64>,
     ) -> Result<(), BpfmanError> {
         debug!("BpfManager::rebuild_multiattach_dispatcher() for program type {program_type} on if_index {if_index:?}");
         let mut old_dispatcher = self.dispatchers.remove(&did);
@@ -793,11 +2905,11 @@ impl BpfManager {
         if let Some(ref mut old) = old_dispatcher {
             debug!("Rebuild Multiattach Dispatcher for {did:?}");
             self.programs
-                .set_program_positions(program_type, if_index, direction);
+                .set_program_positions(program_type, if_index, direction, nsid, false);
             let if_index = Some(if_index);
             let mut programs: Vec<&mut Program> = self
                 .programs
-                .programs_mut(&program_type, &if_index, &direction)
+                .programs_mut(&program_type, &if_index, &direction, &nsid)
                 .collect();
 
             debug!("programs loaded: {}", programs.len());
@@ -822,15 +2934,22 @@ impl BpfManager {
                 1
             };
 
-            let dispatcher = Dispatcher::new(
+            let result = Dispatcher::new(
                 if_config,
+                &self.config.dispatcher_images,
                 &mut programs,
                 next_revision,
                 old_dispatcher,
                 self.image_manager.clone(),
             )
-            .await?;
-            self.dispatchers.insert(did, dispatcher);
+            .await;
+            self.events.record(Event::dispatcher_rebuild(
+                program_type,
+                &if_name,
+                next_revision,
+                &result,
+            ));
+            self.dispatchers.insert(did, result?);
         } else {
             debug!("No dispatcher found in rebuild_multiattach_dispatcher() for {did:?}");
         }
@@ -868,6 +2987,46 @@ impl BpfManager {
             .collect()
     }
 
+    // Lists the programs matching the filter a page at a time, in the order of
+    // their ids. Pages start after the program whose id is the page token, the
+    // last program of the previous page.
+    pub(crate) fn list_programs_page(
+        &mut self,
+        args: &ListArgs,
+    ) -> Result<ProgramPage, BpfmanError> {
+        let after = args
+            .page_token
+            .as_ref()
+            .map(|t| {
+                t.parse::<u32>()
+                    .map_err(|_| BpfmanError::Error(format!("invalid page token {t}")))
+            })
+            .transpose()?;
+
+        let mut programs = Vec::new();
+        for program in self.list_programs()? {
+            let id = program.get_data().get_id()?;
+            if after.map_or(true, |a| id > a) && args.filter.matches(&program)? {
+                programs.push((id, program));
+            } else {
+                discard_listed(&program);
+            }
+        }
+        programs.sort_by_key(|(id, _)| *id);
+
+        let mut next_page_token = None;
+        if args.page_size > 0 && programs.len() > args.page_size {
+            for (_, program) in programs.drain(args.page_size..) {
+                discard_listed(&program);
+            }
+            next_page_token = programs.last().map(|(id, _)| id.to_string());
+        }
+        Ok(ProgramPage {
+            programs: programs.into_iter().map(|(_, p)| p).collect(),
+            next_page_token,
+        })
+    }
+
     pub(crate) fn get_program(&mut self, id: u32) -> Result<Program, BpfmanError> {
         debug!("Getting program with id: {id}");
         // If the program was loaded by bpfman, then use it.
@@ -899,7 +3058,61 @@ impl BpfManager {
         }
     }
 
+    pub(crate) fn capabilities(&self) -> Capabilities {
+        let kernel_features = [
+            ("tcx", check_tcx_supported().is_ok()),
+            ("netkit", check_netkit_supported().is_ok()),
+            ("netfilter", check_netfilter_supported().is_ok()),
+            ("bpf_lsm", check_bpf_lsm_enabled().is_ok()),
+        ]
+        .into_iter()
+        .filter(|(_, supported)| *supported)
+        .map(|(feature, _)| feature.to_string())
+        .collect();
+        Capabilities {
+            kernel_release: kernel_release().unwrap_or_default(),
+            kernel_features,
+            xdp_dispatcher_image: self.config.dispatcher_images.xdp.url.clone(),
+            tc_dispatcher_image: self.config.dispatcher_images.tc.url.clone(),
+        }
+    }
+
+    // Returns the program loaded by bpfman with the UUID given on its load.
+    pub(crate) fn get_program_by_uuid(&mut self, uuid: &str) -> Result<Program, BpfmanError> {
+        debug!("Getting program with uuid: {uuid}");
+        self.programs
+            .get_programs_iter()
+            .find(|(_, p)| {
+                p.get_data()
+                    .get_uuid()
+                    .is_ok_and(|u| u.as_deref() == Some(uuid))
+            })
+            .map(|(_, p)| p.to_owned())
+            .ok_or(BpfmanError::Error(format!(
+                "Program with uuid {uuid} does not exist"
+            )))
+    }
+
+    // Reads the output of a bpf_iter program. Reading the pinned iterator link
+    // creates a new iterator instance, so each call sees fresh kernel state.
+    pub(crate) async fn read_iter(&self, id: u32) -> Result<Vec<u8>, BpfmanError> {
+        match self.programs.get(&id) {
+            Some(Program::Iter(_)) => (),
+            Some(_) => {
+                return Err(BpfmanError::Error(format!(
+                    "program {id} is not an iterator program"
+                )))
+            }
+            None => return Err(BpfmanError::Error(format!("Program {id} does not exist"))),
+        }
+
+        tokio::fs::read(format!("{RTDIR_FS}/prog_{id}_link"))
+            .await
+            .map_err(|e| BpfmanError::Error(format!("unable to read iterator {id}: {e}")))
+    }
+
     async fn pull_bytecode(&self, args: PullBytecodeArgs) -> anyhow::Result<()> {
+        let url = args.image.image_url.clone();
         let (tx, rx) = oneshot::channel();
         self.image_manager
             .send(ImageManagerCommand::Pull {
@@ -907,13 +3120,22 @@ impl BpfManager {
                 pull_policy: args.image.image_pull_policy.clone(),
                 username: args.image.username.clone(),
                 password: args.image.password.clone(),
+                context: Context::current(),
                 resp: tx,
             })
             .await?;
         let res = match rx.await? {
             Ok(_) => {
                 info!("Successfully pulled bytecode");
-                Ok(())
+                // The pulled image is described as it's now in the image store.
+                self.list_images().await.and_then(|images| {
+                    images
+                        .into_iter()
+                        .find(|i| i.url == url)
+                        .ok_or(BpfmanError::Error(format!(
+                            "image {url} is not in the image store"
+                        )))
+                })
             }
             Err(e) => Err(BpfmanError::BpfBytecodeError(e)),
         };
@@ -921,6 +3143,123 @@ impl BpfManager {
         Ok(())
     }
 
+    // Returns the ids of the loaded programs along with the URLs of their
+    // images, and of the images of the programs they replaced.
+    fn program_images(&self) -> Vec<(u32, String)> {
+        let mut images = vec![];
+        for (id, program) in self.programs.programs.iter() {
+            let data = program.get_data();
+            let previous = data.get_previous().ok().flatten();
+            for location in [Some(data), previous.as_ref()]
+                .into_iter()
+                .flatten()
+                .filter_map(|d| d.get_location().ok())
+            {
+                if let Location::Image(image) = location {
+                    images.push((*id, image.image_url));
+                }
+            }
+        }
+        images
+    }
+
+    // Returns the URLs of the images of the loaded programs and of the
+    // dispatcher images.
+    fn images_in_use(&self) -> Vec<String> {
+        let mut images = vec![
+            self.config.dispatcher_images.xdp.url.clone(),
+            self.config.dispatcher_images.tc.url.clone(),
+        ];
+        images.extend(self.program_images().into_iter().map(|(_, url)| url));
+        images
+    }
+
+    async fn list_images(&self) -> Result<Vec<ImageInfo>, BpfmanError> {
+        let (tx, rx) = oneshot::channel();
+        self.image_manager
+            .send(ImageManagerCommand::List {
+                programs: self.program_images(),
+                resp: tx,
+            })
+            .await
+            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+        rx.await
+            .map_err(BpfmanError::RpcRecvError)?
+            .map_err(BpfmanError::BpfBytecodeError)
+    }
+
+    // Removes the images no program uses from the image store, as far as the
+    // limits require, and returns their URLs.
+    async fn prune_images(&self, limits: ImageCacheConfig) -> Result<Vec<String>, BpfmanError> {
+        let (tx, rx) = oneshot::channel();
+        self.image_manager
+            .send(ImageManagerCommand::Prune {
+                in_use: self.images_in_use(),
+                limits,
+                resp: tx,
+            })
+            .await
+            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+        rx.await
+            .map_err(BpfmanError::RpcRecvError)?
+            .map_err(BpfmanError::BpfBytecodeError)
+    }
+
+    // Keeps the image store within the limits of the image cache configuration
+    // after images were pulled or programs unloaded.
+    // Uploaded bytecode is collected along with images.
+    async fn collect_images(&self) {
+        self.collect_uploads().await;
+        if let Some(limits) = self.config.image_cache {
+            if let Err(e) = self.prune_images(limits).await {
+                warn!("Unable to prune the image store: {e}");
+            }
+        }
+    }
+
+    // Removes the uploaded bytecode no program, or program it replaced, is
+    // loaded from anymore.
+    async fn collect_uploads(&self) {
+        let mut in_use = HashSet::new();
+        for program in self.programs.programs.values() {
+            let data = program.get_data();
+            let previous = data.get_previous().ok().flatten();
+            for location in [Some(data), previous.as_ref()]
+                .into_iter()
+                .flatten()
+                .filter_map(|d| d.get_location().ok())
+            {
+                if let Location::File(path) = location {
+                    in_use.insert(PathBuf::from(path));
+                }
+            }
+        }
+
+        let Ok(mut entries) = tokio::fs::read_dir(RTDIR_UPLOADS).await else {
+            return;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if !in_use.contains(&path) {
+                debug!("Removing uploaded bytecode {}", path.display());
+                if let Err(e) = tokio::fs::remove_file(&path).await {
+                    warn!("Unable to remove uploaded bytecode {}: {e}", path.display());
+                }
+            }
+        }
+    }
+
+    async fn prune_images_command(&self, args: PruneImagesArgs) {
+        // Images are removed regardless of age as long as they aren't used.
+        let limits = ImageCacheConfig {
+            max_size: Some(0),
+            max_age: None,
+        };
+        let res = self.prune_images(limits).await;
+        // Ignore errors as they'll be propagated to caller in the RPC status
+        let _ = args.responder.send(res);
+    }
+
     pub(crate) async fn process_commands(&mut self, mut shutdown_channel: broadcast::Receiver<()>) {
         loop {
             // Start receiving messages
@@ -931,34 +3270,183 @@ impl BpfManager {
                     ROOT_DB.flush().expect("Unable to flush database to disk before shutting down BpfManager");
                     break;
                 }
-                Some(cmd) = self.commands.recv() => {
-                    match cmd {
-                        Command::Load(args) => {
-                            let prog = self.add_program(args.program).await;
-                            // Ignore errors as they'll be propagated to caller in the RPC status
-                            let _ = args.responder.send(prog);
-                        },
-                        Command::Unload(args) => self.unload_command(args).await.unwrap(),
-                        Command::List { responder } => {
-                            let progs = self.list_programs();
-                            // Ignore errors as they'll be propagated to caller in the RPC status
-                            let _ = responder.send(progs);
-                        }
-                        Command::Get(args) => {
-                            let prog = self.get_program(args.id);
-                            // Ignore errors as they'll be propagated to caller in the RPC status
-                            let _ = args.responder.send(prog);
-                        },
-                        Command::PullBytecode (args) => self.pull_bytecode(args).await.unwrap(),
-                    }
+                Some((cmd, context)) = self.commands.recv() => {
+                    let cx = child_span(cmd.span_name(), &context);
+                    self.handle_command(cmd).with_context(cx).await;
                 }
             }
         }
         info!("Stopping processing commands");
     }
 
+    async fn handle_command(&mut self, cmd: Command) {
+        match cmd {
+            Command::Load(args) => {
+                let requested = vec![Event::of(EventKind::Load, &args.program)];
+                let prog = self.add_program(args.program).await;
+                self.events.record_loads(
+                    EventKind::Load,
+                    requested,
+                    prog.as_ref().map(slice::from_ref),
+                );
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(prog);
+                self.collect_images().await;
+            }
+            Command::LoadBatch(args) => {
+                let requested = load_events(&args.programs);
+                let progs = self.add_programs(args.programs).await;
+                self.events
+                    .record_loads(EventKind::Load, requested, progs.as_deref());
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(progs);
+                self.collect_images().await;
+            }
+            Command::LoadObject(args) => {
+                let requested = load_events(&args.programs);
+                let progs = self.add_programs_sharing_maps(args.programs).await;
+                self.events
+                    .record_loads(EventKind::Load, requested, progs.as_deref());
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(progs);
+                self.collect_images().await;
+            }
+            Command::Unload(args) => {
+                self.unload_command(args).await.unwrap();
+                self.collect_images().await;
+            }
+            Command::Attach(args) => self.attach_command(args).await.unwrap(),
+            Command::Detach(args) => self.detach_command(args).await.unwrap(),
+            Command::SetPriority(args) => self.set_priority_command(args).await.unwrap(),
+            Command::PublishMaps(args) => {
+                let res = self.publish_maps(args.id, &args.name);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::UnpublishMaps(args) => {
+                let res = self.unpublish_maps(&args.name);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::ExportMaps(args) => {
+                let res = self.export_maps(args.id, &args.maps, &args.path, args.mount);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::UnexportMaps(args) => {
+                let res = self.unexport_maps(&args.path);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::Replace(args) => {
+                let prog = self.replace_program(args.id, args.data).await;
+                self.record_replacement(EventKind::Replace, args.id, &prog);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(prog);
+            }
+            Command::Rollback(args) => {
+                let prog = self.rollback_program(args.id).await;
+                self.record_replacement(EventKind::Rollback, args.id, &prog);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(prog);
+            }
+            Command::List(args) => {
+                let page = self.list_programs_page(&args);
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(page);
+            }
+            Command::Get(args) => {
+                let prog = match args.uuid {
+                    Some(uuid) => self.get_program_by_uuid(&uuid),
+                    None => self.get_program(args.id),
+                };
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(prog);
+            }
+            Command::PullBytecode(args) => {
+                self.pull_bytecode(args).await.unwrap();
+                self.collect_images().await;
+            }
+            Command::PruneImages(args) => self.prune_images_command(args).await,
+            Command::Version(args) => {
+                let _ = args.responder.send(self.capabilities());
+            }
+            Command::ListImages(args) => {
+                let res = self.list_images().await;
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::Read(args) => {
+                let res = self.read_iter(args.id).await;
+                // Ignore errors as they'll be propagated to caller in the RPC status
+                let _ = args.responder.send(res);
+            }
+            Command::LinkEvent(event) => self.handle_link_event(event).await,
+            Command::ReloadConfig(config) => self.reload_config(*config).await,
+        }
+    }
+
     async fn unload_command(&mut self, args: UnloadArgs) -> anyhow::Result<()> {
+        let event = self.program_event(EventKind::Unload, args.id);
         let res = self.remove_program(args.id).await;
+        self.events.record(event.result(&res));
+        // Ignore errors as they'll be propagated to caller in the RPC status
+        let _ = args.responder.send(res);
+        Ok(())
+    }
+
+    // An event of the program with id, as it is before the change.
+    fn program_event(&self, kind: EventKind, id: u32) -> Event {
+        match self.programs.get(&id) {
+            Some(program) => Event::of(kind, program),
+            None => Event {
+                program_id: Some(id),
+                ..Event::new(kind, "program".to_string())
+            },
+        }
+    }
+
+    // Records the replacement of the program with id by the new program of
+    // result.
+    fn record_replacement(&self, kind: EventKind, id: u32, result: &Result<Program, BpfmanError>) {
+        let event = match result {
+            Ok(program) => {
+                let mut event = Event::of(kind, program);
+                event.details = format!("{}, replacing program {id}", event.details);
+                event
+            }
+            Err(_) => self.program_event(kind, id).result(result),
+        };
+        self.events.record(event);
+    }
+
+    async fn attach_command(&mut self, args: AttachArgs) -> anyhow::Result<()> {
+        let res = match args.attachment {
+            Some(attachment) => self.add_attachment(args.id, attachment).await,
+            None => self.attach_program(args.id).await,
+        };
+        // Ignore errors as they'll be propagated to caller in the RPC status
+        let _ = args.responder.send(res);
+        Ok(())
+    }
+
+    async fn detach_command(&mut self, args: DetachArgs) -> anyhow::Result<()> {
+        let res = match args.iface {
+            Some(iface) => {
+                self.remove_attachment(args.id, &iface, args.direction)
+                    .await
+            }
+            None => self.detach_program(args.id).await,
+        };
+        // Ignore errors as they'll be propagated to caller in the RPC status
+        let _ = args.responder.send(res);
+        Ok(())
+    }
+
+    async fn set_priority_command(&mut self, args: SetPriorityArgs) -> anyhow::Result<()> {
+        let res = self
+            .set_priority(args.id, args.priority, args.iface, args.direction)
+            .await;
         // Ignore errors as they'll be propagated to caller in the RPC status
         let _ = args.responder.send(res);
         Ok(())
@@ -977,6 +3465,260 @@ impl BpfManager {
         ))
     }
 
+    // Publishes the maps of program id under name, so programs can be loaded
+    // using them by name rather than by the ID of the program that owns them.
+    // The name is removed once the maps are no longer used by any program.
+    pub(crate) fn publish_maps(&mut self, id: u32, name: &str) -> Result<(), BpfmanError> {
+        info!("Publishing maps of program with id: {id} as {name}");
+
+        let program = self.programs.get(&id).ok_or(BpfmanError::Error(format!(
+            "Program {0} does not exist or was not created by bpfman",
+            id,
+        )))?;
+        let map_owner_id = program.get_data().get_map_owner_id()?.unwrap_or(id);
+        if !self.maps.contains_key(&map_owner_id) {
+            return Err(BpfmanError::Error(format!("program {id} has no maps")));
+        }
+
+        match sled_get_option(&self.published_maps, name)?.map(bytes_to_u32) {
+            Some(owner) if owner != map_owner_id => Err(BpfmanError::Error(format!(
+                "maps of program {owner} are already published as {name}"
+            ))),
+            _ => sled_insert(&self.published_maps, name, &map_owner_id.to_ne_bytes()),
+        }
+    }
+
+    // Removes a name maps were published under. Programs already using the
+    // maps keep them.
+    pub(crate) fn unpublish_maps(&mut self, name: &str) -> Result<(), BpfmanError> {
+        info!("Unpublishing maps {name}");
+
+        self.published_maps
+            .remove(name)
+            .map_err(|e| {
+                BpfmanError::DatabaseError(
+                    format!("Unable to remove published maps {name}"),
+                    e.to_string(),
+                )
+            })?
+            .map(|_| ())
+            .ok_or(BpfmanError::Error(format!(
+                "no maps are published as {name}"
+            )))
+    }
+
+    // Exports maps of program id to path, see map_export.rs. Exports are kept
+    // in the database by path, with the program owning the maps, the names of
+    // the maps and, for mounted exports, the bpffs mounted for them.
+    pub(crate) fn export_maps(
+        &mut self,
+        id: u32,
+        maps: &[String],
+        path: &Path,
+        mount: bool,
+    ) -> Result<Vec<String>, BpfmanError> {
+        info!(
+            "Exporting maps of program with id: {id} to {}",
+            path.display()
+        );
+
+        let program = self.programs.get(&id).ok_or(BpfmanError::Error(format!(
+            "Program {0} does not exist or was not created by bpfman",
+            id,
+        )))?;
+        let map_owner_id = program.get_data().get_map_owner_id()?.unwrap_or(id);
+        if !self.maps.contains_key(&map_owner_id) {
+            return Err(BpfmanError::Error(format!("program {id} has no maps")));
+        }
+        let key = path.to_string_lossy();
+        if sled_get_option(&self.map_exports, &format!("owner_{key}"))?.is_some() {
+            return Err(BpfmanError::Error(format!(
+                "maps are already exported to {key}"
+            )));
+        }
+
+        let staging = mount
+            .then(|| ROOT_DB.generate_id())
+            .transpose()
+            .map_err(|e| {
+                BpfmanError::DatabaseError(
+                    "Unable to generate export id".to_string(),
+                    e.to_string(),
+                )
+            })?
+            .map(|n| PathBuf::from(RTDIR_MAP_EXPORTS).join(n.to_string()));
+        let exported = map_export::export_maps(
+            &calc_map_pin_path(map_owner_id),
+            maps,
+            path,
+            staging.as_deref(),
+        )?;
+
+        sled_insert(
+            &self.map_exports,
+            &format!("owner_{key}"),
+            &map_owner_id.to_ne_bytes(),
+        )?;
+        sled_insert(
+            &self.map_exports,
+            &format!("maps_{key}"),
+            exported.join(",").as_bytes(),
+        )?;
+        if let Some(staging) = &staging {
+            sled_insert(
+                &self.map_exports,
+                &format!("staging_{key}"),
+                staging.to_string_lossy().as_bytes(),
+            )?;
+        }
+        Ok(exported)
+    }
+
+    // Removes the maps exported to path.
+    pub(crate) fn unexport_maps(&mut self, path: &Path) -> Result<(), BpfmanError> {
+        info!("Unexporting maps from {}", path.display());
+
+        let key = path.to_string_lossy();
+        if sled_get_option(&self.map_exports, &format!("owner_{key}"))?.is_none() {
+            return Err(BpfmanError::Error(format!("no maps are exported to {key}")));
+        }
+        let maps: Vec<String> = sled_get_option(&self.map_exports, &format!("maps_{key}"))?
+            .map(|v| bytes_to_string(&v))
+            .unwrap_or_default()
+            .split(',')
+            .filter(|m| !m.is_empty())
+            .map(|m| m.to_string())
+            .collect();
+        let staging = sled_get_option(&self.map_exports, &format!("staging_{key}"))?
+            .map(|v| PathBuf::from(bytes_to_string(&v)));
+
+        map_export::remove_export(path, &maps, staging.as_deref())?;
+        for prefix in ["owner", "maps", "staging"] {
+            self.map_exports
+                .remove(format!("{prefix}_{key}"))
+                .map_err(|e| {
+                    BpfmanError::DatabaseError(
+                        format!("Unable to remove map export {key}"),
+                        e.to_string(),
+                    )
+                })?;
+        }
+        Ok(())
+    }
+
+    // Sets the map owner of a program loaded with the name of published maps
+    // to the program that owns them.
+    fn resolve_map_from(&self, program: &mut Program) -> Result<(), BpfmanError> {
+        if let Some(name) = program.get_data().get_map_from()? {
+            let map_owner_id = sled_get_option(&self.published_maps, &name)?
+                .map(bytes_to_u32)
+                .ok_or(BpfmanError::Error(format!(
+                    "no maps are published as {name}"
+                )))?;
+            program.get_data_mut().set_map_owner_id(map_owner_id)?;
+        }
+        Ok(())
+    }
+
+    // Decides the load by the load policy and then by the admission hook, if
+    // they are configured.
+    async fn admit_load(&mut self, program: &mut Program) -> Result<(), BpfmanError> {
+        if let Some(policy) = &mut self.policy {
+            policy.evaluate(&load_request(program)?)?;
+        }
+        if let Some(admission) = &self.config.admission {
+            admit(admission, program).await?;
+        }
+        Ok(())
+    }
+
+    // Rejects bytecode that isn't in the bytecode allowlist, if one is
+    // configured.
+    fn check_bytecode_allowed(&self, bytecode: &[u8]) -> Result<(), BpfmanError> {
+        match &self.config.bytecode_allowlist {
+            Some(allowlist) => check_allowed(Path::new(&allowlist.path), bytecode),
+            None => Ok(()),
+        }
+    }
+
+    // Returns the number of programs of owner and the approximate memory of
+    // their maps.
+    fn owner_usage(&self, owner: &str) -> Result<(u32, u64), BpfmanError> {
+        let mut usage = (0, 0);
+        for program in self.programs.programs.values() {
+            let data = program.get_data();
+            if data.get_owner()?.as_deref() == Some(owner) {
+                usage.0 += 1;
+                usage.1 += data.get_map_bytes()?;
+            }
+        }
+        Ok(usage)
+    }
+
+    // Denies loading count programs of the owner of program if that would
+    // exceed its quota.
+    fn check_program_quota(&self, program: &Program, count: u32) -> Result<(), BpfmanError> {
+        let (Some(quotas), Some(owner)) = (&self.config.quotas, program.get_data().get_owner()?)
+        else {
+            return Ok(());
+        };
+        let Some(max) = quotas.limits(&owner).max_programs else {
+            return Ok(());
+        };
+        let (programs, _) = self.owner_usage(&owner)?;
+        if programs + count > max {
+            return Err(BpfmanError::QuotaExceeded(format!(
+                "{owner} has {programs} of at most {max} programs loaded"
+            )));
+        }
+        Ok(())
+    }
+
+    // Records the memory of the maps a loaded program owns, and checks that
+    // its owner doesn't exceed its quota with them. The memory of maps is only
+    // known once the kernel created them, so the program has to be unloaded
+    // if it does.
+    fn check_map_quota(&self, program: &mut Program) -> Result<(), BpfmanError> {
+        let data = program.get_data_mut();
+        if data.get_map_owner_id()?.is_none() {
+            if let Some(path) = data.get_map_pin_path()? {
+                data.set_map_bytes(maps_memory(&path)?)?;
+            }
+        }
+        let (Some(quotas), Some(owner)) = (&self.config.quotas, data.get_owner()?) else {
+            return Ok(());
+        };
+        let Some(max) = quotas.limits(&owner).max_map_bytes else {
+            return Ok(());
+        };
+        let (_, bytes) = self.owner_usage(&owner)?;
+        if bytes > max {
+            return Err(BpfmanError::QuotaExceeded(format!(
+                "maps of {owner} would use about {bytes} of at most {max} bytes"
+            )));
+        }
+        Ok(())
+    }
+
+    // Adds the map pinning of the configuration to the map pin policy the
+    // program was loaded with. Names given with the program take precedence.
+    fn add_map_pinning_config(&self, program: &mut Program) -> Result<(), BpfmanError> {
+        if let Some(config) = &self.config.map_pinning {
+            let names = program.get_data().get_map_pin_policy()?.names;
+            program.get_data_mut().set_map_pin_policy(&MapPinPolicy {
+                include: config.include.clone(),
+                exclude: config.exclude.clone(),
+                names: config
+                    .names
+                    .iter()
+                    .filter(|(map, _)| !names.contains_key(*map))
+                    .map(|(map, name)| (map.clone(), name.clone()))
+                    .collect(),
+            })?;
+        }
+        Ok(())
+    }
+
     // This function is called if the program's map directory was created,
     // but the eBPF program failed to load. save_map() has not been called,
     // so self.maps has not been updated for this program.
@@ -1046,11 +3788,17 @@ impl BpfManager {
                 // Update this program with the updated map_used_by
                 data.set_maps_used_by(vec![id])?;
 
-                // Set the permissions on the map_pin_path directory.
+                // Set the permissions on the map_pin_path directory, giving it
+                // to the user and group of the program's map permissions.
                 if let Some(map_pin_path) = data.get_map_pin_path()? {
                     if let Some(path) = map_pin_path.to_str() {
                         debug!("bpf set dir permissions for {}", path);
-                        set_dir_permissions(path, MAPS_MODE).await;
+                        let permissions = data.get_map_permissions()?;
+                        let mode = permissions.mode.unwrap_or(MAPS_MODE);
+                        set_dir_permissions(path, mode).await;
+                        if permissions != MapPermissions::default() {
+                            set_dir_owner(path, permissions.uid, permissions.gid, mode).await?;
+                        }
                     } else {
                         return Err(BpfmanError::Error(format!(
                             "invalid map_pin_path {} for {}",
@@ -1091,6 +3839,29 @@ impl BpfManager {
                 // No more programs using this map, so remove the entry from the map list.
                 let path = calc_map_pin_path(index);
                 self.maps.remove(&index.clone());
+                // The names the maps were published under go with them.
+                for (name, owner) in self.published_maps.iter().filter_map(|e| e.ok()) {
+                    if bytes_to_u32(owner.to_vec()) == index {
+                        let _ = self.published_maps.remove(name);
+                    }
+                }
+                // And so do their exports.
+                let exports: Vec<PathBuf> = self
+                    .map_exports
+                    .scan_prefix("owner_")
+                    .filter_map(|e| e.ok())
+                    .filter(|(_, owner)| bytes_to_u32(owner.to_vec()) == index)
+                    .filter_map(|(key, _)| {
+                        bytes_to_string(&key)
+                            .strip_prefix("owner_")
+                            .map(PathBuf::from)
+                    })
+                    .collect();
+                for export in exports {
+                    if let Err(e) = self.unexport_maps(&export) {
+                        warn!("unable to remove map export {}: {e}", export.display());
+                    }
+                }
                 remove_dir_all(path)
                     .await
                     .map_err(|e| BpfmanError::Error(format!("can't delete map dir: {e}")))?;
@@ -1148,6 +3919,693 @@ impl BpfManager {
     }
 }
 
+// Programs not loaded by bpfman get state while they are listed, which is
+// dropped if they aren't returned.
+fn discard_listed(program: &Program) {
+    if let Program::Unsupported(_) = program {
+        if let Err(e) = program.delete() {
+            warn!("Unable to drop the state of a listed program: {e}");
+        }
+    }
+}
+
+fn cgroup_skb_attach_type(direction: Direction) -> CgroupSkbAttachType {
+    match direction {
+        Direction::Ingress => CgroupSkbAttachType::Ingress,
+        Direction::Egress => CgroupSkbAttachType::Egress,
+    }
+}
+
+// Attaches a loaded cgroup_skb program to its cgroup and pins the resulting
+// link so the attachment outlives the bpfman process.
+fn attach_cgroup_skb(
+    cgroup_skb: &mut CgroupSkb,
+    program: &CgroupSkbProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = cgroup_skb.attach(
+        cgroup,
+        cgroup_skb_attach_type(program.get_direction()?),
+        program.get_attach_flags()?.into(),
+    )?;
+
+    let owned_link: CgroupSkbLink = cgroup_skb.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned cgroup_skb attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+fn sock_addr_attach_type(attach_point: SockAddrAttachPoint) -> CgroupSockAddrAttachType {
+    match attach_point {
+        SockAddrAttachPoint::Bind4 => CgroupSockAddrAttachType::Bind4,
+        SockAddrAttachPoint::Bind6 => CgroupSockAddrAttachType::Bind6,
+        SockAddrAttachPoint::Connect4 => CgroupSockAddrAttachType::Connect4,
+        SockAddrAttachPoint::Connect6 => CgroupSockAddrAttachType::Connect6,
+        SockAddrAttachPoint::GetPeerName4 => CgroupSockAddrAttachType::GetPeerName4,
+        SockAddrAttachPoint::GetPeerName6 => CgroupSockAddrAttachType::GetPeerName6,
+        SockAddrAttachPoint::GetSockName4 => CgroupSockAddrAttachType::GetSockName4,
+        SockAddrAttachPoint::GetSockName6 => CgroupSockAddrAttachType::GetSockName6,
+        SockAddrAttachPoint::SendMsg4 => CgroupSockAddrAttachType::UDPSendMsg4,
+        SockAddrAttachPoint::SendMsg6 => CgroupSockAddrAttachType::UDPSendMsg6,
+        SockAddrAttachPoint::RecvMsg4 => CgroupSockAddrAttachType::UDPRecvMsg4,
+        SockAddrAttachPoint::RecvMsg6 => CgroupSockAddrAttachType::UDPRecvMsg6,
+    }
+}
+
+// Returns the attach point a cgroup/sock_addr program in section is built for,
+// like connect4 for "cgroup/connect4".
+fn section_sock_addr_attach_point(section: &str) -> Option<SockAddrAttachPoint> {
+    match section.split('/').collect::<Vec<_>>()[..] {
+        ["cgroup", attach_point, ..] => {
+            SockAddrAttachPoint::try_from(attach_point.to_string()).ok()
+        }
+        _ => None,
+    }
+}
+
+// The attach type of a cgroup/sock_addr program comes from its section when
+// it's loaded, but the requested attach point is the one it's loaded from its
+// pin with after a restart, so loads where they differ are rejected.
+fn check_sock_addr_attach_point(program: &CgroupSockAddrProgram) -> Result<(), BpfmanError> {
+    let name = program.get_data().get_name()?;
+    let attach_point = program.get_attach_point()?;
+    let Some(section) = usdt::function_section(program.get_data().program_bytes(), &name)? else {
+        return Ok(());
+    };
+    if section_sock_addr_attach_point(&section) != Some(attach_point) {
+        return Err(BpfmanError::SockAddrAttachPointMismatch {
+            name,
+            section,
+            attach_point: attach_point.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Attaches a loaded cgroup/sock_addr program to its cgroup and pins the
+// resulting link, with the attach type checked at load.
+fn attach_cgroup_sock_addr(
+    sock_addr: &mut CgroupSockAddr,
+    program: &CgroupSockAddrProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = sock_addr.attach(cgroup, program.get_attach_flags()?.into())?;
+
+    let owned_link: CgroupSockAddrLink = sock_addr.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned cgroup_sock_addr attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+// Attaches a loaded sockops program to its cgroup2 path and pins the
+// resulting link.
+fn attach_sock_ops(sock_ops: &mut SockOps, program: &SockOpsProgram) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = sock_ops.attach(cgroup, program.get_attach_flags()?.into())?;
+
+    let owned_link: SockOpsLink = sock_ops.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned sock_ops attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+// Attaches a loaded cgroup device program to its cgroup2 path and pins the
+// resulting link.
+fn attach_cgroup_device(
+    cgroup_device: &mut CgroupDevice,
+    program: &CgroupDeviceProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = cgroup_device.attach(cgroup, program.get_attach_flags()?.into())?;
+
+    let owned_link: CgroupDeviceLink = cgroup_device.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned cgroup_device attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+// Attaches a loaded cgroup sysctl program to its cgroup2 path and pins the
+// resulting link.
+fn attach_cgroup_sysctl(
+    cgroup_sysctl: &mut CgroupSysctl,
+    program: &CgroupSysctlProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = cgroup_sysctl.attach(cgroup, program.get_attach_flags()?.into())?;
+
+    let owned_link: CgroupSysctlLink = cgroup_sysctl.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned cgroup_sysctl attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+fn sockopt_attach_type(attach_type: SockoptAttachType) -> CgroupSockoptAttachType {
+    match attach_type {
+        SockoptAttachType::Get => CgroupSockoptAttachType::Get,
+        SockoptAttachType::Set => CgroupSockoptAttachType::Set,
+    }
+}
+
+// Attaches a loaded cgroup sockopt program to its cgroup2 path and pins the
+// resulting link. Whether getsockopt or setsockopt calls are filtered comes
+// from the program's section, the attach type recorded for the program is
+// only needed to load it from its pin again.
+fn attach_cgroup_sockopt(
+    cgroup_sockopt: &mut CgroupSockopt,
+    program: &CgroupSockoptProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let cgroup_path = program.get_cgroup_path()?;
+    let cgroup = std::fs::File::open(&cgroup_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open cgroup {cgroup_path}: {e}")))?;
+
+    let link_id = cgroup_sockopt.attach(cgroup, program.get_attach_flags()?.into())?;
+
+    let owned_link: CgroupSockoptLink = cgroup_sockopt.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned cgroup_sockopt attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+// Returns the pin path of the sockmap an sk_msg or sk_skb program attaches
+// to, or None if the map is owned by the program and hasn't been pinned yet.
+fn sock_map_path(
+    data: &ProgramData,
+    map_name: String,
+    sock_map_pin_path: Option<String>,
+) -> Result<Option<PathBuf>, BpfmanError> {
+    if let Some(path) = sock_map_pin_path {
+        return Ok(Some(PathBuf::from(path)));
+    }
+    Ok(data.get_map_pin_path()?.map(|p| p.join(map_name)))
+}
+
+fn sk_msg_map_path(program: &SkMsgProgram) -> Result<Option<PathBuf>, BpfmanError> {
+    sock_map_path(
+        program.get_data(),
+        program.get_map_name()?,
+        program.get_sock_map_pin_path()?,
+    )
+}
+
+fn sk_skb_map_path(program: &SkSkbProgram) -> Result<Option<PathBuf>, BpfmanError> {
+    sock_map_path(
+        program.get_data(),
+        program.get_map_name()?,
+        program.get_sock_map_pin_path()?,
+    )
+}
+
+fn sk_skb_kind(attach_type: SkSkbAttachType) -> SkSkbKind {
+    match attach_type {
+        SkSkbAttachType::StreamParser => SkSkbKind::StreamParser,
+        SkSkbAttachType::StreamVerdict => SkSkbKind::StreamVerdict,
+    }
+}
+
+// Returns the fd of the sockmap or sockhash sk_msg and sk_skb programs are
+// attached to, whatever the key type of a sockhash.
+fn sock_map_fd(map: &Map) -> Result<BorrowedFd<'_>, BpfmanError> {
+    match map {
+        Map::SockMap(data) | Map::SockHash(data) => Ok(data.fd().as_fd()),
+        _ => Err(BpfmanError::Error(
+            "sk_msg and sk_skb programs must be attached to a sockmap or sockhash".to_string(),
+        )),
+    }
+}
+
+// Adjusts the semaphores guarding a usdt program's probe sites in its target
+// process, +1 while attached and -1 once detached. Sites of the same probe
+// usually share a single semaphore, so each one is only updated once.
+fn update_usdt_semaphores(program: &UsdtProgram, delta: i16) -> Result<(), BpfmanError> {
+    let Some(pid) = program.get_pid()? else {
+        return Ok(());
+    };
+    let target = program.get_target()?;
+    let semaphores: BTreeSet<u64> =
+        usdt::find_probes(&target, &program.get_provider()?, &program.get_name()?)?
+            .iter()
+            .filter_map(|p| p.semaphore_offset)
+            .collect();
+
+    for semaphore in semaphores {
+        usdt::update_semaphore(pid, &target, semaphore, delta)?;
+    }
+    Ok(())
+}
+
+// Opens the program an extension program replaces a function of, either from
+// its pin path or by looking up its kernel program id.
+fn extension_target_fd(program: &ExtensionProgram) -> Result<ProgramFd, BpfmanError> {
+    let info = match program.get_target_path()? {
+        Some(path) => ProgramInfo::from_pin(path)?,
+        None => {
+            let target_id = program
+                .get_target_id()?
+                .ok_or_else(|| BpfmanError::Error("extension target not set".to_string()))?;
+            loaded_programs()
+                .filter_map(|p| p.ok())
+                .find(|p| p.id() == target_id)
+                .ok_or_else(|| {
+                    BpfmanError::Error(format!("extension target program {target_id} not found"))
+                })?
+        }
+    };
+    Ok(info.fd()?)
+}
+
+// sk_msg programs are attached to a map rather than through a link, so there
+// is nothing pinned that can be removed to detach them. They are detached from
+// the map they were attached to instead, see sock_map.
+fn detach_sk_msg(program: &SkMsgProgram) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let path = sk_msg_map_path(program)?.ok_or(BpfmanError::Error(format!(
+        "no sockmap recorded for sk_msg program {id}"
+    )))?;
+
+    let sk_msg = SkMsg::from_pin(format!("{RTDIR_FS}/prog_{id}"))?;
+    let map = Map::from_map_data(MapData::from_pin(path)?)?;
+    sock_map::detach(
+        sock_map_fd(&map)?,
+        sk_msg.fd()?.as_fd(),
+        SockMapProgram::SkMsg,
+    )
+}
+
+// See detach_sk_msg, sk_skb programs are attached to maps the same way.
+fn detach_sk_skb(program: &SkSkbProgram) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let path = sk_skb_map_path(program)?.ok_or(BpfmanError::Error(format!(
+        "no sockmap recorded for sk_skb program {id}"
+    )))?;
+
+    let attach_type = program.get_attach_type()?;
+    let sk_skb = SkSkb::from_pin(format!("{RTDIR_FS}/prog_{id}"), sk_skb_kind(attach_type))?;
+    let map = Map::from_map_data(MapData::from_pin(path)?)?;
+    sock_map::detach(
+        sock_map_fd(&map)?,
+        sk_skb.fd()?.as_fd(),
+        SockMapProgram::SkSkb(attach_type),
+    )
+}
+
+// The link pin for a cgroup program can disappear while bpfman is down, e.g.
+// if the cgroup was removed and recreated. If the program itself is still
+// pinned, attach it again so the database and the kernel agree.
+fn reattach_program(program: &mut Program) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    if let Program::SkLookup(p) = program {
+        remove_stale_sk_lookup_link(p)?;
+    }
+    if program.is_detached() || Path::new(&format!("{RTDIR_FS}/prog_{id}_link")).exists() {
+        return Ok(());
+    }
+
+    match program {
+        Program::CgroupSkb(_)
+        | Program::CgroupSockAddr(_)
+        | Program::SockOps(_)
+        | Program::SkLookup(_)
+        | Program::CgroupDevice(_)
+        | Program::CgroupSysctl(_)
+        | Program::CgroupSockopt(_) => {
+            debug!("reattaching {} program {id}", program.kind());
+            attach_pinned_program(program)
+        }
+        _ => Ok(()),
+    }
+}
+
+// Returns true for the programs that can be detached without being unloaded.
+// Except for the ones attached through a dispatcher, they can also be loaded
+// without being attached.
+fn supports_detach(program: &Program) -> bool {
+    match program {
+        Program::Xdp(_)
+        | Program::Tc(_)
+        | Program::Tracepoint(_)
+        | Program::Kprobe(_)
+        | Program::CgroupSkb(_)
+        | Program::CgroupSockAddr(_)
+        | Program::SockOps(_)
+        | Program::CgroupDevice(_)
+        | Program::CgroupSysctl(_)
+        | Program::CgroupSockopt(_)
+        | Program::SkLookup(_) => true,
+        _ => false,
+    }
+}
+
+// Returns true for the programs that should be loaded even if their interface
+// doesn't exist yet.
+fn waits_for_iface(program: &Program) -> bool {
+    match program {
+        Program::Xdp(p) => p.get_wait_for_iface().unwrap_or(false),
+        Program::Tc(p) => p.get_wait_for_iface().unwrap_or(false),
+        _ => false,
+    }
+}
+
+// Resolves the interface glob an XDP or TC program was loaded with, if any.
+// The program is attached to the first matching interface, the remaining
+// matches are returned to be attached once the program is loaded.
+fn expand_iface_glob(program: &mut Program) -> Result<Vec<String>, BpfmanError> {
+    let glob = program.if_name()?;
+    if !is_iface_glob(&glob) {
+        return Ok(vec![]);
+    }
+    if !uses_dispatcher(program) {
+        return Err(BpfmanError::IfaceGlobNotSupported);
+    }
+
+    let mut ifaces = resolve_iface_glob(&glob, program.netns()?.as_deref())?.into_iter();
+    let iface = ifaces.next().ok_or(BpfmanError::NoMatchingInterfaces {
+        pattern: glob.clone(),
+    })?;
+    debug!("Interface glob {glob} matches {iface}");
+    program.set_iface_glob(glob)?;
+    program.set_if_name(iface)?;
+    Ok(ifaces.collect())
+}
+
+// Records the id of the network namespace an XDP or TC program's interface is
+// in, if it isn't bpfman's own.
+fn set_netns_id(program: &mut Program) -> Result<(), BpfmanError> {
+    if let Some(netns) = program.netns()? {
+        program.set_nsid(get_netns_id(&netns)?)?;
+    }
+    Ok(())
+}
+
+// Checks the function name of an extension before it's attached.
+fn verify_extension(program: &Program) -> Result<(), BpfmanError> {
+    let name = &program.get_data().get_name()?;
+    let mut bpf = BpfLoader::new();
+    bpf.allow_unsupported_maps().extension(name);
+    set_external_btf(&mut bpf);
+    let mut ext_loader = bpf.load(program.get_data().program_bytes())?;
+
+    match ext_loader.program_mut(name) {
+        Some(_) => Ok(()),
+        None => Err(BpfmanError::BpfFunctionNameNotValid(name.to_owned())),
+    }
+}
+
+// Load events of programs as they are requested.
+fn load_events(programs: &[Program]) -> Vec<Event> {
+    programs
+        .iter()
+        .map(|p| Event::of(EventKind::Load, p))
+        .collect()
+}
+
+// XDP and TC programs that aren't attached with tcx are loaded against their
+// dispatcher, so they can't be loaded without being attached.
+fn uses_dispatcher(program: &Program) -> bool {
+    match program {
+        Program::Xdp(_) => true,
+        Program::Tc(_) => !program.is_tcx(),
+        _ => false,
+    }
+}
+
+// Whether a TC program is attached to the egress of its interface as well.
+fn both_directions(program: &Program) -> bool {
+    match program {
+        Program::Tc(p) => p.get_both_directions().unwrap_or(false),
+        _ => false,
+    }
+}
+
+// Attaches a program that is loaded and pinned but has no links, see
+// supports_detach. tcx programs are attached by BpfManager, which knows where
+// they go among the other programs on the hook.
+fn attach_pinned_program(program: &mut Program) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let prog_pin_path = format!("{RTDIR_FS}/prog_{id}");
+
+    match program {
+        Program::Tracepoint(p) => {
+            let mut tracepoint = TracePoint::from_pin(prog_pin_path)?;
+            attach_tracepoint(&mut tracepoint, p)
+        }
+        Program::Kprobe(p) => {
+            let kind = match p.get_retprobe()? {
+                true => ProbeKind::KRetProbe,
+                false => ProbeKind::KProbe,
+            };
+            let mut kprobe = KProbe::from_pin(prog_pin_path, kind)?;
+            attach_kprobe(&mut kprobe, p)
+        }
+        Program::CgroupSkb(p) => {
+            let mut cgroup_skb =
+                CgroupSkb::from_pin(prog_pin_path, cgroup_skb_attach_type(p.get_direction()?))?;
+            attach_cgroup_skb(&mut cgroup_skb, p)
+        }
+        Program::CgroupSockAddr(p) => {
+            let mut sock_addr = CgroupSockAddr::from_pin(
+                prog_pin_path,
+                sock_addr_attach_type(p.get_attach_point()?),
+            )?;
+            attach_cgroup_sock_addr(&mut sock_addr, p)
+        }
+        Program::SockOps(p) => {
+            let mut sock_ops = SockOps::from_pin(prog_pin_path)?;
+            attach_sock_ops(&mut sock_ops, p)
+        }
+        Program::SkLookup(p) => {
+            let mut sk_lookup = SkLookup::from_pin(prog_pin_path)?;
+            attach_sk_lookup(&mut sk_lookup, p)
+        }
+        Program::CgroupDevice(p) => {
+            let mut cgroup_device = CgroupDevice::from_pin(prog_pin_path)?;
+            attach_cgroup_device(&mut cgroup_device, p)
+        }
+        Program::CgroupSysctl(p) => {
+            let mut cgroup_sysctl = CgroupSysctl::from_pin(prog_pin_path)?;
+            attach_cgroup_sysctl(&mut cgroup_sysctl, p)
+        }
+        Program::CgroupSockopt(p) => {
+            let mut cgroup_sockopt =
+                CgroupSockopt::from_pin(prog_pin_path, sockopt_attach_type(p.get_attach_type()?))?;
+            attach_cgroup_sockopt(&mut cgroup_sockopt, p)
+        }
+        _ => Err(BpfmanError::DetachNotSupported(program.kind().to_string())),
+    }
+}
+
+// Splits a tracepoint given as category/name.
+fn tracepoint_parts(program: &TracepointProgram) -> Result<(String, String), BpfmanError> {
+    let tracepoint = program.get_tracepoint()?;
+    match tracepoint.split_once('/') {
+        Some((category, name)) if !name.contains('/') => Ok((category.to_owned(), name.to_owned())),
+        _ => Err(BpfmanError::InvalidAttach(tracepoint)),
+    }
+}
+
+// Attaches a loaded tracepoint program and pins the resulting link.
+fn attach_tracepoint(
+    tracepoint: &mut TracePoint,
+    program: &TracepointProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let (category, name) = tracepoint_parts(program)?;
+
+    let link_id = tracepoint.attach(&category, &name)?;
+
+    let owned_link: TracePointLink = tracepoint.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned tracepoint attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+// Attaches a loaded kprobe or kretprobe program and pins the resulting link.
+fn attach_kprobe(kprobe: &mut KProbe, program: &KprobeProgram) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+
+    let link_id = kprobe.attach(program.get_fn_name()?, program.get_offset()?)?;
+
+    let owned_link: KProbeLink = kprobe.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned kprobe attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{}_link", id))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
+
+fn perf_event_config(event: PerfEventType) -> (PerfTypeId, u64) {
+    match event {
+        PerfEventType::CpuClock => (
+            PerfTypeId::Software,
+            perf_sw_ids::PERF_COUNT_SW_CPU_CLOCK as u64,
+        ),
+        PerfEventType::TaskClock => (
+            PerfTypeId::Software,
+            perf_sw_ids::PERF_COUNT_SW_TASK_CLOCK as u64,
+        ),
+        PerfEventType::PageFaults => (
+            PerfTypeId::Software,
+            perf_sw_ids::PERF_COUNT_SW_PAGE_FAULTS as u64,
+        ),
+        PerfEventType::ContextSwitches => (
+            PerfTypeId::Software,
+            perf_sw_ids::PERF_COUNT_SW_CONTEXT_SWITCHES as u64,
+        ),
+        PerfEventType::CpuMigrations => (
+            PerfTypeId::Software,
+            perf_sw_ids::PERF_COUNT_SW_CPU_MIGRATIONS as u64,
+        ),
+        PerfEventType::CpuCycles => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_CPU_CYCLES as u64,
+        ),
+        PerfEventType::Instructions => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_INSTRUCTIONS as u64,
+        ),
+        PerfEventType::CacheReferences => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_CACHE_REFERENCES as u64,
+        ),
+        PerfEventType::CacheMisses => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_CACHE_MISSES as u64,
+        ),
+        PerfEventType::BranchInstructions => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_BRANCH_INSTRUCTIONS as u64,
+        ),
+        PerfEventType::BranchMisses => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_BRANCH_MISSES as u64,
+        ),
+        PerfEventType::BusCycles => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_BUS_CYCLES as u64,
+        ),
+        PerfEventType::RefCycles => (
+            PerfTypeId::Hardware,
+            perf_hw_id::PERF_COUNT_HW_REF_CPU_CYCLES as u64,
+        ),
+    }
+}
+
+// Attaches a loaded sk_lookup program to its network namespace, pins the
+// resulting link and records which namespace it was attached to.
+fn attach_sk_lookup(
+    sk_lookup: &mut SkLookup,
+    program: &mut SkLookupProgram,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let netns_path = program.get_netns_path()?;
+    let netns = std::fs::File::open(&netns_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open netns {netns_path}: {e}")))?;
+    let (_, ino) = netns_id(&netns_path)?;
+
+    let link_id = sk_lookup.attach(netns)?;
+    let owned_link: SkLookupLink = sk_lookup.take_link(link_id)?;
+    let fd_link: FdLink = owned_link.into();
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    program.set_netns_ino(ino)
+}
+
+// An sk_lookup link goes away with its network namespace, but its pin doesn't.
+// If the namespace was recreated while bpfman was down, remove the stale link
+// pin so the program is attached to the new namespace.
+fn remove_stale_sk_lookup_link(program: &SkLookupProgram) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let link_pin_path = format!("{RTDIR_FS}/prog_{id}_link");
+    if !Path::new(&link_pin_path).exists() {
+        return Ok(());
+    }
+
+    let current = netns_id(&program.get_netns_path()?)
+        .ok()
+        .map(|(_, ino)| ino);
+    if current.is_some() && current != program.get_netns_ino()? {
+        debug!("network namespace of sk_lookup program {id} was recreated");
+        std::fs::remove_file(link_pin_path)?;
+    }
+    Ok(())
+}
+
+// Network namespaces are identified by the device and inode of their nsfs
+// file, so different paths to the same namespace compare equal.
+fn netns_id(netns_path: &str) -> Result<(u64, u64), BpfmanError> {
+    let metadata = std::fs::metadata(netns_path)?;
+    Ok((metadata.dev(), metadata.ino()))
+}
+
 // map_pin_path is a the directory the maps are located. Currently, it
 // is a fixed bpfman location containing the map_index, which is a ID.
 // The ID is either the programs ID, or the ID of another program
@@ -1162,3 +4620,246 @@ pub async fn create_map_pin_path(p: &Path) -> Result<(), BpfmanError> {
         .await
         .map_err(|e| BpfmanError::Error(format!("can't create map dir: {e}")))
 }
+
+// The replacement of a program keeps its name, unless a new one is given,
+// its metadata, and its owner and the memory of the maps it uses, so that it
+// still counts against the quota of the owner.
+fn inherit_data(old: &ProgramData, data: &mut ProgramData) -> Result<(), BpfmanError> {
+    if data.get_name()?.is_empty() {
+        data.set_name(&old.get_name()?)?;
+    }
+    data.set_metadata(old.get_metadata()?)?;
+    if let Some(owner) = old.get_owner()? {
+        data.set_owner(&owner)?;
+    }
+    data.set_map_bytes(old.get_map_bytes()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use bpfman_api::XdpProceedOn;
+
+    use super::*;
+    use crate::command::NetkitPosition;
+
+    fn test_data() -> ProgramData {
+        ProgramData::new_pre_load(
+            Location::File("/tmp/sock.o".to_string()),
+            "sock".to_string(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inherit_data() {
+        let mut old = test_data();
+        old.set_metadata(HashMap::from([("app".to_string(), "web".to_string())]))
+            .unwrap();
+        old.set_owner("alice").unwrap();
+        old.set_map_bytes(4096).unwrap();
+
+        let mut data = ProgramData::new_pre_load(
+            Location::File("/tmp/sock_v2.o".to_string()),
+            String::new(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+        )
+        .unwrap();
+        inherit_data(&old, &mut data).unwrap();
+        assert_eq!(data.get_name().unwrap(), "sock");
+        assert_eq!(data.get_metadata().unwrap()["app"], "web");
+        assert_eq!(data.get_owner().unwrap().as_deref(), Some("alice"));
+        assert_eq!(data.get_map_bytes().unwrap(), 4096);
+    }
+
+    #[test]
+    fn test_section_sock_addr_attach_point() {
+        assert_eq!(
+            section_sock_addr_attach_point("cgroup/connect4"),
+            Some(SockAddrAttachPoint::Connect4)
+        );
+        assert_eq!(
+            section_sock_addr_attach_point("cgroup/recvmsg6"),
+            Some(SockAddrAttachPoint::RecvMsg6)
+        );
+        assert_eq!(section_sock_addr_attach_point("cgroup/skb"), None);
+        assert_eq!(section_sock_addr_attach_point("connect4"), None);
+    }
+
+    #[test]
+    fn test_sk_msg_map_path() {
+        // The program's own sockmap is found in its map pin path once its maps
+        // are pinned.
+        let mut program = SkMsgProgram::new(test_data(), "sock_map".to_string(), None).unwrap();
+        assert_eq!(program.get_map_name().unwrap(), "sock_map");
+        assert_eq!(sk_msg_map_path(&program).unwrap(), None);
+        program
+            .get_data_mut()
+            .set_map_pin_path(Path::new("/run/bpfman/fs/maps/5"))
+            .unwrap();
+        assert_eq!(
+            sk_msg_map_path(&program).unwrap(),
+            Some(PathBuf::from("/run/bpfman/fs/maps/5/sock_map"))
+        );
+
+        // A shared sockmap is used from its own pin.
+        let program = SkMsgProgram::new(
+            test_data(),
+            "sock_map".to_string(),
+            Some("/sys/fs/bpf/shared_sock_map".to_string()),
+        )
+        .unwrap();
+        assert_eq!(
+            program.get_sock_map_pin_path().unwrap().as_deref(),
+            Some("/sys/fs/bpf/shared_sock_map")
+        );
+        assert_eq!(
+            sk_msg_map_path(&program).unwrap(),
+            Some(PathBuf::from("/sys/fs/bpf/shared_sock_map"))
+        );
+    }
+
+    #[test]
+    fn test_detach_sk_msg_without_sockmap() {
+        let program = SkMsgProgram::new(test_data(), "sock_map".to_string(), None).unwrap();
+        assert!(detach_sk_msg(&program)
+            .unwrap_err()
+            .to_string()
+            .contains("no sockmap recorded"));
+    }
+
+    #[test]
+    fn test_check_sk_skb_order() {
+        let shared = Path::new("/sys/fs/bpf/shared_sock_map");
+        let sk_skb = |path: &Path, attach_type| {
+            Program::SkSkb(
+                SkSkbProgram::new(
+                    test_data(),
+                    "sock_map".to_string(),
+                    Some(path.to_str().unwrap().to_string()),
+                    attach_type,
+                )
+                .unwrap(),
+            )
+        };
+        let mut programs = ProgramMap::new();
+
+        // A verdict on a shared sockmap waits for a parser there.
+        assert!(programs
+            .check_sk_skb_order(Some(shared), SkSkbAttachType::StreamVerdict)
+            .is_err());
+        assert!(programs
+            .check_sk_skb_order(Some(shared), SkSkbAttachType::StreamParser)
+            .is_ok());
+        programs.insert(1, sk_skb(shared, SkSkbAttachType::StreamParser));
+        assert!(programs
+            .check_sk_skb_order(Some(shared), SkSkbAttachType::StreamVerdict)
+            .is_ok());
+        assert!(programs
+            .check_sk_skb_order(
+                Some(Path::new("/sys/fs/bpf/other_sock_map")),
+                SkSkbAttachType::StreamVerdict
+            )
+            .is_err());
+
+        // A verdict on its own sockmap doesn't.
+        assert!(ProgramMap::new()
+            .check_sk_skb_order(None, SkSkbAttachType::StreamVerdict)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_detached_programs_leave_dispatcher() {
+        let mut xdp = Program::Xdp(
+            XdpProgram::new(test_data(), 50, "eth0".to_string(), XdpProceedOn::default()).unwrap(),
+        );
+        xdp.set_if_index(2).unwrap();
+        assert!(supports_detach(&xdp));
+        assert!(uses_dispatcher(&xdp));
+
+        let mut programs = ProgramMap::new();
+        programs.insert(1, xdp);
+        let count = |programs: &mut ProgramMap| {
+            programs
+                .programs_mut(&ProgramType::Xdp, &Some(2), &None, &None)
+                .count()
+        };
+        assert_eq!(count(&mut programs), 1);
+
+        // Detaching takes the program out of its dispatcher, with the flag
+        // kept in its database tree.
+        programs
+            .get_mut(&1)
+            .unwrap()
+            .get_data_mut()
+            .set_detached(true)
+            .unwrap();
+        assert!(programs.get(&1).unwrap().is_detached());
+        assert_eq!(count(&mut programs), 0);
+
+        let tcx = Program::Tc(
+            TcProgram::new(
+                test_data(),
+                50,
+                "eth0".to_string(),
+                TcProceedOn::default(),
+                Direction::Ingress,
+                Some(true),
+            )
+            .unwrap(),
+        );
+        assert!(supports_detach(&tcx));
+        assert!(!uses_dispatcher(&tcx));
+    }
+
+    #[test]
+    fn test_flow_dissector_in_netns() {
+        let mut programs = ProgramMap::new();
+        assert_eq!(programs.flow_dissector_in_netns("/proc/self/ns/net"), None);
+
+        let program =
+            FlowDissectorProgram::new(test_data(), "/proc/self/ns/net".to_string()).unwrap();
+        assert_eq!(program.get_netns_path().unwrap(), "/proc/self/ns/net");
+        programs.insert(7, Program::FlowDissector(program));
+
+        // The namespace is compared, not the path naming it.
+        assert_eq!(
+            programs.flow_dissector_in_netns("/proc/thread-self/ns/net"),
+            Some(7)
+        );
+        assert_eq!(programs.flow_dissector_in_netns("/nonexistent"), None);
+    }
+
+    #[test]
+    fn test_next_netkit_program() {
+        let netkit = |position, priority| {
+            let mut program =
+                NetkitProgram::new(test_data(), "nk0".to_string(), position, priority).unwrap();
+            program.set_if_index(5).unwrap();
+            program
+        };
+        let mut programs = ProgramMap::new();
+        for (id, position, priority) in [
+            (1, NetkitPosition::Primary, 10),
+            (2, NetkitPosition::Primary, 20),
+            (3, NetkitPosition::Primary, 20),
+            (4, NetkitPosition::Peer, 30),
+        ] {
+            programs.insert(id, Program::Netkit(netkit(position, priority)));
+        }
+
+        let next = |position, priority| {
+            programs
+                .next_netkit_program(&netkit(position, priority))
+                .unwrap()
+        };
+        assert_eq!(next(NetkitPosition::Primary, 15), Some(2));
+        assert_eq!(next(NetkitPosition::Primary, 10), Some(2));
+        assert_eq!(next(NetkitPosition::Primary, 20), None);
+        assert_eq!(next(NetkitPosition::Peer, 0), Some(4));
+    }
+}
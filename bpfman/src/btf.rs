@@ -0,0 +1,537 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// BTF describes the layout of the keys and values of maps created from bytecode
+// built with debug information. It's used to show map entries as JSON rather
+// than raw bytes, so only the types data can have is parsed here: functions,
+// their prototypes and declaration tags are skipped.
+
+use serde_json::{Map, Value};
+
+use crate::errors::BpfmanError;
+
+const BTF_MAGIC: u16 = 0xeb9f;
+const BTF_HEADER_LEN: usize = 24;
+
+const BTF_KIND_INT: u32 = 1;
+const BTF_KIND_PTR: u32 = 2;
+const BTF_KIND_ARRAY: u32 = 3;
+const BTF_KIND_STRUCT: u32 = 4;
+const BTF_KIND_UNION: u32 = 5;
+const BTF_KIND_ENUM: u32 = 6;
+const BTF_KIND_FWD: u32 = 7;
+const BTF_KIND_TYPEDEF: u32 = 8;
+const BTF_KIND_VOLATILE: u32 = 9;
+const BTF_KIND_CONST: u32 = 10;
+const BTF_KIND_RESTRICT: u32 = 11;
+const BTF_KIND_FUNC: u32 = 12;
+const BTF_KIND_FUNC_PROTO: u32 = 13;
+const BTF_KIND_VAR: u32 = 14;
+const BTF_KIND_DATASEC: u32 = 15;
+const BTF_KIND_FLOAT: u32 = 16;
+const BTF_KIND_DECL_TAG: u32 = 17;
+const BTF_KIND_TYPE_TAG: u32 = 18;
+const BTF_KIND_ENUM64: u32 = 19;
+
+const BTF_INT_SIGNED: u32 = 1;
+const BTF_INT_CHAR: u32 = 2;
+const BTF_INT_BOOL: u32 = 4;
+
+// Types refer to each other, a malformed BTF could make them loop.
+const MAX_DEPTH: usize = 32;
+
+#[derive(Debug)]
+struct Member {
+    name: String,
+    ty: u32,
+    bit_offset: u32,
+    bitfield_size: u32,
+}
+
+#[derive(Debug)]
+enum BtfType {
+    Void,
+    Int {
+        size: u32,
+        encoding: u32,
+        offset: u32,
+        bits: u32,
+    },
+    Ptr,
+    Array {
+        elem: u32,
+        nelems: u32,
+    },
+    Struct {
+        size: u32,
+        members: Vec<Member>,
+    },
+    Enum {
+        size: u32,
+        signed: bool,
+        values: Vec<(String, i64)>,
+    },
+    // Typedefs, qualifiers and variables, which only name another type.
+    Ref(u32),
+    Float {
+        size: u32,
+    },
+    Datasec {
+        size: u32,
+        vars: Vec<(u32, u32, u32)>,
+    },
+    Var {
+        name: String,
+        ty: u32,
+    },
+    Other,
+}
+
+#[derive(Debug)]
+pub(crate) struct Btf {
+    // Indexed by type id, id 0 is void.
+    types: Vec<BtfType>,
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn u32(&mut self) -> Result<u32, BpfmanError> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + 4)
+            .ok_or(BpfmanError::Error(
+                "BTF type section is truncated".to_string(),
+            ))?;
+        self.pos += 4;
+        Ok(u32::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+impl Btf {
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, BpfmanError> {
+        if data.len() < BTF_HEADER_LEN
+            || u16::from_ne_bytes(data[0..2].try_into().unwrap()) != BTF_MAGIC
+        {
+            return Err(BpfmanError::Error("invalid BTF header".to_string()));
+        }
+        let header = |i: usize| u32::from_ne_bytes(data[i..i + 4].try_into().unwrap()) as usize;
+        let hdr_len = header(4);
+        let (type_off, type_len) = (header(8), header(12));
+        let (str_off, str_len) = (header(16), header(20));
+
+        let type_section = data
+            .get(hdr_len + type_off..hdr_len + type_off + type_len)
+            .ok_or(BpfmanError::Error(
+                "BTF type section is truncated".to_string(),
+            ))?;
+        let strings = data
+            .get(hdr_len + str_off..hdr_len + str_off + str_len)
+            .ok_or(BpfmanError::Error(
+                "BTF string section is truncated".to_string(),
+            ))?;
+        let name = |off: u32| -> String {
+            strings
+                .get(off as usize..)
+                .and_then(|s| s.split(|b| *b == 0).next())
+                .map(|s| String::from_utf8_lossy(s).into_owned())
+                .unwrap_or_default()
+        };
+
+        let mut types = vec![BtfType::Void];
+        let mut r = Reader {
+            data: type_section,
+            pos: 0,
+        };
+        while r.pos < type_section.len() {
+            let name_off = r.u32()?;
+            let info = r.u32()?;
+            let size_or_type = r.u32()?;
+            let vlen = info & 0xffff;
+            let kind = (info >> 24) & 0x1f;
+            let kind_flag = info >> 31 == 1;
+
+            let ty = match kind {
+                BTF_KIND_INT => {
+                    let int = r.u32()?;
+                    BtfType::Int {
+                        size: size_or_type,
+                        encoding: (int >> 24) & 0x0f,
+                        offset: (int >> 16) & 0xff,
+                        bits: int & 0xff,
+                    }
+                }
+                BTF_KIND_PTR => BtfType::Ptr,
+                BTF_KIND_ARRAY => {
+                    let elem = r.u32()?;
+                    let _index_type = r.u32()?;
+                    let nelems = r.u32()?;
+                    BtfType::Array { elem, nelems }
+                }
+                BTF_KIND_STRUCT | BTF_KIND_UNION => {
+                    let mut members = Vec::with_capacity(vlen as usize);
+                    for _ in 0..vlen {
+                        let member_name = name(r.u32()?);
+                        let ty = r.u32()?;
+                        let offset = r.u32()?;
+                        // With kind_flag set the offset also holds the size
+                        // of bitfields.
+                        let (bit_offset, bitfield_size) = if kind_flag {
+                            (offset & 0xffffff, offset >> 24)
+                        } else {
+                            (offset, 0)
+                        };
+                        members.push(Member {
+                            name: member_name,
+                            ty,
+                            bit_offset,
+                            bitfield_size,
+                        });
+                    }
+                    BtfType::Struct {
+                        size: size_or_type,
+                        members,
+                    }
+                }
+                BTF_KIND_ENUM => {
+                    let mut values = Vec::with_capacity(vlen as usize);
+                    for _ in 0..vlen {
+                        let value_name = name(r.u32()?);
+                        let value = r.u32()?;
+                        values.push((
+                            value_name,
+                            if kind_flag {
+                                value as i32 as i64
+                            } else {
+                                value as i64
+                            },
+                        ));
+                    }
+                    BtfType::Enum {
+                        size: size_or_type,
+                        signed: kind_flag,
+                        values,
+                    }
+                }
+                BTF_KIND_ENUM64 => {
+                    let mut values = Vec::with_capacity(vlen as usize);
+                    for _ in 0..vlen {
+                        let value_name = name(r.u32()?);
+                        let lo = r.u32()? as u64;
+                        let hi = r.u32()? as u64;
+                        values.push((value_name, ((hi << 32) | lo) as i64));
+                    }
+                    BtfType::Enum {
+                        size: size_or_type,
+                        signed: kind_flag,
+                        values,
+                    }
+                }
+                BTF_KIND_TYPEDEF | BTF_KIND_VOLATILE | BTF_KIND_CONST | BTF_KIND_RESTRICT
+                | BTF_KIND_TYPE_TAG => BtfType::Ref(size_or_type),
+                BTF_KIND_VAR => {
+                    let _linkage = r.u32()?;
+                    BtfType::Var {
+                        name: name(name_off),
+                        ty: size_or_type,
+                    }
+                }
+                BTF_KIND_DATASEC => {
+                    let mut vars = Vec::with_capacity(vlen as usize);
+                    for _ in 0..vlen {
+                        vars.push((r.u32()?, r.u32()?, r.u32()?));
+                    }
+                    BtfType::Datasec {
+                        size: size_or_type,
+                        vars,
+                    }
+                }
+                BTF_KIND_FLOAT => BtfType::Float { size: size_or_type },
+                BTF_KIND_FWD | BTF_KIND_FUNC => BtfType::Other,
+                BTF_KIND_FUNC_PROTO => {
+                    r.pos += 8 * vlen as usize;
+                    BtfType::Other
+                }
+                BTF_KIND_DECL_TAG => {
+                    let _component_idx = r.u32()?;
+                    BtfType::Other
+                }
+                _ => return Err(BpfmanError::Error(format!("unsupported BTF kind {kind}"))),
+            };
+            types.push(ty);
+        }
+
+        Ok(Btf { types })
+    }
+
+    // Returns the size in bytes of the data of type id.
+    fn size(&self, id: u32, depth: usize) -> Option<usize> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match self.types.get(id as usize)? {
+            BtfType::Int { size, .. }
+            | BtfType::Struct { size, .. }
+            | BtfType::Enum { size, .. }
+            | BtfType::Float { size }
+            | BtfType::Datasec { size, .. } => Some(*size as usize),
+            BtfType::Ptr => Some(8),
+            BtfType::Array { elem, nelems } => {
+                Some(self.size(*elem, depth + 1)? * *nelems as usize)
+            }
+            BtfType::Ref(ty) | BtfType::Var { ty, .. } => self.size(*ty, depth + 1),
+            BtfType::Void | BtfType::Other => None,
+        }
+    }
+
+    // Returns true if type id is a char, which arrays of are shown as strings.
+    fn is_char(&self, id: u32, depth: usize) -> bool {
+        if depth > MAX_DEPTH {
+            return false;
+        }
+        match self.types.get(id as usize) {
+            Some(BtfType::Int { size, encoding, .. }) => *size == 1 && encoding & BTF_INT_CHAR != 0,
+            Some(BtfType::Ref(ty)) => self.is_char(*ty, depth + 1),
+            _ => false,
+        }
+    }
+
+    // Formats data as type id. Data the type doesn't describe is shown as a
+    // hex string.
+    pub(crate) fn format(&self, id: u32, data: &[u8]) -> Value {
+        self.format_type(id, data, 0)
+    }
+
+    fn format_type(&self, id: u32, data: &[u8], depth: usize) -> Value {
+        if depth > MAX_DEPTH {
+            return hex_value(data);
+        }
+        let field = |offset: usize, ty: u32| {
+            let size = self.size(ty, depth + 1).unwrap_or(0);
+            match data.get(offset..offset + size) {
+                Some(d) => self.format_type(ty, d, depth + 1),
+                None => Value::Null,
+            }
+        };
+
+        match self.types.get(id as usize) {
+            Some(BtfType::Int {
+                size,
+                encoding,
+                offset,
+                bits,
+            }) => {
+                if encoding & BTF_INT_BOOL != 0 {
+                    return Value::Bool(data.iter().any(|b| *b != 0));
+                }
+                match read_int(data, *size as usize) {
+                    Some(v) => int_value(v >> offset, *bits, encoding & BTF_INT_SIGNED != 0),
+                    None => hex_value(data),
+                }
+            }
+            Some(BtfType::Ptr) => match read_int(data, 8) {
+                Some(v) => Value::String(format!("{v:#x}")),
+                None => hex_value(data),
+            },
+            Some(BtfType::Array { elem, nelems }) => {
+                if self.is_char(*elem, depth + 1) {
+                    let s = data.split(|b| *b == 0).next().unwrap_or_default();
+                    return Value::String(String::from_utf8_lossy(s).into_owned());
+                }
+                let size = self.size(*elem, depth + 1).unwrap_or(0);
+                Value::Array(
+                    (0..*nelems as usize)
+                        .map(|i| field(i * size, *elem))
+                        .collect(),
+                )
+            }
+            Some(BtfType::Struct { members, .. }) => {
+                let mut object = Map::new();
+                for (i, m) in members.iter().enumerate() {
+                    let value = if m.bitfield_size > 0 {
+                        let start = (m.bit_offset / 8) as usize;
+                        let shift = m.bit_offset % 8;
+                        let end = (start + 8).min(data.len());
+                        match data.get(start..end).and_then(|d| read_int(d, d.len())) {
+                            Some(v) => int_value(v >> shift, m.bitfield_size, false),
+                            None => Value::Null,
+                        }
+                    } else {
+                        field((m.bit_offset / 8) as usize, m.ty)
+                    };
+                    match (m.name.is_empty(), value) {
+                        // Members of anonymous structs and unions are shown
+                        // as members of the enclosing one, like in C.
+                        (true, Value::Object(inner)) => object.extend(inner),
+                        (true, value) => {
+                            object.insert(format!("_{i}"), value);
+                        }
+                        (false, value) => {
+                            object.insert(m.name.clone(), value);
+                        }
+                    }
+                }
+                Value::Object(object)
+            }
+            Some(BtfType::Enum {
+                size,
+                signed,
+                values,
+            }) => match read_int(data, *size as usize) {
+                Some(v) => {
+                    let v = if *signed {
+                        sign_extend(v, *size * 8)
+                    } else {
+                        v as i64
+                    };
+                    values
+                        .iter()
+                        .find(|(_, value)| *value == v)
+                        .map(|(name, _)| Value::String(name.clone()))
+                        .unwrap_or(Value::from(v))
+                }
+                None => hex_value(data),
+            },
+            Some(BtfType::Ref(ty)) | Some(BtfType::Var { ty, .. }) => {
+                self.format_type(*ty, data, depth + 1)
+            }
+            Some(BtfType::Float { size: 4 }) if data.len() == 4 => {
+                Value::from(f32::from_ne_bytes(data.try_into().unwrap()))
+            }
+            Some(BtfType::Float { size: 8 }) if data.len() == 8 => {
+                Value::from(f64::from_ne_bytes(data.try_into().unwrap()))
+            }
+            Some(BtfType::Datasec { vars, .. }) => {
+                let mut object = Map::new();
+                for (ty, offset, size) in vars {
+                    let name = match self.types.get(*ty as usize) {
+                        Some(BtfType::Var { name, .. }) => name.clone(),
+                        _ => format!("_{offset}"),
+                    };
+                    let value = match data.get(*offset as usize..(*offset + *size) as usize) {
+                        Some(d) => self.format_type(*ty, d, depth + 1),
+                        None => Value::Null,
+                    };
+                    object.insert(name, value);
+                }
+                Value::Object(object)
+            }
+            _ => hex_value(data),
+        }
+    }
+}
+
+fn hex_value(data: &[u8]) -> Value {
+    Value::String(hex::encode(data))
+}
+
+// Reads an integer of up to 8 bytes in native byte order.
+fn read_int(data: &[u8], size: usize) -> Option<u64> {
+    if size == 0 || size > 8 || data.len() < size {
+        return None;
+    }
+    let mut bytes = [0u8; 8];
+    if cfg!(target_endian = "little") {
+        bytes[..size].copy_from_slice(&data[..size]);
+    } else {
+        bytes[8 - size..].copy_from_slice(&data[..size]);
+    }
+    Some(u64::from_ne_bytes(bytes))
+}
+
+fn sign_extend(v: u64, bits: u32) -> i64 {
+    if bits == 0 || bits >= 64 {
+        return v as i64;
+    }
+    let shift = 64 - bits;
+    ((v << shift) as i64) >> shift
+}
+
+fn int_value(v: u64, bits: u32, signed: bool) -> Value {
+    let v = if bits > 0 && bits < 64 {
+        v & ((1 << bits) - 1)
+    } else {
+        v
+    };
+    if signed {
+        Value::from(sign_extend(v, bits))
+    } else {
+        Value::from(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    // Builds a BTF blob from its types and strings.
+    fn btf(types: &[u32], strings: &[u8]) -> Vec<u8> {
+        let types: Vec<u8> = types.iter().flat_map(|t| t.to_ne_bytes()).collect();
+        let mut data = Vec::new();
+        data.extend(BTF_MAGIC.to_ne_bytes());
+        data.extend([1u8, 0]);
+        for v in [
+            BTF_HEADER_LEN as u32,
+            0,
+            types.len() as u32,
+            types.len() as u32,
+            strings.len() as u32,
+        ] {
+            data.extend(v.to_ne_bytes());
+        }
+        data.extend(types);
+        data.extend(strings);
+        data
+    }
+
+    #[test]
+    fn test_format_struct() {
+        // 1: unsigned int, 2: char, 3: char[4],
+        // 4: struct stats { unsigned int packets; char name[4]; }
+        let data = btf(
+            &[
+                1,
+                BTF_KIND_INT << 24,
+                4,
+                32,
+                14,
+                BTF_KIND_INT << 24,
+                1,
+                ((BTF_INT_SIGNED | BTF_INT_CHAR) << 24) | 8,
+                0,
+                BTF_KIND_ARRAY << 24,
+                0,
+                2,
+                1,
+                4,
+                19,
+                (BTF_KIND_STRUCT << 24) | 2,
+                8,
+                25,
+                1,
+                0,
+                33,
+                3,
+                32,
+            ],
+            b"\0unsigned int\0char\0stats\0packets\0name\0",
+        );
+        let btf = Btf::parse(&data).unwrap();
+
+        let mut value = 42u32.to_ne_bytes().to_vec();
+        value.extend(b"eth\0");
+        assert_eq!(btf.format(4, &value), json!({"packets": 42, "name": "eth"}));
+        // Data of types that aren't described is shown as hex.
+        assert_eq!(btf.format(0, &[0xab, 0x01]), json!("ab01"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Btf::parse(&[0; 8]).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 // Copyright Authors of bpfman
 
 use bpfman_api::ProgramType;
+use chrono::DateTime;
 use clap::{Args, Parser, Subcommand};
 use hex::FromHex;
 
@@ -19,10 +20,34 @@ pub(crate) enum Commands {
     Load(LoadSubcommand),
     /// Unload an eBPF program using the program id.
     Unload(UnloadArgs),
+    /// Attach an eBPF program that was loaded detached using the program id.
+    Attach(AttachArgs),
+    /// Detach an eBPF program without unloading it using the program id.
+    Detach(DetachArgs),
+    /// Change the priority of an XDP or TC program using the program id.
+    SetPriority(SetPriorityArgs),
+    /// Replace an XDP or TC program with new bytecode using the program id.
+    #[command(subcommand)]
+    Replace(ReplaceSubcommand),
+    /// Replace an XDP or TC program with the program it replaced using the program id.
+    Rollback(RollbackArgs),
     /// List all eBPF programs loaded via bpfman.
     List(ListArgs),
     /// Get an eBPF program using the program id.
     Get(GetArgs),
+    /// Run an eBPF iterator program using the program id and print its output.
+    Read(ReadArgs),
+    /// Show the version and capabilities of the bpfman daemon.
+    Version,
+    /// Show the history of loads, unloads and dispatcher rebuilds.
+    Events(EventsArgs),
+    /// Probe the program types, map types, helpers and attach mechanisms the kernel supports.
+    Features(FeaturesArgs),
+    /// List the links in the kernel, marking the ones of programs loaded by bpfman.
+    Links(LinksArgs),
+    /// Publish maps under a name for further programs to share.
+    #[command(subcommand)]
+    Maps(MapsSubcommand),
     /// eBPF Bytecode Image related commands.
     #[command(subcommand)]
     Image(ImageSubCommand),
@@ -39,13 +64,34 @@ pub(crate) enum LoadSubcommand {
     Image(LoadImageArgs),
 }
 
+#[derive(Subcommand, Debug)]
+pub(crate) enum ReplaceSubcommand {
+    /// Replace a program with bytecode from a local .o file.
+    File(ReplaceFileArgs),
+    /// Replace a program with bytecode packaged in a OCI container image from a given registry.
+    Image(ReplaceImageArgs),
+}
+
 #[derive(Args, Debug)]
 pub(crate) struct LoadFileArgs {
-    /// Required: Location of local bytecode file
+    /// Required: Location of local bytecode file, or of a bytecode image in an OCI
+    /// image layout directory (oci:<DIR>[:<REF>]) or archive (oci-archive:<FILE>[:<REF>]).
     /// Example: --path /run/bpfman/examples/go-xdp-counter/bpf_bpfel.o
+    /// Example: --path oci-archive:/var/lib/images/xdp_pass.tar:latest
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) path: String,
 
+    /// Optional: Upload the bytecode file to bpfman instead of having bpfman read it
+    /// from the path, for when bpfman can't access the file.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) upload: bool,
+
+    /// Optional: Have bpfman fetch the bytecode file from the path, which is then an
+    /// https URL, checking it against the given hex encoded sha256 digest.
+    /// Example: --path https://example.com/xdp_pass.bpf.o --sha256 5b4c...
+    #[clap(long, verbatim_doc_comment, conflicts_with = "upload")]
+    pub(crate) sha256: Option<String>,
+
     /// Required: The name of the function that is the entry point for the BPF program.
     #[clap(short, long)]
     pub(crate) name: String,
@@ -75,6 +121,104 @@ pub(crate) struct LoadFileArgs {
     #[clap(long, verbatim_doc_comment)]
     pub(crate) map_owner_id: Option<u32>,
 
+    /// Optional: Name of maps published with `bpfman maps publish` this eBPF
+    /// program will share, instead of giving the program id that owns them.
+    /// Example: --map-from counters
+    #[clap(long, verbatim_doc_comment, conflicts_with = "map_owner_id")]
+    pub(crate) map_from: Option<String>,
+
+    /// Optional: Load and pin the program without attaching it. It can be
+    /// attached later using `bpfman attach`.
+    /// Example: --detached
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) detached: bool,
+
+    /// Optional: Names of further functions in the bytecode to load as programs
+    /// of their own, with the same program type and attach info. They share
+    /// the maps of the first program.
+    /// Example: --additional-names leave,stats
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) additional_names: Option<Vec<String>>,
+
+    /// Optional: Override the max_entries of maps in the bytecode, so the
+    /// same bytecode can be sized per host.
+    /// Format: <MAP NAME>=<MAX ENTRIES>
+    /// Example: --map-max-entries flows=65536,stats=128
+    #[clap(long, verbatim_doc_comment, value_parser=parse_map_max_entries, value_delimiter = ',')]
+    pub(crate) map_max_entries: Option<Vec<(String, u32)>>,
+
+    /// Optional: Only pin the maps whose names match one of these patterns,
+    /// rather than all maps but the ones holding global data. Patterns may
+    /// contain "*" and "?" wildcards.
+    /// Example: --pin-include flows,stats_*
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) pin_include: Option<Vec<String>>,
+
+    /// Optional: Don't pin the maps whose names match one of these patterns,
+    /// keeping them private to the program.
+    /// Example: --pin-exclude scratch_*
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) pin_exclude: Option<Vec<String>>,
+
+    /// Optional: Pin maps under other names than their own.
+    /// Format: <MAP NAME>=<PIN NAME>
+    /// Example: --pin-name flows=flows_v1
+    #[clap(long, verbatim_doc_comment, value_parser=parse_key_val, value_delimiter = ',')]
+    pub(crate) pin_name: Option<Vec<(String, String)>>,
+
+    /// Optional: Entries written into maps of the bytecode once the program is
+    /// loaded. Keys and values are hex encoded, laid out as the map expects.
+    /// Format: <MAP NAME>:<Hex Key>=<Hex Value>
+    /// Example: --map-init config:00000000=0100000000000000
+    #[clap(long, verbatim_doc_comment, num_args(1..), value_parser=parse_map_init_arg)]
+    pub(crate) map_init: Option<Vec<MapInitArg>>,
+
+    /// Optional: Freeze maps of the bytecode once they are initialized, so
+    /// they can't be changed from userspace anymore.
+    /// Example: --freeze-map config
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) freeze_map: Option<Vec<String>>,
+
+    /// Optional: Create an inner map for a key of an array or hash of maps in
+    /// the bytecode once the program is loaded. The map type is the number of
+    /// the kernel's bpf_map_type, and the definition must match the inner map
+    /// the outer map was declared with. It is pinned as <OUTER MAP>_<Hex Key>.
+    /// Format: <OUTER MAP>:<Hex Key>=<MAP TYPE>:<KEY SIZE>:<VALUE SIZE>:<MAX ENTRIES>
+    /// Example: --inner-map flows:00000000=1:4:8:1024
+    #[clap(long, verbatim_doc_comment, num_args(1..), value_parser=parse_inner_map_arg)]
+    pub(crate) inner_map: Option<Vec<InnerMapArg>>,
+
+    /// Optional: User, by name or ID, given the maps of the program and its
+    /// map pin path.
+    /// Example: --map-user tenant1
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) map_user: Option<String>,
+
+    /// Optional: Group, by name or ID, given the maps of the program and its
+    /// map pin path.
+    /// Example: --map-group tenant1
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) map_group: Option<String>,
+
+    /// Optional: Octal mode of the maps of the program, 0660 by default. The
+    /// map pin path gets the search bits of whoever may read the maps.
+    /// Example: --map-mode 0600
+    #[clap(long, verbatim_doc_comment, value_parser=parse_mode)]
+    pub(crate) map_mode: Option<u32>,
+
+    /// Optional: Verifier log level of the program, any of debug, verbose and
+    /// stats, debug,stats by default. The end of the log is shown if the
+    /// verifier rejects the program.
+    /// Example: --verifier-log-level verbose,stats
+    #[clap(long, verbatim_doc_comment, value_parser=parse_verifier_log_level, value_delimiter = ',')]
+    pub(crate) verifier_log_level: Option<Vec<u32>>,
+
+    /// Optional: Bytes of the end of the verifier log shown if the verifier
+    /// rejects the program.
+    /// Example: --verifier-log-size 65536
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) verifier_log_size: Option<u32>,
+
     #[clap(subcommand)]
     pub(crate) command: LoadCommands,
 }
@@ -87,6 +231,7 @@ pub(crate) struct LoadImageArgs {
 
     /// Optional: The name of the function that is the entry point for the BPF program.
     /// If not provided, the program name defined as part of the bytecode image will be used.
+    /// Images holding several programs need the name or function name of the program to load.
     #[clap(short, long, verbatim_doc_comment, default_value = "")]
     pub(crate) name: String,
 
@@ -115,6 +260,104 @@ pub(crate) struct LoadImageArgs {
     #[clap(long, verbatim_doc_comment)]
     pub(crate) map_owner_id: Option<u32>,
 
+    /// Optional: Name of maps published with `bpfman maps publish` this eBPF
+    /// program will share, instead of giving the program id that owns them.
+    /// Example: --map-from counters
+    #[clap(long, verbatim_doc_comment, conflicts_with = "map_owner_id")]
+    pub(crate) map_from: Option<String>,
+
+    /// Optional: Load and pin the program without attaching it. It can be
+    /// attached later using `bpfman attach`.
+    /// Example: --detached
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) detached: bool,
+
+    /// Optional: Names of further functions in the bytecode to load as programs
+    /// of their own, with the same program type and attach info. They share
+    /// the maps of the first program.
+    /// Example: --additional-names leave,stats
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) additional_names: Option<Vec<String>>,
+
+    /// Optional: Override the max_entries of maps in the bytecode, so the
+    /// same bytecode can be sized per host.
+    /// Format: <MAP NAME>=<MAX ENTRIES>
+    /// Example: --map-max-entries flows=65536,stats=128
+    #[clap(long, verbatim_doc_comment, value_parser=parse_map_max_entries, value_delimiter = ',')]
+    pub(crate) map_max_entries: Option<Vec<(String, u32)>>,
+
+    /// Optional: Only pin the maps whose names match one of these patterns,
+    /// rather than all maps but the ones holding global data. Patterns may
+    /// contain "*" and "?" wildcards.
+    /// Example: --pin-include flows,stats_*
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) pin_include: Option<Vec<String>>,
+
+    /// Optional: Don't pin the maps whose names match one of these patterns,
+    /// keeping them private to the program.
+    /// Example: --pin-exclude scratch_*
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) pin_exclude: Option<Vec<String>>,
+
+    /// Optional: Pin maps under other names than their own.
+    /// Format: <MAP NAME>=<PIN NAME>
+    /// Example: --pin-name flows=flows_v1
+    #[clap(long, verbatim_doc_comment, value_parser=parse_key_val, value_delimiter = ',')]
+    pub(crate) pin_name: Option<Vec<(String, String)>>,
+
+    /// Optional: Entries written into maps of the bytecode once the program is
+    /// loaded. Keys and values are hex encoded, laid out as the map expects.
+    /// Format: <MAP NAME>:<Hex Key>=<Hex Value>
+    /// Example: --map-init config:00000000=0100000000000000
+    #[clap(long, verbatim_doc_comment, num_args(1..), value_parser=parse_map_init_arg)]
+    pub(crate) map_init: Option<Vec<MapInitArg>>,
+
+    /// Optional: Freeze maps of the bytecode once they are initialized, so
+    /// they can't be changed from userspace anymore.
+    /// Example: --freeze-map config
+    #[clap(long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) freeze_map: Option<Vec<String>>,
+
+    /// Optional: Create an inner map for a key of an array or hash of maps in
+    /// the bytecode once the program is loaded. The map type is the number of
+    /// the kernel's bpf_map_type, and the definition must match the inner map
+    /// the outer map was declared with. It is pinned as <OUTER MAP>_<Hex Key>.
+    /// Format: <OUTER MAP>:<Hex Key>=<MAP TYPE>:<KEY SIZE>:<VALUE SIZE>:<MAX ENTRIES>
+    /// Example: --inner-map flows:00000000=1:4:8:1024
+    #[clap(long, verbatim_doc_comment, num_args(1..), value_parser=parse_inner_map_arg)]
+    pub(crate) inner_map: Option<Vec<InnerMapArg>>,
+
+    /// Optional: User, by name or ID, given the maps of the program and its
+    /// map pin path.
+    /// Example: --map-user tenant1
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) map_user: Option<String>,
+
+    /// Optional: Group, by name or ID, given the maps of the program and its
+    /// map pin path.
+    /// Example: --map-group tenant1
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) map_group: Option<String>,
+
+    /// Optional: Octal mode of the maps of the program, 0660 by default. The
+    /// map pin path gets the search bits of whoever may read the maps.
+    /// Example: --map-mode 0600
+    #[clap(long, verbatim_doc_comment, value_parser=parse_mode)]
+    pub(crate) map_mode: Option<u32>,
+
+    /// Optional: Verifier log level of the program, any of debug, verbose and
+    /// stats, debug,stats by default. The end of the log is shown if the
+    /// verifier rejects the program.
+    /// Example: --verifier-log-level verbose,stats
+    #[clap(long, verbatim_doc_comment, value_parser=parse_verifier_log_level, value_delimiter = ',')]
+    pub(crate) verifier_log_level: Option<Vec<u32>>,
+
+    /// Optional: Bytes of the end of the verifier log shown if the verifier
+    /// rejects the program.
+    /// Example: --verifier-log-size 65536
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) verifier_log_size: Option<u32>,
+
     #[clap(subcommand)]
     pub(crate) command: LoadCommands,
 }
@@ -125,12 +368,30 @@ pub(crate) struct GlobalArg {
     pub(crate) value: Vec<u8>,
 }
 
+#[derive(Clone, Debug)]
+pub(crate) struct MapInitArg {
+    pub(crate) map: String,
+    pub(crate) key: Vec<u8>,
+    pub(crate) value: Vec<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct InnerMapArg {
+    pub(crate) outer: String,
+    pub(crate) key: Vec<u8>,
+    pub(crate) map_type: u32,
+    pub(crate) key_size: u32,
+    pub(crate) value_size: u32,
+    pub(crate) max_entries: u32,
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum LoadCommands {
     /// Install an eBPF program on the XDP hook point for a given interface.
     Xdp {
-        /// Required: Interface to load program on.
-        #[clap(short, long)]
+        /// Required: Interface to load program on. A glob like "eth*" or a
+        /// prefix like "ens+" loads it on all matching interfaces.
+        #[clap(short, long, verbatim_doc_comment)]
         iface: String,
 
         /// Required: Priority to run program in chain. Lower value runs first.
@@ -146,17 +407,36 @@ pub(crate) enum LoadCommands {
         /// [default: pass, dispatcher_return]
         #[clap(long, verbatim_doc_comment, num_args(1..))]
         proceed_on: Vec<String>,
+
+        /// Optional: If --iface is a glob, also attach to interfaces that are
+        /// created later and match it.
+        #[clap(long, verbatim_doc_comment)]
+        track_iface_glob: bool,
+
+        /// Optional: If the interface doesn't exist yet, load the program
+        /// anyway and attach it once the interface is created.
+        #[clap(long, verbatim_doc_comment)]
+        wait_for_iface: bool,
+
+        /// Optional: Path to the network namespace of the interface.
+        /// Example: --netns-path "/var/run/netns/foo"
+        ///
+        /// [default: bpfman's network namespace]
+        #[clap(long, verbatim_doc_comment)]
+        netns_path: Option<String>,
     },
     /// Install an eBPF program on the TC hook point for a given interface.
     Tc {
-        /// Required: Direction to apply program.
+        /// Required: Direction to apply program. With "both" the program is
+        /// attached to ingress and egress, sharing its maps.
         ///
-        /// [possible values: ingress, egress]
+        /// [possible values: ingress, egress, both]
         #[clap(short, long, verbatim_doc_comment)]
         direction: String,
 
-        /// Required: Interface to load program on.
-        #[clap(short, long)]
+        /// Required: Interface to load program on. A glob like "eth*" or a
+        /// prefix like "ens+" loads it on all matching interfaces.
+        #[clap(short, long, verbatim_doc_comment)]
         iface: String,
 
         /// Required: Priority to run program in chain. Lower value runs first.
@@ -173,6 +453,31 @@ pub(crate) enum LoadCommands {
         /// [default: ok, pipe, dispatcher_return]
         #[clap(long, verbatim_doc_comment, num_args(1..))]
         proceed_on: Vec<String>,
+
+        /// Optional: Attach with a tcx link instead of the tc dispatcher.
+        /// Requires Linux 6.6 or later. Proceed on values are ignored for tcx.
+        ///
+        /// [default: taken from the bpfman configuration]
+        #[clap(long, verbatim_doc_comment)]
+        tcx: Option<bool>,
+
+        /// Optional: If --iface is a glob, also attach to interfaces that are
+        /// created later and match it.
+        #[clap(long, verbatim_doc_comment)]
+        track_iface_glob: bool,
+
+        /// Optional: If the interface doesn't exist yet, load the program
+        /// anyway and attach it once the interface is created.
+        /// Not supported with direction "both".
+        #[clap(long, verbatim_doc_comment)]
+        wait_for_iface: bool,
+
+        /// Optional: Path to the network namespace of the interface.
+        /// Example: --netns-path "/var/run/netns/foo"
+        ///
+        /// [default: bpfman's network namespace]
+        #[clap(long, verbatim_doc_comment)]
+        netns_path: Option<String>,
     },
     /// Install an eBPF program on a Tracepoint.
     Tracepoint {
@@ -236,6 +541,357 @@ pub(crate) enum LoadCommands {
         /// (NOT CURRENTLY SUPPORTED)
         #[clap(short, long)]
         container_pid: Option<i32>,
+
+        /// Optional: ID of the container to attach the uprobe in. --target must
+        /// then be an absolute path in the container, and --pid a PID in the
+        /// container.
+        /// Example: --container-id "containerd://4c7f...e2a1"
+        #[clap(long, verbatim_doc_comment, conflicts_with = "container_pid")]
+        container_id: Option<String>,
+    },
+    /// Install an eBPF program on a BPF LSM hook.
+    Lsm {
+        /// Required: Name of the LSM hook to attach the program to.
+        /// Example: --hook "file_open"
+        #[clap(long, verbatim_doc_comment)]
+        hook: String,
+    },
+    /// Install an eBPF cgroup_skb program on a cgroup.
+    CgroupSkb {
+        /// Required: Absolute path to the cgroup to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup/system.slice"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Required: Direction to apply program.
+        ///
+        /// [possible values: ingress, egress]
+        #[clap(short, long, verbatim_doc_comment)]
+        direction: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF cgroup/sock_addr program on a cgroup.
+    CgroupSockAddr {
+        /// Required: Absolute path to the cgroup to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup/system.slice"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Required: Socket operation to attach the program to. Must match the
+        /// attach point the program was compiled for.
+        ///
+        /// [possible values: bind4, bind6, connect4, connect6, getpeername4,
+        ///                   getpeername6, getsockname4, getsockname6,
+        ///                   sendmsg4, sendmsg6, recvmsg4, recvmsg6]
+        #[clap(short = 'p', long, verbatim_doc_comment)]
+        attach_point: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF sockops program on a cgroup.
+    SockOps {
+        /// Required: Absolute path to the cgroup2 directory to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF cgroup device program on a cgroup.
+    CgroupDevice {
+        /// Required: Absolute path to the cgroup2 directory to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup/system.slice"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF cgroup sysctl program on a cgroup.
+    CgroupSysctl {
+        /// Required: Absolute path to the cgroup2 directory to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup/system.slice"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF cgroup sockopt program on a cgroup.
+    CgroupSockopt {
+        /// Required: Absolute path to the cgroup2 directory to attach the program to.
+        /// Example: --cgroup-path "/sys/fs/cgroup/system.slice"
+        /// A container ID like "containerd://<id>", or a pod UID like
+        /// "pod://<uid>", selects the cgroup of that container or pod.
+        #[clap(short, long, verbatim_doc_comment)]
+        cgroup_path: String,
+
+        /// Required: Socket option call to attach the program to. Must match
+        /// the attach type the program was compiled for.
+        ///
+        /// [possible values: getsockopt, setsockopt]
+        #[clap(short = 't', long, verbatim_doc_comment)]
+        attach_type: String,
+
+        /// Optional: Flags used when attaching the program to the cgroup.
+        ///
+        /// [possible values: none, override, multi]
+        ///
+        /// [default: none]
+        #[clap(short, long, verbatim_doc_comment, default_value = "none")]
+        attach_flags: String,
+    },
+    /// Install an eBPF sk_msg program on a sockmap or sockhash.
+    SkMsg {
+        /// Required: Name of the sockmap or sockhash to attach the program to.
+        /// The map is either owned by this program or, when --map-owner-id is
+        /// set, by the program whose maps are shared (e.g. a sockops program).
+        /// Example: --map-name "sock_hash"
+        #[clap(short, long, verbatim_doc_comment)]
+        map_name: String,
+
+        /// Optional: Path to a sockmap or sockhash pinned outside of bpfman to
+        /// attach the program to instead of --map-name.
+        /// Example: --sock-map-pin-path "/sys/fs/bpf/sock_hash"
+        #[clap(short, long, verbatim_doc_comment)]
+        sock_map_pin_path: Option<String>,
+    },
+    /// Install an eBPF sk_skb stream parser or verdict program on a sockmap or sockhash.
+    SkSkb {
+        /// Required: Name of the sockmap or sockhash to attach the program to.
+        /// The map is either owned by this program or, when --map-owner-id is
+        /// set, by the program whose maps are shared.
+        /// Example: --map-name "sock_map"
+        #[clap(short, long, verbatim_doc_comment)]
+        map_name: String,
+
+        /// Optional: Path to a sockmap or sockhash pinned outside of bpfman to
+        /// attach the program to instead of --map-name.
+        /// Example: --sock-map-pin-path "/sys/fs/bpf/sock_map"
+        #[clap(short, long, verbatim_doc_comment)]
+        sock_map_pin_path: Option<String>,
+
+        /// Required: Whether the program is a stream parser or a stream verdict.
+        /// A stream_verdict program on a shared map can only be loaded once a
+        /// stream_parser is attached to the same map.
+        ///
+        /// [possible values: stream_parser, stream_verdict]
+        #[clap(short, long, verbatim_doc_comment)]
+        attach_type: String,
+    },
+    /// Install an eBPF flow_dissector program on a network namespace.
+    FlowDissector {
+        /// Optional: Path to the network namespace to attach the program to.
+        /// Only one flow dissector can be attached per network namespace.
+        /// Example: --netns-path "/var/run/netns/foo"
+        ///
+        /// [default: /proc/self/ns/net]
+        #[clap(short, long, verbatim_doc_comment, default_value = "/proc/self/ns/net")]
+        netns_path: String,
+    },
+    /// Install an eBPF sk_lookup program on a network namespace.
+    SkLookup {
+        /// Optional: Path to the network namespace to attach the program to.
+        /// Example: --netns-path "/var/run/netns/foo"
+        ///
+        /// [default: /proc/self/ns/net]
+        #[clap(short, long, verbatim_doc_comment, default_value = "/proc/self/ns/net")]
+        netns_path: String,
+    },
+    /// Install an eBPF perf_event program sampling on a perf event.
+    PerfEvent {
+        /// Required: Perf event to sample on.
+        ///
+        /// [possible values: cpu-clock, task-clock, page-faults, context-switches,
+        ///                   cpu-migrations, cpu-cycles, instructions,
+        ///                   cache-references, cache-misses, branch-instructions,
+        ///                   branch-misses, bus-cycles, ref-cycles]
+        #[clap(short, long, verbatim_doc_comment)]
+        event: String,
+
+        /// Optional: Run the program every N events. One of --sample-period or
+        /// --sample-frequency is required.
+        #[clap(long, verbatim_doc_comment, conflicts_with = "sample_frequency")]
+        sample_period: Option<u64>,
+
+        /// Optional: Run the program N times per second. One of --sample-period
+        /// or --sample-frequency is required.
+        #[clap(long, verbatim_doc_comment, required_unless_present = "sample_period")]
+        sample_frequency: Option<u64>,
+
+        /// Optional: CPUs to attach the program on. Multiple values supported by
+        /// repeating the parameter. If not provided, the program is attached on
+        /// all online CPUs.
+        /// Example: --cpus 0 --cpus 1
+        #[clap(long, verbatim_doc_comment, num_args(1..))]
+        cpus: Vec<u32>,
+    },
+    /// Install an eBPF program on a raw tracepoint.
+    RawTracepoint {
+        /// Required: The raw tracepoint to attach to.
+        /// Example: --tracepoint "sched_switch"
+        #[clap(short, long, verbatim_doc_comment)]
+        tracepoint: String,
+
+        /// Optional: Whether the program is a BTF-enabled raw tracepoint (tp_btf).
+        ///
+        /// [default: false]
+        #[clap(short, long, verbatim_doc_comment)]
+        btf: bool,
+    },
+    /// Load a socket filter program.
+    ///
+    /// The program is only loaded and pinned. Clients obtain the program fd
+    /// from the bpfman fd socket and attach it to their own socket.
+    #[clap(verbatim_doc_comment)]
+    SocketFilter {},
+    /// Install an eBPF program on a netkit device (requires Linux 6.7+).
+    Netkit {
+        /// Required: netkit device to load program on.
+        #[clap(short, long)]
+        iface: String,
+
+        /// Optional: Side of the device pair to attach to.
+        /// [possible values: primary, peer]
+        #[clap(long, verbatim_doc_comment, default_value = "primary")]
+        position: String,
+
+        /// Required: Priority to run program in chain. Lower value runs first.
+        #[clap(short, long)]
+        priority: i32,
+    },
+    /// Install an eBPF uprobe program on a USDT (SystemTap SDT) probe.
+    Usdt {
+        /// Required: Absolute path to the binary or library containing the probe.
+        /// Example: --target "/usr/lib/x86_64-linux-gnu/libpython3.11.so.1.0"
+        #[clap(short, long, verbatim_doc_comment)]
+        target: String,
+
+        /// Required: Provider of the USDT probe.
+        /// Example: --provider "python"
+        #[clap(long, verbatim_doc_comment)]
+        provider: String,
+
+        /// Required: Name of the USDT probe.
+        /// Example: --probe "function__entry"
+        #[clap(long, verbatim_doc_comment)]
+        probe: String,
+
+        /// Optional: Only execute the program when the probe is hit by this pid.
+        /// Required for probes guarded by a semaphore, which bpfman increments
+        /// in this process while the program is attached.
+        #[clap(short, long, verbatim_doc_comment)]
+        pid: Option<i32>,
+    },
+    /// Install an eBPF kprobe or kretprobe on several kernel functions at once.
+    KprobeMulti {
+        /// Required: Functions to attach the kprobe to. Globs using "*" and "?"
+        /// are matched against available_filter_functions. Multiple values
+        /// supported by repeating the parameter.
+        /// Example: --fn-names "tcp_*" --fn-names "udp_sendmsg"
+        #[clap(short, long, verbatim_doc_comment, num_args(1..), required = true)]
+        fn_names: Vec<String>,
+
+        /// Optional: Whether the program is a kretprobe.
+        ///
+        /// [default: false]
+        #[clap(short, long, verbatim_doc_comment)]
+        retprobe: bool,
+    },
+    /// Install an eBPF uprobe or uretprobe on several symbols of a binary at once.
+    UprobeMulti {
+        /// Required: Library name or the absolute path to a binary or library.
+        /// Example: --target "libc".
+        #[clap(short, long, verbatim_doc_comment)]
+        target: String,
+
+        /// Required: Functions to attach the uprobe to. Multiple values
+        /// supported by repeating the parameter.
+        /// Example: --fn-names "malloc" --fn-names "free"
+        #[clap(short, long, verbatim_doc_comment, num_args(1..), required = true)]
+        fn_names: Vec<String>,
+
+        /// Optional: Whether the program is a uretprobe.
+        ///
+        /// [default: false]
+        #[clap(short, long, verbatim_doc_comment)]
+        retprobe: bool,
+
+        /// Optional: Only execute uprobe for given process identification number (PID).
+        /// If PID is not provided, uprobe executes for all PIDs.
+        #[clap(short, long, verbatim_doc_comment)]
+        pid: Option<i32>,
+    },
+    /// Load an eBPF iterator program. Use `bpfman read` to run it.
+    Iter {
+        /// Required: The kernel object type to iterate over, e.g. "task" for a
+        /// program in the "iter/task" section.
+        /// Example: --iter-type "task"
+        #[clap(short, long, verbatim_doc_comment)]
+        iter_type: String,
+    },
+    /// Install an eBPF extension (freplace) program, replacing a global
+    /// function of a program that is already loaded.
+    #[clap(verbatim_doc_comment)]
+    Extension {
+        /// Optional: Pin path of the program to extend.
+        /// Either --target-path or --target-id is required.
+        /// Example: --target-path "/sys/fs/bpf/my_prog"
+        #[clap(
+            long,
+            verbatim_doc_comment,
+            conflicts_with = "target_id",
+            required_unless_present = "target_id"
+        )]
+        target_path: Option<String>,
+
+        /// Optional: Kernel program id of the program to extend.
+        /// Either --target-path or --target-id is required.
+        #[clap(long, verbatim_doc_comment)]
+        target_id: Option<u32>,
+
+        /// Required: Global function of the target program to replace.
+        #[clap(short = 'f', long)]
+        target_fn: String,
     },
 }
 
@@ -245,6 +901,155 @@ pub(crate) struct UnloadArgs {
     pub(crate) id: u32,
 }
 
+#[derive(Args, Debug)]
+pub(crate) struct AttachArgs {
+    /// Required: Program id to be attached.
+    pub(crate) id: u32,
+
+    /// Optional: Attach a loaded XDP or TC program to a further interface,
+    /// with its own priority and proceed-on. The program and its maps are
+    /// shared by all its attachments.
+    /// Example: bpfman attach 6213 xdp --iface eth1 --priority 50
+    #[clap(subcommand, verbatim_doc_comment)]
+    pub(crate) command: Option<LoadCommands>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct DetachArgs {
+    /// Required: Program id to be detached.
+    pub(crate) id: u32,
+
+    /// Optional: Only remove the attachment of an XDP or TC program to this
+    /// interface, see `bpfman attach`.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) iface: Option<String>,
+
+    /// Optional: Direction of the TC attachment to remove.
+    ///
+    /// [possible values: ingress, egress]
+    #[clap(short, long, verbatim_doc_comment, requires = "iface")]
+    pub(crate) direction: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ReplaceFileArgs {
+    /// Required: Program id to be replaced. The new program takes its place
+    /// on its interface and uses its maps, the old program is unloaded once
+    /// the new one is attached.
+    pub(crate) id: u32,
+
+    /// Required: Location of local bytecode file
+    /// Example: --path /run/bpfman/examples/go-xdp-counter/bpf_bpfel.o
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) path: String,
+
+    /// Optional: The name of the function that is the entry point for the BPF program.
+    /// If not provided, the name of the replaced program is used.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) name: Option<String>,
+
+    /// Optional: Global variables to be set when program is loaded.
+    /// Format: <NAME>=<Hex Value>
+    #[clap(short, long, verbatim_doc_comment, num_args(1..), value_parser=parse_global_arg)]
+    pub(crate) global: Option<Vec<GlobalArg>>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ReplaceImageArgs {
+    /// Required: Program id to be replaced. The new program takes its place
+    /// on its interface and uses its maps, the old program is unloaded once
+    /// the new one is attached.
+    pub(crate) id: u32,
+
+    /// Specify how the bytecode image should be pulled.
+    #[command(flatten)]
+    pub(crate) pull_args: PullBytecodeArgs,
+
+    /// Optional: The name of the function that is the entry point for the BPF program.
+    /// If not provided, the name of the replaced program is used.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) name: Option<String>,
+
+    /// Optional: Global variables to be set when program is loaded.
+    /// Format: <NAME>=<Hex Value>
+    #[clap(short, long, verbatim_doc_comment, num_args(1..), value_parser=parse_global_arg)]
+    pub(crate) global: Option<Vec<GlobalArg>>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct RollbackArgs {
+    /// Required: Program id to be rolled back. It must have been loaded by
+    /// `bpfman replace`, the program it replaced is loaded again in its place.
+    pub(crate) id: u32,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SetPriorityArgs {
+    /// Required: Program id to be moved.
+    pub(crate) id: u32,
+
+    /// Required: New priority of the program in the chain. Lower value runs
+    /// first. The program isn't reloaded, so its maps keep their contents.
+    /// Example: --priority 20
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) priority: i32,
+
+    /// Optional: Only change the priority of the attachment of the program
+    /// to this interface, see `bpfman attach`.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) iface: Option<String>,
+
+    /// Optional: Direction of the TC attachment to change.
+    ///
+    /// [possible values: ingress, egress]
+    #[clap(short, long, verbatim_doc_comment, requires = "iface")]
+    pub(crate) direction: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct EventsArgs {
+    /// Optional: Show the events recorded since a time, in RFC 3339 format.
+    /// Example: --since 2024-05-02T09:00:00Z
+    #[clap(long, verbatim_doc_comment, value_parser=parse_time)]
+    pub(crate) since: Option<u64>,
+
+    /// Optional: Show the events recorded until a time, in RFC 3339 format.
+    /// Example: --until 2024-05-02T10:00:00+02:00
+    #[clap(long, verbatim_doc_comment, value_parser=parse_time)]
+    pub(crate) until: Option<u64>,
+
+    /// Optional: Show the events of the program with a UUID.
+    /// Example: --uuid 5f6e1cc1-9b4c-4a6f-8a4f-29e4a4a1e2c3
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) uuid: Option<String>,
+
+    /// Optional: Show only the latest events.
+    /// Example: --limit 20
+    #[clap(short = 'n', long, verbatim_doc_comment)]
+    pub(crate) limit: Option<u32>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct LinksArgs {
+    /// Optional: List only the links of the program with the given id.
+    /// Example: --program-id 6204
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) program_id: Option<u32>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct FeaturesArgs {
+    /// Optional: Also probe the helpers programs of the given types can call.
+    /// Example: --helpers xdp,tc
+    #[clap(
+        long,
+        verbatim_doc_comment,
+        value_delimiter = ',',
+        hide_possible_values = true
+    )]
+    pub(crate) helpers: Vec<ProgramType>,
+}
+
 #[derive(Args, Debug)]
 pub(crate) struct ListArgs {
     /// Optional: List a specific program type
@@ -257,7 +1062,8 @@ pub(crate) struct ListArgs {
     ///                   cgroup-sock-addr, lwt-seg6-local, lirc-mode2,
     ///                   sk-reuseport, flow-dissector, cgroup-sysctl,
     ///                   raw-tracepoint-writable, cgroup-sockopt, tracing,
-    ///                   struct-ops, ext, lsm, sk-lookup, syscall]
+    ///                   struct-ops, ext, lsm, sk-lookup, syscall,
+    ///                   netfilter]
     #[clap(short, long, verbatim_doc_comment, hide_possible_values = true)]
     pub(crate) program_type: Option<ProgramType>,
 
@@ -269,21 +1075,303 @@ pub(crate) struct ListArgs {
     #[clap(short, long, verbatim_doc_comment, value_parser=parse_key_val, value_delimiter = ',')]
     pub(crate) metadata_selector: Option<Vec<(String, String)>>,
 
-    /// Optional: List all programs.
+    /// Optional: List the XDP, TC and netkit programs attached to an interface.
+    /// Example: --iface eth0
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) iface: Option<String>,
+
+    /// Optional: List the programs owning or sharing the maps of the program
+    /// with the given id.
+    /// Example: --map-owner-id 63178
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) map_owner_id: Option<u32>,
+
+    /// Optional: List all programs, including the ones not loaded by bpfman,
+    /// marking which are managed by bpfman.
     #[clap(short, long, verbatim_doc_comment)]
     pub(crate) all: bool,
+
+    /// Optional: List only the programs not loaded by bpfman, like programs
+    /// loaded with bpftool or by other agents.
+    #[clap(long, verbatim_doc_comment, conflicts_with = "all")]
+    pub(crate) foreign: bool,
+
+    /// Optional: List the tag, verified instruction count, translated and
+    /// JITed sizes, BTF id and locked memory the kernel reports for the
+    /// programs.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) kernel_info: bool,
+
+    /// Optional: List the runs of the programs and the time they took, as
+    /// counted by the kernel while BPF stats are enabled.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) stats: bool,
 }
 
 #[derive(Args, Debug)]
 pub(crate) struct GetArgs {
-    /// Required: Program id to get.
+    /// Required unless --uuid is given: Program id to get.
+    #[clap(required_unless_present = "uuid")]
+    pub(crate) id: Option<u32>,
+
+    /// Optional: UUID given on load of the program to get, instead of its id.
+    #[clap(long, conflicts_with = "id")]
+    pub(crate) uuid: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ReadArgs {
+    /// Required: Program id of the iterator program to read.
     pub(crate) id: u32,
 }
 
+#[derive(Subcommand, Debug)]
+pub(crate) enum MapsSubcommand {
+    /// Publish the maps of an eBPF program under a name using the program id.
+    Publish(PublishMapsArgs),
+    /// Remove a name maps were published under.
+    Unpublish(UnpublishMapsArgs),
+    /// Expose maps of an eBPF program in a directory of a consumer.
+    Export(ExportMapsArgs),
+    /// Remove the maps exported to a directory.
+    Unexport(UnexportMapsArgs),
+    /// List the maps pinned for an eBPF program using the program id.
+    List(ListMapsArgs),
+    /// List the maps in the kernel with the programs using them.
+    Kernel(KernelMapsArgs),
+    /// Print all entries of a map.
+    Dump(DumpMapArgs),
+    /// Print the value of a key in a map.
+    Lookup(LookupMapEntryArgs),
+    /// Set the value of a key in a map.
+    Update(UpdateMapEntryArgs),
+    /// Remove a key from a map.
+    Delete(DeleteMapEntryArgs),
+    /// Print the records of a ring buffer or perf event array map as they
+    /// are submitted.
+    Watch(WatchMapArgs),
+    /// Save the contents of the maps of an eBPF program to a JSON snapshot.
+    Snapshot(SnapshotMapsArgs),
+    /// Write the contents of a JSON snapshot into the maps of an eBPF program.
+    Restore(RestoreMapsArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct PublishMapsArgs {
+    /// Required: Program id whose maps are published.
+    pub(crate) id: u32,
+
+    /// Required: Name to publish the maps under. Programs can then be loaded
+    /// with `--map-from <NAME>` to share them.
+    pub(crate) name: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct UnpublishMapsArgs {
+    /// Required: Name the maps were published under. Programs already using
+    /// the maps keep them.
+    pub(crate) name: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ExportMapsArgs {
+    /// Required: Program id whose maps are exported.
+    pub(crate) id: u32,
+
+    /// Required: Absolute path of the directory to export the maps to. It
+    /// must be in a bpffs unless --mount is given.
+    #[clap(short, long)]
+    pub(crate) path: String,
+
+    /// Optional: Names the maps to export are pinned under. All pinned maps
+    /// are exported by default.
+    /// Example: --maps xdp_stats_map,events
+    #[clap(short, long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) maps: Option<Vec<String>>,
+
+    /// Optional: Pin the maps in a bpffs of their own and bind mount it on
+    /// the directory, which is created if needed.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) mount: bool,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct UnexportMapsArgs {
+    /// Required: Path of the directory the maps were exported to.
+    pub(crate) path: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ListMapsArgs {
+    /// Required: Program id whose maps are listed.
+    pub(crate) id: u32,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct KernelMapsArgs {
+    /// Optional: List only the maps used by the program with the given id.
+    /// Example: --program-id 6204
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) program_id: Option<u32>,
+
+    /// Optional: List only the maps of programs loaded by bpfman that
+    /// programs not loaded by bpfman use too.
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) foreign_access: bool,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct DumpMapArgs {
+    /// Required: Program id the map belongs to.
+    pub(crate) id: u32,
+
+    /// Required: Name of the map, as printed by `bpfman maps list`.
+    pub(crate) map: String,
+
+    /// Optional: Print keys and values as JSON using the BTF of the map,
+    /// rather than as hex.
+    #[clap(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct LookupMapEntryArgs {
+    /// Required: Program id the map belongs to.
+    pub(crate) id: u32,
+
+    /// Required: Name of the map, as printed by `bpfman maps list`.
+    pub(crate) map: String,
+
+    /// Required: Key to look up, as hex in the byte order of the map.
+    /// Example: --key 0a000001
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) key: String,
+
+    /// Optional: Print the value as JSON using the BTF of the map, rather
+    /// than as hex.
+    #[clap(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct UpdateMapEntryArgs {
+    /// Required: Program id the map belongs to.
+    pub(crate) id: u32,
+
+    /// Required: Name of the map, as printed by `bpfman maps list`.
+    pub(crate) map: String,
+
+    /// Required: Key to set, as hex in the byte order of the map.
+    #[clap(short, long)]
+    pub(crate) key: String,
+
+    /// Required: Value to set, as hex in the byte order of the map. Values
+    /// of per-CPU maps hold the value of every possible CPU, each padded to
+    /// 8 bytes.
+    #[clap(short, long)]
+    pub(crate) value: String,
+
+    /// Optional: Only add the key if it isn't in the map yet.
+    #[clap(long, conflicts_with = "exist")]
+    pub(crate) no_exist: bool,
+
+    /// Optional: Only set the key if it is in the map already.
+    #[clap(long)]
+    pub(crate) exist: bool,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct DeleteMapEntryArgs {
+    /// Required: Program id the map belongs to.
+    pub(crate) id: u32,
+
+    /// Required: Name of the map, as printed by `bpfman maps list`.
+    pub(crate) map: String,
+
+    /// Required: Key to remove, as hex in the byte order of the map.
+    #[clap(short, long)]
+    pub(crate) key: String,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct WatchMapArgs {
+    /// Required: Program id the map belongs to.
+    pub(crate) id: u32,
+
+    /// Required: Name of the ring buffer or perf event array map, as printed
+    /// by `bpfman maps list`.
+    pub(crate) map: String,
+
+    /// Optional: Size of the buffer of each CPU in pages for perf event
+    /// arrays, a power of two. Ignored if the map is already being watched.
+    #[clap(long)]
+    pub(crate) page_count: Option<u32>,
+
+    /// Optional: Number of records bpfman queues for this client when it
+    /// falls behind. Defaults to the queue_size of the bpfman configuration.
+    #[clap(long)]
+    pub(crate) queue_size: Option<u32>,
+
+    /// Optional: What bpfman drops once the queue is full. Defaults to the
+    /// drop_policy of the bpfman configuration.
+    ///
+    /// [possible values: drop-newest, drop-oldest, block]
+    #[clap(long, verbatim_doc_comment)]
+    pub(crate) drop_policy: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SnapshotMapsArgs {
+    /// Required: Program id whose maps are saved.
+    pub(crate) id: u32,
+
+    /// Optional: Names of the maps to save, as printed by `bpfman maps list`.
+    /// By default all maps holding data are saved, leaving out maps of
+    /// programs, sockets, devices or other maps, and ring buffers and perf
+    /// event arrays.
+    /// Example: --maps conntrack,policy
+    #[clap(short, long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) maps: Option<Vec<String>>,
+
+    /// Optional: File to write the snapshot to, instead of stdout.
+    #[clap(short, long)]
+    pub(crate) output: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct RestoreMapsArgs {
+    /// Required: Program id whose maps are written.
+    pub(crate) id: u32,
+
+    /// Required: Snapshot file written by `bpfman maps snapshot`.
+    #[clap(short, long)]
+    pub(crate) file: String,
+
+    /// Optional: Names of the maps of the snapshot to restore. By default all
+    /// of them are.
+    /// Example: --maps conntrack
+    #[clap(short, long, verbatim_doc_comment, value_delimiter = ',')]
+    pub(crate) maps: Option<Vec<String>>,
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum ImageSubCommand {
     /// Pull an eBPF bytecode image from a remote registry.
     Pull(PullBytecodeArgs),
+    /// Remove the bytecode images no loaded program uses from the image store.
+    Prune,
+    /// List the bytecode images in the image store.
+    List,
+    /// Show the details of a bytecode image in the image store.
+    Inspect(InspectImageArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct InspectImageArgs {
+    /// Required: URL the image was pulled from.
+    /// Example: quay.io/bpfman-bytecode/xdp_pass:latest
+    #[clap(verbatim_doc_comment)]
+    pub(crate) image_url: String,
 }
 
 #[derive(Args, Debug)]
@@ -315,6 +1403,10 @@ pub(crate) struct ServiceArgs {
     /// Shutdown after N seconds of inactivity. Use 0 to disable.
     #[clap(long, default_value = "15")]
     pub(crate) timeout: u64,
+    /// Never contact a registry and only load images already in the image
+    /// store. Overrides the offline setting of the configuration file.
+    #[clap(long)]
+    pub(crate) offline: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -329,6 +1421,73 @@ pub(crate) fn parse_key_val(s: &str) -> Result<(String, String), std::io::Error>
     Ok((s[..pos].to_string(), s[pos + 1..].to_string()))
 }
 
+pub(crate) fn parse_map_max_entries(s: &str) -> Result<(String, u32), std::io::Error> {
+    let (name, max_entries) = parse_key_val(s)?;
+    let max_entries = max_entries
+        .parse()
+        .map_err(|_e| std::io::ErrorKind::InvalidInput)?;
+    Ok((name, max_entries))
+}
+
+pub(crate) fn parse_map_init_arg(s: &str) -> Result<MapInitArg, std::io::Error> {
+    let (map, entry) = s.split_once(':').ok_or(std::io::ErrorKind::InvalidInput)?;
+    let (key, value) = parse_key_val(entry)?;
+    let key = Vec::<u8>::from_hex(key).map_err(|_e| std::io::ErrorKind::InvalidInput)?;
+    let value = Vec::<u8>::from_hex(value).map_err(|_e| std::io::ErrorKind::InvalidInput)?;
+    if map.is_empty() || key.is_empty() || value.is_empty() {
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    Ok(MapInitArg {
+        map: map.to_string(),
+        key,
+        value,
+    })
+}
+
+pub(crate) fn parse_inner_map_arg(s: &str) -> Result<InnerMapArg, std::io::Error> {
+    let (outer, entry) = s.split_once(':').ok_or(std::io::ErrorKind::InvalidInput)?;
+    let (key, def) = parse_key_val(entry)?;
+    let key = Vec::<u8>::from_hex(key).map_err(|_e| std::io::ErrorKind::InvalidInput)?;
+    let def = def
+        .split(':')
+        .map(|v| v.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|_e| std::io::ErrorKind::InvalidInput)?;
+    if outer.is_empty() || key.is_empty() || def.len() != 4 {
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    Ok(InnerMapArg {
+        outer: outer.to_string(),
+        key,
+        map_type: def[0],
+        key_size: def[1],
+        value_size: def[2],
+        max_entries: def[3],
+    })
+}
+
+pub(crate) fn parse_mode(s: &str) -> Result<u32, std::io::Error> {
+    u32::from_str_radix(s.trim_start_matches("0o"), 8)
+        .map_err(|_e| std::io::ErrorKind::InvalidInput.into())
+}
+
+// Returns the time in milliseconds since the epoch.
+pub(crate) fn parse_time(s: &str) -> Result<u64, std::io::Error> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .and_then(|t| u64::try_from(t.timestamp_millis()).ok())
+        .ok_or(std::io::ErrorKind::InvalidInput.into())
+}
+
+pub(crate) fn parse_verifier_log_level(s: &str) -> Result<u32, std::io::Error> {
+    match s {
+        "debug" => Ok(1),
+        "verbose" => Ok(2),
+        "stats" => Ok(4),
+        _ => Err(std::io::ErrorKind::InvalidInput.into()),
+    }
+}
+
 pub(crate) fn parse_global_arg(global_arg: &str) -> Result<GlobalArg, std::io::Error> {
     let mut parts = global_arg.split('=');
 
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, AttachRequest};
+
+use crate::cli::{args::AttachArgs, select_channel};
+
+pub(crate) async fn execute_attach(args: &AttachArgs) -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let attach = match &args.command {
+        Some(command) => command.get_attach_type()?,
+        None => None,
+    };
+    let request = tonic::Request::new(AttachRequest {
+        id: args.id,
+        attach,
+    });
+    let _response = client.attach(request).await?.into_inner();
+    Ok(())
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, DetachRequest};
+
+use crate::cli::{args::DetachArgs, select_channel};
+
+pub(crate) async fn execute_detach(args: &DetachArgs) -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(DetachRequest {
+        id: args.id,
+        iface: args.iface.clone(),
+        direction: args.direction.clone(),
+    });
+    let _response = client.detach(request).await?.into_inner();
+    Ok(())
+}
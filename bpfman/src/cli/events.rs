@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, GetEventsRequest};
+
+use crate::cli::{args::EventsArgs, select_channel, table::ProgTable};
+
+pub(crate) async fn execute_events(args: &EventsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(GetEventsRequest {
+        since: args.since,
+        until: args.until,
+        uuid: args.uuid.clone(),
+        limit: args.limit.unwrap_or_default(),
+    });
+    let response = client.get_events(request).await?.into_inner();
+    ProgTable::new_event_list(&response.events).print();
+    Ok(())
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, ProbeFeaturesRequest};
+
+use crate::cli::{args::FeaturesArgs, select_channel, table::ProgTable};
+
+pub(crate) async fn execute_features(args: &FeaturesArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ProbeFeaturesRequest {
+        helper_program_types: args.helpers.iter().map(|t| *t as u32).collect(),
+    });
+    let response = client.probe_features(request).await?.into_inner();
+    ProgTable::new_features(&response)?.print();
+    Ok(())
+}
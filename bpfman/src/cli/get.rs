@@ -8,7 +8,10 @@ use crate::cli::{args::GetArgs, select_channel, table::ProgTable};
 pub(crate) async fn execute_get(args: &GetArgs) -> Result<(), anyhow::Error> {
     let channel = select_channel().expect("failed to select channel");
     let mut client = BpfmanClient::new(channel);
-    let request = tonic::Request::new(GetRequest { id: args.id });
+    let request = tonic::Request::new(GetRequest {
+        id: args.id.unwrap_or_default(),
+        uuid: args.uuid.clone(),
+    });
     let response = client.get(request).await?.into_inner();
 
     ProgTable::new_get_bpfman(&response.info)?.print();
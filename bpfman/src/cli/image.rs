@@ -1,21 +1,29 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
+use anyhow::bail;
 use base64::{engine::general_purpose, Engine};
 use bpfman_api::{
-    v1::{bpfman_client::BpfmanClient, BytecodeImage, PullBytecodeRequest},
+    v1::{
+        bpfman_client::BpfmanClient, BytecodeImage, ListImagesRequest, PruneImagesRequest,
+        PullBytecodeRequest,
+    },
     ImagePullPolicy,
 };
 
 use crate::cli::{
-    args::{ImageSubCommand, PullBytecodeArgs},
+    args::{ImageSubCommand, InspectImageArgs, PullBytecodeArgs},
     select_channel,
+    table::ProgTable,
 };
 
 impl ImageSubCommand {
     pub(crate) async fn execute(&self) -> anyhow::Result<()> {
         match self {
             ImageSubCommand::Pull(args) => execute_pull(args).await,
+            ImageSubCommand::Prune => execute_prune().await,
+            ImageSubCommand::List => execute_list().await,
+            ImageSubCommand::Inspect(args) => execute_inspect(args).await,
         }
     }
 }
@@ -49,6 +57,43 @@ pub(crate) async fn execute_pull(args: &PullBytecodeArgs) -> anyhow::Result<()>
     let mut client = BpfmanClient::new(channel);
     let image: BytecodeImage = args.try_into()?;
     let request = tonic::Request::new(PullBytecodeRequest { image: Some(image) });
-    let _response = client.pull_bytecode(request).await?;
+    let response = client.pull_bytecode(request).await?.into_inner();
+    if let Some(image) = response.image {
+        ProgTable::new_image_info(&image).print();
+    }
+    Ok(())
+}
+
+pub(crate) async fn execute_prune() -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(PruneImagesRequest {});
+    let response = client.prune_images(request).await?.into_inner();
+    for image in response.images {
+        println!("{image}");
+    }
+    Ok(())
+}
+
+pub(crate) async fn execute_list() -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListImagesRequest { url: None });
+    let response = client.list_images(request).await?.into_inner();
+    ProgTable::new_image_list(&response.images).print();
+    Ok(())
+}
+
+pub(crate) async fn execute_inspect(args: &InspectImageArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListImagesRequest {
+        url: Some(args.image_url.clone()),
+    });
+    let response = client.list_images(request).await?.into_inner();
+    match response.images.first() {
+        Some(image) => ProgTable::new_image_info(image).print(),
+        None => bail!("image {} is not in the image store", args.image_url),
+    }
     Ok(())
 }
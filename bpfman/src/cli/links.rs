@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, ListKernelLinksRequest};
+
+use crate::cli::{args::LinksArgs, select_channel, table::ProgTable};
+
+pub(crate) async fn execute_links(args: &LinksArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListKernelLinksRequest {
+        program_id: args.program_id,
+    });
+    let response = client.list_kernel_links(request).await?.into_inner();
+    ProgTable::new_link_list(&response.links).print();
+    Ok(())
+}
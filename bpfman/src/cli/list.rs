@@ -21,13 +21,17 @@ pub(crate) async fn execute_list(args: &ListArgs) -> anyhow::Result<()> {
             .iter()
             .map(|(k, v)| (k.to_owned(), v.to_owned()))
             .collect(),
-        bpfman_programs_only: Some(!args.all),
+        bpfman_programs_only: Some(!args.all && !args.foreign),
+        foreign_programs_only: Some(args.foreign),
+        iface: args.iface.clone(),
+        map_owner_id: args.map_owner_id,
+        ..Default::default()
     });
     let response = client.list(request).await?.into_inner();
-    let mut table = ProgTable::new_list();
+    let mut table = ProgTable::new_list(args.all, args.kernel_info, args.stats);
 
     for r in response.results {
-        if let Err(e) = table.add_response_prog(r) {
+        if let Err(e) = table.add_response_prog(r, args.all, args.kernel_info, args.stats) {
             bail!(e)
         }
     }
@@ -3,22 +3,36 @@
 
 use std::collections::HashMap;
 
-use anyhow::bail;
+use anyhow::{bail, Context};
 use bpfman_api::{
     v1::{
         attach_info::Info, bpfman_client::BpfmanClient, bytecode_location::Location, AttachInfo,
-        BytecodeImage, BytecodeLocation, KprobeAttachInfo, LoadRequest, TcAttachInfo,
-        TracepointAttachInfo, UprobeAttachInfo, XdpAttachInfo,
+        BytecodeImage, BytecodeLocation, BytecodeUrl, CgroupDeviceAttachInfo, CgroupSkbAttachInfo,
+        CgroupSockAddrAttachInfo, CgroupSockoptAttachInfo, CgroupSysctlAttachInfo,
+        ExtensionAttachInfo, FlowDissectorAttachInfo, InnerMaps, IterAttachInfo, KprobeAttachInfo,
+        KprobeMultiAttachInfo, LoadRequest, LoadResponse, LoadUploadRequest, LsmAttachInfo,
+        MapEntry, MapInit, MapPermissions, MapPinPolicy, NetkitAttachInfo, PerfEventAttachInfo,
+        RawTracepointAttachInfo, SkLookupAttachInfo, SkMsgAttachInfo, SkSkbAttachInfo,
+        SockOpsAttachInfo, SocketFilterAttachInfo, TcAttachInfo, TracepointAttachInfo,
+        UprobeAttachInfo, UprobeMultiAttachInfo, UsdtAttachInfo, XdpAttachInfo,
     },
-    ProgramType, TcProceedOn, XdpProceedOn,
+    CgroupAttachFlags, ProgramType, TcProceedOn, XdpProceedOn,
 };
+use tonic::{transport::Channel, Status};
+use tonic_types::StatusExt;
 
 use crate::cli::{
-    args::{GlobalArg, LoadCommands, LoadFileArgs, LoadImageArgs, LoadSubcommand},
+    args::{
+        GlobalArg, InnerMapArg, LoadCommands, LoadFileArgs, LoadImageArgs, LoadSubcommand,
+        MapInitArg,
+    },
     select_channel,
     table::ProgTable,
 };
 
+// Bytecode files are uploaded in chunks of this size.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
 impl LoadSubcommand {
     pub(crate) async fn execute(&self) -> anyhow::Result<()> {
         match self {
@@ -32,13 +46,20 @@ pub(crate) async fn execute_load_file(args: &LoadFileArgs) -> anyhow::Result<()>
     let channel = select_channel().expect("failed to select channel");
     let mut client = BpfmanClient::new(channel);
 
+    let location = match &args.sha256 {
+        Some(sha256) => Location::Url(BytecodeUrl {
+            url: args.path.clone(),
+            sha256: sha256.clone(),
+        }),
+        None => Location::File(args.path.clone()),
+    };
     let bytecode = Some(BytecodeLocation {
-        location: Some(Location::File(args.path.clone())),
+        location: Some(location),
     });
 
     let attach = args.command.get_attach_type()?;
 
-    let request = tonic::Request::new(LoadRequest {
+    let request = LoadRequest {
         bytecode,
         name: args.name.to_string(),
         program_type: args.command.get_prog_type() as u32,
@@ -53,12 +74,32 @@ pub(crate) async fn execute_load_file(args: &LoadFileArgs) -> anyhow::Result<()>
         global_data: parse_global(&args.global),
         uuid: None,
         map_owner_id: args.map_owner_id,
-    });
-    let response = client.load(request).await?.into_inner();
+        map_from: args.map_from.clone(),
+        detached: args.detached,
+        additional_names: args.additional_names.clone().unwrap_or_default(),
+        map_max_entries: args
+            .map_max_entries
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+        map_pinning: map_pin_policy(&args.pin_include, &args.pin_exclude, &args.pin_name),
+        map_init: map_init(&args.map_init, &args.freeze_map),
+        inner_maps: inner_maps(&args.inner_map)?,
+        map_permissions: map_permissions(&args.map_user, &args.map_group, args.map_mode),
+        verifier_log_level: args
+            .verifier_log_level
+            .as_ref()
+            .map(|levels| levels.iter().fold(0, |level, l| level | l)),
+        verifier_log_size: args.verifier_log_size,
+    };
+    let response = if args.upload {
+        load_upload(&mut client, request, &args.path).await?
+    } else {
+        client.load(request).await.map_err(load_error)?.into_inner()
+    };
 
-    ProgTable::new_get_bpfman(&response.info)?.print();
-    ProgTable::new_get_unsupported(&response.kernel_info)?.print();
-    Ok(())
+    print_load_response(&response)
 }
 
 pub(crate) async fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<()> {
@@ -86,11 +127,73 @@ pub(crate) async fn execute_load_image(args: &LoadImageArgs) -> anyhow::Result<(
         global_data: parse_global(&args.global),
         uuid: None,
         map_owner_id: args.map_owner_id,
+        map_from: args.map_from.clone(),
+        detached: args.detached,
+        additional_names: args.additional_names.clone().unwrap_or_default(),
+        map_max_entries: args
+            .map_max_entries
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+        map_pinning: map_pin_policy(&args.pin_include, &args.pin_exclude, &args.pin_name),
+        map_init: map_init(&args.map_init, &args.freeze_map),
+        inner_maps: inner_maps(&args.inner_map)?,
+        map_permissions: map_permissions(&args.map_user, &args.map_group, args.map_mode),
+        verifier_log_level: args
+            .verifier_log_level
+            .as_ref()
+            .map(|levels| levels.iter().fold(0, |level, l| level | l)),
+        verifier_log_size: args.verifier_log_size,
     });
-    let response = client.load(request).await?.into_inner();
+    let response = client.load(request).await.map_err(load_error)?.into_inner();
+
+    print_load_response(&response)
+}
+
+// Uploads the bytecode file at path in chunks, following the load request.
+async fn load_upload(
+    client: &mut BpfmanClient<Channel>,
+    request: LoadRequest,
+    path: &str,
+) -> anyhow::Result<LoadResponse> {
+    let data = std::fs::read(path).with_context(|| format!("unable to read {path}"))?;
+    let mut messages = vec![LoadUploadRequest {
+        load: Some(request),
+        chunk: vec![],
+    }];
+    messages.extend(
+        data.chunks(UPLOAD_CHUNK_SIZE)
+            .map(|chunk| LoadUploadRequest {
+                load: None,
+                chunk: chunk.to_vec(),
+            }),
+    );
+    Ok(client
+        .load_upload(tokio_stream::iter(messages))
+        .await
+        .map_err(load_error)?
+        .into_inner())
+}
 
+// Shows the verifier log a load failed with, if the verifier rejected it.
+pub(crate) fn load_error(status: Status) -> anyhow::Error {
+    match status.get_details_debug_info() {
+        Some(info) if info.detail == "verifier log" => anyhow::anyhow!(
+            "{}\n\nVerifier log:\n{}",
+            status.message(),
+            info.stack_entries.join("\n")
+        ),
+        _ => status.into(),
+    }
+}
+
+fn print_load_response(response: &LoadResponse) -> anyhow::Result<()> {
     ProgTable::new_get_bpfman(&response.info)?.print();
     ProgTable::new_get_unsupported(&response.kernel_info)?.print();
+    for program in &response.additional_programs {
+        print_load_response(program)?;
+    }
     Ok(())
 }
 
@@ -102,6 +205,29 @@ impl LoadCommands {
             LoadCommands::Tracepoint { .. } => ProgramType::Tracepoint,
             LoadCommands::Kprobe { .. } => ProgramType::Probe,
             LoadCommands::Uprobe { .. } => ProgramType::Probe,
+            LoadCommands::Lsm { .. } => ProgramType::Lsm,
+            LoadCommands::CgroupSkb { .. } => ProgramType::CgroupSkb,
+            LoadCommands::CgroupSockAddr { .. } => ProgramType::CgroupSockAddr,
+            LoadCommands::SockOps { .. } => ProgramType::SockOps,
+            LoadCommands::CgroupDevice { .. } => ProgramType::CgroupDevice,
+            LoadCommands::CgroupSysctl { .. } => ProgramType::CgroupSysctl,
+            LoadCommands::CgroupSockopt { .. } => ProgramType::CgroupSockopt,
+            LoadCommands::SkMsg { .. } => ProgramType::SkMsg,
+            LoadCommands::SkSkb { .. } => ProgramType::SkSkb,
+            LoadCommands::FlowDissector { .. } => ProgramType::FlowDissector,
+            LoadCommands::SkLookup { .. } => ProgramType::SkLookup,
+            LoadCommands::PerfEvent { .. } => ProgramType::PerfEvent,
+            LoadCommands::RawTracepoint { btf, .. } => match btf {
+                true => ProgramType::Tracing,
+                false => ProgramType::RawTracepoint,
+            },
+            LoadCommands::SocketFilter {} => ProgramType::SocketFilter,
+            LoadCommands::Netkit { .. } => ProgramType::Tc,
+            LoadCommands::Usdt { .. } => ProgramType::Probe,
+            LoadCommands::KprobeMulti { .. } => ProgramType::Probe,
+            LoadCommands::UprobeMulti { .. } => ProgramType::Probe,
+            LoadCommands::Iter { .. } => ProgramType::Tracing,
+            LoadCommands::Extension { .. } => ProgramType::Ext,
         }
     }
 
@@ -111,6 +237,9 @@ impl LoadCommands {
                 iface,
                 priority,
                 proceed_on,
+                track_iface_glob,
+                wait_for_iface,
+                netns_path,
             } => {
                 let proc_on = match XdpProceedOn::from_strings(proceed_on) {
                     Ok(p) => p,
@@ -122,6 +251,11 @@ impl LoadCommands {
                         iface: iface.to_string(),
                         position: 0,
                         proceed_on: proc_on.as_action_vec(),
+                        track_iface_glob: *track_iface_glob,
+                        wait_for_iface: *wait_for_iface,
+                        netns_path: netns_path.clone(),
+                        slot_stats: None,
+                        mode: None,
                     })),
                 }))
             }
@@ -130,9 +264,13 @@ impl LoadCommands {
                 iface,
                 priority,
                 proceed_on,
+                tcx,
+                track_iface_glob,
+                wait_for_iface,
+                netns_path,
             } => {
                 match direction.as_str() {
-                    "ingress" | "egress" => (),
+                    "ingress" | "egress" | "both" => (),
                     other => bail!("{} is not a valid direction", other),
                 };
                 let proc_on = match TcProceedOn::from_strings(proceed_on) {
@@ -146,6 +284,11 @@ impl LoadCommands {
                         position: 0,
                         direction: direction.to_string(),
                         proceed_on: proc_on.as_action_vec(),
+                        tcx: *tcx,
+                        track_iface_glob: *track_iface_glob,
+                        wait_for_iface: *wait_for_iface,
+                        netns_path: netns_path.clone(),
+                        slot_stats: None,
                     })),
                 }))
             }
@@ -180,6 +323,7 @@ impl LoadCommands {
                 retprobe,
                 pid,
                 container_pid,
+                container_id,
             } => {
                 let offset = offset.unwrap_or(0);
                 Ok(Some(AttachInfo {
@@ -190,14 +334,343 @@ impl LoadCommands {
                         retprobe: *retprobe,
                         pid: *pid,
                         container_pid: *container_pid,
+                        container_id: container_id.clone(),
                     })),
                 }))
             }
+            LoadCommands::Lsm { hook } => Ok(Some(AttachInfo {
+                info: Some(Info::LsmAttachInfo(LsmAttachInfo {
+                    hook: hook.to_string(),
+                })),
+            })),
+            LoadCommands::CgroupSkb {
+                cgroup_path,
+                direction,
+                attach_flags,
+            } => {
+                match direction.as_str() {
+                    "ingress" | "egress" => (),
+                    other => bail!("{} is not a valid direction", other),
+                };
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        direction: direction.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::CgroupSockAddr {
+                cgroup_path,
+                attach_point,
+                attach_flags,
+            } => {
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::CgroupSockAddrAttachInfo(CgroupSockAddrAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        attach_point: attach_point.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::SockOps {
+                cgroup_path,
+                attach_flags,
+            } => {
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::SockOpsAttachInfo(SockOpsAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::CgroupDevice {
+                cgroup_path,
+                attach_flags,
+            } => {
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::CgroupSysctl {
+                cgroup_path,
+                attach_flags,
+            } => {
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::CgroupSockopt {
+                cgroup_path,
+                attach_type,
+                attach_flags,
+            } => {
+                match attach_type.as_str() {
+                    "getsockopt" | "setsockopt" => (),
+                    other => bail!("{} is not a valid attach type", other),
+                };
+                let attach_flags = match CgroupAttachFlags::try_from(attach_flags.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => bail!("error parsing attach_flags {e}"),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+                        cgroup_path: cgroup_path.to_string(),
+                        attach_type: attach_type.to_string(),
+                        attach_flags: attach_flags.into(),
+                    })),
+                }))
+            }
+            LoadCommands::SkMsg {
+                map_name,
+                sock_map_pin_path,
+            } => Ok(Some(AttachInfo {
+                info: Some(Info::SkMsgAttachInfo(SkMsgAttachInfo {
+                    map_name: map_name.to_string(),
+                    sock_map_pin_path: sock_map_pin_path.clone(),
+                })),
+            })),
+            LoadCommands::SkSkb {
+                map_name,
+                sock_map_pin_path,
+                attach_type,
+            } => {
+                match attach_type.as_str() {
+                    "stream_parser" | "stream_verdict" => (),
+                    other => bail!("{} is not a valid attach type", other),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::SkSkbAttachInfo(SkSkbAttachInfo {
+                        map_name: map_name.to_string(),
+                        sock_map_pin_path: sock_map_pin_path.clone(),
+                        attach_type: attach_type.to_string(),
+                    })),
+                }))
+            }
+            LoadCommands::FlowDissector { netns_path } => Ok(Some(AttachInfo {
+                info: Some(Info::FlowDissectorAttachInfo(FlowDissectorAttachInfo {
+                    netns_path: netns_path.to_string(),
+                })),
+            })),
+            LoadCommands::SkLookup { netns_path } => Ok(Some(AttachInfo {
+                info: Some(Info::SkLookupAttachInfo(SkLookupAttachInfo {
+                    netns_path: netns_path.to_string(),
+                })),
+            })),
+            LoadCommands::PerfEvent {
+                event,
+                sample_period,
+                sample_frequency,
+                cpus,
+            } => Ok(Some(AttachInfo {
+                info: Some(Info::PerfEventAttachInfo(PerfEventAttachInfo {
+                    event: event.to_string(),
+                    sample_period: *sample_period,
+                    sample_frequency: *sample_frequency,
+                    cpus: cpus.clone(),
+                })),
+            })),
+            LoadCommands::RawTracepoint { tracepoint, btf } => Ok(Some(AttachInfo {
+                info: Some(Info::RawTracepointAttachInfo(RawTracepointAttachInfo {
+                    tracepoint: tracepoint.to_string(),
+                    btf: *btf,
+                })),
+            })),
+            LoadCommands::SocketFilter {} => Ok(Some(AttachInfo {
+                info: Some(Info::SocketFilterAttachInfo(SocketFilterAttachInfo {})),
+            })),
+            LoadCommands::Netkit {
+                iface,
+                position,
+                priority,
+            } => {
+                match position.as_str() {
+                    "primary" | "peer" => (),
+                    other => bail!("{} is not a valid position", other),
+                };
+                Ok(Some(AttachInfo {
+                    info: Some(Info::NetkitAttachInfo(NetkitAttachInfo {
+                        iface: iface.to_string(),
+                        position: position.to_string(),
+                        priority: *priority,
+                    })),
+                }))
+            }
+            LoadCommands::Usdt {
+                target,
+                provider,
+                probe,
+                pid,
+            } => Ok(Some(AttachInfo {
+                info: Some(Info::UsdtAttachInfo(UsdtAttachInfo {
+                    target: target.to_string(),
+                    provider: provider.to_string(),
+                    name: probe.to_string(),
+                    pid: *pid,
+                })),
+            })),
+            LoadCommands::KprobeMulti { fn_names, retprobe } => Ok(Some(AttachInfo {
+                info: Some(Info::KprobeMultiAttachInfo(KprobeMultiAttachInfo {
+                    fn_names: fn_names.clone(),
+                    retprobe: *retprobe,
+                })),
+            })),
+            LoadCommands::UprobeMulti {
+                target,
+                fn_names,
+                retprobe,
+                pid,
+            } => Ok(Some(AttachInfo {
+                info: Some(Info::UprobeMultiAttachInfo(UprobeMultiAttachInfo {
+                    target: target.to_string(),
+                    fn_names: fn_names.clone(),
+                    retprobe: *retprobe,
+                    pid: *pid,
+                })),
+            })),
+            LoadCommands::Iter { iter_type } => Ok(Some(AttachInfo {
+                info: Some(Info::IterAttachInfo(IterAttachInfo {
+                    iter_type: iter_type.to_string(),
+                })),
+            })),
+            LoadCommands::Extension {
+                target_path,
+                target_id,
+                target_fn,
+            } => Ok(Some(AttachInfo {
+                info: Some(Info::ExtensionAttachInfo(ExtensionAttachInfo {
+                    target_path: target_path.clone(),
+                    target_id: *target_id,
+                    target_fn: target_fn.to_string(),
+                })),
+            })),
+        }
+    }
+}
+
+// Returns the map pin policy of the load arguments, if any of them were given.
+fn map_pin_policy(
+    include: &Option<Vec<String>>,
+    exclude: &Option<Vec<String>>,
+    pin_names: &Option<Vec<(String, String)>>,
+) -> Option<MapPinPolicy> {
+    if include.is_none() && exclude.is_none() && pin_names.is_none() {
+        return None;
+    }
+    Some(MapPinPolicy {
+        include: include.clone().unwrap_or_default(),
+        exclude: exclude.clone().unwrap_or_default(),
+        pin_names: pin_names.clone().unwrap_or_default().into_iter().collect(),
+    })
+}
+
+// Groups the initial map entries of the load arguments by map, adding the maps
+// to freeze.
+fn map_init(entries: &Option<Vec<MapInitArg>>, freeze: &Option<Vec<String>>) -> Vec<MapInit> {
+    let mut init: Vec<MapInit> = Vec::new();
+    for entry in entries.iter().flatten() {
+        map_init_of(&mut init, &entry.map).entries.push(MapEntry {
+            key: entry.key.clone(),
+            value: entry.value.clone(),
+            ..Default::default()
+        });
+    }
+    for map in freeze.iter().flatten() {
+        map_init_of(&mut init, map).freeze = true;
+    }
+    init
+}
+
+fn map_init_of<'a>(init: &'a mut Vec<MapInit>, map: &str) -> &'a mut MapInit {
+    let i = match init.iter().position(|i| i.map == map) {
+        Some(i) => i,
+        None => {
+            init.push(MapInit {
+                map: map.to_string(),
+                ..Default::default()
+            });
+            init.len() - 1
         }
+    };
+    &mut init[i]
+}
+
+// Groups the inner maps of the load arguments by outer map, whose inner maps
+// all have the same definition.
+fn inner_maps(args: &Option<Vec<InnerMapArg>>) -> anyhow::Result<Vec<InnerMaps>> {
+    let mut inner: Vec<InnerMaps> = Vec::new();
+    for arg in args.iter().flatten() {
+        match inner.iter_mut().find(|i| i.outer == arg.outer) {
+            Some(maps) => {
+                if (
+                    maps.map_type,
+                    maps.key_size,
+                    maps.value_size,
+                    maps.max_entries,
+                ) != (arg.map_type, arg.key_size, arg.value_size, arg.max_entries)
+                {
+                    bail!(
+                        "inner maps of map {} must have the same definition",
+                        arg.outer
+                    );
+                }
+                maps.keys.push(arg.key.clone());
+            }
+            None => inner.push(InnerMaps {
+                outer: arg.outer.clone(),
+                map_type: arg.map_type,
+                key_size: arg.key_size,
+                value_size: arg.value_size,
+                max_entries: arg.max_entries,
+                keys: vec![arg.key.clone()],
+            }),
+        }
+    }
+    Ok(inner)
+}
+
+fn map_permissions(
+    user: &Option<String>,
+    group: &Option<String>,
+    mode: Option<u32>,
+) -> Option<MapPermissions> {
+    if user.is_none() && group.is_none() && mode.is_none() {
+        return None;
     }
+    Some(MapPermissions {
+        user: user.clone(),
+        group: group.clone(),
+        mode,
+    })
 }
 
-fn parse_global(global: &Option<Vec<GlobalArg>>) -> HashMap<String, Vec<u8>> {
+pub(crate) fn parse_global(global: &Option<Vec<GlobalArg>>) -> HashMap<String, Vec<u8>> {
     let mut global_data: HashMap<String, Vec<u8>> = HashMap::new();
 
     if let Some(global) = global {
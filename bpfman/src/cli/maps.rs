@@ -0,0 +1,398 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use anyhow::{anyhow, bail};
+use bpfman_api::{
+    v1::{
+        bpfman_client::BpfmanClient, DeleteMapEntryRequest, DumpMapRequest, ExportMapsRequest,
+        ListKernelMapsRequest, ListMapsRequest, LookupMapEntryRequest, PublishMapsRequest,
+        UnexportMapsRequest, UnpublishMapsRequest, UpdateMapEntryRequest, WatchPerfBufferRequest,
+        WatchRingBufferRequest,
+    },
+    DropPolicy,
+};
+use hex::FromHex;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{
+    args::{
+        DeleteMapEntryArgs, DumpMapArgs, ExportMapsArgs, KernelMapsArgs, ListMapsArgs,
+        LookupMapEntryArgs, MapsSubcommand, PublishMapsArgs, RestoreMapsArgs, SnapshotMapsArgs,
+        UnexportMapsArgs, UnpublishMapsArgs, UpdateMapEntryArgs, WatchMapArgs,
+    },
+    select_channel,
+    table::ProgTable,
+};
+
+// Flags of the bpf syscall map update command.
+const BPF_ANY: u64 = 0;
+const BPF_NOEXIST: u64 = 1;
+const BPF_EXIST: u64 = 2;
+
+// Map types that can be watched.
+const BPF_MAP_TYPE_PERF_EVENT_ARRAY: u32 = 4;
+const BPF_MAP_TYPE_RINGBUF: u32 = 27;
+
+// Map types left out of snapshots unless asked for: the values of
+// prog_array, cgroup_array, array_of_maps, hash_of_maps, devmap, sockmap,
+// cpumap, xskmap, sockhash and devmap_hash refer to kernel objects that don't
+// outlive the programs using them, stack_trace maps are filled by the kernel,
+// and perf event arrays and ring buffers can't be read as entries.
+const SNAPSHOT_SKIPPED_MAP_TYPES: [u32; 13] = [3, 4, 7, 8, 12, 13, 14, 15, 16, 17, 18, 25, 27];
+
+// A snapshot of map contents, keys and values hex encoded as the kernel lays
+// them out.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    maps: Vec<MapSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MapSnapshot {
+    name: String,
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    entries: Vec<SnapshotEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotEntry {
+    key: String,
+    value: String,
+}
+
+impl MapsSubcommand {
+    pub(crate) async fn execute(&self) -> anyhow::Result<()> {
+        match self {
+            MapsSubcommand::Publish(args) => execute_publish(args).await,
+            MapsSubcommand::Unpublish(args) => execute_unpublish(args).await,
+            MapsSubcommand::Export(args) => execute_export(args).await,
+            MapsSubcommand::Unexport(args) => execute_unexport(args).await,
+            MapsSubcommand::List(args) => execute_list(args).await,
+            MapsSubcommand::Kernel(args) => execute_kernel(args).await,
+            MapsSubcommand::Dump(args) => execute_dump(args).await,
+            MapsSubcommand::Lookup(args) => execute_lookup(args).await,
+            MapsSubcommand::Update(args) => execute_update(args).await,
+            MapsSubcommand::Delete(args) => execute_delete(args).await,
+            MapsSubcommand::Watch(args) => execute_watch(args).await,
+            MapsSubcommand::Snapshot(args) => execute_snapshot(args).await,
+            MapsSubcommand::Restore(args) => execute_restore(args).await,
+        }
+    }
+}
+
+async fn execute_publish(args: &PublishMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(PublishMapsRequest {
+        id: args.id,
+        name: args.name.clone(),
+    });
+    let _response = client.publish_maps(request).await?.into_inner();
+    Ok(())
+}
+
+async fn execute_unpublish(args: &UnpublishMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(UnpublishMapsRequest {
+        name: args.name.clone(),
+    });
+    let _response = client.unpublish_maps(request).await?.into_inner();
+    Ok(())
+}
+
+async fn execute_export(args: &ExportMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ExportMapsRequest {
+        id: args.id,
+        maps: args.maps.clone().unwrap_or_default(),
+        path: args.path.clone(),
+        mount: args.mount,
+    });
+    let response = client.export_maps(request).await?.into_inner();
+    for map in response.maps {
+        println!("{map}");
+    }
+    Ok(())
+}
+
+async fn execute_unexport(args: &UnexportMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(UnexportMapsRequest {
+        path: args.path.clone(),
+    });
+    let _response = client.unexport_maps(request).await?.into_inner();
+    Ok(())
+}
+
+async fn execute_list(args: &ListMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListMapsRequest { id: args.id });
+    let response = client.list_maps(request).await?.into_inner();
+    ProgTable::new_map_list(&response.maps).print();
+    Ok(())
+}
+
+async fn execute_kernel(args: &KernelMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListKernelMapsRequest {
+        program_id: args.program_id,
+        foreign_access_only: args.foreign_access,
+    });
+    let response = client.list_kernel_maps(request).await?.into_inner();
+    ProgTable::new_kernel_map_list(&response.maps).print();
+    Ok(())
+}
+
+async fn execute_dump(args: &DumpMapArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(DumpMapRequest {
+        id: args.id,
+        map: args.map.clone(),
+        json: args.json,
+    });
+    let response = client.dump_map(request).await?.into_inner();
+    ProgTable::new_map_entries(&response.entries).print();
+    Ok(())
+}
+
+async fn execute_lookup(args: &LookupMapEntryArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(LookupMapEntryRequest {
+        id: args.id,
+        map: args.map.clone(),
+        key: parse_hex(&args.key)?,
+        json: args.json,
+    });
+    match client.lookup_map_entry(request).await?.into_inner().entry {
+        Some(entry) => ProgTable::new_map_entries(&[entry]).print(),
+        None => bail!("key not found in map {}", args.map),
+    }
+    Ok(())
+}
+
+async fn execute_update(args: &UpdateMapEntryArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let flags = if args.no_exist {
+        BPF_NOEXIST
+    } else if args.exist {
+        BPF_EXIST
+    } else {
+        BPF_ANY
+    };
+    let request = tonic::Request::new(UpdateMapEntryRequest {
+        id: args.id,
+        map: args.map.clone(),
+        key: parse_hex(&args.key)?,
+        value: parse_hex(&args.value)?,
+        flags,
+    });
+    let _response = client.update_map_entry(request).await?.into_inner();
+    Ok(())
+}
+
+async fn execute_delete(args: &DeleteMapEntryArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(DeleteMapEntryRequest {
+        id: args.id,
+        map: args.map.clone(),
+        key: parse_hex(&args.key)?,
+    });
+    let _response = client.delete_map_entry(request).await?.into_inner();
+    Ok(())
+}
+
+async fn execute_snapshot(args: &SnapshotMapsArgs) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListMapsRequest { id: args.id });
+    let maps = client.list_maps(request).await?.into_inner().maps;
+
+    let selected = match &args.maps {
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                maps.iter()
+                    .find(|m| &m.name == name)
+                    .ok_or_else(|| anyhow!("program {} has no map {name}", args.id))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?,
+        None => maps
+            .iter()
+            .filter(|m| !SNAPSHOT_SKIPPED_MAP_TYPES.contains(&m.map_type))
+            .collect(),
+    };
+
+    let mut snapshot = Snapshot { maps: vec![] };
+    for map in selected {
+        let request = tonic::Request::new(DumpMapRequest {
+            id: args.id,
+            map: map.name.clone(),
+            json: false,
+        });
+        let entries = client.dump_map(request).await?.into_inner().entries;
+        snapshot.maps.push(MapSnapshot {
+            name: map.name.clone(),
+            map_type: map.map_type,
+            key_size: map.key_size,
+            value_size: map.value_size,
+            entries: entries
+                .iter()
+                .map(|e| SnapshotEntry {
+                    key: hex::encode(&e.key),
+                    value: hex::encode(&e.value),
+                })
+                .collect(),
+        });
+    }
+
+    let json = serde_json::to_string_pretty(&snapshot)?;
+    match &args.output {
+        Some(path) => std::fs::write(path, json)
+            .map_err(|e| anyhow!("unable to write snapshot to {path}: {e}"))?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+// Entries of the snapshot are added to the maps, replacing the values of keys
+// already in them. Maps must have the same type, key size and value size as
+// the maps they were saved from, and values of per-CPU maps must have been
+// saved on a machine with the same number of possible CPUs.
+async fn execute_restore(args: &RestoreMapsArgs) -> anyhow::Result<()> {
+    let json = std::fs::read_to_string(&args.file)
+        .map_err(|e| anyhow!("unable to read snapshot {}: {e}", args.file))?;
+    let snapshot: Snapshot =
+        serde_json::from_str(&json).map_err(|e| anyhow!("invalid snapshot {}: {e}", args.file))?;
+
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ListMapsRequest { id: args.id });
+    let maps = client.list_maps(request).await?.into_inner().maps;
+
+    if let Some(names) = &args.maps {
+        if let Some(name) = names
+            .iter()
+            .find(|n| !snapshot.maps.iter().any(|m| &m.name == *n))
+        {
+            bail!("snapshot {} has no map {name}", args.file);
+        }
+    }
+    let selected = snapshot.maps.iter().filter(|m| {
+        args.maps
+            .as_ref()
+            .map_or(true, |names| names.contains(&m.name))
+    });
+
+    // Check all maps first, so nothing is written if one of them doesn't match.
+    for saved in selected.clone() {
+        let map = maps
+            .iter()
+            .find(|m| m.name == saved.name)
+            .ok_or_else(|| anyhow!("program {} has no map {}", args.id, saved.name))?;
+        if (map.map_type, map.key_size, map.value_size)
+            != (saved.map_type, saved.key_size, saved.value_size)
+        {
+            bail!(
+                "map {} doesn't have the type, key size and value size it was saved with",
+                saved.name
+            );
+        }
+    }
+
+    for saved in selected {
+        for entry in &saved.entries {
+            let request = tonic::Request::new(UpdateMapEntryRequest {
+                id: args.id,
+                map: saved.name.clone(),
+                key: parse_hex(&entry.key)?,
+                value: parse_hex(&entry.value)?,
+                flags: BPF_ANY,
+            });
+            client.update_map_entry(request).await?;
+        }
+    }
+    Ok(())
+}
+
+// Prints each record as hex on its own line, until interrupted. Records of
+// perf event arrays are prefixed with the CPU they were written on.
+async fn execute_watch(args: &WatchMapArgs) -> anyhow::Result<()> {
+    let drop_policy = match &args.drop_policy {
+        Some(p) => match DropPolicy::try_from(p.as_str()) {
+            Ok(p) => Some(p.into()),
+            Err(e) => bail!("error parsing drop_policy {e}"),
+        },
+        None => None,
+    };
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+
+    let maps = client
+        .list_maps(tonic::Request::new(ListMapsRequest { id: args.id }))
+        .await?
+        .into_inner()
+        .maps;
+    let map_type = match maps.iter().find(|m| m.name == args.map) {
+        Some(map) => map.map_type,
+        None => bail!("program {} has no map {}", args.id, args.map),
+    };
+
+    match map_type {
+        BPF_MAP_TYPE_RINGBUF => {
+            let request = tonic::Request::new(WatchRingBufferRequest {
+                id: args.id,
+                map: args.map.clone(),
+                queue_size: args.queue_size,
+                drop_policy,
+            });
+            let mut stream = client.watch_ring_buffer(request).await?.into_inner();
+            while let Some(response) = stream.message().await? {
+                if response.dropped > 0 {
+                    eprintln!("dropped {} records", response.dropped);
+                }
+                println!("{}", hex::encode(&response.data));
+            }
+        }
+        BPF_MAP_TYPE_PERF_EVENT_ARRAY => {
+            let request = tonic::Request::new(WatchPerfBufferRequest {
+                id: args.id,
+                map: args.map.clone(),
+                page_count: args.page_count,
+                queue_size: args.queue_size,
+                drop_policy,
+            });
+            let mut stream = client.watch_perf_buffer(request).await?.into_inner();
+            while let Some(response) = stream.message().await? {
+                if response.dropped > 0 {
+                    eprintln!("dropped {} records", response.dropped);
+                }
+                if response.lost > 0 {
+                    eprintln!("lost {} samples on cpu {}", response.lost, response.cpu);
+                }
+                if !response.data.is_empty() {
+                    println!("{}: {}", response.cpu, hex::encode(&response.data));
+                }
+            }
+        }
+        _ => bail!(
+            "map {} is neither a ring buffer nor a perf event array",
+            args.map
+        ),
+    }
+    Ok(())
+}
+
+fn parse_hex(s: &str) -> anyhow::Result<Vec<u8>> {
+    Vec::<u8>::from_hex(s).map_err(|e| anyhow!("invalid hex value {s}: {e}"))
+}
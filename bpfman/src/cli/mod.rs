@@ -2,27 +2,50 @@
 // Copyright Authors of bpfman
 
 pub(crate) mod args;
+mod attach;
+mod detach;
+mod events;
+mod features;
 mod get;
 mod image;
+mod links;
 mod list;
 mod load;
+mod maps;
+mod read;
+mod replace;
+mod rollback;
+mod set_priority;
 mod system;
 mod table;
 mod unload;
-use std::fs;
+mod version;
+use std::{fs, sync::OnceLock};
 
 use args::Commands;
+use attach::execute_attach;
 use bpfman_api::{
     config::Config,
     util::directories::{CFGPATH_BPFMAN_CONFIG, RTPATH_BPFMAN_SOCKET},
 };
+use detach::execute_detach;
+use events::execute_events;
+use features::execute_features;
 use get::execute_get;
+use links::execute_links;
 use list::execute_list;
 use log::warn;
+use read::execute_read;
+use rollback::execute_rollback;
+use set_priority::execute_set_priority;
 use tokio::net::UnixStream;
 use tonic::transport::{Channel, Endpoint, Uri};
 use tower::service_fn;
 use unload::execute_unload;
+use version::execute_version;
+
+// The path of the socket of the daemon, as configured.
+static SOCKET_PATH: OnceLock<String> = OnceLock::new();
 
 impl Commands {
     pub(crate) async fn execute(&self) -> Result<(), anyhow::Error> {
@@ -35,12 +58,24 @@ impl Commands {
             warn!("Unable to read config file, using defaults");
             Config::default()
         };
+        let _ = SOCKET_PATH.set(config.grpc.socket.path.clone());
 
         match self {
             Commands::Load(l) => l.execute().await,
             Commands::Unload(args) => execute_unload(args).await,
+            Commands::Attach(args) => execute_attach(args).await,
+            Commands::Detach(args) => execute_detach(args).await,
+            Commands::SetPriority(args) => execute_set_priority(args).await,
+            Commands::Replace(r) => r.execute().await,
+            Commands::Rollback(args) => execute_rollback(args).await,
             Commands::List(args) => execute_list(args).await,
             Commands::Get(args) => execute_get(args).await,
+            Commands::Read(args) => execute_read(args).await,
+            Commands::Version => execute_version().await,
+            Commands::Events(args) => execute_events(args).await,
+            Commands::Features(args) => execute_features(args).await,
+            Commands::Links(args) => execute_links(args).await,
+            Commands::Maps(m) => m.execute().await,
             Commands::Image(i) => i.execute().await,
             Commands::System(s) => s.execute(&config).await,
         }
@@ -48,7 +83,10 @@ impl Commands {
 }
 
 fn select_channel() -> Option<Channel> {
-    let path = RTPATH_BPFMAN_SOCKET.to_string();
+    let path = SOCKET_PATH
+        .get()
+        .map_or(RTPATH_BPFMAN_SOCKET, |p| p.as_str())
+        .to_string();
 
     let address = Endpoint::try_from(format!("unix:/{path}"));
     if let Err(e) = address {
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, ReadRequest};
+use tokio::io::AsyncWriteExt;
+
+use crate::cli::{args::ReadArgs, select_channel};
+
+pub(crate) async fn execute_read(args: &ReadArgs) -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(ReadRequest { id: args.id });
+    let mut stream = client.read(request).await?.into_inner();
+
+    let mut stdout = tokio::io::stdout();
+    while let Some(response) = stream.message().await? {
+        stdout.write_all(&response.data).await?;
+    }
+    stdout.flush().await?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{
+    bpfman_client::BpfmanClient, bytecode_location::Location, BytecodeImage, BytecodeLocation,
+    ReplaceRequest,
+};
+
+use crate::cli::{
+    args::{ReplaceFileArgs, ReplaceImageArgs, ReplaceSubcommand},
+    load::{load_error, parse_global},
+    select_channel,
+    table::ProgTable,
+};
+
+impl ReplaceSubcommand {
+    pub(crate) async fn execute(&self) -> anyhow::Result<()> {
+        match self {
+            ReplaceSubcommand::File(r) => execute_replace_file(r).await,
+            ReplaceSubcommand::Image(r) => execute_replace_image(r).await,
+        }
+    }
+}
+
+async fn execute_replace_file(args: &ReplaceFileArgs) -> anyhow::Result<()> {
+    let request = ReplaceRequest {
+        id: args.id,
+        bytecode: Some(BytecodeLocation {
+            location: Some(Location::File(args.path.clone())),
+        }),
+        name: args.name.clone(),
+        global_data: parse_global(&args.global),
+    };
+    execute_replace(request).await
+}
+
+async fn execute_replace_image(args: &ReplaceImageArgs) -> anyhow::Result<()> {
+    let request = ReplaceRequest {
+        id: args.id,
+        bytecode: Some(BytecodeLocation {
+            location: Some(Location::Image(BytecodeImage::try_from(&args.pull_args)?)),
+        }),
+        name: args.name.clone(),
+        global_data: parse_global(&args.global),
+    };
+    execute_replace(request).await
+}
+
+async fn execute_replace(request: ReplaceRequest) -> anyhow::Result<()> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let response = client
+        .replace(tonic::Request::new(request))
+        .await
+        .map_err(load_error)?
+        .into_inner();
+
+    ProgTable::new_get_bpfman(&response.info)?.print();
+    ProgTable::new_get_unsupported(&response.kernel_info)?.print();
+    Ok(())
+}
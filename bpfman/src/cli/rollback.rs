@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, RollbackRequest};
+
+use crate::cli::{args::RollbackArgs, load::load_error, select_channel, table::ProgTable};
+
+pub(crate) async fn execute_rollback(args: &RollbackArgs) -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(RollbackRequest { id: args.id });
+    let response = client
+        .rollback(request)
+        .await
+        .map_err(load_error)?
+        .into_inner();
+
+    ProgTable::new_get_bpfman(&response.info)?.print();
+    ProgTable::new_get_unsupported(&response.kernel_info)?.print();
+    Ok(())
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, SetPriorityRequest};
+
+use crate::cli::{args::SetPriorityArgs, select_channel};
+
+pub(crate) async fn execute_set_priority(args: &SetPriorityArgs) -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(SetPriorityRequest {
+        id: args.id,
+        priority: args.priority,
+        iface: args.iface.clone(),
+        direction: args.direction.clone(),
+    });
+    let _response = client.set_priority(request).await?.into_inner();
+    Ok(())
+}
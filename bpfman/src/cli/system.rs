@@ -10,7 +10,7 @@ use std::{
 
 use anyhow::{bail, Context};
 use bpfman_api::config::Config;
-use log::info;
+use log::{info, LevelFilter};
 use nix::{
     libc::RLIM_INFINITY,
     sys::resource::{setrlimit, Resource},
@@ -19,6 +19,7 @@ use systemd_journal_logger::{connected_to_journal, JournalLog};
 
 use crate::{
     cli::args::{ServiceArgs, SystemSubcommand},
+    reload::set_log_level,
     serve::serve,
     utils::{create_bpffs, set_dir_permissions},
     BPFMAN_ENV_LOG_LEVEL,
@@ -41,11 +42,22 @@ pub(crate) async fn execute_service(args: &ServiceArgs, config: &Config) -> anyh
             .unwrap();
         manage_journal_log_level();
         log::info!("Log using journald");
+    } else if config.log.is_some() {
+        // env_logger drops what its own filter doesn't allow, so it lets all
+        // levels through for the configured one, which can change on reload,
+        // to apply.
+        env_logger::Builder::from_env(BPFMAN_ENV_LOG_LEVEL)
+            .filter_level(LevelFilter::Trace)
+            .init();
+        log::info!("Log using env_logger");
     } else {
         // Otherwise fall back to logging to standard error.
         env_logger::init();
         log::info!("Log using env_logger");
     }
+    if let Some(log) = &config.log {
+        set_log_level(log);
+    }
 
     has_cap(caps::CapSet::Effective, caps::Capability::CAP_BPF);
     has_cap(caps::CapSet::Effective, caps::Capability::CAP_SYS_ADMIN);
@@ -83,7 +95,11 @@ pub(crate) async fn execute_service(args: &ServiceArgs, config: &Config) -> anyh
     set_dir_permissions(STDIR, STDIR_MODE).await;
 
     //TODO https://github.com/bpfman/bpfman/issues/881
-    serve(config, args.csi_support, args.timeout).await?;
+    let mut config = config.clone();
+    if args.offline {
+        config.image_pull.offline = true;
+    }
+    serve(&config, args.csi_support, args.timeout).await?;
     Ok(())
 }
 
@@ -3,17 +3,25 @@
 
 use anyhow::bail;
 use bpfman_api::{
+    util::directories::RTPATH_BPFMAN_FD_SOCKET,
     v1::{
         attach_info::Info, bytecode_location::Location, list_response::ListResult,
-        KernelProgramInfo, KprobeAttachInfo, ProgramInfo, TcAttachInfo, TracepointAttachInfo,
-        UprobeAttachInfo, XdpAttachInfo,
+        CgroupDeviceAttachInfo, CgroupSkbAttachInfo, CgroupSockAddrAttachInfo,
+        CgroupSockoptAttachInfo, CgroupSysctlAttachInfo, DispatcherSlotStats, Event,
+        ExtensionAttachInfo, FlowDissectorAttachInfo, ImageInfo, IterAttachInfo, KernelLinkInfo,
+        KernelMapInfo, KernelProgramInfo, KprobeAttachInfo, KprobeMultiAttachInfo, LsmAttachInfo,
+        MapEntry, MapInfo, NetkitAttachInfo, PerfEventAttachInfo, ProbeFeaturesResponse,
+        ProgramInfo, RawTracepointAttachInfo, SkLookupAttachInfo, SkMsgAttachInfo, SkSkbAttachInfo,
+        SockOpsAttachInfo, SocketFilterAttachInfo, TcAttachInfo, TracepointAttachInfo,
+        UprobeAttachInfo, UprobeMultiAttachInfo, UsdtAttachInfo, VersionResponse, XdpAttachInfo,
     },
-    ImagePullPolicy,
+    CgroupAttachFlags, ImagePullPolicy,
     ProbeType::{Kprobe, Kretprobe, Uprobe, Uretprobe},
     ProgramType, TcProceedOn, XdpProceedOn,
 };
+use chrono::{Local, TimeZone, Utc};
 use comfy_table::{Cell, Color, Table};
-use hex::encode_upper;
+use hex::{encode, encode_upper};
 pub(crate) struct ProgTable(Table);
 
 impl ProgTable {
@@ -43,6 +51,14 @@ impl ProgTable {
             table.add_row(vec!["Name:", &info.name.clone()]);
         }
 
+        if let Some(uuid) = &info.uuid {
+            table.add_row(vec!["UUID:", uuid]);
+        }
+
+        if let Some(owner) = &info.owner {
+            table.add_row(vec!["Owner:", owner]);
+        }
+
         match info.bytecode.clone().unwrap().location.clone() {
             Some(l) => match l {
                 Location::Image(i) => {
@@ -52,6 +68,13 @@ impl ProgTable {
                 Location::File(p) => {
                     table.add_row(vec!["Path:", &p]);
                 }
+                Location::Data(d) => {
+                    table.add_row(vec!["Uploaded (bytes):", &d.len().to_string()]);
+                }
+                Location::Url(u) => {
+                    table.add_row(vec!["URL:", &u.url]);
+                    table.add_row(vec!["SHA256:", &u.sha256]);
+                }
             },
             // not a bpfman program
             None => {
@@ -75,6 +98,19 @@ impl ProgTable {
             }
         }
 
+        if !info.map_max_entries.is_empty() {
+            let mut first = true;
+            for (key, value) in info.map_max_entries.clone() {
+                let data = &format! {"{key}={value}"};
+                if first {
+                    first = false;
+                    table.add_row(vec!["Map Max Entries:", data]);
+                } else {
+                    table.add_row(vec!["", data]);
+                }
+            }
+        }
+
         if info.metadata.is_empty() {
             table.add_row(vec!["Metadata:", "None"]);
         } else {
@@ -115,6 +151,8 @@ impl ProgTable {
             }
         };
 
+        table.add_row(vec!["Detached:", &info.detached.to_string()]);
+
         if info.attach.is_some() {
             match info.attach.clone().unwrap().info.unwrap() {
                 Info::XdpAttachInfo(XdpAttachInfo {
@@ -122,6 +160,11 @@ impl ProgTable {
                     iface,
                     position,
                     proceed_on,
+                    track_iface_glob,
+                    wait_for_iface,
+                    netns_path,
+                    slot_stats,
+                    mode,
                 }) => {
                     let proc_on = match XdpProceedOn::from_int32s(proceed_on) {
                         Ok(p) => p,
@@ -132,6 +175,19 @@ impl ProgTable {
                     table.add_row(vec!["Iface:", &iface]);
                     table.add_row(vec!["Position:", &position.to_string()]);
                     table.add_row(vec!["Proceed On:", &format!("{proc_on}")]);
+                    if track_iface_glob {
+                        table.add_row(vec!["Track Iface Glob:", "true"]);
+                    }
+                    if wait_for_iface {
+                        table.add_row(vec!["Wait For Iface:", "true"]);
+                    }
+                    if let Some(netns_path) = netns_path {
+                        table.add_row(vec!["Network Namespace:", &netns_path]);
+                    }
+                    if let Some(mode) = mode {
+                        table.add_row(vec!["Mode:", &mode]);
+                    }
+                    add_slot_stats(&mut table, slot_stats);
                 }
                 Info::TcAttachInfo(TcAttachInfo {
                     priority,
@@ -139,6 +195,11 @@ impl ProgTable {
                     position,
                     direction,
                     proceed_on,
+                    tcx,
+                    track_iface_glob,
+                    wait_for_iface,
+                    netns_path,
+                    slot_stats,
                 }) => {
                     let proc_on = match TcProceedOn::from_int32s(proceed_on) {
                         Ok(p) => p,
@@ -150,6 +211,20 @@ impl ProgTable {
                     table.add_row(vec!["Position:", &position.to_string()]);
                     table.add_row(vec!["Direction:", &direction]);
                     table.add_row(vec!["Proceed On:", &format!("{proc_on}")]);
+                    if let Some(tcx) = tcx {
+                        let mode = if tcx { "tcx" } else { "dispatcher" };
+                        table.add_row(vec!["Attach Mode:", mode]);
+                    }
+                    if track_iface_glob {
+                        table.add_row(vec!["Track Iface Glob:", "true"]);
+                    }
+                    if wait_for_iface {
+                        table.add_row(vec!["Wait For Iface:", "true"]);
+                    }
+                    if let Some(netns_path) = netns_path {
+                        table.add_row(vec!["Network Namespace:", &netns_path]);
+                    }
+                    add_slot_stats(&mut table, slot_stats);
                 }
                 Info::TracepointAttachInfo(TracepointAttachInfo { tracepoint }) => {
                     table.add_row(vec!["Tracepoint:", &tracepoint]);
@@ -180,6 +255,7 @@ impl ProgTable {
                     retprobe,
                     pid,
                     container_pid,
+                    container_id,
                 }) => {
                     let probe_type = match retprobe {
                         true => Uretprobe,
@@ -195,7 +271,210 @@ impl ProgTable {
                         "Container PID",
                         &container_pid.unwrap_or(0).to_string(),
                     ]);
+                    if let Some(container_id) = container_id {
+                        table.add_row(vec!["Container ID:", &container_id]);
+                    }
+                }
+                Info::LsmAttachInfo(LsmAttachInfo { hook }) => {
+                    table.add_row(vec!["LSM Hook:", &hook]);
+                }
+                Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+                    cgroup_path,
+                    direction,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Direction:", &direction]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
                 }
+                Info::CgroupSockAddrAttachInfo(CgroupSockAddrAttachInfo {
+                    cgroup_path,
+                    attach_point,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Attach Point:", &attach_point]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
+                }
+                Info::SockOpsAttachInfo(SockOpsAttachInfo {
+                    cgroup_path,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
+                }
+                Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+                    cgroup_path,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
+                }
+                Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+                    cgroup_path,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
+                }
+                Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+                    cgroup_path,
+                    attach_type,
+                    attach_flags,
+                }) => {
+                    let attach_flags: CgroupAttachFlags = attach_flags.try_into()?;
+                    table.add_row(vec!["Cgroup Path:", &cgroup_path]);
+                    table.add_row(vec!["Attach Type:", &attach_type]);
+                    table.add_row(vec!["Attach Flags:", &attach_flags.to_string()]);
+                }
+                Info::SkMsgAttachInfo(SkMsgAttachInfo {
+                    map_name,
+                    sock_map_pin_path,
+                }) => {
+                    table.add_row(vec!["Map Name:", &map_name]);
+                    table.add_row(vec![
+                        "Sock Map Pin Path:",
+                        &sock_map_pin_path.unwrap_or("None".to_string()),
+                    ]);
+                }
+                Info::SkSkbAttachInfo(SkSkbAttachInfo {
+                    map_name,
+                    sock_map_pin_path,
+                    attach_type,
+                }) => {
+                    table.add_row(vec!["Map Name:", &map_name]);
+                    table.add_row(vec![
+                        "Sock Map Pin Path:",
+                        &sock_map_pin_path.unwrap_or("None".to_string()),
+                    ]);
+                    table.add_row(vec!["Attach Type:", &attach_type]);
+                }
+                Info::FlowDissectorAttachInfo(FlowDissectorAttachInfo { netns_path }) => {
+                    table.add_row(vec!["Network Namespace:", &netns_path]);
+                }
+                Info::SkLookupAttachInfo(SkLookupAttachInfo { netns_path }) => {
+                    table.add_row(vec!["Network Namespace:", &netns_path]);
+                }
+                Info::PerfEventAttachInfo(PerfEventAttachInfo {
+                    event,
+                    sample_period,
+                    sample_frequency,
+                    cpus,
+                }) => {
+                    table.add_row(vec!["Event:", &event]);
+                    if let Some(period) = sample_period {
+                        table.add_row(vec!["Sample Period:", &period.to_string()]);
+                    }
+                    if let Some(frequency) = sample_frequency {
+                        table.add_row(vec!["Sample Frequency:", &frequency.to_string()]);
+                    }
+                    let cpus = if cpus.is_empty() {
+                        "all".to_string()
+                    } else {
+                        cpus.iter()
+                            .map(|c| c.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    };
+                    table.add_row(vec!["CPUs:", &cpus]);
+                }
+                Info::RawTracepointAttachInfo(RawTracepointAttachInfo { tracepoint, btf }) => {
+                    table.add_row(vec!["Tracepoint:", &tracepoint]);
+                    table.add_row(vec!["BTF:", &btf.to_string()]);
+                }
+                Info::SocketFilterAttachInfo(SocketFilterAttachInfo {}) => {
+                    table.add_row(vec!["Fd Socket:", RTPATH_BPFMAN_FD_SOCKET]);
+                }
+                Info::NetkitAttachInfo(NetkitAttachInfo {
+                    iface,
+                    position,
+                    priority,
+                }) => {
+                    table.add_row(vec!["Iface:", &iface]);
+                    table.add_row(vec!["Position:", &position]);
+                    table.add_row(vec!["Priority:", &priority.to_string()]);
+                }
+                Info::UsdtAttachInfo(UsdtAttachInfo {
+                    target,
+                    provider,
+                    name,
+                    pid,
+                }) => {
+                    table.add_row(vec!["Target:", &target]);
+                    table.add_row(vec!["Provider:", &provider]);
+                    table.add_row(vec!["Probe:", &name]);
+                    table.add_row(vec!["PID", &pid.unwrap_or(0).to_string()]);
+                }
+                Info::KprobeMultiAttachInfo(KprobeMultiAttachInfo { fn_names, retprobe }) => {
+                    let probe_type = match retprobe {
+                        true => Kretprobe,
+                        false => Kprobe,
+                    };
+                    table.add_row(vec!["Probe Type:", &format!["{probe_type}"]]);
+                    table.add_row(vec!["Functions:", &fn_names.join(", ")]);
+                }
+                Info::UprobeMultiAttachInfo(UprobeMultiAttachInfo {
+                    target,
+                    fn_names,
+                    retprobe,
+                    pid,
+                }) => {
+                    let probe_type = match retprobe {
+                        true => Uretprobe,
+                        false => Uprobe,
+                    };
+                    table.add_row(vec!["Probe Type:", &format!["{probe_type}"]]);
+                    table.add_row(vec!["Target:", &target]);
+                    table.add_row(vec!["Functions:", &fn_names.join(", ")]);
+                    table.add_row(vec!["PID", &pid.unwrap_or(0).to_string()]);
+                }
+                Info::IterAttachInfo(IterAttachInfo { iter_type }) => {
+                    table.add_row(vec!["Iterator Type:", &iter_type]);
+                }
+                Info::ExtensionAttachInfo(ExtensionAttachInfo {
+                    target_path,
+                    target_id,
+                    target_fn,
+                }) => {
+                    if let Some(target_path) = target_path {
+                        table.add_row(vec!["Target Path:", &target_path]);
+                    }
+                    if let Some(target_id) = target_id {
+                        table.add_row(vec!["Target ID:", &target_id.to_string()]);
+                    }
+                    table.add_row(vec!["Target Function:", &target_fn]);
+                }
+            }
+        }
+
+        let mut first = true;
+        for attachment in info.attachments.iter().filter_map(|a| a.info.clone()) {
+            let data = match attachment {
+                Info::XdpAttachInfo(XdpAttachInfo {
+                    priority,
+                    iface,
+                    position,
+                    ..
+                }) => format!("{iface} (priority {priority}, position {position})"),
+                Info::TcAttachInfo(TcAttachInfo {
+                    priority,
+                    iface,
+                    position,
+                    direction,
+                    ..
+                }) => format!("{iface} {direction} (priority {priority}, position {position})"),
+                _ => continue,
+            };
+            if first {
+                first = false;
+                table.add_row(vec!["Also Attached To:", &data]);
+            } else {
+                table.add_row(vec!["", &data]);
             }
         }
 
@@ -257,47 +536,358 @@ impl ProgTable {
                 "Verified Instruction Count:".to_string(),
                 kernel_info.verified_insns.to_string(),
             ],
+            vec!["Run Count:".to_string(), kernel_info.run_count.to_string()],
+            vec![
+                "Run Time (ns):".to_string(),
+                kernel_info.run_time_ns.to_string(),
+            ],
+            vec![
+                "Average Run Time (ns):".to_string(),
+                kernel_info
+                    .run_time_ns
+                    .checked_div(kernel_info.run_count)
+                    .unwrap_or_default()
+                    .to_string(),
+            ],
         ];
         table.add_rows(rows);
 
         Ok(ProgTable(table))
     }
 
-    pub(crate) fn new_list() -> Self {
+    // The kernel info of the programs is listed too if kernel_info is set, and
+    // their runtime statistics if stats is.
+    pub(crate) fn new_list(managed: bool, kernel_info: bool, stats: bool) -> Self {
         let mut table = Table::new();
 
         table.load_preset(comfy_table::presets::NOTHING);
-        table.set_header(vec!["Program ID", "Name", "Type", "Load Time"]);
+        let mut header = vec!["Program ID", "Name", "Type", "Load Time"];
+        if managed {
+            header.push("Managed");
+        }
+        if kernel_info {
+            header.extend([
+                "Tag",
+                "Verified Insns",
+                "Xlated (bytes)",
+                "JITed (bytes)",
+                "BTF ID",
+                "Memlock (bytes)",
+            ]);
+        }
+        if stats {
+            header.extend(["Run Count", "Run Time (ns)", "Avg Run Time (ns)"]);
+        }
+        table.set_header(header);
         ProgTable(table)
     }
 
-    pub(crate) fn add_row_list(
+    pub(crate) fn add_response_prog(
         &mut self,
-        id: String,
-        name: String,
-        type_: String,
-        load_time: String,
-    ) {
-        self.0.add_row(vec![id, name, type_, load_time]);
-    }
-
-    pub(crate) fn add_response_prog(&mut self, r: ListResult) -> anyhow::Result<()> {
+        r: ListResult,
+        managed: bool,
+        kernel_info_columns: bool,
+        stats: bool,
+    ) -> anyhow::Result<()> {
         if r.kernel_info.is_none() {
             self.0.add_row(vec!["NONE"]);
             return Ok(());
         }
         let kernel_info = r.kernel_info.unwrap();
 
-        self.add_row_list(
+        let mut row = vec![
             kernel_info.id.to_string(),
-            kernel_info.name,
+            kernel_info.name.clone(),
             (ProgramType::try_from(kernel_info.program_type)?).to_string(),
-            kernel_info.loaded_at,
-        );
+            kernel_info.loaded_at.clone(),
+        ];
+        if managed {
+            row.push(if r.bpfman_managed { "yes" } else { "no" }.to_string());
+        }
+        if kernel_info_columns {
+            row.extend([
+                kernel_info.tag.clone(),
+                kernel_info.verified_insns.to_string(),
+                kernel_info.bytes_xlated.to_string(),
+                kernel_info.bytes_jited.to_string(),
+                kernel_info.btf_id.to_string(),
+                kernel_info.bytes_memlock.to_string(),
+            ]);
+        }
+        if stats {
+            row.extend([
+                kernel_info.run_count.to_string(),
+                kernel_info.run_time_ns.to_string(),
+                kernel_info
+                    .run_time_ns
+                    .checked_div(kernel_info.run_count)
+                    .unwrap_or_default()
+                    .to_string(),
+            ]);
+        }
+        self.0.add_row(row);
 
         Ok(())
     }
 
+    pub(crate) fn new_map_list(maps: &[MapInfo]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Name",
+            "Map ID",
+            "Type",
+            "Key Size",
+            "Value Size",
+            "Max Entries",
+        ]);
+        for map in maps {
+            table.add_row(vec![
+                map.name.clone(),
+                map.id.to_string(),
+                map.map_type.to_string(),
+                map.key_size.to_string(),
+                map.value_size.to_string(),
+                map.max_entries.to_string(),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_link_list(links: &[KernelLinkInfo]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Link ID", "Type", "Program ID", "Managed"]);
+        for link in links {
+            table.add_row(vec![
+                link.id.to_string(),
+                link.link_type.to_string(),
+                link.program_id.to_string(),
+                if link.bpfman_managed { "yes" } else { "no" }.to_string(),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    // Programs loaded by bpfman are marked with a "*".
+    pub(crate) fn new_kernel_map_list(maps: &[KernelMapInfo]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Map ID",
+            "Name",
+            "Type",
+            "Key Size",
+            "Value Size",
+            "Max Entries",
+            "Used By",
+        ]);
+        for map in maps {
+            let used_by = map
+                .program_ids
+                .iter()
+                .map(|id| {
+                    if map.bpfman_program_ids.contains(id) {
+                        format!("{id}*")
+                    } else {
+                        id.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            table.add_row(vec![
+                map.id.to_string(),
+                map.name.clone(),
+                map.map_type.to_string(),
+                map.key_size.to_string(),
+                map.value_size.to_string(),
+                map.max_entries.to_string(),
+                used_by,
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_event_list(events: &[Event]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![
+            "Time",
+            "Event",
+            "Program ID",
+            "Name",
+            "Details",
+            "Error",
+        ]);
+        for event in events {
+            let time = Utc
+                .timestamp_millis_opt(event.timestamp as i64)
+                .single()
+                .map(|t| {
+                    t.with_timezone(&Local)
+                        .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            table.add_row(vec![
+                time,
+                event.kind.clone(),
+                event
+                    .program_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                event.name.clone().unwrap_or_default(),
+                event.details.clone(),
+                event.error.clone().unwrap_or_default(),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_image_list(images: &[ImageInfo]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Image URL", "Digest", "Size", "Type", "Programs"]);
+        for image in images {
+            table.add_row(vec![
+                image.url.clone(),
+                short_digest(&image.digest),
+                image.size.to_string(),
+                image
+                    .labels
+                    .get("io.ebpf.program_type")
+                    .cloned()
+                    .unwrap_or_default(),
+                program_ids(&image.program_ids),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_image_info(image: &ImageInfo) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![Cell::new("Image")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .add_attribute(comfy_table::Attribute::Underlined)
+            .fg(Color::Green)]);
+        let last_used = Utc
+            .timestamp_opt(image.last_used as i64, 0)
+            .single()
+            .map(|t| {
+                t.with_timezone(&Local)
+                    .format("%Y-%m-%dT%H:%M:%S%z")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        table.add_row(vec!["URL:", &image.url]);
+        table.add_row(vec![
+            "Digest:",
+            if image.digest.is_empty() {
+                "None"
+            } else {
+                &image.digest
+            },
+        ]);
+        table.add_row(vec!["Size (bytes):", &image.size.to_string()]);
+        table.add_row(vec!["Last Used:", &last_used]);
+        table.add_row(vec!["Programs:", &program_ids(&image.program_ids)]);
+
+        let mut labels: Vec<_> = image.labels.iter().collect();
+        labels.sort();
+        if labels.is_empty() {
+            table.add_row(vec!["Labels:", "None"]);
+        }
+        for (i, (key, value)) in labels.into_iter().enumerate() {
+            table.add_row(vec![
+                if i == 0 { "Labels:" } else { "" },
+                &format!("{key}={value}"),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    // Keys and values are printed as JSON when the daemon could format them,
+    // as hex otherwise.
+    pub(crate) fn new_map_entries(entries: &[MapEntry]) -> Self {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec!["Key", "Value"]);
+        for entry in entries {
+            table.add_row(vec![
+                entry.key_json.clone().unwrap_or_else(|| encode(&entry.key)),
+                entry
+                    .value_json
+                    .clone()
+                    .unwrap_or_else(|| encode(&entry.value)),
+            ]);
+        }
+        ProgTable(table)
+    }
+
+    pub(crate) fn new_version(version: &VersionResponse) -> Result<Self, anyhow::Error> {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![Cell::new("bpfman")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .add_attribute(comfy_table::Attribute::Underlined)
+            .fg(Color::Green)]);
+        let program_types = version
+            .program_types
+            .iter()
+            .map(|t| ProgramType::try_from(*t).map(|t| t.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        table.add_row(vec!["Version:", &version.version]);
+        table.add_row(vec!["Build:", &version.build]);
+        table.add_row(vec!["Kernel Release:", &version.kernel_release]);
+        table.add_row(vec![
+            "Kernel Features:",
+            &version.kernel_features.join(", "),
+        ]);
+        table.add_row(vec!["Program Types:", &program_types.join(", ")]);
+        table.add_row(vec!["XDP Dispatcher:", &version.xdp_dispatcher_image]);
+        table.add_row(vec!["TC Dispatcher:", &version.tc_dispatcher_image]);
+        Ok(ProgTable(table))
+    }
+
+    pub(crate) fn new_features(features: &ProbeFeaturesResponse) -> Result<Self, anyhow::Error> {
+        let mut table = Table::new();
+
+        table.load_preset(comfy_table::presets::NOTHING);
+        table.set_header(vec![Cell::new("Kernel Features")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .add_attribute(comfy_table::Attribute::Underlined)
+            .fg(Color::Green)]);
+        let program_types = features
+            .program_types
+            .iter()
+            .map(|t| ProgramType::try_from(*t).map(|t| t.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        table.add_row(vec!["Kernel Release:", &features.kernel_release]);
+        table.add_row(vec!["Program Types:", &program_types.join(", ")]);
+        table.add_row(vec!["Map Types:", &features.map_types.join(", ")]);
+        table.add_row(vec!["Features:", &features.features.join(", ")]);
+        for helpers in &features.helpers {
+            let ids: Vec<String> = helpers.helper_ids.iter().map(|id| id.to_string()).collect();
+            table.add_row(vec![
+                format!(
+                    "Helpers ({}):",
+                    ProgramType::try_from(helpers.program_type)?
+                ),
+                ids.join(", "),
+            ]);
+        }
+        Ok(ProgTable(table))
+    }
+
     pub(crate) fn print(&self) {
         println!("{self}\n")
     }
@@ -308,3 +898,38 @@ impl std::fmt::Display for ProgTable {
         write!(f, "{}", self.0)
     }
 }
+
+// Digests are shortened to the first 12 hex digits, like container tools do.
+fn short_digest(digest: &str) -> String {
+    match digest.split_once(':') {
+        Some((_, hex)) => hex.chars().take(12).collect(),
+        None => digest.to_string(),
+    }
+}
+
+// Verdicts are listed by name, as "drop: 3, pass: 1200".
+fn add_slot_stats(table: &mut Table, stats: Option<DispatcherSlotStats>) {
+    if let Some(stats) = stats {
+        let mut verdicts: Vec<_> = stats.verdicts.into_iter().collect();
+        verdicts.sort();
+        let verdicts = verdicts
+            .iter()
+            .map(|(name, count)| format!("{name}: {count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.add_row(vec!["Packets:", &stats.packets.to_string()]);
+        if !verdicts.is_empty() {
+            table.add_row(vec!["Verdicts:", &verdicts]);
+        }
+    }
+}
+
+fn program_ids(ids: &[u32]) -> String {
+    if ids.is_empty() {
+        return "None".to_string();
+    }
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
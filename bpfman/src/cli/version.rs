@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use bpfman_api::v1::{bpfman_client::BpfmanClient, VersionRequest};
+
+use crate::cli::{select_channel, table::ProgTable};
+
+pub(crate) async fn execute_version() -> Result<(), anyhow::Error> {
+    let channel = select_channel().expect("failed to select channel");
+    let mut client = BpfmanClient::new(channel);
+    let request = tonic::Request::new(VersionRequest {});
+    let response = client.version(request).await?.into_inner();
+
+    ProgTable::new_version(&response)?.print();
+    Ok(())
+}
@@ -3,7 +3,7 @@
 
 //! Commands between the RPC thread and the BPF thread
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt, fs,
     path::{Path, PathBuf},
     time::SystemTime,
@@ -11,46 +11,204 @@ use std::{
 
 use aya::programs::ProgramInfo as AyaProgInfo;
 use bpfman_api::{
-    util::directories::RTDIR_FS,
+    config::{Config, XdpMode},
+    dispatcher_stats::{stats_path, DispatcherKind},
+    util::directories::{RTDIR_FS, RTDIR_FS_TC_EGRESS, RTDIR_FS_TC_INGRESS, RTDIR_FS_XDP},
     v1::{
         attach_info::Info, bytecode_location::Location as V1Location, AttachInfo, BytecodeLocation,
-        KernelProgramInfo as V1KernelProgramInfo, KprobeAttachInfo, ProgramInfo as V1ProgramInfo,
-        TcAttachInfo, TracepointAttachInfo, UprobeAttachInfo, XdpAttachInfo,
+        CgroupDeviceAttachInfo, CgroupSkbAttachInfo, CgroupSockAddrAttachInfo,
+        CgroupSockoptAttachInfo, CgroupSysctlAttachInfo, DispatcherSlotStats, ExtensionAttachInfo,
+        FlowDissectorAttachInfo, IterAttachInfo, KernelProgramInfo as V1KernelProgramInfo,
+        KprobeAttachInfo, KprobeMultiAttachInfo, LsmAttachInfo, NetkitAttachInfo,
+        PerfEventAttachInfo, ProgramInfo as V1ProgramInfo, RawTracepointAttachInfo,
+        SkLookupAttachInfo, SkMsgAttachInfo, SkSkbAttachInfo, SockOpsAttachInfo,
+        SocketFilterAttachInfo, TcAttachInfo, TracepointAttachInfo, UprobeAttachInfo,
+        UprobeMultiAttachInfo, UsdtAttachInfo, XdpAttachInfo,
     },
-    ParseError, ProgramType, TcProceedOn, TcProceedOnEntry, XdpProceedOn, XdpProceedOnEntry,
+    CgroupAttachFlags, ParseError, ProgramType, TcProceedOn, TcProceedOnEntry, XdpProceedOn,
+    XdpProceedOnEntry,
 };
 use chrono::{prelude::DateTime, Local};
 use log::info;
+use opentelemetry::Context;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{mpsc::Sender, oneshot};
+use tokio::sync::{
+    mpsc::{self, error::SendError, Receiver, Sender},
+    oneshot,
+};
 
 use crate::{
     errors::BpfmanError,
-    multiprog::{DispatcherId, DispatcherInfo},
-    oci_utils::image_manager::{BytecodeImage, Command as ImageManagerCommand},
+    multiprog::{dispatcher_key, slot_stats, DispatcherId, DispatcherInfo},
+    oci_utils::{
+        image_manager::{
+            BytecodeImage, BytecodeUrl, Command as ImageManagerCommand, ContainerImageMetadata,
+            ImageInfo,
+        },
+        layout::{is_layout_location, read_layout_bytecode},
+        ImageError,
+    },
+    stats::run_stats,
     utils::{
         bytes_to_bool, bytes_to_i32, bytes_to_string, bytes_to_u32, bytes_to_u64, bytes_to_usize,
-        sled_get, sled_get_option, sled_insert,
+        glob_match, should_map_be_pinned, sled_get, sled_get_option, sled_insert, store_upload,
     },
     ROOT_DB,
 };
 
+// Which maps of its bytecode a program pins when it owns its maps, and the
+// names they are pinned under in its map pin path.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MapPinPolicy {
+    pub(crate) include: Vec<String>,
+    pub(crate) exclude: Vec<String>,
+    pub(crate) names: HashMap<String, String>,
+}
+
+impl MapPinPolicy {
+    // Returns the name the map is pinned under, or None if it isn't pinned.
+    // Maps matching an exclude pattern aren't pinned. If include patterns are
+    // given only maps matching one of them are pinned, otherwise all maps but
+    // the ones holding global data are.
+    pub(crate) fn pin_name<'a>(&'a self, map: &'a str) -> Option<&'a str> {
+        let matches = |p: &String| glob_match(p.as_bytes(), map.as_bytes());
+        if self.exclude.iter().any(matches) {
+            return None;
+        }
+        let included = if self.include.is_empty() {
+            should_map_be_pinned(map)
+        } else {
+            self.include.iter().any(matches)
+        };
+        included.then(|| self.names.get(map).map_or(map, |n| n.as_str()))
+    }
+}
+
+// Entries written into a map of the bytecode right after it's pinned, and
+// whether the map is then frozen, making it read-only to userspace.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct MapInit {
+    pub(crate) map: String,
+    pub(crate) entries: Vec<(Vec<u8>, Vec<u8>)>,
+    pub(crate) freeze: bool,
+}
+
+// Inner maps created for the keys of an array or hash of maps of the bytecode,
+// all with the same definition, when the program owning its maps is loaded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct InnerMaps {
+    pub(crate) outer: String,
+    pub(crate) map_type: u32,
+    pub(crate) key_size: u32,
+    pub(crate) value_size: u32,
+    pub(crate) max_entries: u32,
+    pub(crate) keys: Vec<Vec<u8>>,
+}
+
+// The owner, group and mode given to the maps a program pins and to its map
+// pin path, instead of the defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct MapPermissions {
+    pub(crate) uid: Option<u32>,
+    pub(crate) gid: Option<u32>,
+    pub(crate) mode: Option<u32>,
+}
+
 /// Provided by the requester and used by the manager task to send
 /// the command response back to the requester.
 type Responder<T> = oneshot::Sender<T>;
 
+/// Sends commands to the manager task along with the trace context they are
+/// sent in, so that the manager traces them as part of the request.
+#[derive(Debug, Clone)]
+pub(crate) struct CommandSender(Sender<(Command, Context)>);
+
+impl CommandSender {
+    pub(crate) async fn send(&self, command: Command) -> Result<(), SendError<(Command, Context)>> {
+        self.0.send((command, Context::current())).await
+    }
+}
+
+pub(crate) fn command_channel(buffer: usize) -> (CommandSender, Receiver<(Command, Context)>) {
+    let (tx, rx) = mpsc::channel(buffer);
+    (CommandSender(tx), rx)
+}
+
 /// Multiple different commands are multiplexed over a single channel.
 #[derive(Debug)]
 pub(crate) enum Command {
     /// Load a program
     Load(LoadArgs),
+    /// Load programs with a single dispatcher rebuild
+    LoadBatch(LoadBatchArgs),
+    /// Load functions of the same object as programs that share maps
+    LoadObject(LoadObjectArgs),
     Unload(UnloadArgs),
-    List {
-        responder: Responder<Result<Vec<Program>, BpfmanError>>,
-    },
+    Attach(AttachArgs),
+    Detach(DetachArgs),
+    SetPriority(SetPriorityArgs),
+    /// Publish the maps of a program under a name
+    PublishMaps(PublishMapsArgs),
+    UnpublishMaps(UnpublishMapsArgs),
+    /// Expose the maps of a program in a directory of a consumer
+    ExportMaps(ExportMapsArgs),
+    UnexportMaps(UnexportMapsArgs),
+    /// Replace a program with new bytecode in its place
+    Replace(ReplaceArgs),
+    /// Replace a program with the program it replaced
+    Rollback(RollbackArgs),
+    List(ListArgs),
     Get(GetArgs),
     PullBytecode(PullBytecodeArgs),
+    /// Remove the images no program uses from the image store
+    PruneImages(PruneImagesArgs),
+    /// List the images in the image store
+    ListImages(ListImagesArgs),
+    Read(ReadArgs),
+    Version(VersionArgs),
+    LinkEvent(LinkEvent),
+    /// Apply the reloadable settings of the configuration file
+    ReloadConfig(Box<Config>),
+}
+
+impl Command {
+    // The name of the span the command is traced in.
+    pub(crate) fn span_name(&self) -> &'static str {
+        match self {
+            Command::Load(_) => "load",
+            Command::LoadBatch(_) => "load batch",
+            Command::LoadObject(_) => "load object",
+            Command::Unload(_) => "unload",
+            Command::Attach(_) => "attach",
+            Command::Detach(_) => "detach",
+            Command::SetPriority(_) => "set priority",
+            Command::PublishMaps(_) => "publish maps",
+            Command::UnpublishMaps(_) => "unpublish maps",
+            Command::ExportMaps(_) => "export maps",
+            Command::UnexportMaps(_) => "unexport maps",
+            Command::Replace(_) => "replace",
+            Command::Rollback(_) => "rollback",
+            Command::List(_) => "list",
+            Command::Get(_) => "get",
+            Command::PullBytecode(_) => "pull bytecode",
+            Command::PruneImages(_) => "prune images",
+            Command::ListImages(_) => "list images",
+            Command::Read(_) => "read",
+            Command::Version(_) => "version",
+            Command::LinkEvent(_) => "link event",
+            Command::ReloadConfig(_) => "reload config",
+        }
+    }
+}
+
+/// A change to a network interface, reported by the link monitor.
+#[derive(Debug)]
+pub(crate) enum LinkEvent {
+    /// The interface was created or changed, for instance renamed.
+    Changed { if_index: u32, iface: String },
+    /// The interface was removed.
+    Removed { if_index: u32, iface: String },
 }
 
 #[derive(Debug)]
@@ -59,6 +217,18 @@ pub(crate) struct LoadArgs {
     pub(crate) responder: Responder<Result<Program, BpfmanError>>,
 }
 
+#[derive(Debug)]
+pub(crate) struct LoadBatchArgs {
+    pub(crate) programs: Vec<Program>,
+    pub(crate) responder: Responder<Result<Vec<Program>, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct LoadObjectArgs {
+    pub(crate) programs: Vec<Program>,
+    pub(crate) responder: Responder<Result<Vec<Program>, BpfmanError>>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum Program {
     Xdp(XdpProgram),
@@ -66,6 +236,26 @@ pub(crate) enum Program {
     Tracepoint(TracepointProgram),
     Kprobe(KprobeProgram),
     Uprobe(UprobeProgram),
+    Lsm(LsmProgram),
+    CgroupSkb(CgroupSkbProgram),
+    CgroupSockAddr(CgroupSockAddrProgram),
+    SockOps(SockOpsProgram),
+    CgroupDevice(CgroupDeviceProgram),
+    CgroupSysctl(CgroupSysctlProgram),
+    CgroupSockopt(CgroupSockoptProgram),
+    SkMsg(SkMsgProgram),
+    SkSkb(SkSkbProgram),
+    FlowDissector(FlowDissectorProgram),
+    SkLookup(SkLookupProgram),
+    PerfEvent(PerfEventProgram),
+    RawTracepoint(RawTracepointProgram),
+    SocketFilter(SocketFilterProgram),
+    Netkit(NetkitProgram),
+    Usdt(UsdtProgram),
+    KprobeMulti(KprobeMultiProgram),
+    UprobeMulti(UprobeMultiProgram),
+    Iter(IterProgram),
+    Extension(ExtensionProgram),
     Unsupported(ProgramData),
 }
 
@@ -75,31 +265,239 @@ pub(crate) struct UnloadArgs {
     pub(crate) responder: Responder<Result<(), BpfmanError>>,
 }
 
+#[derive(Debug)]
+pub(crate) struct AttachArgs {
+    pub(crate) id: u32,
+    pub(crate) attachment: Option<Program>,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct DetachArgs {
+    pub(crate) id: u32,
+    pub(crate) iface: Option<String>,
+    pub(crate) direction: Option<Direction>,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct SetPriorityArgs {
+    pub(crate) id: u32,
+    pub(crate) priority: i32,
+    pub(crate) iface: Option<String>,
+    pub(crate) direction: Option<Direction>,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PublishMapsArgs {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnpublishMapsArgs {
+    pub(crate) name: String,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ExportMapsArgs {
+    pub(crate) id: u32,
+    pub(crate) maps: Vec<String>,
+    pub(crate) path: PathBuf,
+    pub(crate) mount: bool,
+    pub(crate) responder: Responder<Result<Vec<String>, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnexportMapsArgs {
+    pub(crate) path: PathBuf,
+    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ReplaceArgs {
+    pub(crate) id: u32,
+    pub(crate) data: ProgramData,
+    pub(crate) responder: Responder<Result<Program, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct RollbackArgs {
+    pub(crate) id: u32,
+    pub(crate) responder: Responder<Result<Program, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ListArgs {
+    pub(crate) filter: ListFilter,
+    // Number of programs per page, all of them if 0.
+    pub(crate) page_size: usize,
+    pub(crate) page_token: Option<String>,
+    pub(crate) responder: Responder<Result<ProgramPage, BpfmanError>>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ListFilter {
+    pub(crate) program_type: Option<u32>,
+    pub(crate) iface: Option<String>,
+    pub(crate) map_owner_id: Option<u32>,
+    pub(crate) metadata: HashMap<String, String>,
+    pub(crate) bpfman_programs_only: bool,
+    pub(crate) foreign_programs_only: bool,
+}
+
+impl ListFilter {
+    // Programs not loaded by bpfman only match filters on their type. Programs
+    // match the map owner filter if they own the maps or share them.
+    pub(crate) fn matches(&self, program: &Program) -> Result<bool, BpfmanError> {
+        if self
+            .program_type
+            .is_some_and(|t| t != program.kind() as u32)
+        {
+            return Ok(false);
+        }
+        if let Program::Unsupported(_) = program {
+            return Ok(!self.bpfman_programs_only
+                && self.iface.is_none()
+                && self.map_owner_id.is_none()
+                && self.metadata.is_empty());
+        }
+        if self.foreign_programs_only {
+            return Ok(false);
+        }
+
+        let data = program.get_data();
+        if let Some(iface) = &self.iface {
+            let on_iface = program.if_name().is_ok_and(|i| i == *iface)
+                || program
+                    .attachments()?
+                    .iter()
+                    .any(|a| a.if_name().is_ok_and(|i| i == *iface));
+            if !on_iface {
+                return Ok(false);
+            }
+        }
+        if let Some(owner) = self.map_owner_id {
+            if data.get_map_owner_id()?.unwrap_or(data.get_id()?) != owner {
+                return Ok(false);
+            }
+        }
+        let metadata = data.get_metadata()?;
+        Ok(self
+            .metadata
+            .iter()
+            .all(|(key, value)| metadata.get(key) == Some(value)))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ProgramPage {
+    pub(crate) programs: Vec<Program>,
+    pub(crate) next_page_token: Option<String>,
+}
+
 #[derive(Debug)]
 pub(crate) struct GetArgs {
     pub(crate) id: u32,
+    pub(crate) uuid: Option<String>,
     pub(crate) responder: Responder<Result<Program, BpfmanError>>,
 }
 
+#[derive(Debug)]
+pub(crate) struct ReadArgs {
+    pub(crate) id: u32,
+    pub(crate) responder: Responder<Result<Vec<u8>, BpfmanError>>,
+}
+
 #[derive(Debug)]
 pub(crate) struct PullBytecodeArgs {
     pub(crate) image: BytecodeImage,
-    pub(crate) responder: Responder<Result<(), BpfmanError>>,
+    pub(crate) responder: Responder<Result<ImageInfo, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PruneImagesArgs {
+    pub(crate) responder: Responder<Result<Vec<String>, BpfmanError>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ListImagesArgs {
+    pub(crate) responder: Responder<Result<Vec<ImageInfo>, BpfmanError>>,
 }
 
+#[derive(Debug)]
+pub(crate) struct VersionArgs {
+    pub(crate) responder: Responder<Capabilities>,
+}
+
+// What the running kernel supports and the dispatcher images bpfman uses.
+#[derive(Debug)]
+pub(crate) struct Capabilities {
+    pub(crate) kernel_release: String,
+    pub(crate) kernel_features: Vec<String>,
+    pub(crate) xdp_dispatcher_image: String,
+    pub(crate) tc_dispatcher_image: String,
+}
+
+// Program types bpfman can load. struct_ops and netfilter programs need loader
+// support aya lacks.
+pub(crate) const SUPPORTED_PROGRAM_TYPES: &[ProgramType] = &[
+    ProgramType::SocketFilter,
+    ProgramType::Probe,
+    ProgramType::Tc,
+    ProgramType::Tracepoint,
+    ProgramType::Xdp,
+    ProgramType::PerfEvent,
+    ProgramType::CgroupSkb,
+    ProgramType::SockOps,
+    ProgramType::SkSkb,
+    ProgramType::CgroupDevice,
+    ProgramType::SkMsg,
+    ProgramType::RawTracepoint,
+    ProgramType::CgroupSockAddr,
+    ProgramType::FlowDissector,
+    ProgramType::CgroupSysctl,
+    ProgramType::CgroupSockopt,
+    ProgramType::Tracing,
+    ProgramType::Ext,
+    ProgramType::Lsm,
+    ProgramType::SkLookup,
+];
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub(crate) enum Location {
     Image(BytecodeImage),
     File(String),
+    Url(BytecodeUrl),
 }
 
 impl Location {
     async fn get_program_bytes(
         &self,
         image_manager: Sender<ImageManagerCommand>,
-    ) -> Result<(Vec<u8>, String), BpfmanError> {
+    ) -> Result<(Vec<u8>, ContainerImageMetadata), BpfmanError> {
         match self {
-            Location::File(l) => Ok((crate::utils::read(l).await?, "".to_owned())),
+            Location::File(l) if is_layout_location(l) => {
+                Ok(read_layout_bytecode(l).map_err(BpfmanError::BpfBytecodeError)?)
+            }
+            Location::File(l) => {
+                let (tx, rx) = oneshot::channel();
+                image_manager
+                    .send(ImageManagerCommand::ReadFile {
+                        path: l.clone(),
+                        resp: tx,
+                    })
+                    .await
+                    .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+                let bytecode = rx
+                    .await
+                    .map_err(BpfmanError::RpcRecvError)?
+                    .map_err(BpfmanError::BpfBytecodeError)?;
+                Ok((bytecode, ContainerImageMetadata::default()))
+            }
             Location::Image(l) => {
                 let (tx, rx) = oneshot::channel();
                 image_manager
@@ -108,11 +506,12 @@ impl Location {
                         pull_policy: l.image_pull_policy.clone(),
                         username: l.username.clone(),
                         password: l.password.clone(),
+                        context: Context::current(),
                         resp: tx,
                     })
                     .await
                     .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
-                let (path, bpf_function_name) = rx
+                let (path, metadata) = rx
                     .await
                     .map_err(BpfmanError::RpcRecvError)?
                     .map_err(BpfmanError::BpfBytecodeError)?;
@@ -128,7 +527,23 @@ impl Location {
                     .map_err(BpfmanError::RpcRecvError)?
                     .map_err(BpfmanError::BpfBytecodeError)?;
 
-                Ok((bytecode, bpf_function_name))
+                Ok((bytecode, metadata))
+            }
+            Location::Url(l) => {
+                let (tx, rx) = oneshot::channel();
+                image_manager
+                    .send(ImageManagerCommand::Fetch {
+                        url: l.url.clone(),
+                        sha256: l.sha256.clone(),
+                        resp: tx,
+                    })
+                    .await
+                    .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+                let bytecode = rx
+                    .await
+                    .map_err(BpfmanError::RpcRecvError)?
+                    .map_err(BpfmanError::BpfBytecodeError)?;
+                Ok((bytecode, ContainerImageMetadata::default()))
             }
         }
     }
@@ -177,6 +592,219 @@ impl std::fmt::Display for Direction {
     }
 }
 
+/// The socket operation a cgroup/sock_addr program is attached to.
+#[derive(Debug, Serialize, Hash, Deserialize, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum SockAddrAttachPoint {
+    Bind4,
+    Bind6,
+    Connect4,
+    Connect6,
+    GetPeerName4,
+    GetPeerName6,
+    GetSockName4,
+    GetSockName6,
+    SendMsg4,
+    SendMsg6,
+    RecvMsg4,
+    RecvMsg6,
+}
+
+impl TryFrom<String> for SockAddrAttachPoint {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        match v.as_str() {
+            "bind4" => Ok(Self::Bind4),
+            "bind6" => Ok(Self::Bind6),
+            "connect4" => Ok(Self::Connect4),
+            "connect6" => Ok(Self::Connect6),
+            "getpeername4" => Ok(Self::GetPeerName4),
+            "getpeername6" => Ok(Self::GetPeerName6),
+            "getsockname4" => Ok(Self::GetSockName4),
+            "getsockname6" => Ok(Self::GetSockName6),
+            "sendmsg4" => Ok(Self::SendMsg4),
+            "sendmsg6" => Ok(Self::SendMsg6),
+            "recvmsg4" => Ok(Self::RecvMsg4),
+            "recvmsg6" => Ok(Self::RecvMsg6),
+            m => Err(ParseError::InvalidAttachPoint {
+                attach_point: m.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for SockAddrAttachPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SockAddrAttachPoint::Bind4 => f.write_str("bind4"),
+            SockAddrAttachPoint::Bind6 => f.write_str("bind6"),
+            SockAddrAttachPoint::Connect4 => f.write_str("connect4"),
+            SockAddrAttachPoint::Connect6 => f.write_str("connect6"),
+            SockAddrAttachPoint::GetPeerName4 => f.write_str("getpeername4"),
+            SockAddrAttachPoint::GetPeerName6 => f.write_str("getpeername6"),
+            SockAddrAttachPoint::GetSockName4 => f.write_str("getsockname4"),
+            SockAddrAttachPoint::GetSockName6 => f.write_str("getsockname6"),
+            SockAddrAttachPoint::SendMsg4 => f.write_str("sendmsg4"),
+            SockAddrAttachPoint::SendMsg6 => f.write_str("sendmsg6"),
+            SockAddrAttachPoint::RecvMsg4 => f.write_str("recvmsg4"),
+            SockAddrAttachPoint::RecvMsg6 => f.write_str("recvmsg6"),
+        }
+    }
+}
+
+/// The socket option call a cgroup sockopt program is attached to.
+#[derive(Debug, Serialize, Hash, Deserialize, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum SockoptAttachType {
+    Get,
+    Set,
+}
+
+impl TryFrom<String> for SockoptAttachType {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        match v.as_str() {
+            "getsockopt" => Ok(Self::Get),
+            "setsockopt" => Ok(Self::Set),
+            m => Err(ParseError::InvalidAttachPoint {
+                attach_point: m.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for SockoptAttachType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SockoptAttachType::Get => f.write_str("getsockopt"),
+            SockoptAttachType::Set => f.write_str("setsockopt"),
+        }
+    }
+}
+
+/// The side of a netkit device pair a program is attached to.
+#[derive(Debug, Serialize, Hash, Deserialize, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum NetkitPosition {
+    Primary,
+    Peer,
+}
+
+impl TryFrom<String> for NetkitPosition {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        match v.as_str() {
+            "primary" => Ok(Self::Primary),
+            "peer" => Ok(Self::Peer),
+            m => Err(ParseError::InvalidAttachPoint {
+                attach_point: m.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for NetkitPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetkitPosition::Primary => f.write_str("primary"),
+            NetkitPosition::Peer => f.write_str("peer"),
+        }
+    }
+}
+
+/// The role an sk_skb program plays on a sockmap.
+#[derive(Debug, Serialize, Hash, Deserialize, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum SkSkbAttachType {
+    StreamParser,
+    StreamVerdict,
+}
+
+impl TryFrom<String> for SkSkbAttachType {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        match v.as_str() {
+            "stream_parser" => Ok(Self::StreamParser),
+            "stream_verdict" => Ok(Self::StreamVerdict),
+            m => Err(ParseError::InvalidAttachPoint {
+                attach_point: m.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for SkSkbAttachType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkSkbAttachType::StreamParser => f.write_str("stream_parser"),
+            SkSkbAttachType::StreamVerdict => f.write_str("stream_verdict"),
+        }
+    }
+}
+
+/// The perf event a perf_event program samples on.
+#[derive(Debug, Serialize, Hash, Deserialize, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum PerfEventType {
+    CpuClock,
+    TaskClock,
+    PageFaults,
+    ContextSwitches,
+    CpuMigrations,
+    CpuCycles,
+    Instructions,
+    CacheReferences,
+    CacheMisses,
+    BranchInstructions,
+    BranchMisses,
+    BusCycles,
+    RefCycles,
+}
+
+impl TryFrom<String> for PerfEventType {
+    type Error = ParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        match v.as_str() {
+            "cpu-clock" => Ok(Self::CpuClock),
+            "task-clock" => Ok(Self::TaskClock),
+            "page-faults" => Ok(Self::PageFaults),
+            "context-switches" => Ok(Self::ContextSwitches),
+            "cpu-migrations" => Ok(Self::CpuMigrations),
+            "cpu-cycles" => Ok(Self::CpuCycles),
+            "instructions" => Ok(Self::Instructions),
+            "cache-references" => Ok(Self::CacheReferences),
+            "cache-misses" => Ok(Self::CacheMisses),
+            "branch-instructions" => Ok(Self::BranchInstructions),
+            "branch-misses" => Ok(Self::BranchMisses),
+            "bus-cycles" => Ok(Self::BusCycles),
+            "ref-cycles" => Ok(Self::RefCycles),
+            m => Err(ParseError::InvalidAttachPoint {
+                attach_point: m.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for PerfEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerfEventType::CpuClock => f.write_str("cpu-clock"),
+            PerfEventType::TaskClock => f.write_str("task-clock"),
+            PerfEventType::PageFaults => f.write_str("page-faults"),
+            PerfEventType::ContextSwitches => f.write_str("context-switches"),
+            PerfEventType::CpuMigrations => f.write_str("cpu-migrations"),
+            PerfEventType::CpuCycles => f.write_str("cpu-cycles"),
+            PerfEventType::Instructions => f.write_str("instructions"),
+            PerfEventType::CacheReferences => f.write_str("cache-references"),
+            PerfEventType::CacheMisses => f.write_str("cache-misses"),
+            PerfEventType::BranchInstructions => f.write_str("branch-instructions"),
+            PerfEventType::BranchMisses => f.write_str("branch-misses"),
+            PerfEventType::BusCycles => f.write_str("bus-cycles"),
+            PerfEventType::RefCycles => f.write_str("ref-cycles"),
+        }
+    }
+}
+
 impl TryFrom<&Program> for V1ProgramInfo {
     type Error = BpfmanError;
 
@@ -198,50 +826,21 @@ impl TryFrom<&Program> for V1ProgramInfo {
             crate::command::Location::File(m) => Some(BytecodeLocation {
                 location: Some(V1Location::File(m.to_string())),
             }),
-        };
-
-        let attach_info = AttachInfo {
-            info: match program.clone() {
-                Program::Xdp(p) => Some(Info::XdpAttachInfo(XdpAttachInfo {
-                    priority: p.get_priority()?,
-                    iface: p.get_iface()?.to_string(),
-                    position: p.get_current_position()?.unwrap_or(0) as i32,
-                    proceed_on: p.get_proceed_on()?.as_action_vec(),
-                })),
-                Program::Tc(p) => Some(Info::TcAttachInfo(TcAttachInfo {
-                    priority: p.get_priority()?,
-                    iface: p.get_iface()?.to_string(),
-                    position: p.get_current_position()?.unwrap_or(0) as i32,
-                    direction: p.get_direction()?.to_string(),
-                    proceed_on: p.get_proceed_on()?.as_action_vec(),
-                })),
-                Program::Tracepoint(p) => Some(Info::TracepointAttachInfo(TracepointAttachInfo {
-                    tracepoint: p.get_tracepoint()?.to_string(),
-                })),
-                Program::Kprobe(p) => Some(Info::KprobeAttachInfo(KprobeAttachInfo {
-                    fn_name: p.get_fn_name()?.to_string(),
-                    offset: p.get_offset()?,
-                    retprobe: p.get_retprobe()?,
-                    container_pid: p.get_container_pid()?,
-                })),
-                Program::Uprobe(p) => Some(Info::UprobeAttachInfo(UprobeAttachInfo {
-                    fn_name: p.get_fn_name()?.map(|v| v.to_string()),
-                    offset: p.get_offset()?,
-                    target: p.get_target()?.to_string(),
-                    retprobe: p.get_retprobe()?,
-                    pid: p.get_pid()?,
-                    container_pid: p.get_container_pid()?,
-                })),
-                Program::Unsupported(_) => None,
-            },
+            crate::command::Location::Url(m) => Some(BytecodeLocation {
+                location: Some(V1Location::Url(bpfman_api::v1::BytecodeUrl {
+                    url: m.url,
+                    sha256: m.sha256,
+                })),
+            }),
         };
 
         // Populate the Program Info with bpfman data
         Ok(V1ProgramInfo {
             name: data.get_name()?.to_string(),
             bytecode,
-            attach: Some(attach_info),
+            attach: Some(program.try_into()?),
             global_data: data.get_global_data()?,
+            map_max_entries: data.get_map_max_entries()?,
             map_owner_id: data.get_map_owner_id()?,
             map_pin_path: data
                 .get_map_pin_path()?
@@ -252,6 +851,173 @@ impl TryFrom<&Program> for V1ProgramInfo {
                 .map(|m| m.to_string())
                 .collect(),
             metadata: data.get_metadata()?,
+            detached: data.get_detached()?,
+            uuid: data.get_uuid()?,
+            owner: data.get_owner()?,
+            attachments: program
+                .attachments()?
+                .iter()
+                .map(AttachInfo::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&Program> for AttachInfo {
+    type Error = BpfmanError;
+
+    fn try_from(program: &Program) -> Result<Self, Self::Error> {
+        Ok(AttachInfo {
+            info: match program.clone() {
+                Program::Xdp(p) => Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    priority: p.get_priority()?,
+                    iface: p.get_iface()?.to_string(),
+                    position: p.get_current_position()?.unwrap_or(0) as i32,
+                    proceed_on: p.get_proceed_on()?.as_action_vec(),
+                    track_iface_glob: p.get_track_iface_glob()?,
+                    wait_for_iface: p.get_wait_for_iface()?,
+                    netns_path: p.get_netns()?.map(|n| n.to_string_lossy().to_string()),
+                    slot_stats: p.slot_stats()?,
+                    mode: match p.get_attached()? {
+                        true => p.get_mode()?.map(|m| m.to_string()),
+                        false => None,
+                    },
+                })),
+                Program::Tc(p) => Some(Info::TcAttachInfo(TcAttachInfo {
+                    priority: p.get_priority()?,
+                    iface: p.get_iface()?.to_string(),
+                    position: p.get_current_position()?.unwrap_or(0) as i32,
+                    direction: p.get_direction()?.to_string(),
+                    proceed_on: p.get_proceed_on()?.as_action_vec(),
+                    tcx: Some(p.get_tcx()?.unwrap_or(false)),
+                    track_iface_glob: p.get_track_iface_glob()?,
+                    wait_for_iface: p.get_wait_for_iface()?,
+                    netns_path: p.get_netns()?.map(|n| n.to_string_lossy().to_string()),
+                    slot_stats: p.slot_stats()?,
+                })),
+                Program::Tracepoint(p) => Some(Info::TracepointAttachInfo(TracepointAttachInfo {
+                    tracepoint: p.get_tracepoint()?.to_string(),
+                })),
+                Program::Kprobe(p) => Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: p.get_fn_name()?.to_string(),
+                    offset: p.get_offset()?,
+                    retprobe: p.get_retprobe()?,
+                    container_pid: p.get_container_pid()?,
+                })),
+                Program::Uprobe(p) => Some(Info::UprobeAttachInfo(UprobeAttachInfo {
+                    fn_name: p.get_fn_name()?.map(|v| v.to_string()),
+                    offset: p.get_offset()?,
+                    target: p.get_target()?.to_string(),
+                    retprobe: p.get_retprobe()?,
+                    pid: p.get_pid()?,
+                    container_pid: p.get_container_pid()?,
+                    container_id: p.get_container_id()?,
+                })),
+                Program::Lsm(p) => Some(Info::LsmAttachInfo(LsmAttachInfo {
+                    hook: p.get_hook()?,
+                })),
+                Program::CgroupSkb(p) => Some(Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+                    cgroup_path: p.get_cgroup_path()?,
+                    direction: p.get_direction()?.to_string(),
+                    attach_flags: p.get_attach_flags()?.into(),
+                })),
+                Program::CgroupSockAddr(p) => {
+                    Some(Info::CgroupSockAddrAttachInfo(CgroupSockAddrAttachInfo {
+                        cgroup_path: p.get_cgroup_path()?,
+                        attach_point: p.get_attach_point()?.to_string(),
+                        attach_flags: p.get_attach_flags()?.into(),
+                    }))
+                }
+                Program::SockOps(p) => Some(Info::SockOpsAttachInfo(SockOpsAttachInfo {
+                    cgroup_path: p.get_cgroup_path()?,
+                    attach_flags: p.get_attach_flags()?.into(),
+                })),
+                Program::CgroupDevice(p) => {
+                    Some(Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+                        cgroup_path: p.get_cgroup_path()?,
+                        attach_flags: p.get_attach_flags()?.into(),
+                    }))
+                }
+                Program::CgroupSysctl(p) => {
+                    Some(Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+                        cgroup_path: p.get_cgroup_path()?,
+                        attach_flags: p.get_attach_flags()?.into(),
+                    }))
+                }
+                Program::CgroupSockopt(p) => {
+                    Some(Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+                        cgroup_path: p.get_cgroup_path()?,
+                        attach_type: p.get_attach_type()?.to_string(),
+                        attach_flags: p.get_attach_flags()?.into(),
+                    }))
+                }
+                Program::SkMsg(p) => Some(Info::SkMsgAttachInfo(SkMsgAttachInfo {
+                    map_name: p.get_map_name()?,
+                    sock_map_pin_path: p.get_sock_map_pin_path()?,
+                })),
+                Program::SkSkb(p) => Some(Info::SkSkbAttachInfo(SkSkbAttachInfo {
+                    map_name: p.get_map_name()?,
+                    sock_map_pin_path: p.get_sock_map_pin_path()?,
+                    attach_type: p.get_attach_type()?.to_string(),
+                })),
+                Program::FlowDissector(p) => {
+                    Some(Info::FlowDissectorAttachInfo(FlowDissectorAttachInfo {
+                        netns_path: p.get_netns_path()?,
+                    }))
+                }
+                Program::SkLookup(p) => Some(Info::SkLookupAttachInfo(SkLookupAttachInfo {
+                    netns_path: p.get_netns_path()?,
+                })),
+                Program::PerfEvent(p) => Some(Info::PerfEventAttachInfo(PerfEventAttachInfo {
+                    event: p.get_event()?.to_string(),
+                    sample_period: p.get_sample_period()?,
+                    sample_frequency: p.get_sample_frequency()?,
+                    cpus: p.get_cpus()?,
+                })),
+                Program::RawTracepoint(p) => {
+                    Some(Info::RawTracepointAttachInfo(RawTracepointAttachInfo {
+                        tracepoint: p.get_tracepoint()?,
+                        btf: p.get_btf()?,
+                    }))
+                }
+                Program::SocketFilter(_) => {
+                    Some(Info::SocketFilterAttachInfo(SocketFilterAttachInfo {}))
+                }
+                Program::Netkit(p) => Some(Info::NetkitAttachInfo(NetkitAttachInfo {
+                    iface: p.get_iface()?,
+                    position: p.get_position()?.to_string(),
+                    priority: p.get_priority()?,
+                })),
+                Program::Usdt(p) => Some(Info::UsdtAttachInfo(UsdtAttachInfo {
+                    target: p.get_target()?,
+                    provider: p.get_provider()?,
+                    name: p.get_name()?,
+                    pid: p.get_pid()?,
+                })),
+                Program::KprobeMulti(p) => {
+                    Some(Info::KprobeMultiAttachInfo(KprobeMultiAttachInfo {
+                        fn_names: p.get_fn_names()?,
+                        retprobe: p.get_retprobe()?,
+                    }))
+                }
+                Program::UprobeMulti(p) => {
+                    Some(Info::UprobeMultiAttachInfo(UprobeMultiAttachInfo {
+                        target: p.get_target()?,
+                        fn_names: p.get_fn_names()?,
+                        retprobe: p.get_retprobe()?,
+                        pid: p.get_pid()?,
+                    }))
+                }
+                Program::Extension(p) => Some(Info::ExtensionAttachInfo(ExtensionAttachInfo {
+                    target_path: p.get_target_path()?,
+                    target_id: p.get_target_id()?,
+                    target_fn: p.get_target_fn()?,
+                })),
+                Program::Iter(p) => Some(Info::IterAttachInfo(IterAttachInfo {
+                    iter_type: p.get_iter_type()?,
+                })),
+                Program::Unsupported(_) => None,
+            },
         })
     }
 }
@@ -262,6 +1028,7 @@ impl TryFrom<&Program> for V1KernelProgramInfo {
     fn try_from(program: &Program) -> Result<Self, Self::Error> {
         // Get the Kernel Info.
         let data: &ProgramData = program.get_data();
+        let stats = run_stats(data.get_id()?).unwrap_or_default();
 
         // Populate the Kernel Info.
         Ok(V1KernelProgramInfo {
@@ -278,6 +1045,8 @@ impl TryFrom<&Program> for V1KernelProgramInfo {
             bytes_jited: data.get_kernel_bytes_jited()?,
             bytes_memlock: data.get_kernel_bytes_memlock()?,
             verified_insns: data.get_kernel_verified_insns()?,
+            run_time_ns: stats.run_time_ns,
+            run_count: stats.run_count,
         })
     }
 }
@@ -338,9 +1107,41 @@ impl ProgramData {
         Ok(pd)
     }
 
+    // Attachments of a program to further interfaces are created without any
+    // program data, the daemon fills in what's shared with the program.
+    pub(crate) fn new_pre_attach() -> Self {
+        let mut rng = rand::thread_rng();
+        let id_rand = rng.gen::<u32>();
+
+        let db_tree = ROOT_DB
+            .open_tree(id_rand.to_string())
+            .expect("Unable to open program database tree");
+
+        Self {
+            db_tree,
+            id: id_rand,
+            program_bytes: Vec::new(),
+        }
+    }
+
     pub(crate) fn swap_tree(&mut self, new_id: u32) -> Result<(), BpfmanError> {
+        self.swap_tree_named(&new_id.to_string(), new_id)
+    }
+
+    // Moves an attachment of program id to the tree it's persisted in, see
+    // attachment_tree_name.
+    pub(crate) fn swap_attachment_tree(
+        &mut self,
+        id: u32,
+        if_index: u32,
+        direction: Option<Direction>,
+    ) -> Result<(), BpfmanError> {
+        self.swap_tree_named(&attachment_tree_name(id, if_index, direction), id)
+    }
+
+    fn swap_tree_named(&mut self, name: &str, new_id: u32) -> Result<(), BpfmanError> {
         let new_tree = ROOT_DB
-            .open_tree(new_id.to_string())
+            .open_tree(name)
             .expect("Unable to open program database tree");
 
         // Copy over all key's and values to new tree
@@ -407,6 +1208,10 @@ impl ProgramData {
     pub(crate) fn set_location(&mut self, loc: Location) -> Result<(), BpfmanError> {
         match loc {
             Location::File(l) => sled_insert(&self.db_tree, "location_filename", l.as_bytes()),
+            Location::Url(l) => {
+                sled_insert(&self.db_tree, "location_url", l.url.as_bytes())?;
+                sled_insert(&self.db_tree, "location_url_sha256", l.sha256.as_bytes())
+            }
             Location::Image(l) => {
                 sled_insert(&self.db_tree, "location_image_url", l.image_url.as_bytes())?;
                 sled_insert(
@@ -438,6 +1243,12 @@ impl ProgramData {
     pub(crate) fn get_location(&self) -> Result<Location, BpfmanError> {
         if let Ok(l) = sled_get(&self.db_tree, "location_filename") {
             Ok(Location::File(bytes_to_string(&l).to_string()))
+        } else if let Ok(l) = sled_get(&self.db_tree, "location_url") {
+            Ok(Location::Url(BytecodeUrl {
+                url: bytes_to_string(&l).to_string(),
+                sha256: bytes_to_string(&sled_get(&self.db_tree, "location_url_sha256")?)
+                    .to_string(),
+            }))
         } else {
             Ok(Location::Image(BytecodeImage {
                 image_url: bytes_to_string(&sled_get(&self.db_tree, "location_image_url")?)
@@ -491,6 +1302,274 @@ impl ProgramData {
             .collect()
     }
 
+    // Overrides the max_entries of maps of the bytecode when the program is
+    // loaded, keyed by map name.
+    pub(crate) fn set_map_max_entries(
+        &mut self,
+        max_entries: HashMap<String, u32>,
+    ) -> Result<(), BpfmanError> {
+        max_entries.iter().try_for_each(|(k, v)| {
+            sled_insert(
+                &self.db_tree,
+                format!("map_max_entries_{k}").as_str(),
+                &v.to_ne_bytes(),
+            )
+        })
+    }
+
+    pub(crate) fn get_map_max_entries(&self) -> Result<HashMap<String, u32>, BpfmanError> {
+        self.db_tree
+            .scan_prefix("map_max_entries_")
+            .map(|n| {
+                n.map(|(k, v)| {
+                    (
+                        bytes_to_string(&k)
+                            .strip_prefix("map_max_entries_")
+                            .unwrap()
+                            .to_string(),
+                        bytes_to_u32(v.to_vec()),
+                    )
+                })
+            })
+            .map(|n| {
+                n.map_err(|e| {
+                    BpfmanError::DatabaseError(
+                        "Failed to get map max entries".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    // Adds to the map pin policy of the program, see MapPinPolicy.
+    pub(crate) fn set_map_pin_policy(&mut self, policy: &MapPinPolicy) -> Result<(), BpfmanError> {
+        for pattern in &policy.include {
+            sled_insert(&self.db_tree, &format!("map_pin_include_{pattern}"), &[])?;
+        }
+        for pattern in &policy.exclude {
+            sled_insert(&self.db_tree, &format!("map_pin_exclude_{pattern}"), &[])?;
+        }
+        for (map, name) in &policy.names {
+            sled_insert(
+                &self.db_tree,
+                &format!("map_pin_name_{map}"),
+                name.as_bytes(),
+            )?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_map_pin_policy(&self) -> Result<MapPinPolicy, BpfmanError> {
+        let mut policy = MapPinPolicy::default();
+        for entry in self.db_tree.scan_prefix("map_pin_") {
+            let (key, value) = entry.map_err(|e| {
+                BpfmanError::DatabaseError(
+                    "Failed to get map pin policy".to_string(),
+                    e.to_string(),
+                )
+            })?;
+            let key = bytes_to_string(&key);
+            if let Some(pattern) = key.strip_prefix("map_pin_include_") {
+                policy.include.push(pattern.to_string());
+            } else if let Some(pattern) = key.strip_prefix("map_pin_exclude_") {
+                policy.exclude.push(pattern.to_string());
+            } else if let Some(map) = key.strip_prefix("map_pin_name_") {
+                policy
+                    .names
+                    .insert(map.to_string(), bytes_to_string(&value));
+            }
+        }
+        Ok(policy)
+    }
+
+    // Map names can't contain a '/', which separates them from the hex encoded
+    // keys of their initial entries.
+    pub(crate) fn set_map_init(&mut self, init: &[MapInit]) -> Result<(), BpfmanError> {
+        for map in init {
+            for (key, value) in &map.entries {
+                sled_insert(
+                    &self.db_tree,
+                    &format!("map_init_{}/{}", map.map, hex::encode(key)),
+                    value,
+                )?;
+            }
+            if map.freeze {
+                sled_insert(&self.db_tree, &format!("map_freeze_{}", map.map), &[])?;
+            }
+        }
+        Ok(())
+    }
+
+    // Returns the initial entries of the maps of the program, sorted by map
+    // name.
+    pub(crate) fn get_map_init(&self) -> Result<Vec<MapInit>, BpfmanError> {
+        let mut init: BTreeMap<String, MapInit> = BTreeMap::new();
+        for entry in self.db_tree.scan_prefix("map_") {
+            let (key, value) = entry.map_err(|e| {
+                BpfmanError::DatabaseError(
+                    "Failed to get map initial entries".to_string(),
+                    e.to_string(),
+                )
+            })?;
+            let key = bytes_to_string(&key);
+            if let Some((map, entry_key)) = key
+                .strip_prefix("map_init_")
+                .and_then(|k| k.split_once('/'))
+            {
+                let entry_key = hex::decode(entry_key).map_err(|e| {
+                    BpfmanError::Error(format!("invalid initial key of map {map}: {e}"))
+                })?;
+                init.entry(map.to_string())
+                    .or_insert_with(|| MapInit {
+                        map: map.to_string(),
+                        ..Default::default()
+                    })
+                    .entries
+                    .push((entry_key, value.to_vec()));
+            } else if let Some(map) = key.strip_prefix("map_freeze_") {
+                init.entry(map.to_string())
+                    .or_insert_with(|| MapInit {
+                        map: map.to_string(),
+                        ..Default::default()
+                    })
+                    .freeze = true;
+            }
+        }
+        Ok(init.into_values().collect())
+    }
+
+    pub(crate) fn set_inner_maps(&mut self, inner: &[InnerMaps]) -> Result<(), BpfmanError> {
+        for maps in inner {
+            let def: Vec<u8> = [
+                maps.map_type,
+                maps.key_size,
+                maps.value_size,
+                maps.max_entries,
+            ]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+            sled_insert(
+                &self.db_tree,
+                &format!("inner_map_def_{}", maps.outer),
+                &def,
+            )?;
+            for key in &maps.keys {
+                sled_insert(
+                    &self.db_tree,
+                    &format!("inner_map_key_{}/{}", maps.outer, hex::encode(key)),
+                    &[],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    // Returns the inner maps of the program, sorted by outer map name.
+    pub(crate) fn get_inner_maps(&self) -> Result<Vec<InnerMaps>, BpfmanError> {
+        let mut inner: BTreeMap<String, InnerMaps> = BTreeMap::new();
+        for entry in self.db_tree.scan_prefix("inner_map_") {
+            let (key, value) = entry.map_err(|e| {
+                BpfmanError::DatabaseError("Failed to get inner maps".to_string(), e.to_string())
+            })?;
+            let key = bytes_to_string(&key);
+            if let Some(outer) = key.strip_prefix("inner_map_def_") {
+                let def: Vec<u32> = value
+                    .chunks_exact(4)
+                    .map(|c| bytes_to_u32(c.to_vec()))
+                    .collect();
+                if def.len() != 4 {
+                    return Err(BpfmanError::Error(format!(
+                        "invalid inner map definition of map {outer}"
+                    )));
+                }
+                let maps = inner.entry(outer.to_string()).or_default();
+                maps.outer = outer.to_string();
+                maps.map_type = def[0];
+                maps.key_size = def[1];
+                maps.value_size = def[2];
+                maps.max_entries = def[3];
+            } else if let Some((outer, outer_key)) = key
+                .strip_prefix("inner_map_key_")
+                .and_then(|k| k.split_once('/'))
+            {
+                let outer_key = hex::decode(outer_key)
+                    .map_err(|e| BpfmanError::Error(format!("invalid key of map {outer}: {e}")))?;
+                inner
+                    .entry(outer.to_string())
+                    .or_default()
+                    .keys
+                    .push(outer_key);
+            }
+        }
+        Ok(inner.into_values().collect())
+    }
+
+    pub(crate) fn set_map_permissions(
+        &mut self,
+        permissions: &MapPermissions,
+    ) -> Result<(), BpfmanError> {
+        for (key, value) in [
+            ("map_perm_uid", permissions.uid),
+            ("map_perm_gid", permissions.gid),
+            ("map_perm_mode", permissions.mode),
+        ] {
+            if let Some(value) = value {
+                sled_insert(&self.db_tree, key, &value.to_ne_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_map_permissions(&self) -> Result<MapPermissions, BpfmanError> {
+        let get = |key| sled_get_option(&self.db_tree, key).map(|v| v.map(bytes_to_u32));
+        Ok(MapPermissions {
+            uid: get("map_perm_uid")?,
+            gid: get("map_perm_gid")?,
+            mode: get("map_perm_mode")?,
+        })
+    }
+
+    // Keeps the name, bytecode location, global data and map sizes of the
+    // program this program replaced, so it can be loaded again by a rollback. The previous
+    // program's own previous program isn't kept.
+    pub(crate) fn set_previous(&mut self, previous: &ProgramData) -> Result<(), BpfmanError> {
+        for (key, value) in previous.db_tree.iter().filter_map(|e| e.ok()) {
+            let key = bytes_to_string(&key);
+            if key == "name"
+                || key.starts_with("location_")
+                || key.starts_with("global_data_")
+                || key.starts_with("map_max_entries_")
+            {
+                sled_insert(&self.db_tree, &format!("previous_{key}"), &value)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Returns new program data with the bytecode of the program this program
+    // replaced, see set_previous(), or None if it didn't replace a program.
+    pub(crate) fn get_previous(&self) -> Result<Option<ProgramData>, BpfmanError> {
+        let mut previous: Option<ProgramData> = None;
+        for entry in self.db_tree.scan_prefix("previous_") {
+            let (key, value) = entry.map_err(|e| {
+                BpfmanError::DatabaseError(
+                    "Failed to get previous program".to_string(),
+                    e.to_string(),
+                )
+            })?;
+            let key = bytes_to_string(&key);
+            let data = previous.get_or_insert_with(ProgramData::new_pre_attach);
+            sled_insert(
+                &data.db_tree,
+                key.strip_prefix("previous_").unwrap(),
+                &value,
+            )?;
+        }
+        Ok(previous)
+    }
+
     pub(crate) fn set_metadata(
         &mut self,
         data: HashMap<String, String>,
@@ -534,6 +1613,79 @@ impl ProgramData {
         sled_get_option(&self.db_tree, "map_owner_id").map(|v| v.map(bytes_to_u32))
     }
 
+    // The name of the published maps the program was loaded with, its map
+    // owner is set from it when it's loaded.
+    pub(crate) fn set_map_from(&mut self, name: &str) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "map_from", name.as_bytes())
+    }
+
+    pub(crate) fn get_map_from(&self) -> Result<Option<String>, BpfmanError> {
+        sled_get_option(&self.db_tree, "map_from").map(|v| v.map(|v| bytes_to_string(&v)))
+    }
+
+    // Programs loaded without being attached, or detached afterwards, stay
+    // pinned with no links until they are attached again.
+    // The log_level the program is loaded with, and how much of the end of
+    // the verifier log is returned if the verifier rejects it.
+    pub(crate) fn set_verifier_log(
+        &mut self,
+        level: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<(), BpfmanError> {
+        for (key, value) in [("verifier_log_level", level), ("verifier_log_size", size)] {
+            if let Some(value) = value {
+                sled_insert(&self.db_tree, key, &value.to_ne_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_verifier_log_level(&self) -> Result<Option<u32>, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "verifier_log_level")?.map(bytes_to_u32))
+    }
+
+    pub(crate) fn get_verifier_log_size(&self) -> Result<Option<u32>, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "verifier_log_size")?.map(bytes_to_u32))
+    }
+
+    pub(crate) fn set_detached(&mut self, detached: bool) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "detached", &(detached as i8).to_ne_bytes())
+    }
+
+    pub(crate) fn get_detached(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "detached")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_uuid(&mut self, uuid: &str) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "uuid", uuid.as_bytes())
+    }
+
+    pub(crate) fn get_uuid(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "uuid")?.map(|v| bytes_to_string(&v)))
+    }
+
+    pub(crate) fn set_owner(&mut self, owner: &str) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "owner", owner.as_bytes())
+    }
+
+    pub(crate) fn get_owner(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "owner")?.map(|v| bytes_to_string(&v)))
+    }
+
+    // The approximate memory of the maps the program owns, counted against
+    // the quota of its owner.
+    pub(crate) fn set_map_bytes(&mut self, bytes: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "map_bytes", &bytes.to_ne_bytes())
+    }
+
+    pub(crate) fn get_map_bytes(&self) -> Result<u64, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "map_bytes")?
+            .map(bytes_to_u64)
+            .unwrap_or_default())
+    }
+
     pub(crate) fn set_map_pin_path(&mut self, path: &Path) -> Result<(), BpfmanError> {
         sled_insert(
             &self.db_tree,
@@ -793,37 +1945,75 @@ impl ProgramData {
         self.program_bytes = Vec::new();
     }
 
+    // Bytecode uploaded with a load is kept until set_program_bytes stores it
+    // at the location of the program.
+    pub(crate) fn set_uploaded_bytes(&mut self, bytes: Vec<u8>) {
+        self.program_bytes = bytes;
+    }
+
     pub(crate) async fn set_program_bytes(
         &mut self,
         image_manager: Sender<ImageManagerCommand>,
     ) -> Result<(), BpfmanError> {
         let loc = self.get_location()?;
+        // Uploaded bytecode is read back like any file, so that it's subject
+        // to the same checks.
+        if let Location::File(path) = &loc {
+            if !self.program_bytes.is_empty() {
+                store_upload(path, &std::mem::take(&mut self.program_bytes))?;
+            }
+        }
         match loc.get_program_bytes(image_manager).await {
             Err(e) => Err(e),
-            Ok((v, s)) => {
-                match loc {
+            Ok((v, metadata)) => {
+                let from_image = match loc {
                     Location::Image(l) => {
                         info!(
                             "Loading program bytecode from container image: {}",
                             l.get_url()
                         );
-                        // If program name isn't provided and we're loading from a container
-                        // image use the program name provided in the image metadata, otherwise
-                        // always use the provided program name.
-                        let provided_name = self.get_name()?.clone();
-
-                        if provided_name.is_empty() {
-                            self.set_name(&s)?;
-                        } else if s != provided_name {
-                            return Err(BpfmanError::BytecodeMetaDataMismatch {
-                                image_prog_name: s,
-                                provided_prog_name: provided_name.to_string(),
-                            });
-                        }
+                        true
+                    }
+                    Location::File(l) if is_layout_location(&l) => {
+                        info!("Loading program bytecode from OCI image layout: {}", l);
+                        true
                     }
                     Location::File(l) => {
                         info!("Loading program bytecode from file: {}", l);
+                        false
                     }
+                    Location::Url(l) => {
+                        info!("Loading program bytecode from URL: {}", l.url);
+                        false
+                    }
+                };
+                if from_image {
+                    // Images may hold several programs, of which the one with
+                    // the provided name is loaded. If no name is provided, the
+                    // image must hold a single program, which is loaded.
+                    let provided_name = self.get_name()?.clone();
+                    let program = metadata
+                        .select_program(&provided_name)
+                        .map_err(|e| match e {
+                            // Images following version 1 of the spec hold
+                            // a single program of another name.
+                            ImageError::ProgramNotInImage(..) if metadata.programs.is_empty() => {
+                                BpfmanError::BytecodeMetaDataMismatch {
+                                    image_prog_name: metadata.bpf_function_name.clone(),
+                                    provided_prog_name: provided_name.clone(),
+                                }
+                            }
+                            e => BpfmanError::BpfBytecodeError(e),
+                        })?;
+                    self.set_name(&program.bpf_function_name)?;
+
+                    // The global data of the image is used for the variables
+                    // the load doesn't set.
+                    let mut global_data = program
+                        .global_data()
+                        .map_err(BpfmanError::BpfBytecodeError)?;
+                    global_data.extend(self.get_global_data()?);
+                    self.set_global_data(global_data)?;
                 }
                 self.program_bytes = v;
                 Ok(())
@@ -870,18 +2060,107 @@ impl XdpProgram {
         sled_get(&self.data.db_tree, "xdp_iface").map(|v| bytes_to_string(&v))
     }
 
-    pub(crate) fn set_proceed_on(&mut self, proceed_on: XdpProceedOn) -> Result<(), BpfmanError> {
-        proceed_on
-            .as_action_vec()
-            .iter()
-            .enumerate()
-            .try_for_each(|(i, v)| {
-                sled_insert(
-                    &self.data.db_tree,
-                    format!("xdp_proceed_on_{i}").as_str(),
-                    &v.to_ne_bytes(),
-                )
-            })
+    pub(crate) fn set_iface_glob(&mut self, glob: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "xdp_iface_glob", glob.as_bytes())
+    }
+
+    pub(crate) fn set_netns(&mut self, netns: &Path) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "xdp_netns",
+            netns.to_str().unwrap().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_netns(&self) -> Result<Option<PathBuf>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_netns")?
+            .map(|v| PathBuf::from(bytes_to_string(&v))))
+    }
+
+    // The id of the network namespace, see get_netns_id(). It's kept so the
+    // program can be told apart from programs on interfaces with the same
+    // index in other namespaces, even after the namespace is gone.
+    pub(crate) fn set_nsid(&mut self, nsid: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "xdp_nsid", &nsid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_nsid(&self) -> Result<Option<u64>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_nsid")?.map(bytes_to_u64))
+    }
+
+    pub(crate) fn get_iface_glob(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_iface_glob")?.map(|v| bytes_to_string(&v)))
+    }
+
+    pub(crate) fn set_track_iface_glob(&mut self, track: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "xdp_track_iface_glob",
+            &(track as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_track_iface_glob(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_track_iface_glob")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_wait_for_iface(&mut self, wait: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "xdp_wait_for_iface",
+            &(wait as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_wait_for_iface(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_wait_for_iface")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    // See TcProgram::set_pending().
+    pub(crate) fn set_pending(&mut self, pending: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "xdp_pending",
+            &(pending as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_pending(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "xdp_pending")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    // The mode the dispatcher the program was last attached through took
+    // effect in.
+    pub(crate) fn set_mode(&mut self, mode: XdpMode) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "xdp_mode", &(mode as u32).to_ne_bytes())
+    }
+
+    pub(crate) fn get_mode(&self) -> Result<Option<XdpMode>, BpfmanError> {
+        sled_get_option(&self.data.db_tree, "xdp_mode")?
+            .map(|v| {
+                XdpMode::try_from(bytes_to_u32(v)).map_err(|e| BpfmanError::Error(e.to_string()))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn set_proceed_on(&mut self, proceed_on: XdpProceedOn) -> Result<(), BpfmanError> {
+        proceed_on
+            .as_action_vec()
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, v)| {
+                sled_insert(
+                    &self.data.db_tree,
+                    format!("xdp_proceed_on_{i}").as_str(),
+                    &v.to_ne_bytes(),
+                )
+            })
     }
 
     pub(crate) fn get_proceed_on(&self) -> Result<XdpProceedOn, BpfmanError> {
@@ -937,6 +2216,23 @@ impl XdpProgram {
             .unwrap_or(false))
     }
 
+    // The counters of the dispatcher slot the program is attached to.
+    pub(crate) fn slot_stats(&self) -> Result<Option<DispatcherSlotStats>, BpfmanError> {
+        let (Some(if_index), Some(position)) = (self.get_if_index()?, self.get_current_position()?)
+        else {
+            return Ok(None);
+        };
+        if !self.get_attached()? {
+            return Ok(None);
+        }
+        let key = dispatcher_key(if_index, self.get_nsid()?);
+        Ok(slot_stats(
+            &stats_path(RTDIR_FS_XDP, &key),
+            position,
+            DispatcherKind::Xdp,
+        ))
+    }
+
     pub(crate) fn get_data(&self) -> &ProgramData {
         &self.data
     }
@@ -958,6 +2254,7 @@ impl TcProgram {
         iface: String,
         proceed_on: TcProceedOn,
         direction: Direction,
+        tcx: Option<bool>,
     ) -> Result<Self, BpfmanError> {
         let mut tc_prog = Self { data };
 
@@ -965,6 +2262,10 @@ impl TcProgram {
         tc_prog.set_iface(iface)?;
         tc_prog.set_proceed_on(proceed_on)?;
         tc_prog.set_direction(direction)?;
+        // Left unset when not requested so the daemon configuration decides.
+        if let Some(tcx) = tcx {
+            tc_prog.set_tcx(tcx)?;
+        }
         tc_prog.get_data_mut().set_kind(ProgramType::Tc)?;
 
         Ok(tc_prog)
@@ -986,6 +2287,106 @@ impl TcProgram {
         sled_get(&self.data.db_tree, "tc_iface").map(|v| bytes_to_string(&v))
     }
 
+    pub(crate) fn set_iface_glob(&mut self, glob: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "tc_iface_glob", glob.as_bytes())
+    }
+
+    pub(crate) fn set_netns(&mut self, netns: &Path) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "tc_netns",
+            netns.to_str().unwrap().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_netns(&self) -> Result<Option<PathBuf>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_netns")?
+            .map(|v| PathBuf::from(bytes_to_string(&v))))
+    }
+
+    // The id of the network namespace, see get_netns_id(). It's kept so the
+    // program can be told apart from programs on interfaces with the same
+    // index in other namespaces, even after the namespace is gone.
+    pub(crate) fn set_nsid(&mut self, nsid: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "tc_nsid", &nsid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_nsid(&self) -> Result<Option<u64>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_nsid")?.map(bytes_to_u64))
+    }
+
+    pub(crate) fn get_iface_glob(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_iface_glob")?.map(|v| bytes_to_string(&v)))
+    }
+
+    pub(crate) fn set_track_iface_glob(&mut self, track: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "tc_track_iface_glob",
+            &(track as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_track_iface_glob(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_track_iface_glob")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    // A program loaded for both directions is attached to ingress, and to
+    // egress through an attachment to the same interface.
+    pub(crate) fn set_both_directions(&mut self, both: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "tc_both_directions",
+            &(both as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_both_directions(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_both_directions")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_tcx(&mut self, tcx: bool) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "tc_tcx", &(tcx as i8 % 2).to_ne_bytes())
+    }
+
+    pub(crate) fn get_tcx(&self) -> Result<Option<bool>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_tcx")?.map(bytes_to_bool))
+    }
+
+    pub(crate) fn set_wait_for_iface(&mut self, wait: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "tc_wait_for_iface",
+            &(wait as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_wait_for_iface(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_wait_for_iface")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    // A program loaded before its interface was created stays detached and
+    // pending until the interface appears.
+    pub(crate) fn set_pending(&mut self, pending: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "tc_pending",
+            &(pending as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_pending(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "tc_pending")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
     pub(crate) fn set_proceed_on(&mut self, proceed_on: TcProceedOn) -> Result<(), BpfmanError> {
         proceed_on
             .as_action_vec()
@@ -1050,6 +2451,28 @@ impl TcProgram {
             .unwrap_or(false))
     }
 
+    // The counters of the dispatcher slot the program is attached to, which
+    // programs attached with tcx don't have.
+    pub(crate) fn slot_stats(&self) -> Result<Option<DispatcherSlotStats>, BpfmanError> {
+        let (Some(if_index), Some(position)) = (self.get_if_index()?, self.get_current_position()?)
+        else {
+            return Ok(None);
+        };
+        if !self.get_attached()? || self.get_tcx()?.unwrap_or(false) {
+            return Ok(None);
+        }
+        let base = match self.get_direction()? {
+            Direction::Ingress => RTDIR_FS_TC_INGRESS,
+            Direction::Egress => RTDIR_FS_TC_EGRESS,
+        };
+        let key = dispatcher_key(if_index, self.get_nsid()?);
+        Ok(slot_stats(
+            &stats_path(base, &key),
+            position,
+            DispatcherKind::Tc,
+        ))
+    }
+
     pub(crate) fn set_direction(&mut self, direction: Direction) -> Result<(), BpfmanError> {
         sled_insert(
             &self.data.db_tree,
@@ -1104,69 +2527,1345 @@ impl TracepointProgram {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct KprobeProgram {
+pub(crate) struct KprobeProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl KprobeProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        fn_name: String,
+        offset: u64,
+        retprobe: bool,
+        container_pid: Option<i32>,
+    ) -> Result<Self, BpfmanError> {
+        let mut kprobe_prog = Self { data };
+        kprobe_prog.set_fn_name(fn_name)?;
+        kprobe_prog.set_offset(offset)?;
+        kprobe_prog.set_retprobe(retprobe)?;
+        kprobe_prog.get_data_mut().set_kind(ProgramType::Probe)?;
+        if container_pid.is_some() {
+            kprobe_prog.set_container_pid(container_pid.unwrap())?;
+        }
+        Ok(kprobe_prog)
+    }
+
+    pub(crate) fn set_fn_name(&mut self, fn_name: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "kprobe_fn_name", fn_name.as_bytes())
+    }
+
+    pub(crate) fn get_fn_name(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "kprobe_fn_name").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_offset(&mut self, offset: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "kprobe_offset", &offset.to_ne_bytes())
+    }
+
+    pub(crate) fn get_offset(&self) -> Result<u64, BpfmanError> {
+        sled_get(&self.data.db_tree, "kprobe_offset").map(bytes_to_u64)
+    }
+
+    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "kprobe_retprobe",
+            &(retprobe as i8 % 2).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "kprobe_retprobe")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_container_pid(&mut self, container_pid: i32) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "kprobe_container_pid",
+            &container_pid.to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_container_pid(&self) -> Result<Option<i32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "kprobe_container_pid")?.map(bytes_to_i32))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct UprobeProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl UprobeProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        fn_name: Option<String>,
+        offset: u64,
+        target: String,
+        retprobe: bool,
+        pid: Option<i32>,
+        container_pid: Option<i32>,
+    ) -> Result<Self, BpfmanError> {
+        let mut uprobe_prog = Self { data };
+
+        if fn_name.is_some() {
+            uprobe_prog.set_fn_name(fn_name.unwrap())?;
+        }
+
+        uprobe_prog.set_offset(offset)?;
+        uprobe_prog.set_retprobe(retprobe)?;
+        if let Some(p) = container_pid {
+            uprobe_prog.set_container_pid(p)?;
+        }
+        if let Some(p) = pid {
+            uprobe_prog.set_pid(p)?;
+        }
+        uprobe_prog.set_target(target)?;
+        uprobe_prog.get_data_mut().set_kind(ProgramType::Probe)?;
+        Ok(uprobe_prog)
+    }
+
+    pub(crate) fn set_fn_name(&mut self, fn_name: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_fn_name", fn_name.as_bytes())
+    }
+
+    pub(crate) fn get_fn_name(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "uprobe_fn_name")?.map(|v| bytes_to_string(&v)))
+    }
+
+    pub(crate) fn set_offset(&mut self, offset: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_offset", &offset.to_ne_bytes())
+    }
+
+    pub(crate) fn get_offset(&self) -> Result<u64, BpfmanError> {
+        sled_get(&self.data.db_tree, "uprobe_offset").map(bytes_to_u64)
+    }
+
+    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "uprobe_retprobe",
+            &(retprobe as i8 % 2).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "uprobe_retprobe")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
+    pub(crate) fn set_container_pid(&mut self, container_pid: i32) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "uprobe_container_pid",
+            &container_pid.to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_container_pid(&self) -> Result<Option<i32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "uprobe_container_pid")?.map(bytes_to_i32))
+    }
+
+    pub(crate) fn set_container_id(&mut self, container_id: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "uprobe_container_id",
+            container_id.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_container_id(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(
+            sled_get_option(&self.data.db_tree, "uprobe_container_id")?
+                .map(|v| bytes_to_string(&v)),
+        )
+    }
+
+    pub(crate) fn set_pid(&mut self, pid: i32) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_pid", &pid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_pid(&self) -> Result<Option<i32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "uprobe_pid")?.map(bytes_to_i32))
+    }
+
+    pub(crate) fn set_target(&mut self, target: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_target", target.as_bytes())
+    }
+
+    pub(crate) fn get_target(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "uprobe_target").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LsmProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl LsmProgram {
+    pub(crate) fn new(data: ProgramData, hook: String) -> Result<Self, BpfmanError> {
+        let mut lsm_prog = Self { data };
+        lsm_prog.set_hook(hook)?;
+        lsm_prog.get_data_mut().set_kind(ProgramType::Lsm)?;
+
+        Ok(lsm_prog)
+    }
+
+    pub(crate) fn set_hook(&mut self, hook: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "lsm_hook", hook.as_bytes())
+    }
+
+    pub(crate) fn get_hook(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "lsm_hook").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupSkbProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl CgroupSkbProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        direction: Direction,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut cgroup_skb_prog = Self { data };
+        cgroup_skb_prog.set_cgroup_path(cgroup_path)?;
+        cgroup_skb_prog.set_direction(direction)?;
+        cgroup_skb_prog.set_attach_flags(attach_flags)?;
+        cgroup_skb_prog
+            .get_data_mut()
+            .set_kind(ProgramType::CgroupSkb)?;
+
+        Ok(cgroup_skb_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_skb_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_skb_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_direction(&mut self, direction: Direction) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_skb_direction",
+            direction.to_string().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_direction(&self) -> Result<Direction, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_skb_direction")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_skb_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_skb_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupSockAddrProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl CgroupSockAddrProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        attach_point: SockAddrAttachPoint,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut sock_addr_prog = Self { data };
+        sock_addr_prog.set_cgroup_path(cgroup_path)?;
+        sock_addr_prog.set_attach_point(attach_point)?;
+        sock_addr_prog.set_attach_flags(attach_flags)?;
+        sock_addr_prog
+            .get_data_mut()
+            .set_kind(ProgramType::CgroupSockAddr)?;
+
+        Ok(sock_addr_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sock_addr_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sock_addr_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_attach_point(
+        &mut self,
+        attach_point: SockAddrAttachPoint,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sock_addr_attach_point",
+            attach_point.to_string().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_point(&self) -> Result<SockAddrAttachPoint, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sock_addr_attach_point")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sock_addr_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sock_addr_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SockOpsProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl SockOpsProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut sock_ops_prog = Self { data };
+        sock_ops_prog.set_cgroup_path(cgroup_path)?;
+        sock_ops_prog.set_attach_flags(attach_flags)?;
+        sock_ops_prog
+            .get_data_mut()
+            .set_kind(ProgramType::SockOps)?;
+
+        Ok(sock_ops_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sock_ops_cgroup_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "sock_ops_cgroup_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sock_ops_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "sock_ops_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupDeviceProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl CgroupDeviceProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut cgroup_device_prog = Self { data };
+        cgroup_device_prog.set_cgroup_path(cgroup_path)?;
+        cgroup_device_prog.set_attach_flags(attach_flags)?;
+        cgroup_device_prog
+            .get_data_mut()
+            .set_kind(ProgramType::CgroupDevice)?;
+
+        Ok(cgroup_device_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_device_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_device_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_device_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_device_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupSysctlProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl CgroupSysctlProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut cgroup_sysctl_prog = Self { data };
+        cgroup_sysctl_prog.set_cgroup_path(cgroup_path)?;
+        cgroup_sysctl_prog.set_attach_flags(attach_flags)?;
+        cgroup_sysctl_prog
+            .get_data_mut()
+            .set_kind(ProgramType::CgroupSysctl)?;
+
+        Ok(cgroup_sysctl_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sysctl_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sysctl_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sysctl_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sysctl_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupSockoptProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl CgroupSockoptProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        cgroup_path: String,
+        attach_type: SockoptAttachType,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<Self, BpfmanError> {
+        let mut cgroup_sockopt_prog = Self { data };
+        cgroup_sockopt_prog.set_cgroup_path(cgroup_path)?;
+        cgroup_sockopt_prog.set_attach_type(attach_type)?;
+        cgroup_sockopt_prog.set_attach_flags(attach_flags)?;
+        cgroup_sockopt_prog
+            .get_data_mut()
+            .set_kind(ProgramType::CgroupSockopt)?;
+
+        Ok(cgroup_sockopt_prog)
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sockopt_path",
+            cgroup_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_cgroup_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sockopt_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_attach_type(
+        &mut self,
+        attach_type: SockoptAttachType,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sockopt_attach_type",
+            attach_type.to_string().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_type(&self) -> Result<SockoptAttachType, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sockopt_attach_type")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
+    }
+
+    pub(crate) fn set_attach_flags(
+        &mut self,
+        attach_flags: CgroupAttachFlags,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "cgroup_sockopt_attach_flags",
+            &i32::from(attach_flags).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_flags(&self) -> Result<CgroupAttachFlags, BpfmanError> {
+        sled_get(&self.data.db_tree, "cgroup_sockopt_attach_flags")
+            .map(|v| bytes_to_i32(v).try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SkMsgProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl SkMsgProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        map_name: String,
+        sock_map_pin_path: Option<String>,
+    ) -> Result<Self, BpfmanError> {
+        let mut sk_msg_prog = Self { data };
+        sk_msg_prog.set_map_name(map_name)?;
+        if let Some(path) = sock_map_pin_path {
+            sk_msg_prog.set_sock_map_pin_path(path)?;
+        }
+        sk_msg_prog.get_data_mut().set_kind(ProgramType::SkMsg)?;
+
+        Ok(sk_msg_prog)
+    }
+
+    pub(crate) fn set_map_name(&mut self, map_name: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "sk_msg_map_name", map_name.as_bytes())
+    }
+
+    pub(crate) fn get_map_name(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "sk_msg_map_name").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_sock_map_pin_path(&mut self, path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sk_msg_sock_map_pin_path",
+            path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_sock_map_pin_path(&self) -> Result<Option<String>, BpfmanError> {
+        sled_get_option(&self.data.db_tree, "sk_msg_sock_map_pin_path")
+            .map(|v| v.map(|f| bytes_to_string(&f)))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SkSkbProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl SkSkbProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        map_name: String,
+        sock_map_pin_path: Option<String>,
+        attach_type: SkSkbAttachType,
+    ) -> Result<Self, BpfmanError> {
+        let mut sk_skb_prog = Self { data };
+        sk_skb_prog.set_map_name(map_name)?;
+        if let Some(path) = sock_map_pin_path {
+            sk_skb_prog.set_sock_map_pin_path(path)?;
+        }
+        sk_skb_prog.set_attach_type(attach_type)?;
+        sk_skb_prog.get_data_mut().set_kind(ProgramType::SkSkb)?;
+
+        Ok(sk_skb_prog)
+    }
+
+    pub(crate) fn set_map_name(&mut self, map_name: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "sk_skb_map_name", map_name.as_bytes())
+    }
+
+    pub(crate) fn get_map_name(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "sk_skb_map_name").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_sock_map_pin_path(&mut self, path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sk_skb_sock_map_pin_path",
+            path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_sock_map_pin_path(&self) -> Result<Option<String>, BpfmanError> {
+        sled_get_option(&self.data.db_tree, "sk_skb_sock_map_pin_path")
+            .map(|v| v.map(|f| bytes_to_string(&f)))
+    }
+
+    pub(crate) fn set_attach_type(
+        &mut self,
+        attach_type: SkSkbAttachType,
+    ) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sk_skb_attach_type",
+            attach_type.to_string().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_attach_type(&self) -> Result<SkSkbAttachType, BpfmanError> {
+        sled_get(&self.data.db_tree, "sk_skb_attach_type")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FlowDissectorProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl FlowDissectorProgram {
+    pub(crate) fn new(data: ProgramData, netns_path: String) -> Result<Self, BpfmanError> {
+        let mut flow_dissector_prog = Self { data };
+        flow_dissector_prog.set_netns_path(netns_path)?;
+        flow_dissector_prog
+            .get_data_mut()
+            .set_kind(ProgramType::FlowDissector)?;
+
+        Ok(flow_dissector_prog)
+    }
+
+    pub(crate) fn set_netns_path(&mut self, netns_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "flow_dissector_netns_path",
+            netns_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_netns_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "flow_dissector_netns_path").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SkLookupProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl SkLookupProgram {
+    pub(crate) fn new(data: ProgramData, netns_path: String) -> Result<Self, BpfmanError> {
+        let mut sk_lookup_prog = Self { data };
+        sk_lookup_prog.set_netns_path(netns_path)?;
+        sk_lookup_prog
+            .get_data_mut()
+            .set_kind(ProgramType::SkLookup)?;
+
+        Ok(sk_lookup_prog)
+    }
+
+    pub(crate) fn set_netns_path(&mut self, netns_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sk_lookup_netns_path",
+            netns_path.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_netns_path(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "sk_lookup_netns_path").map(|v| bytes_to_string(&v))
+    }
+
+    // The inode of the network namespace the program was last attached to,
+    // used to notice the namespace being recreated while bpfman was down.
+    pub(crate) fn set_netns_ino(&mut self, ino: u64) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "sk_lookup_netns_ino",
+            &ino.to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_netns_ino(&self) -> Result<Option<u64>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "sk_lookup_netns_ino")?.map(bytes_to_u64))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PerfEventProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl PerfEventProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        event: PerfEventType,
+        sample_period: Option<u64>,
+        sample_frequency: Option<u64>,
+        cpus: Vec<u32>,
+    ) -> Result<Self, BpfmanError> {
+        if sample_period.is_some() == sample_frequency.is_some() {
+            return Err(BpfmanError::Error(
+                "exactly one of sample_period or sample_frequency must be set".to_string(),
+            ));
+        }
+
+        let mut perf_event_prog = Self { data };
+        perf_event_prog.set_event(event)?;
+        if let Some(period) = sample_period {
+            perf_event_prog.set_sample_period(period)?;
+        }
+        if let Some(frequency) = sample_frequency {
+            perf_event_prog.set_sample_frequency(frequency)?;
+        }
+        perf_event_prog.set_cpus(cpus)?;
+        perf_event_prog
+            .get_data_mut()
+            .set_kind(ProgramType::PerfEvent)?;
+
+        Ok(perf_event_prog)
+    }
+
+    pub(crate) fn set_event(&mut self, event: PerfEventType) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "perf_event_event",
+            event.to_string().as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_event(&self) -> Result<PerfEventType, BpfmanError> {
+        sled_get(&self.data.db_tree, "perf_event_event")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
+    }
+
+    pub(crate) fn set_sample_period(&mut self, period: u64) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "perf_event_sample_period",
+            &period.to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_sample_period(&self) -> Result<Option<u64>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "perf_event_sample_period")?.map(bytes_to_u64))
+    }
+
+    pub(crate) fn set_sample_frequency(&mut self, frequency: u64) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "perf_event_sample_frequency",
+            &frequency.to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_sample_frequency(&self) -> Result<Option<u64>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "perf_event_sample_frequency")?.map(bytes_to_u64))
+    }
+
+    pub(crate) fn set_cpus(&mut self, cpus: Vec<u32>) -> Result<(), BpfmanError> {
+        cpus.iter().enumerate().try_for_each(|(i, cpu)| {
+            sled_insert(
+                &self.data.db_tree,
+                format!("perf_event_cpu_{i}").as_str(),
+                &cpu.to_ne_bytes(),
+            )
+        })
+    }
+
+    pub(crate) fn get_cpus(&self) -> Result<Vec<u32>, BpfmanError> {
+        self.data
+            .db_tree
+            .scan_prefix("perf_event_cpu_")
+            .map(|n| {
+                n.map(|(_, v)| bytes_to_u32(v.to_vec())).map_err(|e| {
+                    BpfmanError::DatabaseError("Failed to get cpus".to_string(), e.to_string())
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RawTracepointProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl RawTracepointProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        tracepoint: String,
+        btf: bool,
+    ) -> Result<Self, BpfmanError> {
+        let mut raw_tp_prog = Self { data };
+        raw_tp_prog.set_tracepoint(tracepoint)?;
+        raw_tp_prog.set_btf(btf)?;
+        // tp_btf programs are tracing programs as far as the kernel is concerned.
+        let kind = if btf {
+            ProgramType::Tracing
+        } else {
+            ProgramType::RawTracepoint
+        };
+        raw_tp_prog.get_data_mut().set_kind(kind)?;
+
+        Ok(raw_tp_prog)
+    }
+
+    pub(crate) fn set_tracepoint(&mut self, tracepoint: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "raw_tracepoint_name",
+            tracepoint.as_bytes(),
+        )
+    }
+
+    pub(crate) fn get_tracepoint(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "raw_tracepoint_name").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_btf(&mut self, btf: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "raw_tracepoint_btf",
+            &(btf as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_btf(&self) -> Result<bool, BpfmanError> {
+        sled_get(&self.data.db_tree, "raw_tracepoint_btf").map(bytes_to_bool)
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SocketFilterProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl SocketFilterProgram {
+    pub(crate) fn new(data: ProgramData) -> Result<Self, BpfmanError> {
+        let mut socket_filter_prog = Self { data };
+        socket_filter_prog
+            .get_data_mut()
+            .set_kind(ProgramType::SocketFilter)?;
+
+        Ok(socket_filter_prog)
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct UsdtProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl UsdtProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        target: String,
+        provider: String,
+        name: String,
+        pid: Option<i32>,
+    ) -> Result<Self, BpfmanError> {
+        let mut usdt_prog = Self { data };
+        usdt_prog.set_target(target)?;
+        usdt_prog.set_provider(provider)?;
+        usdt_prog.set_name(name)?;
+        if let Some(p) = pid {
+            usdt_prog.set_pid(p)?;
+        }
+        // USDT probes are attached with a uprobe.
+        usdt_prog.get_data_mut().set_kind(ProgramType::Probe)?;
+
+        Ok(usdt_prog)
+    }
+
+    pub(crate) fn set_target(&mut self, target: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "usdt_target", target.as_bytes())
+    }
+
+    pub(crate) fn get_target(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "usdt_target").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_provider(&mut self, provider: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "usdt_provider", provider.as_bytes())
+    }
+
+    pub(crate) fn get_provider(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "usdt_provider").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_name(&mut self, name: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "usdt_name", name.as_bytes())
+    }
+
+    pub(crate) fn get_name(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "usdt_name").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_pid(&mut self, pid: i32) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "usdt_pid", &pid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_pid(&self) -> Result<Option<i32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "usdt_pid")?.map(bytes_to_i32))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct KprobeMultiProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl KprobeMultiProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        fn_names: Vec<String>,
+        retprobe: bool,
+    ) -> Result<Self, BpfmanError> {
+        if fn_names.is_empty() {
+            return Err(BpfmanError::Error(
+                "at least one function name or pattern must be set".to_string(),
+            ));
+        }
+
+        let mut kprobe_multi_prog = Self { data };
+        kprobe_multi_prog.set_fn_names(fn_names)?;
+        kprobe_multi_prog.set_retprobe(retprobe)?;
+        kprobe_multi_prog
+            .get_data_mut()
+            .set_kind(ProgramType::Probe)?;
+
+        Ok(kprobe_multi_prog)
+    }
+
+    pub(crate) fn set_fn_names(&mut self, fn_names: Vec<String>) -> Result<(), BpfmanError> {
+        fn_names.iter().enumerate().try_for_each(|(i, fn_name)| {
+            sled_insert(
+                &self.data.db_tree,
+                format!("kprobe_multi_fn_name_{i}").as_str(),
+                fn_name.as_bytes(),
+            )
+        })
+    }
+
+    pub(crate) fn get_fn_names(&self) -> Result<Vec<String>, BpfmanError> {
+        self.data
+            .db_tree
+            .scan_prefix("kprobe_multi_fn_name_")
+            .map(|n| {
+                n.map(|(_, v)| bytes_to_string(&v)).map_err(|e| {
+                    BpfmanError::DatabaseError(
+                        "Failed to get function names".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "kprobe_multi_retprobe",
+            &(retprobe as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
+        sled_get(&self.data.db_tree, "kprobe_multi_retprobe").map(bytes_to_bool)
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct UprobeMultiProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl UprobeMultiProgram {
+    pub(crate) fn new(
+        data: ProgramData,
+        target: String,
+        fn_names: Vec<String>,
+        retprobe: bool,
+        pid: Option<i32>,
+    ) -> Result<Self, BpfmanError> {
+        if fn_names.is_empty() {
+            return Err(BpfmanError::Error(
+                "at least one function name must be set".to_string(),
+            ));
+        }
+
+        let mut uprobe_multi_prog = Self { data };
+        uprobe_multi_prog.set_target(target)?;
+        uprobe_multi_prog.set_fn_names(fn_names)?;
+        uprobe_multi_prog.set_retprobe(retprobe)?;
+        if let Some(p) = pid {
+            uprobe_multi_prog.set_pid(p)?;
+        }
+        uprobe_multi_prog
+            .get_data_mut()
+            .set_kind(ProgramType::Probe)?;
+
+        Ok(uprobe_multi_prog)
+    }
+
+    pub(crate) fn set_target(&mut self, target: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_multi_target", target.as_bytes())
+    }
+
+    pub(crate) fn get_target(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "uprobe_multi_target").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn set_fn_names(&mut self, fn_names: Vec<String>) -> Result<(), BpfmanError> {
+        fn_names.iter().enumerate().try_for_each(|(i, fn_name)| {
+            sled_insert(
+                &self.data.db_tree,
+                format!("uprobe_multi_fn_name_{i}").as_str(),
+                fn_name.as_bytes(),
+            )
+        })
+    }
+
+    pub(crate) fn get_fn_names(&self) -> Result<Vec<String>, BpfmanError> {
+        self.data
+            .db_tree
+            .scan_prefix("uprobe_multi_fn_name_")
+            .map(|n| {
+                n.map(|(_, v)| bytes_to_string(&v)).map_err(|e| {
+                    BpfmanError::DatabaseError(
+                        "Failed to get function names".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "uprobe_multi_retprobe",
+            &(retprobe as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
+        sled_get(&self.data.db_tree, "uprobe_multi_retprobe").map(bytes_to_bool)
+    }
+
+    pub(crate) fn set_pid(&mut self, pid: i32) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "uprobe_multi_pid", &pid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_pid(&self) -> Result<Option<i32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "uprobe_multi_pid")?.map(bytes_to_i32))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct IterProgram {
+    pub(crate) data: ProgramData,
+}
+
+impl IterProgram {
+    pub(crate) fn new(data: ProgramData, iter_type: String) -> Result<Self, BpfmanError> {
+        let mut iter_prog = Self { data };
+        iter_prog.set_iter_type(iter_type)?;
+        // Iterators are tracing programs as far as the kernel is concerned.
+        iter_prog.get_data_mut().set_kind(ProgramType::Tracing)?;
+
+        Ok(iter_prog)
+    }
+
+    pub(crate) fn set_iter_type(&mut self, iter_type: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "iter_type", iter_type.as_bytes())
+    }
+
+    pub(crate) fn get_iter_type(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "iter_type").map(|v| bytes_to_string(&v))
+    }
+
+    pub(crate) fn get_data(&self) -> &ProgramData {
+        &self.data
+    }
+
+    pub(crate) fn get_data_mut(&mut self) -> &mut ProgramData {
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ExtensionProgram {
     pub(crate) data: ProgramData,
 }
 
-impl KprobeProgram {
+impl ExtensionProgram {
     pub(crate) fn new(
         data: ProgramData,
-        fn_name: String,
-        offset: u64,
-        retprobe: bool,
-        container_pid: Option<i32>,
+        target_path: Option<String>,
+        target_id: Option<u32>,
+        target_fn: String,
     ) -> Result<Self, BpfmanError> {
-        let mut kprobe_prog = Self { data };
-        kprobe_prog.set_fn_name(fn_name)?;
-        kprobe_prog.set_offset(offset)?;
-        kprobe_prog.set_retprobe(retprobe)?;
-        kprobe_prog.get_data_mut().set_kind(ProgramType::Probe)?;
-        if container_pid.is_some() {
-            kprobe_prog.set_container_pid(container_pid.unwrap())?;
+        let mut ext_prog = Self { data };
+
+        match (target_path, target_id) {
+            (Some(path), None) => ext_prog.set_target_path(path)?,
+            (None, Some(id)) => ext_prog.set_target_id(id)?,
+            _ => {
+                return Err(BpfmanError::Error(
+                    "exactly one of target_path or target_id must be set".to_string(),
+                ))
+            }
         }
-        Ok(kprobe_prog)
-    }
-
-    pub(crate) fn set_fn_name(&mut self, fn_name: String) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "kprobe_fn_name", fn_name.as_bytes())
-    }
+        ext_prog.set_target_fn(target_fn)?;
+        ext_prog.get_data_mut().set_kind(ProgramType::Ext)?;
 
-    pub(crate) fn get_fn_name(&self) -> Result<String, BpfmanError> {
-        sled_get(&self.data.db_tree, "kprobe_fn_name").map(|v| bytes_to_string(&v))
+        Ok(ext_prog)
     }
 
-    pub(crate) fn set_offset(&mut self, offset: u64) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "kprobe_offset", &offset.to_ne_bytes())
+    pub(crate) fn set_target_path(&mut self, target_path: String) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "ext_target_path",
+            target_path.as_bytes(),
+        )
     }
 
-    pub(crate) fn get_offset(&self) -> Result<u64, BpfmanError> {
-        sled_get(&self.data.db_tree, "kprobe_offset").map(bytes_to_u64)
+    pub(crate) fn get_target_path(&self) -> Result<Option<String>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "ext_target_path")?.map(|v| bytes_to_string(&v)))
     }
 
-    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+    pub(crate) fn set_target_id(&mut self, target_id: u32) -> Result<(), BpfmanError> {
         sled_insert(
             &self.data.db_tree,
-            "kprobe_retprobe",
-            &(retprobe as i8 % 2).to_ne_bytes(),
+            "ext_target_id",
+            &target_id.to_ne_bytes(),
         )
     }
 
-    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "kprobe_retprobe")?
-            .map(bytes_to_bool)
-            .unwrap_or(false))
+    pub(crate) fn get_target_id(&self) -> Result<Option<u32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "ext_target_id")?.map(bytes_to_u32))
     }
 
-    pub(crate) fn set_container_pid(&mut self, container_pid: i32) -> Result<(), BpfmanError> {
-        sled_insert(
-            &self.data.db_tree,
-            "kprobe_container_pid",
-            &container_pid.to_ne_bytes(),
-        )
+    pub(crate) fn set_target_fn(&mut self, target_fn: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "ext_target_fn", target_fn.as_bytes())
     }
 
-    pub(crate) fn get_container_pid(&self) -> Result<Option<i32>, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "kprobe_container_pid")?.map(bytes_to_i32))
+    pub(crate) fn get_target_fn(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "ext_target_fn").map(|v| bytes_to_string(&v))
     }
 
     pub(crate) fn get_data(&self) -> &ProgramData {
@@ -1179,95 +3878,71 @@ impl KprobeProgram {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct UprobeProgram {
+pub(crate) struct NetkitProgram {
     pub(crate) data: ProgramData,
 }
 
-impl UprobeProgram {
+impl NetkitProgram {
     pub(crate) fn new(
         data: ProgramData,
-        fn_name: Option<String>,
-        offset: u64,
-        target: String,
-        retprobe: bool,
-        pid: Option<i32>,
-        container_pid: Option<i32>,
+        iface: String,
+        position: NetkitPosition,
+        priority: i32,
     ) -> Result<Self, BpfmanError> {
-        let mut uprobe_prog = Self { data };
-
-        if fn_name.is_some() {
-            uprobe_prog.set_fn_name(fn_name.unwrap())?;
-        }
-
-        uprobe_prog.set_offset(offset)?;
-        uprobe_prog.set_retprobe(retprobe)?;
-        if let Some(p) = container_pid {
-            uprobe_prog.set_container_pid(p)?;
-        }
-        if let Some(p) = pid {
-            uprobe_prog.set_pid(p)?;
-        }
-        uprobe_prog.set_target(target)?;
-        uprobe_prog.get_data_mut().set_kind(ProgramType::Probe)?;
-        Ok(uprobe_prog)
-    }
-
-    pub(crate) fn set_fn_name(&mut self, fn_name: String) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "uprobe_fn_name", fn_name.as_bytes())
-    }
+        let mut netkit_prog = Self { data };
+        netkit_prog.set_iface(iface)?;
+        netkit_prog.set_position(position)?;
+        netkit_prog.set_priority(priority)?;
+        // netkit programs are sched_cls programs as far as the kernel is
+        // concerned.
+        netkit_prog.get_data_mut().set_kind(ProgramType::Tc)?;
 
-    pub(crate) fn get_fn_name(&self) -> Result<Option<String>, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "uprobe_fn_name")?.map(|v| bytes_to_string(&v)))
+        Ok(netkit_prog)
     }
 
-    pub(crate) fn set_offset(&mut self, offset: u64) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "uprobe_offset", &offset.to_ne_bytes())
+    pub(crate) fn set_iface(&mut self, iface: String) -> Result<(), BpfmanError> {
+        sled_insert(&self.data.db_tree, "netkit_iface", iface.as_bytes())
     }
 
-    pub(crate) fn get_offset(&self) -> Result<u64, BpfmanError> {
-        sled_get(&self.data.db_tree, "uprobe_offset").map(bytes_to_u64)
+    pub(crate) fn get_iface(&self) -> Result<String, BpfmanError> {
+        sled_get(&self.data.db_tree, "netkit_iface").map(|v| bytes_to_string(&v))
     }
 
-    pub(crate) fn set_retprobe(&mut self, retprobe: bool) -> Result<(), BpfmanError> {
+    pub(crate) fn set_position(&mut self, position: NetkitPosition) -> Result<(), BpfmanError> {
         sled_insert(
             &self.data.db_tree,
-            "uprobe_retprobe",
-            &(retprobe as i8 % 2).to_ne_bytes(),
+            "netkit_position",
+            position.to_string().as_bytes(),
         )
     }
 
-    pub(crate) fn get_retprobe(&self) -> Result<bool, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "uprobe_retprobe")?
-            .map(bytes_to_bool)
-            .unwrap_or(false))
+    pub(crate) fn get_position(&self) -> Result<NetkitPosition, BpfmanError> {
+        sled_get(&self.data.db_tree, "netkit_position")
+            .map(|v| bytes_to_string(&v).to_string().try_into().unwrap())
     }
 
-    pub(crate) fn set_container_pid(&mut self, container_pid: i32) -> Result<(), BpfmanError> {
+    pub(crate) fn set_priority(&mut self, priority: i32) -> Result<(), BpfmanError> {
         sled_insert(
             &self.data.db_tree,
-            "uprobe_container_pid",
-            &container_pid.to_ne_bytes(),
+            "netkit_priority",
+            &priority.to_ne_bytes(),
         )
     }
 
-    pub(crate) fn get_container_pid(&self) -> Result<Option<i32>, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "uprobe_container_pid")?.map(bytes_to_i32))
-    }
-
-    pub(crate) fn set_pid(&mut self, pid: i32) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "uprobe_pid", &pid.to_ne_bytes())
-    }
-
-    pub(crate) fn get_pid(&self) -> Result<Option<i32>, BpfmanError> {
-        Ok(sled_get_option(&self.data.db_tree, "uprobe_pid")?.map(bytes_to_i32))
+    pub(crate) fn get_priority(&self) -> Result<i32, BpfmanError> {
+        sled_get(&self.data.db_tree, "netkit_priority").map(bytes_to_i32)
     }
 
-    pub(crate) fn set_target(&mut self, target: String) -> Result<(), BpfmanError> {
-        sled_insert(&self.data.db_tree, "uprobe_target", target.as_bytes())
+    pub(crate) fn set_if_index(&mut self, if_index: u32) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.data.db_tree,
+            "netkit_if_index",
+            &if_index.to_ne_bytes(),
+        )
     }
 
-    pub(crate) fn get_target(&self) -> Result<String, BpfmanError> {
-        sled_get(&self.data.db_tree, "uprobe_target").map(|v| bytes_to_string(&v))
+    pub(crate) fn get_if_index(&self) -> Result<Option<u32>, BpfmanError> {
+        Ok(sled_get_option(&self.data.db_tree, "netkit_if_index")?.map(bytes_to_u32))
     }
 
     pub(crate) fn get_data(&self) -> &ProgramData {
@@ -1287,6 +3962,29 @@ impl Program {
             Program::Tracepoint(_) => ProgramType::Tracepoint,
             Program::Kprobe(_) => ProgramType::Probe,
             Program::Uprobe(_) => ProgramType::Probe,
+            Program::Lsm(_) => ProgramType::Lsm,
+            Program::CgroupSkb(_) => ProgramType::CgroupSkb,
+            Program::CgroupSockAddr(_) => ProgramType::CgroupSockAddr,
+            Program::SockOps(_) => ProgramType::SockOps,
+            Program::CgroupDevice(_) => ProgramType::CgroupDevice,
+            Program::CgroupSysctl(_) => ProgramType::CgroupSysctl,
+            Program::CgroupSockopt(_) => ProgramType::CgroupSockopt,
+            Program::SkMsg(_) => ProgramType::SkMsg,
+            Program::SkSkb(_) => ProgramType::SkSkb,
+            Program::FlowDissector(_) => ProgramType::FlowDissector,
+            Program::SkLookup(_) => ProgramType::SkLookup,
+            Program::PerfEvent(_) => ProgramType::PerfEvent,
+            Program::RawTracepoint(p) => match p.get_btf().unwrap_or(false) {
+                true => ProgramType::Tracing,
+                false => ProgramType::RawTracepoint,
+            },
+            Program::SocketFilter(_) => ProgramType::SocketFilter,
+            Program::Netkit(_) => ProgramType::Tc,
+            Program::Usdt(_) => ProgramType::Probe,
+            Program::KprobeMulti(_) => ProgramType::Probe,
+            Program::UprobeMulti(_) => ProgramType::Probe,
+            Program::Iter(_) => ProgramType::Tracing,
+            Program::Extension(_) => ProgramType::Ext,
             Program::Unsupported(i) => i.get_kernel_program_type().unwrap().try_into().unwrap(),
         }
     }
@@ -1297,12 +3995,16 @@ impl Program {
                 p.get_if_index()?
                     .expect("if_index should be known at this point"),
                 None,
+                p.get_nsid()?,
             ))),
-            Program::Tc(p) => Some(DispatcherId::Tc(DispatcherInfo(
-                p.get_if_index()?
-                    .expect("if_index should be known at this point"),
-                Some(p.get_direction()?),
-            ))),
+            Program::Tc(p) if !p.get_tcx()?.unwrap_or(false) => {
+                Some(DispatcherId::Tc(DispatcherInfo(
+                    p.get_if_index()?
+                        .expect("if_index should be known at this point"),
+                    Some(p.get_direction()?),
+                    p.get_nsid()?,
+                )))
+            }
             _ => None,
         })
     }
@@ -1314,6 +4016,26 @@ impl Program {
             Program::Tc(p) => &mut p.data,
             Program::Kprobe(p) => &mut p.data,
             Program::Uprobe(p) => &mut p.data,
+            Program::Lsm(p) => &mut p.data,
+            Program::CgroupSkb(p) => &mut p.data,
+            Program::CgroupSockAddr(p) => &mut p.data,
+            Program::SockOps(p) => &mut p.data,
+            Program::CgroupDevice(p) => &mut p.data,
+            Program::CgroupSysctl(p) => &mut p.data,
+            Program::CgroupSockopt(p) => &mut p.data,
+            Program::SkMsg(p) => &mut p.data,
+            Program::SkSkb(p) => &mut p.data,
+            Program::FlowDissector(p) => &mut p.data,
+            Program::SkLookup(p) => &mut p.data,
+            Program::PerfEvent(p) => &mut p.data,
+            Program::RawTracepoint(p) => &mut p.data,
+            Program::SocketFilter(p) => &mut p.data,
+            Program::Netkit(p) => &mut p.data,
+            Program::Usdt(p) => &mut p.data,
+            Program::KprobeMulti(p) => &mut p.data,
+            Program::UprobeMulti(p) => &mut p.data,
+            Program::Iter(p) => &mut p.data,
+            Program::Extension(p) => &mut p.data,
             Program::Unsupported(p) => p,
         }
     }
@@ -1334,6 +4056,67 @@ impl Program {
         };
     }
 
+    // Returns true for TC programs attached with a tcx link rather than
+    // through the tc dispatcher.
+    pub(crate) fn is_tcx(&self) -> bool {
+        match self {
+            Program::Tc(p) => p.get_tcx().unwrap().unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    // Returns the attachments of an XDP or TC program to interfaces other than
+    // the one it was loaded on.
+    pub(crate) fn attachments(&self) -> Result<Vec<Program>, BpfmanError> {
+        if !matches!(self, Program::Xdp(_) | Program::Tc(_)) {
+            return Ok(vec![]);
+        }
+        let id = self.get_data().get_id()?;
+        let prefix = attachment_tree_prefix(id);
+        ROOT_DB
+            .tree_names()
+            .iter()
+            .map(|name| bytes_to_string(name))
+            .filter(|name| name.starts_with(&prefix))
+            .map(|name| {
+                let tree = ROOT_DB
+                    .open_tree(name)
+                    .expect("unable to open database tree");
+                Program::new_from_db(id, tree)
+            })
+            .collect()
+    }
+
+    // Drops the state of an attachment, see attachments(). The program pin is
+    // owned by the program the attachment belongs to.
+    pub(crate) fn delete_attachment(&self) -> Result<(), anyhow::Error> {
+        ROOT_DB.drop_tree(self.get_data().db_tree.name())?;
+        Ok(())
+    }
+
+    pub(crate) fn is_detached(&self) -> bool {
+        self.get_data().get_detached().unwrap()
+    }
+
+    // Returns true for programs waiting for their interface to be created.
+    pub(crate) fn is_pending(&self) -> bool {
+        match self {
+            Program::Xdp(p) => p.get_pending().unwrap(),
+            Program::Tc(p) => p.get_pending().unwrap(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn set_pending(&mut self, pending: bool) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_pending(pending),
+            Program::Tc(p) => p.set_pending(pending),
+            _ => Err(BpfmanError::Error(
+                "only TC and XDP programs can wait for their interface".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn set_position(&mut self, pos: usize) -> Result<(), BpfmanError> {
         match self {
             Program::Xdp(p) => p.set_current_position(pos),
@@ -1352,10 +4135,26 @@ impl Program {
         if PathBuf::from(&path).exists() {
             fs::remove_file(path)?;
         }
+        self.delete_links()
+    }
+
+    // Removes the pins of the program's links, which detaches the program
+    // unless something else holds the links open.
+    pub(crate) fn delete_links(&self) -> Result<(), anyhow::Error> {
+        let id = self.get_data().get_id()?;
         let path = format!("{RTDIR_FS}/prog_{id}_link");
         if PathBuf::from(&path).exists() {
             fs::remove_file(path)?;
         }
+        // Programs attached in several places, like perf_event programs
+        // attached per CPU, pin one link per attachment.
+        let prefix = format!("prog_{id}_link_");
+        for entry in fs::read_dir(RTDIR_FS)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                fs::remove_file(entry.path())?;
+            }
+        }
         Ok(())
     }
 
@@ -1363,6 +4162,7 @@ impl Program {
         match self {
             Program::Xdp(p) => p.get_if_index(),
             Program::Tc(p) => p.get_if_index(),
+            Program::Netkit(p) => p.get_if_index(),
             _ => Err(BpfmanError::Error(
                 "cannot get if_index on programs other than TC or XDP".to_string(),
             )),
@@ -1373,6 +4173,7 @@ impl Program {
         match self {
             Program::Xdp(p) => p.set_if_index(if_index),
             Program::Tc(p) => p.set_if_index(if_index),
+            Program::Netkit(p) => p.set_if_index(if_index),
             _ => Err(BpfmanError::Error(
                 "cannot set if_index on programs other than TC or XDP".to_string(),
             )),
@@ -1383,12 +4184,190 @@ impl Program {
         match self {
             Program::Xdp(p) => p.get_iface(),
             Program::Tc(p) => p.get_iface(),
+            Program::Netkit(p) => p.get_iface(),
             _ => Err(BpfmanError::Error(
                 "cannot get interface on programs other than TC or XDP".to_string(),
             )),
         }
     }
 
+    pub(crate) fn set_if_name(&mut self, iface: String) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_iface(iface),
+            Program::Tc(p) => p.set_iface(iface),
+            _ => Err(BpfmanError::Error(
+                "cannot set interface on programs other than TC or XDP".to_string(),
+            )),
+        }
+    }
+
+    // Returns the network namespace of an XDP or TC program's interface, None
+    // for bpfman's own.
+    pub(crate) fn netns(&self) -> Result<Option<PathBuf>, BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.get_netns(),
+            Program::Tc(p) => p.get_netns(),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn set_netns(&mut self, netns: &Path) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_netns(netns),
+            Program::Tc(p) => p.set_netns(netns),
+            _ => Err(BpfmanError::Error(
+                "cannot set network namespace on programs other than TC or XDP".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn nsid(&self) -> Result<Option<u64>, BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.get_nsid(),
+            Program::Tc(p) => p.get_nsid(),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn set_nsid(&mut self, nsid: u64) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_nsid(nsid),
+            Program::Tc(p) => p.set_nsid(nsid),
+            _ => Err(BpfmanError::Error(
+                "cannot set network namespace on programs other than TC or XDP".to_string(),
+            )),
+        }
+    }
+
+    // Returns the cgroup of the programs attached to one, None for the others.
+    pub(crate) fn cgroup_path(&self) -> Result<Option<String>, BpfmanError> {
+        match self {
+            Program::CgroupSkb(p) => p.get_cgroup_path().map(Some),
+            Program::CgroupSockAddr(p) => p.get_cgroup_path().map(Some),
+            Program::SockOps(p) => p.get_cgroup_path().map(Some),
+            Program::CgroupDevice(p) => p.get_cgroup_path().map(Some),
+            Program::CgroupSysctl(p) => p.get_cgroup_path().map(Some),
+            Program::CgroupSockopt(p) => p.get_cgroup_path().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn set_cgroup_path(&mut self, cgroup_path: String) -> Result<(), BpfmanError> {
+        match self {
+            Program::CgroupSkb(p) => p.set_cgroup_path(cgroup_path),
+            Program::CgroupSockAddr(p) => p.set_cgroup_path(cgroup_path),
+            Program::SockOps(p) => p.set_cgroup_path(cgroup_path),
+            Program::CgroupDevice(p) => p.set_cgroup_path(cgroup_path),
+            Program::CgroupSysctl(p) => p.set_cgroup_path(cgroup_path),
+            Program::CgroupSockopt(p) => p.set_cgroup_path(cgroup_path),
+            _ => Err(BpfmanError::Error(
+                "cannot set cgroup path on programs that aren't attached to a cgroup".to_string(),
+            )),
+        }
+    }
+
+    // Returns the interface glob an XDP or TC program was loaded with, if any.
+    pub(crate) fn iface_glob(&self) -> Result<Option<String>, BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.get_iface_glob(),
+            Program::Tc(p) => p.get_iface_glob(),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn set_iface_glob(&mut self, glob: String) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_iface_glob(glob),
+            Program::Tc(p) => p.set_iface_glob(glob),
+            _ => Err(BpfmanError::Error(
+                "cannot set interface glob on programs other than TC or XDP".to_string(),
+            )),
+        }
+    }
+
+    // Returns true if the program should also be attached to interfaces that
+    // match its interface glob after it was loaded.
+    pub(crate) fn tracks_iface_glob(&self) -> Result<bool, BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.get_track_iface_glob(),
+            Program::Tc(p) => p.get_track_iface_glob(),
+            _ => Ok(false),
+        }
+    }
+
+    // Returns a new attachment of an XDP or TC program to iface, with the
+    // program's priority and proceed-on, see attachments().
+    pub(crate) fn new_attachment(&self, iface: String) -> Result<Program, BpfmanError> {
+        match self {
+            Program::Xdp(p) => {
+                let mut attachment = XdpProgram::new(
+                    ProgramData::new_pre_attach(),
+                    p.get_priority()?,
+                    iface,
+                    p.get_proceed_on()?,
+                )?;
+                if let Some(netns) = p.get_netns()? {
+                    attachment.set_netns(&netns)?;
+                }
+                Ok(Program::Xdp(attachment))
+            }
+            Program::Tc(p) => {
+                let mut attachment = TcProgram::new(
+                    ProgramData::new_pre_attach(),
+                    p.get_priority()?,
+                    iface,
+                    p.get_proceed_on()?,
+                    p.get_direction()?,
+                    Some(false),
+                )?;
+                if let Some(netns) = p.get_netns()? {
+                    attachment.set_netns(&netns)?;
+                }
+                Ok(Program::Tc(attachment))
+            }
+            _ => Err(BpfmanError::Error(
+                "cannot attach programs other than TC or XDP to further interfaces".to_string(),
+            )),
+        }
+    }
+
+    // Returns a program for the new bytecode in data that replaces an XDP or
+    // TC program, with the program's interface, priority and proceed-on.
+    pub(crate) fn new_replacement(&self, data: ProgramData) -> Result<Program, BpfmanError> {
+        let mut program = match self {
+            Program::Xdp(p) => {
+                let mut replacement =
+                    XdpProgram::new(data, p.get_priority()?, p.get_iface()?, p.get_proceed_on()?)?;
+                replacement.set_track_iface_glob(p.get_track_iface_glob()?)?;
+                Program::Xdp(replacement)
+            }
+            Program::Tc(p) => {
+                let mut replacement = TcProgram::new(
+                    data,
+                    p.get_priority()?,
+                    p.get_iface()?,
+                    p.get_proceed_on()?,
+                    p.get_direction()?,
+                    p.get_tcx()?,
+                )?;
+                replacement.set_track_iface_glob(p.get_track_iface_glob()?)?;
+                Program::Tc(replacement)
+            }
+            _ => {
+                return Err(BpfmanError::Error(
+                    "cannot replace programs other than TC or XDP".to_string(),
+                ))
+            }
+        };
+        if let Some(netns) = self.netns()? {
+            program.set_netns(&netns)?;
+        }
+        if let Some(glob) = self.iface_glob()? {
+            program.set_iface_glob(glob)?;
+        }
+        Ok(program)
+    }
+
     pub(crate) fn priority(&self) -> Result<i32, BpfmanError> {
         match self {
             Program::Xdp(p) => p.get_priority(),
@@ -1399,6 +4378,16 @@ impl Program {
         }
     }
 
+    pub(crate) fn set_priority(&mut self, priority: i32) -> Result<(), BpfmanError> {
+        match self {
+            Program::Xdp(p) => p.set_priority(priority),
+            Program::Tc(p) => p.set_priority(priority),
+            _ => Err(BpfmanError::Error(
+                "cannot set priority on programs other than TC or XDP".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn location(&self) -> Result<Location, BpfmanError> {
         match self {
             Program::Xdp(p) => p.data.get_location(),
@@ -1406,6 +4395,26 @@ impl Program {
             Program::Tc(p) => p.data.get_location(),
             Program::Kprobe(p) => p.data.get_location(),
             Program::Uprobe(p) => p.data.get_location(),
+            Program::Lsm(p) => p.data.get_location(),
+            Program::CgroupSkb(p) => p.data.get_location(),
+            Program::CgroupSockAddr(p) => p.data.get_location(),
+            Program::SockOps(p) => p.data.get_location(),
+            Program::CgroupDevice(p) => p.data.get_location(),
+            Program::CgroupSysctl(p) => p.data.get_location(),
+            Program::CgroupSockopt(p) => p.data.get_location(),
+            Program::SkMsg(p) => p.data.get_location(),
+            Program::SkSkb(p) => p.data.get_location(),
+            Program::FlowDissector(p) => p.data.get_location(),
+            Program::SkLookup(p) => p.data.get_location(),
+            Program::PerfEvent(p) => p.data.get_location(),
+            Program::RawTracepoint(p) => p.data.get_location(),
+            Program::SocketFilter(p) => p.data.get_location(),
+            Program::Netkit(p) => p.data.get_location(),
+            Program::Usdt(p) => p.data.get_location(),
+            Program::KprobeMulti(p) => p.data.get_location(),
+            Program::UprobeMulti(p) => p.data.get_location(),
+            Program::Iter(p) => p.data.get_location(),
+            Program::Extension(p) => p.data.get_location(),
             Program::Unsupported(_) => Err(BpfmanError::Error(
                 "cannot get location for unsupported programs".to_string(),
             )),
@@ -1426,6 +4435,26 @@ impl Program {
             Program::Tc(p) => p.get_data(),
             Program::Kprobe(p) => p.get_data(),
             Program::Uprobe(p) => p.get_data(),
+            Program::Lsm(p) => p.get_data(),
+            Program::CgroupSkb(p) => p.get_data(),
+            Program::CgroupSockAddr(p) => p.get_data(),
+            Program::SockOps(p) => p.get_data(),
+            Program::CgroupDevice(p) => p.get_data(),
+            Program::CgroupSysctl(p) => p.get_data(),
+            Program::CgroupSockopt(p) => p.get_data(),
+            Program::SkMsg(p) => p.get_data(),
+            Program::SkSkb(p) => p.get_data(),
+            Program::FlowDissector(p) => p.get_data(),
+            Program::SkLookup(p) => p.get_data(),
+            Program::PerfEvent(p) => p.get_data(),
+            Program::RawTracepoint(p) => p.get_data(),
+            Program::SocketFilter(p) => p.get_data(),
+            Program::Netkit(p) => p.get_data(),
+            Program::Usdt(p) => p.get_data(),
+            Program::KprobeMulti(p) => p.get_data(),
+            Program::UprobeMulti(p) => p.get_data(),
+            Program::Iter(p) => p.get_data(),
+            Program::Extension(p) => p.get_data(),
             Program::Unsupported(p) => p,
         }
     }
@@ -1441,16 +4470,66 @@ impl Program {
         match data.get_kind()? {
             Some(p) => match p {
                 ProgramType::Xdp => Ok(Program::Xdp(XdpProgram { data })),
-                ProgramType::Tc => Ok(Program::Tc(TcProgram { data })),
+                // netkit programs are sched_cls programs too
+                ProgramType::Tc => {
+                    if data.db_tree.get("netkit_iface").unwrap().is_some() {
+                        Ok(Program::Netkit(NetkitProgram { data }))
+                    } else {
+                        Ok(Program::Tc(TcProgram { data }))
+                    }
+                }
                 ProgramType::Tracepoint => Ok(Program::Tracepoint(TracepointProgram { data })),
                 // kernel does not distinguish between kprobe and uprobe program types
                 ProgramType::Probe => {
-                    if data.db_tree.get("uprobe_offset").unwrap().is_some() {
+                    if data.db_tree.get("usdt_provider").unwrap().is_some() {
+                        Ok(Program::Usdt(UsdtProgram { data }))
+                    } else if data.db_tree.get("kprobe_multi_retprobe").unwrap().is_some() {
+                        Ok(Program::KprobeMulti(KprobeMultiProgram { data }))
+                    } else if data.db_tree.get("uprobe_multi_retprobe").unwrap().is_some() {
+                        Ok(Program::UprobeMulti(UprobeMultiProgram { data }))
+                    } else if data.db_tree.get("uprobe_offset").unwrap().is_some() {
                         Ok(Program::Uprobe(UprobeProgram { data }))
                     } else {
                         Ok(Program::Kprobe(KprobeProgram { data }))
                     }
                 }
+                ProgramType::Lsm => Ok(Program::Lsm(LsmProgram { data })),
+                ProgramType::CgroupSkb => Ok(Program::CgroupSkb(CgroupSkbProgram { data })),
+                ProgramType::CgroupSockAddr => {
+                    Ok(Program::CgroupSockAddr(CgroupSockAddrProgram { data }))
+                }
+                ProgramType::SockOps => Ok(Program::SockOps(SockOpsProgram { data })),
+                ProgramType::CgroupDevice => {
+                    Ok(Program::CgroupDevice(CgroupDeviceProgram { data }))
+                }
+                ProgramType::CgroupSysctl => {
+                    Ok(Program::CgroupSysctl(CgroupSysctlProgram { data }))
+                }
+                ProgramType::CgroupSockopt => {
+                    Ok(Program::CgroupSockopt(CgroupSockoptProgram { data }))
+                }
+                ProgramType::SkMsg => Ok(Program::SkMsg(SkMsgProgram { data })),
+                ProgramType::SkSkb => Ok(Program::SkSkb(SkSkbProgram { data })),
+                ProgramType::FlowDissector => {
+                    Ok(Program::FlowDissector(FlowDissectorProgram { data }))
+                }
+                ProgramType::SkLookup => Ok(Program::SkLookup(SkLookupProgram { data })),
+                ProgramType::PerfEvent => Ok(Program::PerfEvent(PerfEventProgram { data })),
+                ProgramType::RawTracepoint => {
+                    Ok(Program::RawTracepoint(RawTracepointProgram { data }))
+                }
+                // tp_btf and iterator programs are both tracing programs
+                ProgramType::Tracing => {
+                    if data.db_tree.get("iter_type").unwrap().is_some() {
+                        Ok(Program::Iter(IterProgram { data }))
+                    } else {
+                        Ok(Program::RawTracepoint(RawTracepointProgram { data }))
+                    }
+                }
+                ProgramType::SocketFilter => {
+                    Ok(Program::SocketFilter(SocketFilterProgram { data }))
+                }
+                ProgramType::Ext => Ok(Program::Extension(ExtensionProgram { data })),
                 _ => Err(BpfmanError::Error("Unsupported program type".to_string())),
             },
             None => Err(BpfmanError::Error("Unsupported program type".to_string())),
@@ -1466,3 +4545,17 @@ impl Program {
 pub(crate) struct BpfMap {
     pub(crate) used_by: Vec<u32>,
 }
+
+// An XDP or TC program attached to further interfaces keeps the state of each
+// extra attachment in a tree of its own, named after the program and the hook
+// it's attached to.
+pub(crate) fn attachment_tree_prefix(id: u32) -> String {
+    format!("attachment_{id}_")
+}
+
+pub(crate) fn attachment_tree_name(id: u32, if_index: u32, direction: Option<Direction>) -> String {
+    match direction {
+        Some(direction) => format!("{}{if_index}_{direction}", attachment_tree_prefix(id)),
+        None => format!("{}{if_index}", attachment_tree_prefix(id)),
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Containers are looked up by the id their runtime gave them. The id is part
+// of the cgroup of every process in the container, e.g.
+// /kubepods.slice/.../cri-containerd-<id>.scope or /docker/<id>, so the
+// processes can be found through /proc without talking to the runtime.
+
+use std::{fs, os::unix::fs::MetadataExt, path::Path};
+
+use log::debug;
+
+use crate::errors::BpfmanError;
+
+// Prefixes Kubernetes puts in front of container ids in pod statuses.
+const RUNTIME_PREFIXES: [&str; 3] = ["containerd://", "cri-o://", "docker://"];
+const POD_PREFIX: &str = "pod://";
+const CGROUP_MOUNT: &str = "/sys/fs/cgroup";
+
+// Returns the host PID of the first process of the container with the given
+// id. Container ids may be shortened, like the ones docker prints, but must
+// be at least 12 characters long to avoid matching unrelated cgroups.
+pub(crate) fn container_pid(container_id: &str) -> Result<i32, BpfmanError> {
+    let id = RUNTIME_PREFIXES
+        .iter()
+        .find_map(|prefix| container_id.strip_prefix(prefix))
+        .unwrap_or(container_id);
+    if id.len() < 12 || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(BpfmanError::InvalidContainerId(container_id.to_string()));
+    }
+
+    let pid = proc_pids()?
+        .filter(|pid| {
+            fs::read_to_string(format!("/proc/{pid}/cgroup"))
+                .is_ok_and(|cgroup| cgroup.contains(id))
+        })
+        .min()
+        .ok_or_else(|| BpfmanError::ContainerNotFound(container_id.to_string()))?;
+    debug!("container {container_id} has pid {pid}");
+    Ok(pid)
+}
+
+// Returns the host path of path inside the filesystem of the container whose
+// first process is container_pid.
+pub(crate) fn container_path(container_pid: i32, path: &str) -> Result<String, BpfmanError> {
+    if !Path::new(path).is_absolute() {
+        return Err(BpfmanError::Error(format!(
+            "{path} must be an absolute path to be found in a container"
+        )));
+    }
+    Ok(format!("/proc/{container_pid}/root{path}"))
+}
+
+// Returns the host PID of the process with PID ns_pid in the PID namespace of
+// the container whose first process is container_pid.
+pub(crate) fn host_pid(container_pid: i32, ns_pid: i32) -> Result<i32, BpfmanError> {
+    let pid_ns = |pid: i32| {
+        fs::metadata(format!("/proc/{pid}/ns/pid"))
+            .map(|m| m.ino())
+            .ok()
+    };
+    let container_ns = pid_ns(container_pid)
+        .ok_or_else(|| BpfmanError::Error(format!("container process {container_pid} is gone")))?;
+
+    proc_pids()?
+        .find(|pid| pid_ns(*pid) == Some(container_ns) && ns_pids(*pid).last() == Some(&ns_pid))
+        .ok_or_else(|| {
+            BpfmanError::Error(format!(
+                "no process with pid {ns_pid} in the container of process {container_pid}"
+            ))
+        })
+}
+
+// Returns the PIDs of a process in each PID namespace it is in, outermost
+// first, from the NSpid line of its status.
+fn ns_pids(pid: i32) -> Vec<i32> {
+    fs::read_to_string(format!("/proc/{pid}/status"))
+        .ok()
+        .and_then(|status| {
+            status.lines().find_map(|line| {
+                line.strip_prefix("NSpid:").map(|pids| {
+                    pids.split_whitespace()
+                        .filter_map(|p| p.parse().ok())
+                        .collect()
+                })
+            })
+        })
+        .unwrap_or_default()
+}
+
+fn proc_pids() -> Result<impl Iterator<Item = i32>, BpfmanError> {
+    Ok(fs::read_dir("/proc")?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str().and_then(|n| n.parse().ok())))
+}
+
+// Returns the cgroup v2 path of a container or of a pod. target is either a
+// container id, optionally with its runtime prefix as found in pod statuses
+// like "containerd://<id>", or the UID of a pod prefixed with "pod://".
+pub(crate) fn cgroup_path(target: &str) -> Result<String, BpfmanError> {
+    let cgroup = match target.strip_prefix(POD_PREFIX) {
+        Some(uid) => pod_cgroup(uid)?,
+        None => process_cgroup(container_pid(target)?)
+            .ok_or_else(|| BpfmanError::ContainerNotFound(target.to_string()))?,
+    };
+    let path = format!("{CGROUP_MOUNT}{cgroup}");
+    debug!("{target} is in cgroup {path}");
+    Ok(path)
+}
+
+// Returns the cgroup of a pod, the parent of the cgroups of its containers.
+// Depending on the cgroup driver the pod's cgroup is named pod<uid> or
+// kubepods-<qos>-pod<uid>.slice with the dashes of the UID replaced.
+fn pod_cgroup(uid: &str) -> Result<String, BpfmanError> {
+    let names = [format!("pod{uid}"), format!("pod{}", uid.replace('-', "_"))];
+    proc_pids()?
+        .filter_map(process_cgroup)
+        .find_map(|cgroup| {
+            let end = cgroup.split('/').position(|component| {
+                names
+                    .iter()
+                    .any(|n| component == n || component.ends_with(&format!("-{n}.slice")))
+            })?;
+            Some(
+                cgroup
+                    .split('/')
+                    .take(end + 1)
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            )
+        })
+        .ok_or_else(|| BpfmanError::ContainerNotFound(format!("{POD_PREFIX}{uid}")))
+}
+
+// Returns the cgroup v2 path of a process, relative to the cgroup mount.
+fn process_cgroup(pid: i32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{pid}/cgroup"))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("0::").map(|c| c.to_string()))
+}
@@ -39,6 +39,16 @@ pub enum BpfmanError {
         image_prog_name: String,
         provided_prog_name: String,
     },
+    #[error("Load denied by policy: {0}")]
+    PolicyDenied(String),
+    #[error("Load denied by the admission hook: {0}")]
+    AdmissionDenied(String),
+    #[error("Bytecode with sha256 digest {0} is not in the bytecode allowlist")]
+    BytecodeNotAllowed(String),
+    #[error("The verifier rejected the program: {error}")]
+    VerifierRejected { error: String, log: String },
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
     #[error("Unable to delete program {0}")]
     BpfmanProgramDeleteError(#[source] anyhow::Error),
     #[error(transparent)]
@@ -54,6 +64,54 @@ pub enum BpfmanError {
         program_type: String,
         container_pid: i32,
     },
+    #[error("{0} is not a valid container id")]
+    InvalidContainerId(String),
+    #[error("No running container with id {0}")]
+    ContainerNotFound(String),
     #[error("{0}: {1}")]
     DatabaseError(String, String),
+    #[error(transparent)]
+    BtfError(#[from] aya::BtfError),
+    #[error(transparent)]
+    BpfMapError(#[from] aya::maps::MapError),
+    #[error("Flow dissector program {id} is already attached to network namespace {netns}. Only one flow dissector can be attached per network namespace")]
+    FlowDissectorAlreadyAttached { netns: String, id: u32 },
+    #[error("netfilter programs require Linux 6.4 or newer")]
+    NetfilterKernelTooOld,
+    #[error("tcx attachment of TC programs requires Linux 6.6 or newer")]
+    TcxKernelTooOld,
+    #[error("netkit programs require Linux 6.7 or newer")]
+    NetkitKernelTooOld,
+    #[error("{program} programs require Linux {major}.{minor} or newer")]
+    KernelTooOld {
+        program: String,
+        major: u8,
+        minor: u8,
+    },
+    #[error("{program} programs require CONFIG_DEBUG_INFO_BTF; /sys/kernel/btf/vmlinux not found")]
+    KernelBtfNotFound { program: String },
+    #[error("USDT probe {provider}:{name} not found in {target}")]
+    UsdtProbeNotFound {
+        provider: String,
+        name: String,
+        target: String,
+    },
+    #[error("no kernel functions match {pattern}")]
+    NoMatchingKernelFunctions { pattern: String },
+    #[error("no interfaces match {pattern}")]
+    NoMatchingInterfaces { pattern: String },
+    #[error("interface globs are only supported for programs attached through a dispatcher")]
+    IfaceGlobNotSupported,
+    #[error("BPF LSM is not enabled on this system. Ensure the kernel is built with CONFIG_BPF_LSM=y and \"bpf\" is included in the lsm= boot parameter")]
+    BpfLsmNotEnabled,
+    #[error("{0} programs can't be loaded detached or detached after loading yet")]
+    DetachNotSupported(String),
+    #[error("{0} programs are attached through a dispatcher and can't be loaded detached")]
+    LoadDetachedNotSupported(String),
+    #[error("cgroup/sock_addr program {name} is in section {section} and can't be attached to {attach_point}")]
+    SockAddrAttachPointMismatch {
+        name: String,
+        section: String,
+        attach_point: String,
+    },
 }
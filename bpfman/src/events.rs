@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Loads, unloads, replacements, rollbacks and dispatcher rebuilds are recorded
+// as events in the database, failed ones included, so that they can be looked
+// back on after an incident, across restarts of bpfman. Events are keyed by a
+// sequence number, the oldest ones are removed once there are more than the
+// configured number of them.
+
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bpfman_api::ProgramType;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sled::Tree;
+
+use crate::{command::Program, errors::BpfmanError, ROOT_DB};
+
+pub(crate) const EVENTS_TREE: &str = "events";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EventKind {
+    Load,
+    Unload,
+    Replace,
+    Rollback,
+    DispatcherRebuild,
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            EventKind::Load => "load",
+            EventKind::Unload => "unload",
+            EventKind::Replace => "replace",
+            EventKind::Rollback => "rollback",
+            EventKind::DispatcherRebuild => "dispatcher_rebuild",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+// A change of the programs or dispatchers. timestamp is in milliseconds since
+// the epoch, error is set if the change failed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Event {
+    pub(crate) timestamp: u64,
+    pub(crate) kind: EventKind,
+    pub(crate) program_id: Option<u32>,
+    pub(crate) uuid: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) details: String,
+    pub(crate) error: Option<String>,
+}
+
+impl Event {
+    pub(crate) fn new(kind: EventKind, details: String) -> Self {
+        Event {
+            timestamp: now(),
+            kind,
+            program_id: None,
+            uuid: None,
+            name: None,
+            details,
+            error: None,
+        }
+    }
+
+    // An event of program, whose id is only known once it's loaded.
+    pub(crate) fn of(kind: EventKind, program: &Program) -> Self {
+        let data = program.get_data();
+        let details = match program.if_name() {
+            Ok(iface) => format!("{} program on {iface}", program.kind()),
+            Err(_) => format!("{} program", program.kind()),
+        };
+        Event {
+            program_id: data.get_id().ok(),
+            uuid: data.get_uuid().ok().flatten(),
+            name: data.get_name().ok(),
+            ..Event::new(kind, details)
+        }
+    }
+
+    pub(crate) fn dispatcher_rebuild<T>(
+        program_type: ProgramType,
+        if_name: &str,
+        revision: u32,
+        result: &Result<T, BpfmanError>,
+    ) -> Self {
+        Event::new(
+            EventKind::DispatcherRebuild,
+            format!("{program_type} dispatcher on {if_name}, revision {revision}"),
+        )
+        .result(result)
+    }
+
+    pub(crate) fn result<T, E: fmt::Display>(mut self, result: &Result<T, E>) -> Self {
+        if let Err(e) = result {
+            self.error = Some(e.to_string());
+        }
+        self
+    }
+}
+
+// Events recorded in [since, until], of the program with uuid, of which the
+// last limit ones are returned if limit isn't 0.
+#[derive(Debug, Default)]
+pub(crate) struct EventFilter {
+    pub(crate) since: Option<u64>,
+    pub(crate) until: Option<u64>,
+    pub(crate) uuid: Option<String>,
+    pub(crate) limit: usize,
+}
+
+impl EventFilter {
+    fn matches(&self, event: &Event) -> bool {
+        self.since.map_or(true, |since| event.timestamp >= since)
+            && self.until.map_or(true, |until| event.timestamp <= until)
+            && self
+                .uuid
+                .as_ref()
+                .map_or(true, |uuid| event.uuid.as_ref() == Some(uuid))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct EventLog {
+    tree: Tree,
+    max_events: usize,
+}
+
+impl EventLog {
+    pub(crate) fn new(tree: Tree, max_events: usize) -> Self {
+        EventLog { tree, max_events }
+    }
+
+    // Events are only recorded by the BpfManager, so sequence numbers can't be
+    // taken twice. Failing to record an event doesn't fail the change.
+    pub(crate) fn record(&self, event: Event) {
+        if let Err(e) = self.insert(&event) {
+            warn!("Unable to record {} event: {e}", event.kind);
+        }
+    }
+
+    // Records the outcome of loading programs, which are described as they
+    // were requested if the load failed.
+    pub(crate) fn record_loads(
+        &self,
+        kind: EventKind,
+        requested: Vec<Event>,
+        result: Result<&[Program], &BpfmanError>,
+    ) {
+        match result {
+            Ok(programs) => programs
+                .iter()
+                .for_each(|p| self.record(Event::of(kind, p))),
+            Err(e) => requested
+                .into_iter()
+                .for_each(|event| self.record(event.result::<(), _>(&Err(e)))),
+        }
+    }
+
+    fn insert(&self, event: &Event) -> Result<(), BpfmanError> {
+        let first = sequence(self.tree.first().map_err(db_error)?);
+        let next = sequence(self.tree.last().map_err(db_error)?).map_or(0, |last| last + 1);
+        let value = serde_json::to_vec(event)
+            .map_err(|e| BpfmanError::Error(format!("unable to serialize event: {e}")))?;
+        self.tree
+            .insert(next.to_be_bytes(), value)
+            .map_err(db_error)?;
+
+        // Keys are contiguous, the oldest events are removed down to
+        // max_events.
+        if let Some(first) = first {
+            for _ in first..(next + 1).saturating_sub(self.max_events as u64) {
+                self.tree.pop_min().map_err(db_error)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn query(&self, filter: &EventFilter) -> Result<Vec<Event>, BpfmanError> {
+        let mut events = Vec::new();
+        for entry in self.tree.iter() {
+            let (_, value) = entry.map_err(db_error)?;
+            match serde_json::from_slice::<Event>(&value) {
+                Ok(event) if filter.matches(&event) => events.push(event),
+                Ok(_) => {}
+                Err(e) => warn!("Ignoring unreadable event: {e}"),
+            }
+        }
+        if filter.limit > 0 && events.len() > filter.limit {
+            events.drain(..events.len() - filter.limit);
+        }
+        Ok(events)
+    }
+}
+
+// Returns the events in the database matching filter, oldest first.
+pub(crate) fn events(filter: &EventFilter) -> Result<Vec<Event>, BpfmanError> {
+    let tree = ROOT_DB.open_tree(EVENTS_TREE).map_err(db_error)?;
+    EventLog::new(tree, 0).query(filter)
+}
+
+fn sequence(entry: Option<(sled::IVec, sled::IVec)>) -> Option<u64> {
+    entry.and_then(|(key, _)| key.as_ref().try_into().ok().map(u64::from_be_bytes))
+}
+
+fn db_error(e: sled::Error) -> BpfmanError {
+    BpfmanError::DatabaseError("unable to access events".to_string(), e.to_string())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(timestamp: u64, uuid: &str) -> Event {
+        Event {
+            timestamp,
+            uuid: Some(uuid.to_string()),
+            ..Event::new(EventKind::Load, "xdp program on eth0".to_string())
+        }
+    }
+
+    #[test]
+    fn test_event_log_keeps_latest_events() {
+        let database = sled::Config::new().temporary(true).open().unwrap();
+        let log = EventLog::new(database.open_tree(EVENTS_TREE).unwrap(), 3);
+        for timestamp in 1..=5 {
+            log.record(event(timestamp, "a"));
+        }
+        let timestamps: Vec<u64> = log
+            .query(&EventFilter::default())
+            .unwrap()
+            .iter()
+            .map(|e| e.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_event_log_query() {
+        let database = sled::Config::new().temporary(true).open().unwrap();
+        let log = EventLog::new(database.open_tree(EVENTS_TREE).unwrap(), 100);
+        for (timestamp, uuid) in [(10, "a"), (20, "b"), (30, "a"), (40, "a")] {
+            log.record(event(timestamp, uuid));
+        }
+        let query = |filter: EventFilter| -> Vec<u64> {
+            log.query(&filter)
+                .unwrap()
+                .iter()
+                .map(|e| e.timestamp)
+                .collect()
+        };
+        assert_eq!(
+            query(EventFilter {
+                since: Some(20),
+                until: Some(30),
+                ..Default::default()
+            }),
+            vec![20, 30]
+        );
+        assert_eq!(
+            query(EventFilter {
+                uuid: Some("a".to_string()),
+                ..Default::default()
+            }),
+            vec![10, 30, 40]
+        );
+        assert_eq!(
+            query(EventFilter {
+                uuid: Some("a".to_string()),
+                limit: 2,
+                ..Default::default()
+            }),
+            vec![30, 40]
+        );
+    }
+}
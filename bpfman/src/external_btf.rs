@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// CO-RE programs are relocated against the BTF of the kernel, which aya reads
+// from /sys/kernel/btf/vmlinux. Kernels built without CONFIG_DEBUG_INFO_BTF,
+// like those of older LTS distributions, don't have it, so the BTF of such a
+// kernel can be given in a file of its own, like the ones of BTFHub. Program
+// types which find their attach target in the BTF of the kernel, like lsm,
+// still require the kernel to have its own.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use aya::{BpfLoader, Btf, Endianness};
+use bpfman_api::config::BtfConfig;
+use log::{info, warn};
+
+use crate::{errors::BpfmanError, utils::kernel_release};
+
+const KERNEL_BTF_PATH: &str = "/sys/kernel/btf/vmlinux";
+
+static EXTERNAL_BTF: OnceLock<Option<Btf>> = OnceLock::new();
+
+// Reads the BTF of the running kernel from the directory of the configuration
+// if the kernel has none of its own. Programs are loaded as if there was no
+// configuration if it can't be read.
+pub(crate) fn init_external_btf(config: Option<&BtfConfig>) {
+    let btf = config
+        .filter(|_| !Path::new(KERNEL_BTF_PATH).exists())
+        .and_then(|config| match read_external_btf(Path::new(&config.path)) {
+            Ok(btf) => Some(btf),
+            Err(e) => {
+                warn!("Unable to use the BTF of {}: {e}", config.path);
+                None
+            }
+        });
+    let _ = EXTERNAL_BTF.set(btf);
+}
+
+// Has bpf relocate CO-RE programs against the external BTF, if one is used.
+pub(crate) fn set_external_btf(bpf: &mut BpfLoader) {
+    if let Some(btf) = EXTERNAL_BTF.get().and_then(Option::as_ref) {
+        bpf.btf(Some(btf));
+    }
+}
+
+fn read_external_btf(dir: &Path) -> Result<Btf, BpfmanError> {
+    let release = kernel_release()?;
+    let path = find_btf(dir, &format!("{release}.btf")).ok_or_else(|| {
+        BpfmanError::Error(format!("no BTF of kernel {release} in {}", dir.display()))
+    })?;
+    let btf = Btf::parse_file(&path, Endianness::default())?;
+    info!("Using the BTF of kernel {release} in {}", path.display());
+    Ok(btf)
+}
+
+// Looks for the file called name in dir, then in the directories under it,
+// where the BTFHub archive keeps files by distribution, version and
+// architecture.
+fn find_btf(dir: &Path, name: &str) -> Option<PathBuf> {
+    let path = dir.join(name);
+    if path.is_file() {
+        return Some(path);
+    }
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs.iter().find_map(|dir| find_btf(dir, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_btf() {
+        let dir = tempfile::tempdir().unwrap();
+        let release_dir = dir.path().join("ubuntu/20.04/x86_64");
+        fs::create_dir_all(&release_dir).unwrap();
+        fs::write(release_dir.join("5.4.0-150-generic.btf"), b"").unwrap();
+        fs::write(dir.path().join("4.18.0-513.el8.x86_64.btf"), b"").unwrap();
+
+        assert_eq!(
+            find_btf(dir.path(), "5.4.0-150-generic.btf"),
+            Some(release_dir.join("5.4.0-150-generic.btf"))
+        );
+        assert_eq!(
+            find_btf(dir.path(), "4.18.0-513.el8.x86_64.btf"),
+            Some(dir.path().join("4.18.0-513.el8.x86_64.btf"))
+        );
+        assert_eq!(find_btf(dir.path(), "6.1.0-13-amd64.btf"), None);
+    }
+}
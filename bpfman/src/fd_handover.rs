@@ -0,0 +1,507 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use std::{
+    fs,
+    io::{IoSlice, IoSliceMut, Read, Write},
+    os::{
+        fd::{AsFd, AsRawFd, FromRawFd, OwnedFd, RawFd},
+        unix::{fs::MetadataExt, net::UnixStream},
+    },
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context};
+use aya::programs::SocketFilter;
+use bpfman_api::{
+    util::directories::{RTDIR_FS, RTPATH_BPFMAN_FD_SOCKET, RTPATH_BPFMAN_MAP_FD_SOCKET},
+    ProgramType,
+};
+use log::{debug, error, info, warn};
+use nix::sys::socket::{
+    getsockopt, recvmsg, sendmsg, sockopt::PeerCredentials, ControlMessage, ControlMessageOwned,
+    MsgFlags, UnixCredentials,
+};
+use tokio::{fs::remove_file, net::UnixListener, select, sync::broadcast, task::JoinHandle};
+
+use crate::{
+    authz::Authorizer,
+    command::Program,
+    maps::{map_fd, map_path, PinnedMap},
+    utils::{set_file_permissions, SOCK_MODE},
+    ROOT_DB,
+};
+
+// Socket filter programs are attached by clients to their own sockets with
+// SO_ATTACH_BPF, so bpfman only loads and pins them and hands the program fd
+// out on request.
+//
+// A client connects to RTPATH_BPFMAN_FD_SOCKET and writes the program id as a
+// native endian u32. bpfman replies with a single status byte: REPLY_OK with
+// the program fd attached as SCM_RIGHTS ancillary data, or REPLY_ERR followed
+// by a utf-8 error message. The connection is closed after the reply.
+//
+// Maps pinned by bpfman are handed out the same way over
+// RTPATH_BPFMAN_MAP_FD_SOCKET, so clients can poll and mmap them without
+// access to bpffs. The client writes the program id as a native endian u32, a
+// flags byte, the length of the map name as a byte and the name the map is
+// pinned under. The fd only allows reading the map unless the MAP_READ_WRITE
+// flag is set. Clients only get fds of maps they could open the pins of
+// themselves: the user and groups of their process, as reported by the
+// kernel, are checked against the owner, group and mode of the pinned map,
+// which are those of the map permissions of the program, and writing is
+// checked for read-write fds.
+//
+// With [authorization] configured, clients are also limited to the
+// operations, program types and interfaces of their role, as for the RPCs:
+// program fds are handed out for the "program_fd" operation, map fds for
+// "map_fd" and AF_XDP sockets registered for "register_xsk".
+//
+// AF_XDP socket owners can also have bpfman store their socket in an xskmap
+// instead of handling the map themselves. With the MAP_REGISTER_XSK flag set
+// the request is followed by the queue the socket is bound to as a native
+// endian u32, and the socket fd is attached to it as SCM_RIGHTS ancillary
+// data, which requires write access to the map. bpfman replies with REPLY_OK
+// alone once the socket is in the map.
+//
+// Clients have REQUEST_TIMEOUT to send their request.
+const REPLY_OK: u8 = 0;
+const REPLY_ERR: u8 = 1;
+
+const MAP_READ_WRITE: u8 = 1;
+const MAP_REGISTER_XSK: u8 = 2;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Reads a request from the client and returns the fd to hand over, if any.
+type FdRequest = fn(&UnixStream, &Peer) -> anyhow::Result<Option<OwnedFd>>;
+
+// The process at the other end of a connection, as reported by the kernel.
+struct Peer {
+    uid: u32,
+    gids: Vec<u32>,
+    authorizer: Option<Arc<Authorizer>>,
+}
+
+impl Peer {
+    fn of(stream: &UnixStream, authorizer: Option<Arc<Authorizer>>) -> anyhow::Result<Self> {
+        let cred: UnixCredentials =
+            getsockopt(stream, PeerCredentials).context("unable to get peer credentials")?;
+        let mut gids = vec![cred.gid()];
+        gids.extend(supplementary_groups(cred.pid()));
+        Ok(Self {
+            uid: cred.uid(),
+            gids,
+            authorizer,
+        })
+    }
+
+    // Denies the request unless the role of the client allows operation on
+    // program.
+    fn authorize(&self, operation: &str, program: &Program) -> anyhow::Result<()> {
+        let Some(authorizer) = &self.authorizer else {
+            return Ok(());
+        };
+        authorizer
+            .authorize_uid(self.uid, operation)
+            .and_then(|grant| grant.check_program(program))
+            .map_err(|status| anyhow!("{}", status.message()))
+    }
+
+    // Denies access to the map pinned at path unless the mode of the pin
+    // allows it to the client, as it would if the client opened it.
+    fn check_map_access(&self, path: &Path, write: bool) -> anyhow::Result<()> {
+        let meta = fs::metadata(path)
+            .with_context(|| format!("unable to read permissions of {}", path.display()))?;
+        if !may_access(self, meta.uid(), meta.gid(), meta.mode(), write) {
+            bail!(
+                "permission denied: user {} may not {} map {}",
+                self.uid,
+                if write { "write" } else { "read" },
+                path.file_name().unwrap_or_default().to_string_lossy()
+            );
+        }
+        Ok(())
+    }
+}
+
+// The supplementary groups of the process with pid, from its status file.
+fn supplementary_groups(pid: i32) -> Vec<u32> {
+    fs::read_to_string(format!("/proc/{pid}/status"))
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("Groups:"))
+                .map(|groups| {
+                    groups
+                        .split_whitespace()
+                        .filter_map(|gid| gid.parse().ok())
+                        .collect()
+                })
+        })
+        .unwrap_or_default()
+}
+
+// Whether peer may read, and write if write is set, a file of the owner, group
+// and mode, by the owner, group or other bits of the mode as they apply to it.
+// root may access any file.
+fn may_access(peer: &Peer, uid: u32, gid: u32, mode: u32, write: bool) -> bool {
+    if peer.uid == 0 {
+        return true;
+    }
+    let bits = if peer.uid == uid {
+        mode >> 6
+    } else if peer.gids.contains(&gid) {
+        mode >> 3
+    } else {
+        mode
+    };
+    let wanted = if write { 0o6 } else { 0o4 };
+    bits & wanted == wanted
+}
+
+pub(crate) async fn serve_fd_handover(
+    authorizer: Option<Arc<Authorizer>>,
+    shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    serve(
+        RTPATH_BPFMAN_FD_SOCKET,
+        program_fd,
+        authorizer,
+        shutdown_channel,
+    )
+    .await
+}
+
+pub(crate) async fn serve_map_fd_handover(
+    authorizer: Option<Arc<Authorizer>>,
+    shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    serve(
+        RTPATH_BPFMAN_MAP_FD_SOCKET,
+        pinned_map_fd,
+        authorizer,
+        shutdown_channel,
+    )
+    .await
+}
+
+async fn serve(
+    path: &'static str,
+    request: FdRequest,
+    authorizer: Option<Arc<Authorizer>>,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    if Path::new(path).exists() {
+        // Attempt to remove the socket, since bind fails if it exists
+        remove_file(path).await?;
+    }
+
+    let listener = UnixListener::bind(path)?;
+    // Always set the file permissions of our listening socket.
+    set_file_permissions(path, SOCK_MODE).await;
+
+    Ok(tokio::spawn(async move {
+        info!("Listening on {path}");
+        loop {
+            select! {
+                res = listener.accept() => match res.and_then(|(stream, _)| stream.into_std()) {
+                    Ok(stream) => {
+                        let authorizer = authorizer.clone();
+                        tokio::task::spawn_blocking(move || handle_client(stream, request, authorizer));
+                    }
+                    Err(e) => warn!("Unable to accept fd handover connection: {e}"),
+                },
+                res = shutdown_channel.recv() => {
+                    match res {
+                        Ok(()) => debug!("Fd handover socket: Received shutdown signal"),
+                        Err(e) => error!("Error receiving shutdown signal {:?}", e),
+                    };
+                    break;
+                }
+            }
+        }
+        info!("Shutdown fd handover handler {path}");
+    }))
+}
+
+fn handle_client(stream: UnixStream, request: FdRequest, authorizer: Option<Arc<Authorizer>>) {
+    // The tokio stream is non-blocking, this runs on a blocking thread instead,
+    // which clients that don't send their request only hold until the timeout.
+    if let Err(e) = stream
+        .set_nonblocking(false)
+        .and_then(|_| stream.set_read_timeout(Some(REQUEST_TIMEOUT)))
+        .and_then(|_| stream.set_write_timeout(Some(REQUEST_TIMEOUT)))
+    {
+        warn!("Unable to set up fd handover connection: {e}");
+        return;
+    }
+
+    let res = Peer::of(&stream, authorizer)
+        .and_then(|peer| request(&stream, &peer))
+        .and_then(|fd| send_reply(&stream, fd));
+    if let Err(e) = res {
+        debug!("Fd handover request failed: {e:#}");
+        let mut reply = vec![REPLY_ERR];
+        reply.extend_from_slice(format!("{e:#}").as_bytes());
+        if let Err(e) = (&stream).write_all(&reply) {
+            warn!("Unable to send fd handover error reply: {e}");
+        }
+    }
+}
+
+fn send_reply(mut stream: &UnixStream, fd: Option<OwnedFd>) -> anyhow::Result<()> {
+    let Some(fd) = fd else {
+        return stream
+            .write_all(&[REPLY_OK])
+            .context("unable to send reply");
+    };
+    let fds = [fd.as_raw_fd()];
+    sendmsg::<()>(
+        stream.as_raw_fd(),
+        &[IoSlice::new(&[REPLY_OK])],
+        &[ControlMessage::ScmRights(&fds)],
+        MsgFlags::empty(),
+        None,
+    )
+    .context("unable to send fd")?;
+    Ok(())
+}
+
+fn read_program_id(mut stream: &UnixStream) -> anyhow::Result<u32> {
+    let mut buf = [0u8; 4];
+    stream
+        .read_exact(&mut buf)
+        .context("unable to read program id")?;
+    Ok(u32::from_ne_bytes(buf))
+}
+
+// Reads the program id like read_program_id(), along with the fds the client
+// attached to the request.
+fn recv_program_id(mut stream: &UnixStream) -> anyhow::Result<(u32, Vec<OwnedFd>)> {
+    let mut buf = [0u8; 4];
+    let mut cmsg_buf = nix::cmsg_space!([RawFd; 1]);
+    let (len, fds) = {
+        let mut iov = [IoSliceMut::new(&mut buf)];
+        let msg = recvmsg::<()>(
+            stream.as_raw_fd(),
+            &mut iov,
+            Some(&mut cmsg_buf),
+            MsgFlags::MSG_CMSG_CLOEXEC,
+        )
+        .context("unable to read program id")?;
+        let fds: Vec<OwnedFd> = msg
+            .cmsgs()
+            .flat_map(|cmsg| match cmsg {
+                ControlMessageOwned::ScmRights(fds) => fds,
+                _ => vec![],
+            })
+            // SAFETY: the fds were just received and aren't owned elsewhere.
+            .map(|fd| unsafe { OwnedFd::from_raw_fd(fd) })
+            .collect();
+        (msg.bytes, fds)
+    };
+    if len == 0 {
+        bail!("unable to read program id: connection closed");
+    }
+    stream
+        .read_exact(&mut buf[len..])
+        .context("unable to read program id")?;
+    Ok((u32::from_ne_bytes(buf), fds))
+}
+
+fn program_fd(stream: &UnixStream, peer: &Peer) -> anyhow::Result<Option<OwnedFd>> {
+    let id = read_program_id(stream)?;
+    let socket_filter = open_socket_filter(id, peer)?;
+    let fd = socket_filter.fd()?.as_fd().try_clone_to_owned()?;
+    debug!("Handing over fd for socket_filter program {id}");
+    Ok(Some(fd))
+}
+
+fn pinned_map_fd(mut stream: &UnixStream, peer: &Peer) -> anyhow::Result<Option<OwnedFd>> {
+    let (id, mut fds) = recv_program_id(stream)?;
+    let mut buf = [0u8; 2];
+    stream
+        .read_exact(&mut buf)
+        .context("unable to read map flags")?;
+    let [flags, name_len] = buf;
+    let mut name = vec![0u8; name_len as usize];
+    stream
+        .read_exact(&mut name)
+        .context("unable to read map name")?;
+    let name = String::from_utf8(name).context("invalid map name")?;
+
+    let program = open_program(id)?;
+    let Some(map_pin_path) = program.get_data().get_map_pin_path()? else {
+        bail!("program {id} has no pinned maps");
+    };
+
+    if flags & MAP_REGISTER_XSK != 0 {
+        peer.authorize("register_xsk", &program)?;
+        let mut buf = [0u8; 4];
+        stream
+            .read_exact(&mut buf)
+            .context("unable to read queue")?;
+        let queue = u32::from_ne_bytes(buf);
+        if fds.len() != 1 {
+            bail!("expected the AF_XDP socket fd, got {} fds", fds.len());
+        }
+        peer.check_map_access(&map_path(&map_pin_path, &name)?, true)?;
+        PinnedMap::open(&map_pin_path, &name)?.register_xsk(queue, &fds.remove(0))?;
+        debug!("Registered AF_XDP socket for queue {queue} in map {name} of program {id}");
+        return Ok(None);
+    }
+
+    peer.authorize("map_fd", &program)?;
+    let write = flags & MAP_READ_WRITE != 0;
+    peer.check_map_access(&map_path(&map_pin_path, &name)?, write)?;
+    let fd = map_fd(&map_pin_path, &name, !write)?;
+    debug!("Handing over fd for map {name} of program {id}");
+    Ok(Some(fd))
+}
+
+// open_tree() creates missing trees, so check the program is known first.
+fn open_program(id: u32) -> anyhow::Result<Program> {
+    let id_str = id.to_string();
+    if !ROOT_DB
+        .tree_names()
+        .iter()
+        .any(|name| name.as_ref() == id_str.as_bytes())
+    {
+        bail!("program {id} is not managed by bpfman");
+    }
+    Ok(Program::new_from_db(id, ROOT_DB.open_tree(id_str)?)?)
+}
+
+fn open_socket_filter(id: u32, peer: &Peer) -> anyhow::Result<SocketFilter> {
+    let program = open_program(id)?;
+    if program.kind() != ProgramType::SocketFilter {
+        bail!("program {id} is not a socket_filter program");
+    }
+    peer.authorize("program_fd", &program)?;
+
+    SocketFilter::from_pin(format!("{RTDIR_FS}/prog_{id}"))
+        .with_context(|| format!("unable to open pinned program {id}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs::File};
+
+    use bpfman_api::{
+        config::{AuthorizationConfig, RoleBinding, RoleConfig},
+        XdpProceedOn,
+    };
+
+    use super::*;
+    use crate::command::{ProgramData, XdpProgram};
+
+    // Sends a request to register an AF_XDP socket for queue 0 in the xsks
+    // map of program id, with fds attached.
+    fn send_register_xsk(stream: &UnixStream, id: u32, fds: &[RawFd]) {
+        let id = id.to_ne_bytes();
+        let rights = [ControlMessage::ScmRights(fds)];
+        let cmsgs: &[ControlMessage] = if fds.is_empty() { &[] } else { &rights };
+        sendmsg::<()>(
+            stream.as_raw_fd(),
+            &[IoSlice::new(&id)],
+            cmsgs,
+            MsgFlags::empty(),
+            None,
+        )
+        .unwrap();
+        let mut request = vec![MAP_REGISTER_XSK, 4];
+        request.extend_from_slice(b"xsks");
+        request.extend_from_slice(&0u32.to_ne_bytes());
+        (&*stream).write_all(&request).unwrap();
+    }
+
+    fn register_xsk(id: u32, fds: &[RawFd], peer: &Peer) -> String {
+        let (client, server) = UnixStream::pair().unwrap();
+        send_register_xsk(&client, id, fds);
+        format!("{:#}", pinned_map_fd(&server, peer).unwrap_err())
+    }
+
+    #[test]
+    fn test_register_xsk_rejected() {
+        let mut data = ProgramData::new(ROOT_DB.open_tree("4057").unwrap(), 4057);
+        data.set_id(4057).unwrap();
+        data.set_map_pin_path(Path::new("/run/bpfman/fs/maps/4057"))
+            .unwrap();
+        XdpProgram::new(data, 50, "eth0".to_string(), XdpProceedOn::default()).unwrap();
+
+        let peer = Peer {
+            uid: 1001,
+            gids: vec![1001],
+            authorizer: None,
+        };
+        let socket = File::open("/dev/null").unwrap();
+        assert_eq!(
+            register_xsk(4057, &[], &peer),
+            "expected the AF_XDP socket fd, got 0 fds"
+        );
+        assert_eq!(
+            register_xsk(4058, &[socket.as_raw_fd()], &peer),
+            "program 4058 is not managed by bpfman"
+        );
+
+        // The role of the client has to allow registering sockets, handing
+        // out map fds isn't enough.
+        let peer = Peer {
+            authorizer: Some(Arc::new(
+                Authorizer::new(AuthorizationConfig {
+                    default_role: None,
+                    roles: HashMap::from([(
+                        "network".to_string(),
+                        RoleConfig {
+                            operations: vec!["map_fd".to_string()],
+                            program_types: None,
+                            interfaces: None,
+                            registries: None,
+                        },
+                    )]),
+                    bindings: vec![RoleBinding {
+                        role: "network".to_string(),
+                        users: vec![],
+                        uids: vec![1001],
+                        identities: vec![],
+                    }],
+                })
+                .unwrap(),
+            )),
+            ..peer
+        };
+        assert!(register_xsk(4057, &[socket.as_raw_fd()], &peer)
+            .ends_with("doesn't allow register_xsk"));
+    }
+
+    #[test]
+    fn test_may_access() {
+        let peer = Peer {
+            uid: 1000,
+            gids: vec![1000, 27],
+            authorizer: None,
+        };
+        // Owned by the client.
+        assert!(may_access(&peer, 1000, 0, 0o600, true));
+        assert!(!may_access(&peer, 1000, 27, 0o060, false));
+        // Owned by a group of the client.
+        assert!(may_access(&peer, 0, 27, 0o640, false));
+        assert!(!may_access(&peer, 0, 27, 0o640, true));
+        assert!(may_access(&peer, 0, 27, 0o660, true));
+        // Owned by others.
+        assert!(!may_access(&peer, 0, 0, 0o660, false));
+        assert!(may_access(&peer, 0, 0, 0o664, false));
+        assert!(!may_access(&peer, 0, 0, 0o664, true));
+
+        let root = Peer {
+            uid: 0,
+            gids: vec![0],
+            authorizer: None,
+        };
+        assert!(may_access(&root, 1000, 1000, 0o600, true));
+    }
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Along with the programs of List, the links and maps in the kernel complete
+// the inventory of the BPF objects of a node, whether bpfman created them or
+// not. Maps are listed with the programs using them, which tells maps of
+// programs loaded by bpfman that programs it didn't load use too.
+
+use std::collections::{HashMap, HashSet};
+
+use aya::{loaded_programs, maps::loaded_maps, programs::loaded_links};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KernelLink {
+    pub(crate) id: u32,
+    pub(crate) link_type: u32,
+    pub(crate) program_id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KernelMap {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) map_type: u32,
+    pub(crate) key_size: u32,
+    pub(crate) value_size: u32,
+    pub(crate) max_entries: u32,
+    pub(crate) program_ids: Vec<u32>,
+}
+
+impl KernelMap {
+    // Whether the map is used by a program of managed and a program that
+    // isn't.
+    pub(crate) fn has_foreign_access(&self, managed: &HashSet<u32>) -> bool {
+        let (ours, theirs): (Vec<u32>, Vec<u32>) =
+            self.program_ids.iter().partition(|id| managed.contains(id));
+        !ours.is_empty() && !theirs.is_empty()
+    }
+}
+
+// Objects the kernel fails to report, like ones released while they are
+// listed, are left out.
+pub(crate) fn kernel_links() -> Vec<KernelLink> {
+    loaded_links()
+        .flatten()
+        .map(|link| KernelLink {
+            id: link.id,
+            link_type: link.type_,
+            program_id: link.prog_id,
+        })
+        .collect()
+}
+
+pub(crate) fn kernel_maps() -> Vec<KernelMap> {
+    let users = map_users(
+        loaded_programs()
+            .flatten()
+            .map(|p| (p.id(), p.map_ids().unwrap_or_default())),
+    );
+    loaded_maps()
+        .flatten()
+        .map(|map| KernelMap {
+            id: map.id(),
+            name: map.name_as_str().unwrap_or_default().to_string(),
+            map_type: map.map_type(),
+            key_size: map.key_size(),
+            value_size: map.value_size(),
+            max_entries: map.max_entries(),
+            program_ids: users.get(&map.id()).cloned().unwrap_or_default(),
+        })
+        .collect()
+}
+
+// Ids of the programs using each map, by map id, from the ids of the maps of
+// each program.
+fn map_users(programs: impl Iterator<Item = (u32, Vec<u32>)>) -> HashMap<u32, Vec<u32>> {
+    let mut users: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (program_id, map_ids) in programs {
+        for map_id in map_ids {
+            users.entry(map_id).or_default().push(program_id);
+        }
+    }
+    users
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_foreign_access() {
+        let users = map_users([(10, vec![1, 2]), (11, vec![2]), (20, vec![2, 3])].into_iter());
+        let map = |id: u32| KernelMap {
+            id,
+            name: String::new(),
+            map_type: 1,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1,
+            program_ids: users.get(&id).cloned().unwrap_or_default(),
+        };
+        let managed = HashSet::from([10, 11]);
+
+        assert_eq!(map(2).program_ids, vec![10, 11, 20]);
+        assert!(!map(1).has_foreign_access(&managed));
+        assert!(map(2).has_foreign_access(&managed));
+        assert!(!map(3).has_foreign_access(&managed));
+        assert!(!map(4).has_foreign_access(&managed));
+    }
+}
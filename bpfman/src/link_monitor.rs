@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+use futures::StreamExt;
+use log::{debug, error, info};
+use netlink_packet_core::NetlinkPayload;
+use netlink_packet_route::{link::nlas::Nla, RtnlMessage};
+use netlink_sys::{AsyncSocket, SocketAddr};
+use rtnetlink::{constants::RTMGRP_LINK, new_connection};
+use tokio::{select, sync::broadcast, task::JoinHandle};
+
+use crate::command::{Command, CommandSender, LinkEvent};
+
+// Interfaces can be removed and recreated while programs are attached to them,
+// after a driver reload or a VM hot-plug for instance. The link monitor
+// subscribes to the kernel's link notifications and passes them on to the
+// BpfManager, which detaches the programs from removed interfaces and attaches
+// them again once the interfaces reappear.
+pub(crate) async fn serve_link_monitor(
+    tx: CommandSender,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    let (mut connection, _, mut messages) = new_connection()?;
+    connection
+        .socket_mut()
+        .socket_mut()
+        .bind(&SocketAddr::new(0, RTMGRP_LINK))?;
+    let connection = tokio::spawn(connection);
+
+    Ok(tokio::spawn(async move {
+        info!("Monitoring network interfaces");
+        loop {
+            select! {
+                Some((message, _)) = messages.next() => {
+                    if let Some(event) = link_event(message.payload) {
+                        debug!("Received {event:?}");
+                        if tx.send(Command::LinkEvent(event)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                res = shutdown_channel.recv() => {
+                    match res {
+                        Ok(()) => debug!("Link monitor: Received shutdown signal"),
+                        Err(e) => error!("Error receiving shutdown signal {:?}", e),
+                    };
+                    break;
+                }
+            }
+        }
+        connection.abort();
+        info!("Shutdown link monitor");
+    }))
+}
+
+fn link_event(payload: NetlinkPayload<RtnlMessage>) -> Option<LinkEvent> {
+    let (link, removed) = match payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(link)) => (link, false),
+        NetlinkPayload::InnerMessage(RtnlMessage::DelLink(link)) => (link, true),
+        _ => return None,
+    };
+
+    let if_index = link.header.index;
+    let iface = link.nlas.into_iter().find_map(|nla| match nla {
+        Nla::IfName(name) => Some(name),
+        _ => None,
+    })?;
+
+    Some(if removed {
+        LinkEvent::Removed { if_index, iface }
+    } else {
+        LinkEvent::Changed { if_index, iface }
+    })
+}
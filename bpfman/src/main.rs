@@ -6,18 +6,44 @@ use clap::Parser;
 use lazy_static::lazy_static;
 use sled::{Config, Db};
 
+mod admission;
+mod allowlist;
+mod audit;
+mod authz;
 mod bpf;
+mod btf;
 mod cli;
 mod command;
+mod container;
 mod dispatcher_config;
 mod errors;
+mod events;
+mod external_btf;
+mod fd_handover;
+mod inventory;
+mod link_monitor;
+mod map_export;
+mod maps;
 mod multiprog;
 mod oci_utils;
+mod perf_buffer;
+mod policy;
+mod probe;
+mod reload;
+mod rest_gateway;
+mod ring_buffer;
 mod rpc;
 mod serve;
+mod sock_map;
+mod spiffe;
 mod static_program;
+mod stats;
 mod storage;
+mod telemetry;
+mod tls;
+mod usdt;
 mod utils;
+mod verifier;
 
 const BPFMAN_ENV_LOG_LEVEL: &str = "RUST_LOG";
 
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Maps of a program are exported to directories of their consumers, either by
+// pinning them again in a bpffs the consumer provides, or by pinning them in a
+// bpffs mounted for the export which is then bind mounted on the consumer's
+// directory, the way the CSI driver exposes maps to pods.
+
+use std::{
+    fs::{create_dir_all, remove_dir, remove_file},
+    path::Path,
+};
+
+use log::{debug, warn};
+
+use crate::{
+    errors::BpfmanError,
+    maps::{map_fd, map_path, pin_map, pinned_maps},
+    storage::{mount_fs_in_container, unmount},
+    utils::create_bpffs,
+};
+
+// Exports the maps pinned in map_pin_path under the given names, or all of
+// them if none are given, to target. If staging is given the maps are pinned
+// in a bpffs mounted there, which is bind mounted on target. Returns the names
+// of the maps exported.
+pub(crate) fn export_maps(
+    map_pin_path: &Path,
+    maps: &[String],
+    target: &Path,
+    staging: Option<&Path>,
+) -> Result<Vec<String>, BpfmanError> {
+    let maps = if maps.is_empty() {
+        pinned_maps(map_pin_path)?
+            .iter()
+            .map(|m| m.name().to_string())
+            .collect()
+    } else {
+        maps.to_vec()
+    };
+    // Open all maps first, so nothing is exported if one of them is missing.
+    let fds = maps
+        .iter()
+        .map(|name| map_fd(map_pin_path, name, false))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let pin_dir = match staging {
+        Some(staging) => {
+            create_dir_all(staging)?;
+            create_bpffs(&staging.to_string_lossy()).map_err(|e| {
+                BpfmanError::Error(format!("unable to create bpffs for export: {e:#}"))
+            })?;
+            staging
+        }
+        None => target,
+    };
+
+    // Only the pins made here are removed if the export fails, target may
+    // hold other pins.
+    let mut pinned = Vec::new();
+    let res = maps
+        .iter()
+        .zip(fds.iter())
+        .try_for_each(|(name, fd)| {
+            pin_map(fd, &map_path(pin_dir, name)?)?;
+            pinned.push(name.clone());
+            Ok(())
+        })
+        .and_then(|_| match staging {
+            Some(staging) => {
+                create_dir_all(target)?;
+                mount_fs_in_container(&staging.to_string_lossy(), &target.to_string_lossy())
+                    .map_err(|e| BpfmanError::Error(format!("{e:#}")))
+            }
+            None => Ok(()),
+        });
+    if let Err(e) = res {
+        if let Err(e) = remove_pins(pin_dir, &pinned, staging) {
+            warn!(
+                "unable to clean up failed export to {}: {e}",
+                target.display()
+            );
+        }
+        return Err(e);
+    }
+
+    debug!(
+        "Exported maps {} of {} to {}",
+        maps.join(","),
+        map_pin_path.display(),
+        target.display()
+    );
+    Ok(maps)
+}
+
+// Removes the maps exported to target, see export_maps().
+pub(crate) fn remove_export(
+    target: &Path,
+    maps: &[String],
+    staging: Option<&Path>,
+) -> Result<(), BpfmanError> {
+    match staging {
+        Some(staging) => {
+            unmount(&target.to_string_lossy()).map_err(|e| BpfmanError::Error(format!("{e:#}")))?;
+            remove_pins(staging, maps, Some(staging))
+        }
+        None => remove_pins(target, maps, None),
+    }
+}
+
+// Removes the pins of maps in dir, along with the bpffs mounted at staging.
+fn remove_pins(dir: &Path, maps: &[String], staging: Option<&Path>) -> Result<(), BpfmanError> {
+    for name in maps {
+        let path = map_path(dir, name)?;
+        if path.exists() {
+            remove_file(path)?;
+        }
+    }
+    if let Some(staging) = staging {
+        if let Err(e) = unmount(&staging.to_string_lossy()) {
+            debug!("{e:#}");
+        }
+        remove_dir(staging)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,598 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Access to the contents of the maps bpfman pins for programs. aya only offers
+// typed maps, whose key and value sizes are known at compile time, so the map
+// commands of the bpf syscall are used directly with byte buffers here.
+
+use std::{
+    ffi::CString,
+    fs, io, mem,
+    os::{
+        fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
+        unix::ffi::OsStrExt,
+    },
+    path::{Path, PathBuf},
+};
+
+use aya::util::possible_cpus;
+use nix::libc;
+
+use crate::{
+    btf::Btf,
+    command::{InnerMaps, ProgramData},
+    errors::BpfmanError,
+};
+
+const BPF_MAP_CREATE: libc::c_long = 0;
+const BPF_MAP_LOOKUP_ELEM: libc::c_long = 1;
+const BPF_MAP_UPDATE_ELEM: libc::c_long = 2;
+const BPF_MAP_DELETE_ELEM: libc::c_long = 3;
+const BPF_MAP_GET_NEXT_KEY: libc::c_long = 4;
+const BPF_OBJ_PIN: libc::c_long = 6;
+const BPF_OBJ_GET: libc::c_long = 7;
+const BPF_OBJ_GET_INFO_BY_FD: libc::c_long = 15;
+const BPF_BTF_GET_FD_BY_ID: libc::c_long = 19;
+const BPF_MAP_FREEZE: libc::c_long = 22;
+
+// Update flag creating or replacing the entry.
+const BPF_ANY: u64 = 0;
+// BPF_OBJ_GET flag opening the object read-only.
+const BPF_F_RDONLY: u32 = 1 << 3;
+
+const BPF_MAP_TYPE_XSKMAP: u32 = 17;
+const BPF_MAP_TYPE_RINGBUF: u32 = 27;
+
+// array_of_maps and hash_of_maps, whose values are inner maps.
+pub(crate) const MAP_IN_MAP_TYPES: [u32; 2] = [12, 13];
+
+// Map types whose values hold a slot per possible CPU: percpu_hash,
+// percpu_array, lru_percpu_hash and percpu_cgroup_storage.
+const PERCPU_MAP_TYPES: [u32; 4] = [5, 6, 10, 21];
+
+// The parts of union bpf_attr used by the commands above.
+#[repr(C)]
+#[derive(Default)]
+// Also used by BPF_OBJ_PIN, which sets bpf_fd.
+struct ObjGetAttr {
+    pathname: u64,
+    bpf_fd: u32,
+    file_flags: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct MapCreateAttr {
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+    inner_map_fd: u32,
+    numa_node: u32,
+    map_name: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct MapElemAttr {
+    map_fd: u32,
+    _pad: u32,
+    key: u64,
+    value: u64,
+    flags: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct MapFdAttr {
+    map_fd: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct InfoAttr {
+    bpf_fd: u32,
+    info_len: u32,
+    info: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct GetFdByIdAttr {
+    id: u32,
+    next_id: u32,
+    open_flags: u32,
+}
+
+// struct bpf_map_info
+#[repr(C)]
+#[derive(Default)]
+struct MapInfo {
+    map_type: u32,
+    id: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+    name: [u8; 16],
+    ifindex: u32,
+    btf_vmlinux_value_type_id: u32,
+    netns_dev: u64,
+    netns_ino: u64,
+    btf_id: u32,
+    btf_key_type_id: u32,
+    btf_value_type_id: u32,
+    btf_vmlinux_id: u32,
+    map_extra: u64,
+}
+
+// struct bpf_btf_info
+#[repr(C)]
+#[derive(Default)]
+struct BtfInfo {
+    btf: u64,
+    btf_size: u32,
+    id: u32,
+    name: u64,
+    name_len: u32,
+    kernel_btf: u32,
+}
+
+pub(crate) fn sys_bpf<T>(cmd: libc::c_long, attr: &mut T) -> io::Result<libc::c_long> {
+    // SAFETY: attr is one of the bpf_attr layouts above, the buffers it points
+    // to outlive the call.
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_bpf,
+            cmd,
+            attr as *mut T,
+            mem::size_of::<T>() as libc::c_uint,
+        )
+    };
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+pub(crate) fn obj_info<T>(fd: &OwnedFd, info: &mut T) -> io::Result<()> {
+    let mut attr = InfoAttr {
+        bpf_fd: fd.as_raw_fd() as u32,
+        info_len: mem::size_of::<T>() as u32,
+        info: info as *mut T as u64,
+    };
+    sys_bpf(BPF_OBJ_GET_INFO_BY_FD, &mut attr).map(|_| ())
+}
+
+fn load_btf(btf_id: u32) -> Result<Btf, BpfmanError> {
+    let mut attr = GetFdByIdAttr {
+        id: btf_id,
+        ..Default::default()
+    };
+    let fd = sys_bpf(BPF_BTF_GET_FD_BY_ID, &mut attr)
+        .map_err(|e| BpfmanError::Error(format!("unable to get BTF {btf_id}: {e}")))?;
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    let fd = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+    // The first call returns the size of the BTF, the second one its data.
+    let mut info = BtfInfo::default();
+    obj_info(&fd, &mut info)?;
+    let mut data = vec![0u8; info.btf_size as usize];
+    let mut info = BtfInfo {
+        btf: data.as_mut_ptr() as u64,
+        btf_size: data.len() as u32,
+        ..Default::default()
+    };
+    obj_info(&fd, &mut info)?;
+    Btf::parse(&data)
+}
+
+// Opens the map pinned in dir under name, read-only if asked to.
+pub(crate) fn map_fd(dir: &Path, name: &str, read_only: bool) -> Result<OwnedFd, BpfmanError> {
+    let path = CString::new(map_path(dir, name)?.as_os_str().as_bytes())
+        .map_err(|e| BpfmanError::Error(format!("invalid map path: {e}")))?;
+    let mut attr = ObjGetAttr {
+        pathname: path.as_ptr() as u64,
+        file_flags: if read_only { BPF_F_RDONLY } else { 0 },
+        ..Default::default()
+    };
+    let fd = sys_bpf(BPF_OBJ_GET, &mut attr)
+        .map_err(|e| BpfmanError::Error(format!("unable to open map {name}: {e}")))?;
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as i32) })
+}
+
+// Pins the map at path, which must be in a bpffs.
+pub(crate) fn pin_map(fd: &OwnedFd, path: &Path) -> Result<(), BpfmanError> {
+    pin_obj(fd.as_fd(), path)
+        .map_err(|e| BpfmanError::Error(format!("unable to pin map {}: {e}", path.display())))
+}
+
+// Pins the map, program or link of fd at path, which must be in a bpffs.
+pub(crate) fn pin_obj(fd: BorrowedFd<'_>, path: &Path) -> io::Result<()> {
+    let pathname = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let mut attr = ObjGetAttr {
+        pathname: pathname.as_ptr() as u64,
+        bpf_fd: fd.as_raw_fd() as u32,
+        ..Default::default()
+    };
+    sys_bpf(BPF_OBJ_PIN, &mut attr).map(|_| ())
+}
+
+// A map pinned in a program's map pin path.
+pub(crate) struct PinnedMap {
+    name: String,
+    fd: OwnedFd,
+    info: MapInfo,
+    value_len: usize,
+    btf: Option<Btf>,
+}
+
+impl PinnedMap {
+    pub(crate) fn open(dir: &Path, name: &str) -> Result<Self, BpfmanError> {
+        let fd = map_fd(dir, name, false)?;
+
+        let mut info = MapInfo::default();
+        obj_info(&fd, &mut info)?;
+
+        // Values of per-CPU maps are read and written for all CPUs at once,
+        // each one rounded up to 8 bytes.
+        let value_len = if PERCPU_MAP_TYPES.contains(&info.map_type) {
+            let cpus = possible_cpus()
+                .map_err(|e| BpfmanError::Error(format!("unable to get possible cpus: {e:?}")))?;
+            (info.value_size as usize).div_ceil(8) * 8 * cpus.len()
+        } else {
+            info.value_size as usize
+        };
+
+        Ok(PinnedMap {
+            name: name.to_string(),
+            fd,
+            info,
+            value_len,
+            btf: None,
+        })
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn id(&self) -> u32 {
+        self.info.id
+    }
+
+    pub(crate) fn map_type(&self) -> u32 {
+        self.info.map_type
+    }
+
+    pub(crate) fn key_size(&self) -> u32 {
+        self.info.key_size
+    }
+
+    pub(crate) fn value_size(&self) -> u32 {
+        self.info.value_size
+    }
+
+    pub(crate) fn max_entries(&self) -> u32 {
+        self.info.max_entries
+    }
+
+    // Approximates the memory the map locks by the size of its entries. The
+    // max_entries of ring buffers is their size in bytes.
+    pub(crate) fn memory(&self) -> u64 {
+        let max_entries = self.info.max_entries as u64;
+        if self.info.map_type == BPF_MAP_TYPE_RINGBUF {
+            return max_entries;
+        }
+        (self.info.key_size as u64 + self.value_len as u64) * max_entries
+    }
+
+    // Loads the BTF the map was created with, if any, to format its keys and
+    // values, see key_json() and value_json().
+    pub(crate) fn load_btf(&mut self) -> Result<(), BpfmanError> {
+        if self.info.btf_id != 0 {
+            self.btf = Some(load_btf(self.info.btf_id)?);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn key_json(&self, key: &[u8]) -> Option<String> {
+        self.format(self.info.btf_key_type_id, key)
+    }
+
+    // Values of per-CPU maps are formatted as an array with one value per CPU.
+    pub(crate) fn value_json(&self, value: &[u8]) -> Option<String> {
+        let type_id = self.info.btf_value_type_id;
+        if self.value_len == self.info.value_size as usize {
+            return self.format(type_id, value);
+        }
+        let (btf, slot) = (
+            self.btf.as_ref()?,
+            (self.info.value_size as usize).div_ceil(8) * 8,
+        );
+        if type_id == 0 || slot == 0 {
+            return None;
+        }
+        let values: Vec<_> = value
+            .chunks(slot)
+            .map(|v| btf.format(type_id, &v[..self.info.value_size as usize]))
+            .collect();
+        Some(serde_json::Value::Array(values).to_string())
+    }
+
+    fn format(&self, type_id: u32, data: &[u8]) -> Option<String> {
+        if type_id == 0 {
+            return None;
+        }
+        self.btf
+            .as_ref()
+            .map(|btf| btf.format(type_id, data).to_string())
+    }
+
+    fn check_key(&self, key: &[u8]) -> Result<(), BpfmanError> {
+        if key.len() != self.info.key_size as usize {
+            return Err(BpfmanError::Error(format!(
+                "keys of map {} are {} bytes long, got {}",
+                self.name,
+                self.info.key_size,
+                key.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn elem_attr(&self, key: &[u8], value: u64, flags: u64) -> MapElemAttr {
+        MapElemAttr {
+            map_fd: self.fd.as_raw_fd() as u32,
+            key: key.as_ptr() as u64,
+            value,
+            flags,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn lookup(&self, key: &[u8]) -> Result<Option<Vec<u8>>, BpfmanError> {
+        self.check_key(key)?;
+        let mut value = vec![0u8; self.value_len];
+        let mut attr = self.elem_attr(key, value.as_mut_ptr() as u64, 0);
+        match sys_bpf(BPF_MAP_LOOKUP_ELEM, &mut attr) {
+            Ok(_) => Ok(Some(value)),
+            Err(e) if e.raw_os_error() == Some(libc::ENOENT) => Ok(None),
+            Err(e) => Err(BpfmanError::Error(format!(
+                "unable to look up key in map {}: {e}",
+                self.name
+            ))),
+        }
+    }
+
+    // flags is BPF_ANY, BPF_NOEXIST or BPF_EXIST.
+    pub(crate) fn update(&self, key: &[u8], value: &[u8], flags: u64) -> Result<(), BpfmanError> {
+        self.check_key(key)?;
+        if value.len() != self.value_len {
+            return Err(BpfmanError::Error(format!(
+                "values of map {} are {} bytes long, got {}",
+                self.name,
+                self.value_len,
+                value.len()
+            )));
+        }
+        let mut attr = self.elem_attr(key, value.as_ptr() as u64, flags);
+        sys_bpf(BPF_MAP_UPDATE_ELEM, &mut attr)
+            .map(|_| ())
+            .map_err(|e| BpfmanError::Error(format!("unable to update map {}: {e}", self.name)))
+    }
+
+    // Returns false if the key wasn't in the map.
+    pub(crate) fn delete(&self, key: &[u8]) -> Result<bool, BpfmanError> {
+        self.check_key(key)?;
+        let mut attr = self.elem_attr(key, 0, 0);
+        match sys_bpf(BPF_MAP_DELETE_ELEM, &mut attr) {
+            Ok(_) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(libc::ENOENT) => Ok(false),
+            Err(e) => Err(BpfmanError::Error(format!(
+                "unable to delete key from map {}: {e}",
+                self.name
+            ))),
+        }
+    }
+
+    // Makes the map read-only to userspace. Programs can still update it.
+    pub(crate) fn freeze(&self) -> Result<(), BpfmanError> {
+        let mut attr = MapFdAttr {
+            map_fd: self.fd.as_raw_fd() as u32,
+        };
+        sys_bpf(BPF_MAP_FREEZE, &mut attr)
+            .map(|_| ())
+            .map_err(|e| BpfmanError::Error(format!("unable to freeze map {}: {e}", self.name)))
+    }
+
+    // Stores an AF_XDP socket in the xskmap under the queue it's bound to. The
+    // kernel removes it from the map when the socket is closed.
+    pub(crate) fn register_xsk(&self, queue: u32, xsk: &OwnedFd) -> Result<(), BpfmanError> {
+        if self.info.map_type != BPF_MAP_TYPE_XSKMAP {
+            return Err(BpfmanError::Error(format!(
+                "map {} is not an xskmap",
+                self.name
+            )));
+        }
+        self.update(
+            &queue.to_ne_bytes(),
+            &(xsk.as_raw_fd() as u32).to_ne_bytes(),
+            BPF_ANY,
+        )
+    }
+
+    // Returns all entries of the map. Entries removed while the map is walked
+    // are skipped.
+    pub(crate) fn dump(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, BpfmanError> {
+        let mut entries = Vec::new();
+        let mut key: Option<Vec<u8>> = None;
+        loop {
+            let mut next_key = vec![0u8; self.info.key_size as usize];
+            // A null key returns the first key of the map.
+            let mut attr = MapElemAttr {
+                map_fd: self.fd.as_raw_fd() as u32,
+                key: key.as_ref().map_or(0, |k| k.as_ptr() as u64),
+                value: next_key.as_mut_ptr() as u64,
+                ..Default::default()
+            };
+            match sys_bpf(BPF_MAP_GET_NEXT_KEY, &mut attr) {
+                Ok(_) => {}
+                Err(e) if e.raw_os_error() == Some(libc::ENOENT) => break,
+                Err(e) => {
+                    return Err(BpfmanError::Error(format!(
+                        "unable to walk map {}: {e}",
+                        self.name
+                    )))
+                }
+            }
+            if let Some(value) = self.lookup(&next_key)? {
+                entries.push((next_key.clone(), value));
+            }
+            key = Some(next_key);
+        }
+        Ok(entries)
+    }
+}
+
+// Returns the path of the map pinned in dir under name, making sure name
+// doesn't point outside of dir.
+pub(crate) fn map_path(dir: &Path, name: &str) -> Result<PathBuf, BpfmanError> {
+    if !valid_map_name(name) {
+        return Err(BpfmanError::Error(format!(
+            "{name} is not a valid map name"
+        )));
+    }
+    Ok(dir.join(name))
+}
+
+// Maps are pinned in a flat directory, so their names can't contain a path.
+pub(crate) fn valid_map_name(name: &str) -> bool {
+    !(name.is_empty() || name.contains('/') || name == "." || name == "..")
+}
+
+// Creates the inner maps and writes the initial entries of the maps of a
+// program that were just pinned in dir as its map pin policy says, freezing
+// the maps asked for. Maps that aren't pinned can't be initialized.
+pub(crate) fn initialize_maps(dir: &Path, data: &ProgramData) -> Result<(), BpfmanError> {
+    let policy = data.get_map_pin_policy()?;
+    let pinned_name = |map: &str| {
+        policy.pin_name(map).map(|n| n.to_string()).ok_or_else(|| {
+            BpfmanError::Error(format!("map {map} must be pinned to be initialized"))
+        })
+    };
+
+    for inner in data.get_inner_maps()? {
+        let outer = PinnedMap::open(dir, &pinned_name(&inner.outer)?)?;
+        create_inner_maps(dir, &outer, &inner)?;
+    }
+
+    for map_init in data.get_map_init()? {
+        let map = PinnedMap::open(dir, &pinned_name(&map_init.map)?)?;
+        for (key, value) in &map_init.entries {
+            map.update(key, value, BPF_ANY)?;
+        }
+        if map_init.freeze {
+            map.freeze()?;
+        }
+    }
+    Ok(())
+}
+
+// Creates an inner map for each key of the outer map, pinned in dir under the
+// pin name of the outer map followed by the hex encoded key, and stores it in
+// the outer map under the key.
+fn create_inner_maps(dir: &Path, outer: &PinnedMap, inner: &InnerMaps) -> Result<(), BpfmanError> {
+    if !MAP_IN_MAP_TYPES.contains(&outer.map_type()) {
+        return Err(BpfmanError::Error(format!(
+            "map {} isn't an array or hash of maps",
+            outer.name()
+        )));
+    }
+    for key in &inner.keys {
+        let mut attr = MapCreateAttr {
+            map_type: inner.map_type,
+            key_size: inner.key_size,
+            value_size: inner.value_size,
+            max_entries: inner.max_entries,
+            ..Default::default()
+        };
+        let fd = sys_bpf(BPF_MAP_CREATE, &mut attr).map_err(|e| {
+            BpfmanError::Error(format!(
+                "unable to create inner map of map {}: {e}",
+                outer.name()
+            ))
+        })?;
+        // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+        let fd = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+        let path = map_path(dir, &format!("{}_{}", outer.name(), hex::encode(key)))?;
+        pin_map(&fd, &path)?;
+
+        outer.update(key, &(fd.as_raw_fd() as u32).to_ne_bytes(), BPF_ANY)?;
+    }
+    Ok(())
+}
+
+// Returns the approximate memory of the maps pinned in dir.
+pub(crate) fn maps_memory(dir: &Path) -> Result<u64, BpfmanError> {
+    Ok(pinned_maps(dir)?.iter().map(|m| m.memory()).sum())
+}
+
+// Returns the maps pinned in dir, sorted by name.
+pub(crate) fn pinned_maps(dir: &Path) -> Result<Vec<PinnedMap>, BpfmanError> {
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str().map(|n| n.to_string()))
+        .collect();
+    names.sort();
+    names
+        .iter()
+        .map(|name| PinnedMap::open(dir, name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::command::{Location, MapPinPolicy};
+
+    #[test]
+    fn test_initialize_unpinned_map() {
+        let mut data = ProgramData::new_pre_load(
+            Location::File("/tmp/kprobe.o".to_string()),
+            "enter".to_string(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+        )
+        .unwrap();
+        data.set_inner_maps(&[InnerMaps {
+            outer: "flows".to_string(),
+            map_type: 1,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            keys: vec![vec![0; 4]],
+        }])
+        .unwrap();
+        data.set_map_pin_policy(&MapPinPolicy {
+            exclude: vec!["flows".to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        // The policy is checked before any map is opened.
+        let err = initialize_maps(Path::new("/nonexistent"), &data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "An error occurred. map flows must be pinned to be initialized"
+        );
+    }
+}
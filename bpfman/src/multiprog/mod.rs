@@ -1,22 +1,31 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
+pub(crate) mod netkit;
 mod tc;
+pub(crate) mod tcx;
 mod xdp;
 
+use std::{fs, path::Path};
+
+use aya::Bpf;
 use bpfman_api::{
-    config::{InterfaceConfig, XdpMode},
+    config::{DispatcherImages, InterfaceConfig, XdpMode},
+    dispatcher_stats::{DispatcherKind, SlotStats, DISPATCHER_STATS_MAP},
+    v1::DispatcherSlotStats,
     ProgramType,
 };
-use log::debug;
+use log::{debug, warn};
+use opentelemetry::Context;
 pub use tc::TcDispatcher;
-use tokio::sync::mpsc::Sender;
+use tokio::sync::{mpsc::Sender, oneshot};
 pub use xdp::XdpDispatcher;
 
 use crate::{
     command::{Direction, Program},
     errors::BpfmanError,
-    oci_utils::image_manager::Command as ImageManagerCommand,
+    oci_utils::image_manager::{BytecodeImage, Command as ImageManagerCommand},
+    telemetry::traced,
 };
 
 pub(crate) enum Dispatcher {
@@ -27,6 +36,7 @@ pub(crate) enum Dispatcher {
 impl Dispatcher {
     pub async fn new(
         config: Option<&InterfaceConfig>,
+        images: &DispatcherImages,
         programs: &mut [&mut Program],
         revision: u32,
         old_dispatcher: Option<Dispatcher>,
@@ -41,16 +51,35 @@ impl Dispatcher {
             .ok_or_else(|| BpfmanError::Error("missing ifindex".to_string()))?;
         let if_name = p.if_name()?;
         let direction = p.direction()?;
-        let xdp_mode = if let Some(c) = config {
-            c.xdp_mode
+        let netns = p.netns()?;
+        let nsid = p.nsid()?;
+        let (xdp_mode, xdp_mode_fallback) = if let Some(c) = config {
+            (c.xdp_mode, c.xdp_mode_fallback)
         } else {
-            XdpMode::Skb
+            (XdpMode::Skb, false)
         };
         let d = match p.kind() {
             ProgramType::Xdp => {
-                let mut x = XdpDispatcher::new(xdp_mode, if_index, if_name.to_string(), revision)?;
+                let mut x = XdpDispatcher::new(
+                    xdp_mode,
+                    xdp_mode_fallback,
+                    if_index,
+                    if_name.to_string(),
+                    netns,
+                    nsid,
+                    revision,
+                )?;
 
-                x.load(programs, old_dispatcher, image_manager).await?;
+                traced(
+                    "load xdp dispatcher",
+                    x.load(
+                        programs,
+                        old_dispatcher,
+                        BytecodeImage::from(&images.xdp),
+                        image_manager,
+                    ),
+                )
+                .await?;
                 Dispatcher::Xdp(x)
             }
             ProgramType::Tc => {
@@ -58,10 +87,21 @@ impl Dispatcher {
                     direction.expect("missing direction"),
                     if_index,
                     if_name.to_string(),
+                    netns,
+                    nsid,
                     revision,
                 )?;
 
-                t.load(programs, old_dispatcher, image_manager).await?;
+                traced(
+                    "load tc dispatcher",
+                    t.load(
+                        programs,
+                        old_dispatcher,
+                        BytecodeImage::from(&images.tc),
+                        image_manager,
+                    ),
+                )
+                .await?;
                 Dispatcher::Tc(t)
             }
             _ => return Err(BpfmanError::DispatcherNotRequired),
@@ -69,6 +109,58 @@ impl Dispatcher {
         Ok(d)
     }
 
+    // Loads programs waiting for their interface to be created. Extensions
+    // can only be loaded against a dispatcher, so they are loaded into one
+    // that is never attached and removed right away. No interface has index
+    // 0, so it can't clash with the dispatcher of an interface. The programs
+    // stay pinned, and are attached like detached programs once the interface
+    // shows up.
+    pub(crate) async fn load_pending(
+        images: &DispatcherImages,
+        programs: &mut [&mut Program],
+        image_manager: Sender<ImageManagerCommand>,
+    ) -> Result<(), BpfmanError> {
+        debug!("Dispatcher::load_pending()");
+        let p = programs
+            .first()
+            .ok_or_else(|| BpfmanError::Error("No programs to load".to_string()))?;
+        let if_name = p.if_name()?;
+        let direction = p.direction()?;
+        let mut d = match p.kind() {
+            ProgramType::Xdp => {
+                let mut x =
+                    XdpDispatcher::new(XdpMode::Skb, false, 0, if_name.to_string(), None, None, 0)?;
+                x.load_extensions(programs, BytecodeImage::from(&images.xdp), image_manager)
+                    .await?;
+                Dispatcher::Xdp(x)
+            }
+            ProgramType::Tc => {
+                let mut t = TcDispatcher::new(
+                    direction.expect("missing direction"),
+                    0,
+                    if_name.to_string(),
+                    None,
+                    None,
+                    0,
+                )?;
+                t.load_extensions(programs, BytecodeImage::from(&images.tc), image_manager)
+                    .await?;
+                Dispatcher::Tc(t)
+            }
+            _ => return Err(BpfmanError::DispatcherNotRequired),
+        };
+        d.delete(false)
+    }
+
+    // Returns the dispatcher as stored in the database and pinned, like after
+    // a restart.
+    pub(crate) fn reopen(&self) -> Dispatcher {
+        match self {
+            Dispatcher::Xdp(d) => Dispatcher::Xdp(d.reopen()),
+            Dispatcher::Tc(d) => Dispatcher::Tc(d.reopen()),
+        }
+    }
+
     pub(crate) fn delete(&mut self, full: bool) -> Result<(), BpfmanError> {
         debug!("Dispatcher::delete()");
         match self {
@@ -98,6 +190,13 @@ impl Dispatcher {
         }
     }
 
+    pub(crate) fn set_if_name(&mut self, if_name: &str) -> Result<(), BpfmanError> {
+        match self {
+            Dispatcher::Xdp(d) => d.set_ifname(if_name),
+            Dispatcher::Tc(d) => d.set_ifname(if_name),
+        }
+    }
+
     pub(crate) fn num_extensions(&self) -> usize {
         match self {
             Dispatcher::Xdp(d) => d
@@ -116,5 +215,147 @@ pub(crate) enum DispatcherId {
     Tc(DispatcherInfo),
 }
 
+// Interface index, direction for TC, and the id of the interface's network
+// namespace when it isn't bpfman's own.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
-pub(crate) struct DispatcherInfo(pub u32, pub Option<Direction>);
+pub(crate) struct DispatcherInfo(pub u32, pub Option<Direction>, pub Option<u64>);
+
+// Interface indexes are only unique within a network namespace, so the
+// dispatchers on interfaces in other namespaces are stored and pinned under
+// the namespace id as well.
+pub(crate) fn dispatcher_key(if_index: u32, nsid: Option<u64>) -> String {
+    match nsid {
+        Some(nsid) => format!("{nsid}_{if_index}"),
+        None => if_index.to_string(),
+    }
+}
+
+// Pins the stats map of a dispatcher that was just attached at path, in place
+// of the one of the revision it replaces. Dispatchers pulled from images built
+// before the map was added don't count.
+pub(crate) fn pin_stats_map(loader: &mut Bpf, path: &str) -> Result<(), BpfmanError> {
+    remove_stats_map(path)?;
+    let Some(map) = loader.map_mut(DISPATCHER_STATS_MAP) else {
+        debug!("dispatcher has no {DISPATCHER_STATS_MAP} map, its slots aren't counted");
+        return Ok(());
+    };
+    map.pin(path).map_err(BpfmanError::UnableToPinMap)
+}
+
+pub(crate) fn remove_stats_map(path: &str) -> Result<(), BpfmanError> {
+    if Path::new(path).exists() {
+        fs::remove_file(path)
+            .map_err(|e| BpfmanError::Error(format!("unable to cleanup state: {e}")))?;
+    }
+    Ok(())
+}
+
+// Returns the counters of slot of the dispatcher whose stats map is pinned at
+// path, if it has one.
+pub(crate) fn slot_stats(
+    path: &str,
+    slot: usize,
+    kind: DispatcherKind,
+) -> Option<DispatcherSlotStats> {
+    match SlotStats::read(path, slot as u32) {
+        Ok(stats) => Some(DispatcherSlotStats {
+            packets: stats.packets,
+            verdicts: stats.verdicts(kind),
+        }),
+        Err(e) => {
+            debug!("unable to read dispatcher stats at {path}: {e}");
+            None
+        }
+    }
+}
+
+// Object file and function name of a dispatcher built into bpfman.
+type EmbeddedDispatcher = Option<(&'static [u8], &'static str)>;
+
+// With the embed-dispatchers feature, the dispatchers built by
+// `cargo xtask build-ebpf` are included in bpfman so that dispatchers can be
+// created without access to a registry.
+#[cfg(feature = "embed-dispatchers")]
+pub(crate) const XDP_DISPATCHER: EmbeddedDispatcher = Some((
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../.output/xdp_dispatcher_v2.bpf.o"
+    )),
+    "xdp_dispatcher",
+));
+#[cfg(not(feature = "embed-dispatchers"))]
+pub(crate) const XDP_DISPATCHER: EmbeddedDispatcher = None;
+
+#[cfg(feature = "embed-dispatchers")]
+pub(crate) const TC_DISPATCHER: EmbeddedDispatcher = Some((
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../.output/tc_dispatcher.bpf.o"
+    )),
+    "tc_dispatcher",
+));
+#[cfg(not(feature = "embed-dispatchers"))]
+pub(crate) const TC_DISPATCHER: EmbeddedDispatcher = None;
+
+// Returns the bytecode of a dispatcher and the name of its function, pulled
+// from image. If the image can't be pulled, the embedded dispatcher is used
+// instead if there is one.
+pub(crate) async fn dispatcher_bytecode(
+    image: &BytecodeImage,
+    embedded: EmbeddedDispatcher,
+    image_manager: Sender<ImageManagerCommand>,
+) -> Result<(Vec<u8>, String), BpfmanError> {
+    match pull_dispatcher(image, image_manager).await {
+        Ok(res) => Ok(res),
+        Err(e) => match embedded {
+            Some((bytes, function_name)) => {
+                warn!(
+                    "Unable to pull dispatcher image {}, using the embedded dispatcher: {e}",
+                    image.get_url()
+                );
+                // The object file is copied, as it must be aligned to be
+                // parsed.
+                Ok((bytes.to_vec(), function_name.to_string()))
+            }
+            None => Err(e),
+        },
+    }
+}
+
+async fn pull_dispatcher(
+    image: &BytecodeImage,
+    image_manager: Sender<ImageManagerCommand>,
+) -> Result<(Vec<u8>, String), BpfmanError> {
+    let (tx, rx) = oneshot::channel();
+    image_manager
+        .send(ImageManagerCommand::Pull {
+            image: image.image_url.clone(),
+            pull_policy: image.image_pull_policy.clone(),
+            username: image.username.clone(),
+            password: image.password.clone(),
+            context: Context::current(),
+            resp: tx,
+        })
+        .await
+        .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+
+    let (path, metadata) = rx
+        .await
+        .map_err(BpfmanError::RpcRecvError)?
+        .map_err(BpfmanError::BpfBytecodeError)?;
+    let bpf_function_name = metadata
+        .select_program("")
+        .map_err(BpfmanError::BpfBytecodeError)?
+        .bpf_function_name;
+
+    let (tx, rx) = oneshot::channel();
+    image_manager
+        .send(ImageManagerCommand::GetBytecode { path, resp: tx })
+        .await
+        .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
+    let program_bytes = rx
+        .await
+        .map_err(BpfmanError::RpcRecvError)?
+        .map_err(BpfmanError::BpfBytecodeError)?;
+    Ok((program_bytes, bpf_function_name))
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// netkit devices, added in Linux 6.7, are veth-like device pairs whose
+// transmit path runs BPF programs directly. Programs are attached to the
+// primary device or to its peer with links the kernel orders itself, the same
+// way tcx links are, so no dispatcher is needed here either. aya can't create
+// netkit links, so they are created with the bpf syscall and pinned next to
+// the program like the links aya creates.
+
+use std::{
+    os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
+    path::Path,
+};
+
+use aya::util::KernelVersion;
+use bpfman_api::util::directories::RTDIR_FS;
+use log::debug;
+use nix::libc;
+
+use crate::{
+    command::{NetkitPosition, NetkitProgram},
+    errors::BpfmanError,
+    maps::{pin_obj, sys_bpf},
+};
+
+const BPF_LINK_CREATE: libc::c_long = 28;
+
+// enum bpf_attach_type
+const BPF_NETKIT_PRIMARY: u32 = 54;
+const BPF_NETKIT_PEER: u32 = 55;
+
+// Places the link in front of the program whose id is relative_id.
+const BPF_F_BEFORE: u32 = 1 << 3;
+const BPF_F_ID: u32 = 1 << 5;
+
+// The part of union bpf_attr used by BPF_LINK_CREATE for netkit links.
+#[repr(C)]
+#[derive(Default)]
+struct LinkCreateAttr {
+    prog_fd: u32,
+    target_ifindex: u32,
+    attach_type: u32,
+    flags: u32,
+    relative_id: u32,
+    _pad: u32,
+    expected_revision: u64,
+}
+
+// Returns an error if the running kernel predates netkit devices.
+pub(crate) fn check_netkit_supported() -> Result<(), BpfmanError> {
+    let current = KernelVersion::current()
+        .map_err(|e| BpfmanError::Error(format!("unable to determine kernel version: {e}")))?;
+    if current < KernelVersion::new(6, 7, 0) {
+        return Err(BpfmanError::NetkitKernelTooOld);
+    }
+    Ok(())
+}
+
+fn attach_type(position: NetkitPosition) -> u32 {
+    match position {
+        NetkitPosition::Primary => BPF_NETKIT_PRIMARY,
+        NetkitPosition::Peer => BPF_NETKIT_PEER,
+    }
+}
+
+// Attaches a loaded program to its netkit device with a link and pins the
+// link. The program is placed in front of the program with kernel id `before`,
+// or after every other program at its position when there is none.
+pub(crate) fn attach(
+    program: &NetkitProgram,
+    prog_fd: BorrowedFd<'_>,
+    before: Option<u32>,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let iface = program.get_iface()?;
+    let if_index = program
+        .get_if_index()?
+        .ok_or_else(|| BpfmanError::Error("missing ifindex".to_string()))?;
+    let position = program.get_position()?;
+
+    let mut attr = LinkCreateAttr {
+        prog_fd: prog_fd.as_raw_fd() as u32,
+        target_ifindex: if_index,
+        attach_type: attach_type(position),
+        ..Default::default()
+    };
+    if let Some(before) = before {
+        attr.flags = BPF_F_BEFORE | BPF_F_ID;
+        attr.relative_id = before;
+    }
+    debug!("attaching netkit program {id} to {position} of {iface}, before {before:?}");
+
+    let fd = sys_bpf(BPF_LINK_CREATE, &mut attr).map_err(|e| {
+        BpfmanError::Error(format!(
+            "unable to attach netkit program {id} to {iface}, is it a netkit device? {e}"
+        ))
+    })?;
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    let link = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+    let path = format!("{RTDIR_FS}/prog_{id}_link");
+    pin_obj(link.as_fd(), Path::new(&path))
+        .map_err(|e| BpfmanError::Error(format!("unable to pin link {path}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attach_type() {
+        assert_eq!(attach_type(NetkitPosition::Primary), BPF_NETKIT_PRIMARY);
+        assert_eq!(attach_type(NetkitPosition::Peer), BPF_NETKIT_PEER);
+        assert_eq!(std::mem::size_of::<LinkCreateAttr>(), 32);
+    }
+}
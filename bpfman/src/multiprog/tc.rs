@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{fs, mem};
+use std::{
+    fs, mem,
+    path::{Path, PathBuf},
+};
 
 use aya::{
     programs::{
@@ -11,11 +14,11 @@ use aya::{
     },
     Bpf, BpfLoader,
 };
-use bpfman_api::{util::directories::*, ImagePullPolicy};
+use bpfman_api::{dispatcher_stats::stats_path, util::directories::*};
 use futures::stream::TryStreamExt;
 use log::debug;
 use netlink_packet_route::tc::Nla;
-use tokio::sync::{mpsc::Sender, oneshot};
+use tokio::sync::mpsc::Sender;
 
 use crate::{
     bpf::{calc_map_pin_path, create_map_pin_path},
@@ -26,12 +29,18 @@ use crate::{
     },
     dispatcher_config::TcDispatcherConfig,
     errors::BpfmanError,
-    multiprog::Dispatcher,
+    external_btf::set_external_btf,
+    maps::initialize_maps,
+    multiprog::{
+        dispatcher_bytecode, dispatcher_key, pin_stats_map, remove_stats_map, Dispatcher,
+        TC_DISPATCHER,
+    },
     oci_utils::image_manager::{BytecodeImage, Command as ImageManagerCommand},
     utils::{
-        bytes_to_string, bytes_to_u16, bytes_to_u32, bytes_to_usize, should_map_be_pinned,
+        bytes_to_string, bytes_to_u16, bytes_to_u32, bytes_to_u64, bytes_to_usize, in_netns,
         sled_get, sled_get_option, sled_insert,
     },
+    verifier::set_verifier_log_level,
     ROOT_DB,
 };
 
@@ -49,12 +58,16 @@ impl TcDispatcher {
         direction: Direction,
         if_index: u32,
         if_name: String,
+        netns: Option<PathBuf>,
+        nsid: Option<u64>,
         revision: u32,
     ) -> Result<Self, BpfmanError> {
         let db_tree = ROOT_DB
             .open_tree(format!(
                 "tc_dispatcher_{}_{}_{}",
-                if_index, direction, revision
+                dispatcher_key(if_index, nsid),
+                direction,
+                revision
             ))
             .expect("Unable to open tc dispatcher database tree");
 
@@ -65,6 +78,12 @@ impl TcDispatcher {
 
         dp.set_ifindex(if_index)?;
         dp.set_ifname(&if_name)?;
+        if let Some(netns) = netns {
+            dp.set_netns(&netns)?;
+        }
+        if let Some(nsid) = nsid {
+            dp.set_nsid(nsid)?;
+        }
         dp.set_direction(direction)?;
         dp.set_revision(revision)?;
         dp.set_priority(TC_DISPATCHER_PRIORITY)?;
@@ -79,10 +98,37 @@ impl TcDispatcher {
         }
     }
 
+    pub(crate) fn reopen(&self) -> Self {
+        Self::new_from_db(self.db_tree.clone())
+    }
+
     pub(crate) async fn load(
         &mut self,
         programs: &mut [&mut Program],
         old_dispatcher: Option<Dispatcher>,
+        image: BytecodeImage,
+        image_manager: Sender<ImageManagerCommand>,
+    ) -> Result<(), BpfmanError> {
+        self.load_extensions(programs, image, image_manager).await?;
+        self.attach(old_dispatcher).await?;
+        let base = match self.get_direction()? {
+            Ingress => RTDIR_FS_TC_INGRESS,
+            Egress => RTDIR_FS_TC_EGRESS,
+        };
+        let key = self.key()?;
+        pin_stats_map(
+            self.loader.as_mut().ok_or(BpfmanError::NotLoaded)?,
+            &stats_path(base, &key),
+        )?;
+        Ok(())
+    }
+
+    // Loads the dispatcher and the programs into its slots, without attaching
+    // the dispatcher to the interface.
+    pub(crate) async fn load_extensions(
+        &mut self,
+        programs: &mut [&mut Program],
+        image: BytecodeImage,
         image_manager: Sender<ImageManagerCommand>,
     ) -> Result<(), BpfmanError> {
         let if_index = self.get_ifindex()?;
@@ -97,6 +143,11 @@ impl TcDispatcher {
                 _ => panic!("All programs should be of type TC"),
             })
             .collect();
+        // The dispatcher calls the program in the next slot only if the bit
+        // for the verdict a program returned is set in its chain call mask.
+        // TC verdicts start at TC_ACT_UNSPEC (-1), so verdict n maps to bit
+        // n + 1, see TcProceedOn::mask(). Otherwise the verdict is returned
+        // to the kernel as is, and TC_ACT_OK once every program proceeded.
         let mut chain_call_actions = [0; 10];
         for v in extensions.iter() {
             chain_call_actions[v.get_current_position()?.unwrap()] = v.get_proceed_on()?.mask()
@@ -109,36 +160,8 @@ impl TcDispatcher {
         };
 
         debug!("tc dispatcher config: {:?}", config);
-        let image = BytecodeImage::new(
-            "quay.io/bpfman/tc-dispatcher:v1".to_string(),
-            ImagePullPolicy::IfNotPresent as i32,
-            None,
-            None,
-        );
-        let (tx, rx) = oneshot::channel();
-        image_manager
-            .send(ImageManagerCommand::Pull {
-                image: image.image_url.clone(),
-                pull_policy: image.image_pull_policy.clone(),
-                username: image.username.clone(),
-                password: image.password.clone(),
-                resp: tx,
-            })
-            .await
-            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
-
-        let (path, bpf_function_name) = rx
-            .await
-            .map_err(BpfmanError::RpcRecvError)?
-            .map_err(BpfmanError::BpfBytecodeError)?;
-
-        let (tx, rx) = oneshot::channel();
-        image_manager
-            .send(ImageManagerCommand::GetBytecode { path, resp: tx })
-            .await
-            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
-        let program_bytes = rx.await?.map_err(BpfmanError::BpfBytecodeError)?;
-
+        let (program_bytes, bpf_function_name) =
+            dispatcher_bytecode(&image, TC_DISPATCHER, image_manager).await?;
         let mut loader = BpfLoader::new()
             .set_global("CONFIG", &config, true)
             .load(&program_bytes)?;
@@ -152,21 +175,26 @@ impl TcDispatcher {
             Ingress => RTDIR_FS_TC_INGRESS,
             Egress => RTDIR_FS_TC_EGRESS,
         };
-        let path = format!("{base}/dispatcher_{if_index}_{revision}");
+        let key = self.key()?;
+        let path = format!("{base}/dispatcher_{key}_{revision}");
         fs::create_dir_all(path).unwrap();
 
         self.loader = Some(loader);
         self.set_num_extensions(extensions.len())?;
         self.set_program_name(&bpf_function_name)?;
 
-        self.attach_extensions(&mut extensions).await?;
-        self.attach(old_dispatcher).await?;
-        Ok(())
+        self.attach_extensions(&mut extensions).await
     }
 
     /// has_qdisc returns true if the qdisc_name is found on the if_index.
-    async fn has_qdisc(qdisc_name: String, if_index: i32) -> Result<bool, anyhow::Error> {
-        let (connection, handle, _) = rtnetlink::new_connection().unwrap();
+    /// The netlink socket is opened in netns, if any, and keeps talking to
+    /// that namespace afterwards.
+    async fn has_qdisc(
+        qdisc_name: String,
+        if_index: i32,
+        netns: Option<&Path>,
+    ) -> Result<bool, anyhow::Error> {
+        let (connection, handle, _) = in_netns(netns, || Ok(rtnetlink::new_connection()?))?;
         tokio::spawn(connection);
 
         let mut qdiscs = handle.qdisc().get().execute();
@@ -187,6 +215,7 @@ impl TcDispatcher {
         let revision = self.get_revision()?;
         let direction = self.get_direction()?;
         let program_name = self.get_program_name()?;
+        let netns = self.get_netns()?;
 
         debug!(
             "TcDispatcher::attach() for if_index {}, revision {}",
@@ -198,14 +227,16 @@ impl TcDispatcher {
         // qdisc, we return an error. If the qdisc is a clsact qdisc, we do nothing. Otherwise, we add a clsact qdisc.
 
         // no need to add a new clsact qdisc if one already exists.
-        if TcDispatcher::has_qdisc("clsact".to_string(), if_index as i32).await? {
+        if TcDispatcher::has_qdisc("clsact".to_string(), if_index as i32, netns.as_deref()).await? {
             debug!(
                 "clsact qdisc found for if_index {}, no need to add a new clsact qdisc",
                 if_index
             );
 
         // if ingress qdisc exists, return error.
-        } else if TcDispatcher::has_qdisc("ingress".to_string(), if_index as i32).await? {
+        } else if TcDispatcher::has_qdisc("ingress".to_string(), if_index as i32, netns.as_deref())
+            .await?
+        {
             debug!("ingress qdisc found for if_index {}", if_index);
             return Err(BpfmanError::InvalidAttach(format!(
                 "Ingress qdisc found for if_index {}",
@@ -215,7 +246,7 @@ impl TcDispatcher {
         // otherwise, add a new clsact qdisc.
         } else {
             debug!("No qdisc found for if_index {}, adding clsact", if_index);
-            let _ = tc::qdisc_add_clsact(&iface);
+            let _ = in_netns(netns.as_deref(), || Ok(tc::qdisc_add_clsact(&iface)?));
         }
 
         let new_dispatcher: &mut SchedClassifier = self
@@ -231,14 +262,16 @@ impl TcDispatcher {
             Direction::Egress => TcAttachType::Egress,
         };
 
-        let link_id = new_dispatcher.attach_with_options(
-            &iface,
-            attach_type,
-            TcOptions {
-                priority,
-                ..Default::default()
-            },
-        )?;
+        let link_id = in_netns(netns.as_deref(), || {
+            Ok(new_dispatcher.attach_with_options(
+                &iface,
+                attach_type,
+                TcOptions {
+                    priority,
+                    ..Default::default()
+                },
+            )?)
+        })?;
 
         let link = new_dispatcher.take_link(link_id)?;
         self.set_handle(link.handle())?;
@@ -267,6 +300,7 @@ impl TcDispatcher {
         let revision = self.get_revision()?;
         let direction = self.get_direction()?;
         let program_name = self.get_program_name()?;
+        let key = self.key()?;
 
         debug!(
             "TcDispatcher::attach_extensions() for if_index {}, revision {}",
@@ -300,11 +334,12 @@ impl TcDispatcher {
                     Direction::Ingress => RTDIR_FS_TC_INGRESS,
                     Direction::Egress => RTDIR_FS_TC_EGRESS,
                 };
-                let path = format!("{base}/dispatcher_{if_index}_{}/link_{id}", revision);
+                let path = format!("{base}/dispatcher_{key}_{}/link_{id}", revision);
                 new_link.pin(path).map_err(BpfmanError::UnableToPinLink)?;
             } else {
                 let name = &v.data.get_name()?;
                 let global_data = &v.data.get_global_data()?;
+                let map_max_entries = &v.data.get_map_max_entries()?;
 
                 let mut bpf = BpfLoader::new();
 
@@ -314,6 +349,10 @@ impl TcDispatcher {
                     bpf.set_global(name, value.as_slice(), true);
                 }
 
+                for (name, max_entries) in map_max_entries {
+                    bpf.set_max_entries(name, *max_entries);
+                }
+
                 // If map_pin_path is set already it means we need to use a pin
                 // path which should already exist on the system.
                 if let Some(map_pin_path) = v.data.get_map_pin_path()? {
@@ -321,6 +360,9 @@ impl TcDispatcher {
                     bpf.map_pin_path(map_pin_path);
                 }
 
+                set_verifier_log_level(&mut bpf, &v.data)?;
+                set_external_btf(&mut bpf);
+
                 let mut loader = bpf
                     .load(v.data.program_bytes())
                     .map_err(BpfmanError::BpfLoadError)?;
@@ -347,29 +389,29 @@ impl TcDispatcher {
                     Direction::Egress => RTDIR_FS_TC_EGRESS,
                 };
                 fd_link
-                    .pin(format!(
-                        "{base}/dispatcher_{if_index}_{}/link_{id}",
-                        revision,
-                    ))
+                    .pin(format!("{base}/dispatcher_{key}_{}/link_{id}", revision,))
                     .map_err(BpfmanError::UnableToPinLink)?;
 
-                // If this program is the map(s) owner pin all maps (except for .rodata and .bss) by name.
+                // If this program is the map(s) owner pin its maps as its map pin policy says.
                 if v.data.get_map_pin_path()?.is_none() {
                     let map_pin_path = calc_map_pin_path(id);
                     v.data.set_map_pin_path(&map_pin_path.clone())?;
                     create_map_pin_path(&map_pin_path).await?;
 
+                    let policy = v.data.get_map_pin_policy()?;
                     for (name, map) in loader.maps_mut() {
-                        if !should_map_be_pinned(name) {
-                            continue;
-                        }
+                        let pin_name = match policy.pin_name(name) {
+                            Some(pin_name) => pin_name,
+                            None => continue,
+                        };
                         debug!(
                             "Pinning map: {name} to path: {}",
-                            map_pin_path.join(name).display()
+                            map_pin_path.join(pin_name).display()
                         );
-                        map.pin(map_pin_path.join(name))
+                        map.pin(map_pin_path.join(pin_name))
                             .map_err(BpfmanError::UnableToPinMap)?;
                     }
+                    initialize_maps(&map_pin_path, &v.data)?;
                 }
             }
         }
@@ -383,6 +425,8 @@ impl TcDispatcher {
         let direction = self.get_direction()?;
         let handle = self.get_handle()?;
         let priority = self.get_priority()?;
+        let netns = self.get_netns()?;
+        let key = self.key()?;
 
         debug!(
             "TcDispatcher::delete() for if_index {}, revision {}",
@@ -403,31 +447,32 @@ impl TcDispatcher {
             Direction::Ingress => RTDIR_FS_TC_INGRESS,
             Direction::Egress => RTDIR_FS_TC_EGRESS,
         };
-        let path = format!("{base}/dispatcher_{}_{}", if_index, revision);
+        let path = format!("{base}/dispatcher_{}_{}", key, revision);
         fs::remove_dir_all(path)
             .map_err(|e| BpfmanError::Error(format!("unable to cleanup state: {e}")))?;
 
         if full {
+            remove_stats_map(&stats_path(base, &key))?;
             // Also detach the old dispatcher.
             if let Some(old_handle) = handle {
                 let attach_type = match direction {
                     Direction::Ingress => TcAttachType::Ingress,
                     Direction::Egress => TcAttachType::Egress,
                 };
-                if let Ok(old_link) =
-                    SchedClassifierLink::attached(&if_name, attach_type, priority, old_handle)
-                {
-                    let detach_result = old_link.detach();
-                    match detach_result {
-                        Ok(_) => debug!(
-                            "TC dispatcher {}, {}, {}, {} successfully detached",
-                            if_name, direction, priority, old_handle
-                        ),
-                        Err(_) => debug!(
-                            "TC dispatcher {}, {}, {}, {} not attached when detach attempted",
-                            if_name, direction, priority, old_handle
-                        ),
-                    }
+                let detach_result = in_netns(netns.as_deref(), || {
+                    let old_link =
+                        SchedClassifierLink::attached(&if_name, attach_type, priority, old_handle)?;
+                    Ok(old_link.detach()?)
+                });
+                match detach_result {
+                    Ok(_) => debug!(
+                        "TC dispatcher {}, {}, {}, {} successfully detached",
+                        if_name, direction, priority, old_handle
+                    ),
+                    Err(_) => debug!(
+                        "TC dispatcher {}, {}, {}, {} not attached when detach attempted",
+                        if_name, direction, priority, old_handle
+                    ),
                 }
             };
         }
@@ -458,6 +503,28 @@ impl TcDispatcher {
         sled_get(&self.db_tree, "if_name").map(|v| bytes_to_string(&v))
     }
 
+    pub(crate) fn set_netns(&mut self, netns: &Path) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "netns", netns.to_str().unwrap().as_bytes())
+    }
+
+    pub(crate) fn get_netns(&self) -> Result<Option<PathBuf>, BpfmanError> {
+        sled_get_option(&self.db_tree, "netns")
+            .map(|v| v.map(|v| PathBuf::from(bytes_to_string(&v))))
+    }
+
+    pub(crate) fn set_nsid(&mut self, nsid: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "nsid", &nsid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_nsid(&self) -> Result<Option<u64>, BpfmanError> {
+        sled_get_option(&self.db_tree, "nsid").map(|v| v.map(bytes_to_u64))
+    }
+
+    // Returns the key the dispatcher is stored and pinned under.
+    fn key(&self) -> Result<String, BpfmanError> {
+        Ok(dispatcher_key(self.get_ifindex()?, self.get_nsid()?))
+    }
+
     pub(crate) fn set_priority(&mut self, priority: u16) -> Result<(), BpfmanError> {
         sled_insert(&self.db_tree, "priority", &priority.to_ne_bytes())
     }
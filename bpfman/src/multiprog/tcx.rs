@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// tcx links, added in Linux 6.6, let the kernel run several TC programs on the
+// same hook in a given order. Unlike the tc dispatcher, adding or removing a
+// program never requires reloading the others, so there's no revision
+// tracking here: each program owns a single link pinned next to it.
+
+use aya::{
+    programs::{
+        links::{FdLink, LinkOrder},
+        tc::{SchedClassifierLink, TcAttachOptions},
+        ProgramId, SchedClassifier, TcAttachType,
+    },
+    util::KernelVersion,
+};
+use bpfman_api::util::directories::RTDIR_FS;
+use log::debug;
+
+use crate::{
+    command::{Direction, TcProgram},
+    errors::BpfmanError,
+    utils::in_netns,
+};
+
+// Returns an error if the running kernel predates tcx links.
+pub(crate) fn check_tcx_supported() -> Result<(), BpfmanError> {
+    let current = KernelVersion::current()
+        .map_err(|e| BpfmanError::Error(format!("unable to determine kernel version: {e}")))?;
+    if current < KernelVersion::new(6, 6, 0) {
+        return Err(BpfmanError::TcxKernelTooOld);
+    }
+    Ok(())
+}
+
+// Attaches a loaded TC program with a tcx link and pins the link. The program
+// is placed in front of the program with kernel id `before`, or after every
+// other program on the hook when there is none.
+pub(crate) fn attach(
+    program: &TcProgram,
+    classifier: &mut SchedClassifier,
+    before: Option<u32>,
+) -> Result<(), BpfmanError> {
+    let id = program.get_data().get_id()?;
+    let iface = program.get_iface()?;
+    let netns = program.get_netns()?;
+    let attach_type = match program.get_direction()? {
+        Direction::Ingress => TcAttachType::Ingress,
+        Direction::Egress => TcAttachType::Egress,
+    };
+
+    let order = match before {
+        // SAFETY: the id is that of a program bpfman attached to this hook.
+        Some(before) => LinkOrder::before_program_id(unsafe { ProgramId::new(before) }),
+        None => LinkOrder::last(),
+    };
+    debug!("attaching tc program {id} to {iface} with tcx, before {before:?}");
+
+    let link_id =
+        in_netns(netns.as_deref(), || {
+            Ok(classifier.attach_with_options(
+                &iface,
+                attach_type,
+                TcAttachOptions::TcxOrder(order),
+            )?)
+        })?;
+    let owned_link: SchedClassifierLink = classifier.take_link(link_id)?;
+    let fd_link: FdLink = owned_link
+        .try_into()
+        .expect("unable to get owned tcx attach link");
+
+    fd_link
+        .pin(format!("{RTDIR_FS}/prog_{id}_link"))
+        .map_err(BpfmanError::UnableToPinLink)?;
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{fs, path::PathBuf};
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
 use aya::{
     programs::{
@@ -10,40 +14,70 @@ use aya::{
     },
     Bpf, BpfLoader,
 };
-use bpfman_api::{config::XdpMode, util::directories::*, ImagePullPolicy};
-use log::debug;
-use tokio::sync::{mpsc::Sender, oneshot};
+use bpfman_api::{config::XdpMode, dispatcher_stats::stats_path, util::directories::*};
+use log::{debug, warn};
+use nix::libc;
+use tokio::sync::mpsc::Sender;
 
 use crate::{
     bpf::{calc_map_pin_path, create_map_pin_path},
     command::{Program, XdpProgram},
     dispatcher_config::XdpDispatcherConfig,
     errors::BpfmanError,
-    multiprog::Dispatcher,
+    external_btf::set_external_btf,
+    maps::initialize_maps,
+    multiprog::{
+        dispatcher_bytecode, dispatcher_key, pin_stats_map, remove_stats_map, Dispatcher,
+        XDP_DISPATCHER,
+    },
     oci_utils::image_manager::{BytecodeImage, Command as ImageManagerCommand},
     utils::{
-        bytes_to_string, bytes_to_u32, bytes_to_usize, should_map_be_pinned, sled_get, sled_insert,
+        bytes_to_bool, bytes_to_string, bytes_to_u32, bytes_to_u64, bytes_to_usize, in_netns,
+        sled_get, sled_get_option, sled_insert,
     },
+    verifier::set_verifier_log_level,
     ROOT_DB,
 };
 
 pub(crate) const DEFAULT_PRIORITY: u32 = 50;
 
+const ENOTSUPP: i32 = 524;
+
 #[derive(Debug)]
 pub struct XdpDispatcher {
     db_tree: sled::Tree,
     loader: Option<Bpf>,
 }
 
+// Drivers without native or offloaded XDP reject attaching in those modes with
+// EOPNOTSUPP, or the kernel internal ENOTSUPP.
+fn mode_unsupported(e: &(dyn Error + 'static)) -> bool {
+    let mut source = Some(e);
+    while let Some(e) = source {
+        if let Some(e) = e.downcast_ref::<io::Error>() {
+            return matches!(e.raw_os_error(), Some(libc::EOPNOTSUPP | ENOTSUPP));
+        }
+        source = e.source();
+    }
+    false
+}
+
 impl XdpDispatcher {
     pub(crate) fn new(
         mode: XdpMode,
+        mode_fallback: bool,
         if_index: u32,
         if_name: String,
+        netns: Option<PathBuf>,
+        nsid: Option<u64>,
         revision: u32,
     ) -> Result<Self, BpfmanError> {
         let db_tree = ROOT_DB
-            .open_tree(format!("xdp_dispatcher_{}_{}", if_index, revision))
+            .open_tree(format!(
+                "xdp_dispatcher_{}_{}",
+                dispatcher_key(if_index, nsid),
+                revision
+            ))
             .expect("Unable to open xdp dispatcher database tree");
 
         let mut dp = Self {
@@ -53,7 +87,14 @@ impl XdpDispatcher {
 
         dp.set_ifindex(if_index)?;
         dp.set_ifname(&if_name)?;
+        if let Some(netns) = netns {
+            dp.set_netns(&netns)?;
+        }
+        if let Some(nsid) = nsid {
+            dp.set_nsid(nsid)?;
+        }
         dp.set_mode(mode)?;
+        dp.set_mode_fallback(mode_fallback)?;
         dp.set_revision(revision)?;
         Ok(dp)
     }
@@ -66,10 +107,47 @@ impl XdpDispatcher {
         }
     }
 
+    pub(crate) fn reopen(&self) -> Self {
+        Self::new_from_db(self.db_tree.clone())
+    }
+
     pub(crate) async fn load(
         &mut self,
         programs: &mut [&mut Program],
         old_dispatcher: Option<Dispatcher>,
+        image: BytecodeImage,
+        image_manager: Sender<ImageManagerCommand>,
+    ) -> Result<(), BpfmanError> {
+        self.load_extensions(programs, image, image_manager).await?;
+        // The link of the old dispatcher is reused, along with the mode it
+        // was attached in.
+        if let Some(Dispatcher::Xdp(old)) = &old_dispatcher {
+            self.set_mode(old.get_mode()?)?;
+        }
+        self.attach()?;
+        let mode = self.get_mode()?;
+        for p in programs.iter_mut() {
+            if let Program::Xdp(p) = p {
+                p.set_mode(mode)?;
+            }
+        }
+        let key = self.key()?;
+        pin_stats_map(
+            self.loader.as_mut().ok_or(BpfmanError::NotLoaded)?,
+            &stats_path(RTDIR_FS_XDP, &key),
+        )?;
+        if let Some(mut old) = old_dispatcher {
+            old.delete(false)?;
+        }
+        Ok(())
+    }
+
+    // Loads the dispatcher and the programs into its slots, without attaching
+    // the dispatcher to the interface.
+    pub(crate) async fn load_extensions(
+        &mut self,
+        programs: &mut [&mut Program],
+        image: BytecodeImage,
         image_manager: Sender<ImageManagerCommand>,
     ) -> Result<(), BpfmanError> {
         let if_index = self.get_ifindex()?;
@@ -102,38 +180,8 @@ impl XdpDispatcher {
         );
 
         debug!("xdp dispatcher config: {:?}", config);
-        let image = BytecodeImage::new(
-            "quay.io/bpfman/xdp-dispatcher:v2".to_string(),
-            ImagePullPolicy::IfNotPresent as i32,
-            None,
-            None,
-        );
-        let (tx, rx) = oneshot::channel();
-        image_manager
-            .send(ImageManagerCommand::Pull {
-                image: image.image_url.clone(),
-                pull_policy: image.image_pull_policy.clone(),
-                username: image.username.clone(),
-                password: image.password.clone(),
-                resp: tx,
-            })
-            .await
-            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
-
-        let (path, bpf_function_name) = rx
-            .await
-            .map_err(BpfmanError::RpcRecvError)?
-            .map_err(BpfmanError::BpfBytecodeError)?;
-
-        let (tx, rx) = oneshot::channel();
-        image_manager
-            .send(ImageManagerCommand::GetBytecode { path, resp: tx })
-            .await
-            .map_err(|e| BpfmanError::RpcSendError(e.into()))?;
-        let program_bytes = rx
-            .await
-            .map_err(BpfmanError::RpcRecvError)?
-            .map_err(BpfmanError::BpfBytecodeError)?;
+        let (program_bytes, bpf_function_name) =
+            dispatcher_bytecode(&image, XDP_DISPATCHER, image_manager).await?;
         let mut loader = BpfLoader::new()
             .set_global("conf", &config, true)
             .load(&program_bytes)?;
@@ -142,25 +190,22 @@ impl XdpDispatcher {
 
         dispatcher.load()?;
 
-        let path = format!("{RTDIR_FS_XDP}/dispatcher_{if_index}_{revision}");
+        let key = self.key()?;
+        let path = format!("{RTDIR_FS_XDP}/dispatcher_{key}_{revision}");
         fs::create_dir_all(path).unwrap();
 
         self.loader = Some(loader);
         self.set_num_extensions(extensions.len())?;
         self.set_program_name(&bpf_function_name)?;
 
-        self.attach_extensions(&mut extensions).await?;
-        self.attach()?;
-        if let Some(mut old) = old_dispatcher {
-            old.delete(false)?;
-        }
-        Ok(())
+        self.attach_extensions(&mut extensions).await
     }
 
     pub(crate) fn attach(&mut self) -> Result<(), BpfmanError> {
         let if_index = self.get_ifindex()?;
         let revision = self.get_revision()?;
         let mode = self.get_mode()?;
+        let fallback = self.get_mode_fallback()?;
         let program_name = self.get_program_name()?;
 
         debug!(
@@ -168,6 +213,8 @@ impl XdpDispatcher {
             if_index, revision
         );
         let iface = self.get_ifname()?;
+        let netns = self.get_netns()?;
+        let key = self.key()?;
         let dispatcher: &mut Xdp = self
             .loader
             .as_mut()
@@ -176,21 +223,34 @@ impl XdpDispatcher {
             .unwrap()
             .try_into()?;
 
-        let path = PathBuf::from(format!("{RTDIR_FS_XDP}/dispatcher_{if_index}_link"));
+        let path = PathBuf::from(format!("{RTDIR_FS_XDP}/dispatcher_{key}_link"));
         if path.exists() {
             let pinned_link: FdLink = PinnedLink::from_pin(path).unwrap().into();
             dispatcher
                 .attach_to_link(pinned_link.try_into().unwrap())
                 .unwrap();
         } else {
-            let flags = mode.as_flags();
-            let link = dispatcher.attach(&iface, flags).map_err(|e| {
-                BpfmanError::Error(format!(
-                    "dispatcher attach failed on interface {iface}: {e}"
-                ))
+            let (link, effective_mode) = in_netns(netns.as_deref(), || {
+                match dispatcher.attach(&iface, mode.as_flags()) {
+                    Err(e) if fallback && mode != XdpMode::Skb && mode_unsupported(&e) => {
+                        warn!(
+                            "{} mode is not supported on interface {iface}, falling back to skb mode: {e}",
+                            mode.to_string()
+                        );
+                        dispatcher
+                            .attach(&iface, XdpMode::Skb.as_flags())
+                            .map(|link| (link, XdpMode::Skb))
+                    }
+                    result => result.map(|link| (link, mode)),
+                }
+                .map_err(|e| {
+                    BpfmanError::Error(format!(
+                        "dispatcher attach failed on interface {iface}: {e}"
+                    ))
+                })
             })?;
             let owned_link = dispatcher.take_link(link)?;
-            let path = format!("{RTDIR_FS_XDP}/dispatcher_{if_index}_link");
+            let path = format!("{RTDIR_FS_XDP}/dispatcher_{key}_link");
             let _ = TryInto::<FdLink>::try_into(owned_link)
                 .map_err(|e| {
                     BpfmanError::Error(format!(
@@ -199,6 +259,7 @@ impl XdpDispatcher {
                 })?
                 .pin(path)
                 .map_err(BpfmanError::UnableToPinLink)?;
+            self.set_mode(effective_mode)?;
         }
         Ok(())
     }
@@ -210,6 +271,7 @@ impl XdpDispatcher {
         let if_index = self.get_ifindex()?;
         let revision = self.get_revision()?;
         let program_name = self.get_program_name()?;
+        let key = self.key()?;
         debug!(
             "XdpDispatcher::attach_extensions() for if_index {}, revision {}",
             if_index, revision
@@ -235,14 +297,12 @@ impl XdpDispatcher {
                     .attach_to_program(dispatcher.fd().unwrap(), &target_fn)
                     .unwrap();
                 let new_link: FdLink = ext.take_link(new_link_id)?.into();
-                let path = format!(
-                    "{RTDIR_FS_XDP}/dispatcher_{if_index}_{}/link_{id}",
-                    revision
-                );
+                let path = format!("{RTDIR_FS_XDP}/dispatcher_{key}_{}/link_{id}", revision);
                 new_link.pin(path).map_err(BpfmanError::UnableToPinLink)?;
             } else {
                 let name = &v.get_data().get_name()?;
                 let global_data = &v.get_data().get_global_data()?;
+                let map_max_entries = &v.get_data().get_map_max_entries()?;
 
                 let mut bpf = BpfLoader::new();
 
@@ -252,6 +312,10 @@ impl XdpDispatcher {
                     bpf.set_global(name, value.as_slice(), true);
                 }
 
+                for (name, max_entries) in map_max_entries {
+                    bpf.set_max_entries(name, *max_entries);
+                }
+
                 // If map_pin_path is set already it means we need to use a pin
                 // path which should already exist on the system.
                 if let Some(map_pin_path) = v.get_data().get_map_pin_path()? {
@@ -259,6 +323,9 @@ impl XdpDispatcher {
                     bpf.map_pin_path(map_pin_path);
                 }
 
+                set_verifier_log_level(&mut bpf, v.get_data())?;
+                set_external_btf(&mut bpf);
+
                 let mut loader = bpf
                     .load(v.get_data().program_bytes())
                     .map_err(BpfmanError::BpfLoadError)?;
@@ -282,28 +349,31 @@ impl XdpDispatcher {
                 let fd_link: FdLink = new_link.into();
                 fd_link
                     .pin(format!(
-                        "{RTDIR_FS_XDP}/dispatcher_{if_index}_{}/link_{id}",
+                        "{RTDIR_FS_XDP}/dispatcher_{key}_{}/link_{id}",
                         revision,
                     ))
                     .map_err(BpfmanError::UnableToPinLink)?;
 
-                // If this program is the map(s) owner pin all maps (except for .rodata and .bss) by name.
+                // If this program is the map(s) owner pin its maps as its map pin policy says.
                 if v.get_data().get_map_pin_path()?.is_none() {
                     let map_pin_path = calc_map_pin_path(id);
                     v.get_data_mut().set_map_pin_path(&map_pin_path)?;
                     create_map_pin_path(&map_pin_path).await?;
 
+                    let policy = v.get_data().get_map_pin_policy()?;
                     for (name, map) in loader.maps_mut() {
-                        if !should_map_be_pinned(name) {
-                            continue;
-                        }
+                        let pin_name = match policy.pin_name(name) {
+                            Some(pin_name) => pin_name,
+                            None => continue,
+                        };
                         debug!(
                             "Pinning map: {name} to path: {}",
-                            map_pin_path.join(name).display()
+                            map_pin_path.join(pin_name).display()
                         );
-                        map.pin(map_pin_path.join(name))
+                        map.pin(map_pin_path.join(pin_name))
                             .map_err(BpfmanError::UnableToPinMap)?;
                     }
+                    initialize_maps(&map_pin_path, v.get_data())?;
                 }
             }
         }
@@ -327,13 +397,15 @@ impl XdpDispatcher {
             )
         })?;
 
-        let path = format!("{RTDIR_FS_XDP}/dispatcher_{}_{}", if_index, revision);
+        let key = self.key()?;
+        let path = format!("{RTDIR_FS_XDP}/dispatcher_{}_{}", key, revision);
         fs::remove_dir_all(path)
             .map_err(|e| BpfmanError::Error(format!("unable to cleanup state: {e}")))?;
         if full {
-            let path_link = format!("{RTDIR_FS_XDP}/dispatcher_{}_link", if_index);
+            let path_link = format!("{RTDIR_FS_XDP}/dispatcher_{}_link", key);
             fs::remove_file(path_link)
                 .map_err(|e| BpfmanError::Error(format!("unable to cleanup state: {e}")))?;
+            remove_stats_map(&stats_path(RTDIR_FS_XDP, &key))?;
         }
         Ok(())
     }
@@ -362,6 +434,28 @@ impl XdpDispatcher {
         sled_get(&self.db_tree, "if_name").map(|v| bytes_to_string(&v))
     }
 
+    pub(crate) fn set_netns(&mut self, netns: &Path) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "netns", netns.to_str().unwrap().as_bytes())
+    }
+
+    pub(crate) fn get_netns(&self) -> Result<Option<PathBuf>, BpfmanError> {
+        sled_get_option(&self.db_tree, "netns")
+            .map(|v| v.map(|v| PathBuf::from(bytes_to_string(&v))))
+    }
+
+    pub(crate) fn set_nsid(&mut self, nsid: u64) -> Result<(), BpfmanError> {
+        sled_insert(&self.db_tree, "nsid", &nsid.to_ne_bytes())
+    }
+
+    pub(crate) fn get_nsid(&self) -> Result<Option<u64>, BpfmanError> {
+        sled_get_option(&self.db_tree, "nsid").map(|v| v.map(bytes_to_u64))
+    }
+
+    // Returns the key the dispatcher is stored and pinned under.
+    fn key(&self) -> Result<String, BpfmanError> {
+        Ok(dispatcher_key(self.get_ifindex()?, self.get_nsid()?))
+    }
+
     pub(crate) fn set_mode(&mut self, mode: XdpMode) -> Result<(), BpfmanError> {
         sled_insert(&self.db_tree, "mode", &(mode as u32).to_ne_bytes())
     }
@@ -372,6 +466,20 @@ impl XdpDispatcher {
         })?
     }
 
+    pub(crate) fn set_mode_fallback(&mut self, fallback: bool) -> Result<(), BpfmanError> {
+        sled_insert(
+            &self.db_tree,
+            "mode_fallback",
+            &(fallback as i8).to_ne_bytes(),
+        )
+    }
+
+    pub(crate) fn get_mode_fallback(&self) -> Result<bool, BpfmanError> {
+        Ok(sled_get_option(&self.db_tree, "mode_fallback")?
+            .map(bytes_to_bool)
+            .unwrap_or(false))
+    }
+
     pub(crate) fn set_num_extensions(&mut self, num_extensions: usize) -> Result<(), BpfmanError> {
         sled_insert(
             &self.db_tree,
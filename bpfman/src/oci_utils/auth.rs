@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Registry credentials for images pulled without a username and password are
+// looked up in the auth files docker and podman keep, so that they don't have
+// to be passed with every request. The files map registries to base64 encoded
+// "<username>:<password>" strings, or to credential helpers which are run to
+// get the credentials:
+//
+// {
+//   "auths": { "quay.io": { "auth": "..." } },
+//   "credHelpers": { "registry.example.com": "ecr-login" },
+//   "credsStore": "desktop"
+// }
+
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+    process::Stdio,
+};
+
+use base64::{engine::general_purpose, Engine};
+use log::{debug, warn};
+use serde::Deserialize;
+use tokio::{io::AsyncWriteExt, process::Command};
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct AuthFile {
+    #[serde(default)]
+    auths: HashMap<String, AuthEntry>,
+    #[serde(default)]
+    cred_helpers: HashMap<String, String>,
+    creds_store: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthEntry {
+    auth: Option<String>,
+}
+
+// The output of "docker-credential-<helper> get".
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HelperCredentials {
+    username: String,
+    secret: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct RegistryCredentials {
+    auth_files: Vec<PathBuf>,
+}
+
+impl RegistryCredentials {
+    // If no auth files are configured, the files podman and docker use are
+    // searched in the order podman searches them.
+    pub(crate) fn new(auth_files: &[String]) -> Self {
+        let auth_files = if auth_files.is_empty() {
+            default_auth_files()
+        } else {
+            auth_files.iter().map(PathBuf::from).collect()
+        };
+        Self { auth_files }
+    }
+
+    // Returns the username and password for the repository of registry from
+    // the first auth file with credentials for it. Files are read on every
+    // lookup so that logins are picked up without restarting bpfman.
+    pub(crate) async fn lookup(
+        &self,
+        registry: &str,
+        repository: &str,
+    ) -> Option<(String, String)> {
+        for path in &self.auth_files {
+            let file = match read_auth_file(path) {
+                Some(file) => file,
+                None => continue,
+            };
+            if let Some(credentials) = file_credentials(&file, registry, repository).await {
+                debug!("Using credentials for {registry} from {}", path.display());
+                return Some(credentials);
+            }
+        }
+        None
+    }
+}
+
+fn default_auth_files() -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Ok(path) = env::var("REGISTRY_AUTH_FILE") {
+        files.push(PathBuf::from(path));
+    }
+    if let Ok(dir) = env::var("XDG_RUNTIME_DIR") {
+        files.push(Path::new(&dir).join("containers/auth.json"));
+    }
+    files.push(PathBuf::from("/run/containers/0/auth.json"));
+    if let Ok(home) = env::var("HOME") {
+        files.push(Path::new(&home).join(".config/containers/auth.json"));
+        files.push(Path::new(&home).join(".docker/config.json"));
+    }
+    files
+}
+
+fn read_auth_file(path: &Path) -> Option<AuthFile> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&contents) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            warn!("Ignoring auth file {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+async fn file_credentials(
+    file: &AuthFile,
+    registry: &str,
+    repository: &str,
+) -> Option<(String, String)> {
+    if let Some(helper) = file
+        .cred_helpers
+        .iter()
+        .find(|(key, _)| normalize_key(key) == registry)
+        .map(|(_, helper)| helper)
+    {
+        return run_helper(helper, registry).await;
+    }
+
+    // Keys may name a repository or a namespace of the registry, in which case
+    // the most specific one applies.
+    let image = format!("{registry}/{repository}");
+    let entry = file
+        .auths
+        .iter()
+        .filter(|(key, _)| {
+            let key = normalize_key(key);
+            key == registry || image.starts_with(&format!("{key}/"))
+        })
+        .max_by_key(|(key, _)| normalize_key(key).len());
+    if let Some((_, entry)) = entry {
+        if let Some(auth) = &entry.auth {
+            return decode_auth(auth);
+        }
+    }
+
+    match &file.creds_store {
+        Some(helper) => run_helper(helper, registry).await,
+        None => None,
+    }
+}
+
+// Docker keys registries by URL, e.g. "https://index.docker.io/v1/", while
+// podman keys them by name, optionally followed by a namespace.
+fn normalize_key(key: &str) -> &str {
+    let key = match key
+        .strip_prefix("https://")
+        .or_else(|| key.strip_prefix("http://"))
+    {
+        Some(url) => url.split('/').next().unwrap_or(url),
+        None => key.trim_end_matches('/'),
+    };
+    match key {
+        "index.docker.io" | "registry-1.docker.io" => "docker.io",
+        key => key,
+    }
+}
+
+fn decode_auth(auth: &str) -> Option<(String, String)> {
+    let decoded = general_purpose::STANDARD.decode(auth).ok()?;
+    let (username, password) = std::str::from_utf8(&decoded).ok()?.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+async fn run_helper(helper: &str, registry: &str) -> Option<(String, String)> {
+    let program = format!("docker-credential-{helper}");
+    let output = async {
+        let mut child = Command::new(&program)
+            .arg("get")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(registry.as_bytes()).await?;
+        }
+        child.wait_with_output().await
+    }
+    .await;
+    match output {
+        Ok(output) if output.status.success() => {
+            match serde_json::from_slice::<HelperCredentials>(&output.stdout) {
+                Ok(c) => Some((c.username, c.secret)),
+                Err(e) => {
+                    warn!("Invalid credentials from {program} for {registry}: {e}");
+                    None
+                }
+            }
+        }
+        Ok(output) => {
+            debug!(
+                "{program} has no credentials for {registry}: {}",
+                output.status
+            );
+            None
+        }
+        Err(e) => {
+            warn!("Unable to run {program}: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lookup_auths() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"{{
+                "auths": {{
+                    "quay.io": {{ "auth": "{}" }},
+                    "quay.io/bpfman": {{ "auth": "{}" }},
+                    "https://index.docker.io/v1/": {{ "auth": "{}" }}
+                }}
+            }}"#,
+            general_purpose::STANDARD.encode("someone:secret"),
+            general_purpose::STANDARD.encode("bpfman:other"),
+            general_purpose::STANDARD.encode("docker:hub"),
+        )
+        .unwrap();
+        let credentials = RegistryCredentials::new(&[file.path().to_string_lossy().to_string()]);
+
+        assert_eq!(
+            credentials
+                .lookup("quay.io", "bpfman-bytecode/xdp_pass")
+                .await,
+            Some(("someone".to_string(), "secret".to_string()))
+        );
+        assert_eq!(
+            credentials.lookup("quay.io", "bpfman/xdp-dispatcher").await,
+            Some(("bpfman".to_string(), "other".to_string()))
+        );
+        assert_eq!(
+            credentials.lookup("docker.io", "library/busybox").await,
+            Some(("docker".to_string(), "hub".to_string()))
+        );
+        assert_eq!(
+            credentials.lookup("registry.example.com", "bpfman").await,
+            None
+        );
+    }
+}
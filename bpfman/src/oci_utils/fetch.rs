@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Bytecode files can be fetched from HTTPS URLs, for build pipelines that
+// publish them to object storage rather than to registries. As nothing else
+// vouches for what a URL serves, loads from URLs must give the sha256 digest
+// of the file, which it's checked against. Files are fetched with the proxies,
+// timeouts and retries of image pulls, and not at all when offline.
+
+use std::time::Duration;
+
+use bpfman_api::config::ImagePullConfig;
+use log::{debug, warn};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use tokio::time::{sleep, Instant};
+
+use crate::oci_utils::ImageError;
+
+// Checks that url is an HTTPS URL and sha256 a hex encoded sha256 digest.
+pub(crate) fn check_url(url: &str, sha256: &str) -> Result<(), ImageError> {
+    let invalid =
+        |reason: &str| ImageError::InvalidBytecodeUrl(url.to_string(), reason.to_string());
+    let parsed = Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+    if parsed.scheme() != "https" {
+        return Err(invalid("only https URLs are supported"));
+    }
+    if sha256.len() != 64 || !sha256.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid("a hex encoded sha256 digest is required"));
+    }
+    Ok(())
+}
+
+// Fetches the bytecode file at url and checks it against its digest. Failed
+// fetches are retried with exponential backoff, except for client errors,
+// which a retry won't fix.
+pub(crate) async fn fetch_bytecode(
+    url: &str,
+    sha256: &str,
+    config: &ImagePullConfig,
+) -> Result<Vec<u8>, ImageError> {
+    check_url(url, sha256)?;
+    if config.offline {
+        return Err(ImageError::FetchOffline(url.to_string()));
+    }
+    let failed = |e: reqwest::Error| ImageError::FetchFailed(url.to_string(), e.to_string());
+    let client = reqwest::Client::builder().build().map_err(failed)?;
+
+    let deadline = config
+        .deadline
+        .map(|d| Instant::now() + Duration::from_secs(d));
+    let mut backoff = Duration::from_millis(config.retry_backoff);
+    let mut attempt = 0;
+    let data = loop {
+        attempt += 1;
+        let mut limit = Duration::from_secs(config.timeout);
+        if let Some(deadline) = deadline {
+            limit = limit.min(deadline.saturating_duration_since(Instant::now()));
+        }
+        let err = match fetch(&client, url, limit).await {
+            Ok(data) => break data,
+            Err(e) if e.status().is_some_and(|s| s.is_client_error()) => return Err(failed(e)),
+            Err(e) => e,
+        };
+
+        let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+        if attempt > config.retries || remaining.is_some_and(|r| r <= backoff) {
+            return Err(failed(err));
+        }
+        warn!("Fetching bytecode {url} failed, retrying in {backoff:?}: {err}");
+        sleep(backoff).await;
+        backoff *= 2;
+    };
+
+    let digest = base16ct::lower::encode_string(&Sha256::digest(&data));
+    if digest != sha256.to_lowercase() {
+        return Err(ImageError::UrlDigestMismatch(
+            url.to_string(),
+            sha256.to_string(),
+        ));
+    }
+    debug!("Fetched bytecode {url} of {} bytes", data.len());
+    Ok(data)
+}
+
+async fn fetch(
+    client: &reqwest::Client,
+    url: &str,
+    limit: Duration,
+) -> Result<Vec<u8>, reqwest::Error> {
+    let response = client
+        .get(url)
+        .timeout(limit)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.bytes().await?.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_check_url() {
+        let sha256 = "a".repeat(64);
+        assert!(check_url("https://example.com/xdp_pass.bpf.o", &sha256).is_ok());
+        assert_matches!(
+            check_url("http://example.com/xdp_pass.bpf.o", &sha256),
+            Err(ImageError::InvalidBytecodeUrl(..))
+        );
+        assert_matches!(
+            check_url("https://example.com/xdp_pass.bpf.o", ""),
+            Err(ImageError::InvalidBytecodeUrl(..))
+        );
+        assert_matches!(
+            check_url("https://example.com/xdp_pass.bpf.o", &"g".repeat(64)),
+            Err(ImageError::InvalidBytecodeUrl(..))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Bytecode files loaded from the local filesystem are verified against the
+// public keys of the configuration, as written by "cosign generate-key-pair".
+// A file is signed either by a detached signature next to it, named after the
+// file with a ".sig" suffix, as written by "cosign sign-blob", or by being
+// listed in a SHA256SUMS file of its directory, in the format of sha256sum,
+// which is signed by a SHA256SUMS.sig signature.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use bpfman_api::config::FileSigningConfig;
+use log::{debug, warn};
+use sha2::{Digest, Sha256};
+use sigstore::crypto::{CosignVerificationKey, Signature};
+
+use crate::oci_utils::ImageError;
+
+const CHECKSUMS_FILE: &str = "SHA256SUMS";
+
+pub(crate) struct FileVerifier {
+    keys: Vec<CosignVerificationKey>,
+    allow_unsigned: bool,
+}
+
+impl FileVerifier {
+    pub(crate) fn new(config: &FileSigningConfig) -> Result<Self, anyhow::Error> {
+        let keys = config
+            .public_keys
+            .iter()
+            .map(|path| {
+                let pem =
+                    std::fs::read(path).with_context(|| format!("unable to read key {path}"))?;
+                CosignVerificationKey::try_from_pem(&pem)
+                    .with_context(|| format!("invalid public key {path}"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            keys,
+            allow_unsigned: config.allow_unsigned,
+        })
+    }
+
+    // Checks that the file at path with the given contents is signed. Files
+    // with invalid signatures are rejected even if unsigned files are allowed.
+    pub(crate) async fn verify(&self, path: &Path, data: &[u8]) -> Result<(), ImageError> {
+        let name = path.display().to_string();
+
+        if let Some(signature) = read_optional(&signature_path(path)).await? {
+            self.check_signature(data, &signature)
+                .map_err(|e| ImageError::FileSignatureInvalid(name.clone(), e))?;
+            debug!("Bytecode file {name} has a valid signature");
+            return Ok(());
+        }
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let checksums_path = dir.join(CHECKSUMS_FILE);
+        if let Some(checksums) = read_optional(&checksums_path).await? {
+            let file_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if let Some(expected) = find_checksum(&checksums, &file_name) {
+                let signature = read_optional(&signature_path(&checksums_path))
+                    .await?
+                    .ok_or(ImageError::FileNotSigned(name.clone()))?;
+                self.check_signature(&checksums, &signature).map_err(|e| {
+                    ImageError::FileSignatureInvalid(checksums_path.display().to_string(), e)
+                })?;
+                if base16ct::lower::encode_string(&Sha256::digest(data)) != expected {
+                    return Err(ImageError::FileDigestMismatch(name));
+                }
+                debug!("Bytecode file {name} is listed in a signed {CHECKSUMS_FILE}");
+                return Ok(());
+            }
+        }
+
+        if self.allow_unsigned {
+            warn!("Loading unsigned bytecode file {name}");
+            Ok(())
+        } else {
+            Err(ImageError::FileNotSigned(name))
+        }
+    }
+
+    // Signatures are base64 encoded and valid if any of the keys verifies them.
+    fn check_signature(&self, data: &[u8], signature: &[u8]) -> Result<(), String> {
+        let signature = String::from_utf8_lossy(signature);
+        let signature = signature.trim().as_bytes();
+        if self.keys.iter().any(|key| {
+            key.verify_signature(Signature::Base64Encoded(signature), data)
+                .is_ok()
+        }) {
+            Ok(())
+        } else {
+            Err("no public key verifies the signature".to_string())
+        }
+    }
+}
+
+fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".sig");
+    PathBuf::from(name)
+}
+
+async fn read_optional(path: &Path) -> Result<Option<Vec<u8>>, ImageError> {
+    match tokio::fs::read(path).await {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(ImageError::FileReadError(
+            path.display().to_string(),
+            e.to_string(),
+        )),
+    }
+}
+
+// Returns the sha256 digest listed for file_name. Lines are a digest followed
+// by the name of the file, which sha256sum prefixes with a "*" in binary mode.
+fn find_checksum(checksums: &[u8], file_name: &str) -> Option<String> {
+    String::from_utf8_lossy(checksums).lines().find_map(|line| {
+        let (digest, name) = line.split_once(char::is_whitespace)?;
+        let name = name.trim_start().trim_start_matches('*');
+        (name == file_name).then(|| digest.to_lowercase())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_checksum() {
+        let checksums = b"0a1b  xdp_pass.bpf.o\n2C3D *tc_pass.bpf.o\n";
+        assert_eq!(
+            find_checksum(checksums, "xdp_pass.bpf.o"),
+            Some("0a1b".to_string())
+        );
+        assert_eq!(
+            find_checksum(checksums, "tc_pass.bpf.o"),
+            Some("2c3d".to_string())
+        );
+        assert_eq!(find_checksum(checksums, "pass.bpf.o"), None);
+    }
+}
@@ -1,19 +1,31 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::io::{copy, Read};
+use std::{
+    collections::{HashMap, HashSet},
+    io::{copy, Read},
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use bpfman_api::ImagePullPolicy;
+use bpfman_api::{
+    config::{
+        DispatcherImage, FileSigningConfig, ImageCacheConfig, ImagePullConfig, RegistriesConfig,
+    },
+    ImagePullPolicy,
+};
 use flate2::read::GzDecoder;
-use log::{debug, info, trace};
+use log::{debug, info, trace, warn};
 use oci_distribution::{
     client::{ClientConfig, ClientProtocol},
     manifest,
-    manifest::OciImageManifest,
+    manifest::{OciImageManifest, OciManifest},
     secrets::RegistryAuth,
     Client, Reference,
 };
-use serde::{Deserialize, Serialize};
+use opentelemetry::{trace::FutureExt, Context};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 use sled::Db;
@@ -23,13 +35,31 @@ use tokio::{
     sync::{
         broadcast,
         mpsc::{self, Receiver},
-        oneshot,
+        oneshot, Mutex,
+    },
+    time::{sleep, timeout, Instant},
+    try_join,
+};
+
+use crate::{
+    oci_utils::{
+        auth::RegistryCredentials, cosign::CosignVerifier, fetch::fetch_bytecode,
+        file_signing::FileVerifier, select_platform, ImageError,
     },
+    telemetry::child_span,
+    utils::glob_match,
 };
 
-use crate::oci_utils::{cosign::CosignVerifier, ImageError};
+// Database tree of the URLs, manifest digests and last use times of the images
+// in the image store, which is kept in the root tree.
+const IMAGE_CACHE_TREE: &str = "image_cache";
 
-#[derive(Debug, Deserialize, Default)]
+// The labels of a bytecode image. Images following version 1 of the bytecode
+// image spec hold a single program described by the io.ebpf labels, while
+// images following version 2 list their programs in the "io.ebpf.programs"
+// label, as a JSON array.
+#[derive(Debug, Deserialize, Default, Clone)]
+#[serde(default)]
 pub struct ContainerImageMetadata {
     #[serde(rename(deserialize = "io.ebpf.program_name"))]
     pub name: String,
@@ -39,6 +69,112 @@ pub struct ContainerImageMetadata {
     pub program_type: String,
     #[serde(rename(deserialize = "io.ebpf.filename"))]
     pub filename: String,
+    #[serde(
+        rename(deserialize = "io.ebpf.programs"),
+        deserialize_with = "deserialize_programs"
+    )]
+    pub programs: Vec<ImageProgram>,
+}
+
+// A program of an image following version 2 of the bytecode image spec.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct ImageProgram {
+    pub name: String,
+    pub bpf_function_name: String,
+    pub program_type: String,
+    pub section: String,
+    // Maps the program shares with the other programs of the image, which
+    // should be loaded sharing the maps of the first one loaded.
+    pub maps: Vec<String>,
+    // Values of global variables, as hex strings, used unless the load sets
+    // them.
+    pub global_data: HashMap<String, String>,
+}
+
+impl ImageProgram {
+    pub(crate) fn global_data(&self) -> Result<HashMap<String, Vec<u8>>, ImageError> {
+        self.global_data
+            .iter()
+            .map(|(name, value)| {
+                hex::decode(value).map(|v| (name.clone(), v)).map_err(|e| {
+                    ImageError::InvalidImageMetadata(format!(
+                        "global data {name} of program {} is not hex: {e}",
+                        self.name
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+// Labels are strings, so the programs are a JSON array in a string.
+fn deserialize_programs<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<ImageProgram>, D::Error> {
+    let programs = String::deserialize(deserializer)?;
+    serde_json::from_str(&programs).map_err(serde::de::Error::custom)
+}
+
+impl ContainerImageMetadata {
+    // Returns the programs of the image, with their names defaulting to their
+    // BPF function names and the other way around.
+    pub(crate) fn programs(&self) -> Vec<ImageProgram> {
+        if self.programs.is_empty() {
+            if self.bpf_function_name.is_empty() {
+                return vec![];
+            }
+            return vec![ImageProgram {
+                name: self.name.clone(),
+                bpf_function_name: self.bpf_function_name.clone(),
+                program_type: self.program_type.clone(),
+                ..Default::default()
+            }];
+        }
+        self.programs
+            .iter()
+            .map(|p| ImageProgram {
+                name: if p.name.is_empty() {
+                    p.bpf_function_name.clone()
+                } else {
+                    p.name.clone()
+                },
+                bpf_function_name: if p.bpf_function_name.is_empty() {
+                    p.name.clone()
+                } else {
+                    p.bpf_function_name.clone()
+                },
+                ..p.clone()
+            })
+            .collect()
+    }
+
+    // Selects a program of the image by its name or BPF function name. The
+    // name may be left empty if the image holds a single program.
+    pub(crate) fn select_program(&self, name: &str) -> Result<ImageProgram, ImageError> {
+        let programs = self.programs();
+        let names = || {
+            programs
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if name.is_empty() {
+            return match programs.as_slice() {
+                [program] => Ok(program.clone()),
+                [] => Err(ImageError::InvalidImageMetadata(
+                    "image has no programs".to_string(),
+                )),
+                _ => Err(ImageError::ProgramNameRequired(names())),
+            };
+        }
+        programs
+            .iter()
+            .find(|p| p.name == name || p.bpf_function_name == name)
+            .cloned()
+            .ok_or_else(|| ImageError::ProgramNotInImage(name.to_string(), names()))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -75,6 +211,17 @@ impl BytecodeImage {
     }
 }
 
+impl From<&DispatcherImage> for BytecodeImage {
+    fn from(value: &DispatcherImage) -> Self {
+        Self {
+            image_url: value.url.clone(),
+            image_pull_policy: value.pull_policy.clone(),
+            username: value.username.clone(),
+            password: value.password.clone(),
+        }
+    }
+}
+
 impl From<bpfman_api::v1::BytecodeImage> for BytecodeImage {
     fn from(value: bpfman_api::v1::BytecodeImage) -> Self {
         // This function is mapping an empty string to None for
@@ -99,17 +246,50 @@ impl From<bpfman_api::v1::BytecodeImage> for BytecodeImage {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub(crate) struct BytecodeUrl {
+    pub(crate) url: String,
+    pub(crate) sha256: String,
+}
+
+impl From<bpfman_api::v1::BytecodeUrl> for BytecodeUrl {
+    fn from(value: bpfman_api::v1::BytecodeUrl) -> Self {
+        Self {
+            url: value.url,
+            sha256: value.sha256,
+        }
+    }
+}
+
+// The image manager is cloned into the tasks pulling images.
+#[derive(Clone)]
 pub(crate) struct ImageManager {
     database: Db,
     client: Client,
-    cosign_verifier: CosignVerifier,
-    rx: Receiver<Command>,
+    // None when offline, as the verifier fetches its trust root on creation.
+    cosign_verifier: Option<Arc<Mutex<CosignVerifier>>>,
+    registries: RegistriesConfig,
+    credentials: RegistryCredentials,
+    pull_config: ImagePullConfig,
+    // None unless bytecode files must be verified.
+    file_verifier: Option<Arc<FileVerifier>>,
 }
 
 /// Provided by the requester and used by the manager task to send
 /// the command response back to the requester.
 type Responder<T> = oneshot::Sender<T>;
 
+type PullResult = Result<(String, ContainerImageMetadata), ImageError>;
+
+// Requests to pull the same image with the same pull policy and credentials
+// are served by a single pull.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PullKey {
+    image: String,
+    pull_policy: ImagePullPolicy,
+    username: Option<String>,
+}
+
 #[derive(Debug)]
 pub(crate) enum Command {
     Pull {
@@ -117,21 +297,62 @@ pub(crate) enum Command {
         pull_policy: ImagePullPolicy,
         username: Option<String>,
         password: Option<String>,
-        resp: Responder<Result<(String, String), ImageError>>,
+        /// The trace context of the request the image is pulled for.
+        context: Context,
+        resp: Responder<PullResult>,
     },
     GetBytecode {
         path: String,
         resp: Responder<Result<Vec<u8>, ImageError>>,
     },
+    /// Read a bytecode file, verifying it if the configuration requires.
+    ReadFile {
+        path: String,
+        resp: Responder<Result<Vec<u8>, ImageError>>,
+    },
+    /// Fetch a bytecode file from an HTTPS URL, checking it against its
+    /// sha256 digest.
+    Fetch {
+        url: String,
+        sha256: String,
+        resp: Responder<Result<Vec<u8>, ImageError>>,
+    },
+    /// Remove the images the given image URLs don't refer to, as far as the
+    /// cache limits require, and return the URLs of the removed images.
+    Prune {
+        in_use: Vec<String>,
+        limits: ImageCacheConfig,
+        resp: Responder<Result<Vec<String>, ImageError>>,
+    },
+    /// List the images in the image store along with the ids of the given
+    /// programs, given with their image URLs, that use them.
+    List {
+        programs: Vec<(u32, String)>,
+        resp: Responder<Result<Vec<ImageInfo>, ImageError>>,
+    },
+    /// Use the registries and pull settings of a reloaded configuration.
+    Reload {
+        registries: RegistriesConfig,
+        pull_config: ImagePullConfig,
+    },
 }
 
 impl ImageManager {
     pub(crate) async fn new(
         database: Db,
         allow_unsigned: bool,
-        rx: mpsc::Receiver<Command>,
+        registries: RegistriesConfig,
+        pull_config: ImagePullConfig,
+        file_signing: Option<FileSigningConfig>,
     ) -> Result<Self, anyhow::Error> {
-        let cosign_verifier = CosignVerifier::new(allow_unsigned).await?;
+        let cosign_verifier = if pull_config.offline {
+            info!("Offline, only images in the image store can be loaded");
+            None
+        } else {
+            Some(Arc::new(Mutex::new(
+                CosignVerifier::new(allow_unsigned).await?,
+            )))
+        };
         let config = ClientConfig {
             protocol: ClientProtocol::Https,
             ..Default::default()
@@ -141,11 +362,25 @@ impl ImageManager {
             database,
             cosign_verifier,
             client,
-            rx,
+            credentials: RegistryCredentials::new(&registries.auth_files),
+            registries,
+            pull_config,
+            file_verifier: file_signing
+                .map(|config| FileVerifier::new(&config).map(Arc::new))
+                .transpose()?,
         })
     }
 
-    pub(crate) async fn run(&mut self, mut shutdown_rx: broadcast::Receiver<()>) {
+    pub(crate) async fn run(
+        &mut self,
+        mut rx: Receiver<Command>,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) {
+        // Images are pulled in tasks of their own so that they are pulled
+        // concurrently. Requests to pull an image that is being pulled wait
+        // for that pull.
+        let mut pulls: HashMap<PullKey, Vec<Responder<PullResult>>> = HashMap::new();
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel::<(PullKey, PullResult)>();
         loop {
             // Start receiving messages
             select! {
@@ -155,16 +390,56 @@ impl ImageManager {
                     self.database.flush().expect("Unable to flush database to disk before shutting down ImageManager");
                     break;
                 }
-                Some(cmd) = self.rx.recv() => {
+                Some((key, result)) = done_rx.recv() => {
+                    send_pull_result(pulls.remove(&key).unwrap_or_default(), &key.image, result);
+                }
+                Some(cmd) = rx.recv() => {
                     match cmd {
-                        Command::Pull { image, pull_policy, username, password, resp } => {
-                            let result = self.get_image(&image, pull_policy, username, password).await;
-                            let _ = resp.send(result);
+                        Command::Pull { image, pull_policy, username, password, context, resp } => {
+                            let key = PullKey { image, pull_policy, username };
+                            if let Some(waiting) = pulls.get_mut(&key) {
+                                debug!("Waiting for the pull of image {} in progress", key.image);
+                                waiting.push(resp);
+                            } else {
+                                pulls.insert(key.clone(), vec![resp]);
+                                let mut manager = self.clone();
+                                let done_tx = done_tx.clone();
+                                let cx = child_span("pull image", &context);
+                                tokio::spawn(async move {
+                                    let result = manager
+                                        .get_image(&key.image, key.pull_policy.clone(), key.username.clone(), password)
+                                        .await;
+                                    let _ = done_tx.send((key, result));
+                                }.with_context(cx));
+                            }
                         },
                         Command::GetBytecode { path, resp } => {
                             let result = self.get_bytecode_from_image_store(path).await;
                             let _ = resp.send(result);
                         }
+                        Command::ReadFile { path, resp } => {
+                            let result = self.read_file(&path).await;
+                            let _ = resp.send(result);
+                        }
+                        Command::Fetch { url, sha256, resp } => {
+                            let config = self.pull_config;
+                            tokio::spawn(async move {
+                                let _ = resp.send(fetch_bytecode(&url, &sha256, &config).await);
+                            });
+                        }
+                        Command::Prune { mut in_use, limits, resp } => {
+                            // Images being pulled are about to be used.
+                            in_use.extend(pulls.keys().map(|key| key.image.clone()));
+                            let result = self.prune_images(&in_use, limits);
+                            let _ = resp.send(result);
+                        }
+                        Command::List { programs, resp } => {
+                            let result = self.list_images(&programs);
+                            let _ = resp.send(result);
+                        }
+                        Command::Reload { registries, pull_config } => {
+                            self.reload(registries, pull_config);
+                        }
                     }
                 }
             }
@@ -172,21 +447,33 @@ impl ImageManager {
         info!("image_manager: Stopped processing commands");
     }
 
+    // Pulls started from now on use the new settings, while those in progress
+    // finish with the ones they started with. Whether bpfman is offline is
+    // kept, as signatures can only be verified if it started online.
+    fn reload(&mut self, registries: RegistriesConfig, pull_config: ImagePullConfig) {
+        self.credentials = RegistryCredentials::new(&registries.auth_files);
+        self.registries = registries;
+        self.pull_config = ImagePullConfig {
+            offline: self.pull_config.offline,
+            ..pull_config
+        };
+    }
+
     pub(crate) async fn get_image(
         &mut self,
         image_url: &str,
         pull_policy: ImagePullPolicy,
         username: Option<String>,
         password: Option<String>,
-    ) -> Result<(String, String), ImageError> {
+    ) -> PullResult {
         // The reference created here is created using the krustlet oci-distribution
         // crate. It currently contains many defaults more of which can be seen
         // here: https://github.com/krustlet/oci-distribution/blob/main/src/reference.rs#L58
         let image: Reference = image_url.parse().map_err(ImageError::InvalidImageUrl)?;
-
-        self.cosign_verifier
-            .verify(image_url, username.as_deref(), password.as_deref())
-            .await?;
+        check_registry_policy(&self.registries, &image)?;
+        // The image is pulled from its mirror, if any, but stored under its
+        // own name so that it's found again if the mirror changes.
+        let source = mirror_image(&self.registries.mirrors, &image)?;
 
         let image_content_key = get_image_content_key(&image);
 
@@ -197,29 +484,292 @@ impl ImageManager {
                 ImageError::DatabaseError("failed to read db".to_string(), e.to_string())
             })?;
 
-        let image_meta = match pull_policy {
-            ImagePullPolicy::Always => {
-                self.pull_image(image, &image_content_key, username, password)
-                    .await?
+        // Offline, images are only ever served from the image store.
+        let pull = !self.pull_config.offline
+            && match pull_policy {
+                ImagePullPolicy::Always => true,
+                ImagePullPolicy::IfNotPresent => !exists,
+                ImagePullPolicy::Never => false,
+            };
+
+        // Images are verified when they are pulled, so that images in the
+        // image store are loaded without contacting the registry.
+        let image_meta = if pull {
+            self.verify_and_pull(source, &image_content_key, username, password)
+                .await?
+        } else if exists {
+            self.load_image_meta(&image_content_key)?
+        } else if self.pull_config.offline {
+            Err(ImageError::NotCachedOffline(image.to_string()))?
+        } else {
+            Err(ImageError::ByteCodeImageNotfound(image.to_string()))?
+        };
+
+        self.record_use(&image_content_key, image_url)?;
+
+        Ok((image_content_key.to_string(), image_meta))
+    }
+
+    async fn verify_and_pull(
+        &mut self,
+        source: Reference,
+        image_content_key: &str,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<ContainerImageMetadata, ImageError> {
+        // Requests without credentials get the ones of the auth files, if any.
+        let (username, password) = match (username, password) {
+            (Some(username), Some(password)) if !username.is_empty() => {
+                (Some(username), Some(password))
             }
-            ImagePullPolicy::IfNotPresent => {
-                if exists {
-                    self.load_image_meta(&image_content_key)?
-                } else {
-                    self.pull_image(image, &image_content_key, username, password)
-                        .await?
+            _ => match self
+                .credentials
+                .lookup(source.registry(), source.repository())
+                .await
+            {
+                Some((username, password)) => (Some(username), Some(password)),
+                None => (None, None),
+            },
+        };
+
+        if let Some(cosign_verifier) = &self.cosign_verifier {
+            cosign_verifier
+                .lock()
+                .await
+                .verify(&source.whole(), username.as_deref(), password.as_deref())
+                .await?;
+        }
+
+        self.pull_with_retries(source, image_content_key, username, password)
+            .await
+    }
+
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, ImageError> {
+        let data = crate::utils::read(path)
+            .await
+            .map_err(|e| ImageError::FileReadError(path.to_string(), e.to_string()))?;
+        if let Some(file_verifier) = &self.file_verifier {
+            file_verifier.verify(Path::new(path), &data).await?;
+        }
+        Ok(data)
+    }
+
+    // Records the URL of the image stored under image_content_key and that it
+    // was just used.
+    fn record_use(&self, image_content_key: &str, image_url: &str) -> Result<(), ImageError> {
+        let tree = self.image_cache_tree()?;
+        tree.insert(format!("url_{image_content_key}"), image_url)
+            .and_then(|_| {
+                tree.insert(
+                    format!("last_used_{image_content_key}"),
+                    &now().to_be_bytes(),
+                )
+            })
+            .map_err(|e| {
+                ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
+            })?;
+        Ok(())
+    }
+
+    fn image_cache_tree(&self) -> Result<sled::Tree, ImageError> {
+        self.database.open_tree(IMAGE_CACHE_TREE).map_err(|e| {
+            ImageError::DatabaseError("failed to open db tree".to_string(), e.to_string())
+        })
+    }
+
+    // Returns the images in the image store. Images pulled before their use was
+    // recorded count as used now.
+    pub(crate) fn cached_images(&self) -> Result<Vec<CachedImage>, ImageError> {
+        let tree = self.image_cache_tree()?;
+        let mut images = vec![];
+        for entry in self.database.iter() {
+            let (key, manifest) = entry.map_err(|e| {
+                ImageError::DatabaseError("failed to read db".to_string(), e.to_string())
+            })?;
+            let key = String::from_utf8_lossy(&key);
+            let image_content_key = match key.strip_suffix("manifest.json") {
+                Some(k) => k.to_string(),
+                None => continue,
+            };
+            let manifest: OciImageManifest = match serde_json::from_slice(&manifest) {
+                Ok(m) => m,
+                Err(e) => {
+                    debug!("Ignoring image {image_content_key} with invalid manifest: {e}");
+                    continue;
                 }
-            }
-            ImagePullPolicy::Never => {
-                if exists {
-                    self.load_image_meta(&image_content_key)?
-                } else {
-                    Err(ImageError::ByteCodeImageNotfound(image.to_string()))?
+            };
+            let size = manifest_keys(&image_content_key, &manifest)
+                .iter()
+                .filter_map(|k| self.database.get(k).ok().flatten())
+                .map(|v| v.len() as u64)
+                .sum();
+
+            let get_string = |key: String| {
+                tree.get(key)
+                    .ok()
+                    .flatten()
+                    .map(|v| String::from_utf8_lossy(&v).to_string())
+            };
+            let url = get_string(format!("url_{image_content_key}"));
+            let digest = get_string(format!("digest_{image_content_key}"));
+            let last_used = match tree
+                .get(format!("last_used_{image_content_key}"))
+                .ok()
+                .flatten()
+                .and_then(|v| <[u8; 8]>::try_from(v.as_ref()).ok())
+            {
+                Some(t) => u64::from_be_bytes(t),
+                None => {
+                    let t = now();
+                    let _ = tree.insert(format!("last_used_{image_content_key}"), &t.to_be_bytes());
+                    t
                 }
-            }
-        };
+            };
+            images.push(CachedImage {
+                image_content_key,
+                url,
+                digest,
+                manifest,
+                size,
+                last_used,
+            });
+        }
+        Ok(images)
+    }
 
-        Ok((image_content_key.to_string(), image_meta.bpf_function_name))
+    fn list_images(&self, programs: &[(u32, String)]) -> Result<Vec<ImageInfo>, ImageError> {
+        let programs: Vec<(u32, String)> = programs
+            .iter()
+            .filter_map(|(id, url)| {
+                let image: Reference = url.parse().ok()?;
+                Some((*id, get_image_content_key(&image)))
+            })
+            .collect();
+        Ok(self
+            .cached_images()?
+            .into_iter()
+            .map(|image| ImageInfo {
+                url: image.name(),
+                digest: image.digest.clone().unwrap_or_default(),
+                size: image.size,
+                last_used: image.last_used,
+                labels: self.image_labels(&image),
+                program_ids: programs
+                    .iter()
+                    .filter(|(_, key)| *key == image.image_content_key)
+                    .map(|(id, _)| *id)
+                    .collect(),
+            })
+            .collect())
+    }
+
+    // Returns the labels of the config of image, which hold the metadata of
+    // the bytecode.
+    fn image_labels(&self, image: &CachedImage) -> HashMap<String, String> {
+        let config = image
+            .manifest
+            .config
+            .digest
+            .split_once(':')
+            .and_then(|(_, sha)| {
+                self.database
+                    .get(format!("{}{sha}", image.image_content_key))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|v| serde_json::from_slice::<Value>(&v).ok());
+        match config
+            .as_ref()
+            .and_then(|c| c["config"]["Labels"].as_object())
+        {
+            Some(labels) => labels
+                .iter()
+                .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                .collect(),
+            None => HashMap::new(),
+        }
+    }
+
+    fn prune_images(
+        &self,
+        in_use: &[String],
+        limits: ImageCacheConfig,
+    ) -> Result<Vec<String>, ImageError> {
+        let in_use: HashSet<String> = in_use
+            .iter()
+            .filter_map(|url| url.parse::<Reference>().ok())
+            .map(|image| get_image_content_key(&image))
+            .collect();
+        let images = self.cached_images()?;
+        let tree = self.image_cache_tree()?;
+        let mut removed = vec![];
+        for image in select_pruned(&images, &in_use, limits, now()) {
+            info!("Removing image {} from the image store", image.name());
+            let keys = manifest_keys(&image.image_content_key, &image.manifest);
+            let cache_keys = [
+                format!("url_{}", image.image_content_key),
+                format!("last_used_{}", image.image_content_key),
+                format!("digest_{}", image.image_content_key),
+            ];
+            keys.iter()
+                .try_for_each(|k| self.database.remove(k).map(|_| ()))
+                .and_then(|_| {
+                    cache_keys
+                        .iter()
+                        .try_for_each(|k| tree.remove(k).map(|_| ()))
+                })
+                .map_err(|e| {
+                    ImageError::DatabaseError("failed to remove from db".to_string(), e.to_string())
+                })?;
+            removed.push(image.name());
+        }
+        self.database.flush().map_err(|e| {
+            ImageError::DatabaseError("failed to flush db".to_string(), e.to_string())
+        })?;
+        Ok(removed)
+    }
+
+    // Pulls an image within the timeouts of the configuration, retrying failed
+    // pulls with exponential backoff. Errors other than failures to pull, like
+    // policy violations, aren't retried.
+    async fn pull_with_retries(
+        &mut self,
+        image: Reference,
+        base_key: &str,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<ContainerImageMetadata, ImageError> {
+        let config = self.pull_config;
+        let deadline = config
+            .deadline
+            .map(|d| Instant::now() + Duration::from_secs(d));
+        let mut backoff = Duration::from_millis(config.retry_backoff);
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let mut limit = Duration::from_secs(config.timeout);
+            if let Some(deadline) = deadline {
+                limit = limit.min(deadline.saturating_duration_since(Instant::now()));
+            }
+            let pull = self.pull_image(image.clone(), base_key, username.clone(), password.clone());
+            let err = match timeout(limit, pull).await {
+                Ok(Ok(meta)) => return Ok(meta),
+                Ok(Err(
+                    e @ (ImageError::ImageManifestPullFailure(_)
+                    | ImageError::BytecodeImagePullFailure(_)),
+                )) => e,
+                Ok(Err(e)) => return Err(e),
+                Err(_) => ImageError::PullTimeout(image.whole(), attempt),
+            };
+
+            let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+            if attempt > config.retries || remaining.is_some_and(|r| r <= backoff) {
+                return Err(err);
+            }
+            warn!("Pulling image {image} failed, retrying in {backoff:?}: {err}");
+            sleep(backoff).await;
+            backoff *= 2;
+        }
     }
 
     fn get_auth_for_registry(
@@ -249,29 +799,41 @@ impl ImageManager {
         );
 
         let auth = self.get_auth_for_registry(image.registry(), username, password);
-
-        let (image_manifest, _, config_contents) = self
-            .client
-            .pull_manifest_and_config(&image.clone(), &auth)
-            .await
-            .map_err(ImageError::ImageManifestPullFailure)?;
+        let (image, image_manifest, image_digest) = self.resolve_platform(image, &auth).await?;
 
         trace!("Raw container image manifest {}", image_manifest);
 
-        let image_manifest_key = base_key.to_string() + "manifest.json";
+        let tree = self.image_cache_tree()?;
+        if self.registries.pin_digests {
+            let pinned = tree
+                .get(format!("digest_{base_key}"))
+                .map_err(|e| {
+                    ImageError::DatabaseError("failed to read db".to_string(), e.to_string())
+                })?
+                .map(|v| String::from_utf8_lossy(&v).to_string());
+            check_pinned_digest(&image, pinned.as_deref(), &image_digest)?;
+        }
 
-        let image_manifest_json = serde_json::to_string(&image_manifest)
-            .map_err(|e| ImageError::ByteCodeImageProcessFailure(e.into()))?;
+        let bytecode_layer = image_manifest
+            .layers
+            .first()
+            .ok_or(ImageError::BytecodeImageExtractFailure)?;
+        if ![
+            manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE,
+            manifest::IMAGE_DOCKER_LAYER_GZIP_MEDIA_TYPE,
+        ]
+        .contains(&bytecode_layer.media_type.as_str())
+        {
+            return Err(ImageError::BytecodeImageExtractFailure);
+        }
 
-        // inset and flush to disk to avoid races across threads on write.
-        self.database
-            .insert(image_manifest_key, image_manifest_json.as_str())
-            .map_err(|e| {
-                ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
-            })?;
-        self.database.flush().map_err(|e| {
-            ImageError::DatabaseError("failed to flush db".to_string(), e.to_string())
-        })?;
+        // The config and the bytecode layer are downloaded concurrently.
+        let (config_contents, image_content) = try_join!(
+            self.pull_blob(&image, &image_manifest.config.digest),
+            self.pull_blob(&image, &bytecode_layer.digest),
+        )?;
+        let config_contents = String::from_utf8(config_contents)
+            .map_err(|e| ImageError::ByteCodeImageProcessFailure(e.into()))?;
 
         let config_sha = &image_manifest
             .config
@@ -281,10 +843,7 @@ impl ImageManager {
 
         let image_config_path = base_key.to_string() + config_sha;
 
-        let bytecode_sha = image_manifest.layers[0]
-            .digest
-            .split(':')
-            .collect::<Vec<&str>>()[1];
+        let bytecode_sha = bytecode_layer.digest.split(':').collect::<Vec<&str>>()[1];
 
         let bytecode_path = base_key.to_string() + bytecode_sha;
 
@@ -297,45 +856,83 @@ impl ImageManager {
             serde_json::from_str(&image_config["config"]["Labels"].to_string())
                 .map_err(|e| ImageError::ByteCodeImageProcessFailure(e.into()))?;
 
+        // The manifest is stored last, as images are taken to be in the image
+        // store once it is.
         self.database
             .insert(image_config_path, config_contents.as_str())
             .map_err(|e| {
                 ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
             })?;
-        self.database.flush().map_err(|e| {
-            ImageError::DatabaseError("failed to flush db".to_string(), e.to_string())
-        })?;
+        self.database
+            .insert(bytecode_path, image_content)
+            .map_err(|e| {
+                ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
+            })?;
 
-        let image_content = self
-            .client
-            .pull(
-                &image,
-                &auth,
-                vec![
-                    manifest::IMAGE_LAYER_GZIP_MEDIA_TYPE,
-                    manifest::IMAGE_DOCKER_LAYER_GZIP_MEDIA_TYPE,
-                ],
-            )
-            .await
-            .map_err(ImageError::BytecodeImagePullFailure)?
-            .layers
-            .into_iter()
-            .next()
-            .map(|layer| layer.data)
-            .ok_or(ImageError::BytecodeImageExtractFailure)?;
+        let image_manifest_key = base_key.to_string() + "manifest.json";
 
+        let image_manifest_json = serde_json::to_string(&image_manifest)
+            .map_err(|e| ImageError::ByteCodeImageProcessFailure(e.into()))?;
+
+        // inset and flush to disk to avoid races across threads on write.
         self.database
-            .insert(bytecode_path, image_content)
+            .insert(image_manifest_key, image_manifest_json.as_str())
             .map_err(|e| {
                 ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
             })?;
         self.database.flush().map_err(|e| {
             ImageError::DatabaseError("failed to flush db".to_string(), e.to_string())
         })?;
+        tree.insert(format!("digest_{base_key}"), image_digest.as_str())
+            .map_err(|e| {
+                ImageError::DatabaseError("failed to write to db".to_string(), e.to_string())
+            })?;
 
         Ok(image_labels)
     }
 
+    async fn pull_blob(&self, image: &Reference, digest: &str) -> Result<Vec<u8>, ImageError> {
+        let mut data = Vec::new();
+        self.client
+            .pull_blob(image, digest, &mut data)
+            .await
+            .map_err(ImageError::BytecodeImagePullFailure)?;
+        Ok(data)
+    }
+
+    // Returns the image of the host platform if image is an image index, like
+    // the images of multi-arch builds, or image itself otherwise, along with
+    // its manifest and the digest of the manifest.
+    async fn resolve_platform(
+        &mut self,
+        image: Reference,
+        auth: &RegistryAuth,
+    ) -> Result<(Reference, OciImageManifest, String), ImageError> {
+        let (manifest, digest) = self
+            .client
+            .pull_manifest(&image, auth)
+            .await
+            .map_err(ImageError::ImageManifestPullFailure)?;
+        match manifest {
+            OciManifest::Image(manifest) => Ok((image, manifest, digest)),
+            OciManifest::ImageIndex(index) => {
+                let entry = select_platform(&image.whole(), &index.manifests)?;
+                debug!("Using image {} of image index {}", entry.digest, image);
+                let image = Reference::with_digest(
+                    image.registry().to_string(),
+                    image.repository().to_string(),
+                    entry.digest.clone(),
+                );
+                let (manifest, digest) = self
+                    .client
+                    .pull_image_manifest(&image, auth)
+                    .await
+                    .map_err(ImageError::ImageManifestPullFailure)?;
+                Ok((image, manifest, digest))
+            }
+        }
+    }
+
     pub(crate) async fn get_bytecode_from_image_store(
         &self,
         base_key: String,
@@ -460,6 +1057,192 @@ impl ImageManager {
     }
 }
 
+// Sends the result of a pull to the requests waiting for it. Errors can't be
+// cloned, so only the first request gets the error itself and the others get
+// its message.
+fn send_pull_result(waiting: Vec<Responder<PullResult>>, image: &str, result: PullResult) {
+    let mut waiting = waiting.into_iter();
+    match result {
+        Ok(pulled) => waiting.for_each(|resp| {
+            let _ = resp.send(Ok(pulled.clone()));
+        }),
+        Err(e) => {
+            let message = e.to_string();
+            if let Some(resp) = waiting.next() {
+                let _ = resp.send(Err(e));
+            }
+            waiting.for_each(|resp| {
+                let _ = resp.send(Err(ImageError::PullFailed(
+                    image.to_string(),
+                    message.clone(),
+                )));
+            });
+        }
+    }
+}
+
+// Rejects images from registries the configuration doesn't allow, and images
+// given by tag from registries they must be pulled by digest from. Images
+// already in the image store are checked too, as the configuration may have
+// changed since they were pulled.
+fn check_registry_policy(policy: &RegistriesConfig, image: &Reference) -> Result<(), ImageError> {
+    let registry = image.registry();
+    let matches = |p: &String| glob_match(p.as_bytes(), registry.as_bytes());
+    if policy.denied.iter().any(matches)
+        || (!policy.allowed.is_empty() && !policy.allowed.iter().any(matches))
+    {
+        return Err(ImageError::RegistryNotAllowed(registry.to_string()));
+    }
+    if image.digest().is_none() && policy.digest_only.iter().any(matches) {
+        return Err(ImageError::DigestRequired(registry.to_string()));
+    }
+    Ok(())
+}
+
+// Rejects a digest other than the digest the image was pinned to when it was
+// first pulled.
+fn check_pinned_digest(
+    image: &Reference,
+    pinned: Option<&str>,
+    digest: &str,
+) -> Result<(), ImageError> {
+    match pinned {
+        Some(pinned) if pinned != digest => Err(ImageError::DigestChanged(
+            image.whole(),
+            pinned.to_string(),
+            digest.to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+// Returns the image to pull in place of image, which is image itself unless
+// its full name starts with one of the mirror prefixes. The longest matching
+// prefix wins, and prefixes only match up to a path component, so "quay.io"
+// doesn't match "quay.io.example.com/bpfman".
+fn mirror_image(
+    mirrors: &HashMap<String, String>,
+    image: &Reference,
+) -> Result<Reference, ImageError> {
+    let name = image.whole();
+    let mirror = mirrors
+        .iter()
+        .filter(|(prefix, _)| {
+            let prefix = prefix.trim_end_matches('/');
+            name.strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with(['/', ':', '@']))
+        })
+        .max_by_key(|(prefix, _)| prefix.trim_end_matches('/').len());
+    match mirror {
+        Some((prefix, replacement)) => {
+            let url = format!(
+                "{}{}",
+                replacement.trim_end_matches('/'),
+                &name[prefix.trim_end_matches('/').len()..]
+            );
+            debug!("Pulling image {name} from mirror {url}");
+            url.parse()
+                .map_err(|e| ImageError::InvalidMirrorUrl(name, url, e))
+        }
+        None => Ok(image.clone()),
+    }
+}
+
+// An image in the image store as listed to users.
+#[derive(Debug)]
+pub(crate) struct ImageInfo {
+    pub(crate) url: String,
+    pub(crate) digest: String,
+    pub(crate) size: u64,
+    pub(crate) last_used: u64,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) program_ids: Vec<u32>,
+}
+
+impl From<ImageInfo> for bpfman_api::v1::ImageInfo {
+    fn from(value: ImageInfo) -> Self {
+        bpfman_api::v1::ImageInfo {
+            url: value.url,
+            digest: value.digest,
+            size: value.size,
+            labels: value.labels,
+            last_used: value.last_used,
+            program_ids: value.program_ids,
+        }
+    }
+}
+
+// An image in the image store.
+pub(crate) struct CachedImage {
+    pub(crate) image_content_key: String,
+    pub(crate) url: Option<String>,
+    pub(crate) digest: Option<String>,
+    pub(crate) manifest: OciImageManifest,
+    pub(crate) size: u64,
+    pub(crate) last_used: u64,
+}
+
+impl CachedImage {
+    // The URL of the image, or the key it's stored under if it was pulled
+    // before URLs were recorded.
+    pub(crate) fn name(&self) -> String {
+        self.url
+            .clone()
+            .unwrap_or_else(|| self.image_content_key.clone())
+    }
+}
+
+// Returns the keys the manifest, config and bytecode of an image are stored
+// under.
+fn manifest_keys(image_content_key: &str, manifest: &OciImageManifest) -> Vec<String> {
+    let mut keys = vec![format!("{image_content_key}manifest.json")];
+    for digest in
+        std::iter::once(&manifest.config.digest).chain(manifest.layers.first().map(|l| &l.digest))
+    {
+        if let Some((_, sha)) = digest.split_once(':') {
+            keys.push(format!("{image_content_key}{sha}"));
+        }
+    }
+    keys
+}
+
+// Returns the images to remove from the image store: images not in use that
+// haven't been used for longer than max_age, and the least recently used of the
+// other images not in use while the images add up to more than max_size.
+fn select_pruned<'a>(
+    images: &'a [CachedImage],
+    in_use: &HashSet<String>,
+    limits: ImageCacheConfig,
+    now: u64,
+) -> Vec<&'a CachedImage> {
+    let mut unused: Vec<&CachedImage> = images
+        .iter()
+        .filter(|i| !in_use.contains(&i.image_content_key))
+        .collect();
+    unused.sort_by_key(|i| i.last_used);
+
+    let mut size: u64 = images.iter().map(|i| i.size).sum();
+    let mut pruned = vec![];
+    for image in unused {
+        let expired = limits
+            .max_age
+            .is_some_and(|max_age| now.saturating_sub(image.last_used) > max_age);
+        let too_large = limits.max_size.is_some_and(|max_size| size > max_size);
+        if expired || too_large {
+            size -= image.size;
+            pruned.push(image);
+        }
+    }
+    pruned
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
 fn get_image_content_key(image: &Reference) -> String {
     // Try to get the tag, if it doesn't exist, get the digest
     // if neither exist, return "latest" as the tag
@@ -489,8 +1272,15 @@ mod tests {
     async fn image_pull_and_bytecode_verify() {
         let database = sled::Config::new().temporary(true).open().unwrap();
 
-        let (_tx, rx) = mpsc::channel(32);
-        let mut mgr = ImageManager::new(database.clone(), true, rx).await.unwrap();
+        let mut mgr = ImageManager::new(
+            database.clone(),
+            true,
+            RegistriesConfig::default(),
+            ImagePullConfig::default(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let (image_content_key, _) = mgr
             .get_image(
@@ -517,8 +1307,15 @@ mod tests {
     async fn image_pull_policy_never_failure() {
         let database = sled::Config::new().temporary(true).open().unwrap();
 
-        let (_tx, rx) = mpsc::channel(32);
-        let mut mgr = ImageManager::new(database.clone(), true, rx).await.unwrap();
+        let mut mgr = ImageManager::new(
+            database.clone(),
+            true,
+            RegistriesConfig::default(),
+            ImagePullConfig::default(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let result = mgr
             .get_image(
@@ -537,8 +1334,15 @@ mod tests {
     async fn private_image_pull_failure() {
         let database = sled::Config::new().temporary(true).open().unwrap();
 
-        let (_tx, rx) = mpsc::channel(32);
-        let mut mgr = ImageManager::new(database, true, rx).await.unwrap();
+        let mut mgr = ImageManager::new(
+            database,
+            true,
+            RegistriesConfig::default(),
+            ImagePullConfig::default(),
+            None,
+        )
+        .await
+        .unwrap();
 
         mgr.get_image(
             "quay.io/bpfman-bytecode/xdp_pass_private:latest",
@@ -555,8 +1359,15 @@ mod tests {
         env_logger::init();
         let database = sled::Config::new().temporary(true).open().unwrap();
 
-        let (_tx, rx) = mpsc::channel(32);
-        let mut mgr = ImageManager::new(database.clone(), true, rx).await.unwrap();
+        let mut mgr = ImageManager::new(
+            database.clone(),
+            true,
+            RegistriesConfig::default(),
+            ImagePullConfig::default(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let (image_content_key, _) = mgr
             .get_image(
@@ -583,8 +1394,15 @@ mod tests {
     async fn image_pull_failure() {
         let database = sled::Config::new().temporary(true).open().unwrap();
 
-        let (_tx, rx) = mpsc::channel(32);
-        let mut mgr = ImageManager::new(database, true, rx).await.unwrap();
+        let mut mgr = ImageManager::new(
+            database,
+            true,
+            RegistriesConfig::default(),
+            ImagePullConfig::default(),
+            None,
+        )
+        .await
+        .unwrap();
 
         let result = mgr
             .get_image(
@@ -598,6 +1416,176 @@ mod tests {
         assert_matches!(result, Err(ImageError::ByteCodeImageNotfound(_)));
     }
 
+    #[test]
+    fn test_registry_policy() {
+        let policy = RegistriesConfig {
+            allowed: vec![
+                "registry.example.com:5000".to_string(),
+                "*.corp".to_string(),
+            ],
+            denied: vec!["old.corp".to_string()],
+            digest_only: vec!["registry.example.com:5000".to_string()],
+            ..Default::default()
+        };
+        let check = |url: &str| check_registry_policy(&policy, &url.parse().unwrap());
+
+        assert_matches!(
+            check("quay.io/bpfman-bytecode/xdp_pass:latest"),
+            Err(ImageError::RegistryNotAllowed(_))
+        );
+        assert_matches!(
+            check("old.corp/bpfman/xdp_pass:latest"),
+            Err(ImageError::RegistryNotAllowed(_))
+        );
+        assert!(check("images.corp/bpfman/xdp_pass:latest").is_ok());
+        assert_matches!(
+            check("registry.example.com:5000/bpfman/xdp_pass:latest"),
+            Err(ImageError::DigestRequired(_))
+        );
+        assert!(check(
+            "registry.example.com:5000/bpfman/xdp_pass@sha256:\
+            0b5a0f8e4f4a6c0b45f6e1b8c4e2a6d6f1f0e6b3b0d2e1f7a9c8b7a6d5e4f3a2"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_select_pruned() {
+        let image = |key: &str, size, last_used| CachedImage {
+            image_content_key: key.to_string(),
+            url: None,
+            digest: None,
+            manifest: OciImageManifest::default(),
+            size,
+            last_used,
+        };
+        let images = vec![
+            image("in_use", 100, 0),
+            image("old", 10, 100),
+            image("recent", 10, 900),
+            image("new", 10, 1000),
+        ];
+        let in_use = HashSet::from(["in_use".to_string()]);
+        let pruned = |max_size, max_age| {
+            let limits = ImageCacheConfig { max_size, max_age };
+            select_pruned(&images, &in_use, limits, 1000)
+                .iter()
+                .map(|i| i.image_content_key.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(pruned(None, None).is_empty());
+        assert_eq!(pruned(None, Some(500)), vec!["old"]);
+        assert_eq!(pruned(Some(115), None), vec!["old", "recent"]);
+        assert_eq!(pruned(Some(0), None), vec!["old", "recent", "new"]);
+    }
+
+    #[test]
+    fn test_check_pinned_digest() {
+        let image = "quay.io/bpfman-bytecode/xdp_pass:latest".parse().unwrap();
+        assert!(check_pinned_digest(&image, None, "sha256:aaaa").is_ok());
+        assert!(check_pinned_digest(&image, Some("sha256:aaaa"), "sha256:aaaa").is_ok());
+        assert_matches!(
+            check_pinned_digest(&image, Some("sha256:aaaa"), "sha256:bbbb"),
+            Err(ImageError::DigestChanged(..))
+        );
+    }
+
+    #[test]
+    fn test_mirror_image() {
+        let mirrors = HashMap::from([
+            ("quay.io".to_string(), "mirror.corp:5000/quay".to_string()),
+            (
+                "quay.io/bpfman/".to_string(),
+                "mirror.corp:5000/bpfman".to_string(),
+            ),
+        ]);
+        let mirror = |url: &str| {
+            mirror_image(&mirrors, &url.parse().unwrap())
+                .unwrap()
+                .whole()
+        };
+
+        assert_eq!(
+            mirror("quay.io/bpfman-bytecode/xdp_pass:latest"),
+            "mirror.corp:5000/quay/bpfman-bytecode/xdp_pass:latest"
+        );
+        assert_eq!(
+            mirror("quay.io/bpfman/xdp-dispatcher:v2"),
+            "mirror.corp:5000/bpfman/xdp-dispatcher:v2"
+        );
+        assert_eq!(
+            mirror("quay.io.example.com/bpfman/xdp_pass:latest"),
+            "quay.io.example.com/bpfman/xdp_pass:latest"
+        );
+    }
+
+    #[test]
+    fn test_send_pull_result() {
+        let image = "quay.io/bpfman-bytecode/xdp_pass:latest";
+        let (tx1, mut rx1) = oneshot::channel();
+        let (tx2, mut rx2) = oneshot::channel();
+        send_pull_result(
+            vec![tx1, tx2],
+            image,
+            Err(ImageError::ByteCodeImageNotfound(image.to_string())),
+        );
+        assert_matches!(
+            rx1.try_recv().unwrap(),
+            Err(ImageError::ByteCodeImageNotfound(_))
+        );
+        assert_matches!(rx2.try_recv().unwrap(), Err(ImageError::PullFailed(url, _)) if url == image);
+
+        let (tx1, mut rx1) = oneshot::channel();
+        let (tx2, mut rx2) = oneshot::channel();
+        send_pull_result(
+            vec![tx1, tx2],
+            image,
+            Ok(("key".to_string(), ContainerImageMetadata::default())),
+        );
+        assert_eq!(rx1.try_recv().unwrap().unwrap().0, "key");
+        assert_eq!(rx2.try_recv().unwrap().unwrap().0, "key");
+    }
+
+    #[test]
+    fn test_select_program() {
+        let v1: ContainerImageMetadata = serde_json::from_str(
+            r#"{
+                "io.ebpf.program_name": "xdp_counter",
+                "io.ebpf.bpf_function_name": "pass",
+                "io.ebpf.program_type": "xdp",
+                "io.ebpf.filename": "pass.bpf.o"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(v1.select_program("").unwrap().bpf_function_name, "pass");
+        assert_eq!(v1.select_program("pass").unwrap().name, "xdp_counter");
+        assert_matches!(
+            v1.select_program("drop"),
+            Err(ImageError::ProgramNotInImage(..))
+        );
+
+        let v2: ContainerImageMetadata = serde_json::from_str(
+            r#"{
+                "io.ebpf.filename": "stats.bpf.o",
+                "io.ebpf.programs": "[{\"name\": \"stats_xdp\", \"bpf_function_name\": \"xdp_stats\", \"program_type\": \"xdp\", \"section\": \"xdp\", \"maps\": [\"stats\"], \"global_data\": {\"sampling\": \"0a000000\"}}, {\"bpf_function_name\": \"tc_stats\", \"program_type\": \"tc\"}]"
+            }"#,
+        )
+        .unwrap();
+        assert_matches!(
+            v2.select_program(""),
+            Err(ImageError::ProgramNameRequired(_))
+        );
+        let program = v2.select_program("stats_xdp").unwrap();
+        assert_eq!(program.bpf_function_name, "xdp_stats");
+        assert_eq!(program.maps, vec!["stats".to_string()]);
+        assert_eq!(
+            program.global_data().unwrap(),
+            HashMap::from([("sampling".to_string(), vec![10, 0, 0, 0])])
+        );
+        assert_eq!(v2.select_program("tc_stats").unwrap().name, "tc_stats");
+    }
+
     #[tokio::test]
     async fn test_good_image_content_key() {
         struct Case {
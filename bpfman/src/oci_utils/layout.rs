@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Bytecode images can be loaded without a registry from OCI image layouts, as
+// written by "skopeo copy" or "podman save --format oci-dir", given as
+// "oci:<directory>[:<reference>]", and from tarballs of them, as written by
+// "podman save --format oci-archive", given as
+// "oci-archive:<file>[:<reference>]". The reference selects the image by its
+// "org.opencontainers.image.ref.name" annotation and may be left out if the
+// layout holds a single image.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use flate2::read::GzDecoder;
+use oci_distribution::manifest::{
+    ImageIndexEntry, OciImageIndex, OciImageManifest, OCI_IMAGE_INDEX_MEDIA_TYPE,
+};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tar::Archive;
+
+use crate::oci_utils::{image_manager::ContainerImageMetadata, select_platform, ImageError};
+
+const REF_NAME_ANNOTATION: &str = "org.opencontainers.image.ref.name";
+
+// Returns true if location names an OCI image layout or archive rather than a
+// bytecode file.
+pub(crate) fn is_layout_location(location: &str) -> bool {
+    location.starts_with("oci:") || location.starts_with("oci-archive:")
+}
+
+// Returns the bytecode of the image at location along with the metadata in
+// its labels.
+pub(crate) fn read_layout_bytecode(
+    location: &str,
+) -> Result<(Vec<u8>, ContainerImageMetadata), ImageError> {
+    let (layout, reference) = if let Some(rest) = location.strip_prefix("oci-archive:") {
+        let (path, reference) = split_reference(rest);
+        (Layout::from_archive(Path::new(path))?, reference)
+    } else if let Some(rest) = location.strip_prefix("oci:") {
+        let (path, reference) = split_reference(rest);
+        (Layout::Dir(PathBuf::from(path)), reference)
+    } else {
+        return Err(ImageError::OciLayoutError(format!(
+            "{location} is not an OCI layout location"
+        )));
+    };
+
+    let index: OciImageIndex = serde_json::from_slice(&layout.read("index.json")?)
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid index.json: {e}")))?;
+    let mut descriptor = select_manifest(&index.manifests, reference)?.clone();
+    // Multi-arch images are saved as an index of the images of each platform.
+    if descriptor.media_type == OCI_IMAGE_INDEX_MEDIA_TYPE {
+        let platforms: OciImageIndex = serde_json::from_slice(&layout.blob(&descriptor.digest)?)
+            .map_err(|e| ImageError::OciLayoutError(format!("invalid image index: {e}")))?;
+        descriptor = select_platform(location, &platforms.manifests)?.clone();
+    }
+    let manifest: OciImageManifest = serde_json::from_slice(&layout.blob(&descriptor.digest)?)
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid image manifest: {e}")))?;
+
+    let config: Value = serde_json::from_slice(&layout.blob(&manifest.config.digest)?)
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid image config: {e}")))?;
+    let labels = &config["config"]["Labels"];
+    let metadata = if labels.is_null() {
+        ContainerImageMetadata::default()
+    } else {
+        serde_json::from_value(labels.clone())
+            .map_err(|e| ImageError::InvalidImageMetadata(e.to_string()))?
+    };
+
+    let layer = manifest.layers.first().ok_or(ImageError::OciLayoutError(
+        "image has no layers".to_string(),
+    ))?;
+    let bytecode = extract_bytecode(&layout.blob(&layer.digest)?)?;
+    Ok((bytecode, metadata))
+}
+
+// Splits the reference off a layout path. Colons followed by a slash are taken
+// to be part of the path.
+fn split_reference(location: &str) -> (&str, Option<&str>) {
+    match location.rsplit_once(':') {
+        Some((path, reference)) if !reference.contains('/') && !path.is_empty() => {
+            (path, Some(reference))
+        }
+        _ => (location, None),
+    }
+}
+
+fn select_manifest<'a>(
+    manifests: &'a [ImageIndexEntry],
+    reference: Option<&str>,
+) -> Result<&'a ImageIndexEntry, ImageError> {
+    match reference {
+        Some(reference) => manifests
+            .iter()
+            .find(|m| {
+                m.annotations
+                    .as_ref()
+                    .and_then(|a| a.get(REF_NAME_ANNOTATION))
+                    .is_some_and(|name| name == reference)
+            })
+            .ok_or(ImageError::OciLayoutError(format!(
+                "no image is named {reference} in the layout"
+            ))),
+        None => match manifests {
+            [manifest] => Ok(manifest),
+            [] => Err(ImageError::OciLayoutError(
+                "the layout holds no images".to_string(),
+            )),
+            _ => Err(ImageError::OciLayoutError(
+                "the layout holds several images, a reference is needed".to_string(),
+            )),
+        },
+    }
+}
+
+// The bytecode layer is a gzipped tarball holding the bytecode file.
+fn extract_bytecode(layer: &[u8]) -> Result<Vec<u8>, ImageError> {
+    let mut archive = Archive::new(GzDecoder::new(layer));
+    let mut entries = archive
+        .entries()
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid bytecode layer: {e}")))?;
+    let mut entry = entries
+        .next()
+        .ok_or(ImageError::OciLayoutError(
+            "bytecode layer is empty".to_string(),
+        ))?
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid bytecode layer: {e}")))?;
+    let mut data = Vec::new();
+    entry
+        .read_to_end(&mut data)
+        .map_err(|e| ImageError::OciLayoutError(format!("invalid bytecode layer: {e}")))?;
+    Ok(data)
+}
+
+enum Layout {
+    Dir(PathBuf),
+    // The files of an archive, by path. Bytecode images are small enough to
+    // be read into memory at once.
+    Archive(HashMap<String, Vec<u8>>),
+}
+
+impl Layout {
+    fn from_archive(path: &Path) -> Result<Self, ImageError> {
+        let file = File::open(path).map_err(|e| {
+            ImageError::OciLayoutError(format!("unable to open {}: {e}", path.display()))
+        })?;
+        let mut files = HashMap::new();
+        let mut archive = Archive::new(file);
+        let entries = archive.entries().map_err(|e| {
+            ImageError::OciLayoutError(format!("invalid archive {}: {e}", path.display()))
+        })?;
+        for entry in entries {
+            let mut entry = entry.map_err(|e| {
+                ImageError::OciLayoutError(format!("invalid archive {}: {e}", path.display()))
+            })?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let name = entry
+                .path()
+                .map(|p| p.to_string_lossy().trim_start_matches("./").to_string())
+                .unwrap_or_default();
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).map_err(|e| {
+                ImageError::OciLayoutError(format!("invalid archive {}: {e}", path.display()))
+            })?;
+            files.insert(name, data);
+        }
+        Ok(Layout::Archive(files))
+    }
+
+    fn read(&self, name: &str) -> Result<Vec<u8>, ImageError> {
+        match self {
+            Layout::Dir(dir) => std::fs::read(dir.join(name))
+                .map_err(|e| ImageError::OciLayoutError(format!("unable to read {name}: {e}"))),
+            Layout::Archive(files) => files
+                .get(name)
+                .cloned()
+                .ok_or(ImageError::OciLayoutError(format!("{name} is missing"))),
+        }
+    }
+
+    // Reads the blob with the given digest and checks that it matches it.
+    fn blob(&self, digest: &str) -> Result<Vec<u8>, ImageError> {
+        let hex = digest
+            .strip_prefix("sha256:")
+            .ok_or(ImageError::OciLayoutError(format!(
+                "unsupported digest {digest}"
+            )))?;
+        let blob = self.read(&format!("blobs/sha256/{hex}"))?;
+        if base16ct::lower::encode_string(&Sha256::digest(&blob)) != hex {
+            return Err(ImageError::OciLayoutError(format!(
+                "blob {digest} doesn't match its digest"
+            )));
+        }
+        Ok(blob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_reference() {
+        assert_eq!(
+            split_reference("/var/lib/images/xdp_pass:v1"),
+            ("/var/lib/images/xdp_pass", Some("v1"))
+        );
+        assert_eq!(
+            split_reference("/var/lib/images/xdp_pass"),
+            ("/var/lib/images/xdp_pass", None)
+        );
+        assert_eq!(
+            split_reference("/var/lib/images:old/xdp_pass"),
+            ("/var/lib/images:old/xdp_pass", None)
+        );
+    }
+}
@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
+pub(crate) mod auth;
 pub(crate) mod cosign;
+pub(crate) mod fetch;
+pub(crate) mod file_signing;
 pub(crate) mod image_manager;
+pub(crate) mod layout;
 
+use bpfman_api::config::ProxyConfig;
 pub(crate) use image_manager::ImageManager;
+use oci_distribution::manifest::ImageIndexEntry;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -23,4 +29,151 @@ pub enum ImageError {
     ByteCodeImageNotfound(String),
     #[error("{0}: {1}")]
     DatabaseError(String, String),
+    #[error("Pulling images from registry {0} is not allowed")]
+    RegistryNotAllowed(String),
+    #[error("Images from registry {0} must be pulled by digest")]
+    DigestRequired(String),
+    #[error("Mirror {1} of image {0} is not a valid image URL: {2}")]
+    InvalidMirrorUrl(String, String, #[source] oci_distribution::ParseError),
+    #[error("Failed to read OCI image layout: {0}")]
+    OciLayoutError(String),
+    #[error("Timed out pulling image {0} after {1} attempts")]
+    PullTimeout(String, u32),
+    #[error("Tag of image {0} moved from digest {1} to {2}")]
+    DigestChanged(String, String, String),
+    #[error("Image {0} has no variant for platform {1}, only for: {2}")]
+    NoMatchingPlatform(String, String, String),
+    #[error("Image {0} is not in the image store and bpfman is offline")]
+    NotCachedOffline(String),
+    #[error("Failed to pull image {0}: {1}")]
+    PullFailed(String, String),
+    #[error("Unable to read {0}: {1}")]
+    FileReadError(String, String),
+    #[error("Bytecode file {0} is not signed")]
+    FileNotSigned(String),
+    #[error("Signature of {0} is invalid: {1}")]
+    FileSignatureInvalid(String, String),
+    #[error("Bytecode file {0} doesn't match its signed sha256 digest")]
+    FileDigestMismatch(String),
+    #[error("Invalid bytecode image metadata: {0}")]
+    InvalidImageMetadata(String),
+    #[error("Image holds several programs, one of which must be named: {0}")]
+    ProgramNameRequired(String),
+    #[error("Image has no program named {0}, only: {1}")]
+    ProgramNotInImage(String, String),
+    #[error("Invalid bytecode URL {0}: {1}")]
+    InvalidBytecodeUrl(String, String),
+    #[error("Bytecode {0} can't be fetched while bpfman is offline")]
+    FetchOffline(String),
+    #[error("Failed to fetch bytecode {0}: {1}")]
+    FetchFailed(String, String),
+    #[error("Bytecode {0} doesn't match its sha256 digest {1}")]
+    UrlDigestMismatch(String, String),
+}
+
+// The registry and sigstore clients use the proxies given by the standard
+// environment variables, which are read once when the first client is built,
+// so the configured proxies are set there before any client is. Both the upper
+// and lower case variables are set, as clients differ in which they read.
+pub(crate) fn set_proxy_env(proxy: &ProxyConfig) {
+    let vars = [
+        ("HTTP_PROXY", proxy.http_proxy.clone()),
+        ("HTTPS_PROXY", proxy.https_proxy.clone()),
+        (
+            "NO_PROXY",
+            proxy.no_proxy.as_ref().map(|hosts| hosts.join(",")),
+        ),
+    ];
+    for (name, value) in vars {
+        if let Some(value) = value {
+            std::env::set_var(name, &value);
+            std::env::set_var(name.to_lowercase(), &value);
+        }
+    }
+}
+
+// Returns the OS and architecture of the host as named in image indexes.
+pub(crate) fn host_platform() -> (&'static str, &'static str) {
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "amd64",
+        "x86" => "386",
+        "aarch64" => "arm64",
+        "powerpc64" if cfg!(target_endian = "little") => "ppc64le",
+        "loongarch64" => "loong64",
+        arch => arch,
+    };
+    (std::env::consts::OS, arch)
+}
+
+// Selects the image of the host platform from the entries of an image index.
+// Bytecode is built for the architecture it runs on, so images of other
+// architectures are never used instead. Entries without a platform, like
+// attestations, are skipped. The error lists the platforms of the index.
+pub(crate) fn select_platform<'a>(
+    image: &str,
+    entries: &'a [ImageIndexEntry],
+) -> Result<&'a ImageIndexEntry, ImageError> {
+    let (os, arch) = host_platform();
+    entries
+        .iter()
+        .find(|e| {
+            e.platform
+                .as_ref()
+                .is_some_and(|p| p.os == os && p.architecture == arch)
+        })
+        .ok_or_else(|| {
+            let platforms: Vec<String> = entries
+                .iter()
+                .filter_map(|e| e.platform.as_ref())
+                .map(|p| match &p.variant {
+                    Some(variant) => format!("{}/{}/{variant}", p.os, p.architecture),
+                    None => format!("{}/{}", p.os, p.architecture),
+                })
+                .collect();
+            ImageError::NoMatchingPlatform(
+                image.to_string(),
+                format!("{os}/{arch}"),
+                platforms.join(", "),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_select_platform() {
+        let (os, arch) = host_platform();
+        let entries: Vec<ImageIndexEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": "sha256:aaaa",
+                "size": 100,
+                "platform": { "os": "linux", "architecture": "s390x" }
+            },
+            {
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": "sha256:bbbb",
+                "size": 100
+            },
+            {
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": "sha256:cccc",
+                "size": 100,
+                "platform": { "os": os, "architecture": arch }
+            }
+        ]))
+        .unwrap();
+
+        let entry = select_platform("quay.io/bpfman-bytecode/xdp_pass:latest", &entries).unwrap();
+        assert_eq!(entry.digest, "sha256:cccc");
+
+        assert_matches!(
+            select_platform("quay.io/bpfman-bytecode/xdp_pass:latest", &entries[..2]),
+            Err(ImageError::NoMatchingPlatform(_, _, platforms)) if platforms == "linux/s390x"
+        );
+    }
 }
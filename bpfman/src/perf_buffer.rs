@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Records of perf event array maps are streamed to clients like the records
+// of ring buffers. Perf event arrays have a buffer per CPU, each one read by
+// its own task which passes the records on, along with the number of samples
+// the kernel lost because the buffer was full, to the tasks queueing them for
+// the clients. Opening the buffers replaces the ones the map held before, so
+// bpfman opens them once for all clients of the map, and maps the workload
+// reads itself shouldn't be watched.
+
+use std::{path::Path, time::Duration};
+
+use aya::{
+    maps::{
+        perf::{PerfEventArray, PerfEventArrayBuffer},
+        Map, MapData,
+    },
+    util::online_cpus,
+};
+use bpfman_api::{v1::WatchPerfBufferResponse, DropPolicy};
+use bytes::BytesMut;
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use tokio::{
+    io::unix::AsyncFd,
+    select,
+    sync::mpsc,
+    time::{interval, MissedTickBehavior},
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+
+use crate::{
+    errors::BpfmanError,
+    ring_buffer::{Clients, Readers, RecordQueue},
+};
+
+// Records are read from the buffer of a CPU in batches of up to this many.
+const READ_BATCH_SIZE: usize = 16;
+
+// How often the buffers are checked for clients that went away while no
+// records were written.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) type PerfBufferStream = ReceiverStream<Result<WatchPerfBufferResponse, Status>>;
+
+lazy_static! {
+    static ref PERF_BUFFER_READERS: Readers<Result<Batch, Status>> = Readers::new();
+}
+
+// Returns a stream of the records of all online CPUs of the perf event array
+// map pinned at path. page_count is the size of the buffer of each CPU in
+// pages and must be a power of two; it only applies when the map isn't
+// watched yet, otherwise the buffers opened for the first client are shared.
+// The buffers are read until the streams of all clients are dropped.
+pub(crate) fn watch_perf_buffer(
+    path: &Path,
+    page_count: Option<usize>,
+    queue_size: usize,
+    drop_policy: DropPolicy,
+) -> Result<PerfBufferStream, BpfmanError> {
+    let (batch_tx, batch_rx) = mpsc::channel(1);
+    PERF_BUFFER_READERS.join(path, batch_tx, |clients| {
+        let map = Map::from_map_data(MapData::from_pin(path)?)?;
+        let mut perf_array = PerfEventArray::try_from(map)?;
+        let cpus = online_cpus()
+            .map_err(|e| BpfmanError::Error(format!("unable to get online cpus: {e:?}")))?;
+
+        let (tx, rx) = mpsc::channel(cpus.len());
+        for cpu in cpus {
+            let buffer = perf_array.open(cpu, page_count).map_err(|e| {
+                BpfmanError::Error(format!("unable to open perf buffer of cpu {cpu}: {e}"))
+            })?;
+            tokio::spawn(read_buffer(cpu, AsyncFd::new(buffer)?, tx.clone()));
+        }
+        tokio::spawn(distribute_batches(rx, clients));
+        Ok(())
+    })?;
+
+    let (tx, rx) = mpsc::channel(1);
+    tokio::spawn(forward_records(
+        batch_rx,
+        tx,
+        RecordQueue::new(queue_size, drop_policy),
+    ));
+    Ok(ReceiverStream::new(rx))
+}
+
+// Records read at once from the buffer of a CPU.
+#[derive(Clone)]
+struct Batch {
+    cpu: u32,
+    records: Vec<Vec<u8>>,
+    lost: u64,
+}
+
+async fn read_buffer(
+    cpu: u32,
+    mut buffer: AsyncFd<PerfEventArrayBuffer<MapData>>,
+    tx: mpsc::Sender<Result<Batch, Status>>,
+) {
+    let mut out_bufs: Vec<BytesMut> = (0..READ_BATCH_SIZE)
+        .map(|_| BytesMut::with_capacity(1024))
+        .collect();
+    loop {
+        let mut guard = select! {
+            guard = buffer.readable_mut() => match guard {
+                Ok(guard) => guard,
+                Err(e) => {
+                    warn!("error polling perf buffer of cpu {cpu}: {e}");
+                    let _ = tx.send(Err(Status::aborted(format!("{e}")))).await;
+                    return;
+                }
+            },
+            _ = tx.closed() => return,
+        };
+
+        while guard.get_inner().readable() {
+            let events = match guard.get_inner_mut().read_events(&mut out_bufs) {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("error reading perf buffer of cpu {cpu}: {e}");
+                    let _ = tx.send(Err(Status::aborted(format!("{e}")))).await;
+                    return;
+                }
+            };
+            let batch = Batch {
+                cpu,
+                records: out_bufs[..events.read].iter().map(|b| b.to_vec()).collect(),
+                lost: events.lost as u64,
+            };
+            // The buffer isn't read while the batch waits to be queued, which
+            // is how the block policy holds back the buffers.
+            if tx.send(Ok(batch)).await.is_err() {
+                return;
+            }
+        }
+        guard.clear_ready();
+    }
+}
+
+// Passes the batches read from the buffers on to all clients of the map. The
+// readers of the buffers stop once this returns and the batches can't be
+// sent anymore.
+async fn distribute_batches(
+    mut batches: mpsc::Receiver<Result<Batch, Status>>,
+    mut clients: Clients<Result<Batch, Status>>,
+) {
+    let mut idle = interval(IDLE_CHECK_INTERVAL);
+    idle.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        select! {
+            () = clients.joined() => {}
+            _ = idle.tick() => {
+                if clients.done() {
+                    break;
+                }
+            }
+            batch = batches.recv() => {
+                let Some(batch) = batch else {
+                    break;
+                };
+                let failed = batch.is_err();
+                clients.send(batch).await;
+                if failed {
+                    break;
+                }
+            }
+        }
+    }
+    debug!("stopped reading perf buffer");
+}
+
+async fn forward_records(
+    mut batches: mpsc::Receiver<Result<Batch, Status>>,
+    tx: mpsc::Sender<Result<WatchPerfBufferResponse, Status>>,
+    mut queue: RecordQueue<WatchPerfBufferResponse>,
+) {
+    loop {
+        select! {
+            batch = batches.recv(), if !queue.is_blocked() => {
+                match batch {
+                    Some(Ok(batch)) => queue_batch(&mut queue, batch),
+                    Some(Err(status)) => {
+                        let _ = tx.send(Err(status)).await;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+            permit = tx.reserve(), if !queue.is_empty() => {
+                match permit {
+                    Ok(permit) => {
+                        if let Some((response, dropped)) = queue.pop() {
+                            permit.send(Ok(WatchPerfBufferResponse {
+                                dropped,
+                                ..response
+                            }));
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+            _ = tx.closed(), if queue.is_empty() => break,
+        }
+    }
+    debug!("stopped streaming perf buffer");
+}
+
+// The samples lost by the kernel are reported with the first record of the
+// batch, or on their own if no record could be read.
+fn queue_batch(queue: &mut RecordQueue<WatchPerfBufferResponse>, batch: Batch) {
+    let mut lost = batch.lost;
+    if batch.records.is_empty() {
+        if lost > 0 {
+            queue.push(WatchPerfBufferResponse {
+                cpu: batch.cpu,
+                lost,
+                ..Default::default()
+            });
+        }
+        return;
+    }
+    for data in batch.records {
+        queue.push(WatchPerfBufferResponse {
+            data,
+            cpu: batch.cpu,
+            lost: std::mem::take(&mut lost),
+            dropped: 0,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(records: &[&[u8]], lost: u64) -> Batch {
+        Batch {
+            cpu: 1,
+            records: records.iter().map(|r| r.to_vec()).collect(),
+            lost,
+        }
+    }
+
+    fn drain(queue: &mut RecordQueue<WatchPerfBufferResponse>) -> Vec<(Vec<u8>, u64)> {
+        std::iter::from_fn(|| queue.pop())
+            .map(|(r, _)| (r.data, r.lost))
+            .collect()
+    }
+
+    #[test]
+    fn test_queue_batch() {
+        let mut queue = RecordQueue::new(8, DropPolicy::DropNewest);
+
+        queue_batch(&mut queue, batch(&[b"a", b"b"], 3));
+        assert_eq!(
+            drain(&mut queue),
+            vec![(b"a".to_vec(), 3), (b"b".to_vec(), 0)]
+        );
+
+        queue_batch(&mut queue, batch(&[], 2));
+        assert_eq!(drain(&mut queue), vec![(vec![], 2)]);
+
+        queue_batch(&mut queue, batch(&[], 0));
+        assert!(queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_forward_records() {
+        let (batch_tx, batch_rx) = mpsc::channel(1);
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(forward_records(
+            batch_rx,
+            tx,
+            RecordQueue::new(8, DropPolicy::Block),
+        ));
+
+        batch_tx.send(Ok(batch(&[b"a", b"b"], 1))).await.unwrap();
+        let first = rx.recv().await.unwrap().unwrap();
+        assert_eq!((first.data, first.cpu, first.lost), (b"a".to_vec(), 1, 1));
+        let second = rx.recv().await.unwrap().unwrap();
+        assert_eq!((second.data, second.lost), (b"b".to_vec(), 0));
+
+        // An error reading a buffer ends the stream.
+        batch_tx
+            .send(Err(Status::aborted("unable to read buffer")))
+            .await
+            .unwrap();
+        assert!(rx.recv().await.unwrap().is_err());
+        assert!(rx.recv().await.is_none());
+    }
+}
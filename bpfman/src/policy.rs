@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Loads can be decided by a policy evaluated within bpfman, for hosts that
+// can't reach an admission service. The policy is a TOML file of rules, the
+// first of which matching a load decides it, falling back to the default
+// action if none does:
+//
+// default = "deny"
+//
+// [[rules]]
+// action = "allow"
+// program_type = ["xdp", "tc"]
+// image = ["quay.io/acme/*"]
+// iface = ["eth*"]
+// priority = { min = 10, max = 100 }
+//
+// A rule matches a load if all of its conditions do. Conditions list glob
+// patterns, of which the value of the load must match one, and loads without
+// the value, like loads of files for image patterns, don't match. The file is
+// reloaded when it changes, keeping the previous policy if it's invalid.
+
+use std::{collections::HashMap, path::PathBuf, time::SystemTime};
+
+use log::{info, warn};
+use serde::Deserialize;
+
+use crate::{admission::LoadRequest, errors::BpfmanError, utils::glob_match};
+
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Action {
+    Allow,
+    #[default]
+    Deny,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Policy {
+    #[serde(default)]
+    default: Action,
+    #[serde(default)]
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+    action: Action,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    program_type: Vec<String>,
+    #[serde(default)]
+    name: Vec<String>,
+    #[serde(default)]
+    image: Vec<String>,
+    #[serde(default)]
+    file: Vec<String>,
+    #[serde(default)]
+    url: Vec<String>,
+    #[serde(default)]
+    owner: Vec<String>,
+    #[serde(default)]
+    iface: Vec<String>,
+    #[serde(default)]
+    direction: Vec<String>,
+    #[serde(default)]
+    cgroup_path: Vec<String>,
+    priority: Option<PriorityRange>,
+    // Metadata the load must have, with glob patterns as values.
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PriorityRange {
+    min: Option<i32>,
+    max: Option<i32>,
+}
+
+impl Rule {
+    fn matches(&self, request: &LoadRequest) -> bool {
+        let matches_any = |patterns: &[String], value: Option<&str>| {
+            patterns.is_empty()
+                || value.is_some_and(|v| {
+                    patterns
+                        .iter()
+                        .any(|p| glob_match(p.as_bytes(), v.as_bytes()))
+                })
+        };
+        matches_any(&self.program_type, Some(&request.program_type))
+            && matches_any(&self.name, Some(&request.name))
+            && matches_any(
+                &self.image,
+                request.location.get("image").map(|s| s.as_str()),
+            )
+            && matches_any(&self.file, request.location.get("file").map(|s| s.as_str()))
+            && matches_any(&self.url, request.location.get("url").map(|s| s.as_str()))
+            && matches_any(&self.owner, request.owner.as_deref())
+            && matches_any(&self.iface, request.iface.as_deref())
+            && matches_any(&self.direction, request.direction.as_deref())
+            && matches_any(&self.cgroup_path, request.cgroup_path.as_deref())
+            && self.priority.as_ref().map_or(true, |range| {
+                request.priority.is_some_and(|p| {
+                    range.min.map_or(true, |min| p >= min) && range.max.map_or(true, |max| p <= max)
+                })
+            })
+            && self.metadata.iter().all(|(key, pattern)| {
+                request
+                    .metadata
+                    .get(key)
+                    .is_some_and(|v| glob_match(pattern.as_bytes(), v.as_bytes()))
+            })
+    }
+}
+
+impl Policy {
+    fn evaluate(&self, request: &LoadRequest) -> Result<(), BpfmanError> {
+        let (action, reason) = match self.rules.iter().find(|r| r.matches(request)) {
+            Some(rule) => (
+                rule.action,
+                rule.reason
+                    .clone()
+                    .unwrap_or_else(|| "a rule denies it".to_string()),
+            ),
+            None => (self.default, "no rule allows it".to_string()),
+        };
+        match action {
+            Action::Allow => Ok(()),
+            Action::Deny => Err(BpfmanError::PolicyDenied(reason)),
+        }
+    }
+}
+
+pub(crate) struct PolicyEngine {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    policy: Option<Policy>,
+}
+
+impl PolicyEngine {
+    pub(crate) fn new(path: &str) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            modified: None,
+            policy: None,
+        }
+    }
+
+    // Decides the load, denying it if no valid policy could be read yet.
+    pub(crate) fn evaluate(&mut self, request: &LoadRequest) -> Result<(), BpfmanError> {
+        self.reload();
+        match &self.policy {
+            Some(policy) => policy.evaluate(request),
+            None => Err(BpfmanError::PolicyDenied(format!(
+                "no valid policy could be read from {}",
+                self.path.display()
+            ))),
+        }
+    }
+
+    fn reload(&mut self) {
+        let modified = match std::fs::metadata(&self.path).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(e) => {
+                warn!("Unable to read policy {}: {e}", self.path.display());
+                return;
+            }
+        };
+        if self.modified == Some(modified) {
+            return;
+        }
+        self.modified = Some(modified);
+        let policy = std::fs::read_to_string(&self.path)
+            .map_err(|e| e.to_string())
+            .and_then(|p| toml::from_str::<Policy>(&p).map_err(|e| e.to_string()));
+        match policy {
+            Ok(policy) => {
+                info!("Loaded policy {}", self.path.display());
+                self.policy = Some(policy);
+            }
+            Err(e) => warn!(
+                "Ignoring invalid policy {}, keeping the previous one: {e}",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_policy_evaluate() {
+        let policy: Policy = toml::from_str(
+            r#"
+            default = "deny"
+
+            [[rules]]
+            action = "deny"
+            reason = "eth0 is reserved"
+            iface = ["eth0"]
+
+            [[rules]]
+            action = "allow"
+            program_type = ["xdp", "tc"]
+            image = ["quay.io/acme/*"]
+            priority = { min = 10, max = 100 }
+            metadata = { owner = "acme" }
+            "#,
+        )
+        .unwrap();
+        let request = |image: &str, iface: &str, priority: i32| LoadRequest {
+            program_type: "xdp".to_string(),
+            name: "pass".to_string(),
+            location: HashMap::from([("image", image.to_string())]),
+            metadata: HashMap::from([("owner".to_string(), "acme".to_string())]),
+            iface: Some(iface.to_string()),
+            priority: Some(priority),
+            ..Default::default()
+        };
+
+        assert!(policy
+            .evaluate(&request("quay.io/acme/xdp_pass:v1", "eth1", 50))
+            .is_ok());
+        assert_matches!(
+            policy.evaluate(&request("quay.io/acme/xdp_pass:v1", "eth0", 50)),
+            Err(BpfmanError::PolicyDenied(reason)) if reason == "eth0 is reserved"
+        );
+        assert_matches!(
+            policy.evaluate(&request("quay.io/other/xdp_pass:v1", "eth1", 50)),
+            Err(BpfmanError::PolicyDenied(_))
+        );
+        assert_matches!(
+            policy.evaluate(&request("quay.io/acme/xdp_pass:v1", "eth1", 5)),
+            Err(BpfmanError::PolicyDenied(_))
+        );
+    }
+}
@@ -0,0 +1,405 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Probes the BPF features of the running kernel, like bpftool feature does.
+// Program types are probed by loading a program that returns right away, map
+// types by creating a map of a single entry, and helpers by loading a program
+// that calls them, which the verifier rejects with a message of its own when
+// the helper is unknown or not available to the program type. Program types
+// and attach mechanisms which can't be tried without an attach target are
+// told by the kernel version instead.
+//
+// Programs are checked against what they take of the kernel before they are
+// loaded too, so that a load on a kernel lacking it fails with an error
+// naming what's missing rather than with the EINVAL of the bpf syscall.
+
+use std::{
+    os::fd::{FromRawFd, OwnedFd},
+    path::Path,
+};
+
+use aya::util::KernelVersion;
+use bpfman_api::ProgramType;
+use log::info;
+use nix::libc;
+
+use crate::{
+    command::Program,
+    errors::BpfmanError,
+    maps::sys_bpf,
+    multiprog::{netkit::check_netkit_supported, tcx::check_tcx_supported},
+    utils::{check_bpf_lsm_enabled, check_netfilter_supported, kernel_release},
+};
+
+const BPF_MAP_CREATE: libc::c_long = 0;
+const BPF_PROG_LOAD: libc::c_long = 5;
+
+const KERNEL_BTF_PATH: &str = "/sys/kernel/btf/vmlinux";
+
+// Program types of enum bpf_prog_type after unspec, up to netfilter.
+const LAST_PROGRAM_TYPE: u32 = 32;
+
+// Helpers of enum bpf_func_id, up to bpf_cgrp_storage_delete.
+const LAST_HELPER: u32 = 211;
+
+const BPF_F_NO_PREALLOC: u32 = 1 << 0;
+const BPF_F_SLEEPABLE: u32 = 1 << 4;
+
+const BPF_MAP_TYPE_RINGBUF: u32 = 27;
+const BPF_MAP_TYPE_USER_RINGBUF: u32 = 31;
+
+// Program types which are only loaded with the BTF id of their attach
+// target, along with the Linux version that added them: tracing, struct_ops,
+// ext and lsm.
+const BTF_PROGRAM_TYPES: [(u32, u8, u8); 4] = [(26, 5, 5), (27, 5, 6), (28, 5, 6), (29, 5, 7)];
+
+// Map types probed, named as in enum bpf_map_type, with the key size, value
+// size and flags of the probe map. Map types whose maps take BTF or an inner
+// map to create aren't probed.
+const MAP_PROBES: [(u32, &str, u32, u32, u32); 22] = [
+    (1, "hash", 4, 4, 0),
+    (2, "array", 4, 4, 0),
+    (3, "prog_array", 4, 4, 0),
+    (4, "perf_event_array", 4, 4, 0),
+    (5, "percpu_hash", 4, 4, 0),
+    (6, "percpu_array", 4, 4, 0),
+    (7, "stack_trace", 4, 8, 0),
+    (8, "cgroup_array", 4, 4, 0),
+    (9, "lru_hash", 4, 4, 0),
+    (10, "lru_percpu_hash", 4, 4, 0),
+    (11, "lpm_trie", 8, 4, BPF_F_NO_PREALLOC),
+    (14, "devmap", 4, 4, 0),
+    (15, "sockmap", 4, 4, 0),
+    (16, "cpumap", 4, 4, 0),
+    (17, "xskmap", 4, 4, 0),
+    (18, "sockhash", 4, 4, 0),
+    (22, "queue", 0, 4, 0),
+    (23, "stack", 0, 4, 0),
+    (25, "devmap_hash", 4, 4, 0),
+    (BPF_MAP_TYPE_RINGBUF, "ringbuf", 0, 0, 0),
+    (30, "bloom_filter", 0, 4, 0),
+    (BPF_MAP_TYPE_USER_RINGBUF, "user_ringbuf", 0, 0, 0),
+];
+
+// The parts of union bpf_attr used by the commands above.
+#[repr(C)]
+#[derive(Default)]
+struct ProgLoadAttr {
+    prog_type: u32,
+    insn_cnt: u32,
+    insns: u64,
+    license: u64,
+    log_level: u32,
+    log_size: u32,
+    log_buf: u64,
+    kern_version: u32,
+    prog_flags: u32,
+    prog_name: [u8; 16],
+    prog_ifindex: u32,
+    expected_attach_type: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct MapCreateAttr {
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+}
+
+// What the running kernel was found to support. program_types are values of
+// enum bpf_prog_type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Features {
+    pub(crate) kernel_release: String,
+    pub(crate) program_types: Vec<u32>,
+    pub(crate) map_types: Vec<&'static str>,
+    pub(crate) features: Vec<&'static str>,
+}
+
+// What loading a program takes of the kernel, on top of its program type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Requirement {
+    // Linux major.minor or newer.
+    Kernel(u8, u8),
+    // The BTF of the kernel, which the attach target is looked up in.
+    KernelBtf,
+}
+
+pub(crate) fn probe_features() -> Features {
+    let kernel_btf = Path::new(KERNEL_BTF_PATH).exists();
+    let program_types = (1..=LAST_PROGRAM_TYPE)
+        .filter(
+            |t| match BTF_PROGRAM_TYPES.iter().find(|(b, _, _)| b == t) {
+                Some((_, major, minor)) => kernel_btf && kernel_at_least(*major, *minor),
+                None => load_program(*t, &[mov64_r0(0), exit()], &mut []).is_ok(),
+            },
+        )
+        .collect();
+    let map_types = MAP_PROBES
+        .iter()
+        .filter(|(map_type, _, key_size, value_size, flags)| {
+            create_map(*map_type, *key_size, *value_size, *flags)
+        })
+        .map(|(_, name, _, _, _)| *name)
+        .collect();
+    let features = [
+        ("tcx", check_tcx_supported().is_ok()),
+        ("netkit", check_netkit_supported().is_ok()),
+        ("netfilter", check_netfilter_supported().is_ok()),
+        ("bpf_lsm", check_bpf_lsm_enabled().is_ok()),
+        ("kprobe_multi", kernel_at_least(5, 18)),
+        ("uprobe_multi", kernel_at_least(6, 6)),
+        ("bpf_token", kernel_at_least(6, 9)),
+        ("kernel_btf", kernel_btf),
+    ]
+    .into_iter()
+    .filter(|(_, supported)| *supported)
+    .map(|(feature, _)| feature)
+    .collect();
+    Features {
+        kernel_release: kernel_release().unwrap_or_default(),
+        program_types,
+        map_types,
+        features,
+    }
+}
+
+// Returns the ids of the helpers programs of prog_type can call, which is
+// empty if programs of prog_type can't be loaded without an attach target.
+pub(crate) fn probe_helpers(prog_type: u32) -> Vec<u32> {
+    if load_program(prog_type, &[mov64_r0(0), exit()], &mut []).is_err() {
+        return vec![];
+    }
+    let mut log = vec![0u8; 4096];
+    (1..=LAST_HELPER)
+        .filter(|helper| {
+            log.fill(0);
+            match load_program(prog_type, &[call(*helper), exit()], &mut log) {
+                Ok(_) => true,
+                // Loads failing for other reasons than the helper, like the
+                // arguments the probe doesn't pass, leave a log too.
+                Err(_) => {
+                    let end = log.iter().position(|b| *b == 0).unwrap_or(log.len());
+                    let log = String::from_utf8_lossy(&log[..end]);
+                    !log.is_empty() && !helper_rejected(&log)
+                }
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn log_features(features: &Features) {
+    let program_types: Vec<String> = features
+        .program_types
+        .iter()
+        .map(|t| {
+            ProgramType::try_from(*t)
+                .map(|t| t.to_string())
+                .unwrap_or_else(|_| t.to_string())
+        })
+        .collect();
+    info!(
+        "Kernel {} supports program types: {}",
+        features.kernel_release,
+        program_types.join(", ")
+    );
+    info!(
+        "Kernel supports map types: {}",
+        features.map_types.join(", ")
+    );
+    info!("Kernel supports features: {}", features.features.join(", "));
+}
+
+// Checks that the running kernel has what program takes to load. Kernels
+// whose version can't be told are assumed to be recent enough.
+pub(crate) fn check_kernel_requirements(program: &Program) -> Result<(), BpfmanError> {
+    let (name, requirements) = requirements(program)?;
+    check_requirements(
+        name,
+        &requirements,
+        KernelVersion::current().ok(),
+        Path::new(KERNEL_BTF_PATH).exists(),
+    )
+}
+
+// The name of the kind of program in errors, along with what it requires.
+// Requirements of the attach mechanisms checked on attach, like tcx, netkit
+// and netfilter, aren't repeated here.
+fn requirements(program: &Program) -> Result<(&'static str, Vec<Requirement>), BpfmanError> {
+    use Requirement::*;
+    Ok(match program {
+        Program::Lsm(_) => ("lsm", vec![Kernel(5, 7), KernelBtf]),
+        Program::Iter(_) => ("iter", vec![Kernel(5, 8), KernelBtf]),
+        Program::RawTracepoint(p) if p.get_btf()? => ("tp_btf", vec![Kernel(5, 5), KernelBtf]),
+        Program::Extension(_) => ("freplace", vec![Kernel(5, 6)]),
+        Program::SkLookup(_) => ("sk_lookup", vec![Kernel(5, 9)]),
+        Program::KprobeMulti(_) => ("kprobe_multi", vec![Kernel(5, 18)]),
+        Program::UprobeMulti(_) => ("uprobe_multi", vec![Kernel(6, 6)]),
+        _ => ("", vec![]),
+    })
+}
+
+fn check_requirements(
+    name: &str,
+    requirements: &[Requirement],
+    current: Option<KernelVersion>,
+    kernel_btf: bool,
+) -> Result<(), BpfmanError> {
+    for requirement in requirements {
+        match *requirement {
+            Requirement::Kernel(major, minor)
+                if current
+                    .as_ref()
+                    .is_some_and(|v| *v < KernelVersion::new(major, minor, 0)) =>
+            {
+                return Err(BpfmanError::KernelTooOld {
+                    program: name.to_string(),
+                    major,
+                    minor,
+                });
+            }
+            Requirement::KernelBtf if !kernel_btf => {
+                return Err(BpfmanError::KernelBtfNotFound {
+                    program: name.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn helper_rejected(log: &str) -> bool {
+    log.contains("invalid func ")
+        || log.contains("unknown func ")
+        || log.contains("program of this type cannot use helper ")
+}
+
+fn kernel_at_least(major: u8, minor: u8) -> bool {
+    KernelVersion::current().is_ok_and(|v| v >= KernelVersion::new(major, minor, 0))
+}
+
+// The expected attach type and flags programs of prog_type take to load:
+// cgroup_sock_addr, cgroup_sockopt, sk_lookup, syscall and netfilter ones.
+fn load_options(prog_type: u32) -> (u32, u32) {
+    match prog_type {
+        18 => (8, 0),
+        25 => (21, 0),
+        30 => (36, 0),
+        31 => (0, BPF_F_SLEEPABLE),
+        32 => (45, 0),
+        _ => (0, 0),
+    }
+}
+
+// Loads insns as a program of prog_type, filling log with the verifier log if
+// it isn't empty.
+fn load_program(prog_type: u32, insns: &[[u8; 8]], log: &mut [u8]) -> std::io::Result<OwnedFd> {
+    let license = b"GPL\0";
+    let (expected_attach_type, prog_flags) = load_options(prog_type);
+    let mut attr = ProgLoadAttr {
+        prog_type,
+        insn_cnt: insns.len() as u32,
+        insns: insns.as_ptr() as u64,
+        license: license.as_ptr() as u64,
+        prog_flags,
+        expected_attach_type,
+        ..Default::default()
+    };
+    if !log.is_empty() {
+        attr.log_level = 1;
+        attr.log_size = log.len() as u32;
+        attr.log_buf = log.as_mut_ptr() as u64;
+    }
+    let fd = sys_bpf(BPF_PROG_LOAD, &mut attr)?;
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as i32) })
+}
+
+// Whether a map of map_type with a single entry, or a page for ring buffers,
+// can be created.
+fn create_map(map_type: u32, key_size: u32, value_size: u32, map_flags: u32) -> bool {
+    let max_entries = match map_type {
+        BPF_MAP_TYPE_RINGBUF | BPF_MAP_TYPE_USER_RINGBUF => page_size(),
+        _ => 1,
+    };
+    let mut attr = MapCreateAttr {
+        map_type,
+        key_size,
+        value_size,
+        max_entries,
+        map_flags,
+    };
+    sys_bpf(BPF_MAP_CREATE, &mut attr)
+        // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+        .map(|fd| drop(unsafe { OwnedFd::from_raw_fd(fd as i32) }))
+        .is_ok()
+}
+
+fn page_size() -> u32 {
+    // SAFETY: sysconf has no preconditions.
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u32 }
+}
+
+fn insn(code: u8, imm: i32) -> [u8; 8] {
+    let mut insn = [0; 8];
+    insn[0] = code;
+    insn[4..].copy_from_slice(&imm.to_ne_bytes());
+    insn
+}
+
+// r0 = imm
+fn mov64_r0(imm: i32) -> [u8; 8] {
+    insn(0xb7, imm)
+}
+
+fn call(helper: u32) -> [u8; 8] {
+    insn(0x85, helper as i32)
+}
+
+fn exit() -> [u8; 8] {
+    insn(0x95, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helper_rejected() {
+        assert!(helper_rejected(
+            "0: R1=ctx() R10=fp0\n0: (85) call unknown#212\ninvalid func unknown#212\n"
+        ));
+        assert!(helper_rejected(
+            "0: (85) call bpf_xdp_adjust_head#44\nprogram of this type cannot use helper bpf_xdp_adjust_head#44\n"
+        ));
+        assert!(helper_rejected(
+            "0: (85) call bpf_skb_vlan_push#18\nunknown func bpf_skb_vlan_push#18\n"
+        ));
+        assert!(!helper_rejected(
+            "0: (85) call bpf_map_lookup_elem#1\nR1 type=ctx expected=map_ptr\n"
+        ));
+    }
+
+    #[test]
+    fn test_check_requirements() {
+        let requirements = [Requirement::Kernel(5, 5), Requirement::KernelBtf];
+        let check = |current, kernel_btf| {
+            check_requirements("tp_btf", &requirements, current, kernel_btf)
+                .map_err(|e| e.to_string())
+        };
+        assert!(check(Some(KernelVersion::new(6, 1, 0)), true).is_ok());
+        assert!(check(None, true).is_ok());
+        assert_eq!(
+            check(Some(KernelVersion::new(5, 4, 0)), true).unwrap_err(),
+            "tp_btf programs require Linux 5.5 or newer"
+        );
+        assert_eq!(
+            check(Some(KernelVersion::new(6, 1, 0)), false).unwrap_err(),
+            "tp_btf programs require CONFIG_DEBUG_INFO_BTF; /sys/kernel/btf/vmlinux not found"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// On SIGHUP, the configuration file is read again and the settings which can
+// change while bpfman runs are applied, without restarting it and so without
+// closing its listeners: the log level, the registries images may be pulled
+// from, how images are pulled, the quotas and the dispatcher images. The
+// others keep the values bpfman was started with, and a file that can't be
+// read or parsed leaves all of them as they are.
+
+use std::{fs, str::FromStr};
+
+use bpfman_api::{
+    config::{Config, LogConfig},
+    util::directories::CFGPATH_BPFMAN_CONFIG,
+};
+use log::{debug, error, info, warn, LevelFilter};
+use tokio::{
+    select,
+    signal::unix::{signal, SignalKind},
+    sync::broadcast,
+    task::JoinHandle,
+};
+
+use crate::command::{Command, CommandSender};
+
+pub(crate) async fn serve_config_reload(
+    tx: CommandSender,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    let mut sighup = signal(SignalKind::hangup())?;
+
+    Ok(tokio::spawn(async move {
+        loop {
+            select! {
+                Some(()) = sighup.recv() => {
+                    info!("Received SIGHUP, reloading {CFGPATH_BPFMAN_CONFIG}");
+                    let Some(config) = read_config() else {
+                        continue;
+                    };
+                    if let Some(log) = &config.log {
+                        set_log_level(log);
+                    }
+                    if tx.send(Command::ReloadConfig(Box::new(config))).await.is_err() {
+                        break;
+                    }
+                }
+                res = shutdown_channel.recv() => {
+                    match res {
+                        Ok(()) => debug!("Config reload: Received shutdown signal"),
+                        Err(e) => error!("Error receiving shutdown signal {:?}", e),
+                    };
+                    break;
+                }
+            }
+        }
+        info!("Shutdown config reload");
+    }))
+}
+
+fn read_config() -> Option<Config> {
+    let config = fs::read_to_string(CFGPATH_BPFMAN_CONFIG)
+        .map_err(|e| e.to_string())
+        .and_then(|c| Config::from_str(&c).map_err(|e| e.to_string()));
+    match config {
+        Ok(config) => Some(config),
+        Err(e) => {
+            warn!(
+                "Unable to reload {CFGPATH_BPFMAN_CONFIG}, keeping the current configuration: {e}"
+            );
+            None
+        }
+    }
+}
+
+// Logging to standard error can only be made more verbose than RUST_LOG if the
+// level was configured when bpfman started, see execute_service().
+pub(crate) fn set_log_level(config: &LogConfig) {
+    match LevelFilter::from_str(&config.level) {
+        Ok(level) => {
+            log::set_max_level(level);
+            info!("Log level set to {level}");
+        }
+        Err(e) => error!("Invalid Log Level {}: {}", config.level, e),
+    }
+}
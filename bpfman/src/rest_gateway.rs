@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// The REST gateway serves the loader API as JSON over HTTPS, for tools that
+// can't speak gRPC. Requests and responses are the JSON forms of the API
+// messages, with the field names of the proto file, and are handled by the
+// same implementation as gRPC requests:
+//
+// POST   /v1/programs             Load, with a LoadRequest body
+// GET    /v1/programs             List, with the fields of ListRequest other
+//                                 than match_metadata as query parameters
+// GET    /v1/programs/:id         Get
+// DELETE /v1/programs/:id         Unload
+// POST   /v1/programs/:id/attach  Attach, with an AttachRequest body
+// POST   /v1/programs/:id/detach  Detach, with a DetachRequest body
+// GET    /v1/version              Version
+//
+// Errors are returned with the HTTP status corresponding to their gRPC code,
+// as grpc-gateway does, and a body of { "code": <gRPC code>, "message": "" }.
+// Requests carry the certificates the client presented, so that they're
+// authorized and audited like those of gRPC clients on TLS endpoints.
+
+use std::sync::Arc;
+
+use anyhow::{bail, Context};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use bpfman_api::{
+    config::{RestGatewayConfig, TlsConfig},
+    v1::{
+        bpfman_server::Bpfman, AttachRequest, AttachResponse, DetachRequest, DetachResponse,
+        GetRequest, GetResponse, ListRequest, ListResponse, LoadRequest, LoadResponse,
+        UnloadRequest, UnloadResponse, VersionRequest, VersionResponse,
+    },
+};
+use hyper::server::conn::Http;
+use log::{debug, error, info, warn};
+use serde::Serialize;
+use tokio::{net::TcpListener, select, sync::broadcast, task::JoinHandle};
+use tonic::{Code, Request, Status};
+
+use crate::{authz::PeerCerts, rpc::BpfmanLoader, tls::TlsReloader};
+
+type Loader = State<Arc<BpfmanLoader>>;
+type Peer = Extension<PeerCerts>;
+
+pub(crate) async fn serve_rest_gateway(
+    config: &RestGatewayConfig,
+    loader: BpfmanLoader,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> anyhow::Result<JoinHandle<()>> {
+    check_client_auth(&config.tls, config.allow_unauthenticated_clients)?;
+    let mut tls = TlsReloader::new(&config.tls, &[]).await?;
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .with_context(|| format!("unable to listen on {}", config.address))?;
+    let app = router(Arc::new(loader));
+    let address = config.address.clone();
+
+    Ok(tokio::spawn(async move {
+        info!("REST gateway listening on {address}");
+        loop {
+            select! {
+                res = listener.accept() => match res {
+                    Ok((stream, peer)) => {
+                        let acceptor = tls.acceptor();
+                        let app = app.clone();
+                        tokio::spawn(async move {
+                            let stream = match acceptor.accept(stream).await {
+                                Ok(stream) => stream,
+                                Err(e) => {
+                                    debug!("TLS handshake with {peer} failed: {e}");
+                                    return;
+                                }
+                            };
+                            let certs = stream.get_ref().1.peer_certificates().unwrap_or_default();
+                            let app = app.layer(Extension(PeerCerts(Arc::new(certs.to_vec()))));
+                            let connection = Http::new().http1_only(true).serve_connection(stream, app);
+                            if let Err(e) = connection.await {
+                                debug!("REST gateway connection of {peer} failed: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => warn!("Unable to accept REST gateway connection: {e}"),
+                },
+                res = shutdown_channel.recv() => {
+                    match res {
+                        Ok(()) => debug!("REST gateway: Received shutdown signal"),
+                        Err(e) => error!("Error receiving shutdown signal {:?}", e),
+                    };
+                    break;
+                }
+            }
+        }
+        info!("Shutdown REST gateway {address}");
+    }))
+}
+
+// Anyone who can reach the gateway could load programs if clients weren't
+// required to present a certificate, so that has to be asked for explicitly.
+fn check_client_auth(tls: &TlsConfig, allow_unauthenticated: bool) -> anyhow::Result<()> {
+    if tls.client_ca.is_none() && tls.spiffe.is_none() && !allow_unauthenticated {
+        bail!(
+            "REST gateway needs client_ca or spiffe to authenticate clients, \
+             or allow_unauthenticated_clients"
+        );
+    }
+    Ok(())
+}
+
+fn router(loader: Arc<BpfmanLoader>) -> Router {
+    Router::new()
+        .route("/v1/programs", post(load).get(list))
+        .route("/v1/programs/:id", get(get_program).delete(unload))
+        .route("/v1/programs/:id/attach", post(attach))
+        .route("/v1/programs/:id/detach", post(detach))
+        .route("/v1/version", get(version))
+        .with_state(loader)
+}
+
+async fn load(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Json(request): Json<LoadRequest>,
+) -> Result<Json<LoadResponse>, GatewayError> {
+    reply(loader.load(grpc_request(certs, request)).await)
+}
+
+async fn list(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Query(request): Query<ListRequest>,
+) -> Result<Json<ListResponse>, GatewayError> {
+    reply(loader.list(grpc_request(certs, request)).await)
+}
+
+async fn get_program(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Path(id): Path<u32>,
+) -> Result<Json<GetResponse>, GatewayError> {
+    reply(
+        loader
+            .get(grpc_request(certs, GetRequest { id, uuid: None }))
+            .await,
+    )
+}
+
+async fn unload(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Path(id): Path<u32>,
+) -> Result<Json<UnloadResponse>, GatewayError> {
+    reply(
+        loader
+            .unload(grpc_request(certs, UnloadRequest { id }))
+            .await,
+    )
+}
+
+async fn attach(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Path(id): Path<u32>,
+    Json(request): Json<AttachRequest>,
+) -> Result<Json<AttachResponse>, GatewayError> {
+    reply(
+        loader
+            .attach(grpc_request(certs, AttachRequest { id, ..request }))
+            .await,
+    )
+}
+
+async fn detach(
+    State(loader): Loader,
+    Extension(certs): Peer,
+    Path(id): Path<u32>,
+    Json(request): Json<DetachRequest>,
+) -> Result<Json<DetachResponse>, GatewayError> {
+    reply(
+        loader
+            .detach(grpc_request(certs, DetachRequest { id, ..request }))
+            .await,
+    )
+}
+
+async fn version(
+    State(loader): Loader,
+    Extension(certs): Peer,
+) -> Result<Json<VersionResponse>, GatewayError> {
+    reply(loader.version(grpc_request(certs, VersionRequest {})).await)
+}
+
+fn grpc_request<T>(certs: PeerCerts, message: T) -> Request<T> {
+    let mut request = Request::new(message);
+    request.extensions_mut().insert(certs);
+    request
+}
+
+fn reply<T>(result: Result<tonic::Response<T>, Status>) -> Result<Json<T>, GatewayError> {
+    result
+        .map(|response| Json(response.into_inner()))
+        .map_err(GatewayError)
+}
+
+struct GatewayError(Status);
+
+#[derive(Serialize)]
+struct ErrorBody {
+    code: i32,
+    message: String,
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            code: self.0.code() as i32,
+            message: self.0.message().to_string(),
+        };
+        (http_status(self.0.code()), Json(body)).into_response()
+    }
+}
+
+// Maps gRPC codes to HTTP statuses the way grpc-gateway does.
+fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::Cancelled => StatusCode::REQUEST_TIMEOUT,
+        Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => {
+            StatusCode::BAD_REQUEST
+        }
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_status() {
+        assert_eq!(http_status(Code::NotFound), StatusCode::NOT_FOUND);
+        assert_eq!(http_status(Code::Aborted), StatusCode::CONFLICT);
+        assert_eq!(http_status(Code::PermissionDenied), StatusCode::FORBIDDEN);
+        assert_eq!(
+            http_status(Code::Internal),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn test_check_client_auth() {
+        let mut tls = TlsConfig {
+            cert: Some("/etc/bpfman/tls/server.crt".to_string()),
+            key: Some("/etc/bpfman/tls/server.key".to_string()),
+            client_ca: None,
+            spiffe: None,
+        };
+        assert!(check_client_auth(&tls, false).is_err());
+        assert!(check_client_auth(&tls, true).is_ok());
+
+        tls.client_ca = Some("/etc/bpfman/tls/ca.crt".to_string());
+        assert!(check_client_auth(&tls, false).is_ok());
+    }
+}
@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Records of ring buffer maps are streamed to clients so they don't need
+// access to the map themselves. A ring buffer has a single consumer position,
+// shared with anything else reading the map, so bpfman reads each map it
+// streams once and passes the records on to all of its clients. Maps the
+// workload consumes itself shouldn't be watched, as either reader would only
+// see part of the records. Each client has its own queue of records waiting
+// to be sent, which is where the drop policy applies when the client falls
+// behind. With the block policy a client that falls behind stops the reader,
+// and so holds back the other clients of the map as well.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use aya::maps::{Map, MapData, RingBuf};
+use bpfman_api::{v1::WatchRingBufferResponse, DropPolicy};
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use tokio::{
+    io::unix::AsyncFd,
+    select,
+    sync::mpsc,
+    time::{interval, MissedTickBehavior},
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+
+use crate::errors::BpfmanError;
+
+// Clients may queue at most this many records.
+pub(crate) const MAX_QUEUE_SIZE: u32 = 65536;
+
+// How often readers check whether their clients went away while no records
+// were written.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) type RingBufferStream = ReceiverStream<Result<WatchRingBufferResponse, Status>>;
+
+lazy_static! {
+    static ref RING_BUFFER_READERS: Readers<Result<Vec<u8>, Status>> = Readers::new();
+}
+
+// Returns a stream of the records of the ring buffer map pinned at path,
+// which is read until the streams of all its clients are dropped.
+pub(crate) fn watch_ring_buffer(
+    path: &Path,
+    queue_size: usize,
+    drop_policy: DropPolicy,
+) -> Result<RingBufferStream, BpfmanError> {
+    let (records_tx, records_rx) = mpsc::channel(1);
+    RING_BUFFER_READERS.join(path, records_tx, |clients| {
+        let map = Map::from_map_data(MapData::from_pin(path)?)?;
+        let ring_buf = AsyncFd::new(RingBuf::try_from(map)?)?;
+        tokio::spawn(read_ring_buffer(ring_buf, clients));
+        Ok(())
+    })?;
+
+    // Records wait in the queue rather than in the channel, so the drop policy
+    // can be applied to them.
+    let (tx, rx) = mpsc::channel(1);
+    tokio::spawn(forward_records(
+        records_rx,
+        tx,
+        RecordQueue::new(queue_size, drop_policy),
+    ));
+    Ok(ReceiverStream::new(rx))
+}
+
+async fn read_ring_buffer(
+    mut ring_buf: AsyncFd<RingBuf<MapData>>,
+    mut clients: Clients<Result<Vec<u8>, Status>>,
+) {
+    let mut idle = interval(IDLE_CHECK_INTERVAL);
+    idle.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        select! {
+            () = clients.joined() => {}
+            _ = idle.tick() => {
+                if clients.done() {
+                    break;
+                }
+            }
+            guard = ring_buf.readable_mut() => {
+                let mut guard = match guard {
+                    Ok(guard) => guard,
+                    Err(e) => {
+                        warn!("error polling ring buffer: {e}");
+                        clients.send(Err(Status::aborted(format!("{e}")))).await;
+                        break;
+                    }
+                };
+                // Clients with the block policy hold back the reader here
+                // until they catch up.
+                loop {
+                    let Some(record) = guard.get_inner_mut().next() else {
+                        break;
+                    };
+                    let data = record.to_vec();
+                    drop(record);
+                    clients.send(Ok(data)).await;
+                }
+                guard.clear_ready();
+            }
+        }
+    }
+    debug!("stopped reading ring buffer");
+}
+
+// Forwards the records passed on by the reader of the map to a client.
+async fn forward_records(
+    mut records: mpsc::Receiver<Result<Vec<u8>, Status>>,
+    tx: mpsc::Sender<Result<WatchRingBufferResponse, Status>>,
+    mut queue: RecordQueue<Vec<u8>>,
+) {
+    loop {
+        select! {
+            record = records.recv(), if !queue.is_blocked() => {
+                match record {
+                    Some(Ok(data)) => queue.push(data),
+                    Some(Err(status)) => {
+                        let _ = tx.send(Err(status)).await;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+            permit = tx.reserve(), if !queue.is_empty() => {
+                match permit {
+                    Ok(permit) => {
+                        if let Some((data, dropped)) = queue.pop() {
+                            permit.send(Ok(WatchRingBufferResponse { data, dropped }));
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+            _ = tx.closed(), if queue.is_empty() => break,
+        }
+    }
+    debug!("stopped streaming ring buffer");
+}
+
+// Passes new clients of a map on to its reader.
+type Joiner<T> = mpsc::UnboundedSender<mpsc::Sender<T>>;
+
+// The readers of the maps streamed to clients, by pin path.
+pub(crate) struct Readers<T> {
+    readers: Mutex<HashMap<PathBuf, Joiner<T>>>,
+}
+
+impl<T: 'static> Readers<T> {
+    pub(crate) fn new() -> Self {
+        Readers {
+            readers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Adds a client of the map pinned at path, to which the records of the map
+    // are sent through client. If the map isn't read yet, start is called to
+    // start its reader, which is given the clients.
+    pub(crate) fn join(
+        &'static self,
+        path: &Path,
+        client: mpsc::Sender<T>,
+        start: impl FnOnce(Clients<T>) -> Result<(), BpfmanError>,
+    ) -> Result<(), BpfmanError> {
+        let mut readers = self.readers.lock().unwrap();
+        // The reader of a map removes itself before it stops, under the lock,
+        // so a client is never handed to a reader that stopped.
+        let client = match readers.get(path) {
+            Some(joiner) => match joiner.send(client) {
+                Ok(()) => return Ok(()),
+                Err(e) => e.0,
+            },
+            None => client,
+        };
+        let (joiner, joins) = mpsc::unbounded_channel();
+        start(Clients {
+            path: path.to_path_buf(),
+            readers: self,
+            joins,
+            clients: vec![client],
+        })?;
+        readers.insert(path.to_path_buf(), joiner);
+        Ok(())
+    }
+}
+
+// The clients of a map, held by its reader.
+pub(crate) struct Clients<T: 'static> {
+    path: PathBuf,
+    readers: &'static Readers<T>,
+    joins: mpsc::UnboundedReceiver<mpsc::Sender<T>>,
+    clients: Vec<mpsc::Sender<T>>,
+}
+
+impl<T: Clone + 'static> Clients<T> {
+    // Waits for a client to join.
+    pub(crate) async fn joined(&mut self) {
+        if let Some(client) = self.joins.recv().await {
+            self.clients.push(client);
+        }
+    }
+
+    // Sends record to every client, waiting for the ones that don't accept
+    // records right now. Clients that went away are removed.
+    pub(crate) async fn send(&mut self, record: T) {
+        let mut gone = vec![];
+        for (i, client) in self.clients.iter().enumerate() {
+            if client.send(record.clone()).await.is_err() {
+                gone.push(i);
+            }
+        }
+        for i in gone.into_iter().rev() {
+            self.clients.remove(i);
+        }
+    }
+
+    // Returns true once all clients went away, in which case the reader must
+    // stop, as no further clients are handed to it.
+    pub(crate) fn done(&mut self) -> bool {
+        self.clients.retain(|client| !client.is_closed());
+        if !self.clients.is_empty() {
+            return false;
+        }
+        let mut readers = self.readers.readers.lock().unwrap();
+        while let Ok(client) = self.joins.try_recv() {
+            self.clients.push(client);
+        }
+        if self.clients.is_empty() {
+            readers.remove(&self.path);
+            return true;
+        }
+        false
+    }
+}
+
+// Records waiting to be sent to a client, with the number of records dropped
+// since the last one was sent.
+pub(crate) struct RecordQueue<T> {
+    records: VecDeque<T>,
+    size: usize,
+    drop_policy: DropPolicy,
+    dropped: u64,
+}
+
+impl<T> RecordQueue<T> {
+    pub(crate) fn new(size: usize, drop_policy: DropPolicy) -> Self {
+        RecordQueue {
+            records: VecDeque::new(),
+            size,
+            drop_policy,
+            dropped: 0,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    // With the block policy records aren't read while the queue is full.
+    pub(crate) fn is_blocked(&self) -> bool {
+        self.drop_policy == DropPolicy::Block && self.records.len() >= self.size
+    }
+
+    pub(crate) fn push(&mut self, record: T) {
+        if self.records.len() >= self.size {
+            match self.drop_policy {
+                DropPolicy::DropNewest => {
+                    self.dropped += 1;
+                    return;
+                }
+                DropPolicy::DropOldest => {
+                    self.dropped += 1;
+                    self.records.pop_front();
+                }
+                // Reading stops once the queue is full, records read at once
+                // before may still overfill it.
+                DropPolicy::Block => {}
+            }
+        }
+        self.records.push_back(record);
+    }
+
+    // Returns the oldest record with the number of records dropped before it.
+    pub(crate) fn pop(&mut self) -> Option<(T, u64)> {
+        self.records
+            .pop_front()
+            .map(|record| (record, std::mem::take(&mut self.dropped)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(queue: &mut RecordQueue<Vec<u8>>) {
+        for i in 0..4u8 {
+            queue.push(vec![i]);
+        }
+    }
+
+    #[test]
+    fn test_record_queue_drop_policies() {
+        let mut queue = RecordQueue::new(2, DropPolicy::DropNewest);
+        fill(&mut queue);
+        assert_eq!(queue.pop(), Some((vec![0], 2)));
+        assert_eq!(queue.pop(), Some((vec![1], 0)));
+        assert!(queue.pop().is_none());
+
+        let mut queue = RecordQueue::new(2, DropPolicy::DropOldest);
+        fill(&mut queue);
+        assert_eq!(queue.pop(), Some((vec![2], 2)));
+        assert_eq!(queue.pop(), Some((vec![3], 0)));
+
+        let mut queue = RecordQueue::new(2, DropPolicy::Block);
+        queue.push(vec![0]);
+        assert!(!queue.is_blocked());
+        queue.push(vec![1]);
+        assert!(queue.is_blocked());
+        queue.push(vec![2]);
+        assert_eq!(queue.pop(), Some((vec![0], 0)));
+        assert!(queue.is_blocked());
+        queue.pop();
+        assert!(!queue.is_blocked());
+    }
+
+    lazy_static! {
+        static ref TEST_READERS: Readers<u8> = Readers::new();
+    }
+
+    #[tokio::test]
+    async fn test_readers_fan_out() {
+        let path = Path::new("/run/bpfman/fs/maps/1/events");
+        let (a_tx, mut a_rx) = mpsc::channel(1);
+        let mut started = None;
+        TEST_READERS
+            .join(path, a_tx, |clients| {
+                started = Some(clients);
+                Ok(())
+            })
+            .unwrap();
+        let mut clients = started.take().unwrap();
+
+        // A second client joins the running reader.
+        let (b_tx, mut b_rx) = mpsc::channel(1);
+        TEST_READERS.join(path, b_tx, |_| unreachable!()).unwrap();
+        clients.joined().await;
+        clients.send(1).await;
+        assert_eq!(a_rx.recv().await, Some(1));
+        assert_eq!(b_rx.recv().await, Some(1));
+
+        drop(a_rx);
+        clients.send(2).await;
+        assert_eq!(b_rx.recv().await, Some(2));
+        assert!(!clients.done());
+
+        // Once all clients are gone the reader is removed, and the next client
+        // starts a new one.
+        drop(b_rx);
+        assert!(clients.done());
+        let (c_tx, _c_rx) = mpsc::channel(1);
+        TEST_READERS
+            .join(path, c_tx, |clients| {
+                started = Some(clients);
+                Ok(())
+            })
+            .unwrap();
+        assert!(started.is_some());
+    }
+}
@@ -1,161 +1,191 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+};
+
 use bpfman_api::{
+    config::RingBufferConfig,
     v1::{
         attach_info::Info, bpfman_server::Bpfman, bytecode_location::Location,
-        list_response::ListResult, GetRequest, GetResponse, KprobeAttachInfo, ListRequest,
-        ListResponse, LoadRequest, LoadResponse, PullBytecodeRequest, PullBytecodeResponse,
-        TcAttachInfo, TracepointAttachInfo, UnloadRequest, UnloadResponse, UprobeAttachInfo,
-        XdpAttachInfo,
+        list_response::ListResult, AttachRequest, AttachResponse, BytecodeLocation,
+        CgroupDeviceAttachInfo, CgroupSkbAttachInfo, CgroupSockAddrAttachInfo,
+        CgroupSockoptAttachInfo, CgroupSysctlAttachInfo, DeleteMapEntryRequest,
+        DeleteMapEntryResponse, DetachRequest, DetachResponse, DumpMapRequest, DumpMapResponse,
+        Event, ExportMapsRequest, ExportMapsResponse, ExtensionAttachInfo, FlowDissectorAttachInfo,
+        GetEventsRequest, GetEventsResponse, GetRequest, GetResponse, IterAttachInfo,
+        KernelLinkInfo, KernelMapInfo, KprobeAttachInfo, KprobeMultiAttachInfo, ListImagesRequest,
+        ListImagesResponse, ListKernelLinksRequest, ListKernelLinksResponse, ListKernelMapsRequest,
+        ListKernelMapsResponse, ListMapsRequest, ListMapsResponse, ListRequest, ListResponse,
+        LoadBatchRequest, LoadBatchResponse, LoadRequest, LoadResponse, LoadUploadRequest,
+        LookupMapEntryRequest, LookupMapEntryResponse, LsmAttachInfo, MapEntry, MapInfo,
+        NetkitAttachInfo, PerfEventAttachInfo, ProbeFeaturesRequest, ProbeFeaturesResponse,
+        ProgramHelpers, PruneImagesRequest, PruneImagesResponse, PublishMapsRequest,
+        PublishMapsResponse, PullBytecodeRequest, PullBytecodeResponse, RawTracepointAttachInfo,
+        ReadRequest, ReadResponse, ReplaceRequest, ReplaceResponse, RollbackRequest,
+        RollbackResponse, SetPriorityRequest, SetPriorityResponse, SkLookupAttachInfo,
+        SkMsgAttachInfo, SkSkbAttachInfo, SockOpsAttachInfo, SocketFilterAttachInfo, TcAttachInfo,
+        TracepointAttachInfo, UnexportMapsRequest, UnexportMapsResponse, UnloadRequest,
+        UnloadResponse, UnpublishMapsRequest, UnpublishMapsResponse, UpdateMapEntryRequest,
+        UpdateMapEntryResponse, UprobeAttachInfo, UprobeMultiAttachInfo, UsdtAttachInfo,
+        VersionRequest, VersionResponse, WatchPerfBufferRequest, WatchPerfBufferResponse,
+        WatchRingBufferRequest, WatchRingBufferResponse, XdpAttachInfo,
     },
-    TcProceedOn, XdpProceedOn,
+    CgroupAttachFlags, DropPolicy, ProgramType, TcProceedOn, XdpProceedOn,
 };
-use log::warn;
-use tokio::sync::{mpsc, mpsc::Sender, oneshot};
-use tonic::{Request, Response, Status};
+use log::{debug, warn};
+use nix::unistd::{Group, Uid, User};
+use serde::Serialize;
+use tokio::sync::oneshot;
+use tokio_stream::Stream;
+use tonic::{transport::server::UdsConnectInfo, Code, Request, Response, Status, Streaming};
+use tonic_types::{ErrorDetails, StatusExt};
 
-use crate::command::{
-    Command, GetArgs, KprobeProgram, LoadArgs, Program, ProgramData, PullBytecodeArgs, TcProgram,
-    TracepointProgram, UnloadArgs, UprobeProgram, XdpProgram,
+use crate::{
+    audit::{AuditRecord, Audited, Auditor},
+    authz::{Authorizer, Grant},
+    bpf::{MAP_EXPORTS_TREE, PUBLISHED_MAPS_TREE},
+    command::{
+        AttachArgs, CgroupDeviceProgram, CgroupSkbProgram, CgroupSockAddrProgram,
+        CgroupSockoptProgram, CgroupSysctlProgram, Command, CommandSender, DetachArgs, Direction,
+        ExportMapsArgs, ExtensionProgram, FlowDissectorProgram, GetArgs, InnerMaps, IterProgram,
+        KprobeMultiProgram, KprobeProgram, ListArgs, ListFilter, ListImagesArgs, LoadArgs,
+        LoadBatchArgs, LoadObjectArgs, LsmProgram, MapInit, MapPermissions, MapPinPolicy,
+        NetkitProgram, PerfEventProgram, Program, ProgramData, PruneImagesArgs, PublishMapsArgs,
+        PullBytecodeArgs, RawTracepointProgram, ReadArgs, ReplaceArgs, RollbackArgs,
+        SetPriorityArgs, SkLookupProgram, SkMsgProgram, SkSkbProgram, SockOpsProgram,
+        SocketFilterProgram, TcProgram, TracepointProgram, UnexportMapsArgs, UnloadArgs,
+        UnpublishMapsArgs, UprobeMultiProgram, UprobeProgram, UsdtProgram, VersionArgs, XdpProgram,
+        SUPPORTED_PROGRAM_TYPES,
+    },
+    errors::BpfmanError,
+    events::{events, EventFilter},
+    inventory::{kernel_links, kernel_maps},
+    maps::{map_path, pinned_maps, valid_map_name, PinnedMap, MAP_IN_MAP_TYPES},
+    oci_utils::fetch::check_url,
+    perf_buffer::watch_perf_buffer,
+    probe::{probe_features, probe_helpers},
+    ring_buffer::{watch_ring_buffer, MAX_QUEUE_SIZE},
+    utils::{bytes_to_u32, sled_get_option, upload_path},
+    ROOT_DB,
 };
 
+// Iterator output is streamed back to the client in chunks of this size.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+// Largest bytecode object clients can upload.
+const MAX_UPLOAD_SIZE: usize = 128 * 1024 * 1024;
+
 #[derive(Debug)]
 pub struct BpfmanLoader {
-    tx: Sender<Command>,
+    tx: CommandSender,
+    ring_buffer: Option<RingBufferConfig>,
+    authorizer: Option<Arc<Authorizer>>,
+    auditor: Option<Arc<Auditor>>,
 }
 
 impl BpfmanLoader {
-    pub(crate) fn new(tx: mpsc::Sender<Command>) -> BpfmanLoader {
-        BpfmanLoader { tx }
+    pub(crate) fn new(tx: CommandSender, ring_buffer: Option<RingBufferConfig>) -> BpfmanLoader {
+        BpfmanLoader {
+            tx,
+            ring_buffer,
+            authorizer: None,
+            auditor: None,
+        }
     }
-}
 
-#[tonic::async_trait]
-impl Bpfman for BpfmanLoader {
-    async fn load(&self, request: Request<LoadRequest>) -> Result<Response<LoadResponse>, Status> {
-        let request = request.into_inner();
+    pub(crate) fn with_authorizer(mut self, authorizer: Option<Arc<Authorizer>>) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
 
-        let (resp_tx, resp_rx) = oneshot::channel();
+    pub(crate) fn with_auditor(mut self, auditor: Option<Arc<Auditor>>) -> Self {
+        self.auditor = auditor;
+        self
+    }
 
-        let bytecode_source = match request
-            .bytecode
-            .ok_or(Status::aborted("missing bytecode info"))?
-            .location
-            .ok_or(Status::aborted("missing location"))?
-        {
-            Location::Image(i) => crate::command::Location::Image(i.into()),
-            Location::File(p) => crate::command::Location::File(p),
-        };
+    fn audit_start<T: Audited>(
+        &self,
+        request: &mut Request<T>,
+        operation: &'static str,
+    ) -> Option<AuditRecord> {
+        self.auditor.as_ref().map(|a| a.start(request, operation))
+    }
 
-        let data = ProgramData::new_pre_load(
-            bytecode_source,
-            request.name,
-            request.metadata,
-            request.global_data,
-            request.map_owner_id,
-        )
-        .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    fn audit_finish<R: Serialize>(
+        &self,
+        record: Option<AuditRecord>,
+        result: &Result<Response<R>, Status>,
+    ) {
+        if let (Some(auditor), Some(record)) = (&self.auditor, record) {
+            auditor.finish(record, result);
+        }
+    }
 
-        let load_args = LoadArgs {
-            program: match request
-                .attach
-                .ok_or(Status::aborted("missing attach info"))?
-                .info
-                .ok_or(Status::aborted("missing info"))?
-            {
-                Info::XdpAttachInfo(XdpAttachInfo {
-                    priority,
-                    iface,
-                    position: _,
-                    proceed_on,
-                }) => Program::Xdp(
-                    XdpProgram::new(
-                        data,
-                        priority,
-                        iface,
-                        XdpProceedOn::from_int32s(proceed_on)
-                            .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
-                    )
-                    .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?,
-                ),
-                Info::TcAttachInfo(TcAttachInfo {
-                    priority,
-                    iface,
-                    position: _,
-                    direction,
-                    proceed_on,
-                }) => {
-                    let direction = direction
-                        .try_into()
-                        .map_err(|_| Status::aborted("direction is not a string"))?;
-                    Program::Tc(
-                        TcProgram::new(
-                            data,
-                            priority,
-                            iface,
-                            TcProceedOn::from_int32s(proceed_on)
-                                .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
-                            direction,
-                        )
-                        .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?,
-                    )
-                }
-                Info::TracepointAttachInfo(TracepointAttachInfo { tracepoint }) => {
-                    Program::Tracepoint(
-                        TracepointProgram::new(data, tracepoint).map_err(|e| {
-                            Status::aborted(format!("failed to create tcprogram: {e}"))
-                        })?,
-                    )
-                }
-                Info::KprobeAttachInfo(KprobeAttachInfo {
-                    fn_name,
-                    offset,
-                    retprobe,
-                    container_pid,
-                }) => Program::Kprobe(
-                    KprobeProgram::new(data, fn_name, offset, retprobe, container_pid).map_err(
-                        |e| Status::aborted(format!("failed to create kprobeprogram: {e}")),
-                    )?,
-                ),
-                Info::UprobeAttachInfo(UprobeAttachInfo {
-                    fn_name,
-                    offset,
-                    target,
-                    retprobe,
-                    pid,
-                    container_pid,
-                }) => Program::Uprobe(
-                    UprobeProgram::new(data, fn_name, offset, target, retprobe, pid, container_pid)
-                        .map_err(|e| {
-                            Status::aborted(format!("failed to create uprobeprogram: {e}"))
-                        })?,
-                ),
-            },
+    // Returns what the client of request may do, denying requests of
+    // operations its role doesn't allow.
+    fn authorize<T>(&self, request: &Request<T>, operation: &str) -> Result<Grant, Status> {
+        match &self.authorizer {
+            Some(authorizer) => authorizer.authorize(request, operation),
+            None => Ok(Grant::unlimited()),
+        }
+    }
+
+    // Loads the functions of a load request with additional names as programs
+    // that share the maps of the first one.
+    async fn load_object(
+        &self,
+        request: LoadRequest,
+        additional_names: Vec<String>,
+        owner: Option<String>,
+        grant: &Grant,
+    ) -> Result<Response<LoadResponse>, Status> {
+        let programs = additional_names
+            .into_iter()
+            .map(|name| LoadRequest {
+                name,
+                ..request.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let programs = std::iter::once(request)
+            .chain(programs)
+            .map(|r| program_from_request(r, owner.as_deref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        for program in &programs {
+            grant.check_program(program)?;
+        }
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let load_object_args = LoadObjectArgs {
+            programs,
             responder: resp_tx,
         };
 
-        // Send the GET request
-        self.tx.send(Command::Load(load_args)).await.unwrap();
+        // Send the LOAD_OBJECT request
+        self.tx
+            .send(Command::LoadObject(load_object_args))
+            .await
+            .unwrap();
 
         // Await the response
         match resp_rx.await {
             Ok(res) => match res {
-                Ok(program) => {
-                    let reply_entry = LoadResponse {
-                        info: Some((&program).try_into().map_err(|e| {
-                            Status::aborted(format!("convert Program to GRPC program: {e}"))
-                        })?),
-                        kernel_info: Some((&program).try_into().map_err(|e| {
-                            Status::aborted(format!(
-                                "convert Program to GRPC kernel program info: {e}"
-                            ))
-                        })?),
-                    };
-                    Ok(Response::new(reply_entry))
+                Ok(programs) => {
+                    let mut responses = programs
+                        .iter()
+                        .map(load_response)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let mut response = responses.remove(0);
+                    response.additional_programs = responses;
+                    Ok(Response::new(response))
                 }
                 Err(e) => {
                     warn!("BPFMAN load error: {:#?}", e);
-                    Err(Status::aborted(format!("{e}")))
+                    Err(load_error_status(e))
                 }
             },
 
@@ -166,46 +196,50 @@ impl Bpfman for BpfmanLoader {
         }
     }
 
-    async fn unload(
-        &self,
-        request: Request<UnloadRequest>,
-    ) -> Result<Response<UnloadResponse>, Status> {
-        let reply = UnloadResponse {};
-        let request = request.into_inner();
-        let id = request.id;
-
+    // Ids of the programs loaded by bpfman.
+    async fn managed_program_ids(&self) -> Result<HashSet<u32>, Status> {
         let (resp_tx, resp_rx) = oneshot::channel();
-        let cmd = Command::Unload(UnloadArgs {
-            id,
+        let cmd = Command::List(ListArgs {
+            filter: ListFilter {
+                bpfman_programs_only: true,
+                ..Default::default()
+            },
+            page_size: 0,
+            page_token: None,
             responder: resp_tx,
         });
 
-        // Send the GET request
+        // Send the LIST request
         self.tx.send(cmd).await.unwrap();
 
         // Await the response
         match resp_rx.await {
-            Ok(res) => match res {
-                Ok(_) => Ok(Response::new(reply)),
-                Err(e) => {
-                    warn!("BPFMAN unload error: {}", e);
-                    Err(Status::aborted(format!("{e}")))
-                }
-            },
+            Ok(res) => res
+                .map_err(|e| {
+                    warn!("BPFMAN list error: {}", e);
+                    Status::aborted(format!("{e}"))
+                })?
+                .programs
+                .iter()
+                .map(|p| {
+                    p.get_data()
+                        .get_id()
+                        .map_err(|e| Status::aborted(format!("{e}")))
+                })
+                .collect(),
             Err(e) => {
-                warn!("RPC unload error: {}", e);
+                warn!("RPC list error: {}", e);
                 Err(Status::aborted(format!("{e}")))
             }
         }
     }
 
-    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
-        let request = request.into_inner();
-        let id = request.id;
-
+    // Returns program id, which must have been loaded by bpfman.
+    async fn get_program(&self, id: u32) -> Result<Program, Status> {
         let (resp_tx, resp_rx) = oneshot::channel();
         let cmd = Command::Get(GetArgs {
             id,
+            uuid: None,
             responder: resp_tx,
         });
 
@@ -213,230 +247,2284 @@ impl Bpfman for BpfmanLoader {
         self.tx.send(cmd).await.unwrap();
 
         // Await the response
-        match resp_rx.await {
-            Ok(res) => match res {
-                Ok(program) => {
-                    let reply_entry = GetResponse {
-                        info: if let Program::Unsupported(_) = program {
-                            None
-                        } else {
-                            Some((&program).try_into().map_err(|e| {
-                                Status::aborted(format!("failed to get program metadata: {e}"))
-                            })?)
-                        },
-                        kernel_info: match (&program).try_into() {
-                            Ok(i) => {
-                                if let Program::Unsupported(_) = program {
-                                    program.delete().map_err(|e| {
-                                        Status::aborted(format!(
-                                            "failed to get program metadata: {e}"
-                                        ))
-                                    })?;
-                                };
-                                Ok(Some(i))
-                            }
-                            Err(e) => Err(Status::aborted(format!(
-                                "convert Program to GRPC kernel program info: {e}"
-                            ))),
-                        }?,
-                    };
-                    Ok(Response::new(reply_entry))
-                }
-                Err(e) => {
-                    warn!("BPFMAN get error: {}", e);
-                    Err(Status::aborted(format!("{e}")))
-                }
-            },
+        let program = match resp_rx.await {
+            Ok(res) => res.map_err(|e| {
+                warn!("BPFMAN get error: {}", e);
+                Status::aborted(format!("{e}"))
+            })?,
             Err(e) => {
                 warn!("RPC get error: {}", e);
-                Err(Status::aborted(format!("{e}")))
+                return Err(Status::aborted(format!("{e}")));
             }
+        };
+
+        if let Program::Unsupported(_) = program {
+            program
+                .delete()
+                .map_err(|e| Status::aborted(format!("failed to get program metadata: {e}")))?;
+            return Err(Status::aborted(format!(
+                "program {id} was not loaded by bpfman"
+            )));
         }
+        Ok(program)
     }
 
-    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
-        let mut reply = ListResponse { results: vec![] };
+    // Denies requests on program id unless the role of the client allows its
+    // program type, interface and location, as for loads.
+    async fn check_target(&self, grant: &Grant, id: u32) -> Result<(), Status> {
+        if grant.is_unlimited() {
+            return Ok(());
+        }
+        grant.check_program(&self.get_program(id).await?)
+    }
 
-        let (resp_tx, resp_rx) = oneshot::channel();
-        let cmd = Command::List { responder: resp_tx };
+    // Like check_target(), for the program owning the maps stored under key in
+    // the database tree of published or exported maps, if any.
+    async fn check_maps_owner(&self, grant: &Grant, tree: &str, key: &str) -> Result<(), Status> {
+        if grant.is_unlimited() {
+            return Ok(());
+        }
+        let owner = ROOT_DB
+            .open_tree(tree)
+            .map_err(|e| Status::aborted(format!("{e}")))
+            .and_then(|t| sled_get_option(&t, key).map_err(|e| Status::aborted(format!("{e}"))))?;
+        match owner {
+            Some(owner) => self.check_target(grant, bytes_to_u32(owner)).await,
+            None => Ok(()),
+        }
+    }
 
-        // Send the GET request
-        self.tx.send(cmd).await.unwrap();
+    // Returns the directory the maps of a program loaded by bpfman are pinned
+    // in, if the role of the client allows the program.
+    async fn map_pin_path(&self, grant: &Grant, id: u32) -> Result<PathBuf, Status> {
+        let program = self.get_program(id).await?;
+        grant.check_program(&program)?;
+        program
+            .get_data()
+            .get_map_pin_path()
+            .map_err(|e| Status::aborted(format!("failed to get map pin path: {e}")))?
+            .ok_or_else(|| Status::aborted(format!("program {id} has no pinned maps")))
+    }
 
-        // Await the response
-        match resp_rx.await {
-            Ok(res) => match res {
-                Ok(results) => {
-                    for r in results {
-                        // If filtering on Program Type, then make sure this program matches, else skip.
-                        if let Some(p) = request.get_ref().program_type {
-                            if p != r.kind() as u32 {
-                                continue;
-                            }
-                        }
-
-                        // filter based on list all flag
-                        if let Program::Unsupported(_) = r {
-                            if request.get_ref().bpfman_programs_only()
-                                || !request.get_ref().match_metadata.is_empty()
-                            {
-                                continue;
-                            }
-                        } else {
-                            // Filter on the input metadata field if provided
-                            let mut meta_match = true;
-                            for (key, value) in &request.get_ref().match_metadata {
-                                if let Some(v) = r
-                                    .get_data()
-                                    .get_metadata()
-                                    .map_err(|e| {
-                                        Status::aborted(format!(
-                                            "failed to get program metadata: {e}"
-                                        ))
-                                    })?
-                                    .get(key)
-                                {
-                                    if *value != *v {
-                                        meta_match = false;
-                                        break;
-                                    }
-                                } else {
-                                    meta_match = false;
-                                    break;
-                                }
-                            }
+    async fn open_map(
+        &self,
+        grant: &Grant,
+        id: u32,
+        name: &str,
+        json: bool,
+    ) -> Result<PinnedMap, Status> {
+        let mut map = PinnedMap::open(&self.map_pin_path(grant, id).await?, name)
+            .map_err(|e| Status::aborted(format!("{e}")))?;
+        if json {
+            map.load_btf()
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+        }
+        Ok(map)
+    }
 
-                            if !meta_match {
-                                continue;
-                            }
-                        }
+    // Returns the size and drop policy of the queue of records streamed to a
+    // client, from the request or else from the configuration.
+    fn record_queue_config(
+        &self,
+        queue_size: Option<u32>,
+        drop_policy: Option<i32>,
+    ) -> Result<(usize, DropPolicy), Status> {
+        let config = self.ring_buffer.unwrap_or_default();
+        let drop_policy = match drop_policy {
+            Some(p) => DropPolicy::try_from(p).map_err(|e| Status::aborted(format!("{e}")))?,
+            None => config.drop_policy,
+        };
+        let queue_size = queue_size.unwrap_or(config.queue_size);
+        if queue_size == 0 {
+            return Err(Status::aborted("queue_size must be greater than 0"));
+        }
+        if queue_size > MAX_QUEUE_SIZE {
+            return Err(Status::aborted(format!(
+                "queue_size must be at most {MAX_QUEUE_SIZE}"
+            )));
+        }
+        Ok((queue_size as usize, drop_policy))
+    }
+}
 
-                        // Populate the response with the Program Info and the Kernel Info.
-                        let reply_entry = ListResult {
-                            info: if let Program::Unsupported(_) = r {
-                                None
-                            } else {
-                                Some((&r).try_into().map_err(|e| {
-                                    Status::aborted(format!("failed to get program metadata: {e}"))
-                                })?)
-                            },
-                            kernel_info: match (&r).try_into() {
-                                Ok(i) => {
-                                    if let Program::Unsupported(_) = r {
-                                        r.delete().map_err(|e| {
-                                            Status::aborted(format!(
-                                                "failed to get program metadata: {e}"
-                                            ))
-                                        })?;
-                                    };
-                                    Ok(Some(i))
-                                }
-                                Err(e) => Err(Status::aborted(format!(
-                                    "convert Program to GRPC kernel program info: {e}"
-                                ))),
-                            }?,
-                        };
-                        reply.results.push(reply_entry)
+fn map_entry(map: &PinnedMap, key: Vec<u8>, value: Vec<u8>, json: bool) -> MapEntry {
+    MapEntry {
+        key_json: if json { map.key_json(&key) } else { None },
+        value_json: if json { map.value_json(&value) } else { None },
+        key,
+        value,
+    }
+}
+
+#[tonic::async_trait]
+impl Bpfman for BpfmanLoader {
+    type ReadStream = Pin<Box<dyn Stream<Item = Result<ReadResponse, Status>> + Send>>;
+    type WatchRingBufferStream =
+        Pin<Box<dyn Stream<Item = Result<WatchRingBufferResponse, Status>> + Send>>;
+    type WatchPerfBufferStream =
+        Pin<Box<dyn Stream<Item = Result<WatchPerfBufferResponse, Status>> + Send>>;
+
+    async fn load(
+        &self,
+        mut request: Request<LoadRequest>,
+    ) -> Result<Response<LoadResponse>, Status> {
+        let audit = self.audit_start(&mut request, "load");
+        let result: Result<Response<LoadResponse>, Status> = async {
+            let grant = self.authorize(&request, "load")?;
+            let owner = peer_owner(&request);
+            let mut request = request.into_inner();
+            let additional_names = std::mem::take(&mut request.additional_names);
+            if !additional_names.is_empty() {
+                return self
+                    .load_object(request, additional_names, owner, &grant)
+                    .await;
+            }
+
+            let program = program_from_request(request, owner.as_deref())?;
+            grant.check_program(&program)?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let load_args = LoadArgs {
+                program,
+                responder: resp_tx,
+            };
+
+            // Send the LOAD request
+            self.tx.send(Command::Load(load_args)).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(program) => Ok(Response::new(load_response(&program)?)),
+                    Err(e) => {
+                        warn!("BPFMAN load error: {:#?}", e);
+                        Err(load_error_status(e))
                     }
-                    Ok(Response::new(reply))
-                }
+                },
+
                 Err(e) => {
-                    warn!("BPFMAN list error: {}", e);
+                    warn!("RPC load error: {:#?}", e);
                     Err(Status::aborted(format!("{e}")))
                 }
-            },
-            Err(e) => {
-                warn!("RPC list error: {}", e);
-                Err(Status::aborted(format!("{e}")))
             }
         }
+        .await;
+        self.audit_finish(audit, &result);
+        result
     }
 
-    async fn pull_bytecode(
+    async fn load_upload(
         &self,
-        request: tonic::Request<PullBytecodeRequest>,
-    ) -> std::result::Result<tonic::Response<PullBytecodeResponse>, tonic::Status> {
-        let request = request.into_inner();
-        let image = match request.image {
-            Some(i) => i.into(),
-            None => return Err(Status::aborted("Empty pull_bytecode request received")),
-        };
-        let (resp_tx, resp_rx) = oneshot::channel();
-        let cmd = Command::PullBytecode(PullBytecodeArgs {
-            image,
-            responder: resp_tx,
+        request: Request<Streaming<LoadUploadRequest>>,
+    ) -> Result<Response<LoadResponse>, Status> {
+        self.authorize(&request, "load")?;
+        // The load keeps the connection info, and so the owner, of the upload.
+        let (metadata, extensions, mut stream) = request.into_parts();
+        let mut load = None;
+        let mut data = Vec::new();
+        while let Some(message) = stream.message().await? {
+            if let Some(l) = message.load {
+                if load.replace(l).is_some() {
+                    return Err(Status::aborted("load request was sent more than once"));
+                }
+            }
+            if data.len() + message.chunk.len() > MAX_UPLOAD_SIZE {
+                return Err(Status::aborted(format!(
+                    "uploaded bytecode is larger than {MAX_UPLOAD_SIZE} bytes"
+                )));
+            }
+            data.extend(message.chunk);
+        }
+
+        let mut load = load.ok_or(Status::aborted("missing load request"))?;
+        load.bytecode = Some(BytecodeLocation {
+            location: Some(Location::Data(data)),
         });
+        self.load(Request::from_parts(metadata, extensions, load))
+            .await
+    }
 
-        self.tx.send(cmd).await.unwrap();
+    async fn load_batch(
+        &self,
+        mut request: Request<LoadBatchRequest>,
+    ) -> Result<Response<LoadBatchResponse>, Status> {
+        let audit = self.audit_start(&mut request, "load_batch");
+        let result: Result<Response<LoadBatchResponse>, Status> = async {
+            let grant = self.authorize(&request, "load_batch")?;
+            let owner = peer_owner(&request);
+            let request = request.into_inner();
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            if request
+                .requests
+                .iter()
+                .any(|r| !r.additional_names.is_empty())
+            {
+                return Err(Status::aborted(
+                    "additional_names isn't supported in a batch",
+                ));
+            }
+
+            let programs = request
+                .requests
+                .into_iter()
+                .map(|r| program_from_request(r, owner.as_deref()))
+                .collect::<Result<Vec<_>, _>>()?;
+            for program in &programs {
+                grant.check_program(program)?;
+            }
+
+            let load_batch_args = LoadBatchArgs {
+                programs,
+                responder: resp_tx,
+            };
+
+            // Send the LOAD_BATCH request
+            self.tx
+                .send(Command::LoadBatch(load_batch_args))
+                .await
+                .unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(programs) => Ok(Response::new(LoadBatchResponse {
+                        programs: programs
+                            .iter()
+                            .map(load_response)
+                            .collect::<Result<_, _>>()?,
+                    })),
+                    Err(e) => {
+                        warn!("BPFMAN load_batch error: {:#?}", e);
+                        Err(load_error_status(e))
+                    }
+                },
 
-        // Await the response
-        match resp_rx.await {
-            Ok(res) => match res {
-                Ok(_) => {
-                    let reply = PullBytecodeResponse {};
-                    Ok(Response::new(reply))
-                }
                 Err(e) => {
-                    warn!("BPFMAN pull_bytecode error: {:#?}", e);
+                    warn!("RPC load_batch error: {:#?}", e);
                     Err(Status::aborted(format!("{e}")))
                 }
-            },
-
-            Err(e) => {
-                warn!("RPC pull_bytecode error: {:#?}", e);
-                Err(Status::aborted(format!("{e}")))
             }
         }
+        .await;
+        self.audit_finish(audit, &result);
+        result
     }
-}
 
-#[cfg(test)]
-mod test {
-    use std::{collections::HashMap, time::SystemTime};
+    async fn unload(
+        &self,
+        mut request: Request<UnloadRequest>,
+    ) -> Result<Response<UnloadResponse>, Status> {
+        let audit = self.audit_start(&mut request, "unload");
+        let result: Result<Response<UnloadResponse>, Status> = async {
+            let grant = self.authorize(&request, "unload")?;
+            let reply = UnloadResponse {};
+            let request = request.into_inner();
+            let id = request.id;
+            self.check_target(&grant, id).await?;
 
-    use bpfman_api::v1::{
-        bytecode_location::Location, AttachInfo, BytecodeLocation, LoadRequest, XdpAttachInfo,
-    };
-    use tokio::sync::mpsc::Receiver;
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::Unload(UnloadArgs {
+                id,
+                responder: resp_tx,
+            });
 
-    use super::*;
+            // Send the GET request
+            self.tx.send(cmd).await.unwrap();
 
-    #[tokio::test]
-    async fn test_load_with_valid_id() {
-        let (tx, rx) = mpsc::channel(32);
-        let loader = BpfmanLoader::new(tx.clone());
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN unload error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC unload error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn attach(
+        &self,
+        mut request: Request<AttachRequest>,
+    ) -> Result<Response<AttachResponse>, Status> {
+        let audit = self.audit_start(&mut request, "attach");
+        let result: Result<Response<AttachResponse>, Status> = async {
+            let grant = self.authorize(&request, "attach")?;
+            let reply = AttachResponse {};
+            let request = request.into_inner();
+            let id = request.id;
+            self.check_target(&grant, id).await?;
+
+            // Only XDP and TC programs can be attached to further interfaces.
+            let attachment = match request.attach.and_then(|a| a.info) {
+                None => None,
+                Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    priority,
+                    iface,
+                    position: _,
+                    proceed_on,
+                    track_iface_glob: _,
+                    wait_for_iface: _,
+                    netns_path,
+                    slot_stats: _,
+                    mode: _,
+                })) => {
+                    let mut program = XdpProgram::new(
+                        ProgramData::new_pre_attach(),
+                        priority,
+                        iface,
+                        XdpProceedOn::from_int32s(proceed_on)
+                            .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
+                    )
+                    .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?;
+                    if let Some(netns_path) = netns_path {
+                        program.set_netns(Path::new(&netns_path)).map_err(|e| {
+                            Status::aborted(format!("failed to create xdpprogram: {e}"))
+                        })?;
+                    }
+                    Some(Program::Xdp(program))
+                }
+                Some(Info::TcAttachInfo(TcAttachInfo {
+                    priority,
+                    iface,
+                    position: _,
+                    direction,
+                    proceed_on,
+                    tcx,
+                    track_iface_glob: _,
+                    wait_for_iface: _,
+                    netns_path,
+                    slot_stats: _,
+                })) => {
+                    if direction == "both" {
+                        return Err(Status::aborted(
+                            "direction both is only supported when loading a program",
+                        ));
+                    }
+                    let direction = direction
+                        .try_into()
+                        .map_err(|_| Status::aborted("direction is not a string"))?;
+                    let mut program = TcProgram::new(
+                        ProgramData::new_pre_attach(),
+                        priority,
+                        iface,
+                        TcProceedOn::from_int32s(proceed_on)
+                            .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
+                        direction,
+                        tcx,
+                    )
+                    .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+                    if let Some(netns_path) = netns_path {
+                        program.set_netns(Path::new(&netns_path)).map_err(|e| {
+                            Status::aborted(format!("failed to create tcprogram: {e}"))
+                        })?;
+                    }
+                    Some(Program::Tc(program))
+                }
+                Some(_) => {
+                    return Err(Status::aborted(
+                        "only xdp and tc programs can be attached to further interfaces",
+                    ))
+                }
+            };
+            if let Some(iface) = attachment.as_ref().and_then(|a| a.if_name().ok()) {
+                grant.check_iface(&iface)?;
+            }
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::Attach(AttachArgs {
+                id,
+                attachment,
+                responder: resp_tx,
+            });
+
+            // Send the ATTACH request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN attach error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC attach error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn detach(
+        &self,
+        mut request: Request<DetachRequest>,
+    ) -> Result<Response<DetachResponse>, Status> {
+        let audit = self.audit_start(&mut request, "detach");
+        let result: Result<Response<DetachResponse>, Status> = async {
+            let grant = self.authorize(&request, "detach")?;
+            let reply = DetachResponse {};
+            let request = request.into_inner();
+            let id = request.id;
+            self.check_target(&grant, id).await?;
+            if let Some(iface) = &request.iface {
+                grant.check_iface(iface)?;
+            }
+            let direction: Option<Direction> = request
+                .direction
+                .map(|d| d.try_into())
+                .transpose()
+                .map_err(|_| Status::aborted("direction is not a string"))?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::Detach(DetachArgs {
+                id,
+                iface: request.iface,
+                direction,
+                responder: resp_tx,
+            });
+
+            // Send the DETACH request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN detach error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC detach error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn set_priority(
+        &self,
+        mut request: Request<SetPriorityRequest>,
+    ) -> Result<Response<SetPriorityResponse>, Status> {
+        let audit = self.audit_start(&mut request, "set_priority");
+        let result: Result<Response<SetPriorityResponse>, Status> = async {
+            let grant = self.authorize(&request, "set_priority")?;
+            let reply = SetPriorityResponse {};
+            let request = request.into_inner();
+            self.check_target(&grant, request.id).await?;
+            if let Some(iface) = &request.iface {
+                grant.check_iface(iface)?;
+            }
+            let direction: Option<Direction> = request
+                .direction
+                .map(|d| d.try_into())
+                .transpose()
+                .map_err(|_| Status::aborted("direction is not a string"))?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::SetPriority(SetPriorityArgs {
+                id: request.id,
+                priority: request.priority,
+                iface: request.iface,
+                direction,
+                responder: resp_tx,
+            });
+
+            // Send the SET_PRIORITY request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN set_priority error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC set_priority error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn publish_maps(
+        &self,
+        mut request: Request<PublishMapsRequest>,
+    ) -> Result<Response<PublishMapsResponse>, Status> {
+        let audit = self.audit_start(&mut request, "publish_maps");
+        let result: Result<Response<PublishMapsResponse>, Status> = async {
+            let grant = self.authorize(&request, "publish_maps")?;
+            let reply = PublishMapsResponse {};
+            let request = request.into_inner();
+            self.check_target(&grant, request.id).await?;
+            if request.name.is_empty() {
+                return Err(Status::aborted("missing name"));
+            }
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::PublishMaps(PublishMapsArgs {
+                id: request.id,
+                name: request.name,
+                responder: resp_tx,
+            });
+
+            // Send the PUBLISH_MAPS request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN publish_maps error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC publish_maps error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn unpublish_maps(
+        &self,
+        mut request: Request<UnpublishMapsRequest>,
+    ) -> Result<Response<UnpublishMapsResponse>, Status> {
+        let audit = self.audit_start(&mut request, "unpublish_maps");
+        let result: Result<Response<UnpublishMapsResponse>, Status> = async {
+            let grant = self.authorize(&request, "unpublish_maps")?;
+            let reply = UnpublishMapsResponse {};
+            let request = request.into_inner();
+            self.check_maps_owner(&grant, PUBLISHED_MAPS_TREE, &request.name)
+                .await?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::UnpublishMaps(UnpublishMapsArgs {
+                name: request.name,
+                responder: resp_tx,
+            });
+
+            // Send the UNPUBLISH_MAPS request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN unpublish_maps error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC unpublish_maps error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn export_maps(
+        &self,
+        mut request: Request<ExportMapsRequest>,
+    ) -> Result<Response<ExportMapsResponse>, Status> {
+        let audit = self.audit_start(&mut request, "export_maps");
+        let result: Result<Response<ExportMapsResponse>, Status> = async {
+            let grant = self.authorize(&request, "export_maps")?;
+            let request = request.into_inner();
+            self.check_target(&grant, request.id).await?;
+            let path = PathBuf::from(&request.path);
+            if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+                return Err(Status::aborted(format!(
+                    "{} is not an absolute path",
+                    request.path
+                )));
+            }
+            if let Some(name) = request.maps.iter().find(|m| !valid_map_name(m)) {
+                return Err(Status::aborted(format!("{name} is not a valid map name")));
+            }
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::ExportMaps(ExportMapsArgs {
+                id: request.id,
+                maps: request.maps,
+                path,
+                mount: request.mount,
+                responder: resp_tx,
+            });
+
+            // Send the EXPORT_MAPS request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(maps) => Ok(Response::new(ExportMapsResponse { maps })),
+                    Err(e) => {
+                        warn!("BPFMAN export_maps error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC export_maps error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn unexport_maps(
+        &self,
+        mut request: Request<UnexportMapsRequest>,
+    ) -> Result<Response<UnexportMapsResponse>, Status> {
+        let audit = self.audit_start(&mut request, "unexport_maps");
+        let result: Result<Response<UnexportMapsResponse>, Status> = async {
+            let grant = self.authorize(&request, "unexport_maps")?;
+            let reply = UnexportMapsResponse {};
+            let request = request.into_inner();
+            self.check_maps_owner(&grant, MAP_EXPORTS_TREE, &format!("owner_{}", request.path))
+                .await?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::UnexportMaps(UnexportMapsArgs {
+                path: PathBuf::from(request.path),
+                responder: resp_tx,
+            });
+
+            // Send the UNEXPORT_MAPS request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(_) => Ok(Response::new(reply)),
+                    Err(e) => {
+                        warn!("BPFMAN unexport_maps error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC unexport_maps error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn replace(
+        &self,
+        mut request: Request<ReplaceRequest>,
+    ) -> Result<Response<ReplaceResponse>, Status> {
+        let audit = self.audit_start(&mut request, "replace");
+        let result: Result<Response<ReplaceResponse>, Status> = async {
+            let grant = self.authorize(&request, "replace")?;
+            let request = request.into_inner();
+            self.check_target(&grant, request.id).await?;
+
+            let (bytecode_source, uploaded) = request_location(request.bytecode)?;
+            grant.check_location(&bytecode_source)?;
+
+            let mut data = ProgramData::new_pre_load(
+                bytecode_source,
+                request.name.unwrap_or_default(),
+                HashMap::new(),
+                request.global_data,
+                None,
+            )
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+            data.set_uploaded_bytes(uploaded);
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::Replace(ReplaceArgs {
+                id: request.id,
+                data,
+                responder: resp_tx,
+            });
+
+            // Send the REPLACE request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(program) => {
+                        let LoadResponse {
+                            info, kernel_info, ..
+                        } = load_response(&program)?;
+                        Ok(Response::new(ReplaceResponse { info, kernel_info }))
+                    }
+                    Err(e) => {
+                        warn!("BPFMAN replace error: {:#?}", e);
+                        Err(load_error_status(e))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC replace error: {:#?}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn rollback(
+        &self,
+        mut request: Request<RollbackRequest>,
+    ) -> Result<Response<RollbackResponse>, Status> {
+        let audit = self.audit_start(&mut request, "rollback");
+        let result: Result<Response<RollbackResponse>, Status> = async {
+            let grant = self.authorize(&request, "rollback")?;
+            let request = request.into_inner();
+            self.check_target(&grant, request.id).await?;
+
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::Rollback(RollbackArgs {
+                id: request.id,
+                responder: resp_tx,
+            });
+
+            // Send the ROLLBACK request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(program) => {
+                        let LoadResponse {
+                            info, kernel_info, ..
+                        } = load_response(&program)?;
+                        Ok(Response::new(RollbackResponse { info, kernel_info }))
+                    }
+                    Err(e) => {
+                        warn!("BPFMAN rollback error: {:#?}", e);
+                        Err(load_error_status(e))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC rollback error: {:#?}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        self.authorize(&request, "get")?;
+        let request = request.into_inner();
+        let id = request.id;
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let cmd = Command::Get(GetArgs {
+            id,
+            uuid: request.uuid,
+            responder: resp_tx,
+        });
+
+        // Send the GET request
+        self.tx.send(cmd).await.unwrap();
+
+        // Await the response
+        match resp_rx.await {
+            Ok(res) => match res {
+                Ok(program) => {
+                    let reply_entry = GetResponse {
+                        info: if let Program::Unsupported(_) = program {
+                            None
+                        } else {
+                            Some((&program).try_into().map_err(|e| {
+                                Status::aborted(format!("failed to get program metadata: {e}"))
+                            })?)
+                        },
+                        kernel_info: match (&program).try_into() {
+                            Ok(i) => {
+                                if let Program::Unsupported(_) = program {
+                                    program.delete().map_err(|e| {
+                                        Status::aborted(format!(
+                                            "failed to get program metadata: {e}"
+                                        ))
+                                    })?;
+                                };
+                                Ok(Some(i))
+                            }
+                            Err(e) => Err(Status::aborted(format!(
+                                "convert Program to GRPC kernel program info: {e}"
+                            ))),
+                        }?,
+                    };
+                    Ok(Response::new(reply_entry))
+                }
+                Err(e) => {
+                    warn!("BPFMAN get error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            },
+            Err(e) => {
+                warn!("RPC get error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
+        self.authorize(&request, "list")?;
+        let request = request.into_inner();
+        if request.bpfman_programs_only.unwrap_or_default()
+            && request.foreign_programs_only.unwrap_or_default()
+        {
+            return Err(Status::invalid_argument(
+                "bpfman_programs_only and foreign_programs_only can't both be set",
+            ));
+        }
+        let mut reply = ListResponse {
+            results: vec![],
+            next_page_token: String::new(),
+        };
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let cmd = Command::List(ListArgs {
+            filter: ListFilter {
+                program_type: request.program_type,
+                iface: request.iface,
+                map_owner_id: request.map_owner_id,
+                metadata: request.match_metadata,
+                bpfman_programs_only: request.bpfman_programs_only.unwrap_or_default(),
+                foreign_programs_only: request.foreign_programs_only.unwrap_or_default(),
+            },
+            page_size: request.page_size as usize,
+            page_token: (!request.page_token.is_empty()).then_some(request.page_token),
+            responder: resp_tx,
+        });
+
+        // Send the LIST request
+        self.tx.send(cmd).await.unwrap();
+
+        // Await the response
+        match resp_rx.await {
+            Ok(res) => match res {
+                Ok(page) => {
+                    reply.next_page_token = page.next_page_token.unwrap_or_default();
+                    for r in page.programs {
+                        let bpfman_managed = !matches!(r, Program::Unsupported(_));
+                        // Populate the response with the Program Info and the Kernel Info.
+                        let reply_entry = ListResult {
+                            bpfman_managed,
+                            info: if let Program::Unsupported(_) = r {
+                                None
+                            } else {
+                                Some((&r).try_into().map_err(|e| {
+                                    Status::aborted(format!("failed to get program metadata: {e}"))
+                                })?)
+                            },
+                            kernel_info: match (&r).try_into() {
+                                Ok(i) => {
+                                    if let Program::Unsupported(_) = r {
+                                        r.delete().map_err(|e| {
+                                            Status::aborted(format!(
+                                                "failed to get program metadata: {e}"
+                                            ))
+                                        })?;
+                                    };
+                                    Ok(Some(i))
+                                }
+                                Err(e) => Err(Status::aborted(format!(
+                                    "convert Program to GRPC kernel program info: {e}"
+                                ))),
+                            }?,
+                        };
+                        reply.results.push(reply_entry)
+                    }
+                    Ok(Response::new(reply))
+                }
+                Err(e) => {
+                    warn!("BPFMAN list error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            },
+            Err(e) => {
+                warn!("RPC list error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+
+    async fn pull_bytecode(
+        &self,
+        mut request: tonic::Request<PullBytecodeRequest>,
+    ) -> std::result::Result<tonic::Response<PullBytecodeResponse>, tonic::Status> {
+        let audit = self.audit_start(&mut request, "pull_bytecode");
+        let result: Result<Response<PullBytecodeResponse>, Status> = async {
+            self.authorize(&request, "pull_bytecode")?;
+            let request = request.into_inner();
+            let image = match request.image {
+                Some(i) => i.into(),
+                None => return Err(Status::aborted("Empty pull_bytecode request received")),
+            };
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::PullBytecode(PullBytecodeArgs {
+                image,
+                responder: resp_tx,
+            });
+
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(image) => {
+                        let reply = PullBytecodeResponse {
+                            image: Some(image.into()),
+                        };
+                        Ok(Response::new(reply))
+                    }
+                    Err(e) => {
+                        warn!("BPFMAN pull_bytecode error: {:#?}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+
+                Err(e) => {
+                    warn!("RPC pull_bytecode error: {:#?}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn prune_images(
+        &self,
+        mut request: Request<PruneImagesRequest>,
+    ) -> Result<Response<PruneImagesResponse>, Status> {
+        let audit = self.audit_start(&mut request, "prune_images");
+        let result: Result<Response<PruneImagesResponse>, Status> = async {
+            self.authorize(&request, "prune_images")?;
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let cmd = Command::PruneImages(PruneImagesArgs { responder: resp_tx });
+
+            // Send the PRUNE_IMAGES request
+            self.tx.send(cmd).await.unwrap();
+
+            // Await the response
+            match resp_rx.await {
+                Ok(res) => match res {
+                    Ok(images) => Ok(Response::new(PruneImagesResponse { images })),
+                    Err(e) => {
+                        warn!("BPFMAN prune_images error: {}", e);
+                        Err(Status::aborted(format!("{e}")))
+                    }
+                },
+                Err(e) => {
+                    warn!("RPC prune_images error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            }
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn list_images(
+        &self,
+        request: Request<ListImagesRequest>,
+    ) -> Result<Response<ListImagesResponse>, Status> {
+        self.authorize(&request, "list_images")?;
+        let request = request.into_inner();
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let cmd = Command::ListImages(ListImagesArgs { responder: resp_tx });
+
+        // Send the LIST_IMAGES request
+        self.tx.send(cmd).await.unwrap();
+
+        // Await the response
+        match resp_rx.await {
+            Ok(res) => match res {
+                Ok(images) => Ok(Response::new(ListImagesResponse {
+                    images: images
+                        .into_iter()
+                        .filter(|i| request.url.as_ref().map_or(true, |url| *url == i.url))
+                        .map(Into::into)
+                        .collect(),
+                })),
+                Err(e) => {
+                    warn!("BPFMAN list_images error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            },
+            Err(e) => {
+                warn!("RPC list_images error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+
+    async fn version(
+        &self,
+        request: Request<VersionRequest>,
+    ) -> Result<Response<VersionResponse>, Status> {
+        self.authorize(&request, "version")?;
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let cmd = Command::Version(VersionArgs { responder: resp_tx });
+
+        // Send the VERSION request
+        self.tx.send(cmd).await.unwrap();
+
+        // Await the response
+        match resp_rx.await {
+            Ok(capabilities) => Ok(Response::new(VersionResponse {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                build: format!(
+                    "{} {}",
+                    std::env::consts::ARCH,
+                    if cfg!(debug_assertions) {
+                        "debug"
+                    } else {
+                        "release"
+                    }
+                ),
+                kernel_release: capabilities.kernel_release,
+                program_types: SUPPORTED_PROGRAM_TYPES.iter().map(|t| *t as u32).collect(),
+                kernel_features: capabilities.kernel_features,
+                xdp_dispatcher_image: capabilities.xdp_dispatcher_image,
+                tc_dispatcher_image: capabilities.tc_dispatcher_image,
+            })),
+            Err(e) => {
+                warn!("RPC version error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+
+    async fn read(
+        &self,
+        request: Request<ReadRequest>,
+    ) -> Result<Response<Self::ReadStream>, Status> {
+        let grant = self.authorize(&request, "read")?;
+        let request = request.into_inner();
+        let id = request.id;
+        self.check_target(&grant, id).await?;
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let cmd = Command::Read(ReadArgs {
+            id,
+            responder: resp_tx,
+        });
+
+        // Send the READ request
+        self.tx.send(cmd).await.unwrap();
+
+        // Await the response
+        match resp_rx.await {
+            Ok(res) => match res {
+                Ok(data) => {
+                    let chunks: Vec<Result<ReadResponse, Status>> = data
+                        .chunks(READ_CHUNK_SIZE)
+                        .map(|c| Ok(ReadResponse { data: c.to_vec() }))
+                        .collect();
+                    Ok(Response::new(
+                        Box::pin(tokio_stream::iter(chunks)) as Self::ReadStream
+                    ))
+                }
+                Err(e) => {
+                    warn!("BPFMAN read error: {}", e);
+                    Err(Status::aborted(format!("{e}")))
+                }
+            },
+            Err(e) => {
+                warn!("RPC read error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+
+    async fn list_maps(
+        &self,
+        request: Request<ListMapsRequest>,
+    ) -> Result<Response<ListMapsResponse>, Status> {
+        let grant = self.authorize(&request, "list_maps")?;
+        let request = request.into_inner();
+        let maps = pinned_maps(&self.map_pin_path(&grant, request.id).await?)
+            .map_err(|e| Status::aborted(format!("{e}")))?
+            .iter()
+            .map(|map| MapInfo {
+                name: map.name().to_string(),
+                id: map.id(),
+                map_type: map.map_type(),
+                key_size: map.key_size(),
+                value_size: map.value_size(),
+                max_entries: map.max_entries(),
+            })
+            .collect();
+        Ok(Response::new(ListMapsResponse { maps }))
+    }
+
+    async fn dump_map(
+        &self,
+        request: Request<DumpMapRequest>,
+    ) -> Result<Response<DumpMapResponse>, Status> {
+        let grant = self.authorize(&request, "dump_map")?;
+        let request = request.into_inner();
+        let map = self
+            .open_map(&grant, request.id, &request.map, request.json)
+            .await?;
+        let entries = map
+            .dump()
+            .map_err(|e| Status::aborted(format!("{e}")))?
+            .into_iter()
+            .map(|(key, value)| map_entry(&map, key, value, request.json))
+            .collect();
+        Ok(Response::new(DumpMapResponse { entries }))
+    }
+
+    async fn lookup_map_entry(
+        &self,
+        request: Request<LookupMapEntryRequest>,
+    ) -> Result<Response<LookupMapEntryResponse>, Status> {
+        let grant = self.authorize(&request, "lookup_map_entry")?;
+        let request = request.into_inner();
+        let map = self
+            .open_map(&grant, request.id, &request.map, request.json)
+            .await?;
+        let entry = map
+            .lookup(&request.key)
+            .map_err(|e| Status::aborted(format!("{e}")))?
+            .map(|value| map_entry(&map, request.key, value, request.json));
+        Ok(Response::new(LookupMapEntryResponse { entry }))
+    }
+
+    async fn update_map_entry(
+        &self,
+        mut request: Request<UpdateMapEntryRequest>,
+    ) -> Result<Response<UpdateMapEntryResponse>, Status> {
+        let audit = self.audit_start(&mut request, "update_map_entry");
+        let result: Result<Response<UpdateMapEntryResponse>, Status> = async {
+            let grant = self.authorize(&request, "update_map_entry")?;
+            let request = request.into_inner();
+            let map = self
+                .open_map(&grant, request.id, &request.map, false)
+                .await?;
+            map.update(&request.key, &request.value, request.flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Ok(Response::new(UpdateMapEntryResponse {}))
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn delete_map_entry(
+        &self,
+        mut request: Request<DeleteMapEntryRequest>,
+    ) -> Result<Response<DeleteMapEntryResponse>, Status> {
+        let audit = self.audit_start(&mut request, "delete_map_entry");
+        let result: Result<Response<DeleteMapEntryResponse>, Status> = async {
+            let grant = self.authorize(&request, "delete_map_entry")?;
+            let request = request.into_inner();
+            let map = self
+                .open_map(&grant, request.id, &request.map, false)
+                .await?;
+            if !map
+                .delete(&request.key)
+                .map_err(|e| Status::aborted(format!("{e}")))?
+            {
+                return Err(Status::not_found(format!(
+                    "key not found in map {}",
+                    request.map
+                )));
+            }
+            Ok(Response::new(DeleteMapEntryResponse {}))
+        }
+        .await;
+        self.audit_finish(audit, &result);
+        result
+    }
+
+    async fn watch_ring_buffer(
+        &self,
+        request: Request<WatchRingBufferRequest>,
+    ) -> Result<Response<Self::WatchRingBufferStream>, Status> {
+        let grant = self.authorize(&request, "watch_ring_buffer")?;
+        let request = request.into_inner();
+        let (queue_size, drop_policy) =
+            self.record_queue_config(request.queue_size, request.drop_policy)?;
+
+        let path = map_path(&self.map_pin_path(&grant, request.id).await?, &request.map)
+            .map_err(|e| Status::aborted(format!("{e}")))?;
+        let stream = watch_ring_buffer(&path, queue_size, drop_policy)
+            .map_err(|e| Status::aborted(format!("{e}")))?;
+        Ok(Response::new(
+            Box::pin(stream) as Self::WatchRingBufferStream
+        ))
+    }
+
+    async fn watch_perf_buffer(
+        &self,
+        request: Request<WatchPerfBufferRequest>,
+    ) -> Result<Response<Self::WatchPerfBufferStream>, Status> {
+        let grant = self.authorize(&request, "watch_perf_buffer")?;
+        let request = request.into_inner();
+        let (queue_size, drop_policy) =
+            self.record_queue_config(request.queue_size, request.drop_policy)?;
+        if let Some(page_count) = request.page_count {
+            if !page_count.is_power_of_two() {
+                return Err(Status::aborted("page_count must be a power of two"));
+            }
+        }
+
+        let path = map_path(&self.map_pin_path(&grant, request.id).await?, &request.map)
+            .map_err(|e| Status::aborted(format!("{e}")))?;
+        let stream = watch_perf_buffer(
+            &path,
+            request.page_count.map(|p| p as usize),
+            queue_size,
+            drop_policy,
+        )
+        .map_err(|e| Status::aborted(format!("{e}")))?;
+        Ok(Response::new(
+            Box::pin(stream) as Self::WatchPerfBufferStream
+        ))
+    }
+
+    async fn get_events(
+        &self,
+        request: Request<GetEventsRequest>,
+    ) -> Result<Response<GetEventsResponse>, Status> {
+        self.authorize(&request, "get_events")?;
+        let request = request.into_inner();
+        let filter = EventFilter {
+            since: request.since,
+            until: request.until,
+            uuid: request.uuid,
+            limit: request.limit as usize,
+        };
+        let events = events(&filter)
+            .map_err(|e| Status::aborted(format!("{e}")))?
+            .into_iter()
+            .map(|event| Event {
+                timestamp: event.timestamp,
+                kind: event.kind.to_string(),
+                program_id: event.program_id,
+                uuid: event.uuid,
+                name: event.name,
+                details: event.details,
+                error: event.error,
+            })
+            .collect();
+        Ok(Response::new(GetEventsResponse { events }))
+    }
+
+    async fn list_kernel_links(
+        &self,
+        request: Request<ListKernelLinksRequest>,
+    ) -> Result<Response<ListKernelLinksResponse>, Status> {
+        self.authorize(&request, "list_kernel_links")?;
+        let request = request.into_inner();
+        let managed = self.managed_program_ids().await?;
+        let links = kernel_links()
+            .into_iter()
+            .filter(|link| request.program_id.map_or(true, |id| link.program_id == id))
+            .map(|link| KernelLinkInfo {
+                id: link.id,
+                link_type: link.link_type,
+                program_id: link.program_id,
+                bpfman_managed: managed.contains(&link.program_id),
+            })
+            .collect();
+        Ok(Response::new(ListKernelLinksResponse { links }))
+    }
+
+    async fn list_kernel_maps(
+        &self,
+        request: Request<ListKernelMapsRequest>,
+    ) -> Result<Response<ListKernelMapsResponse>, Status> {
+        self.authorize(&request, "list_kernel_maps")?;
+        let request = request.into_inner();
+        let managed = self.managed_program_ids().await?;
+        let maps = kernel_maps()
+            .into_iter()
+            .filter(|map| {
+                request
+                    .program_id
+                    .map_or(true, |id| map.program_ids.contains(&id))
+                    && (!request.foreign_access_only || map.has_foreign_access(&managed))
+            })
+            .map(|map| KernelMapInfo {
+                bpfman_program_ids: map
+                    .program_ids
+                    .iter()
+                    .copied()
+                    .filter(|id| managed.contains(id))
+                    .collect(),
+                id: map.id,
+                name: map.name,
+                map_type: map.map_type,
+                key_size: map.key_size,
+                value_size: map.value_size,
+                max_entries: map.max_entries,
+                program_ids: map.program_ids,
+            })
+            .collect();
+        Ok(Response::new(ListKernelMapsResponse { maps }))
+    }
+
+    async fn probe_features(
+        &self,
+        request: Request<ProbeFeaturesRequest>,
+    ) -> Result<Response<ProbeFeaturesResponse>, Status> {
+        self.authorize(&request, "probe_features")?;
+        let request = request.into_inner();
+        for program_type in &request.helper_program_types {
+            if *program_type == ProgramType::Unspec as u32
+                || ProgramType::try_from(*program_type).is_err()
+            {
+                return Err(Status::invalid_argument(format!(
+                    "unknown program type {program_type}"
+                )));
+            }
+        }
+
+        // Probing loads a program per program type, map type and helper.
+        let result = tokio::task::spawn_blocking(move || {
+            let features = probe_features();
+            let helpers = request
+                .helper_program_types
+                .iter()
+                .map(|program_type| ProgramHelpers {
+                    program_type: *program_type,
+                    helper_ids: probe_helpers(*program_type),
+                })
+                .collect();
+            ProbeFeaturesResponse {
+                kernel_release: features.kernel_release,
+                program_types: features.program_types,
+                map_types: features.map_types.iter().map(|t| t.to_string()).collect(),
+                features: features.features.iter().map(|f| f.to_string()).collect(),
+                helpers,
+            }
+        })
+        .await;
+        match result {
+            Ok(response) => Ok(Response::new(response)),
+            Err(e) => {
+                warn!("RPC probe_features error: {}", e);
+                Err(Status::aborted(format!("{e}")))
+            }
+        }
+    }
+}
+
+// Builds the program to load from a load request.
+// Returns the location of the bytecode of a request, along with the bytecode
+// if it was uploaded with the request, which is stored under its digest.
+fn request_location(
+    bytecode: Option<BytecodeLocation>,
+) -> Result<(crate::command::Location, Vec<u8>), Status> {
+    match bytecode
+        .ok_or(Status::aborted("missing bytecode info"))?
+        .location
+        .ok_or(Status::aborted("missing location"))?
+    {
+        Location::Image(i) => Ok((crate::command::Location::Image(i.into()), vec![])),
+        Location::File(p) => Ok((crate::command::Location::File(p), vec![])),
+        Location::Data(d) if d.is_empty() => Err(Status::aborted("uploaded bytecode is empty")),
+        Location::Data(d) => Ok((crate::command::Location::File(upload_path(&d)), d)),
+        Location::Url(u) => {
+            check_url(&u.url, &u.sha256).map_err(|e| Status::aborted(e.to_string()))?;
+            Ok((crate::command::Location::Url(u.into()), vec![]))
+        }
+    }
+}
+
+// Returns the user of the process at the other end of the Unix socket the
+// request came in on, as reported by SO_PEERCRED, which clients can't forge.
+// Requests over other transports, like the REST gateway, have none.
+fn peer_owner<T>(request: &Request<T>) -> Option<String> {
+    let cred = request.extensions().get::<UdsConnectInfo>()?.peer_cred?;
+    debug!(
+        "Request from uid {} gid {} pid {:?}",
+        cred.uid(),
+        cred.gid(),
+        cred.pid()
+    );
+    Some(username(cred.uid()))
+}
+
+// Users without an entry in the user database are named by their uid.
+pub(crate) fn username(uid: u32) -> String {
+    User::from_uid(Uid::from_raw(uid))
+        .ok()
+        .flatten()
+        .map_or_else(|| uid.to_string(), |user| user.name)
+}
+
+fn program_from_request(request: LoadRequest, owner: Option<&str>) -> Result<Program, Status> {
+    let (bytecode_source, uploaded) = request_location(request.bytecode)?;
+
+    let mut data = ProgramData::new_pre_load(
+        bytecode_source,
+        request.name,
+        request.metadata,
+        request.global_data,
+        request.map_owner_id,
+    )
+    .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    data.set_uploaded_bytes(uploaded);
+
+    if request.detached {
+        data.set_detached(true)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if request.verifier_log_level.is_some_and(|l| l & !7 != 0) {
+        return Err(Status::aborted(
+            "verifier log level may only combine the bits 1, 2 and 4",
+        ));
+    }
+    data.set_verifier_log(request.verifier_log_level, request.verifier_log_size)
+        .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+
+    if let Some(uuid) = &request.uuid {
+        data.set_uuid(uuid)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if let Some(owner) = owner {
+        data.set_owner(owner)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if request.map_max_entries.values().any(|v| *v == 0) {
+        return Err(Status::aborted("map max_entries must be greater than 0"));
+    }
+    data.set_map_max_entries(request.map_max_entries)
+        .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+
+    if let Some(policy) = request.map_pinning {
+        if let Some(name) = policy.pin_names.values().find(|n| !valid_map_name(n)) {
+            return Err(Status::aborted(format!("{name} is not a valid pin name")));
+        }
+        data.set_map_pin_policy(&MapPinPolicy {
+            include: policy.include,
+            exclude: policy.exclude,
+            names: policy.pin_names,
+        })
+        .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if (!request.map_init.is_empty() || !request.inner_maps.is_empty())
+        && (request.map_owner_id.is_some() || request.map_from.is_some())
+    {
+        return Err(Status::aborted(
+            "maps can only be initialized by programs owning them",
+        ));
+    }
+
+    if let Some(permissions) = request.map_permissions {
+        if request.map_owner_id.is_some() || request.map_from.is_some() {
+            return Err(Status::aborted(
+                "map permissions can only be set by programs owning their maps",
+            ));
+        }
+        if permissions.mode.is_some_and(|m| m > 0o777) {
+            return Err(Status::aborted("map mode must be at most 0777"));
+        }
+        data.set_map_permissions(&MapPermissions {
+            uid: permissions.user.as_deref().map(resolve_user).transpose()?,
+            gid: permissions
+                .group
+                .as_deref()
+                .map(resolve_group)
+                .transpose()?,
+            mode: permissions.mode,
+        })
+        .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if !request.inner_maps.is_empty() {
+        for inner in &request.inner_maps {
+            if !valid_map_name(&inner.outer) {
+                return Err(Status::aborted(format!(
+                    "{} is not a valid map name",
+                    inner.outer
+                )));
+            }
+            // The outer map couldn't have been declared with an inner map
+            // holding maps itself.
+            if MAP_IN_MAP_TYPES.contains(&inner.map_type) {
+                return Err(Status::aborted(format!(
+                    "inner maps of map {} can't hold maps",
+                    inner.outer
+                )));
+            }
+        }
+        let inner: Vec<InnerMaps> = request
+            .inner_maps
+            .into_iter()
+            .map(|i| InnerMaps {
+                outer: i.outer,
+                map_type: i.map_type,
+                key_size: i.key_size,
+                value_size: i.value_size,
+                max_entries: i.max_entries,
+                keys: i.keys,
+            })
+            .collect();
+        data.set_inner_maps(&inner)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if !request.map_init.is_empty() {
+        if let Some(init) = request.map_init.iter().find(|i| !valid_map_name(&i.map)) {
+            return Err(Status::aborted(format!(
+                "{} is not a valid map name",
+                init.map
+            )));
+        }
+        let init: Vec<MapInit> = request
+            .map_init
+            .into_iter()
+            .map(|i| MapInit {
+                map: i.map,
+                entries: i.entries.into_iter().map(|e| (e.key, e.value)).collect(),
+                freeze: i.freeze,
+            })
+            .collect();
+        data.set_map_init(&init)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    if let Some(name) = request.map_from {
+        if request.map_owner_id.is_some() {
+            return Err(Status::aborted(
+                "only one of map_owner_id and map_from can be set",
+            ));
+        }
+        data.set_map_from(&name)
+            .map_err(|e| Status::aborted(format!("failed to create ProgramData: {e}")))?;
+    }
+
+    let program = match request
+        .attach
+        .ok_or(Status::aborted("missing attach info"))?
+        .info
+        .ok_or(Status::aborted("missing info"))?
+    {
+        Info::XdpAttachInfo(XdpAttachInfo {
+            priority,
+            iface,
+            position: _,
+            proceed_on,
+            track_iface_glob,
+            wait_for_iface,
+            netns_path,
+            slot_stats: _,
+            mode: _,
+        }) => {
+            let mut program = XdpProgram::new(
+                data,
+                priority,
+                iface,
+                XdpProceedOn::from_int32s(proceed_on)
+                    .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
+            )
+            .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?;
+            if track_iface_glob {
+                program
+                    .set_track_iface_glob(true)
+                    .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?;
+            }
+            if wait_for_iface {
+                program
+                    .set_wait_for_iface(true)
+                    .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?;
+            }
+            if let Some(netns_path) = netns_path {
+                program
+                    .set_netns(Path::new(&netns_path))
+                    .map_err(|e| Status::aborted(format!("failed to create xdpprogram: {e}")))?;
+            }
+            Program::Xdp(program)
+        }
+        Info::TcAttachInfo(TcAttachInfo {
+            priority,
+            iface,
+            position: _,
+            direction,
+            proceed_on,
+            tcx,
+            track_iface_glob,
+            wait_for_iface,
+            netns_path,
+            slot_stats: _,
+        }) => {
+            let both_directions = direction == "both";
+            let direction = if both_directions {
+                Direction::Ingress
+            } else {
+                direction
+                    .try_into()
+                    .map_err(|_| Status::aborted("direction is not a string"))?
+            };
+            let mut program = TcProgram::new(
+                data,
+                priority,
+                iface,
+                TcProceedOn::from_int32s(proceed_on)
+                    .map_err(|_| Status::aborted("failed to parse proceed_on"))?,
+                direction,
+                tcx,
+            )
+            .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+            if track_iface_glob {
+                program
+                    .set_track_iface_glob(true)
+                    .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+            }
+            if wait_for_iface {
+                program
+                    .set_wait_for_iface(true)
+                    .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+            }
+            if both_directions {
+                program
+                    .set_both_directions(true)
+                    .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+            }
+            if let Some(netns_path) = netns_path {
+                program
+                    .set_netns(Path::new(&netns_path))
+                    .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?;
+            }
+            Program::Tc(program)
+        }
+        Info::TracepointAttachInfo(TracepointAttachInfo { tracepoint }) => Program::Tracepoint(
+            TracepointProgram::new(data, tracepoint)
+                .map_err(|e| Status::aborted(format!("failed to create tcprogram: {e}")))?,
+        ),
+        Info::KprobeAttachInfo(KprobeAttachInfo {
+            fn_name,
+            offset,
+            retprobe,
+            container_pid,
+        }) => Program::Kprobe(
+            KprobeProgram::new(data, fn_name, offset, retprobe, container_pid)
+                .map_err(|e| Status::aborted(format!("failed to create kprobeprogram: {e}")))?,
+        ),
+        Info::UprobeAttachInfo(UprobeAttachInfo {
+            fn_name,
+            offset,
+            target,
+            retprobe,
+            pid,
+            container_pid,
+            container_id,
+        }) => {
+            let mut program =
+                UprobeProgram::new(data, fn_name, offset, target, retprobe, pid, container_pid)
+                    .map_err(|e| Status::aborted(format!("failed to create uprobeprogram: {e}")))?;
+            if let Some(container_id) = container_id {
+                program
+                    .set_container_id(container_id)
+                    .map_err(|e| Status::aborted(format!("failed to create uprobeprogram: {e}")))?;
+            }
+            Program::Uprobe(program)
+        }
+        Info::LsmAttachInfo(LsmAttachInfo { hook }) => Program::Lsm(
+            LsmProgram::new(data, hook)
+                .map_err(|e| Status::aborted(format!("failed to create lsmprogram: {e}")))?,
+        ),
+        Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+            cgroup_path,
+            direction,
+            attach_flags,
+        }) => {
+            let direction = direction
+                .try_into()
+                .map_err(|_| Status::aborted("direction is not a string"))?;
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::CgroupSkb(
+                CgroupSkbProgram::new(data, cgroup_path, direction, attach_flags).map_err(|e| {
+                    Status::aborted(format!("failed to create cgroupskbprogram: {e}"))
+                })?,
+            )
+        }
+        Info::CgroupSockAddrAttachInfo(CgroupSockAddrAttachInfo {
+            cgroup_path,
+            attach_point,
+            attach_flags,
+        }) => {
+            let attach_point = attach_point
+                .try_into()
+                .map_err(|_| Status::aborted("attach_point is not valid"))?;
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::CgroupSockAddr(
+                CgroupSockAddrProgram::new(data, cgroup_path, attach_point, attach_flags).map_err(
+                    |e| Status::aborted(format!("failed to create cgroupsockaddrprogram: {e}")),
+                )?,
+            )
+        }
+        Info::SockOpsAttachInfo(SockOpsAttachInfo {
+            cgroup_path,
+            attach_flags,
+        }) => {
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::SockOps(
+                SockOpsProgram::new(data, cgroup_path, attach_flags).map_err(|e| {
+                    Status::aborted(format!("failed to create sockopsprogram: {e}"))
+                })?,
+            )
+        }
+        Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+            cgroup_path,
+            attach_flags,
+        }) => {
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::CgroupDevice(
+                CgroupDeviceProgram::new(data, cgroup_path, attach_flags).map_err(|e| {
+                    Status::aborted(format!("failed to create cgroupdeviceprogram: {e}"))
+                })?,
+            )
+        }
+        Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+            cgroup_path,
+            attach_flags,
+        }) => {
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::CgroupSysctl(
+                CgroupSysctlProgram::new(data, cgroup_path, attach_flags).map_err(|e| {
+                    Status::aborted(format!("failed to create cgroupsysctlprogram: {e}"))
+                })?,
+            )
+        }
+        Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+            cgroup_path,
+            attach_type,
+            attach_flags,
+        }) => {
+            let attach_type = attach_type
+                .try_into()
+                .map_err(|_| Status::aborted("attach_type is not valid"))?;
+            let attach_flags = CgroupAttachFlags::try_from(attach_flags)
+                .map_err(|e| Status::aborted(format!("{e}")))?;
+            Program::CgroupSockopt(
+                CgroupSockoptProgram::new(data, cgroup_path, attach_type, attach_flags).map_err(
+                    |e| Status::aborted(format!("failed to create cgroupsockoptprogram: {e}")),
+                )?,
+            )
+        }
+        Info::SkMsgAttachInfo(SkMsgAttachInfo {
+            map_name,
+            sock_map_pin_path,
+        }) => Program::SkMsg(
+            SkMsgProgram::new(data, map_name, sock_map_pin_path)
+                .map_err(|e| Status::aborted(format!("failed to create skmsgprogram: {e}")))?,
+        ),
+        Info::SkSkbAttachInfo(SkSkbAttachInfo {
+            map_name,
+            sock_map_pin_path,
+            attach_type,
+        }) => {
+            let attach_type = attach_type
+                .try_into()
+                .map_err(|_| Status::aborted("attach_type is not valid"))?;
+            Program::SkSkb(
+                SkSkbProgram::new(data, map_name, sock_map_pin_path, attach_type)
+                    .map_err(|e| Status::aborted(format!("failed to create skskbprogram: {e}")))?,
+            )
+        }
+        Info::FlowDissectorAttachInfo(FlowDissectorAttachInfo { netns_path }) => {
+            Program::FlowDissector(FlowDissectorProgram::new(data, netns_path).map_err(|e| {
+                Status::aborted(format!("failed to create flowdissectorprogram: {e}"))
+            })?)
+        }
+        Info::SkLookupAttachInfo(SkLookupAttachInfo { netns_path }) => Program::SkLookup(
+            SkLookupProgram::new(data, netns_path)
+                .map_err(|e| Status::aborted(format!("failed to create sklookupprogram: {e}")))?,
+        ),
+        Info::PerfEventAttachInfo(PerfEventAttachInfo {
+            event,
+            sample_period,
+            sample_frequency,
+            cpus,
+        }) => {
+            let event = event
+                .try_into()
+                .map_err(|_| Status::aborted("event is not valid"))?;
+            Program::PerfEvent(
+                PerfEventProgram::new(data, event, sample_period, sample_frequency, cpus).map_err(
+                    |e| Status::aborted(format!("failed to create perfeventprogram: {e}")),
+                )?,
+            )
+        }
+        Info::RawTracepointAttachInfo(RawTracepointAttachInfo { tracepoint, btf }) => {
+            Program::RawTracepoint(RawTracepointProgram::new(data, tracepoint, btf).map_err(
+                |e| Status::aborted(format!("failed to create rawtracepointprogram: {e}")),
+            )?)
+        }
+        Info::SocketFilterAttachInfo(SocketFilterAttachInfo {}) => {
+            Program::SocketFilter(SocketFilterProgram::new(data).map_err(|e| {
+                Status::aborted(format!("failed to create socketfilterprogram: {e}"))
+            })?)
+        }
+        Info::NetkitAttachInfo(NetkitAttachInfo {
+            iface,
+            position,
+            priority,
+        }) => {
+            let position = position
+                .try_into()
+                .map_err(|_| Status::aborted("position is not valid"))?;
+            Program::Netkit(
+                NetkitProgram::new(data, iface, position, priority)
+                    .map_err(|e| Status::aborted(format!("failed to create netkitprogram: {e}")))?,
+            )
+        }
+        Info::UsdtAttachInfo(UsdtAttachInfo {
+            target,
+            provider,
+            name,
+            pid,
+        }) => Program::Usdt(
+            UsdtProgram::new(data, target, provider, name, pid)
+                .map_err(|e| Status::aborted(format!("failed to create usdtprogram: {e}")))?,
+        ),
+        Info::KprobeMultiAttachInfo(KprobeMultiAttachInfo { fn_names, retprobe }) => {
+            Program::KprobeMulti(KprobeMultiProgram::new(data, fn_names, retprobe).map_err(
+                |e| Status::aborted(format!("failed to create kprobemultiprogram: {e}")),
+            )?)
+        }
+        Info::UprobeMultiAttachInfo(UprobeMultiAttachInfo {
+            target,
+            fn_names,
+            retprobe,
+            pid,
+        }) => Program::UprobeMulti(
+            UprobeMultiProgram::new(data, target, fn_names, retprobe, pid).map_err(|e| {
+                Status::aborted(format!("failed to create uprobemultiprogram: {e}"))
+            })?,
+        ),
+        Info::IterAttachInfo(IterAttachInfo { iter_type }) => Program::Iter(
+            IterProgram::new(data, iter_type)
+                .map_err(|e| Status::aborted(format!("failed to create iterprogram: {e}")))?,
+        ),
+        Info::ExtensionAttachInfo(ExtensionAttachInfo {
+            target_path,
+            target_id,
+            target_fn,
+        }) => Program::Extension(
+            ExtensionProgram::new(data, target_path, target_id, target_fn)
+                .map_err(|e| Status::aborted(format!("failed to create extensionprogram: {e}")))?,
+        ),
+    };
+    Ok(program)
+}
+
+// Users and groups are given by name or ID.
+fn resolve_user(user: &str) -> Result<u32, Status> {
+    if let Ok(uid) = user.parse() {
+        return Ok(uid);
+    }
+    match User::from_name(user) {
+        Ok(Some(u)) => Ok(u.uid.as_raw()),
+        Ok(None) => Err(Status::aborted(format!("unknown user {user}"))),
+        Err(e) => Err(Status::aborted(format!(
+            "unable to look up user {user}: {e}"
+        ))),
+    }
+}
+
+fn resolve_group(group: &str) -> Result<u32, Status> {
+    if let Ok(gid) = group.parse() {
+        return Ok(gid);
+    }
+    match Group::from_name(group) {
+        Ok(Some(g)) => Ok(g.gid.as_raw()),
+        Ok(None) => Err(Status::aborted(format!("unknown group {group}"))),
+        Err(e) => Err(Status::aborted(format!(
+            "unable to look up group {group}: {e}"
+        ))),
+    }
+}
+
+// Loads the verifier rejected fail with the verifier log in the details of the
+// status, as the DebugInfo of the google.rpc error model, so clients don't need
+// to parse it out of the message.
+fn load_error_status(e: BpfmanError) -> Status {
+    match &e {
+        BpfmanError::VerifierRejected { log, .. } => {
+            let mut details = ErrorDetails::with_error_info(
+                "VERIFIER_REJECTED",
+                "bpfman.io",
+                HashMap::<String, String>::new(),
+            );
+            details.set_debug_info(
+                log.lines().map(|l| l.to_string()).collect::<Vec<_>>(),
+                "verifier log",
+            );
+            Status::with_error_details(Code::Aborted, format!("{e}"), details)
+        }
+        _ => Status::aborted(format!("{e}")),
+    }
+}
+
+fn load_response(program: &Program) -> Result<LoadResponse, Status> {
+    Ok(LoadResponse {
+        info: Some(
+            program
+                .try_into()
+                .map_err(|e| Status::aborted(format!("convert Program to GRPC program: {e}")))?,
+        ),
+        kernel_info: Some(program.try_into().map_err(|e| {
+            Status::aborted(format!("convert Program to GRPC kernel program info: {e}"))
+        })?),
+        additional_programs: vec![],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, time::SystemTime};
+
+    use bpfman_api::{
+        config::{TC_DISPATCHER_IMAGE, XDP_DISPATCHER_IMAGE},
+        v1::{
+            bytecode_location::Location, AttachInfo, BytecodeLocation, LoadRequest, XdpAttachInfo,
+        },
+        ProgramType,
+    };
+    use opentelemetry::Context;
+    use tokio::sync::mpsc::Receiver;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+    use crate::{
+        command::{command_channel, Capabilities, ProgramPage},
+        events::{Event, EventKind, EventLog, EVENTS_TREE},
+    };
+
+    #[tokio::test]
+    async fn test_load_with_valid_id() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        let attach_info = AttachInfo {
+            info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                iface: "eth0".to_string(),
+                priority: 50,
+                position: 0,
+                proceed_on: vec![2, 31],
+                track_iface_glob: false,
+                wait_for_iface: false,
+                netns_path: None,
+                slot_stats: None,
+                mode: None,
+            })),
+        };
+        let request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::Image(bpfman_api::v1::BytecodeImage {
+                    url: "quay.io/bpfman-bytecode/xdp:latest".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            attach: Some(attach_info),
+            ..Default::default()
+        };
+
+        tokio::spawn(async move {
+            mock_serve(rx).await;
+        });
+
+        let res = loader.load(Request::new(request)).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_load_additional_names() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
 
-        let attach_info = AttachInfo {
-            info: Some(Info::XdpAttachInfo(XdpAttachInfo {
-                iface: "eth0".to_string(),
-                priority: 50,
-                position: 0,
-                proceed_on: vec![2, 31],
-            })),
-        };
         let request = LoadRequest {
             bytecode: Some(BytecodeLocation {
-                location: Some(Location::Image(bpfman_api::v1::BytecodeImage {
-                    url: "quay.io/bpfman-bytecode/xdp:latest".to_string(),
+                location: Some(Location::File("/tmp/kprobe.o".to_string())),
+            }),
+            name: "enter".to_string(),
+            attach: Some(AttachInfo {
+                info: Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: "do_unlinkat".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            additional_names: vec!["leave".to_string(), "stats".to_string()],
+            ..Default::default()
+        };
+        let res = loader.load(Request::new(request.clone())).await;
+        assert_eq!(res.unwrap().into_inner().additional_programs.len(), 2);
+
+        // Loads with additional names can't be batched.
+        let res = loader
+            .load_batch(Request::new(LoadBatchRequest {
+                requests: vec![request],
+            }))
+            .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_map_max_entries() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let mut request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/kprobe.o".to_string())),
+            }),
+            name: "enter".to_string(),
+            attach: Some(AttachInfo {
+                info: Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: "do_unlinkat".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            map_max_entries: HashMap::from([("flows".to_string(), 65536)]),
+            ..Default::default()
+        };
+        assert!(loader.load(Request::new(request.clone())).await.is_ok());
+
+        request.map_max_entries.insert("stats".to_string(), 0);
+        assert!(loader.load(Request::new(request)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_map_init() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let mut request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/kprobe.o".to_string())),
+            }),
+            name: "enter".to_string(),
+            attach: Some(AttachInfo {
+                info: Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: "do_unlinkat".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            map_init: vec![bpfman_api::v1::MapInit {
+                map: "config".to_string(),
+                entries: vec![MapEntry {
+                    key: vec![0; 4],
+                    value: vec![1; 8],
+                    ..Default::default()
+                }],
+                freeze: true,
+            }],
+            ..Default::default()
+        };
+        assert!(loader.load(Request::new(request.clone())).await.is_ok());
+
+        request.inner_maps = vec![bpfman_api::v1::InnerMaps {
+            outer: "flows".to_string(),
+            map_type: 1,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            keys: vec![vec![0; 4], vec![1, 0, 0, 0]],
+        }];
+        assert!(loader.load(Request::new(request.clone())).await.is_ok());
+
+        // Inner maps can't hold maps themselves.
+        request.inner_maps[0].map_type = 12;
+        assert!(loader.load(Request::new(request.clone())).await.is_err());
+        request.inner_maps[0].map_type = 1;
+
+        // Programs using the maps of another program can't initialize them.
+        request.map_owner_id = Some(1);
+        assert!(loader.load(Request::new(request)).await.is_err());
+    }
+
+    #[test]
+    fn test_inner_maps_stored() {
+        let inner_maps = bpfman_api::v1::InnerMaps {
+            outer: "flows".to_string(),
+            map_type: 1,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            keys: vec![vec![0; 4], vec![1, 0, 0, 0]],
+        };
+        let mut request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/kprobe.o".to_string())),
+            }),
+            name: "enter".to_string(),
+            attach: Some(AttachInfo {
+                info: Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: "do_unlinkat".to_string(),
                     ..Default::default()
                 })),
             }),
-            attach: Some(attach_info),
+            inner_maps: vec![inner_maps.clone()],
             ..Default::default()
         };
+        let program = program_from_request(request.clone(), None).unwrap();
+        assert_eq!(
+            program.get_data().get_inner_maps().unwrap(),
+            vec![InnerMaps {
+                outer: inner_maps.outer,
+                map_type: inner_maps.map_type,
+                key_size: inner_maps.key_size,
+                value_size: inner_maps.value_size,
+                max_entries: inner_maps.max_entries,
+                keys: inner_maps.keys,
+            }]
+        );
 
-        tokio::spawn(async move {
-            mock_serve(rx).await;
-        });
+        request.inner_maps[0].outer = "a/b".to_string();
+        let err = program_from_request(request, None).err().unwrap();
+        assert_eq!(err.message(), "a/b is not a valid map name");
+    }
 
-        let res = loader.load(Request::new(request)).await;
-        assert!(res.is_ok());
+    #[tokio::test]
+    async fn test_load_map_permissions() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let mut request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/kprobe.o".to_string())),
+            }),
+            name: "enter".to_string(),
+            attach: Some(AttachInfo {
+                info: Some(Info::KprobeAttachInfo(KprobeAttachInfo {
+                    fn_name: "do_unlinkat".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            map_permissions: Some(bpfman_api::v1::MapPermissions {
+                user: Some("0".to_string()),
+                group: Some("root".to_string()),
+                mode: Some(0o600),
+            }),
+            ..Default::default()
+        };
+        assert!(loader.load(Request::new(request.clone())).await.is_ok());
+
+        request.map_permissions.as_mut().unwrap().mode = Some(0o4755);
+        assert!(loader.load(Request::new(request)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_batch() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let requests: Vec<LoadRequest> = (0..3)
+            .map(|i| LoadRequest {
+                bytecode: Some(BytecodeLocation {
+                    location: Some(Location::Image(bpfman_api::v1::BytecodeImage {
+                        url: "quay.io/bpfman-bytecode/xdp:latest".to_string(),
+                        ..Default::default()
+                    })),
+                }),
+                attach: Some(AttachInfo {
+                    info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                        iface: "eth0".to_string(),
+                        priority: 50 + i,
+                        position: 0,
+                        proceed_on: vec![2, 31],
+                        track_iface_glob: false,
+                        wait_for_iface: false,
+                        netns_path: None,
+                        slot_stats: None,
+                        mode: None,
+                    })),
+                }),
+                ..Default::default()
+            })
+            .collect();
+        let res = loader
+            .load_batch(Request::new(LoadBatchRequest {
+                requests: requests.clone(),
+            }))
+            .await;
+        assert_eq!(res.unwrap().into_inner().programs.len(), 3);
+
+        // A request missing its attach info fails the whole batch.
+        let mut requests = requests;
+        requests[1].attach = None;
+        let res = loader
+            .load_batch(Request::new(LoadBatchRequest { requests }))
+            .await;
+        assert!(res.is_err());
     }
 
     #[tokio::test]
     async fn test_pull_bytecode() {
-        let (tx, rx) = mpsc::channel(32);
-        let loader = BpfmanLoader::new(tx.clone());
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
 
         let request = PullBytecodeRequest {
             image: Some(bpfman_api::v1::BytecodeImage {
@@ -450,10 +2538,676 @@ mod test {
         tokio::spawn(async move { mock_serve(rx).await });
 
         let res = loader.pull_bytecode(Request::new(request)).await;
+        let image = res.unwrap().into_inner().image.unwrap();
+        assert_eq!(image.url, "quay.io/bpfman-bytecode/xdp_pass:latest");
+    }
+
+    #[tokio::test]
+    async fn test_attach_and_detach() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .detach(Request::new(DetachRequest {
+                id: 0,
+                ..Default::default()
+            }))
+            .await;
+        assert!(res.is_ok());
+
+        let res = loader
+            .attach(Request::new(AttachRequest {
+                id: 0,
+                ..Default::default()
+            }))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_priority() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .set_priority(Request::new(SetPriorityRequest {
+                id: 0,
+                priority: 20,
+                iface: Some("eth1".to_string()),
+                direction: Some("ingress".to_string()),
+            }))
+            .await;
+        assert!(res.is_ok());
+
+        let res = loader
+            .set_priority(Request::new(SetPriorityRequest {
+                id: 0,
+                priority: 20,
+                direction: Some("sideways".to_string()),
+                ..Default::default()
+            }))
+            .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_publish_maps() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .publish_maps(Request::new(PublishMapsRequest {
+                id: 0,
+                name: "counters".to_string(),
+            }))
+            .await;
+        assert!(res.is_ok());
+
+        let request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/xdp_counter.o".to_string())),
+            }),
+            attach: Some(AttachInfo {
+                info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    iface: "eth0".to_string(),
+                    priority: 50,
+                    position: 0,
+                    proceed_on: vec![2, 31],
+                    track_iface_glob: false,
+                    wait_for_iface: false,
+                    netns_path: None,
+                    slot_stats: None,
+                    mode: None,
+                })),
+            }),
+            map_from: Some("counters".to_string()),
+            ..Default::default()
+        };
+        let res = loader.load(Request::new(request.clone())).await;
+        assert!(res.is_ok());
+
+        // Maps are given either by name or by owner.
+        let res = loader
+            .load(Request::new(LoadRequest {
+                map_owner_id: Some(6371),
+                ..request
+            }))
+            .await;
+        assert!(res.is_err());
+
+        let res = loader
+            .unpublish_maps(Request::new(UnpublishMapsRequest {
+                name: "counters".to_string(),
+            }))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_export_maps() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let request = ExportMapsRequest {
+            id: 0,
+            maps: vec!["xdp_stats_map".to_string()],
+            path: "/run/consumer/maps".to_string(),
+            mount: true,
+        };
+        let res = loader.export_maps(Request::new(request.clone())).await;
+        assert_eq!(res.unwrap().into_inner().maps, request.maps);
+
+        let res = loader
+            .export_maps(Request::new(ExportMapsRequest {
+                path: "/run/consumer/../maps".to_string(),
+                ..request
+            }))
+            .await;
+        assert!(res.is_err());
+
+        let res = loader
+            .unexport_maps(Request::new(UnexportMapsRequest {
+                path: "/run/consumer/maps".to_string(),
+            }))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_images() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .list_images(Request::new(ListImagesRequest { url: None }))
+            .await;
+        let images = res.unwrap().into_inner().images;
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].program_ids, vec![0]);
+        assert_eq!(images[0].labels["io.ebpf.program_type"], "xdp");
+
+        let res = loader
+            .list_images(Request::new(ListImagesRequest {
+                url: Some("quay.io/bpfman-bytecode/tc_pass:latest".to_string()),
+            }))
+            .await;
+        assert!(res.unwrap().into_inner().images.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_images() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .prune_images(Request::new(PruneImagesRequest {}))
+            .await;
+        assert_eq!(
+            res.unwrap().into_inner().images,
+            vec!["quay.io/bpfman-bytecode/xdp_pass:latest".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .replace(Request::new(ReplaceRequest {
+                id: 0,
+                bytecode: Some(BytecodeLocation {
+                    location: Some(Location::File("/tmp/xdp_pass_v2.o".to_string())),
+                }),
+                ..Default::default()
+            }))
+            .await;
+        assert!(res.unwrap().into_inner().info.is_some());
+
+        let res = loader
+            .replace(Request::new(ReplaceRequest {
+                id: 0,
+                ..Default::default()
+            }))
+            .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rollback() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .rollback(Request::new(RollbackRequest { id: 0 }))
+            .await;
+        assert!(res.unwrap().into_inner().kernel_info.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_maps_without_pinned_maps() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        // The mock program was never loaded, so it has no map pin path.
+        let res = loader
+            .list_maps(Request::new(ListMapsRequest { id: 0 }))
+            .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watch_perf_buffer_rejects_sizes() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        // Both are checked before the map is looked up.
+        let err = loader
+            .watch_perf_buffer(Request::new(WatchPerfBufferRequest {
+                map: "events".to_string(),
+                page_count: Some(3),
+                ..Default::default()
+            }))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.message(), "page_count must be a power of two");
+
+        let err = loader
+            .watch_perf_buffer(Request::new(WatchPerfBufferRequest {
+                map: "events".to_string(),
+                page_count: Some(4),
+                queue_size: Some(0),
+                ..Default::default()
+            }))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.message(), "queue_size must be greater than 0");
+    }
+
+    #[tokio::test]
+    async fn test_attach_to_further_interface() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let request = AttachRequest {
+            id: 0,
+            attach: Some(AttachInfo {
+                info: Some(Info::XdpAttachInfo(XdpAttachInfo {
+                    iface: "eth1".to_string(),
+                    priority: 50,
+                    position: 0,
+                    proceed_on: vec![2, 31],
+                    track_iface_glob: false,
+                    wait_for_iface: false,
+                    netns_path: None,
+                    slot_stats: None,
+                    mode: None,
+                })),
+            }),
+        };
+        let res = loader.attach(Request::new(request)).await;
+        assert!(res.is_ok());
+
+        let request = AttachRequest {
+            id: 0,
+            attach: Some(AttachInfo {
+                info: Some(Info::TracepointAttachInfo(TracepointAttachInfo {
+                    tracepoint: "syscalls/sys_enter_kill".to_string(),
+                })),
+            }),
+        };
+        let res = loader.attach(Request::new(request)).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tc_both_directions() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let attach_info = AttachInfo {
+            info: Some(Info::TcAttachInfo(TcAttachInfo {
+                priority: 50,
+                iface: "eth0".to_string(),
+                position: 0,
+                direction: "both".to_string(),
+                proceed_on: vec![],
+                tcx: Some(false),
+                track_iface_glob: false,
+                wait_for_iface: false,
+                netns_path: None,
+                slot_stats: None,
+            })),
+        };
+        let request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::Image(bpfman_api::v1::BytecodeImage {
+                    url: "quay.io/bpfman-bytecode/tc_pass:latest".to_string(),
+                    ..Default::default()
+                })),
+            }),
+            attach: Some(attach_info.clone()),
+            ..Default::default()
+        };
+        let res = loader.load(Request::new(request)).await;
         assert!(res.is_ok());
+
+        // Attachments have a single direction.
+        let request = AttachRequest {
+            id: 0,
+            attach: Some(attach_info),
+        };
+        let res = loader.attach(Request::new(request)).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_streams_chunks() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let mut stream = loader
+            .read(Request::new(ReadRequest { id: 0 }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut chunks = 0;
+        let mut data = vec![];
+        while let Some(resp) = stream.next().await {
+            chunks += 1;
+            data.extend(resp.unwrap().data);
+        }
+        assert_eq!(chunks, 3);
+        assert_eq!(data, mock_iter_output());
+    }
+
+    #[test]
+    fn test_request_location_uploaded() {
+        let (location, uploaded) = request_location(Some(BytecodeLocation {
+            location: Some(Location::Data(vec![1, 2, 3])),
+        }))
+        .unwrap();
+        let crate::command::Location::File(path) = location else {
+            panic!("uploaded bytecode should be loaded from a file");
+        };
+        assert_eq!(path, upload_path(&[1, 2, 3]));
+        assert_eq!(uploaded, vec![1, 2, 3]);
+
+        assert!(request_location(Some(BytecodeLocation {
+            location: Some(Location::Data(vec![])),
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_username() {
+        assert_eq!(username(0), "root");
+        assert_eq!(username(u32::MAX - 1), (u32::MAX - 1).to_string());
+    }
+
+    #[test]
+    fn test_request_location_url() {
+        let url = |url: &str, sha256: &str| {
+            request_location(Some(BytecodeLocation {
+                location: Some(Location::Url(bpfman_api::v1::BytecodeUrl {
+                    url: url.to_string(),
+                    sha256: sha256.to_string(),
+                })),
+            }))
+        };
+        let sha256 = "0".repeat(64);
+        assert!(matches!(
+            url("https://example.com/xdp_pass.bpf.o", &sha256),
+            Ok((crate::command::Location::Url(_), _))
+        ));
+        assert!(url("https://example.com/xdp_pass.bpf.o", "").is_err());
+        assert!(url("http://example.com/xdp_pass.bpf.o", &sha256).is_err());
+    }
+
+    // Builds the program a load request with the given attach info would
+    // load, storing it in the database, and reads the attach info back.
+    fn attach_info_round_trip(info: Info) -> Result<Info, Status> {
+        let request = LoadRequest {
+            bytecode: Some(BytecodeLocation {
+                location: Some(Location::File("/tmp/prog.o".to_string())),
+            }),
+            name: "prog".to_string(),
+            attach: Some(AttachInfo { info: Some(info) }),
+            ..Default::default()
+        };
+        let program = program_from_request(request, None)?;
+        Ok(AttachInfo::try_from(&program).unwrap().info.unwrap())
+    }
+
+    #[test]
+    fn test_cgroup_skb_attach_info() {
+        let info = Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+            cgroup_path: "/sys/fs/cgroup/test".to_string(),
+            direction: "egress".to_string(),
+            attach_flags: 2,
+        });
+        assert_eq!(attach_info_round_trip(info.clone()).unwrap(), info);
+
+        let err = attach_info_round_trip(Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+            cgroup_path: "/sys/fs/cgroup/test".to_string(),
+            direction: "both".to_string(),
+            attach_flags: 0,
+        }))
+        .unwrap_err();
+        assert_eq!(err.message(), "direction is not a string");
+        assert!(
+            attach_info_round_trip(Info::CgroupSkbAttachInfo(CgroupSkbAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                direction: "ingress".to_string(),
+                attach_flags: 7,
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sock_ops_attach_info() {
+        let info = Info::SockOpsAttachInfo(SockOpsAttachInfo {
+            cgroup_path: "/sys/fs/cgroup/test".to_string(),
+            attach_flags: 1,
+        });
+        assert_eq!(attach_info_round_trip(info.clone()).unwrap(), info);
+
+        assert!(
+            attach_info_round_trip(Info::SockOpsAttachInfo(SockOpsAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_flags: -1,
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_flow_dissector_attach_info() {
+        let info = Info::FlowDissectorAttachInfo(FlowDissectorAttachInfo {
+            netns_path: "/var/run/netns/test".to_string(),
+        });
+        assert_eq!(attach_info_round_trip(info.clone()).unwrap(), info);
+    }
+
+    #[test]
+    fn test_cgroup_attach_infos() {
+        let infos = [
+            Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_flags: 0,
+            }),
+            Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_flags: 1,
+            }),
+            Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_type: "getsockopt".to_string(),
+                attach_flags: 2,
+            }),
+            Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_type: "setsockopt".to_string(),
+                attach_flags: 0,
+            }),
+        ];
+        for info in infos {
+            assert_eq!(attach_info_round_trip(info.clone()).unwrap(), info);
+        }
+
+        let err = attach_info_round_trip(Info::CgroupSockoptAttachInfo(CgroupSockoptAttachInfo {
+            cgroup_path: "/sys/fs/cgroup/test".to_string(),
+            attach_type: "sockopt".to_string(),
+            attach_flags: 0,
+        }))
+        .unwrap_err();
+        assert_eq!(err.message(), "attach_type is not valid");
+        assert!(
+            attach_info_round_trip(Info::CgroupDeviceAttachInfo(CgroupDeviceAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_flags: 3,
+            }))
+            .is_err()
+        );
+        assert!(
+            attach_info_round_trip(Info::CgroupSysctlAttachInfo(CgroupSysctlAttachInfo {
+                cgroup_path: "/sys/fs/cgroup/test".to_string(),
+                attach_flags: 3,
+            }))
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_page() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .list(Request::new(ListRequest {
+                iface: Some("eth0".to_string()),
+                page_size: 2,
+                page_token: "42".to_string(),
+                ..Default::default()
+            }))
+            .await;
+        let reply = res.unwrap().into_inner();
+        assert_eq!(reply.results.len(), 2);
+        assert!(reply.results.iter().all(|r| r.bpfman_managed));
+        assert_eq!(reply.next_page_token, "0");
+    }
+
+    #[tokio::test]
+    async fn test_list_conflicting_ownership_filters() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .list(Request::new(ListRequest {
+                bpfman_programs_only: Some(true),
+                foreign_programs_only: Some(true),
+                ..Default::default()
+            }))
+            .await;
+        assert_eq!(res.unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_version() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader.version(Request::new(VersionRequest {})).await;
+        let reply = res.unwrap().into_inner();
+        assert_eq!(reply.version, env!("CARGO_PKG_VERSION"));
+        assert!(reply.program_types.contains(&(ProgramType::Xdp as u32)));
+        assert_eq!(reply.kernel_features, vec!["tcx"]);
+        assert_eq!(reply.xdp_dispatcher_image, XDP_DISPATCHER_IMAGE);
+    }
+
+    #[tokio::test]
+    async fn test_probe_features_unknown_program_type() {
+        let (tx, _rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx, None);
+
+        for program_type in [ProgramType::Unspec as u32, 99] {
+            let status = loader
+                .probe_features(Request::new(ProbeFeaturesRequest {
+                    helper_program_types: vec![ProgramType::Xdp as u32, program_type],
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_by_uuid() {
+        let (tx, rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        tokio::spawn(async move { mock_serve(rx).await });
+
+        let res = loader
+            .get(Request::new(GetRequest {
+                id: 0,
+                uuid: Some(MOCK_UUID.to_string()),
+            }))
+            .await;
+        let info = res.unwrap().into_inner().info.unwrap();
+        assert_eq!(info.uuid.as_deref(), Some(MOCK_UUID));
+        assert_eq!(info.name, "xdp_pass");
+    }
+
+    #[tokio::test]
+    async fn test_get_events() {
+        let (tx, _rx) = command_channel(32);
+        let loader = BpfmanLoader::new(tx.clone(), None);
+
+        // Events are recorded by the BpfManager, which isn't running.
+        let uuid = "0e1d7c9a-83c2-4f4b-b2a7-2f8f0e6c4d11";
+        let log = EventLog::new(ROOT_DB.open_tree(EVENTS_TREE).unwrap(), 100);
+        for (kind, error) in [
+            (EventKind::Load, None),
+            (EventKind::Unload, Some("Program 6932 does not exist")),
+        ] {
+            log.record(Event {
+                uuid: Some(uuid.to_string()),
+                error: error.map(|e| e.to_string()),
+                ..Event::new(kind, "xdp program on eth0".to_string())
+            });
+        }
+
+        let res = loader
+            .get_events(Request::new(GetEventsRequest {
+                uuid: Some(uuid.to_string()),
+                limit: 1,
+                ..Default::default()
+            }))
+            .await;
+        let events = res.unwrap().into_inner().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, "unload");
+        assert_eq!(
+            events[0].error.as_deref(),
+            Some("Program 6932 does not exist")
+        );
+    }
+
+    const MOCK_UUID: &str = "1b8bb6e2-4b3c-4a4e-9d52-05c3f8e6e2a1";
+
+    fn mock_iter_output() -> Vec<u8> {
+        (0..READ_CHUNK_SIZE * 2 + 10).map(|i| i as u8).collect()
     }
 
-    async fn mock_serve(mut rx: Receiver<Command>) {
+    fn mock_image() -> crate::oci_utils::image_manager::ImageInfo {
+        crate::oci_utils::image_manager::ImageInfo {
+            url: "quay.io/bpfman-bytecode/xdp_pass:latest".to_string(),
+            digest: String::new(),
+            size: 4096,
+            last_used: 0,
+            labels: HashMap::from([("io.ebpf.program_type".to_string(), "xdp".to_string())]),
+            program_ids: vec![0],
+        }
+    }
+
+    async fn mock_serve(mut rx: Receiver<(Command, Context)>) {
         let mut data = ProgramData::new_pre_load(
             crate::command::Location::File("/tmp/fake".to_string()),
             "xdp_pass".to_string(),
@@ -463,6 +3217,8 @@ mod test {
         )
         .unwrap();
 
+        data.set_uuid(MOCK_UUID).unwrap();
+
         // Set kernel info
         data.set_id(0).unwrap();
         data.set_kernel_name("").unwrap();
@@ -482,13 +3238,54 @@ mod test {
             XdpProgram::new(data, 0, "eth0".to_string(), XdpProceedOn::default()).unwrap(),
         );
 
-        while let Some(cmd) = rx.recv().await {
+        while let Some((cmd, _)) = rx.recv().await {
             match cmd {
                 Command::Load(args) => args.responder.send(Ok(program.clone())).unwrap(),
+                Command::LoadBatch(args) => args
+                    .responder
+                    .send(Ok(vec![program.clone(); args.programs.len()]))
+                    .unwrap(),
+                Command::LoadObject(args) => args
+                    .responder
+                    .send(Ok(vec![program.clone(); args.programs.len()]))
+                    .unwrap(),
                 Command::Unload(args) => args.responder.send(Ok(())).unwrap(),
-                Command::List { responder, .. } => responder.send(Ok(vec![])).unwrap(),
+                Command::Attach(args) => args.responder.send(Ok(())).unwrap(),
+                Command::Detach(args) => args.responder.send(Ok(())).unwrap(),
+                Command::SetPriority(args) => args.responder.send(Ok(())).unwrap(),
+                Command::PublishMaps(args) => args.responder.send(Ok(())).unwrap(),
+                Command::UnpublishMaps(args) => args.responder.send(Ok(())).unwrap(),
+                Command::ExportMaps(args) => args.responder.send(Ok(args.maps)).unwrap(),
+                Command::UnexportMaps(args) => args.responder.send(Ok(())).unwrap(),
+                Command::Replace(args) => args.responder.send(Ok(program.clone())).unwrap(),
+                Command::Rollback(args) => args.responder.send(Ok(program.clone())).unwrap(),
+                Command::List(args) => args
+                    .responder
+                    .send(Ok(ProgramPage {
+                        programs: vec![program.clone(); args.page_size.max(1)],
+                        next_page_token: Some("0".to_string()),
+                    }))
+                    .unwrap(),
                 Command::Get(args) => args.responder.send(Ok(program.clone())).unwrap(),
-                Command::PullBytecode(args) => args.responder.send(Ok(())).unwrap(),
+                Command::PullBytecode(args) => args.responder.send(Ok(mock_image())).unwrap(),
+                Command::ListImages(args) => args.responder.send(Ok(vec![mock_image()])).unwrap(),
+                Command::PruneImages(args) => args
+                    .responder
+                    .send(Ok(vec![
+                        "quay.io/bpfman-bytecode/xdp_pass:latest".to_string()
+                    ]))
+                    .unwrap(),
+                Command::Read(args) => args.responder.send(Ok(mock_iter_output())).unwrap(),
+                Command::Version(args) => args
+                    .responder
+                    .send(Capabilities {
+                        kernel_release: "6.8.0".to_string(),
+                        kernel_features: vec!["tcx".to_string()],
+                        xdp_dispatcher_image: XDP_DISPATCHER_IMAGE.to_string(),
+                        tc_dispatcher_image: TC_DISPATCHER_IMAGE.to_string(),
+                    })
+                    .unwrap(),
+                Command::LinkEvent(_) | Command::ReloadConfig(_) => (),
             }
         }
     }
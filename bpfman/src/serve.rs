@@ -2,64 +2,209 @@
 // Copyright Authors of bpfman
 
 use std::{
-    fs::remove_file,
+    collections::HashSet,
+    fs::{create_dir_all, remove_file},
     os::unix::prelude::{FromRawFd, IntoRawFd},
     path::Path,
+    sync::Arc,
 };
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail, Context};
 use bpfman_api::{
-    config::Config, util::directories::RTPATH_BPFMAN_SOCKET, v1::bpfman_server::BpfmanServer,
+    config::{Config, GrpcConfig, GrpcEndpoint, SocketConfig},
+    v1::bpfman_server::BpfmanServer,
+    FILE_DESCRIPTOR_SET,
 };
 use libsystemd::activation::IsType;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
+use nix::unistd::{chown, Group};
 use tokio::{
     join,
-    net::UnixListener,
+    net::{TcpListener, UnixListener},
+    select,
     signal::unix::{signal, SignalKind},
     sync::{broadcast, mpsc},
     task::{JoinHandle, JoinSet},
 };
-use tokio_stream::wrappers::UnixListenerStream;
-use tonic::transport::Server;
+use tokio_stream::wrappers::{ReceiverStream, UnixListenerStream};
+use tonic::transport::{server::Router, Server};
+use tonic_health::ServingStatus;
 
 use crate::{
+    audit::Auditor,
+    authz::Authorizer,
     bpf::BpfManager,
-    oci_utils::ImageManager,
+    command::command_channel,
+    external_btf::init_external_btf,
+    fd_handover::{serve_fd_handover, serve_map_fd_handover},
+    link_monitor::serve_link_monitor,
+    oci_utils::{set_proxy_env, ImageManager},
+    probe::{log_features, probe_features},
+    reload::serve_config_reload,
+    rest_gateway::serve_rest_gateway,
     rpc::BpfmanLoader,
+    stats::enable_run_time_stats,
     storage::StorageManager,
-    utils::{set_file_permissions, SOCK_MODE},
+    telemetry::{init_tracing, shutdown_tracing, TracedService},
+    tls::TlsReloader,
+    utils::set_file_permissions,
     ROOT_DB,
 };
 
 pub async fn serve(config: &Config, csi_support: bool, timeout: u64) -> anyhow::Result<()> {
+    if let Some(proxy) = &config.proxy {
+        set_proxy_env(proxy);
+    }
+    if let Some(tracing) = &config.tracing {
+        init_tracing(tracing)?;
+    }
+
     let (shutdown_tx, shutdown_rx1) = broadcast::channel(32);
     let shutdown_rx2 = shutdown_tx.subscribe();
     let shutdown_rx3 = shutdown_tx.subscribe();
     let shutdown_rx4 = shutdown_tx.subscribe();
+    let shutdown_rx5 = shutdown_tx.subscribe();
+    let shutdown_rx6 = shutdown_tx.subscribe();
+    let shutdown_rx7 = shutdown_tx.subscribe();
+    let shutdown_rx8 = shutdown_tx.subscribe();
+    let shutdown_rx9 = shutdown_tx.subscribe();
+    let endpoint_shutdown_tx = shutdown_tx.clone();
     let shutdown_handle = tokio::spawn(shutdown_handler(timeout, shutdown_tx));
 
-    let (tx, rx) = mpsc::channel(32);
+    let (tx, rx) = command_channel(32);
 
-    let loader = BpfmanLoader::new(tx.clone());
-    let path = RTPATH_BPFMAN_SOCKET.to_string();
+    let authorizer = config
+        .authorization
+        .clone()
+        .map(Authorizer::new)
+        .transpose()
+        .context("invalid authorization configuration")?
+        .map(Arc::new);
+    let auditor = config
+        .audit
+        .as_ref()
+        .map(Auditor::new)
+        .transpose()?
+        .map(Arc::new);
+    // The kernel counts program runs for as long as this fd is open.
+    let _stats_fd = config
+        .program_stats
+        .enabled
+        .then(enable_run_time_stats)
+        .transpose()?;
+    let loader = BpfmanLoader::new(tx.clone(), config.ring_buffer)
+        .with_authorizer(authorizer.clone())
+        .with_auditor(auditor.clone());
     let service = BpfmanServer::new(loader);
 
+    // bpfman reports that it's serving once its state is rebuilt.
+    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter
+        .set_not_serving::<BpfmanServer<BpfmanLoader>>()
+        .await;
+    health_reporter
+        .set_service_status("", ServingStatus::NotServing)
+        .await;
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build()?;
+    // Each endpoint has a router of its own, as TLS is set up per server.
+    let grpc_router = |mut server: Server| {
+        server
+            .add_service(health_service.clone())
+            .add_service(reflection_service.clone())
+            .add_service(TracedService::new(service.clone()))
+    };
+
+    check_endpoints(&config.grpc)?;
     let mut listeners: Vec<_> = Vec::new();
 
-    let handle = serve_unix(path.clone(), service.clone(), shutdown_rx1).await?;
+    let path = config.grpc.socket.path.clone();
+    let uds_stream = if let Ok(stream) = systemd_unix_stream(path.clone()) {
+        stream
+    } else {
+        std_unix_stream(&config.grpc.socket).await?
+    };
+    let handle = serve_unix(
+        path,
+        uds_stream,
+        grpc_router(Server::builder()),
+        shutdown_rx1,
+    );
     listeners.push(handle);
 
+    for endpoint in &config.grpc.endpoints {
+        let shutdown_rx = endpoint_shutdown_tx.subscribe();
+        let handle = match endpoint {
+            GrpcEndpoint::Unix(socket) => serve_unix(
+                socket.path.clone(),
+                std_unix_stream(socket).await?,
+                grpc_router(Server::builder()),
+                shutdown_rx,
+            ),
+            GrpcEndpoint::Tcp(tcp) => {
+                let tls = TlsReloader::new(&tcp.tls, &[b"h2"])
+                    .await
+                    .with_context(|| format!("invalid TLS configuration of {}", tcp.address))?;
+                serve_tcp(
+                    &tcp.address,
+                    tls,
+                    grpc_router(Server::builder()),
+                    shutdown_rx,
+                )
+                .await?
+            }
+        };
+        listeners.push(handle);
+    }
+
+    let handle = serve_fd_handover(authorizer.clone(), shutdown_rx5).await?;
+    listeners.push(handle);
+
+    let handle = serve_map_fd_handover(authorizer.clone(), shutdown_rx7).await?;
+    listeners.push(handle);
+
+    let handle = serve_link_monitor(tx.clone(), shutdown_rx6).await?;
+    listeners.push(handle);
+
+    let handle = serve_config_reload(tx.clone(), shutdown_rx9).await?;
+    listeners.push(handle);
+
+    if let Some(gateway) = &config.rest_gateway {
+        let loader = BpfmanLoader::new(tx.clone(), config.ring_buffer)
+            .with_authorizer(authorizer)
+            .with_auditor(auditor);
+        let handle = serve_rest_gateway(gateway, loader, shutdown_rx8).await?;
+        listeners.push(handle);
+    }
+
     let allow_unsigned = config.signing.as_ref().map_or(true, |s| s.allow_unsigned);
     let (itx, irx) = mpsc::channel(32);
 
-    let mut image_manager = ImageManager::new(ROOT_DB.clone(), allow_unsigned, irx).await?;
+    let mut image_manager = ImageManager::new(
+        ROOT_DB.clone(),
+        allow_unsigned,
+        config.registries.clone().unwrap_or_default(),
+        config.image_pull,
+        config.file_signing.clone(),
+    )
+    .await?;
     let image_manager_handle = tokio::spawn(async move {
-        image_manager.run(shutdown_rx2).await;
+        image_manager.run(irx, shutdown_rx2).await;
     });
 
+    log_features(&probe_features());
+    init_external_btf(config.btf.as_ref());
+
     let mut bpf_manager = BpfManager::new(config.clone(), rx, itx);
     bpf_manager.rebuild_state().await?;
+    health_reporter
+        .set_serving::<BpfmanServer<BpfmanLoader>>()
+        .await;
+    health_reporter
+        .set_service_status("", ServingStatus::Serving)
+        .await;
 
     // TODO(astoycos) see issue #881
     //let static_programs = get_static_programs(static_program_path).await?;
@@ -105,6 +250,7 @@ pub async fn serve(config: &Config, csi_support: bool, timeout: u64) -> anyhow::
         }
     }
 
+    shutdown_tracing();
     Ok(())
 }
 
@@ -142,33 +288,97 @@ async fn join_listeners(listeners: Vec<JoinHandle<()>>) {
     }
 }
 
-async fn serve_unix(
-    path: String,
-    service: BpfmanServer<BpfmanLoader>,
+// Unix sockets may only be used by a single endpoint, as each endpoint
+// replaces any existing socket at its path.
+fn check_endpoints(grpc: &GrpcConfig) -> anyhow::Result<()> {
+    let mut paths = HashSet::from([grpc.socket.path.as_str()]);
+    for endpoint in &grpc.endpoints {
+        if let GrpcEndpoint::Unix(socket) = endpoint {
+            if !paths.insert(&socket.path) {
+                bail!("socket {} is configured more than once", socket.path);
+            }
+        }
+    }
+    Ok(())
+}
+
+// TLS is set up here rather than by the server, so that each connection uses
+// the current certificates of the endpoint.
+async fn serve_tcp(
+    address: &str,
+    mut tls: TlsReloader,
+    router: Router,
     mut shutdown_channel: broadcast::Receiver<()>,
 ) -> anyhow::Result<JoinHandle<()>> {
-    let uds_stream = if let Ok(stream) = systemd_unix_stream(path.clone()) {
-        stream
-    } else {
-        std_unix_stream(path.clone()).await?
-    };
+    let listener = TcpListener::bind(address)
+        .await
+        .with_context(|| format!("unable to listen on {address}"))?;
+    let address = address.to_string();
 
-    let serve = Server::builder()
-        .add_service(service)
-        .serve_with_incoming_shutdown(uds_stream, async move {
-            match shutdown_channel.recv().await {
-                Ok(()) => debug!("Unix Socket: Received shutdown signal"),
-                Err(e) => error!("Error receiving shutdown signal {:?}", e),
+    // Connections are handed to the server once their handshake is done, and
+    // are no longer accepted once the server shut down.
+    let (conn_tx, conn_rx) = mpsc::channel::<std::io::Result<_>>(32);
+    tokio::spawn(async move {
+        loop {
+            let (stream, peer) = select! {
+                res = listener.accept() => match res {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        warn!("Unable to accept TCP connection: {e}");
+                        continue;
+                    }
+                },
+                _ = conn_tx.closed() => break,
             };
-        });
+            let acceptor = tls.acceptor();
+            let conn_tx = conn_tx.clone();
+            tokio::spawn(async move {
+                match acceptor.accept(stream).await {
+                    Ok(stream) => {
+                        let _ = conn_tx.send(Ok(stream)).await;
+                    }
+                    Err(e) => debug!("TLS handshake with {peer} failed: {e}"),
+                }
+            });
+        }
+    });
+
+    let serve = router.serve_with_incoming_shutdown(ReceiverStream::new(conn_rx), async move {
+        match shutdown_channel.recv().await {
+            Ok(()) => debug!("TCP Socket: Received shutdown signal"),
+            Err(e) => error!("Error receiving shutdown signal {:?}", e),
+        };
+    });
 
     Ok(tokio::spawn(async move {
+        info!("Listening on {address}");
+        if let Err(e) = serve.await {
+            eprintln!("Error = {e:?}");
+        }
+        info!("Shutdown TCP Handler {}", address);
+    }))
+}
+
+fn serve_unix(
+    path: String,
+    uds_stream: UnixListenerStream,
+    router: Router,
+    mut shutdown_channel: broadcast::Receiver<()>,
+) -> JoinHandle<()> {
+    let serve = router.serve_with_incoming_shutdown(uds_stream, async move {
+        match shutdown_channel.recv().await {
+            Ok(()) => debug!("Unix Socket: Received shutdown signal"),
+            Err(e) => error!("Error receiving shutdown signal {:?}", e),
+        };
+    });
+
+    tokio::spawn(async move {
         info!("Listening on {path}");
         if let Err(e) = serve.await {
             eprintln!("Error = {e:?}");
         }
         info!("Shutdown Unix Handler {}", path);
-    }))
+    })
 }
 
 fn systemd_unix_stream(_path: String) -> anyhow::Result<UnixListenerStream> {
@@ -190,17 +400,28 @@ fn systemd_unix_stream(_path: String) -> anyhow::Result<UnixListenerStream> {
     Err(anyhow!("Unable to retrieve fd from systemd"))
 }
 
-async fn std_unix_stream(path: String) -> anyhow::Result<UnixListenerStream> {
+async fn std_unix_stream(socket: &SocketConfig) -> anyhow::Result<UnixListenerStream> {
+    let path = &socket.path;
     // Listen on Unix socket
-    if Path::new(&path).exists() {
+    if Path::new(path).exists() {
         // Attempt to remove the socket, since bind fails if it exists
-        remove_file(&path)?;
+        remove_file(path)?;
+    } else if let Some(dir) = Path::new(path).parent() {
+        create_dir_all(dir)
+            .with_context(|| format!("unable to create socket directory {}", dir.display()))?;
     }
 
-    let uds = UnixListener::bind(&path)?;
+    let uds = UnixListener::bind(path).with_context(|| format!("unable to listen on {path}"))?;
     let stream = UnixListenerStream::new(uds);
     // Always set the file permissions of our listening socket.
-    set_file_permissions(&path.clone(), SOCK_MODE).await;
+    set_file_permissions(path, socket.mode).await;
+    if let Some(group) = &socket.group {
+        let gid = Group::from_name(group)?
+            .with_context(|| format!("socket group {group} doesn't exist"))?
+            .gid;
+        chown(path.as_str(), None, Some(gid))
+            .with_context(|| format!("unable to give {path} to group {group}"))?;
+    }
 
     info!("Using default Unix socket");
     Ok(stream)
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// sk_msg and sk_skb programs are attached to a sockmap or sockhash rather than
+// through a link. aya can only attach them to sockhashes whose key type is
+// known at compile time, and detaches them again when the program they were
+// attached with is dropped, so the bpf syscall is used directly here. The
+// attachment then lasts as long as the map, and detaching only needs the map,
+// the program and the attach type, so nothing has to be kept from the attach.
+
+use std::{
+    fmt,
+    os::fd::{AsRawFd, BorrowedFd},
+};
+
+use nix::libc;
+
+use crate::{command::SkSkbAttachType, errors::BpfmanError, maps::sys_bpf};
+
+const BPF_PROG_ATTACH: libc::c_long = 8;
+const BPF_PROG_DETACH: libc::c_long = 9;
+
+// enum bpf_attach_type
+const BPF_SK_SKB_STREAM_PARSER: u32 = 4;
+const BPF_SK_SKB_STREAM_VERDICT: u32 = 5;
+const BPF_SK_MSG_VERDICT: u32 = 7;
+
+// The part of union bpf_attr used by BPF_PROG_ATTACH and BPF_PROG_DETACH.
+#[repr(C)]
+#[derive(Default)]
+struct ProgAttachAttr {
+    target_fd: u32,
+    attach_bpf_fd: u32,
+    attach_type: u32,
+    attach_flags: u32,
+    replace_bpf_fd: u32,
+}
+
+// The kinds of programs attached to sockmaps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SockMapProgram {
+    SkMsg,
+    SkSkb(SkSkbAttachType),
+}
+
+impl SockMapProgram {
+    fn attach_type(self) -> u32 {
+        match self {
+            SockMapProgram::SkMsg => BPF_SK_MSG_VERDICT,
+            SockMapProgram::SkSkb(SkSkbAttachType::StreamParser) => BPF_SK_SKB_STREAM_PARSER,
+            SockMapProgram::SkSkb(SkSkbAttachType::StreamVerdict) => BPF_SK_SKB_STREAM_VERDICT,
+        }
+    }
+}
+
+impl fmt::Display for SockMapProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SockMapProgram::SkMsg => write!(f, "sk_msg"),
+            SockMapProgram::SkSkb(attach_type) => write!(f, "sk_skb {attach_type}"),
+        }
+    }
+}
+
+pub(crate) fn attach(
+    map: BorrowedFd<'_>,
+    program: BorrowedFd<'_>,
+    kind: SockMapProgram,
+) -> Result<(), BpfmanError> {
+    prog_attach(BPF_PROG_ATTACH, map, program, kind)
+        .map_err(|e| BpfmanError::Error(format!("unable to attach {kind} program to map: {e}")))
+}
+
+pub(crate) fn detach(
+    map: BorrowedFd<'_>,
+    program: BorrowedFd<'_>,
+    kind: SockMapProgram,
+) -> Result<(), BpfmanError> {
+    prog_attach(BPF_PROG_DETACH, map, program, kind)
+        .map_err(|e| BpfmanError::Error(format!("unable to detach {kind} program from map: {e}")))
+}
+
+fn prog_attach(
+    cmd: libc::c_long,
+    map: BorrowedFd<'_>,
+    program: BorrowedFd<'_>,
+    kind: SockMapProgram,
+) -> std::io::Result<()> {
+    let mut attr = ProgAttachAttr {
+        target_fd: map.as_raw_fd() as u32,
+        attach_bpf_fd: program.as_raw_fd() as u32,
+        attach_type: kind.attach_type(),
+        ..Default::default()
+    };
+    sys_bpf(cmd, &mut attr).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, os::fd::AsFd};
+
+    use super::*;
+
+    #[test]
+    fn test_attach_type() {
+        assert_eq!(SockMapProgram::SkMsg.attach_type(), BPF_SK_MSG_VERDICT);
+        assert_eq!(
+            SockMapProgram::SkSkb(SkSkbAttachType::StreamParser).attach_type(),
+            BPF_SK_SKB_STREAM_PARSER
+        );
+        assert_eq!(
+            SockMapProgram::SkSkb(SkSkbAttachType::StreamVerdict).attach_type(),
+            BPF_SK_SKB_STREAM_VERDICT
+        );
+    }
+
+    #[test]
+    fn test_attach_not_bpf_fds() {
+        // Files that aren't a map and a program are rejected by the kernel,
+        // and the error tells what was being attached.
+        let file = File::open("/dev/null").unwrap();
+        let err = attach(file.as_fd(), file.as_fd(), SockMapProgram::SkMsg).unwrap_err();
+        assert!(err.to_string().contains("sk_msg"));
+        assert!(detach(file.as_fd(), file.as_fd(), SockMapProgram::SkMsg).is_err());
+    }
+}
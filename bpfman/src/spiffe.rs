@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// TLS listeners can get their certificate, private key and client CAs from the
+// SPIFFE Workload API, as served by the SPIRE agent, instead of files. The API
+// streams a new X.509 SVID before the previous one expires, which listeners
+// then use for new connections.
+
+use std::{collections::HashMap, time::Duration};
+
+use anyhow::Context;
+use log::{info, warn};
+use tokio::{
+    net::UnixStream,
+    sync::watch,
+    time::{sleep, timeout},
+};
+use tonic::{
+    client::Grpc,
+    codec::ProstCodec,
+    codegen::http::uri::PathAndQuery,
+    metadata::MetadataValue,
+    transport::{Channel, Endpoint, Uri},
+    Request, Streaming,
+};
+use tower::service_fn;
+
+const FETCH_X509_SVID: &str = "/SpiffeWorkloadAPI/FetchX509SVID";
+
+// The Workload API rejects requests without this header, so that it can't be
+// reached through proxies by mistake.
+const WORKLOAD_HEADER: &str = "workload.spiffe.io";
+
+const FIRST_SVID_TIMEOUT: Duration = Duration::from_secs(30);
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+// The messages of FetchX509SVID, see workload.proto of the SPIFFE project.
+#[derive(Clone, PartialEq, prost::Message)]
+struct X509SvidRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct X509SvidResponse {
+    #[prost(message, repeated, tag = "1")]
+    svids: Vec<X509Svid>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    crl: Vec<Vec<u8>>,
+    #[prost(map = "string, bytes", tag = "3")]
+    federated_bundles: HashMap<String, Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct X509Svid {
+    #[prost(string, tag = "1")]
+    spiffe_id: String,
+    #[prost(bytes = "vec", tag = "2")]
+    x509_svid: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    x509_svid_key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    bundle: Vec<u8>,
+}
+
+// The SVID of bpfman as DER certificates and a PKCS #8 key, and the DER
+// certificates of the trust bundles of its own and of federated trust domains.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Svid {
+    pub(crate) spiffe_id: String,
+    pub(crate) certs: Vec<Vec<u8>>,
+    pub(crate) key: Vec<u8>,
+    pub(crate) bundle: Vec<Vec<u8>>,
+}
+
+// Streams the SVIDs of bpfman from the Workload API at socket, once the first
+// one was received. The stream is reopened if it fails, and ends once all
+// receivers are dropped.
+pub(crate) async fn watch_svid(socket: &str) -> anyhow::Result<watch::Receiver<Svid>> {
+    let path = socket.strip_prefix("unix://").unwrap_or(socket).to_string();
+    let channel = Endpoint::try_from("http://[::]:50051")?
+        .connect_with_connector_lazy(service_fn(move |_: Uri| UnixStream::connect(path.clone())));
+
+    let first = async {
+        let mut stream = fetch_x509_svid(channel.clone()).await?;
+        let svid = next_svid(&mut stream).await?;
+        anyhow::Ok((stream, svid))
+    };
+    let (stream, svid) = timeout(FIRST_SVID_TIMEOUT, first)
+        .await
+        .context("timed out")
+        .and_then(|r| r)
+        .with_context(|| format!("unable to get an SVID from the Workload API at {socket}"))?;
+    info!("Got SVID {} from the Workload API", svid.spiffe_id);
+
+    let (tx, rx) = watch::channel(svid);
+    let socket = socket.to_string();
+    tokio::spawn(async move {
+        let mut stream = Some(stream);
+        loop {
+            let result = match &mut stream {
+                Some(stream) => next_svid(stream).await,
+                None => match fetch_x509_svid(channel.clone()).await {
+                    Ok(s) => {
+                        stream = Some(s);
+                        continue;
+                    }
+                    Err(e) => Err(e),
+                },
+            };
+            match result {
+                Ok(svid) => {
+                    info!("Rotated SVID {}", svid.spiffe_id);
+                    if tx.send(svid).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "Unable to get an SVID from the Workload API at {socket}, retrying: {e:#}"
+                    );
+                    stream = None;
+                    sleep(RETRY_DELAY).await;
+                }
+            }
+        }
+    });
+    Ok(rx)
+}
+
+async fn fetch_x509_svid(channel: Channel) -> anyhow::Result<Streaming<X509SvidResponse>> {
+    let mut client = Grpc::new(channel);
+    client.ready().await?;
+    let mut request = Request::new(X509SvidRequest {});
+    request
+        .metadata_mut()
+        .insert(WORKLOAD_HEADER, MetadataValue::from_static("true"));
+    let response = client
+        .server_streaming(
+            request,
+            PathAndQuery::from_static(FETCH_X509_SVID),
+            ProstCodec::default(),
+        )
+        .await?;
+    Ok(response.into_inner())
+}
+
+async fn next_svid(stream: &mut Streaming<X509SvidResponse>) -> anyhow::Result<Svid> {
+    let response = stream
+        .message()
+        .await?
+        .context("the Workload API closed the stream")?;
+    svid(response)
+}
+
+// bpfman uses the first SVID, the default one of its workload.
+fn svid(response: X509SvidResponse) -> anyhow::Result<Svid> {
+    let first = response
+        .svids
+        .into_iter()
+        .next()
+        .context("the Workload API returned no SVID")?;
+    let mut bundle = split_der(&first.bundle)?;
+    for federated in response.federated_bundles.values() {
+        bundle.extend(split_der(federated)?);
+    }
+    Ok(Svid {
+        spiffe_id: first.spiffe_id,
+        certs: split_der(&first.x509_svid)?,
+        key: first.x509_svid_key,
+        bundle,
+    })
+}
+
+// Splits the concatenated DER certificates the Workload API returns.
+fn split_der(mut der: &[u8]) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut certs = Vec::new();
+    while !der.is_empty() {
+        let len = der_len(der).context("invalid DER certificate")?;
+        certs.push(der[..len].to_vec());
+        der = &der[len..];
+    }
+    Ok(certs)
+}
+
+// Returns the length of the DER element at the start of der, header included.
+fn der_len(der: &[u8]) -> Option<usize> {
+    let first = *der.get(1)?;
+    let (header, len) = if first & 0x80 == 0 {
+        (2, first as usize)
+    } else {
+        let n = (first & 0x7f) as usize;
+        if n == 0 || n > 4 {
+            return None;
+        }
+        let bytes = der.get(2..2 + n)?;
+        (2 + n, bytes.iter().fold(0, |len, b| len << 8 | *b as usize))
+    };
+    let len = header + len;
+    (len <= der.len()).then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_der() {
+        let mut long = vec![0x30, 0x81, 0x80];
+        long.extend([0u8; 0x80]);
+        let mut der = vec![0x30, 0x03, 1, 2, 3];
+        der.extend(&long);
+
+        let certs = split_der(&der).unwrap();
+        assert_eq!(certs, vec![vec![0x30, 0x03, 1, 2, 3], long]);
+        assert!(split_der(&[]).unwrap().is_empty());
+        assert!(split_der(&[0x30, 0x05, 1, 2]).is_err());
+        assert!(split_der(&[0x30]).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// The kernel counts the runs of each program and the time they take while BPF
+// stats are enabled, either by the kernel.bpf_stats_enabled sysctl or for as
+// long as a process holds the fd returned by BPF_ENABLE_STATS. aya doesn't
+// report the counters yet, so they are read from bpf_prog_info here.
+
+use std::os::fd::{FromRawFd, OwnedFd};
+
+use log::info;
+use nix::libc;
+
+use crate::{
+    errors::BpfmanError,
+    maps::{obj_info, sys_bpf},
+};
+
+const BPF_PROG_GET_FD_BY_ID: libc::c_long = 13;
+const BPF_ENABLE_STATS: libc::c_long = 32;
+
+const BPF_STATS_RUN_TIME: u32 = 0;
+
+#[repr(C)]
+#[derive(Default)]
+struct GetFdByIdAttr {
+    id: u32,
+    next_id: u32,
+    open_flags: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct EnableStatsAttr {
+    stats_type: u32,
+}
+
+// struct bpf_prog_info, up to the counters.
+#[repr(C)]
+#[derive(Default)]
+struct ProgInfo {
+    _fields: [u64; 24],
+    run_time_ns: u64,
+    run_cnt: u64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct RunStats {
+    pub(crate) run_time_ns: u64,
+    pub(crate) run_count: u64,
+}
+
+// Enables the counters until the returned fd is closed.
+pub(crate) fn enable_run_time_stats() -> Result<OwnedFd, BpfmanError> {
+    let mut attr = EnableStatsAttr {
+        stats_type: BPF_STATS_RUN_TIME,
+    };
+    let fd = sys_bpf(BPF_ENABLE_STATS, &mut attr)
+        .map_err(|e| BpfmanError::Error(format!("unable to enable BPF stats: {e}")))?;
+    info!("Enabled BPF program runtime statistics");
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as i32) })
+}
+
+// Returns the counters of the program with the kernel id, which are zero if
+// stats were never enabled while it ran.
+pub(crate) fn run_stats(id: u32) -> Result<RunStats, BpfmanError> {
+    let mut attr = GetFdByIdAttr {
+        id,
+        ..Default::default()
+    };
+    let fd = sys_bpf(BPF_PROG_GET_FD_BY_ID, &mut attr)
+        .map_err(|e| BpfmanError::Error(format!("unable to get program {id}: {e}")))?;
+    // SAFETY: the fd was just returned by the kernel and isn't owned elsewhere.
+    let fd = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+    let mut info = ProgInfo::default();
+    obj_info(&fd, &mut info)?;
+    Ok(RunStats {
+        run_time_ns: info.run_time_ns,
+        run_count: info.run_cnt,
+    })
+}
@@ -27,13 +27,13 @@ use log::{debug, error, info, warn};
 use nix::mount::{mount, umount, MsFlags};
 use tokio::{
     net::UnixListener,
-    sync::{broadcast, mpsc, mpsc::Sender, oneshot},
+    sync::{broadcast, oneshot},
 };
 use tokio_stream::wrappers::UnixListenerStream;
 use tonic::{transport::Server, Request, Response, Status};
 
 use crate::{
-    command::Command,
+    command::{Command, CommandSender},
     utils::{create_bpffs, set_dir_permissions, set_file_permissions, SOCK_MODE},
 };
 
@@ -57,7 +57,7 @@ struct CsiIdentity {
 
 struct CsiNode {
     node_id: String,
-    tx: Sender<Command>,
+    tx: CommandSender,
 }
 
 #[async_trait]
@@ -342,7 +342,7 @@ impl Node for CsiNode {
 }
 
 impl StorageManager {
-    pub fn new(tx: mpsc::Sender<Command>) -> Self {
+    pub fn new(tx: CommandSender) -> Self {
         const VERSION: &str = env!("CARGO_PKG_VERSION");
         let node_id = std::env::var("KUBE_NODE_NAME")
             .expect("cannot start bpfman csi driver if KUBE_NODE_NAME not set");
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Requests can be traced with OpenTelemetry, from the gRPC call through the
+// command loop, BpfManager, the image manager and dispatcher loads, to find
+// out where a slow load spent its time. Spans are exported to an OTLP
+// collector if one is configured, and are otherwise dropped as they end. A
+// client can make the call part of a trace of its own with the W3C
+// traceparent gRPC metadata.
+
+use std::{
+    convert::Infallible,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context as TaskContext, Poll},
+};
+
+use anyhow::Context as _;
+use bpfman_api::config::TracingConfig;
+use opentelemetry::{
+    global::{self, BoxedTracer},
+    propagation::Extractor,
+    trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::{self as sdktrace, Sampler},
+    Resource,
+};
+use tonic::{
+    codegen::http::{HeaderMap, Request, Response},
+    server::NamedService,
+};
+use tower::Service;
+
+fn tracer() -> BoxedTracer {
+    global::tracer("bpfman")
+}
+
+// Exports spans to the OTLP collector of the configuration.
+pub(crate) fn init_tracing(config: &TracingConfig) -> anyhow::Result<()> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(config.sample_ratio)));
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(&config.otlp_endpoint),
+        )
+        .with_trace_config(
+            sdktrace::config()
+                .with_sampler(sampler)
+                .with_resource(Resource::new(vec![KeyValue::new(
+                    opentelemetry_semantic_conventions::resource::SERVICE_NAME,
+                    "bpfman",
+                )])),
+        )
+        .install_batch(runtime::Tokio)
+        .with_context(|| format!("unable to export traces to {}", config.otlp_endpoint))?;
+    Ok(())
+}
+
+// Exports the spans that ended but weren't exported yet.
+pub(crate) fn shutdown_tracing() {
+    global::shutdown_tracer_provider();
+}
+
+// Runs future in a span named name, a child of the span it's started in,
+// which is marked as failed if the future fails.
+pub(crate) async fn traced<T, E: fmt::Display>(
+    name: &'static str,
+    future: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let cx = Context::current_with_span(tracer().start(name));
+    let result = future.with_context(cx.clone()).await;
+    if let Err(e) = &result {
+        cx.span().set_status(Status::error(e.to_string()));
+    }
+    result
+}
+
+// Returns the context of a span named name, a child of the span of parent.
+pub(crate) fn child_span(name: &'static str, parent: &Context) -> Context {
+    let span = tracer().start_with_context(name, parent);
+    parent.with_span(span)
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+// Runs each call of a gRPC service in a span named after its method, which
+// continues the trace of the client if its metadata has one.
+#[derive(Debug, Clone)]
+pub(crate) struct TracedService<S>(S);
+
+impl<S> TracedService<S> {
+    pub(crate) fn new(service: S) -> Self {
+        Self(service)
+    }
+}
+
+impl<S: NamedService> NamedService for TracedService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, B, R> Service<Request<B>> for TracedService<S>
+where
+    S: Service<Request<B>, Response = Response<R>, Error = Infallible>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let parent =
+            global::get_text_map_propagator(|p| p.extract(&HeaderExtractor(request.headers())));
+        let tracer = tracer();
+        let span = tracer
+            .span_builder(request.uri().path().trim_start_matches('/').to_string())
+            .with_kind(SpanKind::Server)
+            .start_with_context(&tracer, &parent);
+        let cx = parent.with_span(span);
+        let future = self.0.call(request);
+        let span_cx = cx.clone();
+        Box::pin(
+            async move {
+                let response = future.await;
+                // Failed calls, which have no message, carry their status in
+                // the headers.
+                if let Ok(response) = &response {
+                    let headers = response.headers();
+                    let code = headers.get("grpc-status").and_then(|v| v.to_str().ok());
+                    if code.is_some_and(|c| c != "0") {
+                        let message = headers
+                            .get("grpc-message")
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default();
+                        span_cx
+                            .span()
+                            .set_status(Status::error(message.to_string()));
+                    }
+                }
+                response
+            }
+            .with_context(cx),
+        )
+    }
+}
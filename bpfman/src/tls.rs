@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// TLS listeners reload their certificate, key and client CA files when they
+// change, so that short-lived certificates can be rotated without restarting
+// bpfman. The files are checked whenever a client connects, and connections
+// keep the certificates they were made with. Listeners using the SPIFFE
+// Workload API instead use each SVID it streams for the connections after it.
+
+use std::{fs::File, io::BufReader, sync::Arc, time::SystemTime};
+
+use anyhow::{bail, Context};
+use bpfman_api::config::TlsConfig;
+use log::{info, warn};
+use rustls_pemfile::Item;
+use tokio::sync::watch;
+use tokio_rustls::{
+    rustls::{
+        server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore, ServerConfig,
+    },
+    TlsAcceptor,
+};
+
+use crate::spiffe::{watch_svid, Svid};
+
+enum Source {
+    Files {
+        tls: TlsConfig,
+        modified: Vec<Option<SystemTime>>,
+    },
+    Spiffe(watch::Receiver<Svid>),
+}
+
+pub(crate) struct TlsReloader {
+    source: Source,
+    alpn: Vec<Vec<u8>>,
+    acceptor: TlsAcceptor,
+}
+
+impl TlsReloader {
+    // Builds the acceptor of a listener offering the ALPN protocols alpn.
+    pub(crate) async fn new(tls: &TlsConfig, alpn: &[&[u8]]) -> anyhow::Result<Self> {
+        let alpn: Vec<Vec<u8>> = alpn.iter().map(|p| p.to_vec()).collect();
+        let (source, config) = match &tls.spiffe {
+            Some(spiffe) => {
+                let mut svids = watch_svid(&spiffe.socket).await?;
+                let config = svid_server_config(&svids.borrow_and_update())?;
+                (Source::Spiffe(svids), config)
+            }
+            None => {
+                let source = Source::Files {
+                    modified: modified(tls),
+                    tls: tls.clone(),
+                };
+                (source, file_server_config(tls)?)
+            }
+        };
+        Ok(Self {
+            acceptor: acceptor(config, &alpn),
+            source,
+            alpn,
+        })
+    }
+
+    // Returns the acceptor for a new connection, rebuilding it first if the
+    // files or the SVID changed. The previous acceptor is kept if they are
+    // invalid, like while the certificate is written but not its key yet.
+    pub(crate) fn acceptor(&mut self) -> TlsAcceptor {
+        let config = match &mut self.source {
+            Source::Files { tls, modified } => {
+                let current = self::modified(tls);
+                if current == *modified {
+                    None
+                } else {
+                    file_server_config(tls)
+                        .map(|config| {
+                            *modified = current;
+                            config
+                        })
+                        .map_err(|e| warn!("Keeping the previous TLS certificate: {e:#}"))
+                        .ok()
+                }
+            }
+            Source::Spiffe(svids) => match svids.has_changed() {
+                Ok(true) => svid_server_config(&svids.borrow_and_update())
+                    .map_err(|e| warn!("Keeping the previous SVID: {e:#}"))
+                    .ok(),
+                _ => None,
+            },
+        };
+        if let Some(config) = config {
+            info!("Reloaded TLS certificate");
+            self.acceptor = acceptor(config, &self.alpn);
+        }
+        self.acceptor.clone()
+    }
+}
+
+fn modified(tls: &TlsConfig) -> Vec<Option<SystemTime>> {
+    [&tls.cert, &tls.key, &tls.client_ca]
+        .into_iter()
+        .flatten()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn acceptor(mut config: ServerConfig, alpn: &[Vec<u8>]) -> TlsAcceptor {
+    config.alpn_protocols = alpn.to_vec();
+    TlsAcceptor::from(Arc::new(config))
+}
+
+fn file_server_config(tls: &TlsConfig) -> anyhow::Result<ServerConfig> {
+    let (Some(cert), Some(key)) = (&tls.cert, &tls.key) else {
+        bail!("TLS needs either cert and key or spiffe");
+    };
+    let certs: Vec<_> = read_pem(cert)?
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::X509Certificate(cert) => Some(Certificate(cert)),
+            _ => None,
+        })
+        .collect();
+    if certs.is_empty() {
+        bail!("no certificate in {cert}");
+    }
+    let key = read_pem(key)?
+        .into_iter()
+        .find_map(|item| match item {
+            Item::RSAKey(key) | Item::PKCS8Key(key) | Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .with_context(|| format!("no private key in {key}"))?;
+    let client_cas = match &tls.client_ca {
+        Some(path) => {
+            let cas: Vec<_> = read_pem(path)?
+                .into_iter()
+                .filter_map(|item| match item {
+                    Item::X509Certificate(cert) => Some(Certificate(cert)),
+                    _ => None,
+                })
+                .collect();
+            if cas.is_empty() {
+                bail!("no CA certificate in {path}");
+            }
+            Some(cas)
+        }
+        None => None,
+    };
+    tls_server_config(certs, key, client_cas)
+}
+
+// Clients of listeners using SPIFFE must always present an SVID.
+fn svid_server_config(svid: &Svid) -> anyhow::Result<ServerConfig> {
+    let certs = svid.certs.iter().cloned().map(Certificate).collect();
+    let bundle = svid.bundle.iter().cloned().map(Certificate).collect();
+    tls_server_config(certs, PrivateKey(svid.key.clone()), Some(bundle))
+        .with_context(|| format!("invalid SVID {}", svid.spiffe_id))
+}
+
+// Builds the TLS configuration of a listener, which requires client
+// certificates if there are client CAs.
+fn tls_server_config(
+    certs: Vec<Certificate>,
+    key: PrivateKey,
+    client_cas: Option<Vec<Certificate>>,
+) -> anyhow::Result<ServerConfig> {
+    let builder = ServerConfig::builder().with_safe_defaults();
+    let builder = match client_cas {
+        Some(cas) => {
+            let mut roots = RootCertStore::empty();
+            for ca in cas {
+                roots.add(&ca).context("invalid CA certificate")?;
+            }
+            if roots.is_empty() {
+                bail!("no CA certificate");
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        }
+        None => builder.with_no_client_auth(),
+    };
+    builder
+        .with_single_cert(certs, key)
+        .context("invalid TLS certificate or key")
+}
+
+fn read_pem(path: &str) -> anyhow::Result<Vec<Item>> {
+    let file = File::open(path).with_context(|| format!("unable to read {path}"))?;
+    rustls_pemfile::read_all(&mut BufReader::new(file))
+        .with_context(|| format!("invalid PEM file {path}"))
+}
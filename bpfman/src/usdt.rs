@@ -0,0 +1,518 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// Minimal ELF parsing needed to attach to USDT (SystemTap SDT) probes. Each
+// probe is described by an NT_STAPSDT note in the .note.stapsdt section of the
+// target binary, which records the probe address, the address of the
+// .stapsdt.base section at link time and the address of the optional
+// semaphore guarding the probe. The section of the functions of BPF objects
+// is found from the same ELF headers, as it tells the attach type programs are
+// built for.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use log::debug;
+
+use crate::errors::BpfmanError;
+
+const NT_STAPSDT: u32 = 3;
+const PT_LOAD: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const STT_FUNC: u8 = 2;
+const SYM_SIZE: u64 = 24;
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UsdtProbe {
+    pub(crate) provider: String,
+    pub(crate) name: String,
+    // File offset of the probe site, this is what a uprobe attaches to.
+    pub(crate) offset: u64,
+    // File offset of the semaphore, if the probe is guarded by one.
+    pub(crate) semaphore_offset: Option<u64>,
+}
+
+// Returns every site of the provider:name probe in target. The same probe can
+// be emitted more than once, e.g. when the surrounding function is inlined.
+pub(crate) fn find_probes(
+    target: &str,
+    provider: &str,
+    name: &str,
+) -> Result<Vec<UsdtProbe>, BpfmanError> {
+    let data = fs::read(target)
+        .map_err(|e| BpfmanError::Error(format!("unable to read {target}: {e}")))?;
+
+    let probes: Vec<UsdtProbe> = parse_probes(&data)?
+        .into_iter()
+        .filter(|p| p.provider == provider && p.name == name)
+        .collect();
+
+    if probes.is_empty() {
+        return Err(BpfmanError::UsdtProbeNotFound {
+            provider: provider.to_string(),
+            name: name.to_string(),
+            target: target.to_string(),
+        });
+    }
+
+    debug!(
+        "found {} site(s) for usdt {provider}:{name} in {target}",
+        probes.len()
+    );
+    Ok(probes)
+}
+
+// Adds delta to the semaphore of a probe in the address space of pid. Tracers
+// are expected to increment the semaphore while attached and decrement it
+// again when detaching, so that the guarded argument setup only runs while
+// someone is listening.
+pub(crate) fn update_semaphore(
+    pid: i32,
+    target: &str,
+    semaphore_offset: u64,
+    delta: i16,
+) -> Result<(), BpfmanError> {
+    let addr = semaphore_address(pid, target, semaphore_offset)?;
+    let mem_path = format!("/proc/{pid}/mem");
+    let mut mem = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&mem_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to open {mem_path}: {e}")))?;
+
+    let mut buf = [0u8; 2];
+    mem.seek(SeekFrom::Start(addr))
+        .and_then(|_| mem.read_exact(&mut buf))
+        .map_err(|e| BpfmanError::Error(format!("unable to read usdt semaphore: {e}")))?;
+
+    let value = u16::from_ne_bytes(buf).saturating_add_signed(delta);
+    mem.seek(SeekFrom::Start(addr))
+        .and_then(|_| mem.write_all(&value.to_ne_bytes()))
+        .map_err(|e| BpfmanError::Error(format!("unable to write usdt semaphore: {e}")))?;
+
+    debug!("usdt semaphore at {addr:#x} in pid {pid} set to {value}");
+    Ok(())
+}
+
+// Translates a file offset in target to a virtual address in pid by finding
+// the mapping of target that covers it.
+fn semaphore_address(pid: i32, target: &str, offset: u64) -> Result<u64, BpfmanError> {
+    let target = fs::canonicalize(target)
+        .map_err(|e| BpfmanError::Error(format!("unable to resolve {target}: {e}")))?;
+    let maps_path = format!("/proc/{pid}/maps");
+    let maps = fs::read_to_string(&maps_path)
+        .map_err(|e| BpfmanError::Error(format!("unable to read {maps_path}: {e}")))?;
+
+    for line in maps.lines() {
+        // Format: start-end perms offset dev inode pathname
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 6 || Path::new(fields[5]) != target {
+            continue;
+        }
+        let Some((start, end)) = fields[0].split_once('-') else {
+            continue;
+        };
+        let (Ok(start), Ok(end), Ok(map_offset)) = (
+            u64::from_str_radix(start, 16),
+            u64::from_str_radix(end, 16),
+            u64::from_str_radix(fields[2], 16),
+        ) else {
+            continue;
+        };
+        if offset >= map_offset && offset < map_offset + (end - start) {
+            return Ok(start + offset - map_offset);
+        }
+    }
+
+    Err(BpfmanError::Error(format!(
+        "{} is not mapped by pid {pid}",
+        target.display()
+    )))
+}
+
+struct Elf<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Elf<'a> {
+    fn bytes(&self, offset: u64, len: u64) -> Result<&'a [u8], BpfmanError> {
+        let start = usize::try_from(offset).map_err(|_| malformed())?;
+        let end = start
+            .checked_add(usize::try_from(len).map_err(|_| malformed())?)
+            .ok_or_else(malformed)?;
+        self.data.get(start..end).ok_or_else(malformed)
+    }
+
+    fn u16(&self, offset: u64) -> Result<u16, BpfmanError> {
+        let b = self.bytes(offset, 2)?.try_into().unwrap();
+        Ok(match self.little_endian {
+            true => u16::from_le_bytes(b),
+            false => u16::from_be_bytes(b),
+        })
+    }
+
+    fn u32(&self, offset: u64) -> Result<u32, BpfmanError> {
+        let b = self.bytes(offset, 4)?.try_into().unwrap();
+        Ok(match self.little_endian {
+            true => u32::from_le_bytes(b),
+            false => u32::from_be_bytes(b),
+        })
+    }
+
+    fn u64(&self, offset: u64) -> Result<u64, BpfmanError> {
+        let b = self.bytes(offset, 8)?.try_into().unwrap();
+        Ok(match self.little_endian {
+            true => u64::from_le_bytes(b),
+            false => u64::from_be_bytes(b),
+        })
+    }
+}
+
+struct Section {
+    name: String,
+    kind: u32,
+    link: u32,
+    addr: u64,
+    offset: u64,
+    size: u64,
+}
+
+struct Segment {
+    vaddr: u64,
+    offset: u64,
+    filesz: u64,
+}
+
+fn malformed() -> BpfmanError {
+    BpfmanError::Error("malformed ELF file".to_string())
+}
+
+fn c_str(data: &[u8]) -> Result<(String, &[u8]), BpfmanError> {
+    let end = data.iter().position(|b| *b == 0).ok_or_else(malformed)?;
+    Ok((
+        String::from_utf8_lossy(&data[..end]).to_string(),
+        &data[end + 1..],
+    ))
+}
+
+fn align4(v: u64) -> u64 {
+    (v + 3) & !3
+}
+
+fn parse_probes(data: &[u8]) -> Result<Vec<UsdtProbe>, BpfmanError> {
+    if data.get(..4) != Some(b"\x7fELF".as_slice()) {
+        return Err(BpfmanError::Error("not an ELF file".to_string()));
+    }
+    if data.get(4) != Some(&ELFCLASS64) {
+        return Err(BpfmanError::Error(
+            "only 64-bit ELF files are supported for usdt probes".to_string(),
+        ));
+    }
+    let elf = Elf {
+        data,
+        little_endian: data.get(5) == Some(&ELFDATA2LSB),
+    };
+
+    let sections = parse_sections(&elf)?;
+    let segments = parse_segments(&elf)?;
+
+    let Some(notes) = sections.iter().find(|s| s.name == ".note.stapsdt") else {
+        return Ok(vec![]);
+    };
+    let base_addr = sections
+        .iter()
+        .find(|s| s.name == ".stapsdt.base")
+        .map(|s| s.addr);
+
+    let to_offset = |addr: u64| {
+        segments
+            .iter()
+            .find(|s| addr >= s.vaddr && addr < s.vaddr + s.filesz)
+            .map(|s| addr - s.vaddr + s.offset)
+            .ok_or_else(|| {
+                BpfmanError::Error(format!("usdt address {addr:#x} is not in a loaded segment"))
+            })
+    };
+
+    let mut probes = vec![];
+    let mut pos = notes.offset;
+    let end = notes.offset + notes.size;
+    while pos + 12 <= end {
+        let namesz = elf.u32(pos)? as u64;
+        let descsz = elf.u32(pos + 4)? as u64;
+        let note_type = elf.u32(pos + 8)?;
+        let name_pos = pos + 12;
+        let desc_pos = name_pos + align4(namesz);
+        pos = desc_pos + align4(descsz);
+
+        if note_type != NT_STAPSDT || elf.bytes(name_pos, namesz)? != b"stapsdt\0" {
+            continue;
+        }
+
+        let desc = elf.bytes(desc_pos, descsz)?;
+        if desc.len() < 24 {
+            return Err(malformed());
+        }
+        let mut pc = elf.u64(desc_pos)?;
+        let note_base = elf.u64(desc_pos + 8)?;
+        let semaphore = elf.u64(desc_pos + 16)?;
+        let (provider, rest) = c_str(&desc[24..])?;
+        let (name, _) = c_str(rest)?;
+
+        // Account for prelinking, which moves the binary after the notes were
+        // written. The .stapsdt.base section tracks the shift.
+        if let Some(base_addr) = base_addr {
+            pc = pc.wrapping_add(base_addr).wrapping_sub(note_base);
+        }
+
+        probes.push(UsdtProbe {
+            provider,
+            name,
+            offset: to_offset(pc)?,
+            semaphore_offset: match semaphore {
+                0 => None,
+                s => Some(to_offset(s)?),
+            },
+        });
+    }
+
+    Ok(probes)
+}
+
+// Returns the name of the section the function name of the ELF64 object in
+// data is in, like "cgroup/connect4" for BPF programs, or None if there is no
+// such function.
+pub(crate) fn function_section(data: &[u8], name: &str) -> Result<Option<String>, BpfmanError> {
+    if data.get(..4) != Some(b"\x7fELF".as_slice()) || data.get(4) != Some(&ELFCLASS64) {
+        return Err(BpfmanError::Error("not an ELF64 file".to_string()));
+    }
+    let elf = Elf {
+        data,
+        little_endian: data.get(5) == Some(&ELFDATA2LSB),
+    };
+
+    let sections = parse_sections(&elf)?;
+    let Some(symtab) = sections.iter().find(|s| s.kind == SHT_SYMTAB) else {
+        return Ok(None);
+    };
+    let strtab = sections.get(symtab.link as usize).ok_or_else(malformed)?;
+    let strings = elf.bytes(strtab.offset, strtab.size)?;
+
+    for i in 0..symtab.size / SYM_SIZE {
+        let sym = symtab.offset + i * SYM_SIZE;
+        if elf.bytes(sym + 4, 1)?[0] & 0xf != STT_FUNC {
+            continue;
+        }
+        let name_off = elf.u32(sym)? as usize;
+        let (sym_name, _) = c_str(strings.get(name_off..).ok_or_else(malformed)?)?;
+        if sym_name == name {
+            let shndx = elf.u16(sym + 6)? as usize;
+            return Ok(sections.get(shndx).map(|s| s.name.clone()));
+        }
+    }
+    Ok(None)
+}
+
+fn parse_sections(elf: &Elf) -> Result<Vec<Section>, BpfmanError> {
+    let shoff = elf.u64(0x28)?;
+    let shentsize = elf.u16(0x3a)? as u64;
+    let shnum = elf.u16(0x3c)? as u64;
+    let shstrndx = elf.u16(0x3e)? as u64;
+    if shoff == 0 || shnum == 0 {
+        return Ok(vec![]);
+    }
+
+    let strtab_offset = elf.u64(shoff + shstrndx * shentsize + 24)?;
+    let strtab_size = elf.u64(shoff + shstrndx * shentsize + 32)?;
+    let strtab = elf.bytes(strtab_offset, strtab_size)?;
+
+    (0..shnum)
+        .map(|i| {
+            let sh = shoff + i * shentsize;
+            let name_off = elf.u32(sh)? as usize;
+            let (name, _) = c_str(strtab.get(name_off..).ok_or_else(malformed)?)?;
+            Ok(Section {
+                name,
+                kind: elf.u32(sh + 4)?,
+                link: elf.u32(sh + 40)?,
+                addr: elf.u64(sh + 16)?,
+                offset: elf.u64(sh + 24)?,
+                size: elf.u64(sh + 32)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_segments(elf: &Elf) -> Result<Vec<Segment>, BpfmanError> {
+    let phoff = elf.u64(0x20)?;
+    let phentsize = elf.u16(0x36)? as u64;
+    let phnum = elf.u16(0x38)? as u64;
+
+    let mut segments = vec![];
+    for i in 0..phnum {
+        let ph = phoff + i * phentsize;
+        if elf.u32(ph)? != PT_LOAD {
+            continue;
+        }
+        segments.push(Segment {
+            offset: elf.u64(ph + 8)?,
+            vaddr: elf.u64(ph + 16)?,
+            filesz: elf.u64(ph + 32)?,
+        });
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a little endian ELF64 image with a single PT_LOAD segment mapping
+    // file offset 0 at vaddr 0x400000 and a .note.stapsdt section holding the
+    // given (provider, name, pc, semaphore) notes.
+    fn build_elf(notes: &[(&str, &str, u64, u64)]) -> Vec<u8> {
+        let mut note_data = vec![];
+        for (provider, name, pc, semaphore) in notes {
+            let mut desc = vec![];
+            desc.extend_from_slice(&pc.to_le_bytes());
+            desc.extend_from_slice(&0u64.to_le_bytes());
+            desc.extend_from_slice(&semaphore.to_le_bytes());
+            desc.extend_from_slice(format!("{provider}\0{name}\0\0").as_bytes());
+            note_data.extend_from_slice(&8u32.to_le_bytes());
+            note_data.extend_from_slice(&(desc.len() as u32).to_le_bytes());
+            note_data.extend_from_slice(&NT_STAPSDT.to_le_bytes());
+            note_data.extend_from_slice(b"stapsdt\0");
+            while desc.len() % 4 != 0 {
+                desc.push(0);
+            }
+            note_data.extend_from_slice(&desc);
+        }
+        let strtab = b"\0.note.stapsdt\0.shstrtab\0";
+
+        let phoff = 64u64;
+        let notes_off = phoff + 56;
+        let strtab_off = notes_off + note_data.len() as u64;
+        let shoff = align4(strtab_off + strtab.len() as u64 + 4) + 4;
+
+        let mut elf = vec![0u8; shoff as usize];
+        elf[..4].copy_from_slice(b"\x7fELF");
+        elf[4] = ELFCLASS64;
+        elf[5] = ELFDATA2LSB;
+        elf[0x20..0x28].copy_from_slice(&phoff.to_le_bytes());
+        elf[0x28..0x30].copy_from_slice(&shoff.to_le_bytes());
+        elf[0x36..0x38].copy_from_slice(&56u16.to_le_bytes());
+        elf[0x38..0x3a].copy_from_slice(&1u16.to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&3u16.to_le_bytes());
+        elf[0x3e..0x40].copy_from_slice(&2u16.to_le_bytes());
+
+        let ph = phoff as usize;
+        elf[ph..ph + 4].copy_from_slice(&PT_LOAD.to_le_bytes());
+        elf[ph + 16..ph + 24].copy_from_slice(&0x400000u64.to_le_bytes());
+        elf[ph + 32..ph + 40].copy_from_slice(&0x10000u64.to_le_bytes());
+
+        elf[notes_off as usize..strtab_off as usize].copy_from_slice(&note_data);
+        elf[strtab_off as usize..strtab_off as usize + strtab.len()].copy_from_slice(strtab);
+
+        let section = |name: u32, offset: u64, size: u64| {
+            let mut sh = vec![0u8; 64];
+            sh[..4].copy_from_slice(&name.to_le_bytes());
+            sh[24..32].copy_from_slice(&offset.to_le_bytes());
+            sh[32..40].copy_from_slice(&size.to_le_bytes());
+            sh
+        };
+        elf.extend(section(0, 0, 0));
+        elf.extend(section(1, notes_off, note_data.len() as u64));
+        elf.extend(section(15, strtab_off, strtab.len() as u64));
+        elf
+    }
+
+    #[test]
+    fn test_parse_probes() {
+        let elf = build_elf(&[
+            ("node", "gc__start", 0x401000, 0),
+            ("python", "function__entry", 0x402000, 0x408000),
+        ]);
+
+        let probes = parse_probes(&elf).unwrap();
+        assert_eq!(
+            probes,
+            vec![
+                UsdtProbe {
+                    provider: "node".to_string(),
+                    name: "gc__start".to_string(),
+                    offset: 0x1000,
+                    semaphore_offset: None,
+                },
+                UsdtProbe {
+                    provider: "python".to_string(),
+                    name: "function__entry".to_string(),
+                    offset: 0x2000,
+                    semaphore_offset: Some(0x8000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_probes_not_elf() {
+        assert!(parse_probes(b"not an elf file").is_err());
+    }
+
+    #[test]
+    fn test_function_section() {
+        // A BPF object with the function "connect" in the section
+        // "cgroup/connect4" and its symbol and section name tables.
+        let shstrtab = b"\0cgroup/connect4\0.symtab\0.strtab\0.shstrtab\0";
+        let strtab = b"\0connect\0";
+        let mut symtab = vec![0u8; 2 * SYM_SIZE as usize];
+        symtab[24..28].copy_from_slice(&1u32.to_le_bytes());
+        symtab[28] = 0x10 | STT_FUNC;
+        symtab[30..32].copy_from_slice(&1u16.to_le_bytes());
+
+        let symtab_off = 64u64;
+        let strtab_off = symtab_off + symtab.len() as u64;
+        let shstrtab_off = strtab_off + strtab.len() as u64;
+        let shoff = shstrtab_off + shstrtab.len() as u64;
+
+        let mut elf = vec![0u8; 64];
+        elf[..4].copy_from_slice(b"\x7fELF");
+        elf[4] = ELFCLASS64;
+        elf[5] = ELFDATA2LSB;
+        elf[0x28..0x30].copy_from_slice(&shoff.to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&5u16.to_le_bytes());
+        elf[0x3e..0x40].copy_from_slice(&4u16.to_le_bytes());
+        elf.extend_from_slice(&symtab);
+        elf.extend_from_slice(strtab);
+        elf.extend_from_slice(shstrtab);
+
+        let section = |name: u32, kind: u32, link: u32, offset: u64, size: u64| {
+            let mut sh = vec![0u8; 64];
+            sh[..4].copy_from_slice(&name.to_le_bytes());
+            sh[4..8].copy_from_slice(&kind.to_le_bytes());
+            sh[24..32].copy_from_slice(&offset.to_le_bytes());
+            sh[32..40].copy_from_slice(&size.to_le_bytes());
+            sh[40..44].copy_from_slice(&link.to_le_bytes());
+            sh
+        };
+        elf.extend(section(0, 0, 0, 0, 0));
+        elf.extend(section(1, 1, 0, 0, 0));
+        elf.extend(section(17, SHT_SYMTAB, 3, symtab_off, symtab.len() as u64));
+        elf.extend(section(25, 3, 0, strtab_off, strtab.len() as u64));
+        elf.extend(section(33, 3, 0, shstrtab_off, shstrtab.len() as u64));
+
+        assert_eq!(
+            function_section(&elf, "connect").unwrap(),
+            Some("cgroup/connect4".to_string())
+        );
+        assert_eq!(function_section(&elf, "bind").unwrap(), None);
+        assert!(function_section(b"not an elf file", "connect").is_err());
+    }
+}
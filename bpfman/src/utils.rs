@@ -1,14 +1,25 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright Authors of bpfman
 
-use std::{os::unix::fs::PermissionsExt, path::Path, str};
+use std::{
+    fs::File,
+    os::unix::fs::{MetadataExt, PermissionsExt},
+    path::Path,
+    str, thread,
+};
 
 use anyhow::{Context, Result};
+use aya::util::KernelVersion;
+use bpfman_api::util::directories::RTDIR_UPLOADS;
 use log::{debug, info, warn};
 use nix::{
     mount::{mount, MsFlags},
-    net::if_::if_nametoindex,
+    net::if_::{if_nameindex, if_nametoindex},
+    sched::{setns, CloneFlags},
+    sys::utsname::uname,
+    unistd::{chown, Gid, Uid},
 };
+use sha2::{Digest, Sha256};
 use sled::Tree;
 use tokio::{fs, io::AsyncReadExt};
 
@@ -18,6 +29,15 @@ use crate::{errors::BpfmanError, ROOT_DB};
 // to Read/Write to it.
 pub(crate) const SOCK_MODE: u32 = 0o0660;
 
+// List of the active Linux Security Modules, in the order they are called.
+const LSM_PATH: &str = "/sys/kernel/security/lsm";
+
+// Kernel functions that can be probed, tracefs may be mounted in either place.
+const AVAILABLE_FILTER_FUNCTIONS_PATHS: [&str; 2] = [
+    "/sys/kernel/tracing/available_filter_functions",
+    "/sys/kernel/debug/tracing/available_filter_functions",
+];
+
 // Like tokio::fs::read, but with O_NOCTTY set
 pub(crate) async fn read<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, BpfmanError> {
     let mut data = vec![];
@@ -46,6 +66,87 @@ pub(crate) fn get_ifindex(iface: &str) -> Result<u32, BpfmanError> {
     }
 }
 
+// Like get_ifindex, but looks the interface up in the network namespace at
+// netns when one is given.
+pub(crate) fn get_ifindex_in(iface: &str, netns: Option<&Path>) -> Result<u32, BpfmanError> {
+    in_netns(netns, || get_ifindex(iface))
+}
+
+// Returns an id for the network namespace at netns, the inode of its file.
+// Interface indexes are only unique within a network namespace.
+pub(crate) fn get_netns_id(netns: &Path) -> Result<u64, BpfmanError> {
+    std::fs::metadata(netns).map(|m| m.ino()).map_err(|e| {
+        BpfmanError::Error(format!(
+            "unable to open network namespace {}: {e}",
+            netns.display()
+        ))
+    })
+}
+
+// Runs f inside the network namespace at netns, or right away when netns is
+// None. Network namespaces are per thread, so f runs on a scoped thread that
+// enters the namespace and exits along with it.
+pub(crate) fn in_netns<T, F>(netns: Option<&Path>, f: F) -> Result<T, BpfmanError>
+where
+    T: Send,
+    F: FnOnce() -> Result<T, BpfmanError> + Send,
+{
+    let Some(netns) = netns else {
+        return f();
+    };
+    let file = File::open(netns).map_err(|e| {
+        BpfmanError::Error(format!(
+            "unable to open network namespace {}: {e}",
+            netns.display()
+        ))
+    })?;
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            setns(&file, CloneFlags::CLONE_NEWNET).map_err(|e| {
+                BpfmanError::Error(format!(
+                    "unable to enter network namespace {}: {e}",
+                    netns.display()
+                ))
+            })?;
+            f()
+        })
+        .join()
+        .map_err(|_| BpfmanError::Error("network namespace thread panicked".to_string()))?
+    })
+}
+
+// Returns true if iface is an interface glob rather than an interface name.
+// Besides "*" and "?", a trailing "+" matches any suffix like in iptables.
+pub(crate) fn is_iface_glob(iface: &str) -> bool {
+    iface.contains(['*', '?']) || iface.ends_with('+')
+}
+
+pub(crate) fn iface_glob_match(glob: &str, iface: &str) -> bool {
+    match glob.strip_suffix('+') {
+        Some(prefix) => glob_match(format!("{prefix}*").as_bytes(), iface.as_bytes()),
+        None => glob_match(glob.as_bytes(), iface.as_bytes()),
+    }
+}
+
+// Returns the names of the interfaces matching an interface glob, sorted. The
+// interfaces are listed in the network namespace at netns when one is given.
+pub(crate) fn resolve_iface_glob(
+    glob: &str,
+    netns: Option<&Path>,
+) -> Result<Vec<String>, BpfmanError> {
+    let mut ifaces: Vec<String> = in_netns(netns, || {
+        Ok(if_nameindex()
+            .map_err(|e| BpfmanError::Error(format!("unable to list interfaces: {e}")))?
+            .iter()
+            .filter_map(|i| i.name().to_str().ok().map(|n| n.to_string()))
+            .filter(|n| iface_glob_match(glob, n))
+            .collect())
+    })?;
+    ifaces.sort();
+    Ok(ifaces)
+}
+
 pub(crate) async fn set_file_permissions(path: &str, mode: u32) {
     // Set the permissions on the file based on input
     if (tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).await).is_err() {
@@ -62,6 +163,33 @@ pub(crate) async fn set_dir_permissions(directory: &str, mode: u32) {
     }
 }
 
+// Gives the directory and the files in it to the user and group, leaving
+// whichever isn't set as is. The directory can be listed by whoever may read
+// its files, so it's given the files' mode plus the matching search bits.
+pub(crate) async fn set_dir_owner(
+    directory: &str,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    mode: u32,
+) -> Result<(), BpfmanError> {
+    let uid = uid.map(Uid::from_raw);
+    let gid = gid.map(Gid::from_raw);
+    let mut entries = fs::read_dir(directory).await?;
+    while let Some(file) = entries.next_entry().await? {
+        chown(&file.path(), uid, gid).map_err(|e| {
+            BpfmanError::Error(format!(
+                "unable to change owner of {}: {e}",
+                file.path().display()
+            ))
+        })?;
+    }
+    chown(directory, uid, gid)
+        .map_err(|e| BpfmanError::Error(format!("unable to change owner of {directory}: {e}")))?;
+    let dir_mode = mode | ((mode & 0o444) >> 2);
+    fs::set_permissions(directory, std::fs::Permissions::from_mode(dir_mode)).await?;
+    Ok(())
+}
+
 pub(crate) fn create_bpffs(directory: &str) -> anyhow::Result<()> {
     debug!("Creating bpffs at {directory}");
     let flags = MsFlags::MS_NOSUID | MsFlags::MS_NODEV | MsFlags::MS_NOEXEC | MsFlags::MS_RELATIME;
@@ -69,6 +197,141 @@ pub(crate) fn create_bpffs(directory: &str) -> anyhow::Result<()> {
         .with_context(|| format!("unable to create bpffs at {directory}"))
 }
 
+// Returns an error if the BPF LSM is not one of the active Linux Security
+// Modules, which is required before any LSM program can be attached.
+pub(crate) fn check_bpf_lsm_enabled() -> Result<(), BpfmanError> {
+    let lsms = std::fs::read_to_string(LSM_PATH)
+        .map_err(|e| BpfmanError::Error(format!("unable to read {LSM_PATH}: {e}")))?;
+    if lsms.trim().split(',').any(|l| l == "bpf") {
+        Ok(())
+    } else {
+        Err(BpfmanError::BpfLsmNotEnabled)
+    }
+}
+
+// Returns the path bytecode uploaded by clients is stored at.
+pub(crate) fn upload_path(data: &[u8]) -> String {
+    format!(
+        "{RTDIR_UPLOADS}/{}.o",
+        base16ct::lower::encode_string(&Sha256::digest(data))
+    )
+}
+
+// Stores uploaded bytecode at its path, see upload_path(). Programs loaded
+// from the same object share the file.
+pub(crate) fn store_upload(path: &str, data: &[u8]) -> Result<(), BpfmanError> {
+    if Path::new(path).exists() {
+        return Ok(());
+    }
+    std::fs::create_dir_all(RTDIR_UPLOADS)
+        .and_then(|_| std::fs::write(path, data))
+        .map_err(|e| BpfmanError::Error(format!("unable to store uploaded bytecode {path}: {e}")))
+}
+
+// Returns the release of the running kernel, like "6.8.0-45-generic".
+pub(crate) fn kernel_release() -> Result<String, BpfmanError> {
+    let uname = uname()
+        .map_err(|e| BpfmanError::Error(format!("unable to determine kernel release: {e}")))?;
+    Ok(uname.release().to_string_lossy().to_string())
+}
+
+// Returns an error if the running kernel predates netfilter programs and
+// links, which were added in Linux 6.4.
+pub(crate) fn check_netfilter_supported() -> Result<(), BpfmanError> {
+    let current = KernelVersion::current()
+        .map_err(|e| BpfmanError::Error(format!("unable to determine kernel version: {e}")))?;
+    if current < KernelVersion::new(6, 4, 0) {
+        return Err(BpfmanError::NetfilterKernelTooOld);
+    }
+    Ok(())
+}
+
+// Expands a list of kernel function names and globs ("*" and "?") into the
+// matching probeable kernel functions. Plain names are passed through as is.
+pub(crate) fn resolve_kernel_functions(patterns: &[String]) -> Result<Vec<String>, BpfmanError> {
+    let mut available: Option<Vec<String>> = None;
+    let mut functions = vec![];
+
+    for pattern in patterns {
+        if !pattern.contains(['*', '?']) {
+            functions.push(pattern.clone());
+            continue;
+        }
+
+        if available.is_none() {
+            available = Some(available_filter_functions()?);
+        }
+        let matches: Vec<String> = available
+            .iter()
+            .flatten()
+            .filter(|f| glob_match(pattern.as_bytes(), f.as_bytes()))
+            .cloned()
+            .collect();
+        if matches.is_empty() {
+            return Err(BpfmanError::NoMatchingKernelFunctions {
+                pattern: pattern.clone(),
+            });
+        }
+        functions.extend(matches);
+    }
+
+    functions.sort();
+    functions.dedup();
+    Ok(functions)
+}
+
+fn available_filter_functions() -> Result<Vec<String>, BpfmanError> {
+    let content = AVAILABLE_FILTER_FUNCTIONS_PATHS
+        .iter()
+        .find_map(|p| std::fs::read_to_string(p).ok())
+        .ok_or_else(|| {
+            BpfmanError::Error(
+                "unable to read available_filter_functions, is tracefs mounted?".to_string(),
+            )
+        })?;
+
+    // Lines are "<function>" or "<function> [<module>]".
+    let mut functions: Vec<String> = content
+        .lines()
+        .filter_map(|l| l.split_whitespace().next())
+        .map(|f| f.to_string())
+        .collect();
+    functions.sort();
+    functions.dedup();
+    Ok(functions)
+}
+
+// Matches name against a pattern where "*" matches any sequence of bytes and
+// "?" any single byte. On a mismatch only the last "*" seen is retried, with
+// one more byte of name, so long names and patterns with many stars take at
+// most a pass over the pattern per byte of name rather than exponential time.
+pub(crate) fn glob_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // The position after the last "*" and the byte of name it was tried at.
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                star = Some((p, n));
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    star = Some((star_p, n));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
 pub(crate) fn should_map_be_pinned(name: &str) -> bool {
     !(name.contains(".rodata") || name.contains(".bss") || name.contains(".data"))
 }
@@ -182,3 +445,42 @@ pub(crate) fn sled_insert(db_tree: &Tree, key: &str, value: &[u8]) -> Result<(),
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(b"", b""));
+        assert!(glob_match(b"*", b""));
+        assert!(glob_match(b"eth0", b"eth0"));
+        assert!(!glob_match(b"eth0", b"eth1"));
+        assert!(glob_match(b"eth?", b"eth1"));
+        assert!(!glob_match(b"eth?", b"eth10"));
+        assert!(glob_match(b"veth*", b"veth1a2b"));
+        assert!(glob_match(b"*@*", b"veth0@if12"));
+        assert!(glob_match(b"a*b*c", b"aXbYbZc"));
+        assert!(!glob_match(b"a*b*c", b"aXbYbZ"));
+        assert!(!glob_match(b"*a", b"b"));
+        assert!(glob_match(b"**", b"anything"));
+
+        // Would take exponential time with backtracking over every star.
+        let name = [b'a'; 64];
+        let mut pattern = b"*a".repeat(32);
+        pattern.push(b'b');
+        assert!(!glob_match(&pattern, &name));
+    }
+
+    #[test]
+    fn test_iface_glob_match() {
+        assert!(iface_glob_match("eth+", "eth0"));
+        assert!(iface_glob_match("eth+", "eth"));
+        assert!(!iface_glob_match("eth+", "veth0"));
+        assert!(iface_glob_match("veth*", "veth12ab"));
+        assert!(iface_glob_match("eth?", "eth1"));
+        assert!(!iface_glob_match("eth?", "eth"));
+        assert!(iface_glob_match("eth0", "eth0"));
+        assert!(!iface_glob_match("eth0", "eth00"));
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright Authors of bpfman
+
+// aya reports a program the verifier rejects with the errno of the load and
+// the whole verifier log, which can be megabytes long for large programs. The
+// reason of the rejection is at the end of the log, which is what is kept of
+// it for the client. Loads can ask for a log_level of their own, like for the
+// statistics of the verification.
+
+use aya::{programs::ProgramError, BpfLoader, VerifierLogLevel};
+
+use crate::{command::ProgramData, errors::BpfmanError};
+
+// Has bpf load its programs with the verifier log level of the program, if it
+// was loaded with one.
+pub(crate) fn set_verifier_log_level(
+    bpf: &mut BpfLoader,
+    data: &ProgramData,
+) -> Result<(), BpfmanError> {
+    if let Some(level) = data.get_verifier_log_level()? {
+        bpf.verifier_log_level(VerifierLogLevel::from_bits_retain(level));
+    }
+    Ok(())
+}
+
+// Turns a verifier rejection into a VerifierRejected error with the last
+// max_log_size bytes of the log, leaving other errors as they are.
+pub(crate) fn verifier_error(e: BpfmanError, max_log_size: usize) -> BpfmanError {
+    match e {
+        BpfmanError::BpfProgramError(ProgramError::LoadError {
+            io_error,
+            verifier_log,
+        }) => BpfmanError::VerifierRejected {
+            error: io_error.to_string(),
+            log: log_tail(&verifier_log.to_string(), max_log_size),
+        },
+        e => e,
+    }
+}
+
+// Returns the lines at the end of log which fit in max_size bytes, or the end
+// of its last line if even that doesn't fit, marking that some was left out.
+fn log_tail(log: &str, max_size: usize) -> String {
+    let log = log.trim_end();
+    if log.len() <= max_size {
+        return log.to_string();
+    }
+    let mut start = log.len() - max_size;
+    while !log.is_char_boundary(start) {
+        start += 1;
+    }
+    let mut tail = &log[start..];
+    if !log[..start].ends_with('\n') {
+        tail = tail.split_once('\n').map_or(tail, |(_, rest)| rest);
+    }
+    format!(
+        "[{} bytes of the verifier log left out]\n{tail}",
+        log.len() - tail.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_tail() {
+        let log = "0: R1=ctx() R10=fp0\n0: (b7) r0 = 2\n1: (95) exit\nR0 !read_ok\n";
+        assert_eq!(log_tail(log, 1024), log.trim_end());
+        assert_eq!(
+            log_tail(log, 24),
+            "[35 bytes of the verifier log left out]\n1: (95) exit\nR0 !read_ok"
+        );
+        assert_eq!(
+            log_tail(log, 30),
+            "[35 bytes of the verifier log left out]\n1: (95) exit\nR0 !read_ok"
+        );
+        assert_eq!(
+            log_tail(log, 4),
+            "[55 bytes of the verifier log left out]\nd_ok"
+        );
+    }
+}
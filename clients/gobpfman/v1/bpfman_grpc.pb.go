@@ -23,10 +23,36 @@ const _ = grpc.SupportPackageIsVersion7
 // For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
 type BpfmanClient interface {
 	Load(ctx context.Context, in *LoadRequest, opts ...grpc.CallOption) (*LoadResponse, error)
+	LoadUpload(ctx context.Context, opts ...grpc.CallOption) (Bpfman_LoadUploadClient, error)
+	LoadBatch(ctx context.Context, in *LoadBatchRequest, opts ...grpc.CallOption) (*LoadBatchResponse, error)
 	Unload(ctx context.Context, in *UnloadRequest, opts ...grpc.CallOption) (*UnloadResponse, error)
 	List(ctx context.Context, in *ListRequest, opts ...grpc.CallOption) (*ListResponse, error)
 	PullBytecode(ctx context.Context, in *PullBytecodeRequest, opts ...grpc.CallOption) (*PullBytecodeResponse, error)
+	PruneImages(ctx context.Context, in *PruneImagesRequest, opts ...grpc.CallOption) (*PruneImagesResponse, error)
+	ListImages(ctx context.Context, in *ListImagesRequest, opts ...grpc.CallOption) (*ListImagesResponse, error)
 	Get(ctx context.Context, in *GetRequest, opts ...grpc.CallOption) (*GetResponse, error)
+	Version(ctx context.Context, in *VersionRequest, opts ...grpc.CallOption) (*VersionResponse, error)
+	Read(ctx context.Context, in *ReadRequest, opts ...grpc.CallOption) (Bpfman_ReadClient, error)
+	Attach(ctx context.Context, in *AttachRequest, opts ...grpc.CallOption) (*AttachResponse, error)
+	Detach(ctx context.Context, in *DetachRequest, opts ...grpc.CallOption) (*DetachResponse, error)
+	SetPriority(ctx context.Context, in *SetPriorityRequest, opts ...grpc.CallOption) (*SetPriorityResponse, error)
+	PublishMaps(ctx context.Context, in *PublishMapsRequest, opts ...grpc.CallOption) (*PublishMapsResponse, error)
+	UnpublishMaps(ctx context.Context, in *UnpublishMapsRequest, opts ...grpc.CallOption) (*UnpublishMapsResponse, error)
+	Replace(ctx context.Context, in *ReplaceRequest, opts ...grpc.CallOption) (*ReplaceResponse, error)
+	Rollback(ctx context.Context, in *RollbackRequest, opts ...grpc.CallOption) (*RollbackResponse, error)
+	ListMaps(ctx context.Context, in *ListMapsRequest, opts ...grpc.CallOption) (*ListMapsResponse, error)
+	DumpMap(ctx context.Context, in *DumpMapRequest, opts ...grpc.CallOption) (*DumpMapResponse, error)
+	LookupMapEntry(ctx context.Context, in *LookupMapEntryRequest, opts ...grpc.CallOption) (*LookupMapEntryResponse, error)
+	UpdateMapEntry(ctx context.Context, in *UpdateMapEntryRequest, opts ...grpc.CallOption) (*UpdateMapEntryResponse, error)
+	DeleteMapEntry(ctx context.Context, in *DeleteMapEntryRequest, opts ...grpc.CallOption) (*DeleteMapEntryResponse, error)
+	WatchRingBuffer(ctx context.Context, in *WatchRingBufferRequest, opts ...grpc.CallOption) (Bpfman_WatchRingBufferClient, error)
+	WatchPerfBuffer(ctx context.Context, in *WatchPerfBufferRequest, opts ...grpc.CallOption) (Bpfman_WatchPerfBufferClient, error)
+	ExportMaps(ctx context.Context, in *ExportMapsRequest, opts ...grpc.CallOption) (*ExportMapsResponse, error)
+	UnexportMaps(ctx context.Context, in *UnexportMapsRequest, opts ...grpc.CallOption) (*UnexportMapsResponse, error)
+	GetEvents(ctx context.Context, in *GetEventsRequest, opts ...grpc.CallOption) (*GetEventsResponse, error)
+	ListKernelLinks(ctx context.Context, in *ListKernelLinksRequest, opts ...grpc.CallOption) (*ListKernelLinksResponse, error)
+	ListKernelMaps(ctx context.Context, in *ListKernelMapsRequest, opts ...grpc.CallOption) (*ListKernelMapsResponse, error)
+	ProbeFeatures(ctx context.Context, in *ProbeFeaturesRequest, opts ...grpc.CallOption) (*ProbeFeaturesResponse, error)
 }
 
 type bpfmanClient struct {
@@ -46,6 +72,49 @@ func (c *bpfmanClient) Load(ctx context.Context, in *LoadRequest, opts ...grpc.C
 	return out, nil
 }
 
+func (c *bpfmanClient) LoadUpload(ctx context.Context, opts ...grpc.CallOption) (Bpfman_LoadUploadClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[0], "/bpfman.v1.Bpfman/LoadUpload", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanLoadUploadClient{stream}
+	return x, nil
+}
+
+type Bpfman_LoadUploadClient interface {
+	Send(*LoadUploadRequest) error
+	CloseAndRecv() (*LoadResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanLoadUploadClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanLoadUploadClient) Send(m *LoadUploadRequest) error {
+	return x.ClientStream.SendMsg(m)
+}
+
+func (x *bpfmanLoadUploadClient) CloseAndRecv() (*LoadResponse, error) {
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	m := new(LoadResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *bpfmanClient) LoadBatch(ctx context.Context, in *LoadBatchRequest, opts ...grpc.CallOption) (*LoadBatchResponse, error) {
+	out := new(LoadBatchResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/LoadBatch", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *bpfmanClient) Unload(ctx context.Context, in *UnloadRequest, opts ...grpc.CallOption) (*UnloadResponse, error) {
 	out := new(UnloadResponse)
 	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Unload", in, out, opts...)
@@ -73,6 +142,24 @@ func (c *bpfmanClient) PullBytecode(ctx context.Context, in *PullBytecodeRequest
 	return out, nil
 }
 
+func (c *bpfmanClient) PruneImages(ctx context.Context, in *PruneImagesRequest, opts ...grpc.CallOption) (*PruneImagesResponse, error) {
+	out := new(PruneImagesResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/PruneImages", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ListImages(ctx context.Context, in *ListImagesRequest, opts ...grpc.CallOption) (*ListImagesResponse, error) {
+	out := new(ListImagesResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ListImages", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *bpfmanClient) Get(ctx context.Context, in *GetRequest, opts ...grpc.CallOption) (*GetResponse, error) {
 	out := new(GetResponse)
 	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Get", in, out, opts...)
@@ -82,15 +169,308 @@ func (c *bpfmanClient) Get(ctx context.Context, in *GetRequest, opts ...grpc.Cal
 	return out, nil
 }
 
+func (c *bpfmanClient) Version(ctx context.Context, in *VersionRequest, opts ...grpc.CallOption) (*VersionResponse, error) {
+	out := new(VersionResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Version", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Read(ctx context.Context, in *ReadRequest, opts ...grpc.CallOption) (Bpfman_ReadClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[1], "/bpfman.v1.Bpfman/Read", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanReadClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Bpfman_ReadClient interface {
+	Recv() (*ReadResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanReadClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanReadClient) Recv() (*ReadResponse, error) {
+	m := new(ReadResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *bpfmanClient) Attach(ctx context.Context, in *AttachRequest, opts ...grpc.CallOption) (*AttachResponse, error) {
+	out := new(AttachResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Attach", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Detach(ctx context.Context, in *DetachRequest, opts ...grpc.CallOption) (*DetachResponse, error) {
+	out := new(DetachResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Detach", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) SetPriority(ctx context.Context, in *SetPriorityRequest, opts ...grpc.CallOption) (*SetPriorityResponse, error) {
+	out := new(SetPriorityResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/SetPriority", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) PublishMaps(ctx context.Context, in *PublishMapsRequest, opts ...grpc.CallOption) (*PublishMapsResponse, error) {
+	out := new(PublishMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/PublishMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) UnpublishMaps(ctx context.Context, in *UnpublishMapsRequest, opts ...grpc.CallOption) (*UnpublishMapsResponse, error) {
+	out := new(UnpublishMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/UnpublishMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Replace(ctx context.Context, in *ReplaceRequest, opts ...grpc.CallOption) (*ReplaceResponse, error) {
+	out := new(ReplaceResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Replace", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) Rollback(ctx context.Context, in *RollbackRequest, opts ...grpc.CallOption) (*RollbackResponse, error) {
+	out := new(RollbackResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/Rollback", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ListMaps(ctx context.Context, in *ListMapsRequest, opts ...grpc.CallOption) (*ListMapsResponse, error) {
+	out := new(ListMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ListMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DumpMap(ctx context.Context, in *DumpMapRequest, opts ...grpc.CallOption) (*DumpMapResponse, error) {
+	out := new(DumpMapResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/DumpMap", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) LookupMapEntry(ctx context.Context, in *LookupMapEntryRequest, opts ...grpc.CallOption) (*LookupMapEntryResponse, error) {
+	out := new(LookupMapEntryResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/LookupMapEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) UpdateMapEntry(ctx context.Context, in *UpdateMapEntryRequest, opts ...grpc.CallOption) (*UpdateMapEntryResponse, error) {
+	out := new(UpdateMapEntryResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/UpdateMapEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) DeleteMapEntry(ctx context.Context, in *DeleteMapEntryRequest, opts ...grpc.CallOption) (*DeleteMapEntryResponse, error) {
+	out := new(DeleteMapEntryResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/DeleteMapEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) WatchRingBuffer(ctx context.Context, in *WatchRingBufferRequest, opts ...grpc.CallOption) (Bpfman_WatchRingBufferClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[2], "/bpfman.v1.Bpfman/WatchRingBuffer", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanWatchRingBufferClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Bpfman_WatchRingBufferClient interface {
+	Recv() (*WatchRingBufferResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanWatchRingBufferClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanWatchRingBufferClient) Recv() (*WatchRingBufferResponse, error) {
+	m := new(WatchRingBufferResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *bpfmanClient) WatchPerfBuffer(ctx context.Context, in *WatchPerfBufferRequest, opts ...grpc.CallOption) (Bpfman_WatchPerfBufferClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Bpfman_ServiceDesc.Streams[3], "/bpfman.v1.Bpfman/WatchPerfBuffer", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &bpfmanWatchPerfBufferClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Bpfman_WatchPerfBufferClient interface {
+	Recv() (*WatchPerfBufferResponse, error)
+	grpc.ClientStream
+}
+
+type bpfmanWatchPerfBufferClient struct {
+	grpc.ClientStream
+}
+
+func (x *bpfmanWatchPerfBufferClient) Recv() (*WatchPerfBufferResponse, error) {
+	m := new(WatchPerfBufferResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func (c *bpfmanClient) ExportMaps(ctx context.Context, in *ExportMapsRequest, opts ...grpc.CallOption) (*ExportMapsResponse, error) {
+	out := new(ExportMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ExportMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) UnexportMaps(ctx context.Context, in *UnexportMapsRequest, opts ...grpc.CallOption) (*UnexportMapsResponse, error) {
+	out := new(UnexportMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/UnexportMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) GetEvents(ctx context.Context, in *GetEventsRequest, opts ...grpc.CallOption) (*GetEventsResponse, error) {
+	out := new(GetEventsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/GetEvents", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ListKernelLinks(ctx context.Context, in *ListKernelLinksRequest, opts ...grpc.CallOption) (*ListKernelLinksResponse, error) {
+	out := new(ListKernelLinksResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ListKernelLinks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ListKernelMaps(ctx context.Context, in *ListKernelMapsRequest, opts ...grpc.CallOption) (*ListKernelMapsResponse, error) {
+	out := new(ListKernelMapsResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ListKernelMaps", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *bpfmanClient) ProbeFeatures(ctx context.Context, in *ProbeFeaturesRequest, opts ...grpc.CallOption) (*ProbeFeaturesResponse, error) {
+	out := new(ProbeFeaturesResponse)
+	err := c.cc.Invoke(ctx, "/bpfman.v1.Bpfman/ProbeFeatures", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // BpfmanServer is the server API for Bpfman service.
 // All implementations must embed UnimplementedBpfmanServer
 // for forward compatibility
 type BpfmanServer interface {
 	Load(context.Context, *LoadRequest) (*LoadResponse, error)
+	LoadUpload(Bpfman_LoadUploadServer) error
+	LoadBatch(context.Context, *LoadBatchRequest) (*LoadBatchResponse, error)
 	Unload(context.Context, *UnloadRequest) (*UnloadResponse, error)
 	List(context.Context, *ListRequest) (*ListResponse, error)
 	PullBytecode(context.Context, *PullBytecodeRequest) (*PullBytecodeResponse, error)
+	PruneImages(context.Context, *PruneImagesRequest) (*PruneImagesResponse, error)
+	ListImages(context.Context, *ListImagesRequest) (*ListImagesResponse, error)
 	Get(context.Context, *GetRequest) (*GetResponse, error)
+	Version(context.Context, *VersionRequest) (*VersionResponse, error)
+	Read(*ReadRequest, Bpfman_ReadServer) error
+	Attach(context.Context, *AttachRequest) (*AttachResponse, error)
+	Detach(context.Context, *DetachRequest) (*DetachResponse, error)
+	SetPriority(context.Context, *SetPriorityRequest) (*SetPriorityResponse, error)
+	PublishMaps(context.Context, *PublishMapsRequest) (*PublishMapsResponse, error)
+	UnpublishMaps(context.Context, *UnpublishMapsRequest) (*UnpublishMapsResponse, error)
+	Replace(context.Context, *ReplaceRequest) (*ReplaceResponse, error)
+	Rollback(context.Context, *RollbackRequest) (*RollbackResponse, error)
+	ListMaps(context.Context, *ListMapsRequest) (*ListMapsResponse, error)
+	DumpMap(context.Context, *DumpMapRequest) (*DumpMapResponse, error)
+	LookupMapEntry(context.Context, *LookupMapEntryRequest) (*LookupMapEntryResponse, error)
+	UpdateMapEntry(context.Context, *UpdateMapEntryRequest) (*UpdateMapEntryResponse, error)
+	DeleteMapEntry(context.Context, *DeleteMapEntryRequest) (*DeleteMapEntryResponse, error)
+	WatchRingBuffer(*WatchRingBufferRequest, Bpfman_WatchRingBufferServer) error
+	WatchPerfBuffer(*WatchPerfBufferRequest, Bpfman_WatchPerfBufferServer) error
+	ExportMaps(context.Context, *ExportMapsRequest) (*ExportMapsResponse, error)
+	UnexportMaps(context.Context, *UnexportMapsRequest) (*UnexportMapsResponse, error)
+	GetEvents(context.Context, *GetEventsRequest) (*GetEventsResponse, error)
+	ListKernelLinks(context.Context, *ListKernelLinksRequest) (*ListKernelLinksResponse, error)
+	ListKernelMaps(context.Context, *ListKernelMapsRequest) (*ListKernelMapsResponse, error)
+	ProbeFeatures(context.Context, *ProbeFeaturesRequest) (*ProbeFeaturesResponse, error)
 	mustEmbedUnimplementedBpfmanServer()
 }
 
@@ -101,6 +481,12 @@ type UnimplementedBpfmanServer struct {
 func (UnimplementedBpfmanServer) Load(context.Context, *LoadRequest) (*LoadResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Load not implemented")
 }
+func (UnimplementedBpfmanServer) LoadUpload(Bpfman_LoadUploadServer) error {
+	return status.Errorf(codes.Unimplemented, "method LoadUpload not implemented")
+}
+func (UnimplementedBpfmanServer) LoadBatch(context.Context, *LoadBatchRequest) (*LoadBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method LoadBatch not implemented")
+}
 func (UnimplementedBpfmanServer) Unload(context.Context, *UnloadRequest) (*UnloadResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Unload not implemented")
 }
@@ -110,9 +496,81 @@ func (UnimplementedBpfmanServer) List(context.Context, *ListRequest) (*ListRespo
 func (UnimplementedBpfmanServer) PullBytecode(context.Context, *PullBytecodeRequest) (*PullBytecodeResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method PullBytecode not implemented")
 }
+func (UnimplementedBpfmanServer) PruneImages(context.Context, *PruneImagesRequest) (*PruneImagesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PruneImages not implemented")
+}
+func (UnimplementedBpfmanServer) ListImages(context.Context, *ListImagesRequest) (*ListImagesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListImages not implemented")
+}
 func (UnimplementedBpfmanServer) Get(context.Context, *GetRequest) (*GetResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Get not implemented")
 }
+func (UnimplementedBpfmanServer) Version(context.Context, *VersionRequest) (*VersionResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Version not implemented")
+}
+func (UnimplementedBpfmanServer) Read(*ReadRequest, Bpfman_ReadServer) error {
+	return status.Errorf(codes.Unimplemented, "method Read not implemented")
+}
+func (UnimplementedBpfmanServer) Attach(context.Context, *AttachRequest) (*AttachResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Attach not implemented")
+}
+func (UnimplementedBpfmanServer) Detach(context.Context, *DetachRequest) (*DetachResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Detach not implemented")
+}
+func (UnimplementedBpfmanServer) SetPriority(context.Context, *SetPriorityRequest) (*SetPriorityResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetPriority not implemented")
+}
+func (UnimplementedBpfmanServer) PublishMaps(context.Context, *PublishMapsRequest) (*PublishMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PublishMaps not implemented")
+}
+func (UnimplementedBpfmanServer) UnpublishMaps(context.Context, *UnpublishMapsRequest) (*UnpublishMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UnpublishMaps not implemented")
+}
+func (UnimplementedBpfmanServer) Replace(context.Context, *ReplaceRequest) (*ReplaceResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Replace not implemented")
+}
+func (UnimplementedBpfmanServer) Rollback(context.Context, *RollbackRequest) (*RollbackResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Rollback not implemented")
+}
+func (UnimplementedBpfmanServer) ListMaps(context.Context, *ListMapsRequest) (*ListMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListMaps not implemented")
+}
+func (UnimplementedBpfmanServer) DumpMap(context.Context, *DumpMapRequest) (*DumpMapResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DumpMap not implemented")
+}
+func (UnimplementedBpfmanServer) LookupMapEntry(context.Context, *LookupMapEntryRequest) (*LookupMapEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method LookupMapEntry not implemented")
+}
+func (UnimplementedBpfmanServer) UpdateMapEntry(context.Context, *UpdateMapEntryRequest) (*UpdateMapEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateMapEntry not implemented")
+}
+func (UnimplementedBpfmanServer) DeleteMapEntry(context.Context, *DeleteMapEntryRequest) (*DeleteMapEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteMapEntry not implemented")
+}
+func (UnimplementedBpfmanServer) WatchRingBuffer(*WatchRingBufferRequest, Bpfman_WatchRingBufferServer) error {
+	return status.Errorf(codes.Unimplemented, "method WatchRingBuffer not implemented")
+}
+func (UnimplementedBpfmanServer) WatchPerfBuffer(*WatchPerfBufferRequest, Bpfman_WatchPerfBufferServer) error {
+	return status.Errorf(codes.Unimplemented, "method WatchPerfBuffer not implemented")
+}
+func (UnimplementedBpfmanServer) ExportMaps(context.Context, *ExportMapsRequest) (*ExportMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ExportMaps not implemented")
+}
+func (UnimplementedBpfmanServer) UnexportMaps(context.Context, *UnexportMapsRequest) (*UnexportMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UnexportMaps not implemented")
+}
+func (UnimplementedBpfmanServer) GetEvents(context.Context, *GetEventsRequest) (*GetEventsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetEvents not implemented")
+}
+func (UnimplementedBpfmanServer) ListKernelLinks(context.Context, *ListKernelLinksRequest) (*ListKernelLinksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListKernelLinks not implemented")
+}
+func (UnimplementedBpfmanServer) ListKernelMaps(context.Context, *ListKernelMapsRequest) (*ListKernelMapsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListKernelMaps not implemented")
+}
+func (UnimplementedBpfmanServer) ProbeFeatures(context.Context, *ProbeFeaturesRequest) (*ProbeFeaturesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ProbeFeatures not implemented")
+}
 func (UnimplementedBpfmanServer) mustEmbedUnimplementedBpfmanServer() {}
 
 // UnsafeBpfmanServer may be embedded to opt out of forward compatibility for this service.
@@ -144,6 +602,50 @@ func _Bpfman_Load_Handler(srv interface{}, ctx context.Context, dec func(interfa
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Bpfman_LoadUpload_Handler(srv interface{}, stream grpc.ServerStream) error {
+	return srv.(BpfmanServer).LoadUpload(&bpfmanLoadUploadServer{stream})
+}
+
+type Bpfman_LoadUploadServer interface {
+	SendAndClose(*LoadResponse) error
+	Recv() (*LoadUploadRequest, error)
+	grpc.ServerStream
+}
+
+type bpfmanLoadUploadServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanLoadUploadServer) SendAndClose(m *LoadResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func (x *bpfmanLoadUploadServer) Recv() (*LoadUploadRequest, error) {
+	m := new(LoadUploadRequest)
+	if err := x.ServerStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
+func _Bpfman_LoadBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(LoadBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).LoadBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/LoadBatch",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).LoadBatch(ctx, req.(*LoadBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Bpfman_Unload_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(UnloadRequest)
 	if err := dec(in); err != nil {
@@ -198,20 +700,461 @@ func _Bpfman_PullBytecode_Handler(srv interface{}, ctx context.Context, dec func
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Bpfman_Get_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(GetRequest)
+func _Bpfman_PruneImages_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PruneImagesRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(BpfmanServer).Get(ctx, in)
+		return srv.(BpfmanServer).PruneImages(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/bpfman.v1.Bpfman/Get",
+		FullMethod: "/bpfman.v1.Bpfman/PruneImages",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(BpfmanServer).Get(ctx, req.(*GetRequest))
+		return srv.(BpfmanServer).PruneImages(ctx, req.(*PruneImagesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ListImages_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListImagesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListImages(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ListImages",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListImages(ctx, req.(*ListImagesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Get_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Get(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Get",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Get(ctx, req.(*GetRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Version_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(VersionRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Version(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Version",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Version(ctx, req.(*VersionRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Read_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(ReadRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(BpfmanServer).Read(m, &bpfmanReadServer{stream})
+}
+
+type Bpfman_ReadServer interface {
+	Send(*ReadResponse) error
+	grpc.ServerStream
+}
+
+type bpfmanReadServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanReadServer) Send(m *ReadResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _Bpfman_Attach_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AttachRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Attach(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Attach",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Attach(ctx, req.(*AttachRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Detach_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DetachRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Detach(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Detach",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Detach(ctx, req.(*DetachRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_SetPriority_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetPriorityRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).SetPriority(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/SetPriority",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).SetPriority(ctx, req.(*SetPriorityRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_PublishMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PublishMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).PublishMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/PublishMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).PublishMaps(ctx, req.(*PublishMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_UnpublishMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UnpublishMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).UnpublishMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/UnpublishMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).UnpublishMaps(ctx, req.(*UnpublishMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Replace_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReplaceRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Replace(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Replace",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Replace(ctx, req.(*ReplaceRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_Rollback_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RollbackRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).Rollback(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/Rollback",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).Rollback(ctx, req.(*RollbackRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ListMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ListMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListMaps(ctx, req.(*ListMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DumpMap_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DumpMapRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DumpMap(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/DumpMap",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DumpMap(ctx, req.(*DumpMapRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_LookupMapEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(LookupMapEntryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).LookupMapEntry(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/LookupMapEntry",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).LookupMapEntry(ctx, req.(*LookupMapEntryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_UpdateMapEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateMapEntryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).UpdateMapEntry(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/UpdateMapEntry",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).UpdateMapEntry(ctx, req.(*UpdateMapEntryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_DeleteMapEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteMapEntryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).DeleteMapEntry(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/DeleteMapEntry",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).DeleteMapEntry(ctx, req.(*DeleteMapEntryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_WatchRingBuffer_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(WatchRingBufferRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(BpfmanServer).WatchRingBuffer(m, &bpfmanWatchRingBufferServer{stream})
+}
+
+type Bpfman_WatchRingBufferServer interface {
+	Send(*WatchRingBufferResponse) error
+	grpc.ServerStream
+}
+
+type bpfmanWatchRingBufferServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanWatchRingBufferServer) Send(m *WatchRingBufferResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _Bpfman_WatchPerfBuffer_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(WatchPerfBufferRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(BpfmanServer).WatchPerfBuffer(m, &bpfmanWatchPerfBufferServer{stream})
+}
+
+type Bpfman_WatchPerfBufferServer interface {
+	Send(*WatchPerfBufferResponse) error
+	grpc.ServerStream
+}
+
+type bpfmanWatchPerfBufferServer struct {
+	grpc.ServerStream
+}
+
+func (x *bpfmanWatchPerfBufferServer) Send(m *WatchPerfBufferResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _Bpfman_ExportMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ExportMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ExportMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ExportMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ExportMaps(ctx, req.(*ExportMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_UnexportMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UnexportMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).UnexportMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/UnexportMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).UnexportMaps(ctx, req.(*UnexportMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_GetEvents_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetEventsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).GetEvents(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/GetEvents",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).GetEvents(ctx, req.(*GetEventsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ListKernelLinks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListKernelLinksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListKernelLinks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ListKernelLinks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListKernelLinks(ctx, req.(*ListKernelLinksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ListKernelMaps_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListKernelMapsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ListKernelMaps(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ListKernelMaps",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ListKernelMaps(ctx, req.(*ListKernelMapsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Bpfman_ProbeFeatures_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ProbeFeaturesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(BpfmanServer).ProbeFeatures(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/bpfman.v1.Bpfman/ProbeFeatures",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(BpfmanServer).ProbeFeatures(ctx, req.(*ProbeFeaturesRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
@@ -227,6 +1170,10 @@ var Bpfman_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Load",
 			Handler:    _Bpfman_Load_Handler,
 		},
+		{
+			MethodName: "LoadBatch",
+			Handler:    _Bpfman_LoadBatch_Handler,
+		},
 		{
 			MethodName: "Unload",
 			Handler:    _Bpfman_Unload_Handler,
@@ -239,11 +1186,116 @@ var Bpfman_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "PullBytecode",
 			Handler:    _Bpfman_PullBytecode_Handler,
 		},
+		{
+			MethodName: "PruneImages",
+			Handler:    _Bpfman_PruneImages_Handler,
+		},
+		{
+			MethodName: "ListImages",
+			Handler:    _Bpfman_ListImages_Handler,
+		},
 		{
 			MethodName: "Get",
 			Handler:    _Bpfman_Get_Handler,
 		},
+		{
+			MethodName: "Version",
+			Handler:    _Bpfman_Version_Handler,
+		},
+		{
+			MethodName: "Attach",
+			Handler:    _Bpfman_Attach_Handler,
+		},
+		{
+			MethodName: "Detach",
+			Handler:    _Bpfman_Detach_Handler,
+		},
+		{
+			MethodName: "SetPriority",
+			Handler:    _Bpfman_SetPriority_Handler,
+		},
+		{
+			MethodName: "PublishMaps",
+			Handler:    _Bpfman_PublishMaps_Handler,
+		},
+		{
+			MethodName: "UnpublishMaps",
+			Handler:    _Bpfman_UnpublishMaps_Handler,
+		},
+		{
+			MethodName: "Replace",
+			Handler:    _Bpfman_Replace_Handler,
+		},
+		{
+			MethodName: "Rollback",
+			Handler:    _Bpfman_Rollback_Handler,
+		},
+		{
+			MethodName: "ListMaps",
+			Handler:    _Bpfman_ListMaps_Handler,
+		},
+		{
+			MethodName: "DumpMap",
+			Handler:    _Bpfman_DumpMap_Handler,
+		},
+		{
+			MethodName: "LookupMapEntry",
+			Handler:    _Bpfman_LookupMapEntry_Handler,
+		},
+		{
+			MethodName: "UpdateMapEntry",
+			Handler:    _Bpfman_UpdateMapEntry_Handler,
+		},
+		{
+			MethodName: "DeleteMapEntry",
+			Handler:    _Bpfman_DeleteMapEntry_Handler,
+		},
+		{
+			MethodName: "ExportMaps",
+			Handler:    _Bpfman_ExportMaps_Handler,
+		},
+		{
+			MethodName: "UnexportMaps",
+			Handler:    _Bpfman_UnexportMaps_Handler,
+		},
+		{
+			MethodName: "GetEvents",
+			Handler:    _Bpfman_GetEvents_Handler,
+		},
+		{
+			MethodName: "ListKernelLinks",
+			Handler:    _Bpfman_ListKernelLinks_Handler,
+		},
+		{
+			MethodName: "ListKernelMaps",
+			Handler:    _Bpfman_ListKernelMaps_Handler,
+		},
+		{
+			MethodName: "ProbeFeatures",
+			Handler:    _Bpfman_ProbeFeatures_Handler,
+		},
+	},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "LoadUpload",
+			Handler:       _Bpfman_LoadUpload_Handler,
+			ClientStreams: true,
+		},
+		{
+			StreamName:    "Read",
+			Handler:       _Bpfman_Read_Handler,
+			ServerStreams: true,
+		},
+		{
+			StreamName:    "WatchRingBuffer",
+			Handler:       _Bpfman_WatchRingBuffer_Handler,
+			ServerStreams: true,
+		},
+		{
+			StreamName:    "WatchPerfBuffer",
+			Handler:       _Bpfman_WatchPerfBuffer_Handler,
+			ServerStreams: true,
+		},
 	},
-	Streams:  []grpc.StreamDesc{},
 	Metadata: "bpfman.proto",
 }
@@ -35,6 +35,14 @@ fn build_bpfman(_opts: &Options) -> anyhow::Result<()> {
     let protos = &["bpfman.proto"];
     let includes = &[proto_dir.to_str().unwrap()];
     tonic_build::configure()
+        // The REST gateway translates JSON to and from the API messages.
+        .type_attribute(
+            ".bpfman.v1",
+            "#[derive(serde::Serialize, serde::Deserialize)]",
+        )
+        .message_attribute(".bpfman.v1", "#[serde(default)]")
+        .enum_attribute(".bpfman.v1", "#[serde(rename_all = \"snake_case\")]")
+        .file_descriptor_set_path(out_dir.join("bpfman_descriptor.bin"))
         .out_dir(out_dir)
         .compile(protos, includes)?;
 